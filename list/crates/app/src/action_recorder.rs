@@ -0,0 +1,314 @@
+use std::time::{Duration, Instant};
+
+use gpui::{
+    actions, point, px, AppContext, Global, Keystroke, KeystrokeEvent, Modifiers, MouseButton,
+    MouseDownEvent, MouseUpEvent, PlatformInput, Subscription, Task, WindowContext,
+};
+use rust_i18n::t;
+use serde::{Deserialize, Serialize};
+use ui::{notification::Notification, ContextModal as _};
+
+use crate::paths;
+
+actions!(debug, [ToggleRecording, ReplayRecording]);
+
+/// The mouse buttons can be recorded, the navigation buttons are ignored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RecordedButton {
+    Left,
+    Right,
+    Middle,
+}
+
+impl RecordedButton {
+    fn from_mouse_button(button: MouseButton) -> Option<Self> {
+        match button {
+            MouseButton::Left => Some(Self::Left),
+            MouseButton::Right => Some(Self::Right),
+            MouseButton::Middle => Some(Self::Middle),
+            _ => None,
+        }
+    }
+}
+
+impl From<RecordedButton> for MouseButton {
+    fn from(button: RecordedButton) -> Self {
+        match button {
+            RecordedButton::Left => MouseButton::Left,
+            RecordedButton::Right => MouseButton::Right,
+            RecordedButton::Middle => MouseButton::Middle,
+        }
+    }
+}
+
+/// An input captured by the recorder, replayed into the active window.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum RecordedEvent {
+    /// The keystroke, e.g.: `cmd-shift-p`, with the name of the action it dispatched.
+    ///
+    /// The action is kept to read the recording, the keystroke dispatches it again when replayed.
+    Keystroke {
+        keystroke: String,
+        #[serde(default)]
+        action: Option<String>,
+    },
+    MouseDown {
+        x: f32,
+        y: f32,
+        button: RecordedButton,
+        click_count: usize,
+    },
+    MouseUp {
+        x: f32,
+        y: f32,
+        button: RecordedButton,
+        click_count: usize,
+    },
+}
+
+impl RecordedEvent {
+    fn dispatch(&self, cx: &mut WindowContext) {
+        match self {
+            Self::Keystroke { keystroke, .. } => match Keystroke::parse(keystroke) {
+                Ok(keystroke) => {
+                    cx.dispatch_keystroke(keystroke);
+                }
+                Err(err) => log::warn!("Skip the invalid keystroke `{}`: {}", keystroke, err),
+            },
+            Self::MouseDown {
+                x,
+                y,
+                button,
+                click_count,
+            } => {
+                cx.dispatch_event(PlatformInput::MouseDown(MouseDownEvent {
+                    button: (*button).into(),
+                    position: point(px(*x), px(*y)),
+                    modifiers: Modifiers::default(),
+                    click_count: *click_count,
+                    first_mouse: false,
+                }));
+            }
+            Self::MouseUp {
+                x,
+                y,
+                button,
+                click_count,
+            } => {
+                cx.dispatch_event(PlatformInput::MouseUp(MouseUpEvent {
+                    button: (*button).into(),
+                    position: point(px(*x), px(*y)),
+                    modifiers: Modifiers::default(),
+                    click_count: *click_count,
+                }));
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimedEvent {
+    /// The milliseconds since the recording started.
+    pub at: u64,
+    #[serde(flatten)]
+    pub event: RecordedEvent,
+}
+
+/// The keystrokes and the clicks to replay for the demos or to reproduce the bugs.
+///
+/// Persisted to the [`paths::recording_file`] when the recording stops, to be shared.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Recording {
+    pub events: Vec<TimedEvent>,
+}
+
+impl Recording {
+    pub fn load() -> anyhow::Result<Self> {
+        let json = std::fs::read_to_string(paths::recording_file())?;
+        Ok(serde_json::from_str(&json)?)
+    }
+
+    pub fn save(&self) -> anyhow::Result<()> {
+        std::fs::create_dir_all(paths::data_dir())?;
+        std::fs::write(paths::recording_file(), serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+}
+
+/// Record the keystrokes of all the windows, and the clicks passed by [`record_mouse_down`] and [`record_mouse_up`].
+///
+/// The actions dispatched by the native menus are not keystrokes, they can't be recorded.
+struct ActionRecorder {
+    /// The start time and the events of the recording in progress.
+    recording: Option<(Instant, Vec<TimedEvent>)>,
+    /// True while replaying, the recording can't be started to not record the replayed events,
+    /// and the replayed clicks on the Debug menu will not toggle the recording again.
+    replaying: bool,
+    replay_task: Option<Task<()>>,
+    _subscription: Subscription,
+}
+
+impl Global for ActionRecorder {}
+
+pub fn init(cx: &mut AppContext) {
+    let _subscription = cx.observe_keystrokes(|event: &KeystrokeEvent, cx| {
+        let action = event
+            .action
+            .as_ref()
+            .map(|action| action.name().to_string());
+        record(
+            RecordedEvent::Keystroke {
+                keystroke: event.keystroke.unparse(),
+                action,
+            },
+            cx,
+        );
+    });
+    cx.set_global(ActionRecorder {
+        recording: None,
+        replaying: false,
+        replay_task: None,
+        _subscription,
+    });
+
+    cx.on_action(|_: &ToggleRecording, cx: &mut AppContext| toggle_recording(cx));
+    cx.on_action(|_: &ReplayRecording, cx: &mut AppContext| replay(cx));
+}
+
+/// Returns true if the recording is in progress.
+pub fn is_recording(cx: &AppContext) -> bool {
+    cx.try_global::<ActionRecorder>()
+        .map_or(false, |recorder| recorder.recording.is_some())
+}
+
+fn record(event: RecordedEvent, cx: &mut AppContext) {
+    if !is_recording(cx) {
+        return;
+    }
+
+    if let Some((started_at, events)) = &mut cx.global_mut::<ActionRecorder>().recording {
+        events.push(TimedEvent {
+            at: started_at.elapsed().as_millis() as u64,
+            event,
+        });
+    }
+}
+
+/// Record the mouse down of the window, call it in the capture phase to not be stopped by the elements.
+pub fn record_mouse_down(event: &MouseDownEvent, cx: &mut WindowContext) {
+    let Some(button) = RecordedButton::from_mouse_button(event.button) else {
+        return;
+    };
+
+    record(
+        RecordedEvent::MouseDown {
+            x: event.position.x.0,
+            y: event.position.y.0,
+            button,
+            click_count: event.click_count,
+        },
+        cx,
+    );
+}
+
+pub fn record_mouse_up(event: &MouseUpEvent, cx: &mut WindowContext) {
+    let Some(button) = RecordedButton::from_mouse_button(event.button) else {
+        return;
+    };
+
+    record(
+        RecordedEvent::MouseUp {
+            x: event.position.x.0,
+            y: event.position.y.0,
+            button,
+            click_count: event.click_count,
+        },
+        cx,
+    );
+}
+
+fn notify(notification: Notification, cx: &mut AppContext) {
+    if let Some(window) = cx.active_window() {
+        window
+            .update(cx, |_, cx| cx.push_notification(notification))
+            .ok();
+    }
+}
+
+/// Start the recording, or stop and save it.
+fn toggle_recording(cx: &mut AppContext) {
+    let recorder = cx.global_mut::<ActionRecorder>();
+    if recorder.replaying {
+        return;
+    }
+    let Some((_, events)) = recorder.recording.take() else {
+        recorder.recording = Some((Instant::now(), Vec::new()));
+        notify(
+            Notification::info(t!("Recorder.Recording Started").to_string()),
+            cx,
+        );
+        return;
+    };
+
+    let recording = Recording { events };
+    let count = recording.events.len();
+    match recording.save() {
+        Ok(_) => notify(
+            Notification::success(
+                t!(
+                    "Recorder.Recording Saved",
+                    count = count,
+                    path = paths::recording_file().display()
+                )
+                .to_string(),
+            ),
+            cx,
+        ),
+        Err(err) => notify(
+            Notification::error(t!("Recorder.Save Failed", error = err).to_string()),
+            cx,
+        ),
+    }
+}
+
+/// Replay the saved recording into the active window, with the same intervals as recorded.
+fn replay(cx: &mut AppContext) {
+    if is_recording(cx) {
+        toggle_recording(cx);
+    }
+
+    let recording = match Recording::load() {
+        Ok(recording) => recording,
+        Err(err) => {
+            notify(
+                Notification::error(t!("Recorder.Load Failed", error = err).to_string()),
+                cx,
+            );
+            return;
+        }
+    };
+    let Some(window) = cx.active_window() else {
+        return;
+    };
+
+    let replay_task = cx.spawn(|mut cx| async move {
+        let started_at = Instant::now();
+        for TimedEvent { at, event } in recording.events {
+            if let Some(delay) = Duration::from_millis(at).checked_sub(started_at.elapsed()) {
+                cx.background_executor().timer(delay).await;
+            }
+            if window.update(&mut cx, |_, cx| event.dispatch(cx)).is_err() {
+                log::warn!("The window of the replay has been closed.");
+                break;
+            }
+        }
+        log::info!("Replay finished.");
+        cx.update(|cx| cx.global_mut::<ActionRecorder>().replaying = false)
+            .ok();
+    });
+    let recorder = cx.global_mut::<ActionRecorder>();
+    recorder.replaying = true;
+    recorder.replay_task = Some(replay_task);
+}
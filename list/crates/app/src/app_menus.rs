@@ -0,0 +1,166 @@
+use std::rc::Rc;
+
+use gpui::{Action, AppContext, Menu, MenuItem};
+use ui::{
+    input::{Copy, Cut, Paste, Redo, Undo},
+    menu_bar::MenuBar,
+    theme::{ResetZoom, ZoomIn, ZoomOut},
+};
+
+use crate::{
+    action_recorder::{ReplayRecording, ToggleRecording},
+    panel_capture,
+    story_workspace::{
+        CapturePanel, CloseTab, CloseWindow, MinimizeWindow, Open, OpenDocs, OpenSettings,
+        ReopenClosedTab, ToggleCommandPalette, ToggleKeyboardShortcuts, ToggleLeftPanel,
+        TogglePanelZoom, ToggleRightPanel, ToggleSearch, ZoomWindow,
+    },
+    Quit,
+};
+
+enum AppMenuItem {
+    Separator,
+    Action {
+        name: &'static str,
+        action: Box<dyn Action>,
+    },
+}
+
+impl AppMenuItem {
+    fn action(name: &'static str, action: impl Action) -> Self {
+        Self::Action {
+            name,
+            action: Box::new(action),
+        }
+    }
+}
+
+struct AppMenu {
+    name: &'static str,
+    items: Vec<AppMenuItem>,
+}
+
+/// The menus of the app, shared by the native menus and the in-window [`MenuBar`].
+fn app_menus() -> Vec<AppMenu> {
+    vec![
+        AppMenu {
+            name: "File",
+            items: vec![
+                AppMenuItem::action("New Window", Open),
+                AppMenuItem::action("Close Window", CloseWindow),
+                AppMenuItem::Separator,
+                AppMenuItem::action("Close Tab", CloseTab),
+                AppMenuItem::action("Reopen Closed Tab", ReopenClosedTab),
+                AppMenuItem::Separator,
+                AppMenuItem::action("Settings", OpenSettings),
+                AppMenuItem::Separator,
+                AppMenuItem::action("Quit", Quit),
+            ],
+        },
+        AppMenu {
+            name: "View",
+            items: vec![
+                AppMenuItem::action("Command Palette", ToggleCommandPalette),
+                AppMenuItem::action("Search", ToggleSearch),
+                AppMenuItem::Separator,
+                AppMenuItem::action("Toggle Left Panel", ToggleLeftPanel),
+                AppMenuItem::action("Toggle Right Panel", ToggleRightPanel),
+                AppMenuItem::action("Toggle Panel Zoom", TogglePanelZoom),
+            ]
+            .into_iter()
+            // The screen can't be captured on all the platforms.
+            .chain(
+                panel_capture::SUPPORTED
+                    .then(|| AppMenuItem::action("Capture Panel", CapturePanel)),
+            )
+            .chain([
+                AppMenuItem::Separator,
+                AppMenuItem::action("Zoom In", ZoomIn),
+                AppMenuItem::action("Zoom Out", ZoomOut),
+                AppMenuItem::action("Reset Zoom", ResetZoom),
+            ])
+            .collect(),
+        },
+        AppMenu {
+            name: "Window",
+            items: vec![
+                AppMenuItem::action("Minimize", MinimizeWindow),
+                AppMenuItem::action("Zoom", ZoomWindow),
+            ],
+        },
+        AppMenu {
+            name: "Debug",
+            items: vec![
+                AppMenuItem::action("Start or Stop Recording", ToggleRecording),
+                AppMenuItem::action("Replay Recording", ReplayRecording),
+            ],
+        },
+        AppMenu {
+            name: "Help",
+            items: vec![
+                AppMenuItem::action("Keyboard Shortcuts", ToggleKeyboardShortcuts),
+                AppMenuItem::action("Documentation", OpenDocs),
+            ],
+        },
+    ]
+}
+
+/// Set the native application menus, only macOS has them, see [`menu_bar`] for the others.
+pub fn init(cx: &mut AppContext) {
+    let mut menus = app_menus()
+        .into_iter()
+        .enumerate()
+        .map(|(ix, menu)| Menu {
+            // The first menu is the application menu on macOS.
+            name: if ix == 0 { "GPUI App" } else { menu.name }.into(),
+            items: menu
+                .items
+                .into_iter()
+                .map(|item| match item {
+                    AppMenuItem::Separator => MenuItem::separator(),
+                    AppMenuItem::Action { name, action } => MenuItem::Action {
+                        name: name.into(),
+                        action,
+                        os_action: None,
+                    },
+                })
+                .collect(),
+        })
+        .collect::<Vec<_>>();
+
+    menus.insert(
+        1,
+        Menu {
+            name: "Edit".into(),
+            items: vec![
+                MenuItem::os_action("Undo", Undo, gpui::OsAction::Undo),
+                MenuItem::os_action("Redo", Redo, gpui::OsAction::Redo),
+                MenuItem::separator(),
+                MenuItem::os_action("Cut", Cut, gpui::OsAction::Cut),
+                MenuItem::os_action("Copy", Copy, gpui::OsAction::Copy),
+                MenuItem::os_action("Paste", Paste, gpui::OsAction::Paste),
+            ],
+        },
+    );
+
+    cx.set_menus(menus);
+}
+
+/// The in-window menu bar for Windows and Linux, with the same menus as the native ones.
+pub fn menu_bar() -> MenuBar {
+    app_menus()
+        .into_iter()
+        .fold(MenuBar::new("app-menu-bar"), |menu_bar, menu| {
+            let items = Rc::new(menu.items);
+            menu_bar.menu(menu.name, move |popup_menu, _| {
+                items
+                    .iter()
+                    .fold(popup_menu, |popup_menu, item| match item {
+                        AppMenuItem::Separator => popup_menu.separator(),
+                        AppMenuItem::Action { name, action } => {
+                            popup_menu.menu(*name, action.boxed_clone())
+                        }
+                    })
+            })
+        })
+}
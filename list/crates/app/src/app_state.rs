@@ -1,15 +1,129 @@
-use std::sync::Weak;
+use std::{
+    cell::{RefCell, RefMut},
+    path::PathBuf,
+    sync::{Arc, Weak},
+};
 
-use gpui::{AppContext, Global};
+use gpui::{AnyWindowHandle, AppContext, Global, Model, Task, WindowContext, WindowHandle};
+use story::{LogBuffer, StoryEntry, StoryRegistry};
+use ui::{
+    file_dialog::{self, OpenOptions, SaveOptions},
+    task_manager::TaskManager,
+    Root,
+};
 
-pub struct AppState {}
+use crate::undo_stack::UndoStack;
 
-struct GlobalAppState();
+/// The kind of the windows opened by the app.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AppWindowKind {
+    /// The main window with a [`crate::story_workspace::StoryWorkspace`].
+    Workspace,
+    /// The window with the panels floated out of a workspace, see [`crate::floating_window::FloatingWindow`].
+    Floating,
+}
+
+/// The shared state of the app, all the windows are sharing the same AppState.
+pub struct AppState {
+    /// All the opened windows, the app quits when the last one is closed.
+    windows: RefCell<Vec<(WindowHandle<Root>, AppWindowKind)>>,
+    /// The theme and layout changes of all the windows, to undo and redo.
+    undo_stack: RefCell<UndoStack>,
+    /// The latest log records, shown in the Logs story.
+    log_buffer: Arc<LogBuffer>,
+}
+
+struct GlobalAppState(Weak<AppState>);
 
 impl Global for GlobalAppState {}
 
 impl AppState {
-    pub fn set_global(_app_state: Weak<AppState>, cx: &mut AppContext) {
-        cx.set_global(GlobalAppState());
+    pub fn new() -> Self {
+        Self {
+            windows: RefCell::new(Vec::new()),
+            undo_stack: RefCell::new(UndoStack::new()),
+            log_buffer: Arc::new(LogBuffer::default()),
+        }
+    }
+
+    pub fn set_global(app_state: Weak<AppState>, cx: &mut AppContext) {
+        cx.set_global(GlobalAppState(app_state));
+    }
+
+    /// Returns the global AppState, `None` if it has been released.
+    pub fn global(cx: &AppContext) -> Option<Arc<AppState>> {
+        cx.try_global::<GlobalAppState>()
+            .and_then(|state| state.0.upgrade())
+    }
+
+    /// Returns all the opened StoryWorkspace windows.
+    pub fn windows(&self) -> Vec<WindowHandle<Root>> {
+        self.windows
+            .borrow()
+            .iter()
+            .filter(|(_, kind)| *kind == AppWindowKind::Workspace)
+            .map(|(window, _)| *window)
+            .collect()
+    }
+
+    pub fn undo_stack(&self) -> RefMut<'_, UndoStack> {
+        self.undo_stack.borrow_mut()
+    }
+
+    /// Returns the buffer of the latest log records, written by the [`crate::logger`].
+    pub fn log_buffer(&self) -> Arc<LogBuffer> {
+        self.log_buffer.clone()
+    }
+
+    /// Open the native dialog to pick the files to open, the result is empty if it's cancelled.
+    ///
+    /// The stories can't depend on the app, they use the same [`file_dialog`] directly.
+    pub fn prompt_open(
+        options: OpenOptions,
+        cx: &mut WindowContext,
+    ) -> Task<anyhow::Result<Vec<PathBuf>>> {
+        file_dialog::prompt_open(options, cx)
+    }
+
+    /// Open the native dialog to pick the path to save the file, `None` if it's cancelled.
+    pub fn prompt_save(
+        options: SaveOptions,
+        cx: &mut WindowContext,
+    ) -> Task<anyhow::Result<Option<PathBuf>>> {
+        file_dialog::prompt_save(options, cx)
+    }
+
+    /// Returns the manager of the background tasks, the tasks are shown in the status bar to cancel.
+    ///
+    /// The stories load in the same global [`TaskManager`] of the ui crate.
+    pub fn task_manager(cx: &AppContext) -> Model<TaskManager> {
+        TaskManager::global(cx)
+    }
+
+    /// Returns the stories to show in the workspaces, in the registered order.
+    ///
+    /// They are kept in the global [`StoryRegistry`] of the story crate,
+    /// so the stories can be registered by [`story::register_story`] without depending on the app.
+    pub fn stories(cx: &AppContext) -> Vec<StoryEntry> {
+        StoryRegistry::global(cx)
+            .map(|registry| registry.stories().to_vec())
+            .unwrap_or_default()
+    }
+
+    /// Returns the registered story by the name, see [`AppState::stories`].
+    pub fn find_story(name: &str, cx: &AppContext) -> Option<StoryEntry> {
+        StoryRegistry::global(cx).and_then(|registry| registry.find(name))
+    }
+
+    /// Track a new opened window.
+    pub fn add_window(&self, window: WindowHandle<Root>, kind: AppWindowKind) {
+        self.windows.borrow_mut().push((window, kind));
+    }
+
+    /// Remove the window when it is closed, returns the number of the remaining windows of all kinds.
+    pub fn remove_window(&self, window: AnyWindowHandle) -> usize {
+        let mut windows = self.windows.borrow_mut();
+        windows.retain(|(handle, _)| AnyWindowHandle::from(*handle) != window);
+        windows.len()
     }
 }
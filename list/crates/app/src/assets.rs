@@ -2,23 +2,28 @@ use anyhow::anyhow;
 
 use gpui::AssetSource;
 use rust_embed::RustEmbed;
+use ui::IconAssets;
 
+/// The fonts of the app, the icons are bundled by the ui crate, see [`IconAssets`].
 #[derive(RustEmbed)]
 #[folder = "../../assets"]
 #[include = "fonts/**/*"]
-#[include = "icons/**/*"]
 #[exclude = "*.DS_Store"]
 pub struct Assets;
 
 impl AssetSource for Assets {
     fn load(&self, path: &str) -> gpui::Result<Option<std::borrow::Cow<'static, [u8]>>> {
+        if let Some(icon) = IconAssets.load(path)? {
+            return Ok(Some(icon));
+        }
+
         Self::get(path)
             .map(|f| Some(f.data))
             .ok_or_else(|| anyhow!("could not find asset at path \"{}\"", path))
     }
 
     fn list(&self, path: &str) -> gpui::Result<Vec<gpui::SharedString>> {
-        Ok(Self::iter()
+        let mut paths: Vec<gpui::SharedString> = Self::iter()
             .filter_map(|p| {
                 if p.starts_with(path) {
                     Some(p.into())
@@ -26,6 +31,8 @@ impl AssetSource for Assets {
                     None
                 }
             })
-            .collect())
+            .collect();
+        paths.extend(IconAssets.list(path)?);
+        Ok(paths)
     }
 }
@@ -0,0 +1,243 @@
+use gpui::{
+    div, prelude::FluentBuilder as _, px, Action, AppContext, FocusHandle, FocusableView,
+    IntoElement, ParentElement as _, Render, SharedString, Styled as _, Task, View, ViewContext,
+    VisualContext as _, WeakView, WindowContext,
+};
+use story::StoryEntry;
+use ui::{
+    accessibility::Accessible as _,
+    fuzzy::fuzzy_match,
+    h_flex,
+    list::{List, ListDelegate, ListItem},
+    theme::ActiveTheme as _,
+    v_flex, ContextModal as _, Icon, IconName,
+};
+
+use crate::{app_state::AppState, story_workspace::StoryWorkspace};
+
+enum Command {
+    Story(StoryEntry),
+    Action {
+        name: SharedString,
+        action: Box<dyn Action>,
+    },
+}
+
+impl Command {
+    fn label(&self) -> SharedString {
+        match self {
+            Command::Story(story) => story.name.clone(),
+            Command::Action { name, .. } => name.clone(),
+        }
+    }
+}
+
+/// Humanize the action name, e.g.: `dock::ToggleZoom` to `dock: toggle zoom`.
+pub(crate) fn humanize_action_name(name: &str) -> String {
+    let mut result = String::new();
+    let mut prev_char = None;
+    for char in name.chars() {
+        if char == ':' {
+            if prev_char != Some(':') {
+                result.push(':');
+                result.push(' ');
+            }
+        } else if char.is_uppercase() {
+            if !result.is_empty() && !result.ends_with(' ') {
+                result.push(' ');
+            }
+            result.extend(char.to_lowercase());
+        } else {
+            result.push(char);
+        }
+        prev_char = Some(char);
+    }
+    result
+}
+
+struct CommandPaletteDelegate {
+    workspace: WeakView<StoryWorkspace>,
+    commands: Vec<Command>,
+    /// The index of the matched commands, sorted by the score.
+    matches: Vec<usize>,
+}
+
+impl ListDelegate for CommandPaletteDelegate {
+    type Item = ListItem;
+
+    fn items_count(&self) -> usize {
+        self.matches.len()
+    }
+
+    fn perform_search(&mut self, query: &str, _: &mut ViewContext<List<Self>>) -> Task<()> {
+        let mut matches = self
+            .commands
+            .iter()
+            .enumerate()
+            .filter_map(|(ix, command)| fuzzy_match(query, &command.label()).map(|m| (ix, m.score)))
+            .collect::<Vec<_>>();
+        matches.sort_by(|a, b| b.1.cmp(&a.1));
+
+        self.matches = matches.into_iter().map(|(ix, _)| ix).collect();
+
+        Task::Ready(Some(()))
+    }
+
+    fn render_item(&self, ix: usize, cx: &mut ViewContext<List<Self>>) -> Option<Self::Item> {
+        let command = self.commands.get(*self.matches.get(ix)?)?;
+
+        let (icon, description, keybinding) = match command {
+            Command::Story(story) => (IconName::Inbox, Some(story.description.clone()), None),
+            Command::Action { action, .. } => (
+                IconName::ChevronRight,
+                None,
+                cx.bindings_for_action(action.as_ref())
+                    .first()
+                    .map(|binding| {
+                        binding
+                            .keystrokes()
+                            .iter()
+                            .map(|keystroke| format!("{}", keystroke))
+                            .collect::<Vec<_>>()
+                            .join(" ")
+                    }),
+            ),
+        };
+
+        Some(
+            ListItem::new(("command", ix))
+                .accessible_label(command.label())
+                .child(
+                    h_flex()
+                        .w_full()
+                        .gap_2()
+                        .justify_between()
+                        .child(
+                            h_flex()
+                                .gap_2()
+                                .overflow_x_hidden()
+                                .child(
+                                    Icon::new(icon).text_color(cx.theme().tokens.on_surface_muted),
+                                )
+                                .child(v_flex().child(command.label()).when_some(
+                                    description,
+                                    |this, description| {
+                                        this.child(
+                                            div()
+                                                .text_xs()
+                                                .text_color(cx.theme().tokens.on_surface_muted)
+                                                .child(description),
+                                        )
+                                    },
+                                )),
+                        )
+                        .when_some(keybinding, |this, keybinding| {
+                            this.child(
+                                div()
+                                    .text_xs()
+                                    .text_color(cx.theme().tokens.on_surface_muted)
+                                    .child(keybinding),
+                            )
+                        }),
+                ),
+        )
+    }
+
+    fn set_selected_index(&mut self, _: Option<usize>, _: &mut ViewContext<List<Self>>) {}
+
+    fn confirm(&mut self, ix: Option<usize>, cx: &mut ViewContext<List<Self>>) {
+        let Some(command) = ix
+            .and_then(|ix| self.matches.get(ix))
+            .and_then(|ix| self.commands.get(*ix))
+        else {
+            return;
+        };
+
+        cx.close_modal();
+        match command {
+            Command::Story(story) => {
+                let story = story.clone();
+                let _ = self.workspace.update(cx, |workspace, cx| {
+                    workspace.open_story(story, cx);
+                });
+            }
+            Command::Action { action, .. } => {
+                cx.dispatch_action(action.boxed_clone());
+            }
+        }
+    }
+
+    fn cancel(&mut self, cx: &mut ViewContext<List<Self>>) {
+        cx.close_modal();
+    }
+}
+
+/// A fuzzy-searchable list of the stories and the available actions.
+pub struct CommandPalette {
+    list: View<List<CommandPaletteDelegate>>,
+}
+
+impl CommandPalette {
+    /// Open the command palette in a Modal.
+    ///
+    /// The actions are collected from the current focused element, so this must be called before the modal opened.
+    pub fn open(workspace: WeakView<StoryWorkspace>, cx: &mut WindowContext) {
+        let view = cx.new_view(|cx| Self::new(workspace, cx));
+        cx.open_modal({
+            let view = view.clone();
+            move |modal, _| {
+                modal
+                    .accessible_label("Command Palette")
+                    .width(px(560.))
+                    .child(view.clone())
+            }
+        });
+        view.focus_handle(cx).focus(cx);
+    }
+
+    fn new(workspace: WeakView<StoryWorkspace>, cx: &mut ViewContext<Self>) -> Self {
+        let mut commands = AppState::stories(cx)
+            .into_iter()
+            .map(Command::Story)
+            .collect::<Vec<_>>();
+
+        let mut actions = cx
+            .available_actions()
+            .into_iter()
+            .map(|action| Command::Action {
+                name: humanize_action_name(action.name()).into(),
+                action,
+            })
+            .collect::<Vec<_>>();
+        actions.sort_by_key(|command| command.label());
+        actions.dedup_by_key(|command| command.label());
+        commands.extend(actions);
+
+        let matches = (0..commands.len()).collect();
+        let delegate = CommandPaletteDelegate {
+            workspace,
+            commands,
+            matches,
+        };
+
+        let list = cx.new_view(|cx| {
+            let mut list = List::new(delegate, cx).max_h(px(400.));
+            list.set_selected_index(Some(0), cx);
+            list
+        });
+
+        Self { list }
+    }
+}
+
+impl FocusableView for CommandPalette {
+    fn focus_handle(&self, cx: &AppContext) -> FocusHandle {
+        self.list.focus_handle(cx)
+    }
+}
+
+impl Render for CommandPalette {
+    fn render(&mut self, _: &mut ViewContext<Self>) -> impl IntoElement {
+        div().w_full().child(self.list.clone())
+    }
+}
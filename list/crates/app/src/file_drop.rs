@@ -0,0 +1,26 @@
+use std::path::{Path, PathBuf};
+
+/// A file dropped onto the workspace from the OS, routed by the extension.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DroppedFile {
+    /// A theme JSON file, to apply it to the global [`ui::theme::Theme`].
+    Theme(PathBuf),
+    /// A CSV file, to open it in a new Table tab.
+    Csv(PathBuf),
+    Unsupported(PathBuf),
+}
+
+impl DroppedFile {
+    pub fn new(path: &Path) -> Self {
+        let extension = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .map(|ext| ext.to_lowercase());
+
+        match extension.as_deref() {
+            Some("json") => Self::Theme(path.to_path_buf()),
+            Some("csv") => Self::Csv(path.to_path_buf()),
+            _ => Self::Unsupported(path.to_path_buf()),
+        }
+    }
+}
@@ -0,0 +1,384 @@
+use std::{sync::Arc, time::Duration};
+
+use gpui::*;
+use prelude::FluentBuilder as _;
+use rust_i18n::t;
+use ui::{
+    button::Button,
+    dock::{DockArea, DockAreaState, PanelInfo, PanelState, StackAxis, StackPanel},
+    theme::{ActiveTheme as _, Theme, ThemeMode},
+    IconName, Root, Selectable as _, Sizable as _,
+};
+use workspace::TitleBar;
+
+use crate::{
+    app_state::{AppState, AppWindowKind},
+    settings::Settings,
+    story_workspace::StoryWorkspace,
+};
+
+actions!(floating_window, [DockBack]);
+
+/// The size of the picture-in-picture window.
+const PIP_SIZE: Size<Pixels> = Size {
+    width: px(360.),
+    height: px(240.),
+};
+/// The margin of the picture-in-picture window to the corner of the display.
+const PIP_MARGIN: Pixels = px(24.);
+/// The delay after the window stops moving to dock it onto the workspace window under it.
+const DOCK_DELAY: Duration = Duration::from_millis(600);
+
+/// How the panel is floated out of the [`StoryWorkspace`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FloatingMode {
+    /// A normal window in the center of the display.
+    Window,
+    /// A small always-on-top window at the bottom right of the display,
+    /// keeps the panel visible while working in the other apps.
+    PictureInPicture,
+}
+
+/// A window to show a panel floated out of the [`StoryWorkspace`], the panel will be docked back when closed.
+///
+/// The panel is docked back by the button in the title bar, by closing the window,
+/// or by dragging the window by its title bar onto a workspace window.
+///
+/// gpui keeps the drag of a tab in the window where it started, so the window itself is dragged,
+/// and it's docked when it stops moving over another workspace window than the one it was over,
+/// see [`DOCK_DELAY`].
+pub struct FloatingWindow {
+    app_state: Arc<AppState>,
+    /// The workspace window that the panel is floated from, to dock back to.
+    origin: WindowHandle<Root>,
+    dock_area: View<DockArea>,
+    title: SharedString,
+    mode: FloatingMode,
+    /// The last bounds of the window, to tell the moves from the resizes.
+    bounds: Bounds<Pixels>,
+    /// The workspace window under the title bar when the window stopped moving the last time,
+    /// it's not docked into the same window, e.g.: the window is opened above the workspace.
+    settled_over: Option<AnyWindowHandle>,
+    _dock_task: Option<Task<()>>,
+}
+
+impl FloatingWindow {
+    /// Open the panel from the state in a new window.
+    pub fn open(
+        app_state: Arc<AppState>,
+        origin: WindowHandle<Root>,
+        state: PanelState,
+        mode: FloatingMode,
+        cx: &mut AppContext,
+    ) -> Task<anyhow::Result<WindowHandle<Root>>> {
+        let (window_bounds, kind) = match mode {
+            FloatingMode::Window => (
+                Bounds::centered(None, size(px(800.), px(600.)), cx),
+                WindowKind::Normal,
+            ),
+            // The pop up windows are kept above the other windows, even of the other apps.
+            FloatingMode::PictureInPicture => (Self::pip_bounds(cx), WindowKind::PopUp),
+        };
+
+        cx.spawn(|mut cx| async move {
+            let options = WindowOptions {
+                window_bounds: Some(WindowBounds::Windowed(window_bounds)),
+                titlebar: Some(TitlebarOptions {
+                    title: None,
+                    appears_transparent: true,
+                    traffic_light_position: Some(point(px(9.0), px(9.0))),
+                }),
+                window_min_size: Some(gpui::Size {
+                    width: px(320.),
+                    height: px(240.),
+                }),
+                kind,
+                ..Default::default()
+            };
+
+            let window = cx.open_window(options, |cx| {
+                let view = cx.new_view(|cx| Self::new(app_state.clone(), origin, state, mode, cx));
+                cx.new_view(|cx| Root::new(view.into(), cx))
+            })?;
+
+            // Track it as the main windows, so the app keeps running with the floating panels
+            // after the last workspace window is closed.
+            app_state.add_window(window, AppWindowKind::Floating);
+            window.update(&mut cx, |_, cx| {
+                cx.activate_window();
+                cx.on_release(move |_, window, cx| {
+                    if app_state.remove_window(window) == 0 {
+                        cx.quit();
+                    }
+                })
+                .detach();
+            })?;
+            Ok(window)
+        })
+    }
+
+    /// The bounds at the bottom right of the primary display, or the center if there is no display.
+    fn pip_bounds(cx: &AppContext) -> Bounds<Pixels> {
+        let Some(display) = cx.primary_display() else {
+            return Bounds::centered(None, PIP_SIZE, cx);
+        };
+
+        let display_bounds = display.bounds();
+        let origin = point(
+            display_bounds.origin.x + display_bounds.size.width - PIP_SIZE.width - PIP_MARGIN,
+            display_bounds.origin.y + display_bounds.size.height - PIP_SIZE.height - PIP_MARGIN,
+        );
+        Bounds::new(origin, PIP_SIZE)
+    }
+
+    fn new(
+        app_state: Arc<AppState>,
+        origin: WindowHandle<Root>,
+        state: PanelState,
+        mode: FloatingMode,
+        cx: &mut ViewContext<Self>,
+    ) -> Self {
+        let stack_panel = cx.new_view(|cx| StackPanel::new(Axis::Horizontal, cx));
+        let dock_area = cx.new_view(|cx| DockArea::new("floating-dock", stack_panel, cx));
+
+        // Restore the panel into a single TabPanel.
+        let layout = DockAreaState {
+            version: None,
+            center: PanelState {
+                panel_name: "StackPanel".into(),
+                children: vec![PanelState {
+                    panel_name: "TabPanel".into(),
+                    children: vec![state],
+                    info: PanelInfo::Tabs { active_index: 0 },
+                }],
+                info: PanelInfo::Stack {
+                    sizes: vec![],
+                    axis: StackAxis::Horizontal,
+                    min_sizes: vec![],
+                    max_sizes: vec![],
+                },
+            },
+            floating: vec![],
+        };
+        dock_area.update(cx, |dock_area, cx| dock_area.load(&layout, cx));
+
+        let title = dock_area
+            .read(cx)
+            .tab_panels(cx)
+            .first()
+            .and_then(|tab_panel| tab_panel.read(cx).active_panel())
+            .map(|panel| panel.title(cx))
+            .unwrap_or_else(|| t!("Workspace.title").into());
+        cx.set_window_title(&title);
+
+        Settings::apply_rem_size(cx);
+        cx.observe_global::<Settings>(|_, cx| Settings::apply_rem_size(cx))
+            .detach();
+        cx.observe_global::<Theme>(|_, cx| Settings::apply_rem_size(cx))
+            .detach();
+
+        // Dock back the panels when the window is closed by the system, to avoid losing them.
+        let view = cx.view().downgrade();
+        cx.on_window_should_close(move |cx| {
+            view.update(cx, |this, cx| this.dock_back(cx))
+                .unwrap_or(true)
+        });
+
+        cx.observe_window_bounds(|this, cx| this.on_bounds_changed(cx))
+            .detach();
+
+        let mut this = Self {
+            app_state,
+            origin,
+            dock_area,
+            title,
+            mode,
+            bounds: cx.bounds(),
+            settled_over: None,
+            _dock_task: None,
+        };
+        this.settled_over = this.workspace_window_below(cx).map(Into::into);
+        this
+    }
+
+    /// The states of the panels in the window, to add them to a workspace.
+    fn panel_states(&self, cx: &AppContext) -> Vec<PanelState> {
+        self.dock_area
+            .read(cx)
+            .tab_panels(cx)
+            .iter()
+            .flat_map(|tab_panel| tab_panel.read(cx).panels().to_vec())
+            .map(|panel| panel.dump(cx))
+            .collect()
+    }
+
+    /// Add the panels to the workspace of the window, returns `false` if it is not a workspace window or closed.
+    fn dock_into(window: WindowHandle<Root>, states: &[PanelState], cx: &mut AppContext) -> bool {
+        let docked = window.update(cx, |root, cx| {
+            let Ok(workspace) = root.view().clone().downcast::<StoryWorkspace>() else {
+                return false;
+            };
+
+            workspace.update(cx, |workspace, cx| {
+                for state in states {
+                    workspace.add_panel_from_state(state, cx);
+                }
+            });
+            cx.activate_window();
+            true
+        });
+
+        matches!(docked, Ok(true))
+    }
+
+    /// Move the panels back to the origin workspace, or the first workspace if the origin has been closed.
+    ///
+    /// If all the workspace windows have been closed, a new one is opened to dock the panels into,
+    /// returns `false` in this case, the floating window is removed after the panels are docked.
+    fn dock_back(&mut self, cx: &mut ViewContext<Self>) -> bool {
+        let states = self.panel_states(cx);
+        let windows = Some(self.origin)
+            .into_iter()
+            .chain(self.app_state.windows())
+            .collect::<Vec<_>>();
+        if windows
+            .into_iter()
+            .any(|window| Self::dock_into(window, &states, cx))
+        {
+            return true;
+        }
+
+        let new_window = StoryWorkspace::new_local(self.app_state.clone(), cx);
+        cx.spawn(|this, mut cx| async move {
+            match new_window.await {
+                Ok(window) => {
+                    let _ = cx.update(|cx| Self::dock_into(window, &states, cx));
+                    let _ = this.update(&mut cx, |_, cx| cx.remove_window());
+                }
+                Err(err) => log::error!("Failed to open a workspace window to dock back: {}", err),
+            }
+        })
+        .detach();
+        false
+    }
+
+    /// Dock the panels into the workspace window under the title bar, when the window stops moving.
+    fn on_bounds_changed(&mut self, cx: &mut ViewContext<Self>) {
+        let bounds = cx.bounds();
+        let moved = bounds.size == self.bounds.size && bounds.origin != self.bounds.origin;
+        self.bounds = bounds;
+        if !moved {
+            return;
+        }
+
+        // Replaced by the next move, so it only runs after the last one.
+        self._dock_task = Some(cx.spawn(|this, mut cx| async move {
+            cx.background_executor().timer(DOCK_DELAY).await;
+            let _ = this.update(&mut cx, |this, cx| this.dock_onto_window_below(cx));
+        }));
+    }
+
+    fn dock_onto_window_below(&mut self, cx: &mut ViewContext<Self>) {
+        let target = self.workspace_window_below(cx);
+        let settled_over = std::mem::replace(&mut self.settled_over, target.map(Into::into));
+        let Some(window) = target.filter(|window| Some((*window).into()) != settled_over) else {
+            return;
+        };
+
+        if Self::dock_into(window, &self.panel_states(cx), cx) {
+            cx.remove_window();
+        }
+    }
+
+    /// The workspace window under the center of the title bar.
+    fn workspace_window_below(&self, cx: &mut ViewContext<Self>) -> Option<WindowHandle<Root>> {
+        let bounds = cx.bounds();
+        let position = point(
+            bounds.center().x,
+            bounds.origin.y + TitleBar::height(cx) / 2.,
+        );
+        self.app_state.windows().into_iter().find(|window| {
+            window
+                .update(cx, |_, cx| cx.bounds().contains(&position))
+                .unwrap_or(false)
+        })
+    }
+
+    /// Use the opposite theme mode of the global theme in this window, to compare with the main window.
+    fn toggle_window_theme(&mut self, cx: &mut ViewContext<Self>) {
+        let theme = if Theme::window_theme(cx).is_some() {
+            None
+        } else {
+            let mode = match Theme::get_global(cx).mode {
+                ThemeMode::Light => ThemeMode::Dark,
+                ThemeMode::Dark => ThemeMode::Light,
+            };
+            Some(Theme::for_mode(mode))
+        };
+        Theme::set_window_theme(theme, cx);
+    }
+
+    fn on_action_dock_back(&mut self, _: &DockBack, cx: &mut ViewContext<Self>) {
+        if self.dock_back(cx) {
+            cx.remove_window();
+        }
+    }
+}
+
+impl Render for FloatingWindow {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let notification_view = Root::read(cx).notification.clone();
+        let is_pip = self.mode == FloatingMode::PictureInPicture;
+
+        div()
+            .relative()
+            .size_full()
+            .flex()
+            .flex_col()
+            .on_action(cx.listener(Self::on_action_dock_back))
+            .bg(cx.theme().tokens.surface)
+            .text_color(cx.theme().tokens.on_surface)
+            .child(
+                TitleBar::new("floating-title", Box::new(DockBack))
+                    .child(div().flex().items_center().child(self.title.clone()))
+                    .child(
+                        div()
+                            .flex()
+                            .items_center()
+                            .justify_end()
+                            .px_2()
+                            .gap_2()
+                            .when(!is_pip, |this| {
+                                this.child(
+                                    Button::new("window-theme", cx)
+                                        .icon(match cx.theme().mode {
+                                            ThemeMode::Light => IconName::Moon,
+                                            ThemeMode::Dark => IconName::Sun,
+                                        })
+                                        .small()
+                                        .ghost()
+                                        .selected(Theme::window_theme(cx).is_some())
+                                        .tooltip(t!("Workspace.Toggle Window Theme"))
+                                        .on_click(
+                                            cx.listener(|this, _, cx| this.toggle_window_theme(cx)),
+                                        ),
+                                )
+                            })
+                            .child(
+                                Button::new("dock-back", cx)
+                                    .icon(IconName::PanelLeft)
+                                    .small()
+                                    .ghost()
+                                    .tooltip(t!("Workspace.Dock Back"))
+                                    .on_click(cx.listener(|this, _, cx| {
+                                        this.on_action_dock_back(&DockBack, cx)
+                                    })),
+                            ),
+                    ),
+            )
+            .child(self.dock_area.clone())
+            .children(Root::render_drawer_layer(cx))
+            .children(Root::render_modal_layer(cx))
+            .child(div().absolute().top_8().child(notification_view))
+    }
+}
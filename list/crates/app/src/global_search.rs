@@ -0,0 +1,216 @@
+use gpui::{
+    div, prelude::FluentBuilder as _, px, AppContext, FocusHandle, FocusableView, IntoElement,
+    ParentElement as _, Render, SharedString, Styled as _, Task, View, ViewContext,
+    VisualContext as _, WeakView, WindowContext,
+};
+use story::{StoryContainer, StoryEntry};
+use ui::{
+    accessibility::Accessible as _,
+    fuzzy::fuzzy_match,
+    h_flex,
+    list::{List, ListDelegate, ListItem},
+    theme::ActiveTheme as _,
+    v_flex, ContextModal as _, Icon, IconName,
+};
+
+use crate::{app_state::AppState, story_workspace::StoryWorkspace};
+
+/// The max number of the matched items, to keep the results short for a large story.
+const MAX_ITEM_RESULTS: usize = 100;
+
+enum SearchResult {
+    /// The story matched by the name or the description.
+    Story(StoryEntry),
+    /// An item in a searchable story, the story is opened if it's closed.
+    Item {
+        container: View<StoryContainer>,
+        story_name: SharedString,
+        label: SharedString,
+        ix: usize,
+    },
+}
+
+struct GlobalSearchDelegate {
+    workspace: WeakView<StoryWorkspace>,
+    /// The opened stories and the closed searchable stories to search the items in.
+    containers: Vec<View<StoryContainer>>,
+    results: Vec<SearchResult>,
+}
+
+impl GlobalSearchDelegate {
+    fn search(&mut self, query: &str, cx: &AppContext) {
+        let mut stories = AppState::stories(cx)
+            .into_iter()
+            .filter_map(|story| {
+                let score = fuzzy_match(query, &story.name)
+                    .or_else(|| fuzzy_match(query, &story.description))?
+                    .score;
+                Some((score, SearchResult::Story(story)))
+            })
+            .collect::<Vec<_>>();
+        stories.sort_by(|a, b| b.0.cmp(&a.0));
+        self.results = stories.into_iter().map(|(_, result)| result).collect();
+
+        if query.is_empty() {
+            return;
+        }
+
+        let mut items = vec![];
+        for container in &self.containers {
+            let story_name = container.read(cx).name();
+            for (ix, label) in container.read(cx).search_items(cx).into_iter().enumerate() {
+                if let Some(m) = fuzzy_match(query, &label) {
+                    items.push((
+                        m.score,
+                        SearchResult::Item {
+                            container: container.clone(),
+                            story_name: story_name.clone(),
+                            label,
+                            ix,
+                        },
+                    ));
+                }
+            }
+        }
+        items.sort_by(|a, b| b.0.cmp(&a.0));
+        self.results.extend(
+            items
+                .into_iter()
+                .take(MAX_ITEM_RESULTS)
+                .map(|(_, result)| result),
+        );
+    }
+}
+
+impl ListDelegate for GlobalSearchDelegate {
+    type Item = ListItem;
+
+    fn items_count(&self) -> usize {
+        self.results.len()
+    }
+
+    fn perform_search(&mut self, query: &str, cx: &mut ViewContext<List<Self>>) -> Task<()> {
+        self.search(query, cx);
+        Task::Ready(Some(()))
+    }
+
+    fn render_item(&self, ix: usize, cx: &mut ViewContext<List<Self>>) -> Option<Self::Item> {
+        let (icon, label, detail): (_, SharedString, SharedString) = match self.results.get(ix)? {
+            SearchResult::Story(story) => (
+                IconName::Inbox,
+                story.name.clone(),
+                story.description.clone(),
+            ),
+            SearchResult::Item {
+                story_name, label, ..
+            } => (IconName::ChevronRight, label.clone(), story_name.clone()),
+        };
+
+        Some(
+            ListItem::new(("search-result", ix))
+                .accessible_label(label.clone())
+                .child(
+                    h_flex()
+                        .w_full()
+                        .gap_2()
+                        .overflow_x_hidden()
+                        .child(Icon::new(icon).text_color(cx.theme().tokens.on_surface_muted))
+                        .child(v_flex().child(label).when(!detail.is_empty(), |this| {
+                            this.child(
+                                div()
+                                    .text_xs()
+                                    .text_color(cx.theme().tokens.on_surface_muted)
+                                    .child(detail),
+                            )
+                        })),
+                ),
+        )
+    }
+
+    fn set_selected_index(&mut self, _: Option<usize>, _: &mut ViewContext<List<Self>>) {}
+
+    fn confirm(&mut self, ix: Option<usize>, cx: &mut ViewContext<List<Self>>) {
+        let Some(result) = ix.and_then(|ix| self.results.get(ix)) else {
+            return;
+        };
+
+        cx.close_modal();
+        match result {
+            SearchResult::Story(story) => {
+                let story = story.clone();
+                let _ = self.workspace.update(cx, |workspace, cx| {
+                    workspace.open_story(story, cx);
+                });
+            }
+            SearchResult::Item { container, ix, .. } => {
+                let _ = self.workspace.update(cx, |workspace, cx| {
+                    workspace.open_story_container(container.clone(), cx);
+                });
+                container.update(cx, |container, cx| container.reveal_item(*ix, cx));
+            }
+        }
+    }
+
+    fn cancel(&mut self, cx: &mut ViewContext<List<Self>>) {
+        cx.close_modal();
+    }
+}
+
+/// Search the stories and the items in the searchable stories, then jump to the matched one.
+pub struct GlobalSearch {
+    list: View<List<GlobalSearchDelegate>>,
+}
+
+impl GlobalSearch {
+    /// Open the search in a Modal, the items are searched in the `containers`.
+    pub fn open(
+        workspace: WeakView<StoryWorkspace>,
+        containers: Vec<View<StoryContainer>>,
+        cx: &mut WindowContext,
+    ) {
+        let view = cx.new_view(|cx| Self::new(workspace, containers, cx));
+        cx.open_modal({
+            let view = view.clone();
+            move |modal, _| {
+                modal
+                    .accessible_label("Search")
+                    .width(px(560.))
+                    .child(view.clone())
+            }
+        });
+        view.focus_handle(cx).focus(cx);
+    }
+
+    fn new(
+        workspace: WeakView<StoryWorkspace>,
+        containers: Vec<View<StoryContainer>>,
+        cx: &mut ViewContext<Self>,
+    ) -> Self {
+        let mut delegate = GlobalSearchDelegate {
+            workspace,
+            containers,
+            results: vec![],
+        };
+        delegate.search("", cx);
+
+        let list = cx.new_view(|cx| {
+            let mut list = List::new(delegate, cx).max_h(px(400.));
+            list.set_selected_index(Some(0), cx);
+            list
+        });
+
+        Self { list }
+    }
+}
+
+impl FocusableView for GlobalSearch {
+    fn focus_handle(&self, cx: &AppContext) -> FocusHandle {
+        self.list.focus_handle(cx)
+    }
+}
+
+impl Render for GlobalSearch {
+    fn render(&mut self, _: &mut ViewContext<Self>) -> impl IntoElement {
+        div().w_full().child(self.list.clone())
+    }
+}
@@ -0,0 +1,163 @@
+use std::collections::BTreeMap;
+
+use gpui::{
+    div, px, AppContext, FocusHandle, FocusableView, InteractiveElement as _, IntoElement,
+    ParentElement as _, Render, SharedString, StatefulInteractiveElement as _, Styled as _,
+    ViewContext, VisualContext as _, WindowContext,
+};
+use ui::{
+    accessibility::Accessible as _, h_flex, theme::ActiveTheme as _, v_flex, ContextModal as _,
+    StyledExt as _,
+};
+
+use crate::command_palette::humanize_action_name;
+
+/// The title of the group by the namespace of the actions.
+fn category_title(namespace: &str) -> SharedString {
+    match namespace {
+        "workspace" => "Workspace".into(),
+        "dock" => "Tabs & Panels".into(),
+        "theme" => "Theme".into(),
+        "story" => "Stories".into(),
+        "main_menu" => "Application".into(),
+        namespace => {
+            let mut chars = namespace.chars();
+            chars
+                .next()
+                .map_or(String::new(), |c| c.to_uppercase().chain(chars).collect())
+                .into()
+        }
+    }
+}
+
+/// A group of the key bindings, by the namespace of the actions.
+struct Category {
+    title: SharedString,
+    /// The label of the action and the keystrokes bound to it.
+    shortcuts: Vec<(SharedString, Vec<SharedString>)>,
+}
+
+/// A cheat sheet of all the key bindings registered in the keymap, grouped by category.
+pub struct KeyboardShortcuts {
+    focus_handle: FocusHandle,
+    categories: Vec<Category>,
+}
+
+impl KeyboardShortcuts {
+    /// Open the cheat sheet in a Modal.
+    pub fn open(cx: &mut WindowContext) {
+        let view = cx.new_view(Self::new);
+        cx.open_modal({
+            let view = view.clone();
+            move |modal, _| {
+                modal
+                    .title("Keyboard Shortcuts")
+                    .accessible_label("Keyboard Shortcuts")
+                    .width(px(720.))
+                    .child(view.clone())
+            }
+        });
+        view.focus_handle(cx).focus(cx);
+    }
+
+    fn new(cx: &mut ViewContext<Self>) -> Self {
+        Self {
+            focus_handle: cx.focus_handle(),
+            categories: Self::categories(cx),
+        }
+    }
+
+    /// Collect all the key bindings of the keymap, grouped by the namespace of the actions.
+    fn categories(cx: &AppContext) -> Vec<Category> {
+        let mut groups: BTreeMap<String, BTreeMap<String, Vec<SharedString>>> = BTreeMap::new();
+
+        let keymap = cx.key_bindings();
+        for binding in keymap.borrow().bindings() {
+            let name = binding.action().name();
+            let (namespace, action) = name.split_once("::").unwrap_or(("", name));
+            let keystrokes: SharedString = binding
+                .keystrokes()
+                .iter()
+                .map(|keystroke| keystroke.to_string())
+                .collect::<Vec<_>>()
+                .join(" ")
+                .into();
+
+            let keys = groups
+                .entry(namespace.to_string())
+                .or_default()
+                .entry(humanize_action_name(action))
+                .or_default();
+            if !keys.contains(&keystrokes) {
+                keys.push(keystrokes);
+            }
+        }
+
+        groups
+            .into_iter()
+            .map(|(namespace, shortcuts)| Category {
+                title: category_title(&namespace),
+                shortcuts: shortcuts
+                    .into_iter()
+                    .map(|(label, keys)| (label.into(), keys))
+                    .collect(),
+            })
+            .collect()
+    }
+
+    fn render_category(category: &Category, cx: &WindowContext) -> impl IntoElement {
+        v_flex()
+            .w(px(320.))
+            .gap_1()
+            .child(
+                div()
+                    .pb_1()
+                    .text_sm()
+                    .font_semibold()
+                    .border_b_1()
+                    .border_color(cx.theme().tokens.outline)
+                    .child(category.title.clone()),
+            )
+            .children(category.shortcuts.iter().map(|(label, keys)| {
+                h_flex()
+                    .gap_2()
+                    .justify_between()
+                    .text_sm()
+                    .child(label.clone())
+                    .child(h_flex().gap_1().children(keys.iter().map(|keys| {
+                        div()
+                            .px_1()
+                            .rounded_md()
+                            .border_1()
+                            .border_color(cx.theme().tokens.outline)
+                            .bg(cx.theme().tokens.surface_muted)
+                            .text_xs()
+                            .text_color(cx.theme().tokens.on_surface_muted)
+                            .child(keys.clone())
+                    })))
+            }))
+    }
+}
+
+impl FocusableView for KeyboardShortcuts {
+    fn focus_handle(&self, _: &AppContext) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for KeyboardShortcuts {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        div()
+            .id("keyboard-shortcuts")
+            .track_focus(&self.focus_handle)
+            .max_h(px(480.))
+            .overflow_y_scroll()
+            .child(
+                h_flex().flex_wrap().items_start().gap_6().children(
+                    self.categories
+                        .iter()
+                        .map(|category| Self::render_category(category, cx)),
+                ),
+            )
+    }
+}
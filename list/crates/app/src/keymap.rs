@@ -0,0 +1,113 @@
+use std::collections::BTreeMap;
+
+use anyhow::{anyhow, Result};
+use gpui::{AppContext, KeyBinding};
+use serde::Deserialize;
+
+use crate::paths;
+
+#[cfg(target_os = "macos")]
+const DEFAULT_KEYMAP: &str = include_str!("../keymaps/default-macos.json");
+#[cfg(not(target_os = "macos"))]
+const DEFAULT_KEYMAP: &str = include_str!("../keymaps/default-linux.json");
+
+/// The keymap file, a list of sections, e.g.:
+///
+/// ```json
+/// [
+///   {
+///     "context": "TabPanel",
+///     "bindings": {
+///       "ctrl-tab": "dock::NextTab",
+///       "cmd-1": ["workspace::OpenStory", "Buttons"]
+///     }
+///   }
+/// ]
+/// ```
+#[derive(Debug, Default, Deserialize)]
+#[serde(transparent)]
+pub struct Keymap(Vec<KeymapSection>);
+
+#[derive(Debug, Default, Deserialize)]
+pub struct KeymapSection {
+    /// The key context of the bindings, `None` to bind globally.
+    #[serde(default)]
+    pub context: Option<String>,
+    /// The keystrokes to the action name, or the action name with the data.
+    #[serde(default)]
+    pub bindings: BTreeMap<String, KeymapAction>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum KeymapAction {
+    Name(String),
+    WithData(String, serde_json::Value),
+}
+
+impl Keymap {
+    pub fn parse(json: &str) -> Result<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    /// Build the key bindings, the invalid bindings will be logged and skipped.
+    pub fn bindings(&self, cx: &AppContext) -> Vec<KeyBinding> {
+        let mut bindings = Vec::new();
+
+        for section in &self.0 {
+            for (keystrokes, action) in &section.bindings {
+                match Self::build_binding(keystrokes, action, section.context.as_deref(), cx) {
+                    Ok(binding) => bindings.push(binding),
+                    Err(err) => log::error!("Invalid key binding `{}`: {}", keystrokes, err),
+                }
+            }
+        }
+
+        bindings
+    }
+
+    fn build_binding(
+        keystrokes: &str,
+        action: &KeymapAction,
+        context: Option<&str>,
+        cx: &AppContext,
+    ) -> Result<KeyBinding> {
+        let (name, data) = match action {
+            KeymapAction::Name(name) => (name, None),
+            KeymapAction::WithData(name, data) => (name, Some(data.clone())),
+        };
+
+        let action = cx
+            .build_action(name, data)
+            .map_err(|err| anyhow!("unknown action `{}`, {}", name, err))?;
+        KeyBinding::load(keystrokes, action, context)
+    }
+}
+
+/// Bind the keys of the default keymap, and then the user keymap to override them.
+pub fn init(cx: &mut AppContext) {
+    match Keymap::parse(DEFAULT_KEYMAP) {
+        Ok(keymap) => {
+            let bindings = keymap.bindings(cx);
+            cx.bind_keys(bindings);
+        }
+        Err(err) => log::error!("Failed to parse the default keymap: {}", err),
+    }
+
+    let json = match std::fs::read_to_string(paths::keymap_file()) {
+        Ok(json) => json,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return,
+        Err(err) => {
+            log::error!("Failed to read the user keymap: {}", err);
+            return;
+        }
+    };
+
+    match Keymap::parse(&json) {
+        Ok(keymap) => {
+            let bindings = keymap.bindings(cx);
+            cx.bind_keys(bindings);
+        }
+        Err(err) => log::error!("Failed to parse the user keymap: {}", err),
+    }
+}
@@ -0,0 +1,49 @@
+use std::{sync::Arc, time::SystemTime};
+
+use log::{LevelFilter, Log, Metadata, Record};
+use story::{LogBuffer, LogRecord};
+
+/// The max level of the records captured, can be changed by the `RUST_LOG` environment variable,
+/// e.g.: `RUST_LOG=debug`.
+const DEFAULT_LEVEL: LevelFilter = LevelFilter::Info;
+
+/// Write the records into the [`LogBuffer`] of the [`crate::app_state::AppState`] for the Logs story,
+/// and to the stderr.
+struct Logger {
+    buffer: Arc<LogBuffer>,
+}
+
+impl Log for Logger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= log::max_level()
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+
+        let message = record.args().to_string();
+        eprintln!("[{}] {}: {}", record.level(), record.target(), message);
+        self.buffer.push(LogRecord {
+            time: SystemTime::now(),
+            level: record.level(),
+            target: record.target().to_string().into(),
+            message: message.into(),
+        });
+    }
+
+    fn flush(&self) {}
+}
+
+/// Install the logger of the app, the records are kept in the `buffer`.
+pub fn init(buffer: Arc<LogBuffer>) {
+    let level = std::env::var("RUST_LOG")
+        .ok()
+        .and_then(|level| level.parse().ok())
+        .unwrap_or(DEFAULT_LEVEL);
+
+    if log::set_boxed_logger(Box::new(Logger { buffer })).is_ok() {
+        log::set_max_level(level);
+    }
+}
@@ -3,25 +3,46 @@ use std::sync::Arc;
 use anyhow::Result;
 use app_state::AppState;
 use assets::Assets;
-use gpui::{actions, App, AppContext, KeyBinding, Menu, MenuItem};
-use ui::input::{Copy, Cut, Paste, Redo, Undo};
+use gpui::{actions, App, AppContext};
 
+mod action_recorder;
+mod app_menus;
 mod app_state;
 mod assets;
+mod command_palette;
+mod file_drop;
+mod floating_window;
+mod global_search;
+mod keyboard_shortcuts;
+mod keymap;
+mod logger;
+mod panel_capture;
+mod paths;
+mod recovery;
+mod settings;
+mod settings_view;
+mod status_items;
 mod story_workspace;
+mod theme_watcher;
+mod undo_stack;
+mod window_state;
+
+rust_i18n::i18n!("locales", fallback = "en");
 
 actions!(main_menu, [Quit]);
 
 fn init(app_state: Arc<AppState>, cx: &mut AppContext) -> Result<()> {
     story_workspace::init(app_state.clone(), cx);
-
-    cx.bind_keys([KeyBinding::new("cmd-q", Quit, None)]);
+    keymap::init(cx);
+    action_recorder::init(cx);
+    app_menus::init(cx);
 
     Ok(())
 }
 
 fn main() {
-    let app_state = Arc::new(AppState {});
+    let app_state = Arc::new(AppState::new());
+    logger::init(app_state.log_buffer());
 
     let app = App::new().with_assets(Assets);
 
@@ -35,23 +56,6 @@ fn main() {
 
         cx.on_action(quit);
 
-        cx.set_menus(vec![
-            Menu {
-                name: "GPUI App".into(),
-                items: vec![MenuItem::action("Quit", Quit)],
-            },
-            Menu {
-                name: "Edit".into(),
-                items: vec![
-                    MenuItem::os_action("Undo", Undo, gpui::OsAction::Undo),
-                    MenuItem::os_action("Redo", Redo, gpui::OsAction::Redo),
-                    MenuItem::separator(),
-                    MenuItem::os_action("Cut", Cut, gpui::OsAction::Cut),
-                    MenuItem::os_action("Copy", Copy, gpui::OsAction::Copy),
-                    MenuItem::os_action("Paste", Paste, gpui::OsAction::Paste),
-                ],
-            },
-        ]);
         cx.activate(true);
 
         story_workspace::open_new(app_state.clone(), cx, |_workspace, _cx| {
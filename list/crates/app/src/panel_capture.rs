@@ -0,0 +1,109 @@
+use std::{fmt, path::Path, process::Command};
+
+use gpui::{Bounds, Pixels};
+use rust_i18n::t;
+
+/// The screen can be captured on this platform, by the screenshot tools of macOS and Linux.
+///
+/// Windows has no screenshot tool to run from the command line, so the capture is disabled there.
+pub const SUPPORTED: bool = cfg!(any(target_os = "macos", target_os = "linux"));
+
+/// The screenshot tools to install on Linux, shown when none of them can capture the screen.
+const LINUX_TOOLS: &str = "grim (Wayland), ImageMagick (X11)";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CaptureError {
+    /// The screen can't be captured on this platform, see [`SUPPORTED`].
+    Unsupported,
+    /// The panel has not been laid out.
+    EmptyArea,
+    /// None of the screenshot tools has captured the screen, e.g.: not installed.
+    NoTool,
+}
+
+impl fmt::Display for CaptureError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            Self::Unsupported => t!("Capture.Unsupported"),
+            Self::EmptyArea => t!("Capture.Empty Area"),
+            Self::NoTool if cfg!(target_os = "linux") => t!("Capture.No Tool", tools = LINUX_TOOLS),
+            Self::NoTool => t!("Capture.Failed"),
+        };
+        write!(f, "{}", message)
+    }
+}
+
+impl std::error::Error for CaptureError {}
+
+/// Capture the area of the screen to a PNG file, by the screenshot tool of the system.
+///
+/// gpui can't render the elements into an image, so the area is captured from the screen,
+/// the window should be activated before, or the windows above it are captured.
+/// The `bounds` are in the screen coordinates, and the `scale_factor` converts them to the device pixels.
+pub fn capture_screen(
+    bounds: Bounds<Pixels>,
+    scale_factor: f32,
+    path: &Path,
+) -> Result<(), CaptureError> {
+    if !SUPPORTED {
+        return Err(CaptureError::Unsupported);
+    }
+    if bounds.size.width.0 < 1. || bounds.size.height.0 < 1. {
+        return Err(CaptureError::EmptyArea);
+    }
+
+    for mut command in capture_commands(bounds, scale_factor, path) {
+        let program = command.get_program().to_string_lossy().to_string();
+        match command.status() {
+            Ok(status) if status.success() && path.exists() => return Ok(()),
+            Ok(status) => log::warn!("`{}` failed to capture the screen: {}", program, status),
+            // Try the next one if the tool is not installed.
+            Err(err) => log::warn!("Failed to run `{}`: {}", program, err),
+        }
+    }
+
+    Err(CaptureError::NoTool)
+}
+
+/// The screenshot commands to try in order.
+#[cfg(target_os = "macos")]
+fn capture_commands(bounds: Bounds<Pixels>, _scale_factor: f32, path: &Path) -> Vec<Command> {
+    // `screencapture` takes the points, not the device pixels.
+    let mut command = Command::new("screencapture");
+    command
+        .arg("-x")
+        .arg(format!(
+            "-R{},{},{},{}",
+            bounds.origin.x.0, bounds.origin.y.0, bounds.size.width.0, bounds.size.height.0
+        ))
+        .arg(path);
+    vec![command]
+}
+
+#[cfg(target_os = "linux")]
+fn capture_commands(bounds: Bounds<Pixels>, scale_factor: f32, path: &Path) -> Vec<Command> {
+    let x = (bounds.origin.x.0 * scale_factor).round() as i32;
+    let y = (bounds.origin.y.0 * scale_factor).round() as i32;
+    let width = (bounds.size.width.0 * scale_factor).round() as i32;
+    let height = (bounds.size.height.0 * scale_factor).round() as i32;
+
+    // Wayland
+    let mut grim = Command::new("grim");
+    grim.arg("-g")
+        .arg(format!("{},{} {}x{}", x, y, width, height))
+        .arg(path);
+
+    // X11, by ImageMagick
+    let mut import = Command::new("import");
+    import
+        .args(["-window", "root", "-crop"])
+        .arg(format!("{}x{}+{}+{}", width, height, x, y))
+        .arg(path);
+
+    vec![grim, import]
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "linux")))]
+fn capture_commands(_bounds: Bounds<Pixels>, _scale_factor: f32, _path: &Path) -> Vec<Command> {
+    vec![]
+}
@@ -0,0 +1,66 @@
+use std::path::PathBuf;
+
+use crate::settings::DEFAULT_SESSION;
+
+/// Returns the directory to store the app data, e.g.: `~/Library/Application Support/gpui-app`.
+pub fn data_dir() -> PathBuf {
+    dirs::data_dir()
+        .unwrap_or_else(std::env::temp_dir)
+        .join("gpui-app")
+}
+
+/// Returns the path of the saved dock layout.
+pub fn layout_file() -> PathBuf {
+    data_dir().join("layout.json")
+}
+
+/// Returns the path of the saved dock layout of the session.
+pub fn session_layout_file(session: &str) -> PathBuf {
+    if session == DEFAULT_SESSION {
+        layout_file()
+    } else {
+        data_dir()
+            .join("sessions")
+            .join(format!("{}.json", session))
+    }
+}
+
+/// Returns the path of the saved window position and size.
+pub fn window_state_file() -> PathBuf {
+    data_dir().join("window_state.json")
+}
+
+/// Returns the path of the workspace snapshot, to recover the workspace after an unclean shutdown.
+pub fn recovery_file() -> PathBuf {
+    data_dir().join("recovery.json")
+}
+
+/// Returns the path of the lock file, it exists while the app is running.
+pub fn session_lock_file() -> PathBuf {
+    data_dir().join("session.lock")
+}
+
+/// Returns the path of the recorded keystrokes and clicks, to be replayed.
+pub fn recording_file() -> PathBuf {
+    data_dir().join("recording.json")
+}
+
+/// Returns the path of the user settings.
+pub fn settings_file() -> PathBuf {
+    data_dir().join("settings.json")
+}
+
+/// Returns the path of the user keymap, the bindings in it override the default keymap.
+pub fn keymap_file() -> PathBuf {
+    data_dir().join("keymap.json")
+}
+
+/// Returns the path of the notes written in the Notes story.
+pub fn notes_file() -> PathBuf {
+    data_dir().join("notes.md")
+}
+
+/// Returns the path of the theme file, it's applied to the app once changed.
+pub fn theme_file() -> PathBuf {
+    data_dir().join("theme.json")
+}
@@ -0,0 +1,96 @@
+use std::{path::Path, time::Duration};
+
+use gpui::{AppContext, Global};
+use serde::{Deserialize, Serialize};
+use ui::dock::DockAreaState;
+
+use crate::paths;
+
+/// The interval to snapshot the workspace to the [`paths::recovery_file`].
+pub const SNAPSHOT_INTERVAL: Duration = Duration::from_secs(30);
+
+/// The state of the workspace snapshotted periodically, to be restored after an unclean shutdown.
+///
+/// Persisted to the [`paths::recovery_file`], and removed when the app quits normally.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecoverySnapshot {
+    /// The layout session of the workspace.
+    pub session: String,
+    /// The layout with the open tabs.
+    pub layout: DockAreaState,
+    /// The text of the notes scratchpad, `None` if it has been saved.
+    #[serde(default)]
+    pub notes: Option<String>,
+}
+
+impl RecoverySnapshot {
+    pub fn load() -> anyhow::Result<Self> {
+        let json = std::fs::read_to_string(paths::recovery_file())?;
+        Ok(serde_json::from_str(&json)?)
+    }
+
+    pub fn save(&self) -> anyhow::Result<()> {
+        std::fs::create_dir_all(paths::data_dir())?;
+        std::fs::write(paths::recovery_file(), serde_json::to_string(self)?)?;
+        Ok(())
+    }
+}
+
+/// The snapshot of the last run that was not shut down cleanly, to be offered by the first workspace.
+struct Recovery(Option<RecoverySnapshot>);
+
+impl Global for Recovery {}
+
+/// Create the [`paths::session_lock_file`] for this run, it is removed when the app quits normally.
+///
+/// If the lock file already exists, the last run has crashed or been killed,
+/// the snapshot of it is kept to be restored by [`take_snapshot`].
+pub fn init(cx: &mut AppContext) {
+    let lock_file = paths::session_lock_file();
+    let snapshot = if lock_file.exists() {
+        match RecoverySnapshot::load() {
+            Ok(snapshot) => Some(snapshot),
+            Err(err) => {
+                log::info!("No snapshot to recover the unclean shutdown: {}", err);
+                None
+            }
+        }
+    } else {
+        None
+    };
+    cx.set_global(Recovery(snapshot));
+
+    let result = std::fs::create_dir_all(paths::data_dir())
+        .and_then(|_| std::fs::write(&lock_file, std::process::id().to_string()));
+    if let Err(err) = result {
+        log::error!("Failed to create the session lock file: {}", err);
+    }
+
+    cx.on_app_quit(|_| {
+        remove_file(&paths::session_lock_file());
+        remove_file(&paths::recovery_file());
+        async {}
+    })
+    .detach();
+}
+
+/// Remove the snapshot of the last unclean shutdown from the disk, the user has chosen not to restore it.
+///
+/// The snapshot of this run will be written again by the next interval.
+pub fn discard_snapshot() {
+    remove_file(&paths::recovery_file());
+}
+
+fn remove_file(path: &Path) {
+    if let Err(err) = std::fs::remove_file(path) {
+        if err.kind() != std::io::ErrorKind::NotFound {
+            log::error!("Failed to remove {}: {}", path.display(), err);
+        }
+    }
+}
+
+/// Take the snapshot of the last unclean shutdown, it can only be taken once.
+pub fn take_snapshot(cx: &mut AppContext) -> Option<RecoverySnapshot> {
+    cx.try_global::<Recovery>()?;
+    cx.global_mut::<Recovery>().0.take()
+}
@@ -0,0 +1,230 @@
+use std::{collections::HashMap, time::Duration};
+
+use gpui::{AppContext, Global, Hsla, Task, WindowContext};
+use serde::{Deserialize, Serialize};
+use ui::{
+    animation,
+    color_picker::RecentColors,
+    table::{TableLayout, TableLayouts},
+    theme::{ActiveTheme as _, Theme, ThemeModeSetting},
+    ColorExt as _,
+};
+
+use crate::{paths, story_workspace};
+
+/// The locales that the app has been translated to, with the display names.
+pub const LOCALES: [(&str, &str); 3] = [
+    ("en", "English"),
+    ("zh-CN", "简体中文"),
+    ("zh-HK", "繁體中文"),
+];
+
+/// The layout session that always exists, its layout is saved to the [`paths::layout_file`].
+pub const DEFAULT_SESSION: &str = "Default";
+
+/// The delay to save the settings after the last change, e.g.: dragging a slider changes them on every move.
+const SAVE_DELAY: Duration = Duration::from_millis(500);
+
+/// The user preferences of the app, persisted to the [`paths::settings_file`].
+///
+/// It's a gpui global instead of a field of the [`crate::app_state::AppState`],
+/// so the windows can use `cx.observe_global::<Settings>` to react to the changes.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    pub theme_mode: ThemeModeSetting,
+    pub locale: String,
+    /// The base font size in pixels, used as the rem size of the windows.
+    pub font_size: f32,
+    /// The [`Theme::scale`] of the UI, changed by the zoom actions.
+    pub ui_scale: f32,
+    /// Show the left panel when the workspace is opened.
+    pub show_left_panel: bool,
+    /// Show the right panel when the workspace is opened.
+    pub show_right_panel: bool,
+    /// Keep the panels in the proportions of the window when it is resized,
+    /// otherwise only the center panel is resized.
+    pub proportional_panels: bool,
+    /// Open and close the drawers and modals without the animations.
+    pub reduce_motion: bool,
+    /// The hex strings of the [`RecentColors`] of the color pickers.
+    pub recent_colors: Vec<String>,
+    /// The [`TableLayouts`] of the tables, by the layout id of the table.
+    pub table_layouts: HashMap<String, TableLayout>,
+    /// The names of the layout sessions, each session saves its own layout.
+    pub sessions: Vec<String>,
+    /// The active layout session, to be restored on the next launch.
+    pub session: String,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            theme_mode: ThemeModeSetting::default(),
+            locale: "en".into(),
+            font_size: 16.,
+            ui_scale: 1.,
+            show_left_panel: true,
+            show_right_panel: true,
+            proportional_panels: true,
+            reduce_motion: false,
+            recent_colors: Vec::new(),
+            table_layouts: HashMap::new(),
+            sessions: vec![DEFAULT_SESSION.into()],
+            session: DEFAULT_SESSION.into(),
+        }
+    }
+}
+
+impl Global for Settings {}
+
+/// The pending save of the [`Settings`], it's replaced by the next change.
+struct PendingSave(Option<Task<()>>);
+
+impl Global for PendingSave {}
+
+impl Settings {
+    pub const MIN_FONT_SIZE: f32 = 12.;
+    pub const MAX_FONT_SIZE: f32 = 20.;
+
+    /// Load the settings from the file, must be called after the [`Theme`] has been initialized.
+    pub fn init(cx: &mut AppContext) {
+        let mut settings = match Self::load() {
+            Ok(settings) => settings,
+            Err(err) => {
+                log::info!("Failed to load settings, use the default settings: {}", err);
+                Self::default()
+            }
+        };
+        // The file may have been edited by hand.
+        settings.clamp();
+
+        Theme::set_mode_setting(settings.theme_mode, cx);
+        Theme::set_scale(settings.ui_scale, cx);
+        ui::set_locale(&settings.locale);
+        animation::set_reduce_motion(settings.reduce_motion, cx);
+        cx.set_global(RecentColors(
+            settings
+                .recent_colors
+                .iter()
+                .filter_map(|hex| Hsla::parse_hex_string(hex).ok())
+                .collect(),
+        ));
+        cx.set_global(TableLayouts(settings.table_layouts.clone()));
+        cx.set_global(settings);
+        cx.set_global(PendingSave(None));
+
+        // Save the last changes that are still waiting for the delay.
+        cx.on_app_quit(|cx| {
+            if cx.global_mut::<PendingSave>().0.take().is_some() {
+                Self::save_now(cx);
+            }
+            async {}
+        })
+        .detach();
+
+        cx.observe_global::<RecentColors>(|cx| {
+            let recent_colors = cx
+                .global::<RecentColors>()
+                .0
+                .iter()
+                .map(|color| color.to_hex_string())
+                .collect();
+            Self::update(cx, |settings| settings.recent_colors = recent_colors);
+        })
+        .detach();
+
+        cx.observe_global::<TableLayouts>(|cx| {
+            let table_layouts = cx.global::<TableLayouts>().0.clone();
+            Self::update(cx, |settings| settings.table_layouts = table_layouts);
+        })
+        .detach();
+
+        // Save the scale changed by the zoom actions.
+        cx.observe_global::<Theme>(|cx| {
+            let scale = cx.global::<Theme>().scale;
+            Self::update(cx, |settings| settings.ui_scale = scale);
+        })
+        .detach();
+    }
+
+    pub fn get(cx: &AppContext) -> &Self {
+        cx.global::<Self>()
+    }
+
+    /// Update the settings and save them to the file after the [`SAVE_DELAY`],
+    /// the app-wide changes (theme mode, locale, motion) are applied here.
+    pub fn update(cx: &mut AppContext, f: impl FnOnce(&mut Self)) {
+        let old = Self::get(cx).clone();
+        let mut settings = old.clone();
+        f(&mut settings);
+        settings.clamp();
+        if settings == old {
+            return;
+        }
+
+        if settings.theme_mode != old.theme_mode {
+            Theme::set_mode_setting(settings.theme_mode, cx);
+        }
+        if settings.ui_scale != old.ui_scale {
+            Theme::set_scale(settings.ui_scale, cx);
+        }
+        if settings.locale != old.locale {
+            ui::set_locale(&settings.locale);
+            story_workspace::update_window_titles(cx);
+        }
+        if settings.reduce_motion != old.reduce_motion {
+            animation::set_reduce_motion(settings.reduce_motion, cx);
+        }
+
+        cx.set_global(settings);
+        Self::schedule_save(cx);
+        // Refresh all windows to render with the new settings.
+        cx.refresh();
+    }
+
+    /// Clamp the sizes to their ranges, e.g.: [`Settings::MIN_FONT_SIZE`] to [`Settings::MAX_FONT_SIZE`].
+    fn clamp(&mut self) {
+        self.font_size = self
+            .font_size
+            .clamp(Self::MIN_FONT_SIZE, Self::MAX_FONT_SIZE);
+        self.ui_scale = self.ui_scale.clamp(Theme::MIN_SCALE, Theme::MAX_SCALE);
+    }
+
+    /// Apply the [`Settings::font_size`] scaled by the [`Theme::scale`] to the rem size of the window,
+    /// call it when the window is opened and when the settings or the theme are changed.
+    pub fn apply_rem_size(cx: &mut WindowContext) {
+        let font_size = Self::get(cx).font_size;
+        let rem_size = cx.theme().rem_size(font_size);
+        if cx.rem_size() != rem_size {
+            cx.set_rem_size(rem_size);
+            cx.refresh();
+        }
+    }
+
+    /// Save the settings after the [`SAVE_DELAY`], the pending save is replaced by the new one.
+    fn schedule_save(cx: &mut AppContext) {
+        let task = cx.spawn(|mut cx| async move {
+            cx.background_executor().timer(SAVE_DELAY).await;
+            let _ = cx.update(Self::save_now);
+        });
+        cx.set_global(PendingSave(Some(task)));
+    }
+
+    fn save_now(cx: &mut AppContext) {
+        if let Err(err) = Self::get(cx).save() {
+            log::error!("Failed to save settings: {}", err);
+        }
+    }
+
+    fn load() -> anyhow::Result<Self> {
+        let json = std::fs::read_to_string(paths::settings_file())?;
+        Ok(serde_json::from_str(&json)?)
+    }
+
+    fn save(&self) -> anyhow::Result<()> {
+        std::fs::create_dir_all(paths::data_dir())?;
+        std::fs::write(paths::settings_file(), serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+}
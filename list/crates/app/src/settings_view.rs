@@ -0,0 +1,229 @@
+use gpui::{
+    div, px, Div, FocusHandle, FocusableView, IntoElement, ParentElement as _, Render,
+    SharedString, Styled as _, Subscription, View, ViewContext, VisualContext as _, WindowContext,
+};
+use rust_i18n::t;
+use ui::{
+    accessibility::Accessible as _,
+    h_flex,
+    label::Label,
+    radio::Radio,
+    slider::{Slider, SliderEvent},
+    switch::Switch,
+    theme::{ActiveTheme as _, Theme, ThemeModeSetting},
+    v_flex, ContextModal as _,
+};
+
+use crate::settings::{Settings, LOCALES};
+
+/// The view to edit the [`Settings`], the changes are applied and saved immediately.
+pub struct SettingsView {
+    focus_handle: FocusHandle,
+    font_size_slider: View<Slider>,
+    /// The [`Settings::ui_scale`] in percent.
+    scale_slider: View<Slider>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl SettingsView {
+    /// Open the settings in a Modal.
+    pub fn open(cx: &mut WindowContext) {
+        let view = cx.new_view(Self::new);
+        cx.open_modal({
+            let view = view.clone();
+            move |modal, _| {
+                modal
+                    .title(t!("Settings.title").to_string())
+                    .accessible_label(t!("Settings.title"))
+                    .width(px(480.))
+                    .child(view.clone())
+            }
+        });
+        view.focus_handle(cx).focus(cx);
+    }
+
+    fn new(cx: &mut ViewContext<Self>) -> Self {
+        let font_size = Settings::get(cx).font_size;
+        let font_size_slider = cx.new_view(|_| {
+            Slider::horizontal()
+                .min(Settings::MIN_FONT_SIZE)
+                .max(Settings::MAX_FONT_SIZE)
+                .step(1.)
+                .default_value(font_size)
+        });
+        let scale = Settings::get(cx).ui_scale;
+        let scale_slider = cx.new_view(|_| {
+            Slider::horizontal()
+                .min(Theme::MIN_SCALE * 100.)
+                .max(Theme::MAX_SCALE * 100.)
+                .step(Theme::SCALE_STEP * 100.)
+                .default_value(scale * 100.)
+        });
+
+        let _subscriptions = vec![
+            cx.subscribe(
+                &font_size_slider,
+                |_, _, event: &SliderEvent, cx| match event {
+                    SliderEvent::Change(value) => {
+                        let value = *value;
+                        Settings::update(cx, |settings| settings.font_size = value)
+                    }
+                },
+            ),
+            cx.subscribe(&scale_slider, |_, _, event: &SliderEvent, cx| match event {
+                SliderEvent::Change(value) => {
+                    let value = *value / 100.;
+                    Settings::update(cx, |settings| settings.ui_scale = value)
+                }
+            }),
+            // Sync the slider with the zoom actions.
+            cx.observe_global::<Settings>(|this: &mut Self, cx| {
+                let scale = Settings::get(cx).ui_scale;
+                this.scale_slider
+                    .update(cx, |slider, cx| slider.set_value(scale * 100., cx));
+                cx.notify()
+            }),
+        ];
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            font_size_slider,
+            scale_slider,
+            _subscriptions,
+        }
+    }
+}
+
+fn section(title: impl Into<SharedString>) -> Div {
+    v_flex().gap_2().child(
+        Label::new(title)
+            .text_sm()
+            .font_weight(gpui::FontWeight::SEMIBOLD),
+    )
+}
+
+impl FocusableView for SettingsView {
+    fn focus_handle(&self, _: &gpui::AppContext) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for SettingsView {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let settings = Settings::get(cx).clone();
+        let theme_modes = [
+            (ThemeModeSetting::Light, t!("Workspace.Light")),
+            (ThemeModeSetting::Dark, t!("Workspace.Dark")),
+            (ThemeModeSetting::System, t!("Workspace.Follow System")),
+            (
+                ThemeModeSetting::HighContrastLight,
+                t!("Workspace.High Contrast Light"),
+            ),
+            (
+                ThemeModeSetting::HighContrastDark,
+                t!("Workspace.High Contrast Dark"),
+            ),
+        ];
+
+        v_flex()
+            .gap_5()
+            .child(
+                section(t!("Workspace.Theme Mode").to_string()).child(
+                    h_flex()
+                        .flex_wrap()
+                        .gap_4()
+                        .children(theme_modes.into_iter().map(|(mode, label)| {
+                            Radio::new(SharedString::from(format!("theme-mode-{:?}", mode)))
+                                .label(label.to_string())
+                                .checked(settings.theme_mode == mode)
+                                .on_click(move |_, cx| {
+                                    Settings::update(cx, |settings| settings.theme_mode = mode)
+                                })
+                        })),
+                ),
+            )
+            .child(
+                section(t!("Workspace.Language").to_string()).child(h_flex().gap_4().children(
+                    LOCALES.into_iter().map(|(locale, name)| {
+                        Radio::new(SharedString::from(format!("locale-{}", locale)))
+                            .label(name)
+                            .checked(settings.locale == locale)
+                            .on_click(move |_, cx| {
+                                Settings::update(cx, |settings| settings.locale = locale.into())
+                            })
+                    }),
+                )),
+            )
+            .child(
+                section(t!("Settings.Font Size").to_string()).child(
+                    h_flex()
+                        .gap_3()
+                        .child(div().flex_1().child(self.font_size_slider.clone()))
+                        .child(
+                            div()
+                                .w_12()
+                                .text_sm()
+                                .text_color(cx.theme().tokens.on_surface_muted)
+                                .child(format!("{}px", settings.font_size)),
+                        ),
+                ),
+            )
+            .child(
+                section(t!("Settings.UI Scale").to_string()).child(
+                    h_flex()
+                        .gap_3()
+                        .child(div().flex_1().child(self.scale_slider.clone()))
+                        .child(
+                            div()
+                                .w_12()
+                                .text_sm()
+                                .text_color(cx.theme().tokens.on_surface_muted)
+                                .child(format!("{:.0}%", settings.ui_scale * 100.)),
+                        ),
+                ),
+            )
+            .child(
+                section(t!("Settings.Panels").to_string())
+                    .child(
+                        Switch::new("show-left-panel")
+                            .label(t!("Settings.Show Left Panel").to_string())
+                            .checked(settings.show_left_panel)
+                            .on_click(|checked, cx| {
+                                let checked = *checked;
+                                Settings::update(cx, |settings| settings.show_left_panel = checked)
+                            }),
+                    )
+                    .child(
+                        Switch::new("show-right-panel")
+                            .label(t!("Settings.Show Right Panel").to_string())
+                            .checked(settings.show_right_panel)
+                            .on_click(|checked, cx| {
+                                let checked = *checked;
+                                Settings::update(cx, |settings| settings.show_right_panel = checked)
+                            }),
+                    )
+                    .child(
+                        Switch::new("proportional-panels")
+                            .label(t!("Settings.Proportional Panels").to_string())
+                            .checked(settings.proportional_panels)
+                            .on_click(|checked, cx| {
+                                let checked = *checked;
+                                Settings::update(cx, |settings| {
+                                    settings.proportional_panels = checked
+                                })
+                            }),
+                    ),
+            )
+            .child(
+                section(t!("Settings.Accessibility").to_string()).child(
+                    Switch::new("reduce-motion")
+                        .label(t!("Settings.Reduce Motion").to_string())
+                        .checked(settings.reduce_motion)
+                        .on_click(|checked, cx| {
+                            let checked = *checked;
+                            Settings::update(cx, |settings| settings.reduce_motion = checked)
+                        }),
+                ),
+            )
+    }
+}
@@ -0,0 +1,292 @@
+use std::{sync::Arc, time::Duration};
+
+use gpui::{
+    div, prelude::FluentBuilder as _, px, relative, AnchorCorner, IntoElement, Model,
+    ParentElement as _, Render, Styled as _, Subscription, Task, ViewContext, VisualContext as _,
+};
+use rust_i18n::t;
+use story::{ListStory, StoryContainer};
+use ui::{
+    button::Button,
+    divider::Divider,
+    dock::PanelView,
+    h_flex,
+    notification::NotificationHistory,
+    popover::{Popover, PopoverContent},
+    progress::Progress,
+    task_manager::TaskManager,
+    theme::{ActiveTheme as _, Theme, ThemeModeSetting},
+    v_flex, ContextModal as _, IconName, Sizable as _, StyledExt as _,
+};
+use workspace::StatusItemView;
+
+use crate::{app_state::AppState, story_workspace::OpenSettings};
+
+/// Shows the title of the active panel, and the items count if it is a list.
+pub struct ActivePanelItem {
+    panel: Option<Arc<dyn PanelView>>,
+    items_count: Option<usize>,
+    _subscription: Option<Subscription>,
+}
+
+impl ActivePanelItem {
+    pub fn new(_cx: &mut ViewContext<Self>) -> Self {
+        Self {
+            panel: None,
+            items_count: None,
+            _subscription: None,
+        }
+    }
+}
+
+impl StatusItemView for ActivePanelItem {
+    fn set_active_panel(&mut self, panel: Option<Arc<dyn PanelView>>, cx: &mut ViewContext<Self>) {
+        let list_story = panel
+            .as_ref()
+            .and_then(|panel| panel.view().downcast::<StoryContainer>().ok())
+            .and_then(|container| container.read(cx).story_view())
+            .and_then(|story| story.downcast::<ListStory>().ok());
+
+        self.items_count = list_story
+            .as_ref()
+            .map(|list| list.read(cx).items_count(cx));
+        self._subscription = list_story.map(|list| {
+            cx.observe(&list, |this, list, cx| {
+                this.items_count = Some(list.read(cx).items_count(cx));
+                cx.notify();
+            })
+        });
+        self.panel = panel;
+        cx.notify();
+    }
+}
+
+impl Render for ActivePanelItem {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let Some(panel) = self.panel.as_ref() else {
+            return div();
+        };
+
+        div()
+            .child(panel.title(cx))
+            .when_some(self.items_count, |this, count| {
+                this.child(format!(" · {}", t!("StatusBar.items", count = count)))
+            })
+    }
+}
+
+/// Shows the theme mode, click to open the settings.
+pub struct ThemeModeItem;
+
+impl StatusItemView for ThemeModeItem {}
+
+impl Render for ThemeModeItem {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let label = match Theme::mode_setting(cx) {
+            ThemeModeSetting::Light => t!("Workspace.Light"),
+            ThemeModeSetting::Dark => t!("Workspace.Dark"),
+            ThemeModeSetting::System => t!("Workspace.Follow System"),
+            ThemeModeSetting::HighContrastLight => t!("Workspace.High Contrast Light"),
+            ThemeModeSetting::HighContrastDark => t!("Workspace.High Contrast Dark"),
+        };
+
+        Button::new("status-theme-mode", cx)
+            .xsmall()
+            .ghost()
+            .map(|this| {
+                if cx.theme().mode.is_dark() {
+                    this.icon(IconName::Moon)
+                } else {
+                    this.icon(IconName::Sun)
+                }
+            })
+            .label(label)
+            .on_click(|_, cx| cx.dispatch_action(Box::new(OpenSettings)))
+    }
+}
+
+/// Shows the unread notifications count, click to open the notification history.
+pub struct NotificationsItem;
+
+impl StatusItemView for NotificationsItem {}
+
+impl Render for NotificationsItem {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let count = cx.unread_notifications_count();
+
+        div()
+            .relative()
+            .child(
+                Button::new("status-notifications", cx)
+                    .xsmall()
+                    .ghost()
+                    .compact()
+                    .icon(IconName::Bell)
+                    .tooltip(t!("Workspace.Notifications"))
+                    .on_click(|_, cx| {
+                        cx.mark_notifications_read();
+                        cx.open_drawer(|drawer, _| {
+                            drawer
+                                .title(t!("Workspace.Notifications").to_string())
+                                .child(NotificationHistory::new())
+                        });
+                    }),
+            )
+            .when(count > 0, |this| {
+                this.child(
+                    h_flex()
+                        .absolute()
+                        .rounded_full()
+                        .top(px(-2.))
+                        .right(px(-2.))
+                        .p(px(1.))
+                        .min_w(px(12.))
+                        .bg(ui::red_500())
+                        .text_color(ui::white())
+                        .justify_center()
+                        .text_size(px(10.))
+                        .line_height(relative(1.))
+                        .child(format!("{}", count.min(99))),
+                )
+            })
+    }
+}
+
+/// The interval to refresh the progress of the background tasks.
+const TASKS_REFRESH_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Shows the running background tasks count, click to open the tasks to cancel them.
+pub struct BackgroundTasksItem {
+    task_manager: Model<TaskManager>,
+    _subscription: Subscription,
+}
+
+impl BackgroundTasksItem {
+    pub fn new(cx: &mut ViewContext<Self>) -> Self {
+        let task_manager = AppState::task_manager(cx);
+        let _subscription = cx.observe(&task_manager, |_, _, cx| cx.notify());
+
+        Self {
+            task_manager,
+            _subscription,
+        }
+    }
+}
+
+impl StatusItemView for BackgroundTasksItem {}
+
+impl Render for BackgroundTasksItem {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let count = self.task_manager.read(cx).tasks().len();
+        let task_manager = self.task_manager.clone();
+
+        Popover::new("status-background-tasks")
+            .anchor(AnchorCorner::BottomRight)
+            .trigger(
+                Button::new("status-background-tasks", cx)
+                    .xsmall()
+                    .ghost()
+                    .compact()
+                    .icon(IconName::LoaderCircle)
+                    .when(count > 0, |this| this.label(count.to_string()))
+                    .tooltip(t!("StatusBar.Background Tasks")),
+            )
+            .content(move |cx| {
+                let list = cx.new_view(|cx| BackgroundTasksList::new(task_manager.clone(), cx));
+                cx.new_view(|cx| PopoverContent::new(cx, move |_| list.clone().into_any_element()))
+            })
+    }
+}
+
+/// The running tasks in the popover of the [`BackgroundTasksItem`], with the progress and a cancel button.
+struct BackgroundTasksList {
+    task_manager: Model<TaskManager>,
+    _subscription: Subscription,
+    _refresh_task: Task<()>,
+}
+
+impl BackgroundTasksList {
+    fn new(task_manager: Model<TaskManager>, cx: &mut ViewContext<Self>) -> Self {
+        let _subscription = cx.observe(&task_manager, |_, _, cx| cx.notify());
+
+        // The progress and the elapsed time are changed without notifying.
+        let _refresh_task = cx.spawn(|this, mut cx| async move {
+            loop {
+                cx.background_executor().timer(TASKS_REFRESH_INTERVAL).await;
+                if this.update(&mut cx, |_, cx| cx.notify()).is_err() {
+                    return;
+                }
+            }
+        });
+
+        Self {
+            task_manager,
+            _subscription,
+            _refresh_task,
+        }
+    }
+}
+
+impl Render for BackgroundTasksList {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let tasks = self
+            .task_manager
+            .read(cx)
+            .tasks()
+            .iter()
+            .map(|task| (task.id, task.name.clone(), task.elapsed(), task.progress()))
+            .collect::<Vec<_>>();
+
+        v_flex()
+            .w(px(320.))
+            .gap_2()
+            .child(
+                div()
+                    .text_sm()
+                    .font_semibold()
+                    .child(t!("StatusBar.Background Tasks").to_string()),
+            )
+            .child(Divider::horizontal())
+            .when(tasks.is_empty(), |this| {
+                this.child(
+                    div()
+                        .text_sm()
+                        .text_color(cx.theme().tokens.on_surface_muted)
+                        .child(t!("StatusBar.No background tasks").to_string()),
+                )
+            })
+            .children(tasks.into_iter().map(|(id, name, elapsed, progress)| {
+                let task_manager = self.task_manager.clone();
+
+                v_flex()
+                    .gap_1()
+                    .child(
+                        h_flex()
+                            .gap_2()
+                            .child(div().flex_1().text_sm().child(name))
+                            .child(
+                                div()
+                                    .text_xs()
+                                    .text_color(cx.theme().tokens.on_surface_muted)
+                                    .child(format!("{}s", elapsed.as_secs())),
+                            )
+                            .child(
+                                Button::new(("cancel-task", id), cx)
+                                    .xsmall()
+                                    .ghost()
+                                    .icon(IconName::Close)
+                                    .tooltip(t!("Workspace.Cancel"))
+                                    .on_click(move |_, cx| {
+                                        task_manager.update(cx, |task_manager, cx| {
+                                            task_manager.cancel(id, cx);
+                                        });
+                                    }),
+                            ),
+                    )
+                    .child(match progress {
+                        Some(value) => Progress::new().value(value),
+                        None => Progress::new().indeterminate(true),
+                    })
+            }))
+    }
+}
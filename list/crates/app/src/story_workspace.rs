@@ -2,77 +2,708 @@ use gpui::*;
 use prelude::FluentBuilder as _;
 use private::serde::Deserialize;
 use story::{
-    ButtonStory, CalendarStory, DropdownStory, IconStory, ImageStory, InputStory, ListStory,
-    ModalStory, PopupStory, ProgressStory, ResizableStory, ScrollableStory, StoryContainer,
-    SwitchStory, TableStory, TextStory, TooltipStory,
+    AvatarStory, ButtonStory, CalendarStory, ChartsStory, CsvTableStory, DetailStory, DockPosition,
+    DrawerStory, DropdownStory, EventBus, FormStory, GroupedListStory, IconStory, ImageStory,
+    InputStory, InspectorStory, KanbanStory, ListStory, LogsStory, MetricsStory, ModalStory,
+    NotesStory, PagedListStory, PopoverStory, PopupStory, ProfilerStory, ProgressStory,
+    ResizableStory, RichTableStory, ScrollableStory, SetupFinished, SetupWizardStory, StoryBuild,
+    StoryContainer, StoryEntry, StoryState, SwitchStory, TableStory, TagStory, TextStory,
+    ThemeContrastStory, ThemeEditorStory, TooltipStory, TreeStory, UnsavedStory as _,
 };
-use workspace::TitleBar;
+use workspace::{ActivityBar, ActivityItem, StatusBar, TitleBar};
 
-use std::sync::Arc;
+use rust_i18n::t;
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::Duration,
+};
 use ui::{
+    accessibility::Accessible as _,
+    breadcrumb::{Breadcrumb, BreadcrumbItem},
     button::Button,
     color_picker::{ColorPicker, ColorPickerEvent},
-    dock::{DockArea, StackPanel, TabPanel},
-    drawer::Drawer,
+    dock::{
+        register_panel, DockArea, DockAreaState, DockEvent, PanelSize, PanelState, StackPanel,
+        TabPanel,
+    },
+    drag_drop::{DragDropExt as _, DropIndicator, DropTarget},
+    dropdown::{Dropdown, DropdownEvent, DropdownItem},
+    file_dialog::{self, OpenOptions, SaveOptions},
     h_flex,
-    modal::Modal,
+    input::{InputEvent, TextInput},
+    notification::Notification,
     popup_menu::PopupMenuExt,
-    theme::{ActiveTheme, Colorize as _, Theme},
-    ContextModal, IconName, Placement, Root, Sizable,
+    theme::{ActiveTheme, SeedPalette, Theme, ThemeModeSetting},
+    v_flex, ColorExt as _, ContextModal, IconName, Placement, Root, Selectable as _, Sizable,
+};
+
+use crate::{
+    action_recorder, app_menus,
+    app_state::{AppState, AppWindowKind},
+    command_palette::CommandPalette,
+    file_drop::DroppedFile,
+    floating_window::{FloatingMode, FloatingWindow},
+    global_search::GlobalSearch,
+    keyboard_shortcuts::KeyboardShortcuts,
+    panel_capture, paths,
+    recovery::{self, RecoverySnapshot},
+    settings::{self, Settings, DEFAULT_SESSION},
+    settings_view::SettingsView,
+    status_items::{ActivePanelItem, BackgroundTasksItem, NotificationsItem, ThemeModeItem},
+    theme_watcher,
+    undo_stack::{UndoEntry, UndoStack},
+    window_state::WindowState,
 };
 
-use crate::app_state::AppState;
+/// Open the story by the name, or activate it if it's already opened.
+#[derive(Clone, PartialEq, Eq, Deserialize)]
+pub struct OpenStory(SharedString);
+
+#[derive(Clone, PartialEq, Eq, Deserialize)]
+struct SelectThemeMode(ThemeModeSetting);
 
+/// Save the layout of the current session, and switch to the layout of the session by the name.
 #[derive(Clone, PartialEq, Eq, Deserialize)]
-struct SelectLocale(SharedString);
+pub struct SwitchSession(SharedString);
 
-impl_actions!(locale_switcher, [SelectLocale]);
+impl_actions!(workspace, [OpenStory, SelectThemeMode, SwitchSession]);
 
-actions!(workspace, [Open, CloseWindow]);
+actions!(
+    workspace,
+    [
+        Open,
+        CloseWindow,
+        ToggleCommandPalette,
+        ToggleKeyboardShortcuts,
+        ToggleSearch,
+        SaveTheme,
+        LoadTheme,
+        ToggleLeftPanel,
+        ToggleRightPanel,
+        TogglePanelZoom,
+        CapturePanel,
+        CloseTab,
+        ReopenClosedTab,
+        MinimizeWindow,
+        ZoomWindow,
+        OpenDocs,
+        OpenSettings,
+        UndoChange,
+        RedoChange,
+        NewSession,
+        DeleteSession
+    ]
+);
 
-pub fn init(_app_state: Arc<AppState>, cx: &mut AppContext) {
-    cx.on_action(|_action: &Open, _cx: &mut AppContext| {});
+const LAYOUT_VERSION: usize = 2;
+
+pub fn init(app_state: Arc<AppState>, cx: &mut AppContext) {
+    cx.on_action({
+        let app_state = app_state.clone();
+        move |_action: &Open, cx: &mut AppContext| {
+            open_new(app_state.clone(), cx, |_, _| {}).detach();
+        }
+    });
+    cx.on_action(|_: &OpenDocs, cx: &mut AppContext| {
+        cx.open_url("https://github.com/huacnlee/gpui-component");
+    });
 
     Theme::init(cx);
+    Settings::init(cx);
+    theme_watcher::init(cx);
+    recovery::init(cx);
+
+    for story in builtin_stories() {
+        story::register_story(story, cx);
+    }
+
+    app_state.undo_stack().record_theme(cx.theme());
+    cx.observe_global::<Theme>(move |cx| app_state.undo_stack().record_theme(cx.theme()))
+        .detach();
+    cx.on_action(|_: &UndoChange, cx: &mut AppContext| UndoStack::undo(cx));
+    cx.on_action(|_: &RedoChange, cx: &mut AppContext| UndoStack::redo(cx));
+
     ui::init(cx);
     story::init(cx);
+    file_dialog::set_default_directory(dirs::document_dir().unwrap_or_else(paths::data_dir), cx);
+
+    register_panel(cx, "StoryContainer", |_, state, cx| {
+        let state = StoryState::from_panel_state(state)?;
+        let story = AppState::find_story(&state.name, cx)?;
+        let container = story.container(state.closeable, cx);
+        if let Some(title) = state.title {
+            container.update(cx, |container, cx| {
+                container.set_custom_title(Some(title.into()), cx)
+            });
+        }
+        Some(Arc::new(container))
+    });
+}
+
+/// The built-in stories, in the order to be added into the default layout.
+fn builtin_stories() -> Vec<StoryEntry> {
+    vec![
+        StoryEntry::new(
+            "Buttons",
+            "Displays a button or a component that looks like a button.",
+            StoryBuild::view(|cx| ButtonStory::view(cx).into()),
+        )
+        .closeable(false),
+        StoryEntry::new(
+            "Input",
+            "A control that allows the user to input text.",
+            StoryBuild::view(|cx| InputStory::view(cx).into()),
+        )
+        .closeable(false),
+        StoryEntry::new(
+            "Text",
+            "Links, paragraphs, checkboxes, and more.",
+            StoryBuild::view(|cx| TextStory::view(cx).into()),
+        )
+        .split(Placement::Bottom, Some(px(200.))),
+        StoryEntry::new(
+            "Switch",
+            "A control that allows the user to toggle between two states.",
+            StoryBuild::view(|cx| SwitchStory::view(cx).into()),
+        ),
+        StoryEntry::new(
+            "Dropdowns",
+            "Displays a list of options for the user to pick from—triggered by a button.",
+            StoryBuild::view(|cx| DropdownStory::new(cx).into()),
+        ),
+        StoryEntry::new(
+            "Form",
+            "Text inputs, radio group, switch and checkbox in a form, with the change events.",
+            StoryBuild::view(|cx| FormStory::view(cx).into()),
+        ),
+        StoryEntry::new(
+            "Modal",
+            "Modal & Drawer use examples",
+            StoryBuild::view(|cx| ModalStory::view(cx).into()),
+        )
+        .icon(IconName::Bell),
+        StoryEntry::new(
+            "Drawer",
+            "A panel slides in from any edge of the window, and can be resized.",
+            StoryBuild::view(|cx| DrawerStory::view(cx).into()),
+        ),
+        StoryEntry::new(
+            "Popup",
+            "A popup displays content on top of the main page.",
+            StoryBuild::view(|cx| PopupStory::view(cx).into()),
+        ),
+        StoryEntry::new(
+            "Popover",
+            "An anchored overlay, flipped to the other side when near the window edge.",
+            StoryBuild::view(|cx| PopoverStory::view(cx).into()),
+        ),
+        StoryEntry::new(
+            "Tooltip",
+            "Displays a short message when users hover over an element.",
+            StoryBuild::view(|cx| TooltipStory::view(cx).into()),
+        )
+        .position(DockPosition::Right),
+        StoryEntry::new(
+            "List",
+            "A list displays a series of items.",
+            StoryBuild::view(|cx| ListStory::view(cx).into()),
+        )
+        .searchable::<ListStory>()
+        .icon(IconName::Menu)
+        .position(DockPosition::Left),
+        StoryEntry::new(
+            "Detail",
+            "Shows the details of the company selected in the List story, a master-detail example.",
+            StoryBuild::view(|cx| DetailStory::view(cx).into()),
+        ),
+        StoryEntry::new(
+            "PagedList",
+            "A list loads the items page by page from a data source when scrolled.",
+            StoryBuild::view(|cx| PagedListStory::view(cx).into()),
+        )
+        .icon(IconName::Inbox)
+        .position(DockPosition::Left),
+        StoryEntry::new(
+            "GroupedList",
+            "A list of the grouped items, the group headers stick to the top and can be collapsed.",
+            StoryBuild::view(|cx| GroupedListStory::view(cx).into()),
+        ),
+        StoryEntry::new(
+            "Icon",
+            "Icon use examples",
+            StoryBuild::view(|cx| IconStory::view(cx).into()),
+        )
+        .icon(IconName::Star)
+        .position(DockPosition::Left)
+        .split(Placement::Bottom, Some(px(200.))),
+        StoryEntry::new(
+            "Image",
+            "Render SVG image and Chart",
+            StoryBuild::load(ImageStory::load),
+        )
+        .position(DockPosition::Right)
+        .split(Placement::Bottom, None),
+        StoryEntry::new(
+            "Table",
+            "Powerful table and datagrids built.",
+            StoryBuild::view(|cx| TableStory::view(cx).into()),
+        ),
+        StoryEntry::new(
+            "RichTable",
+            "A table of the columns that render the badges, sparklines, buttons and progress bars in the cells.",
+            StoryBuild::view(|cx| RichTableStory::view(cx).into()),
+        ),
+        StoryEntry::new(
+            "Kanban",
+            "A kanban board of the cards that can be dragged between the columns.",
+            StoryBuild::view(|cx| KanbanStory::view(cx).into()),
+        ),
+        StoryEntry::new(
+            "Tree",
+            "A tree view displays hierarchical data with expandable items.",
+            StoryBuild::view(|cx| TreeStory::view(cx).into()),
+        )
+        .icon(IconName::FolderOpen)
+        .position(DockPosition::Left),
+        StoryEntry::new(
+            "Progress",
+            "Displays an indicator showing the completion progress of a task, typically displayed as a progress bar.",
+            StoryBuild::view(|cx| ProgressStory::view(cx).into()),
+        )
+        .split(Placement::Bottom, Some(px(200.))),
+        StoryEntry::new(
+            "Avatar",
+            "An image of the user with the initials fallback and the status, or a group of them.",
+            StoryBuild::view(|cx| AvatarStory::view(cx).into()),
+        ),
+        StoryEntry::new(
+            "Tag",
+            "Badges, tags and chips in the solid, outline or custom colors, and an input of the tags.",
+            StoryBuild::view(|cx| TagStory::view(cx).into()),
+        ),
+        StoryEntry::new(
+            "SetupWizard",
+            "A stepper and a multi-step modal that configures the theme and the locale.",
+            StoryBuild::view(|cx| SetupWizardStory::view(cx).into()),
+        ),
+        StoryEntry::new(
+            "Charts",
+            "Bar, line and pie charts drawn with the colors of the theme.",
+            StoryBuild::view(|cx| ChartsStory::view(cx).into()),
+        ),
+        StoryEntry::new(
+            "Metrics",
+            "Live metrics with the sparklines in the list items and the table cells.",
+            StoryBuild::view(|cx| MetricsStory::view(cx).into()),
+        ),
+        StoryEntry::new(
+            "Profiler",
+            "The render time of the stories, to find the slow ones.",
+            StoryBuild::view(|cx| ProfilerStory::view(cx).into()),
+        ),
+        StoryEntry::new(
+            "Resizable",
+            "Accessible resizable panel groups and layouts with keyboard support.",
+            StoryBuild::view(|cx| ResizableStory::view(cx).into()),
+        ),
+        StoryEntry::new(
+            "Scrollable",
+            "A scrollable area with scroll bar.",
+            StoryBuild::view(|cx| ScrollableStory::view(cx).into()),
+        ),
+        StoryEntry::new(
+            "Calendar",
+            "A calendar component.",
+            StoryBuild::view(|cx| CalendarStory::view(cx).into()),
+        )
+        .icon(IconName::Calendar)
+        .position(DockPosition::Right)
+        .split(Placement::Bottom, None),
+        StoryEntry::new(
+            "ThemeEditor",
+            "Edit the colors of the theme, the changes are applied immediately.",
+            StoryBuild::view(|cx| ThemeEditorStory::view(cx).into()),
+        )
+        .icon(IconName::Palette)
+        .position(DockPosition::Right),
+        StoryEntry::new(
+            "Inspector",
+            "The bounds and the style of the hovered component, with the colors resolved from the theme.",
+            StoryBuild::view(|cx| InspectorStory::view(cx).into()),
+        )
+        .position(DockPosition::Right),
+        StoryEntry::new(
+            "ThemeContrast",
+            "The palette of the theme and the WCAG contrast ratios of the text colors.",
+            StoryBuild::view(|cx| ThemeContrastStory::view(cx).into()),
+        )
+        .position(DockPosition::Right),
+        StoryEntry::new(
+            "Notes",
+            "A scratchpad to write down the notes, saved to the disk automatically.",
+            StoryBuild::view(|cx| NotesStory::view(paths::notes_file(), cx).into()),
+        )
+        .icon(IconName::File),
+        StoryEntry::new(
+            "Logs",
+            "The log records of the app, filtered by the level and the search.",
+            StoryBuild::view(|cx| {
+                let buffer = AppState::global(cx)
+                    .map(|app_state| app_state.log_buffer())
+                    .unwrap_or_default();
+                LogsStory::view(buffer, cx).into()
+            }),
+        ),
+    ]
+}
+
+/// Update the titles of the workspace windows to the current locale, e.g.: the locale is changed in the settings.
+pub(crate) fn update_window_titles(cx: &mut AppContext) {
+    let Some(app_state) = AppState::global(cx) else {
+        return;
+    };
+
+    for window in app_state.windows() {
+        let _ = window.update(cx, |root, cx| {
+            if root.view().clone().downcast::<StoryWorkspace>().is_ok() {
+                cx.set_window_title(&t!("Workspace.title"));
+            }
+        });
+    }
 }
 
 pub struct StoryWorkspace {
+    app_state: Arc<AppState>,
     dock_area: View<DockArea>,
     locale_selector: View<LocaleSelector>,
     theme_color_picker: View<ColorPicker>,
+    /// The palette generated from the picked primary color, previewed before it's applied.
+    seed_palette: Option<SeedPalette>,
+    status_bar: View<StatusBar>,
+    /// The focus handles of the title bar buttons, to be cycled by Tab / Shift-Tab.
+    title_bar_focus_handles: [FocusHandle; 4],
+    /// The `(show_left_panel, show_right_panel)` of the [`Settings`] that has been applied.
+    panel_defaults: (bool, bool),
+    /// The layout before the last change, to be recorded into the [`UndoStack`].
+    last_layout: DockAreaState,
+    /// The layout session of the window, the layout is saved to the [`paths::session_layout_file`].
+    session: SharedString,
+    _save_window_state: Task<()>,
+    _snapshot_task: Task<()>,
+    _setup_subscription: Subscription,
 }
 
 impl StoryWorkspace {
-    pub fn new(_app_state: Arc<AppState>, cx: &mut ViewContext<Self>) -> Self {
+    pub fn new(app_state: Arc<AppState>, cx: &mut ViewContext<Self>) -> Self {
         cx.observe_window_appearance(|_workspace, cx| {
             Theme::sync_system_appearance(cx);
         })
         .detach();
 
         let stack_panel = cx.new_view(|cx| StackPanel::new(Axis::Horizontal, cx));
-        let dock_area = cx.new_view(|cx| DockArea::new("main-dock", stack_panel.clone(), cx));
-        let weak_dock_area = dock_area.downgrade();
+        let dock_area = cx.new_view(|cx| {
+            let mut dock_area = DockArea::new("main-dock", stack_panel, cx).floatable(true);
+            dock_area.set_proportional(Settings::get(cx).proportional_panels, cx);
+            dock_area
+        });
+        cx.subscribe(&dock_area, |this, _, event: &DockEvent, cx| match event {
+            DockEvent::FloatPanel(state) => {
+                this.float_panel(state.clone(), FloatingMode::Window, cx)
+            }
+            DockEvent::PinPanel(state) => {
+                this.float_panel(state.clone(), FloatingMode::PictureInPicture, cx)
+            }
+            DockEvent::ActivePanelChanged => {
+                let panel = this.dock_area.read(cx).active_panel();
+                this.status_bar
+                    .update(cx, |status_bar, cx| status_bar.set_active_panel(panel, cx));
+                // Update the breadcrumb in the title bar.
+                cx.notify();
+            }
+            DockEvent::LayoutChanged => this.record_layout(cx),
+        })
+        .detach();
+
+        let session = SharedString::from(Settings::get(cx).session.clone());
+        match Self::load_layout(&session) {
+            Ok(state) => dock_area.update(cx, |dock_area, cx| dock_area.load(&state, cx)),
+            Err(err) => {
+                log::info!("Failed to load layout, use the default layout: {}", err);
+                Self::reset_default_layout(&dock_area, cx);
+            }
+        }
+
+        let view = cx.view().downgrade();
+        cx.on_window_should_close(move |cx| {
+            let _ = view.update(cx, |this, cx| this.save_layout(cx));
+            true
+        });
+        cx.on_app_quit(|this, cx| {
+            this.save_layout(cx);
+            async {}
+        })
+        .detach();
+
+        let locale_selector = cx.new_view(LocaleSelector::new);
+
+        let theme_color_picker = cx.new_view(|cx| {
+            let mut picker = ColorPicker::new("theme-color-picker", cx)
+                .xsmall()
+                .anchor(AnchorCorner::TopRight)
+                .label(t!("Workspace.Primary Color"));
+            picker.set_value(cx.theme().tokens.accent, cx);
+            picker
+        });
+        cx.subscribe(
+            &theme_color_picker,
+            |this, _, ev: &ColorPickerEvent, cx| match ev {
+                ColorPickerEvent::Change(color) => {
+                    if let Some(color) = color {
+                        this.preview_palette(*color, cx);
+                    }
+                }
+            },
+        )
+        .detach();
+
+        // Sync the picker when the theme is changed by undo or redo.
+        cx.observe_global::<Theme>(|this, cx| {
+            Settings::apply_rem_size(cx);
+            let accent = cx.theme().tokens.accent;
+            this.theme_color_picker
+                .update(cx, |picker, cx| picker.set_value(accent, cx));
+        })
+        .detach();
+
+        Settings::apply_rem_size(cx);
+        cx.observe_global::<Settings>(|this, cx| {
+            Settings::apply_rem_size(cx);
+            let settings = Settings::get(cx);
+            let panel_defaults = (settings.show_left_panel, settings.show_right_panel);
+            let proportional = settings.proportional_panels;
+            if panel_defaults != this.panel_defaults {
+                this.panel_defaults = panel_defaults;
+                this.apply_panel_defaults(cx);
+            }
+
+            if proportional != this.dock_area.read(cx).is_proportional() {
+                this.dock_area.update(cx, |dock_area, cx| {
+                    dock_area.set_proportional(proportional, cx)
+                });
+            }
+        })
+        .detach();
+
+        let status_bar = cx.new_view(|cx| {
+            let mut status_bar = StatusBar::new(cx);
+            status_bar.add_left_item(cx.new_view(ActivePanelItem::new), cx);
+            status_bar.add_right_item(cx.new_view(BackgroundTasksItem::new), cx);
+            status_bar.add_right_item(cx.new_view(|_| NotificationsItem), cx);
+            status_bar.add_right_item(cx.new_view(|_| ThemeModeItem), cx);
+            status_bar
+        });
+
+        cx.observe_window_bounds(|this, cx| this.save_window_state(cx))
+            .detach();
+
+        let _snapshot_task = cx.spawn(|this, mut cx| async move {
+            loop {
+                cx.background_executor()
+                    .timer(recovery::SNAPSHOT_INTERVAL)
+                    .await;
+                let Ok(snapshot) = this.update(&mut cx, |this, cx| this.recovery_snapshot(cx))
+                else {
+                    return;
+                };
+                let Some(snapshot) = snapshot else {
+                    continue;
+                };
+
+                let result = cx
+                    .background_executor()
+                    .spawn(async move { snapshot.save() })
+                    .await;
+                if let Err(err) = result {
+                    log::error!("Failed to save the recovery snapshot: {}", err);
+                }
+            }
+        });
+        // Offer to restore the snapshot of the last unclean shutdown, after the Root of the window is created.
+        cx.defer(|this, cx| this.offer_recovery(cx));
+
+        // Apply the choices of the setup wizard in the stories.
+        let _setup_subscription =
+            EventBus::subscribe(cx, |_: &mut Self, setup: &SetupFinished, cx| {
+                let theme_mode = setup.theme_mode;
+                let locale = setup.locale.to_string();
+                Settings::update(cx, |settings| {
+                    settings.theme_mode = theme_mode;
+                    settings.locale = locale;
+                });
+            });
+
+        let title_bar_focus_handles = [
+            cx.focus_handle(),
+            cx.focus_handle(),
+            cx.focus_handle(),
+            cx.focus_handle(),
+        ];
+
+        let settings = Settings::get(cx);
+        let last_layout = dock_area.read(cx).dump(cx);
+        let mut this = Self {
+            app_state,
+            dock_area,
+            locale_selector,
+            theme_color_picker,
+            seed_palette: None,
+            status_bar,
+            title_bar_focus_handles,
+            panel_defaults: (settings.show_left_panel, settings.show_right_panel),
+            last_layout,
+            session,
+            _save_window_state: Task::ready(()),
+            _snapshot_task,
+            _setup_subscription,
+        };
+        this.apply_panel_defaults(cx);
+        this
+    }
 
-        let center_tab_panel = cx.new_view(|cx| {
-            let stack_panel = cx.new_view(|cx| StackPanel::new(Axis::Vertical, cx));
-            TabPanel::new(Some(stack_panel), weak_dock_area.clone(), cx)
+    /// Save the window bounds to restore on the next launch, after the move or resize has settled.
+    fn save_window_state(&mut self, cx: &mut ViewContext<Self>) {
+        let state = WindowState::new(cx.window_bounds());
+        let timer = cx.background_executor().timer(Duration::from_millis(500));
+        self._save_window_state = cx.background_executor().spawn(async move {
+            timer.await;
+            if let Err(err) = state.save() {
+                log::error!("Failed to save window state: {}", err);
+            }
         });
-        let left_tab_panel = cx.new_view(|cx| {
-            let stack_panel = cx.new_view(|cx| StackPanel::new(Axis::Vertical, cx));
-            TabPanel::new(Some(stack_panel), weak_dock_area.clone(), cx)
+    }
+
+    /// Snapshot the layout and the unsaved notes to recover them after an unclean shutdown.
+    ///
+    /// Returns `None` if it is not the first window, only the first window is snapshotted.
+    fn recovery_snapshot(&self, cx: &mut ViewContext<Self>) -> Option<RecoverySnapshot> {
+        let first_window = self.app_state.windows().first().copied()?;
+        if AnyWindowHandle::from(first_window) != cx.window_handle() {
+            return None;
+        }
+
+        let mut layout = self.dock_area.read(cx).dump(cx);
+        layout.version = Some(LAYOUT_VERSION);
+        let notes = self
+            .dock_area
+            .read(cx)
+            .tab_panels(cx)
+            .iter()
+            .flat_map(|tab_panel| tab_panel.read(cx).panels().to_vec())
+            .filter_map(|panel| panel.view().downcast::<StoryContainer>().ok())
+            .filter_map(|container| container.read(cx).story_view())
+            .find_map(|story| story.downcast::<NotesStory>().ok())
+            .filter(|notes| notes.read(cx).has_unsaved_changes(cx))
+            .map(|notes| notes.read(cx).text(cx).to_string());
+
+        Some(RecoverySnapshot {
+            session: self.session.to_string(),
+            layout,
+            notes,
+        })
+    }
+
+    /// Ask to restore the workspace if the last run was not shut down cleanly.
+    fn offer_recovery(&mut self, cx: &mut ViewContext<Self>) {
+        let Some(snapshot) = recovery::take_snapshot(cx) else {
+            return;
+        };
+
+        let view = cx.view().clone();
+        cx.open_modal(move |modal, cx| {
+            modal
+                .title(t!("Workspace.Restore Workspace").to_string())
+                .accessible_label(t!("Workspace.Restore Workspace"))
+                .width(px(420.))
+                .child(t!("Workspace.Restore Workspace Message").to_string())
+                .footer(
+                    h_flex()
+                        .gap_6()
+                        .items_center()
+                        .child(
+                            Button::new("restore-workspace", cx)
+                                .primary()
+                                .label(t!("Workspace.Restore").to_string())
+                                .on_click({
+                                    let view = view.clone();
+                                    let snapshot = snapshot.clone();
+                                    move |_, cx| {
+                                        let snapshot = snapshot.clone();
+                                        view.update(cx, |this, cx| {
+                                            this.restore_snapshot(snapshot, cx)
+                                        });
+                                        cx.close_modal();
+                                    }
+                                }),
+                        )
+                        .child(
+                            Button::new("discard-recovery", cx)
+                                .label(t!("Workspace.Discard").to_string())
+                                .on_click(|_, cx| {
+                                    recovery::discard_snapshot();
+                                    cx.close_modal();
+                                }),
+                        ),
+                )
         });
+    }
+
+    /// Restore the session, the layout and the notes from the snapshot of the last unclean shutdown.
+    fn restore_snapshot(&mut self, snapshot: RecoverySnapshot, cx: &mut ViewContext<Self>) {
+        // Write the notes before loading the layout, the Notes story reads the file when it's created.
+        if let Some(notes) = &snapshot.notes {
+            if let Err(err) = std::fs::write(paths::notes_file(), notes) {
+                log::error!("Failed to restore the notes: {}", err);
+            }
+        }
+
+        self.dock_area
+            .update(cx, |dock_area, cx| dock_area.load(&snapshot.layout, cx));
+        self.last_layout = self.dock_area.read(cx).dump(cx);
+        self.session = snapshot.session.clone().into();
+        Settings::update(cx, |settings| settings.session = snapshot.session);
+        cx.notify();
+    }
 
-        let right_tab_panel = cx.new_view(|cx| {
-            let stack_panel = cx.new_view(|cx| StackPanel::new(Axis::Vertical, cx));
-            TabPanel::new(Some(stack_panel), weak_dock_area.clone(), cx)
+    /// Show or hide the side panels by the `show_left_panel` and `show_right_panel` settings.
+    fn apply_panel_defaults(&mut self, cx: &mut ViewContext<Self>) {
+        let (show_left, show_right) = self.panel_defaults;
+        self.dock_area.update(cx, |dock_area, cx| {
+            for (placement, show) in [(Placement::Left, show_left), (Placement::Right, show_right)]
+            {
+                if dock_area.is_side_panel_collapsed(placement, cx) == show {
+                    dock_area.toggle_side_panel(placement, cx);
+                }
+            }
         });
+        cx.notify();
+    }
+
+    fn reset_default_layout(dock_area: &View<DockArea>, cx: &mut WindowContext) {
+        let stack_panel = dock_area.read(cx).root();
+        let weak_dock_area = dock_area.downgrade();
+
+        let center_tab_panel = cx.new_view(|cx| TabPanel::new(None, weak_dock_area.clone(), cx));
+        let left_tab_panel = cx.new_view(|cx| TabPanel::new(None, weak_dock_area.clone(), cx));
+        let right_tab_panel = cx.new_view(|cx| TabPanel::new(None, weak_dock_area.clone(), cx));
 
         stack_panel.update(cx, |view, cx| {
             view.add_panel(
                 left_tab_panel.clone(),
-                Some(px(300.)),
+                PanelSize::new()
+                    .size(px(300.))
+                    .min_size(px(200.))
+                    .max_size(px(600.)),
                 weak_dock_area.clone(),
                 cx,
             );
@@ -80,238 +711,788 @@ impl StoryWorkspace {
             view.add_panel(center_tab_panel.clone(), None, weak_dock_area.clone(), cx);
             view.add_panel(
                 right_tab_panel.clone(),
-                Some(px(350.)),
+                PanelSize::new().size(px(350.)).min_size(px(250.)),
                 weak_dock_area.clone(),
                 cx,
             );
         });
 
-        StoryContainer::add_panel(
-            "Buttons",
-            "Displays a button or a component that looks like a button.",
-            ButtonStory::view(cx).into(),
-            center_tab_panel.clone(),
-            None,
-            None,
-            false,
-            cx,
-        );
+        for story in AppState::stories(cx) {
+            let tab_panel = match story.position {
+                DockPosition::Left => &left_tab_panel,
+                DockPosition::Center => &center_tab_panel,
+                DockPosition::Right => &right_tab_panel,
+            };
+            let (placement, size) = story.split.unzip();
 
-        StoryContainer::add_panel(
-            "Input",
-            "A control that allows the user to input text.",
-            InputStory::view(cx).into(),
-            center_tab_panel.clone(),
-            None,
-            None,
-            false,
-            cx,
-        );
+            let view = story.container(story.closeable, cx);
+            StoryContainer::add_panel(view, tab_panel.clone(), placement, size.flatten(), cx);
+        }
+    }
 
-        StoryContainer::add_panel(
-            "Text",
-            "Links, paragraphs, checkboxes, and more.",
-            TextStory::view(cx).into(),
-            center_tab_panel.clone(),
-            Some(Placement::Bottom),
-            Some(px(200.)),
-            true,
-            cx,
-        );
+    /// Activate and focus the story if it is opened, returns `false` if not found.
+    fn activate_story(&mut self, name: &str, cx: &mut ViewContext<Self>) -> bool {
+        self.dock_area.update(cx, |dock_area, cx| {
+            dock_area.activate_panel(
+                |panel, cx| {
+                    panel
+                        .view()
+                        .downcast::<StoryContainer>()
+                        .map_or(false, |view| view.read(cx).name() == name)
+                },
+                cx,
+            )
+        })
+    }
 
-        StoryContainer::add_panel(
-            "Switch",
-            "A control that allows the user to toggle between two states.",
-            SwitchStory::view(cx).into(),
-            center_tab_panel.clone(),
-            None,
-            None,
-            true,
-            cx,
-        );
+    /// Activate the story if it is opened, otherwise add it to the first TabPanel.
+    pub(crate) fn open_story(&mut self, story: StoryEntry, cx: &mut ViewContext<Self>) {
+        if self.activate_story(&story.name, cx) {
+            return;
+        }
 
-        StoryContainer::add_panel(
-            "Dropdowns",
-            "Displays a list of options for the user to pick from—triggered by a button.",
-            DropdownStory::new(cx).into(),
-            center_tab_panel.clone(),
-            None,
-            None,
-            true,
-            cx,
-        );
+        let view = story.container(true, cx);
+        self.open_story_container(view, cx);
+    }
 
-        StoryContainer::add_panel(
-            "Modal",
-            "Modal & Drawer use examples",
-            ModalStory::view(cx).into(),
-            center_tab_panel.clone(),
-            None,
-            None,
-            true,
-            cx,
-        );
+    /// Activate the story of the container if it is opened, otherwise add the container to the first TabPanel,
+    /// e.g.: the container of a closed story built by the global search.
+    pub(crate) fn open_story_container(
+        &mut self,
+        container: View<StoryContainer>,
+        cx: &mut ViewContext<Self>,
+    ) {
+        let name = container.read(cx).name();
+        if self.activate_story(&name, cx) {
+            return;
+        }
 
-        StoryContainer::add_panel(
-            "Popup",
-            "A popup displays content on top of the main page.",
-            PopupStory::view(cx).into(),
-            center_tab_panel.clone(),
-            None,
-            None,
-            true,
-            cx,
-        );
+        let Some(tab_panel) = self.dock_area.read(cx).tab_panels(cx).first().cloned() else {
+            return;
+        };
+        StoryContainer::add_panel(container, tab_panel, None, None, cx);
+    }
 
-        StoryContainer::add_panel(
-            "Tooltip",
-            "Displays a short message when users hover over an element.",
-            TooltipStory::view(cx).into(),
-            right_tab_panel.clone(),
-            None,
-            None,
-            true,
-            cx,
-        );
+    fn on_action_toggle_command_palette(
+        &mut self,
+        _: &ToggleCommandPalette,
+        cx: &mut ViewContext<Self>,
+    ) {
+        if cx.has_active_modal() {
+            cx.close_modal();
+            return;
+        }
 
-        StoryContainer::add_panel(
-            "List",
-            "A list displays a series of items.",
-            ListStory::view(cx).into(),
-            left_tab_panel.clone(),
-            None,
-            None,
-            true,
-            cx,
-        );
+        CommandPalette::open(cx.view().downgrade(), cx);
+    }
 
-        StoryContainer::add_panel(
-            "Icon",
-            "Icon use examples",
-            IconStory::view(cx).into(),
-            left_tab_panel.clone(),
-            Some(Placement::Bottom),
-            Some(px(200.)),
-            true,
-            cx,
-        );
+    fn on_action_toggle_keyboard_shortcuts(
+        &mut self,
+        _: &ToggleKeyboardShortcuts,
+        cx: &mut ViewContext<Self>,
+    ) {
+        // Keep typing `?` into the focused input.
+        if cx
+            .context_stack()
+            .iter()
+            .any(|context| context.contains("Input"))
+        {
+            cx.propagate();
+            return;
+        }
 
-        StoryContainer::add_panel(
-            "Image",
-            "Render SVG image and Chart",
-            ImageStory::view(cx).into(),
-            right_tab_panel.clone(),
-            Some(Placement::Bottom),
-            None,
-            true,
-            cx,
-        );
+        if cx.has_active_modal() {
+            cx.close_modal();
+            return;
+        }
 
-        // StoryContainer::add_panel(
-        //     WebViewStory::view(cx).into(),
-        //     stack_panel.clone(),
-        //     DockPosition::Right,
-        //     px(450.),
-        //     cx,
-        // );
+        KeyboardShortcuts::open(cx);
+    }
 
-        StoryContainer::add_panel(
-            "Table",
-            "Powerful table and datagrids built.",
-            TableStory::view(cx).into(),
-            center_tab_panel.clone(),
-            None,
-            None,
-            true,
-            cx,
-        );
+    fn on_action_toggle_search(&mut self, _: &ToggleSearch, cx: &mut ViewContext<Self>) {
+        if cx.has_active_modal() {
+            cx.close_modal();
+            return;
+        }
 
-        StoryContainer::add_panel(
-            "Progress",
-            "Displays an indicator showing the completion progress of a task, typically displayed as a progress bar.",
-            ProgressStory::view(cx).into(),
-            center_tab_panel.clone(),
-            Some(Placement::Bottom),
-            Some(px(200.)),
-            true,
-            cx,
-        );
+        // The closed searchable stories are built to search their items,
+        // one of them is added to the dock area when its item is chosen.
+        let mut containers = self.story_containers(cx);
+        let opened = containers
+            .iter()
+            .map(|container| container.read(cx).name())
+            .collect::<Vec<_>>();
+        for story in AppState::stories(cx) {
+            if story.is_searchable() && !opened.contains(&story.name) {
+                containers.push(story.container(true, cx));
+            }
+        }
+        GlobalSearch::open(cx.view().downgrade(), containers, cx);
+    }
 
-        StoryContainer::add_panel(
-            "Resizable",
-            "Accessible resizable panel groups and layouts with keyboard support.",
-            ResizableStory::view(cx).into(),
-            center_tab_panel.clone(),
-            None,
-            None,
-            true,
-            cx,
-        );
+    /// Returns all the opened stories in the dock area.
+    pub(crate) fn story_containers(&self, cx: &AppContext) -> Vec<View<StoryContainer>> {
+        self.dock_area
+            .read(cx)
+            .tab_panels(cx)
+            .iter()
+            .flat_map(|tab_panel| tab_panel.read(cx).panels().to_vec())
+            .filter_map(|panel| panel.view().downcast::<StoryContainer>().ok())
+            .collect()
+    }
 
-        StoryContainer::add_panel(
-            "Scrollable",
-            "A scrollable area with scroll bar.",
-            ScrollableStory::view(cx).into(),
-            center_tab_panel.clone(),
-            None,
-            None,
-            true,
-            cx,
-        );
+    fn on_action_open_story(&mut self, action: &OpenStory, cx: &mut ViewContext<Self>) {
+        if let Some(story) = AppState::find_story(&action.0, cx) {
+            self.open_story(story, cx);
+        }
+    }
 
-        StoryContainer::add_panel(
-            "Calendar",
-            "A calendar component.",
-            CalendarStory::view(cx).into(),
-            right_tab_panel.clone(),
-            Some(Placement::Bottom),
-            None,
-            true,
+    fn on_action_close_window(&mut self, _: &CloseWindow, cx: &mut ViewContext<Self>) {
+        // The `on_window_should_close` is not called when the window is removed by the app.
+        self.save_layout(cx);
+        cx.remove_window();
+    }
+
+    /// Returns the names of the stories that are shown in the left dock, empty if it's collapsed.
+    fn left_dock_stories(&self, cx: &AppContext) -> Vec<SharedString> {
+        let dock_area = self.dock_area.read(cx);
+        if dock_area.is_side_panel_collapsed(Placement::Left, cx) {
+            return vec![];
+        }
+
+        dock_area
+            .side_tab_panels(Placement::Left, cx)
+            .iter()
+            .filter_map(|tab_panel| tab_panel.read(cx).active_panel())
+            .filter_map(|panel| panel.view().downcast::<StoryContainer>().ok())
+            .map(|view| view.read(cx).name())
+            .collect()
+    }
+
+    /// Show the story in the left dock by the activity bar, or collapse the left dock if it's shown.
+    fn toggle_left_dock_story(&mut self, story: StoryEntry, cx: &mut ViewContext<Self>) {
+        let shown = self
+            .left_dock_stories(cx)
+            .iter()
+            .any(|name| name == &story.name);
+        let collapsed = self
+            .dock_area
+            .read(cx)
+            .is_side_panel_collapsed(Placement::Left, cx);
+        if shown || collapsed {
+            self.dock_area.update(cx, |dock_area, cx| {
+                dock_area.toggle_side_panel(Placement::Left, cx)
+            });
+        }
+
+        if !shown && !self.activate_story(&story.name, cx) {
+            let dock_area = self.dock_area.read(cx);
+            let tab_panel = dock_area
+                .side_tab_panels(Placement::Left, cx)
+                .first()
+                .or(dock_area.tab_panels(cx).first())
+                .cloned();
+            if let Some(tab_panel) = tab_panel {
+                let view = story.container(true, cx);
+                StoryContainer::add_panel(view, tab_panel, None, None, cx);
+            }
+        }
+        cx.notify();
+    }
+
+    /// The icons of the stories in the left dock, to show or hide them in the left dock.
+    fn render_activity_bar(&self, cx: &mut ViewContext<Self>) -> Option<impl IntoElement> {
+        let stories = AppState::stories(cx)
+            .into_iter()
+            .filter(|story| story.position == DockPosition::Left)
+            .collect::<Vec<_>>();
+        if stories.is_empty() {
+            return None;
+        }
+
+        let shown_stories = self.left_dock_stories(cx);
+        let panels = self
+            .dock_area
+            .read(cx)
+            .tab_panels(cx)
+            .iter()
+            .flat_map(|tab_panel| tab_panel.read(cx).panels().to_vec())
+            .collect::<Vec<_>>();
+
+        let items = stories.into_iter().map(|story| {
+            let panel = panels.iter().find(|panel| {
+                panel
+                    .view()
+                    .downcast::<StoryContainer>()
+                    .map_or(false, |view| view.read(cx).name() == story.name)
+            });
+
+            ActivityItem::new(story.name.clone(), story.icon.unwrap_or(IconName::File))
+                .tooltip(panel.map_or(story.name.clone(), |panel| panel.title(cx)))
+                .badge(panel.and_then(|panel| panel.badge(cx)))
+                .active(shown_stories.iter().any(|name| name == &story.name))
+                .on_click(
+                    cx.listener(move |this, _, cx| this.toggle_left_dock_story(story.clone(), cx)),
+                )
+        });
+
+        Some(ActivityBar::new("activity-bar").items(items.collect::<Vec<_>>()))
+    }
+
+    fn on_action_toggle_left_panel(&mut self, _: &ToggleLeftPanel, cx: &mut ViewContext<Self>) {
+        self.dock_area.update(cx, |dock_area, cx| {
+            dock_area.toggle_side_panel(Placement::Left, cx)
+        });
+        cx.notify();
+    }
+
+    fn on_action_toggle_right_panel(&mut self, _: &ToggleRightPanel, cx: &mut ViewContext<Self>) {
+        self.dock_area.update(cx, |dock_area, cx| {
+            dock_area.toggle_side_panel(Placement::Right, cx)
+        });
+        cx.notify();
+    }
+
+    fn on_action_toggle_panel_zoom(&mut self, _: &TogglePanelZoom, cx: &mut ViewContext<Self>) {
+        let Some(tab_panel) = self.dock_area.read(cx).active_tab_panel() else {
+            return;
+        };
+        tab_panel.update(cx, |tab_panel, cx| tab_panel.toggle_zoom(cx));
+    }
+
+    fn on_action_close_tab(&mut self, _: &CloseTab, cx: &mut ViewContext<Self>) {
+        let Some(tab_panel) = self.dock_area.read(cx).active_tab_panel() else {
+            return;
+        };
+        tab_panel.update(cx, |tab_panel, cx| {
+            if let Some(panel) = tab_panel.active_panel() {
+                if panel.closeable(cx) {
+                    tab_panel.close_panel(panel, cx);
+                }
+            }
+        });
+    }
+
+    fn on_action_reopen_closed_tab(&mut self, _: &ReopenClosedTab, cx: &mut ViewContext<Self>) {
+        self.dock_area
+            .update(cx, |dock_area, cx| dock_area.reopen_closed_panel(cx));
+    }
+
+    fn on_action_minimize_window(&mut self, _: &MinimizeWindow, cx: &mut ViewContext<Self>) {
+        cx.minimize_window();
+    }
+
+    fn on_action_zoom_window(&mut self, _: &ZoomWindow, cx: &mut ViewContext<Self>) {
+        cx.zoom_window();
+    }
+
+    fn on_action_select_theme_mode(
+        &mut self,
+        action: &SelectThemeMode,
+        cx: &mut ViewContext<Self>,
+    ) {
+        let mode = action.0;
+        Settings::update(cx, |settings| settings.theme_mode = mode);
+    }
+
+    fn on_action_open_settings(&mut self, _: &OpenSettings, cx: &mut ViewContext<Self>) {
+        SettingsView::open(cx);
+    }
+
+    /// Capture the active TabPanel to a PNG file picked by the save dialog.
+    fn on_action_capture_panel(&mut self, _: &CapturePanel, cx: &mut ViewContext<Self>) {
+        if !panel_capture::SUPPORTED {
+            let err = panel_capture::CaptureError::Unsupported;
+            cx.push_notification(Notification::error(err.to_string()));
+            return;
+        }
+        let Some(tab_panel) = self.dock_area.read(cx).active_tab_panel() else {
+            cx.push_notification(Notification::warning(
+                t!("Capture.No Active Panel").to_string(),
+            ));
+            return;
+        };
+        let path = AppState::prompt_save(SaveOptions::new().extension("png"), cx);
+
+        cx.spawn(|_, mut cx| async move {
+            let Some(path) = path.await.ok().flatten() else {
+                return;
+            };
+            // Bring the window to the front, the screen is captured with the windows above it,
+            // and wait for the save dialog to disappear from the screen.
+            cx.update(|cx| cx.activate_window()).ok();
+            cx.background_executor()
+                .timer(Duration::from_millis(300))
+                .await;
+
+            let Ok((bounds, scale_factor)) = cx.update(|cx| {
+                let mut bounds = tab_panel.read(cx).bounds();
+                bounds.origin = cx.bounds().origin + bounds.origin;
+                (bounds, cx.scale_factor())
+            }) else {
+                return;
+            };
+            let result = cx
+                .background_executor()
+                .spawn({
+                    let path = path.clone();
+                    async move { panel_capture::capture_screen(bounds, scale_factor, &path) }
+                })
+                .await;
+
+            cx.update(|cx| match result {
+                Ok(_) => cx.push_notification(Notification::success(
+                    t!("Capture.Captured", path = path.display()).to_string(),
+                )),
+                Err(err) => cx.push_notification(Notification::error(err.to_string())),
+            })
+            .ok();
+        })
+        .detach();
+    }
+
+    fn on_action_save_theme(&mut self, _: &SaveTheme, cx: &mut ViewContext<Self>) {
+        let json = cx.theme().to_json();
+        let path = AppState::prompt_save(
+            SaveOptions::new()
+                .directory(paths::data_dir())
+                .extension("json"),
             cx,
         );
 
-        let locale_selector = cx.new_view(LocaleSelector::new);
+        cx.spawn(|_, mut cx| async move {
+            let Some(path) = path.await.ok().flatten() else {
+                return;
+            };
 
-        let theme_color_picker = cx.new_view(|cx| {
-            let mut picker = ColorPicker::new("theme-color-picker", cx)
-                .xsmall()
-                .anchor(AnchorCorner::TopRight)
-                .label("Primary Color");
-            picker.set_value(cx.theme().primary, cx);
-            picker
-        });
-        cx.subscribe(
-            &theme_color_picker,
-            |_, _, ev: &ColorPickerEvent, cx| match ev {
-                ColorPickerEvent::Change(color) => {
-                    if let Some(color) = color {
-                        let theme = cx.global_mut::<Theme>();
-                        theme.primary = *color;
-                        theme.primary_hover = color.lighten(0.1);
-                        theme.primary_active = color.darken(0.1);
-                        cx.refresh();
+            let result = std::fs::write(&path, json);
+            cx.update(|cx| match result {
+                Ok(_) => cx.push_notification(Notification::success(format!(
+                    "Theme saved to {}",
+                    path.display()
+                ))),
+                Err(err) => cx.push_notification(Notification::error(format!(
+                    "Failed to save theme: {}",
+                    err
+                ))),
+            })
+            .ok();
+        })
+        .detach();
+    }
+
+    fn on_action_load_theme(&mut self, _: &LoadTheme, cx: &mut ViewContext<Self>) {
+        let paths = AppState::prompt_open(OpenOptions::file().extensions(&["json"]), cx);
+
+        cx.spawn(|this, mut cx| async move {
+            let result = paths.await;
+            this.update(&mut cx, |this, cx| match result {
+                Ok(paths) => {
+                    if let Some(path) = paths.first() {
+                        this.load_theme(path, cx);
                     }
                 }
-            },
-        )
+                Err(err) => cx.push_notification(Notification::error(format!(
+                    "Failed to load theme: {}",
+                    err
+                ))),
+            })
+            .ok();
+        })
         .detach();
+    }
 
-        Self {
-            dock_area,
-            locale_selector,
-            theme_color_picker,
+    /// Generate the palette from the picked primary color, and open the modal to preview it.
+    ///
+    /// The modal reads the latest palette, so it's updated when the color is picked again.
+    fn preview_palette(&mut self, seed: Hsla, cx: &mut ViewContext<Self>) {
+        let is_open = self.seed_palette.is_some() && cx.has_active_modal();
+        self.seed_palette = Some(SeedPalette::generate(seed, cx.theme().mode));
+        if is_open {
+            cx.refresh();
+            return;
+        }
+
+        let view = cx.view().clone();
+        cx.open_modal(move |modal, cx| {
+            let Some(palette) = view.read(cx).seed_palette else {
+                return modal;
+            };
+
+            modal
+                .title(t!("Workspace.Generate Palette").to_string())
+                .accessible_label(t!("Workspace.Generate Palette"))
+                .width(px(520.))
+                .on_close({
+                    let view = view.clone();
+                    move |_, cx| view.update(cx, |this, cx| this.discard_palette(cx))
+                })
+                .child(
+                    h_flex().flex_wrap().gap_3().children(
+                        palette
+                            .colors()
+                            .into_iter()
+                            .map(|(name, color)| Self::render_palette_swatch(name, color, cx)),
+                    ),
+                )
+                .footer(
+                    h_flex()
+                        .gap_6()
+                        .items_center()
+                        .child(
+                            Button::new("apply-palette", cx)
+                                .primary()
+                                .label(t!("Workspace.Apply").to_string())
+                                .on_click({
+                                    let view = view.clone();
+                                    move |_, cx| {
+                                        view.update(cx, |this, cx| this.apply_palette(cx));
+                                        cx.close_modal();
+                                    }
+                                }),
+                        )
+                        .child(
+                            Button::new("discard-palette", cx)
+                                .label(t!("Workspace.Cancel").to_string())
+                                .on_click({
+                                    let view = view.clone();
+                                    move |_, cx| {
+                                        view.update(cx, |this, cx| this.discard_palette(cx));
+                                        cx.close_modal();
+                                    }
+                                }),
+                        ),
+                )
+        });
+    }
+
+    fn render_palette_swatch(
+        name: &'static str,
+        color: Hsla,
+        cx: &WindowContext,
+    ) -> impl IntoElement {
+        v_flex()
+            .w(px(88.))
+            .gap_1()
+            .child(
+                div()
+                    .h_8()
+                    .rounded(px(cx.theme().radius))
+                    .border_1()
+                    .border_color(cx.theme().tokens.outline)
+                    .bg(color),
+            )
+            .child(div().text_xs().child(name))
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(cx.theme().tokens.on_surface_muted)
+                    .child(color.to_hex_string()),
+            )
+    }
+
+    /// Apply the previewed palette, all the colors are replaced at once, to be undone by one step.
+    fn apply_palette(&mut self, cx: &mut ViewContext<Self>) {
+        if let Some(palette) = self.seed_palette.take() {
+            let theme = cx.theme().with_palette(&palette);
+            Theme::replace_global(theme, cx);
+        }
+    }
+
+    /// Discard the previewed palette, and restore the picker to the primary color of the theme.
+    fn discard_palette(&mut self, cx: &mut ViewContext<Self>) {
+        self.seed_palette = None;
+        let accent = cx.theme().tokens.accent;
+        self.theme_color_picker
+            .update(cx, |picker, cx| picker.set_value(accent, cx));
+    }
+
+    /// Apply the theme JSON file to the global [`Theme`].
+    fn load_theme(&mut self, path: &Path, cx: &mut ViewContext<Self>) {
+        let result = std::fs::read_to_string(path)
+            .map_err(anyhow::Error::from)
+            .and_then(|json| Theme::from_json(&json));
+
+        match result {
+            Ok(theme) => {
+                let accent = theme.tokens.accent;
+                Theme::replace_global(theme, cx);
+                self.theme_color_picker.update(cx, |picker, cx| {
+                    picker.set_value(accent, cx);
+                });
+            }
+            Err(err) => cx.push_notification(Notification::error(format!(
+                "Failed to load theme: {}",
+                err
+            ))),
+        }
+    }
+
+    /// Open the CSV file in a new Table tab of the first TabPanel.
+    fn open_csv(&mut self, path: PathBuf, cx: &mut ViewContext<Self>) {
+        let Some(tab_panel) = self.dock_area.read(cx).tab_panels(cx).first().cloned() else {
+            return;
+        };
+
+        let name = path.file_name().map_or_else(
+            || path.display().to_string(),
+            |name| name.to_string_lossy().to_string(),
+        );
+        let description = path.display().to_string();
+        let view = cx.new_view(|cx| {
+            StoryContainer::load_with(
+                name,
+                description,
+                true,
+                move |cx| CsvTableStory::load(path.clone(), cx),
+                cx,
+            )
+            .icon(IconName::File)
+        });
+        StoryContainer::add_panel(view, tab_panel, None, None, cx);
+    }
+
+    /// Route the files dropped from the OS by the type, see [`DroppedFile`].
+    fn on_drop_paths(&mut self, paths: &ExternalPaths, cx: &mut ViewContext<Self>) {
+        for path in paths.paths() {
+            match DroppedFile::new(path) {
+                DroppedFile::Theme(path) => self.load_theme(&path, cx),
+                DroppedFile::Csv(path) => self.open_csv(path, cx),
+                DroppedFile::Unsupported(path) => cx.push_notification(Notification::warning(
+                    format!("Unsupported file: {}", path.display()),
+                )),
+            }
+        }
+    }
+
+    /// Open the floated panel in a new window, or in an always-on-top window if it is pinned.
+    fn float_panel(&mut self, state: PanelState, mode: FloatingMode, cx: &mut ViewContext<Self>) {
+        let Some(origin) = cx.window_handle().downcast::<Root>() else {
+            return;
+        };
+
+        FloatingWindow::open(self.app_state.clone(), origin, state, mode, cx)
+            .detach_and_log_err(cx);
+    }
+
+    /// Add a panel restored from the state, e.g.: a panel docked back from a floating window.
+    pub(crate) fn add_panel_from_state(&mut self, state: &PanelState, cx: &mut ViewContext<Self>) {
+        self.dock_area.update(cx, |dock_area, cx| {
+            if !dock_area.add_panel_from_state(state, cx) {
+                log::warn!("Failed to restore the panel: {}", state.panel_name);
+            }
+        });
+    }
+
+    /// Record the layout change by the user, e.g.: resizing the panels or moving the tabs.
+    fn record_layout(&mut self, cx: &mut ViewContext<Self>) {
+        let after = self.dock_area.read(cx).dump(cx);
+        let before = std::mem::replace(&mut self.last_layout, after.clone());
+        self.app_state.undo_stack().push(UndoEntry::Layout {
+            window: cx.window_handle(),
+            workspace: cx.view().downgrade(),
+            before,
+            after,
+        });
+    }
+
+    /// Restore the layout by undo or redo, it will not be recorded again.
+    pub(crate) fn restore_layout(&mut self, state: &DockAreaState, cx: &mut ViewContext<Self>) {
+        self.last_layout = state.clone();
+        self.dock_area
+            .update(cx, |dock_area, cx| dock_area.load(state, cx));
+        cx.notify();
+    }
+
+    fn load_layout(session: &str) -> anyhow::Result<DockAreaState> {
+        let json = std::fs::read_to_string(paths::session_layout_file(session))?;
+        let state = serde_json::from_str::<DockAreaState>(&json)?;
+        if state.version != Some(LAYOUT_VERSION) {
+            anyhow::bail!("layout version changed");
+        }
+
+        Ok(state)
+    }
+
+    fn save_layout(&self, cx: &AppContext) {
+        let mut state = self.dock_area.read(cx).dump(cx);
+        state.version = Some(LAYOUT_VERSION);
+
+        if let Err(err) = Self::write_layout(&self.session, &state) {
+            log::error!("Failed to save layout: {}", err);
+        }
+    }
+
+    fn write_layout(session: &str, state: &DockAreaState) -> anyhow::Result<()> {
+        let path = paths::session_layout_file(session);
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(path, serde_json::to_string_pretty(state)?)?;
+        Ok(())
+    }
+
+    fn on_action_switch_session(&mut self, action: &SwitchSession, cx: &mut ViewContext<Self>) {
+        if action.0 == self.session {
+            return;
+        }
+
+        self.save_layout(cx);
+        self.load_session(action.0.clone(), cx);
+    }
+
+    /// Load the layout of the session, or the default layout if the session has not been saved yet.
+    fn load_session(&mut self, session: SharedString, cx: &mut ViewContext<Self>) {
+        match Self::load_layout(&session) {
+            Ok(state) => self
+                .dock_area
+                .update(cx, |dock_area, cx| dock_area.load(&state, cx)),
+            Err(_) => {
+                self.dock_area.update(cx, |dock_area, cx| {
+                    let root = cx.new_view(|cx| StackPanel::new(Axis::Horizontal, cx));
+                    dock_area.replace_root(root, cx);
+                });
+                Self::reset_default_layout(&self.dock_area, cx);
+            }
+        }
+
+        self.last_layout = self.dock_area.read(cx).dump(cx);
+        self.session = session.clone();
+        Settings::update(cx, |settings| settings.session = session.to_string());
+        cx.notify();
+    }
+
+    fn on_action_new_session(&mut self, _: &NewSession, cx: &mut ViewContext<Self>) {
+        let input = cx.new_view(|cx| {
+            TextInput::new(cx).placeholder(t!("Workspace.Session Name").to_string())
+        });
+        cx.subscribe(&input, |this, input, event: &InputEvent, cx| {
+            if let InputEvent::PressEnter = event {
+                let name = input.read(cx).text();
+                this.create_session(name, cx);
+            }
+        })
+        .detach();
+        input.focus_handle(cx).focus(cx);
+
+        let view = cx.view().clone();
+        cx.open_modal(move |modal, cx| {
+            modal
+                .title(t!("Workspace.New Session").to_string())
+                .accessible_label(t!("Workspace.New Session"))
+                .width(px(360.))
+                .child(input.clone())
+                .footer(
+                    h_flex()
+                        .gap_6()
+                        .items_center()
+                        .child(
+                            Button::new("create-session", cx)
+                                .primary()
+                                .label(t!("Workspace.Create").to_string())
+                                .on_click({
+                                    let view = view.clone();
+                                    let input = input.clone();
+                                    move |_, cx| {
+                                        let name = input.read(cx).text();
+                                        view.update(cx, |this, cx| this.create_session(name, cx));
+                                    }
+                                }),
+                        )
+                        .child(
+                            Button::new("cancel", cx)
+                                .label(t!("Workspace.Cancel").to_string())
+                                .on_click(|_, cx| cx.close_modal()),
+                        ),
+                )
+        });
+    }
+
+    /// Create the session with the default layout and switch to it, or just switch to it if it exists.
+    fn create_session(&mut self, name: SharedString, cx: &mut ViewContext<Self>) {
+        let name = name.trim().to_string();
+        // The name is used as the file name of the layout.
+        if name.is_empty() || name.contains(['/', '\\']) {
+            cx.push_notification(Notification::error(
+                t!("Workspace.Invalid Session Name").to_string(),
+            ));
+            return;
+        }
+
+        cx.close_modal();
+        if !Settings::get(cx).sessions.contains(&name) {
+            Settings::update(cx, |settings| settings.sessions.push(name.clone()));
         }
+        self.on_action_switch_session(&SwitchSession(name.into()), cx);
+    }
+
+    /// Delete the current session and its saved layout, then switch to the default session.
+    fn on_action_delete_session(&mut self, _: &DeleteSession, cx: &mut ViewContext<Self>) {
+        if self.session.as_ref() == DEFAULT_SESSION {
+            return;
+        }
+
+        let session = self.session.to_string();
+        let _ = std::fs::remove_file(paths::session_layout_file(&session));
+        Settings::update(cx, |settings| {
+            settings.sessions.retain(|name| *name != session)
+        });
+        self.load_session(DEFAULT_SESSION.into(), cx);
+    }
+
+    fn render_session_menu(&self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let sessions = Settings::get(cx).sessions.clone();
+        let current = self.session.clone();
+
+        Button::new("session", cx)
+            .label(self.session.clone())
+            .small()
+            .ghost()
+            .tooltip(t!("Workspace.Session"))
+            .popup_menu(move |mut menu, _| {
+                for session in &sessions {
+                    menu = menu.menu_with_check(
+                        session.clone(),
+                        session.as_str() == current.as_ref(),
+                        Box::new(SwitchSession(session.clone().into())),
+                    );
+                }
+                menu = menu
+                    .separator()
+                    .menu(t!("Workspace.New Session"), Box::new(NewSession));
+                if current.as_ref() != DEFAULT_SESSION {
+                    menu = menu.menu(t!("Workspace.Delete Session"), Box::new(DeleteSession));
+                }
+                menu
+            })
+            .anchor(AnchorCorner::TopRight)
     }
 
     pub fn new_local(
         app_state: Arc<AppState>,
         cx: &mut AppContext,
     ) -> Task<anyhow::Result<WindowHandle<Root>>> {
-        let window_bounds = Bounds::centered(None, size(px(1600.0), px(1200.0)), cx);
+        // Restore the bounds of the last window for the first window,
+        // fallback to centered if it's out of the displays now.
+        let restored_bounds = if app_state.windows().is_empty() {
+            WindowState::load()
+                .ok()
+                .and_then(|state| state.window_bounds(cx))
+        } else {
+            None
+        };
+        let window_bounds = restored_bounds.unwrap_or_else(|| {
+            let mut bounds = Bounds::centered(None, size(px(1600.0), px(1200.0)), cx);
+            // Cascade the new window to avoid covering the existing windows.
+            let offset = px(30.) * app_state.windows().len() as f32;
+            bounds.origin.x += offset;
+            bounds.origin.y += offset;
+            WindowBounds::Windowed(bounds)
+        });
 
         cx.spawn(|mut cx| async move {
             let options = WindowOptions {
-                window_bounds: Some(WindowBounds::Windowed(window_bounds)),
+                window_bounds: Some(window_bounds),
                 titlebar: Some(TitlebarOptions {
                     title: None,
                     appears_transparent: true,
@@ -330,13 +1511,16 @@ impl StoryWorkspace {
                 cx.new_view(|cx| Root::new(story_view.into(), cx))
             })?;
 
+            app_state.add_window(window, AppWindowKind::Workspace);
             window
                 .update(&mut cx, |_, cx| {
                     cx.activate_window();
-                    cx.set_window_title("GPUI App");
-                    cx.on_release(|_, _, cx| {
-                        // exit app
-                        cx.quit();
+                    cx.set_window_title(&t!("Workspace.title"));
+                    cx.on_release(move |_, window, cx| {
+                        // exit app when the last window is closed
+                        if app_state.remove_window(window) == 0 {
+                            cx.quit();
+                        }
                     })
                     .detach();
                 })
@@ -345,6 +1529,50 @@ impl StoryWorkspace {
             Ok(window)
         })
     }
+
+    /// Render the path of the active panel: DockArea > TabPanel > Tab, click to focus it.
+    fn render_breadcrumb(&self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let dock_area = self.dock_area.clone();
+        let tab_panel = dock_area.read(cx).active_tab_panel();
+        let panel = dock_area.read(cx).active_panel();
+
+        Breadcrumb::new()
+            .item({
+                let dock_area = dock_area.clone();
+                BreadcrumbItem::new("breadcrumb-dock", t!("Workspace.Dock").to_string()).on_click(
+                    move |_, cx| dock_area.update(cx, |dock_area, cx| dock_area.focus(cx)),
+                )
+            })
+            .when_some(tab_panel, |this, tab_panel| {
+                let ix = dock_area
+                    .read(cx)
+                    .tab_panels(cx)
+                    .iter()
+                    .position(|view| view == &tab_panel)
+                    .unwrap_or_default();
+
+                this.item(
+                    BreadcrumbItem::new(
+                        "breadcrumb-tab-panel",
+                        t!("Workspace.Group", ix = ix + 1).to_string(),
+                    )
+                    .on_click({
+                        let tab_panel = tab_panel.clone();
+                        move |_, cx| tab_panel.update(cx, |tab_panel, cx| tab_panel.focus(cx))
+                    }),
+                )
+                .when_some(panel, |this, panel| {
+                    this.item(
+                        BreadcrumbItem::new("breadcrumb-tab", panel.title(cx)).on_click(
+                            move |_, cx| {
+                                tab_panel
+                                    .update(cx, |tab_panel, cx| tab_panel.focus_active_panel(cx))
+                            },
+                        ),
+                    )
+                })
+            })
+    }
 }
 
 pub fn open_new(
@@ -364,19 +1592,50 @@ pub fn open_new(
 
 impl Render for StoryWorkspace {
     fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
-        let active_modal = Root::read(cx).active_modal.clone();
-        let active_drawer = Root::read(cx).active_drawer.clone();
-        let has_active_modal = active_modal.is_some();
         let notification_view = Root::read(cx).notification.clone();
-        let notifications_count = cx.notifications().len();
-
+        let mode_setting = Theme::mode_setting(cx);
+        let left_collapsed = self
+            .dock_area
+            .read(cx)
+            .is_side_panel_collapsed(Placement::Left, cx);
+        let right_collapsed = self
+            .dock_area
+            .read(cx)
+            .is_side_panel_collapsed(Placement::Right, cx);
         div()
             .relative()
             .size_full()
             .flex()
             .flex_col()
-            .bg(cx.theme().background)
-            .text_color(cx.theme().foreground)
+            .on_action(cx.listener(Self::on_action_toggle_command_palette))
+            .on_action(cx.listener(Self::on_action_toggle_keyboard_shortcuts))
+            .on_action(cx.listener(Self::on_action_toggle_search))
+            .on_action(cx.listener(Self::on_action_open_story))
+            .on_action(cx.listener(Self::on_action_close_window))
+            .on_action(cx.listener(Self::on_action_toggle_left_panel))
+            .on_action(cx.listener(Self::on_action_toggle_right_panel))
+            .on_action(cx.listener(Self::on_action_toggle_panel_zoom))
+            .on_action(cx.listener(Self::on_action_capture_panel))
+            .on_action(cx.listener(Self::on_action_close_tab))
+            .on_action(cx.listener(Self::on_action_reopen_closed_tab))
+            .on_action(cx.listener(Self::on_action_minimize_window))
+            .on_action(cx.listener(Self::on_action_zoom_window))
+            .on_action(cx.listener(Self::on_action_select_theme_mode))
+            .on_action(cx.listener(Self::on_action_save_theme))
+            .on_action(cx.listener(Self::on_action_load_theme))
+            .on_action(cx.listener(Self::on_action_open_settings))
+            .on_action(cx.listener(Self::on_action_switch_session))
+            .on_action(cx.listener(Self::on_action_new_session))
+            .on_action(cx.listener(Self::on_action_delete_session))
+            .capture_any_mouse_down(action_recorder::record_mouse_down)
+            .capture_any_mouse_up(action_recorder::record_mouse_up)
+            // The whole window accepts the files, without the indicator.
+            .drop_target(
+                DropTarget::new(cx.listener(Self::on_drop_paths))
+                    .indicator(|_, _| DropIndicator::None),
+            )
+            .bg(cx.theme().tokens.surface)
+            .text_color(cx.theme().tokens.on_surface)
             .child(
                 TitleBar::new("main-title", Box::new(CloseWindow))
                     .when(cfg!(not(windows)), |this| {
@@ -387,7 +1646,18 @@ impl Render for StoryWorkspace {
                         })
                     })
                     // left side
-                    .child(div().flex().items_center().child("GPUI App"))
+                    .child(
+                        div()
+                            .flex()
+                            .items_center()
+                            .gap_3()
+                            .child(t!("Workspace.title").to_string())
+                            // macOS has the native menus, see `app_menus::init`.
+                            .when(cfg!(not(target_os = "macos")), |this| {
+                                this.child(app_menus::menu_bar())
+                            })
+                            .child(self.render_breadcrumb(cx)),
+                    )
                     .child(
                         div()
                             .flex()
@@ -395,9 +1665,57 @@ impl Render for StoryWorkspace {
                             .justify_end()
                             .px_2()
                             .gap_2()
+                            .child(self.render_session_menu(cx))
+                            .child(
+                                Button::new("toggle-left-panel", cx)
+                                    .tab_stop(&self.title_bar_focus_handles[0])
+                                    .icon(IconName::PanelLeft)
+                                    .accessible_label(t!("Workspace.Toggle Left Panel"))
+                                    .small()
+                                    .ghost()
+                                    .selected(!left_collapsed)
+                                    .tooltip_with_action(
+                                        t!("Workspace.Toggle Left Panel"),
+                                        &ToggleLeftPanel,
+                                    )
+                                    .on_click(cx.listener(|this, _, cx| {
+                                        this.on_action_toggle_left_panel(&ToggleLeftPanel, cx)
+                                    })),
+                            )
+                            .child(
+                                Button::new("toggle-right-panel", cx)
+                                    .tab_stop(&self.title_bar_focus_handles[1])
+                                    .icon(IconName::PanelRight)
+                                    .accessible_label(t!("Workspace.Toggle Right Panel"))
+                                    .small()
+                                    .ghost()
+                                    .selected(!right_collapsed)
+                                    .tooltip_with_action(
+                                        t!("Workspace.Toggle Right Panel"),
+                                        &ToggleRightPanel,
+                                    )
+                                    .on_click(cx.listener(|this, _, cx| {
+                                        this.on_action_toggle_right_panel(&ToggleRightPanel, cx)
+                                    })),
+                            )
                             .child(self.theme_color_picker.clone())
+                            .child(
+                                Button::new("theme-file", cx)
+                                    .tooltip(t!("Workspace.Theme File"))
+                                    .accessible_label(t!("Workspace.Theme File"))
+                                    .icon(IconName::Ellipsis)
+                                    .small()
+                                    .ghost()
+                                    .popup_menu(|this, _| {
+                                        this.menu(t!("Workspace.Save Theme"), Box::new(SaveTheme))
+                                            .menu(t!("Workspace.Load Theme"), Box::new(LoadTheme))
+                                    })
+                                    .anchor(AnchorCorner::TopRight),
+                            )
                             .child(
                                 Button::new("theme-mode", cx)
+                                    .tooltip(t!("Workspace.Theme Mode"))
+                                    .accessible_label(t!("Workspace.Theme Mode"))
                                     .map(|this| {
                                         if cx.theme().mode.is_dark() {
                                             this.icon(IconName::Sun)
@@ -407,114 +1725,160 @@ impl Render for StoryWorkspace {
                                     })
                                     .small()
                                     .ghost()
-                                    .on_click(move |_, cx| {
-                                        let mode = match cx.theme().mode.is_dark() {
-                                            true => ui::theme::ThemeMode::Light,
-                                            false => ui::theme::ThemeMode::Dark,
-                                        };
-
-                                        Theme::change(mode, cx);
-                                    }),
+                                    .popup_menu(move |this, _| {
+                                        this.menu_with_check(
+                                            t!("Workspace.Light"),
+                                            mode_setting == ThemeModeSetting::Light,
+                                            Box::new(SelectThemeMode(ThemeModeSetting::Light)),
+                                        )
+                                        .menu_with_check(
+                                            t!("Workspace.Dark"),
+                                            mode_setting == ThemeModeSetting::Dark,
+                                            Box::new(SelectThemeMode(ThemeModeSetting::Dark)),
+                                        )
+                                        .menu_with_check(
+                                            t!("Workspace.Follow System"),
+                                            mode_setting == ThemeModeSetting::System,
+                                            Box::new(SelectThemeMode(ThemeModeSetting::System)),
+                                        )
+                                        .separator()
+                                        .menu_with_check(
+                                            t!("Workspace.High Contrast Light"),
+                                            mode_setting == ThemeModeSetting::HighContrastLight,
+                                            Box::new(SelectThemeMode(
+                                                ThemeModeSetting::HighContrastLight,
+                                            )),
+                                        )
+                                        .menu_with_check(
+                                            t!("Workspace.High Contrast Dark"),
+                                            mode_setting == ThemeModeSetting::HighContrastDark,
+                                            Box::new(SelectThemeMode(
+                                                ThemeModeSetting::HighContrastDark,
+                                            )),
+                                        )
+                                    })
+                                    .anchor(AnchorCorner::TopRight),
                             )
                             .child(self.locale_selector.clone())
+                            .child(
+                                Button::new("settings", cx)
+                                    .tab_stop(&self.title_bar_focus_handles[2])
+                                    .icon(IconName::Settings)
+                                    .accessible_label(t!("Settings.title"))
+                                    .small()
+                                    .ghost()
+                                    .tooltip_with_action(t!("Settings.title"), &OpenSettings)
+                                    .on_click(cx.listener(|this, _, cx| {
+                                        this.on_action_open_settings(&OpenSettings, cx)
+                                    })),
+                            )
                             .child(
                                 Button::new("github", cx)
+                                    .tab_stop(&self.title_bar_focus_handles[3])
                                     .icon(IconName::GitHub)
                                     .small()
                                     .ghost()
+                                    .tooltip(t!("Workspace.View on GitHub"))
+                                    .accessible_label(t!("Workspace.View on GitHub"))
                                     .on_click(|_, cx| {
                                         cx.open_url("https://github.com/huacnlee/gpui-component")
                                     }),
-                            )
-                            .child(
-                                div()
-                                    .relative()
-                                    .child(
-                                        Button::new("bell", cx)
-                                            .small()
-                                            .ghost()
-                                            .compact()
-                                            .icon(IconName::Bell),
-                                    )
-                                    .when(notifications_count > 0, |this| {
-                                        this.child(
-                                            h_flex()
-                                                .absolute()
-                                                .rounded_full()
-                                                .top(px(-2.))
-                                                .right(px(-2.))
-                                                .p(px(1.))
-                                                .min_w(px(12.))
-                                                .bg(ui::red_500())
-                                                .text_color(ui::white())
-                                                .justify_center()
-                                                .text_size(px(10.))
-                                                .line_height(relative(1.))
-                                                .child(format!("{}", notifications_count.min(99))),
-                                        )
-                                    }),
                             ),
                     ),
             )
-            .child(self.dock_area.clone())
-            .when(!has_active_modal, |this| {
-                this.when_some(active_drawer, |this, builder| {
-                    let drawer = Drawer::new(cx);
-                    this.child(builder(drawer, cx))
-                })
-            })
-            .when_some(active_modal, |this, builder| {
-                let modal = Modal::new(cx);
-                this.child(builder(modal, cx))
-            })
+            .child(
+                h_flex()
+                    .flex_1()
+                    .w_full()
+                    .overflow_hidden()
+                    .children(self.render_activity_bar(cx))
+                    .child(
+                        div()
+                            .flex_1()
+                            .h_full()
+                            .overflow_hidden()
+                            .child(self.dock_area.clone()),
+                    ),
+            )
+            .child(self.status_bar.clone())
+            .children(Root::render_drawer_layer(cx))
+            .children(Root::render_modal_layer(cx))
             .child(div().absolute().top_8().child(notification_view))
     }
 }
 
+/// A locale option of the [`LocaleSelector`].
+struct LocaleItem {
+    locale: SharedString,
+    name: SharedString,
+}
+
+impl DropdownItem for LocaleItem {
+    type Value = SharedString;
+
+    fn title(&self) -> SharedString {
+        self.name.clone()
+    }
+
+    fn value(&self) -> &Self::Value {
+        &self.locale
+    }
+}
+
 struct LocaleSelector {
-    focus_handle: FocusHandle,
+    dropdown: View<Dropdown<Vec<LocaleItem>>>,
 }
 
 impl LocaleSelector {
     pub fn new(cx: &mut ViewContext<Self>) -> Self {
-        Self {
-            focus_handle: cx.focus_handle(),
-        }
+        let locales = settings::LOCALES
+            .into_iter()
+            .map(|(locale, name)| LocaleItem {
+                locale: locale.into(),
+                name: name.into(),
+            })
+            .collect::<Vec<_>>();
+        let selected_index = settings::LOCALES
+            .iter()
+            .position(|(locale, _)| *locale == Settings::get(cx).locale);
+
+        let dropdown = cx.new_view(|cx| {
+            Dropdown::new("locale-selector", locales, selected_index, cx)
+                .small()
+                .icon(IconName::Globe)
+                .width(px(110.))
+                .menu_width(px(140.))
+        });
+
+        cx.subscribe(&dropdown, Self::on_dropdown_event).detach();
+        // Keep the selection in sync, the locale can be changed in the settings too.
+        cx.observe_global::<Settings>(|this, cx| {
+            let locale = SharedString::from(Settings::get(cx).locale.clone());
+            this.dropdown
+                .update(cx, |dropdown, cx| dropdown.set_selected_value(&locale, cx));
+        })
+        .detach();
+
+        Self { dropdown }
     }
 
-    fn on_select_locale(&mut self, locale: &SelectLocale, cx: &mut ViewContext<Self>) {
-        ui::set_locale(&locale.0);
-        cx.refresh();
+    fn on_dropdown_event(
+        &mut self,
+        _: View<Dropdown<Vec<LocaleItem>>>,
+        event: &DropdownEvent<Vec<LocaleItem>>,
+        cx: &mut ViewContext<Self>,
+    ) {
+        let DropdownEvent::Confirm(Some(locale)) = event else {
+            return;
+        };
+
+        let locale = locale.to_string();
+        Settings::update(cx, |settings| settings.locale = locale);
     }
 }
 
 impl Render for LocaleSelector {
-    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
-        let focus_handle = self.focus_handle.clone();
-        let locale = ui::locale().to_string();
-
-        div()
-            .id("locale-selector")
-            .track_focus(&focus_handle)
-            .on_action(cx.listener(Self::on_select_locale))
-            .child(
-                Button::new("btn", cx)
-                    .small()
-                    .ghost()
-                    .icon(IconName::Globe)
-                    .popup_menu(move |this, _| {
-                        this.menu_with_check(
-                            "English",
-                            locale == "en",
-                            Box::new(SelectLocale("en".into())),
-                        )
-                        .menu_with_check(
-                            "简体中文",
-                            locale == "zh-CN",
-                            Box::new(SelectLocale("zh-CN".into())),
-                        )
-                    })
-                    .anchor(AnchorCorner::TopRight),
-            )
+    fn render(&mut self, _: &mut ViewContext<Self>) -> impl IntoElement {
+        div().child(self.dropdown.clone())
     }
 }
@@ -0,0 +1,93 @@
+use std::{path::Path, time::Duration};
+
+use gpui::{AppContext, Global, Task};
+use notify::{RecommendedWatcher, RecursiveMode, Watcher as _};
+use ui::theme::Theme;
+
+use crate::paths;
+
+/// An editor may write the file several times in one save, wait for it to finish before loading.
+const DEBOUNCE_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Keep the file watcher alive to receive the changes.
+struct ThemeWatcher {
+    _watcher: RecommendedWatcher,
+    _reload_task: Task<()>,
+}
+
+impl Global for ThemeWatcher {}
+
+/// Apply the [`paths::theme_file`] to the global [`Theme`] if it exists, and apply it again once it is changed.
+///
+/// The file is in the format of [`Theme::to_json`], use `Save Theme` to export the current theme to start with.
+pub fn init(cx: &mut AppContext) {
+    let path = paths::theme_file();
+    if path.exists() {
+        apply_theme_file(&path, cx);
+    }
+
+    let (tx, rx) = smol::channel::unbounded();
+    let file_name = path.file_name().map(|name| name.to_os_string());
+    let watcher = notify::recommended_watcher(move |event: notify::Result<notify::Event>| {
+        let Ok(event) = event else {
+            return;
+        };
+        if !event.kind.is_create() && !event.kind.is_modify() {
+            return;
+        }
+        if event
+            .paths
+            .iter()
+            .any(|path| path.file_name() == file_name.as_deref())
+        {
+            let _ = tx.send_blocking(());
+        }
+    });
+
+    // Watch the directory instead of the file, because the editors usually save by replacing the file,
+    // and the file may not exist yet.
+    let result = watcher.and_then(|mut watcher| {
+        let _ = std::fs::create_dir_all(paths::data_dir());
+        watcher.watch(&paths::data_dir(), RecursiveMode::NonRecursive)?;
+        Ok(watcher)
+    });
+    let watcher = match result {
+        Ok(watcher) => watcher,
+        Err(err) => {
+            log::error!("Failed to watch the theme file: {}", err);
+            return;
+        }
+    };
+
+    let reload_task = cx.spawn(|cx| async move {
+        while rx.recv().await.is_ok() {
+            cx.background_executor().timer(DEBOUNCE_INTERVAL).await;
+            while rx.try_recv().is_ok() {}
+
+            if cx.update(|cx| apply_theme_file(&path, cx)).is_err() {
+                break;
+            }
+        }
+    });
+
+    cx.set_global(ThemeWatcher {
+        _watcher: watcher,
+        _reload_task: reload_task,
+    });
+}
+
+fn apply_theme_file(path: &Path, cx: &mut AppContext) {
+    let result = std::fs::read_to_string(path)
+        .map_err(anyhow::Error::from)
+        .and_then(|json| Theme::from_json(&json));
+
+    match result {
+        Ok(mut theme) => {
+            theme.scale = cx.global::<Theme>().scale;
+            if cx.global::<Theme>() != &theme {
+                Theme::replace_global(theme, cx);
+            }
+        }
+        Err(err) => log::error!("Failed to load the theme from {}: {}", path.display(), err),
+    }
+}
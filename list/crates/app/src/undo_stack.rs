@@ -0,0 +1,140 @@
+use std::time::{Duration, Instant};
+
+use gpui::{AnyWindowHandle, AppContext, WeakView};
+use ui::{dock::DockAreaState, theme::Theme};
+
+use crate::{app_state::AppState, story_workspace::StoryWorkspace};
+
+/// The maximum number of changes to keep.
+const MAX_UNDO: usize = 100;
+/// The theme changes within this interval are merged into one, e.g.: dragging in the color picker.
+const GROUP_INTERVAL: Duration = Duration::from_millis(500);
+
+/// A change that can be reverted by [`UndoStack::undo`].
+pub enum UndoEntry {
+    Theme {
+        before: Theme,
+        after: Theme,
+        changed_at: Instant,
+    },
+    Layout {
+        window: AnyWindowHandle,
+        workspace: WeakView<StoryWorkspace>,
+        before: DockAreaState,
+        after: DockAreaState,
+    },
+}
+
+/// The undo and redo history of the theme edits and the layout changes of all the windows.
+pub struct UndoStack {
+    undos: Vec<UndoEntry>,
+    redos: Vec<UndoEntry>,
+    /// The last recorded theme, to be the `before` of the next theme change.
+    theme: Option<Theme>,
+}
+
+impl UndoStack {
+    pub fn new() -> Self {
+        Self {
+            undos: Vec::new(),
+            redos: Vec::new(),
+            theme: None,
+        }
+    }
+
+    /// Record the theme change, the theme mode switching and the zooming are not edits and will not be recorded.
+    pub fn record_theme(&mut self, theme: &Theme) {
+        let Some(before) = self.theme.replace(theme.clone()) else {
+            return;
+        };
+        if before == *theme || before.mode != theme.mode || before.scale != theme.scale {
+            return;
+        }
+
+        if let Some(UndoEntry::Theme {
+            after, changed_at, ..
+        }) = self.undos.last_mut()
+        {
+            if changed_at.elapsed() < GROUP_INTERVAL {
+                *after = theme.clone();
+                *changed_at = Instant::now();
+                self.redos.clear();
+                return;
+            }
+        }
+
+        self.push(UndoEntry::Theme {
+            before,
+            after: theme.clone(),
+            changed_at: Instant::now(),
+        });
+    }
+
+    pub fn push(&mut self, entry: UndoEntry) {
+        if self.undos.len() >= MAX_UNDO {
+            self.undos.remove(0);
+        }
+        self.undos.push(entry);
+        self.redos.clear();
+    }
+
+    /// Revert the last change.
+    pub fn undo(cx: &mut AppContext) {
+        let Some(app_state) = AppState::global(cx) else {
+            return;
+        };
+        let Some(entry) = app_state.undo_stack().undos.pop() else {
+            return;
+        };
+
+        match &entry {
+            UndoEntry::Theme { before, .. } => Self::apply_theme(&app_state, before, cx),
+            UndoEntry::Layout {
+                window,
+                workspace,
+                before,
+                ..
+            } => Self::apply_layout(*window, workspace, before, cx),
+        }
+        app_state.undo_stack().redos.push(entry);
+    }
+
+    /// Reapply the last reverted change.
+    pub fn redo(cx: &mut AppContext) {
+        let Some(app_state) = AppState::global(cx) else {
+            return;
+        };
+        let Some(entry) = app_state.undo_stack().redos.pop() else {
+            return;
+        };
+
+        match &entry {
+            UndoEntry::Theme { after, .. } => Self::apply_theme(&app_state, after, cx),
+            UndoEntry::Layout {
+                window,
+                workspace,
+                after,
+                ..
+            } => Self::apply_layout(*window, workspace, after, cx),
+        }
+        app_state.undo_stack().undos.push(entry);
+    }
+
+    fn apply_theme(app_state: &AppState, theme: &Theme, cx: &mut AppContext) {
+        // Update the recorded theme first, so the change will not be recorded again.
+        app_state.undo_stack().theme = Some(theme.clone());
+        Theme::replace_global(theme.clone(), cx);
+    }
+
+    fn apply_layout(
+        window: AnyWindowHandle,
+        workspace: &WeakView<StoryWorkspace>,
+        state: &DockAreaState,
+        cx: &mut AppContext,
+    ) {
+        // The window may have been closed.
+        let _ = window.update(cx, |_, cx| {
+            workspace.update(cx, |workspace, cx| workspace.restore_layout(state, cx))
+        });
+    }
+}
@@ -0,0 +1,77 @@
+use gpui::{point, px, size, AppContext, Bounds, Pixels, WindowBounds};
+use serde::{Deserialize, Serialize};
+
+use crate::paths;
+
+/// The position and size of the last moved or resized window, restored on the next launch.
+///
+/// Persisted to the [`paths::window_state_file`].
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct WindowState {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+    /// The window is maximized, the bounds above are used when it's restored.
+    pub maximized: bool,
+}
+
+impl WindowState {
+    pub fn new(window_bounds: WindowBounds) -> Self {
+        let (bounds, maximized) = match window_bounds {
+            WindowBounds::Windowed(bounds) => (bounds, false),
+            WindowBounds::Maximized(bounds) => (bounds, true),
+            // Restore a fullscreen window as a normal one.
+            WindowBounds::Fullscreen(bounds) => (bounds, false),
+        };
+
+        Self {
+            x: bounds.origin.x.0,
+            y: bounds.origin.y.0,
+            width: bounds.size.width.0,
+            height: bounds.size.height.0,
+            maximized,
+        }
+    }
+
+    fn bounds(&self) -> Bounds<Pixels> {
+        Bounds {
+            origin: point(px(self.x), px(self.y)),
+            size: size(px(self.width), px(self.height)),
+        }
+    }
+
+    /// Returns the bounds to open the window with.
+    ///
+    /// Returns `None` if the window is out of all the displays, e.g.: the monitor has been disconnected.
+    pub fn window_bounds(&self, cx: &AppContext) -> Option<WindowBounds> {
+        let bounds = self.bounds();
+        if !cx
+            .displays()
+            .iter()
+            .any(|display| display.bounds().intersects(&bounds))
+        {
+            return None;
+        }
+
+        Some(if self.maximized {
+            WindowBounds::Maximized(bounds)
+        } else {
+            WindowBounds::Windowed(bounds)
+        })
+    }
+
+    pub fn load() -> anyhow::Result<Self> {
+        let json = std::fs::read_to_string(paths::window_state_file())?;
+        Ok(serde_json::from_str(&json)?)
+    }
+
+    pub fn save(&self) -> anyhow::Result<()> {
+        std::fs::create_dir_all(paths::data_dir())?;
+        std::fs::write(
+            paths::window_state_file(),
+            serde_json::to_string_pretty(self)?,
+        )?;
+        Ok(())
+    }
+}
@@ -0,0 +1,175 @@
+use gpui::{
+    div, px, IntoElement, ParentElement as _, Render, SharedString, Styled as _, View, ViewContext,
+    VisualContext as _, WindowContext,
+};
+use ui::{
+    avatar::{Avatar, AvatarGroup, AvatarStatus},
+    h_flex,
+    theme::ActiveTheme as _,
+    v_flex, Sizable as _,
+};
+
+use crate::section;
+
+struct User {
+    name: SharedString,
+    /// The image id of the avatar, `None` to show the initials.
+    image: Option<usize>,
+    status: AvatarStatus,
+    message: SharedString,
+}
+
+impl User {
+    fn new(name: &str, image: Option<usize>, status: AvatarStatus, message: &str) -> Self {
+        Self {
+            name: name.to_string().into(),
+            image,
+            status,
+            message: message.to_string().into(),
+        }
+    }
+
+    fn avatar(&self) -> Avatar {
+        let avatar = Avatar::new(self.name.clone());
+        match self.image {
+            Some(id) => avatar.src(format!("https://i.pravatar.cc/80?image={}", id)),
+            None => avatar,
+        }
+    }
+}
+
+/// The avatars with the images, the initials and the status, and the groups of them.
+pub struct AvatarStory {
+    users: Vec<User>,
+}
+
+impl AvatarStory {
+    pub fn view(cx: &mut WindowContext) -> View<Self> {
+        cx.new_view(|_| Self::new())
+    }
+
+    fn new() -> Self {
+        Self {
+            users: vec![
+                User::new(
+                    "Jason Lee",
+                    Some(12),
+                    AvatarStatus::Online,
+                    "The new build is ready.",
+                ),
+                User::new(
+                    "Mary Smith",
+                    None,
+                    AvatarStatus::Away,
+                    "Back in 10 minutes.",
+                ),
+                User::new(
+                    "Floyd Miles",
+                    Some(33),
+                    AvatarStatus::Busy,
+                    "In a meeting until 3pm.",
+                ),
+                User::new(
+                    "Kristin Watson",
+                    Some(45),
+                    AvatarStatus::Online,
+                    "Can you review my PR?",
+                ),
+                User::new(
+                    "Ronald Richards",
+                    None,
+                    AvatarStatus::Offline,
+                    "See you tomorrow!",
+                ),
+                User::new(
+                    "Esther Howard",
+                    Some(47),
+                    AvatarStatus::Online,
+                    "The design is updated.",
+                ),
+                User::new("Cody Fisher", None, AvatarStatus::Away, "Thanks!"),
+            ],
+        }
+    }
+
+    /// A chat list, the avatar shows the status of the user.
+    fn render_chat_list(&self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        v_flex()
+            .w(px(360.))
+            .border_1()
+            .border_color(cx.theme().tokens.outline)
+            .rounded_lg()
+            .children(self.users.iter().map(|user| {
+                h_flex()
+                    .gap_3()
+                    .px_3()
+                    .py_2()
+                    .child(user.avatar().status(user.status))
+                    .child(
+                        v_flex()
+                            .overflow_hidden()
+                            .child(div().text_sm().child(user.name.clone()))
+                            .child(
+                                div()
+                                    .text_xs()
+                                    .text_color(cx.theme().tokens.on_surface_muted)
+                                    .child(user.message.clone()),
+                            ),
+                    )
+            }))
+    }
+}
+
+impl Render for AvatarStory {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let user = &self.users[0];
+
+        v_flex()
+            .gap_6()
+            .child(
+                section("Sizes", cx)
+                    .child(user.avatar().xsmall())
+                    .child(user.avatar().small())
+                    .child(user.avatar())
+                    .child(user.avatar().large())
+                    .child(user.avatar().with_size(px(64.))),
+            )
+            .child(
+                section("Initials", cx).children(
+                    self.users
+                        .iter()
+                        .map(|user| Avatar::new(user.name.clone()).large()),
+                ),
+            )
+            .child(
+                section("Status", cx).children(
+                    [
+                        AvatarStatus::Online,
+                        AvatarStatus::Away,
+                        AvatarStatus::Busy,
+                        AvatarStatus::Offline,
+                    ]
+                    .into_iter()
+                    .zip(self.users.iter())
+                    .map(|(status, user)| user.avatar().large().status(status)),
+                ),
+            )
+            .child(
+                section("Group", cx)
+                    .child(AvatarGroup::new().children(self.users.iter().map(User::avatar)))
+                    .child(
+                        AvatarGroup::new()
+                            .max(3)
+                            .small()
+                            .children(self.users.iter().map(User::avatar)),
+                    )
+                    .child(
+                        AvatarGroup::new()
+                            .max(4)
+                            .large()
+                            .children(self.users.iter().map(User::avatar)),
+                    ),
+            )
+            .child(section("Chat", cx).child(self.render_chat_list(cx)))
+    }
+}
@@ -0,0 +1,38 @@
+use std::{any::TypeId, collections::HashMap, rc::Rc};
+
+use gpui::{AnyView, AppContext, Global, WindowContext};
+use ui::dock::PanelBadge;
+
+/// A story that shows a badge on the tab of its panel.
+pub trait BadgeStory: 'static + Sized {
+    /// Returns the badge to show, call `cx.notify()` in the story to update it.
+    fn badge(&self, cx: &WindowContext) -> Option<PanelBadge>;
+}
+
+/// The registry of the stories with a badge by the view type.
+#[derive(Default)]
+struct BadgeRegistry {
+    items: HashMap<TypeId, Rc<dyn Fn(&AnyView, &WindowContext) -> Option<PanelBadge>>>,
+}
+
+impl Global for BadgeRegistry {}
+
+/// Register a story view type, to show its badge on the tab.
+pub fn register_badge<S: BadgeStory>(cx: &mut AppContext) {
+    cx.default_global::<BadgeRegistry>().items.insert(
+        TypeId::of::<S>(),
+        Rc::new(|view, cx| {
+            view.clone()
+                .downcast::<S>()
+                .ok()
+                .and_then(|view| view.read(cx).badge(cx))
+        }),
+    );
+}
+
+/// Returns the badge of the story view, `None` if the view type is not registered.
+pub(crate) fn badge(view: &AnyView, cx: &WindowContext) -> Option<PanelBadge> {
+    cx.try_global::<BadgeRegistry>()
+        .and_then(|registry| registry.items.get(&view.entity_type()))
+        .and_then(|badge| badge(view, cx))
+}
@@ -1,10 +1,12 @@
+use std::time::Duration;
+
 use gpui::{
     px, ClickEvent, IntoElement, ParentElement as _, Render, Styled as _, View, ViewContext,
     VisualContext as _, WindowContext,
 };
 
 use ui::{
-    button::{Button, ButtonCustomStyle},
+    button::{Button, ButtonCustomStyle, LoadingState},
     checkbox::Checkbox,
     h_flex,
     prelude::FluentBuilder,
@@ -19,6 +21,8 @@ pub struct ButtonStory {
     loading: bool,
     selected: bool,
     compact: bool,
+    /// The state of the async button, it's loading while the simulated request is running.
+    save_state: LoadingState,
 }
 
 impl ButtonStory {
@@ -28,12 +32,22 @@ impl ButtonStory {
             loading: false,
             selected: false,
             compact: false,
+            save_state: LoadingState::new(),
         })
     }
 
     fn on_click(ev: &ClickEvent, _: &mut WindowContext) {
         println!("Button clicked! {:?}", ev);
     }
+
+    /// Simulate a slow request, the button is loading until it's done.
+    fn on_save(_: &ClickEvent, cx: &mut WindowContext) -> gpui::Task<()> {
+        let timer = cx.background_executor().timer(Duration::from_secs(2));
+        cx.spawn(|_| async move {
+            timer.await;
+            println!("Saved!");
+        })
+    }
 }
 
 impl Render for ButtonStory {
@@ -164,11 +178,11 @@ impl Render for ButtonStory {
                                 Button::new("button-6-custom", cx)
                                     .custom(
                                         ButtonCustomStyle::new(cx)
-                                            .color(cx.theme().muted)
-                                            .foreground(cx.theme().destructive)
+                                            .color(cx.theme().tokens.surface_muted)
+                                            .foreground(cx.theme().tokens.danger)
                                             .border(cx.theme().scrollbar)
                                             .hover(cx.theme().tab_active_foreground)
-                                            .active(cx.theme().selection),
+                                            .active(cx.theme().tokens.selection),
                                     )
                                     .label("Custom Button")
                                     .disabled(disabled)
@@ -263,6 +277,59 @@ impl Render for ButtonStory {
                             ),
                     ),
             )
+            .child(
+                h_flex()
+                    .gap_6()
+                    .child(
+                        section("Variants", cx)
+                            .child(
+                                Button::new("variant-primary", cx)
+                                    .primary()
+                                    .label("Primary"),
+                            )
+                            .child(
+                                Button::new("variant-secondary", cx)
+                                    .secondary()
+                                    .label("Secondary"),
+                            )
+                            .child(Button::new("variant-danger", cx).danger().label("Danger"))
+                            .child(
+                                Button::new("variant-outline", cx)
+                                    .outline()
+                                    .label("Outline"),
+                            )
+                            .child(Button::new("variant-ghost", cx).ghost().label("Ghost"))
+                            .child(Button::new("variant-link", cx).link().label("Link")),
+                    )
+                    .child(
+                        section("Suffix Icon", cx)
+                            .child(
+                                Button::new("suffix-icon-1", cx)
+                                    .label("Options")
+                                    .suffix_icon(IconName::ChevronDown)
+                                    .disabled(disabled)
+                                    .on_click(Self::on_click),
+                            )
+                            .child(
+                                Button::new("suffix-icon-2", cx)
+                                    .primary()
+                                    .icon(IconName::Search)
+                                    .label("Next")
+                                    .suffix_icon(IconName::ArrowRight)
+                                    .disabled(disabled)
+                                    .on_click(Self::on_click),
+                            ),
+                    )
+                    .child(
+                        section("Async Loading", cx).child(
+                            Button::new("async-save", cx)
+                                .primary()
+                                .label("Save")
+                                .disabled(disabled)
+                                .on_click_async(&self.save_state, Self::on_save),
+                        ),
+                    ),
+            )
             .child(
                 h_flex()
                     .gap_6()
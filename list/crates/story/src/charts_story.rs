@@ -0,0 +1,170 @@
+use std::{collections::VecDeque, time::Duration};
+
+use fake::Fake;
+use gpui::{
+    div, px, IntoElement, ParentElement as _, Render, Styled as _, Task, View, ViewContext,
+    VisualContext as _, WindowContext,
+};
+use ui::{
+    button::Button,
+    chart::{BarChart, ChartSeries, LineChart, PieChart, PieSlice},
+    h_flex,
+    theme::ActiveTheme as _,
+    v_flex, Sizable as _, StyledExt as _,
+};
+
+const MONTHS: [&str; 6] = ["Jan", "Feb", "Mar", "Apr", "May", "Jun"];
+const BROWSERS: [&str; 5] = ["Chrome", "Safari", "Edge", "Firefox", "Other"];
+/// The number of the samples in the line chart.
+const SAMPLES: usize = 12;
+/// The interval to update the sample data.
+const UPDATE_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Returns a random value in the range of `base` ± `spread`, not less than zero.
+fn jitter(base: f64, spread: f64) -> f64 {
+    if spread <= 0. {
+        return base.max(0.);
+    }
+    (base + (-spread..spread).fake::<f64>()).max(0.)
+}
+
+pub struct ChartsStory {
+    revenue: Vec<f64>,
+    expenses: Vec<f64>,
+    cpu: VecDeque<f64>,
+    memory: VecDeque<f64>,
+    browsers: Vec<f64>,
+    live: bool,
+    _update_task: Option<Task<()>>,
+}
+
+impl ChartsStory {
+    pub fn view(cx: &mut WindowContext) -> View<Self> {
+        cx.new_view(Self::new)
+    }
+
+    fn new(cx: &mut ViewContext<Self>) -> Self {
+        let mut this = Self {
+            revenue: MONTHS.iter().map(|_| jitter(120., 40.)).collect(),
+            expenses: MONTHS.iter().map(|_| jitter(80., 30.)).collect(),
+            cpu: (0..SAMPLES).map(|_| jitter(40., 20.)).collect(),
+            memory: (0..SAMPLES).map(|_| jitter(60., 10.)).collect(),
+            browsers: vec![64., 19., 5., 3., 9.],
+            live: false,
+            _update_task: None,
+        };
+        this.set_live(true, cx);
+        this
+    }
+
+    /// Start or stop updating the sample data every second.
+    fn set_live(&mut self, live: bool, cx: &mut ViewContext<Self>) {
+        self.live = live;
+        self._update_task = live.then(|| {
+            cx.spawn(|this, mut cx| async move {
+                loop {
+                    cx.background_executor().timer(UPDATE_INTERVAL).await;
+                    let result = this.update(&mut cx, |this, cx| {
+                        this.update_data();
+                        cx.notify();
+                    });
+                    if result.is_err() {
+                        return;
+                    }
+                }
+            })
+        });
+        cx.notify();
+    }
+
+    /// Simulate the new data: the last month changes, and a new sample of the usage is added.
+    fn update_data(&mut self) {
+        if let Some(value) = self.revenue.last_mut() {
+            *value = jitter(*value, 10.);
+        }
+        if let Some(value) = self.expenses.last_mut() {
+            *value = jitter(*value, 8.);
+        }
+
+        let cpu = self.cpu.back().copied().unwrap_or(40.);
+        let memory = self.memory.back().copied().unwrap_or(60.);
+        self.cpu.push_back(jitter(cpu, 15.).min(100.));
+        self.memory.push_back(jitter(memory, 5.).min(100.));
+        if self.cpu.len() > SAMPLES {
+            self.cpu.pop_front();
+        }
+        if self.memory.len() > SAMPLES {
+            self.memory.pop_front();
+        }
+
+        for value in self.browsers.iter_mut() {
+            *value = jitter(*value, *value * 0.1);
+        }
+    }
+
+    fn render_card(
+        title: &'static str,
+        chart: impl IntoElement,
+        cx: &ViewContext<Self>,
+    ) -> impl IntoElement {
+        v_flex()
+            .flex_1()
+            .min_w(px(320.))
+            .gap_3()
+            .p_4()
+            .rounded_lg()
+            .border_1()
+            .border_color(cx.theme().tokens.outline)
+            .child(div().text_sm().font_semibold().child(title))
+            .child(chart)
+    }
+}
+
+impl Render for ChartsStory {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let live = self.live;
+        let seconds = (0..SAMPLES).map(|ix| format!("{}s", ix as i32 - SAMPLES as i32 + 1));
+
+        v_flex()
+            .gap_4()
+            .child(
+                h_flex().gap_2().child(
+                    Button::new("toggle-live", cx)
+                        .label(if live { "Pause" } else { "Resume" })
+                        .small()
+                        .on_click(cx.listener(move |this, _, cx| this.set_live(!live, cx))),
+                ),
+            )
+            .child(
+                h_flex()
+                    .flex_wrap()
+                    .items_start()
+                    .gap_4()
+                    .child(Self::render_card(
+                        "Revenue",
+                        BarChart::new("revenue", MONTHS)
+                            .series(ChartSeries::new("Revenue", self.revenue.clone()))
+                            .series(ChartSeries::new("Expenses", self.expenses.clone())),
+                        cx,
+                    ))
+                    .child(Self::render_card(
+                        "Usage",
+                        LineChart::new("usage", seconds)
+                            .fill(true)
+                            .series(ChartSeries::new("CPU", self.cpu.iter().copied()))
+                            .series(ChartSeries::new("Memory", self.memory.iter().copied())),
+                        cx,
+                    ))
+                    .child(Self::render_card(
+                        "Browsers",
+                        PieChart::new("browsers").donut(0.5).slices(
+                            BROWSERS
+                                .iter()
+                                .zip(self.browsers.iter())
+                                .map(|(label, value)| PieSlice::new(*label, *value)),
+                        ),
+                        cx,
+                    )),
+            )
+    }
+}
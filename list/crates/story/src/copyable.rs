@@ -0,0 +1,126 @@
+use std::{any::TypeId, collections::HashMap, rc::Rc};
+
+use gpui::{AnyView, AppContext, ClipboardItem, Global, SharedString, WindowContext};
+use ui::table::{Table, TableDelegate};
+
+/// The selected rows of a story to copy, with the headers of the columns.
+#[derive(Debug, Clone, Default)]
+pub struct CopiedRows {
+    pub headers: Vec<SharedString>,
+    pub rows: Vec<Vec<SharedString>>,
+}
+
+impl CopiedRows {
+    pub fn new(headers: Vec<SharedString>, rows: Vec<Vec<SharedString>>) -> Self {
+        Self { headers, rows }
+    }
+
+    /// Returns the rows as TSV, the tabs and line breaks in the fields are replaced by spaces.
+    ///
+    /// The headers are included as the first line if there are more than one column.
+    pub fn to_tsv(&self) -> String {
+        let line = |fields: &[SharedString]| {
+            fields
+                .iter()
+                .map(|field| field.replace(['\t', '\r', '\n'], " "))
+                .collect::<Vec<_>>()
+                .join("\t")
+        };
+
+        let mut lines = vec![];
+        if self.headers.len() > 1 {
+            lines.push(line(&self.headers));
+        }
+        lines.extend(self.rows.iter().map(|row| line(row)));
+        lines.join("\n")
+    }
+
+    /// Returns the rows as a JSON array of objects keyed by the headers.
+    pub fn to_json(&self) -> String {
+        let rows = self
+            .rows
+            .iter()
+            .map(|row| {
+                self.headers
+                    .iter()
+                    .zip(row.iter())
+                    .map(|(header, field)| (header.to_string(), field.to_string().into()))
+                    .collect::<serde_json::Map<_, _>>()
+            })
+            .collect::<Vec<_>>();
+
+        serde_json::to_string_pretty(&rows).unwrap_or_default()
+    }
+}
+
+/// Returns the selected row of the table with all the columns, or the selected column with all the rows.
+///
+/// The `text` returns the text of the cell by the row and column index.
+pub(crate) fn table_selection<D: TableDelegate>(
+    table: &Table<D>,
+    text: impl Fn(&D, usize, usize) -> SharedString,
+) -> Option<CopiedRows> {
+    let delegate = table.delegate();
+
+    if let Some(row_ix) = table.selected_row() {
+        let cols = 0..delegate.cols_count();
+        let headers = cols
+            .clone()
+            .map(|col_ix| delegate.col_name(col_ix))
+            .collect();
+        let row = cols.map(|col_ix| text(delegate, row_ix, col_ix)).collect();
+        return Some(CopiedRows::new(headers, vec![row]));
+    }
+
+    let col_ix = table.selected_col()?;
+    let rows = (0..delegate.rows_count())
+        .map(|row_ix| vec![text(delegate, row_ix, col_ix)])
+        .collect();
+    Some(CopiedRows::new(vec![delegate.col_name(col_ix)], rows))
+}
+
+/// A story that can copy its selection to the clipboard, by `cmd-c` as TSV or `cmd-shift-c` as JSON.
+pub trait CopyableStory: 'static + Sized {
+    /// Returns the selected rows to copy, `None` if there is nothing selected.
+    fn copy_selection(&self, cx: &AppContext) -> Option<CopiedRows>;
+}
+
+/// The registry of the copyable stories by the view type.
+#[derive(Default)]
+struct CopyableRegistry {
+    items: HashMap<TypeId, Rc<dyn Fn(&AnyView, &AppContext) -> Option<CopiedRows>>>,
+}
+
+impl Global for CopyableRegistry {}
+
+/// Register a story view type, to copy its selection by the [`crate::CopySelection`] actions.
+pub fn register_copyable<S: CopyableStory>(cx: &mut AppContext) {
+    cx.default_global::<CopyableRegistry>().items.insert(
+        TypeId::of::<S>(),
+        Rc::new(|view, cx| {
+            view.clone()
+                .downcast::<S>()
+                .ok()
+                .and_then(|view| view.read(cx).copy_selection(cx))
+        }),
+    );
+}
+
+/// Copy the selection of the story view to the clipboard, returns false if there is nothing to copy.
+pub(crate) fn copy_selection(view: &AnyView, as_json: bool, cx: &mut WindowContext) -> bool {
+    let Some(rows) = cx
+        .try_global::<CopyableRegistry>()
+        .and_then(|registry| registry.items.get(&view.entity_type()))
+        .and_then(|copy| copy(view, cx))
+    else {
+        return false;
+    };
+
+    let text = if as_json {
+        rows.to_json()
+    } else {
+        rows.to_tsv()
+    };
+    cx.write_to_clipboard(ClipboardItem::new_string(text));
+    true
+}
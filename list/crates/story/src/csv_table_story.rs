@@ -0,0 +1,137 @@
+use std::path::PathBuf;
+
+use gpui::{
+    div, AnyView, AppContext, IntoElement, ParentElement, Pixels, Render, SharedString, Styled,
+    Task, View, ViewContext, VisualContext as _, WindowContext,
+};
+use ui::{
+    h_flex,
+    table::{ColSort, Table, TableDelegate},
+    v_flex,
+};
+
+use crate::{copyable::table_selection, data_import::ImportedData, CopiedRows, CopyableStory};
+
+/// The delegate to show the [`ImportedData`] in a [`Table`], all columns can be sorted as text.
+pub(crate) struct DataTableDelegate {
+    headers: Vec<SharedString>,
+    rows: Vec<Vec<SharedString>>,
+    sorts: Vec<ColSort>,
+}
+
+impl DataTableDelegate {
+    pub(crate) fn new(data: ImportedData) -> Self {
+        let cols_count = data.headers.len();
+
+        Self {
+            headers: data.headers,
+            rows: data.rows,
+            sorts: vec![ColSort::Default; cols_count],
+        }
+    }
+
+    pub(crate) fn cell(&self, row_ix: usize, col_ix: usize) -> SharedString {
+        self.rows
+            .get(row_ix)
+            .and_then(|row| row.get(col_ix))
+            .cloned()
+            .unwrap_or_default()
+    }
+}
+
+impl TableDelegate for DataTableDelegate {
+    fn cols_count(&self) -> usize {
+        self.headers.len()
+    }
+
+    fn rows_count(&self) -> usize {
+        self.rows.len()
+    }
+
+    fn col_name(&self, col_ix: usize) -> SharedString {
+        self.headers.get(col_ix).cloned().unwrap_or("--".into())
+    }
+
+    fn col_width(&self, _: usize) -> Option<Pixels> {
+        Some(180.0.into())
+    }
+
+    fn col_sort(&self, col_ix: usize) -> Option<ColSort> {
+        self.sorts.get(col_ix).copied()
+    }
+
+    fn perform_sort(&mut self, col_ix: usize, sort: ColSort, _: &mut ViewContext<Table<Self>>) {
+        for (ix, col_sort) in self.sorts.iter_mut().enumerate() {
+            *col_sort = if ix == col_ix { sort } else { ColSort::Default };
+        }
+
+        let field = |row: &Vec<SharedString>| row.get(col_ix).cloned().unwrap_or_default();
+        match sort {
+            ColSort::Ascending => self.rows.sort_by(|a, b| field(a).cmp(&field(b))),
+            ColSort::Descending => self.rows.sort_by(|a, b| field(b).cmp(&field(a))),
+            ColSort::Default => {}
+        }
+    }
+
+    fn render_td(
+        &self,
+        row_ix: usize,
+        col_ix: usize,
+        _: &mut ViewContext<Table<Self>>,
+    ) -> impl IntoElement {
+        self.cell(row_ix, col_ix)
+    }
+}
+
+/// Show the CSV file in a [`Table`], the first line is used as the headers if it looks like one.
+pub struct CsvTableStory {
+    path: PathBuf,
+    table: View<Table<DataTableDelegate>>,
+}
+
+impl CsvTableStory {
+    /// Read and parse the CSV file in the background, to show it in a [`crate::StoryContainer`].
+    pub fn load(path: PathBuf, cx: &mut WindowContext) -> Task<anyhow::Result<AnyView>> {
+        let data = cx
+            .background_executor()
+            .spawn(async move { ImportedData::read(&path) });
+
+        cx.spawn(|mut cx| async move {
+            let data = data.await?;
+            let view = cx.new_view(|cx| Self::new(data, cx))?;
+            Ok(view.into())
+        })
+    }
+
+    fn new(data: ImportedData, cx: &mut ViewContext<Self>) -> Self {
+        let path = data.path.clone();
+        let delegate = DataTableDelegate::new(data);
+        let table = cx.new_view(|cx| Table::new(delegate, cx));
+
+        Self { path, table }
+    }
+}
+
+impl CopyableStory for CsvTableStory {
+    fn copy_selection(&self, cx: &AppContext) -> Option<CopiedRows> {
+        table_selection(self.table.read(cx), DataTableDelegate::cell)
+    }
+}
+
+impl Render for CsvTableStory {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let delegate = self.table.read(cx).delegate();
+
+        v_flex()
+            .size_full()
+            .gap_2()
+            .child(
+                h_flex()
+                    .items_center()
+                    .gap_4()
+                    .child(div().flex_1().child(self.path.display().to_string()))
+                    .child(format!("Total Rows: {}", delegate.rows_count())),
+            )
+            .child(self.table.clone())
+    }
+}
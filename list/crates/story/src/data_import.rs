@@ -0,0 +1,322 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::anyhow;
+use gpui::{SharedString, ViewContext};
+use ui::{
+    file_dialog::{self, OpenOptions},
+    notification::Notification,
+    ContextModal as _,
+};
+
+/// The rows of a CSV or JSON file, with the headers of the columns.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ImportedData {
+    pub path: PathBuf,
+    pub headers: Vec<SharedString>,
+    pub rows: Vec<Vec<SharedString>>,
+}
+
+impl ImportedData {
+    /// Read the file and parse it by the extension, `.json` is parsed as JSON, others as CSV.
+    pub fn read(path: &Path) -> anyhow::Result<Self> {
+        let text = std::fs::read_to_string(path)
+            .map_err(|err| anyhow!("Failed to read {}: {}", path.display(), err))?;
+        let is_json = path
+            .extension()
+            .map_or(false, |ext| ext.eq_ignore_ascii_case("json"));
+
+        let mut data = if is_json {
+            Self::from_json(&text)?
+        } else {
+            Self::from_csv(&text)
+        };
+        data.path = path.to_path_buf();
+        Ok(data)
+    }
+
+    pub fn from_csv(text: &str) -> Self {
+        Self::from_rows(parse_csv(text))
+    }
+
+    /// Parse an array of objects, the keys are the headers, or an array of arrays like the CSV rows.
+    pub fn from_json(text: &str) -> anyhow::Result<Self> {
+        let text = text.strip_prefix('\u{feff}').unwrap_or(text);
+        let value: serde_json::Value = serde_json::from_str(text)?;
+        let Some(items) = value.as_array() else {
+            return Err(anyhow!("Expected an array of objects or arrays."));
+        };
+
+        if items.iter().all(|item| item.is_object()) {
+            let mut headers: Vec<SharedString> = vec![];
+            for item in items.iter().filter_map(|item| item.as_object()) {
+                for key in item.keys() {
+                    if !headers.iter().any(|header| header.as_ref() == key) {
+                        headers.push(key.clone().into());
+                    }
+                }
+            }
+
+            let rows = items
+                .iter()
+                .filter_map(|item| item.as_object())
+                .map(|item| {
+                    headers
+                        .iter()
+                        .map(|header| item.get(header.as_ref()).map(json_text).unwrap_or_default())
+                        .collect()
+                })
+                .collect();
+
+            return Ok(Self {
+                path: PathBuf::new(),
+                headers,
+                rows,
+            });
+        }
+
+        let rows = items
+            .iter()
+            .map(|item| match item.as_array() {
+                Some(fields) => Ok(fields.iter().map(json_text).collect()),
+                None => Err(anyhow!("Expected an array of objects or arrays.")),
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        Ok(Self::from_rows(rows))
+    }
+
+    /// Use the first row as the headers if it looks like one, see [`is_header_row`].
+    ///
+    /// The missing headers are named by the column number, e.g.: "Column 3".
+    fn from_rows(mut rows: Vec<Vec<SharedString>>) -> Self {
+        let headers = if is_header_row(&rows) {
+            rows.remove(0)
+        } else {
+            vec![]
+        };
+
+        let cols_count = rows.iter().map(Vec::len).fold(headers.len(), usize::max);
+        let headers = (0..cols_count)
+            .map(|ix| {
+                headers
+                    .get(ix)
+                    .cloned()
+                    .unwrap_or_else(|| format!("Column {}", ix + 1).into())
+            })
+            .collect();
+
+        Self {
+            path: PathBuf::new(),
+            headers,
+            rows,
+        }
+    }
+
+    /// Returns the index of the column by the header name, case insensitive.
+    pub fn col_ix(&self, name: &str) -> Option<usize> {
+        self.headers
+            .iter()
+            .position(|header| header.trim().eq_ignore_ascii_case(name))
+    }
+}
+
+/// The first row is the headers, if all the fields are unique and not empty or numbers.
+fn is_header_row(rows: &[Vec<SharedString>]) -> bool {
+    let Some(first) = rows.first() else {
+        return false;
+    };
+
+    first.iter().enumerate().all(|(ix, field)| {
+        let field = field.trim();
+        !field.is_empty()
+            && field.parse::<f64>().is_err()
+            && !first[..ix].iter().any(|other| other.trim() == field)
+    })
+}
+
+fn json_text(value: &serde_json::Value) -> SharedString {
+    match value {
+        serde_json::Value::Null => SharedString::default(),
+        serde_json::Value::String(s) => s.clone().into(),
+        value => value.to_string().into(),
+    }
+}
+
+/// Parse the CSV text into the rows of fields.
+///
+/// The quoted fields may contain the `,`, the line breaks and the escaped quotes `""`.
+/// The blank lines and the UTF-8 BOM at the start are skipped.
+fn parse_csv(text: &str) -> Vec<Vec<SharedString>> {
+    let text = text.strip_prefix('\u{feff}').unwrap_or(text);
+    let mut rows = vec![];
+    let mut row: Vec<SharedString> = vec![];
+    let mut field = String::new();
+    let mut quoted = false;
+    // The current field has been quoted, so a line with only `""` is not blank.
+    let mut field_quoted = false;
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted => {
+                if chars.peek() == Some(&'"') {
+                    chars.next();
+                    field.push('"');
+                } else {
+                    quoted = false;
+                }
+            }
+            '"' if field.is_empty() => {
+                quoted = true;
+                field_quoted = true;
+            }
+            ',' if !quoted => {
+                row.push(std::mem::take(&mut field).into());
+                field_quoted = false;
+            }
+            '\r' if !quoted => {}
+            '\n' if !quoted => {
+                if row.is_empty() && field.is_empty() && !field_quoted {
+                    continue;
+                }
+                row.push(std::mem::take(&mut field).into());
+                rows.push(std::mem::take(&mut row));
+                field_quoted = false;
+            }
+            _ => field.push(c),
+        }
+    }
+
+    if !field.is_empty() || !row.is_empty() || field_quoted {
+        row.push(field.into());
+        rows.push(row);
+    }
+
+    rows
+}
+
+/// Open the file dialog to pick a CSV or JSON file, and call `on_import` with the parsed data.
+///
+/// A notification is shown if the file can't be parsed.
+pub(crate) fn import_data<V: 'static>(
+    cx: &mut ViewContext<V>,
+    on_import: impl FnOnce(&mut V, ImportedData, &mut ViewContext<V>) + 'static,
+) {
+    let paths = file_dialog::prompt_open(OpenOptions::file().extensions(&["csv", "json"]), cx);
+
+    cx.spawn(|view, mut cx| async move {
+        let result = match paths.await {
+            Ok(paths) => {
+                let Some(path) = paths.into_iter().next() else {
+                    return;
+                };
+
+                cx.background_executor()
+                    .spawn(async move { ImportedData::read(&path) })
+                    .await
+            }
+            Err(err) => Err(err),
+        };
+
+        view.update(&mut cx, |view, cx| match result {
+            Ok(data) => on_import(view, data, cx),
+            Err(err) => cx.push_notification(Notification::error(format!(
+                "Failed to import data: {}",
+                err
+            ))),
+        })
+        .ok();
+    })
+    .detach();
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::SharedString;
+
+    use super::{parse_csv, ImportedData};
+
+    fn fields(fields: &[&str]) -> Vec<SharedString> {
+        fields
+            .iter()
+            .map(|field| SharedString::from(field.to_string()))
+            .collect()
+    }
+
+    fn rows(rows: &[&[&str]]) -> Vec<Vec<SharedString>> {
+        rows.iter().map(|row| fields(row)).collect()
+    }
+
+    #[test]
+    fn test_parse_csv() {
+        assert_eq!(
+            parse_csv("name,price\nApple,1.5\nBanana,2\n"),
+            rows(&[&["name", "price"], &["Apple", "1.5"], &["Banana", "2"]])
+        );
+
+        // The quoted fields with the commas, the line breaks and the escaped quotes.
+        assert_eq!(
+            parse_csv("\"a, b\",\"line 1\nline 2\",\"say \"\"hi\"\"\"\n"),
+            rows(&[&["a, b", "line 1\nline 2", "say \"hi\""]])
+        );
+
+        // CRLF line endings, and the last line without a line break.
+        assert_eq!(
+            parse_csv("a,b\r\n1,2\r\n3,4"),
+            rows(&[&["a", "b"], &["1", "2"], &["3", "4"]])
+        );
+
+        // The blank lines are skipped, but not a line with an empty quoted field.
+        assert_eq!(
+            parse_csv("a,b\n\n1,2\r\n\r\n\"\"\n\n"),
+            rows(&[&["a", "b"], &["1", "2"], &[""]])
+        );
+
+        // The BOM is not a part of the first header.
+        assert_eq!(
+            parse_csv("\u{feff}name\nApple"),
+            rows(&[&["name"], &["Apple"]])
+        );
+        assert!(parse_csv("").is_empty());
+        assert!(parse_csv("\n\r\n").is_empty());
+    }
+
+    #[test]
+    fn test_from_csv_headers() {
+        let data = ImportedData::from_csv("\u{feff}Name,Price\nApple,1.5\n\nBanana,2,extra\n");
+        assert_eq!(data.headers, fields(&["Name", "Price", "Column 3"]));
+        assert_eq!(data.rows.len(), 2);
+        assert_eq!(data.col_ix("name"), Some(0));
+        assert_eq!(data.col_ix("price"), Some(1));
+
+        // The first row with a number is data, not the headers.
+        let data = ImportedData::from_csv("Apple,1.5\nBanana,2\n");
+        assert_eq!(data.headers, fields(&["Column 1", "Column 2"]));
+        assert_eq!(data.rows.len(), 2);
+
+        // The duplicate or empty names are not the headers.
+        let data = ImportedData::from_csv("a,a\n1,2\n");
+        assert_eq!(data.headers, fields(&["Column 1", "Column 2"]));
+        let data = ImportedData::from_csv("a,\n1,2\n");
+        assert_eq!(data.rows.len(), 2);
+    }
+
+    #[test]
+    fn test_from_json() {
+        let data = ImportedData::from_json(
+            r#"[{"name": "Apple", "price": 1.5}, {"name": "Banana", "stock": null}]"#,
+        )
+        .unwrap();
+        assert_eq!(data.headers, fields(&["name", "price", "stock"]));
+        assert_eq!(
+            data.rows,
+            rows(&[&["Apple", "1.5", ""], &["Banana", "", ""]])
+        );
+
+        let data = ImportedData::from_json(r#"[["name", "price"], ["Apple", 1.5]]"#).unwrap();
+        assert_eq!(data.headers, fields(&["name", "price"]));
+        assert_eq!(data.rows, rows(&[&["Apple", "1.5"]]));
+
+        assert!(ImportedData::from_json(r#"{"name": "Apple"}"#).is_err());
+    }
+}
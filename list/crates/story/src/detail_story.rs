@@ -0,0 +1,71 @@
+use gpui::{
+    div, IntoElement, ParentElement, Render, Styled, Subscription, View, ViewContext,
+    VisualContext as _, WindowContext,
+};
+use ui::{h_flex, label::Label, theme::ActiveTheme as _, v_flex, StyledExt as _};
+
+use crate::{CompanySelected, EventBus};
+
+/// Shows the details of the company selected in the [`crate::ListStory`], by the [`EventBus`].
+pub struct DetailStory {
+    company: Option<CompanySelected>,
+    _subscription: Subscription,
+}
+
+impl DetailStory {
+    pub fn view(cx: &mut WindowContext) -> View<Self> {
+        cx.new_view(Self::new)
+    }
+
+    fn new(cx: &mut ViewContext<Self>) -> Self {
+        let _subscription =
+            EventBus::subscribe(cx, |this: &mut Self, event: &CompanySelected, cx| {
+                this.company = Some(event.clone());
+                cx.notify();
+            });
+
+        Self {
+            company: None,
+            _subscription,
+        }
+    }
+}
+
+impl Render for DetailStory {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let Some(company) = self.company.as_ref() else {
+            return div()
+                .text_sm()
+                .text_color(cx.theme().tokens.on_surface_muted)
+                .child("Select a company in the List story to show the details here.");
+        };
+
+        let change_color = if company.change_percent >= 0.0 {
+            ui::red_500()
+        } else {
+            ui::green_500()
+        };
+
+        div().child(
+            v_flex()
+                .gap_2()
+                .child(Label::new(company.name.clone()).text_xl().font_semibold())
+                .child(
+                    Label::new(company.industry.clone())
+                        .text_color(cx.theme().tokens.on_surface_muted),
+                )
+                .child(
+                    h_flex()
+                        .gap_3()
+                        .items_center()
+                        .child(div().text_lg().child(format!("{:.2}", company.last_done)))
+                        .child(
+                            div()
+                                .text_sm()
+                                .text_color(change_color)
+                                .child(format!("{:+.2}%", company.change_percent * 100.)),
+                        ),
+                ),
+        )
+    }
+}
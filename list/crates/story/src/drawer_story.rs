@@ -0,0 +1,122 @@
+use gpui::{
+    div, px, FocusHandle, FocusableView, IntoElement, ParentElement, Render, Styled, View,
+    ViewContext, VisualContext as _, WindowContext,
+};
+
+use ui::{
+    button::Button, checkbox::Checkbox, h_flex, theme::ActiveTheme as _, v_flex, ContextModal as _,
+    Placement,
+};
+
+use crate::section;
+
+pub struct DrawerStory {
+    focus_handle: FocusHandle,
+    overlay: bool,
+    resizable: bool,
+}
+
+impl DrawerStory {
+    pub fn view(cx: &mut WindowContext) -> View<Self> {
+        cx.new_view(Self::new)
+    }
+
+    fn new(cx: &mut ViewContext<Self>) -> Self {
+        Self {
+            focus_handle: cx.focus_handle(),
+            overlay: true,
+            resizable: true,
+        }
+    }
+
+    fn open_drawer_at(&mut self, placement: Placement, cx: &mut ViewContext<Self>) {
+        let overlay = self.overlay;
+        let resizable = self.resizable;
+        let (size, min_size, max_size) = match placement {
+            Placement::Left | Placement::Right => (360., 240., 640.),
+            Placement::Top | Placement::Bottom => (240., 120., 480.),
+        };
+
+        cx.open_drawer(move |drawer, cx| {
+            drawer
+                .margin_top(px(33.))
+                .placement(placement)
+                .overlay(overlay)
+                .resizable(resizable)
+                .size(px(size))
+                .min_size(px(min_size))
+                .max_size(px(max_size))
+                .title(format!("{:?} Drawer", placement))
+                .gap_3()
+                .child(
+                    div()
+                        .text_sm()
+                        .text_color(cx.theme().tokens.on_surface_muted)
+                        .child(if resizable {
+                            format!(
+                                "Drag the inner edge to resize between {}px and {}px.",
+                                min_size, max_size
+                            )
+                        } else {
+                            "This drawer is not resizable.".to_string()
+                        }),
+                )
+                .footer(
+                    h_flex().gap_6().items_center().child(
+                        Button::new("close", cx)
+                            .label("Close")
+                            .on_click(|_, cx| cx.close_drawer()),
+                    ),
+                )
+        });
+    }
+}
+
+impl FocusableView for DrawerStory {
+    fn focus_handle(&self, _: &gpui::AppContext) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for DrawerStory {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let placements = [
+            ("drawer-left", "Left Drawer...", Placement::Left),
+            ("drawer-top", "Top Drawer...", Placement::Top),
+            ("drawer-right", "Right Drawer...", Placement::Right),
+            ("drawer-bottom", "Bottom Drawer...", Placement::Bottom),
+        ];
+
+        v_flex()
+            .gap_6()
+            .child(
+                h_flex()
+                    .gap_3()
+                    .child(
+                        Checkbox::new("drawer-overlay")
+                            .label("Overlay")
+                            .checked(self.overlay)
+                            .on_click(cx.listener(|this, _, cx| {
+                                this.overlay = !this.overlay;
+                                cx.notify();
+                            })),
+                    )
+                    .child(
+                        Checkbox::new("drawer-resizable")
+                            .label("Resizable")
+                            .checked(self.resizable)
+                            .on_click(cx.listener(|this, _, cx| {
+                                this.resizable = !this.resizable;
+                                cx.notify();
+                            })),
+                    ),
+            )
+            .child(section("Placement", cx).child(h_flex().gap_3().children(
+                placements.into_iter().map(|(id, label, placement)| {
+                    Button::new(id, cx).label(label).on_click(
+                        cx.listener(move |this, _, cx| this.open_drawer_at(placement, cx)),
+                    )
+                }),
+            )))
+    }
+}
@@ -1,10 +1,12 @@
+use std::time::Duration;
+
 use gpui::{
     actions, px, AppContext, InteractiveElement, IntoElement, KeyBinding, ParentElement, Render,
     SharedString, Styled, View, ViewContext, VisualContext, WindowContext,
 };
 
 use ui::{
-    dropdown::{Dropdown, DropdownEvent, DropdownItem, SearchableVec},
+    dropdown::{AsyncSearchableVec, Dropdown, DropdownEvent, DropdownItem, SearchableVec},
     h_flex,
     theme::ActiveTheme,
     v_flex, FocusableCycle, IconName, Sizable,
@@ -20,6 +22,23 @@ pub fn init(cx: &mut AppContext) {
     ])
 }
 
+/// The crate names to search in the async dropdown.
+const CRATES: [&str; 12] = [
+    "anyhow",
+    "chrono",
+    "clap",
+    "gpui",
+    "rand",
+    "regex",
+    "reqwest",
+    "serde",
+    "serde_json",
+    "smallvec",
+    "tokio",
+    "uuid",
+];
+
+#[derive(Clone)]
 struct Country {
     name: SharedString,
     code: SharedString,
@@ -49,6 +68,8 @@ impl DropdownItem for Country {
 pub struct DropdownStory {
     country_dropdown: View<Dropdown<Vec<Country>>>,
     fruit_dropdown: View<Dropdown<SearchableVec<SharedString>>>,
+    grouped_dropdown: View<Dropdown<SearchableVec<Country>>>,
+    async_dropdown: View<Dropdown<AsyncSearchableVec<SharedString>>>,
     simple_dropdown1: View<Dropdown<Vec<SharedString>>>,
     simple_dropdown2: View<Dropdown<Vec<SharedString>>>,
     simple_dropdown3: View<Dropdown<Vec<SharedString>>>,
@@ -90,6 +111,64 @@ impl DropdownStory {
                 .menu_width(px(320.))
         });
 
+        let continents = SearchableVec::grouped([
+            (
+                "Asia",
+                vec![
+                    Country::new("China", "CN"),
+                    Country::new("India", "IN"),
+                    Country::new("Japan", "JP"),
+                ],
+            ),
+            (
+                "Europe",
+                vec![
+                    Country::new("France", "FR"),
+                    Country::new("Germany", "DE"),
+                    Country::new("Italy", "IT"),
+                ],
+            ),
+            (
+                "South America",
+                vec![
+                    Country::new("Argentina", "AR"),
+                    Country::new("Brazil", "BR"),
+                    Country::new("Chile", "CL"),
+                ],
+            ),
+        ]);
+        let grouped_dropdown = cx.new_view(|cx| {
+            Dropdown::new("dropdown-grouped", continents, None, cx)
+                .width(px(200.))
+                .placeholder("Grouped")
+        });
+
+        // Simulate to search the crates from a remote API.
+        let crates = AsyncSearchableVec::new(|query, cx| {
+            let query = query.to_lowercase();
+            let timer = cx.background_executor().timer(Duration::from_millis(300));
+            cx.background_executor().spawn(async move {
+                timer.await;
+                CRATES
+                    .iter()
+                    .filter(|name| name.contains(&query))
+                    .map(|name| SharedString::from(*name))
+                    .collect()
+            })
+        });
+        let async_dropdown = cx.new_view(|cx| {
+            Dropdown::new("dropdown-async", crates, None, cx)
+                .width(px(200.))
+                .placeholder("Search crates")
+                .empty(|cx| {
+                    h_flex()
+                        .h_24()
+                        .justify_center()
+                        .text_color(cx.theme().tokens.on_surface_muted)
+                        .child("Type to search crates")
+                })
+        });
+
         cx.new_view(|cx| {
             cx.subscribe(&country_dropdown, Self::on_dropdown_event)
                 .detach();
@@ -97,6 +176,8 @@ impl DropdownStory {
             Self {
                 country_dropdown,
                 fruit_dropdown,
+                grouped_dropdown,
+                async_dropdown,
                 simple_dropdown1: cx.new_view(|cx| {
                     Dropdown::new(
                         "string-list1",
@@ -131,7 +212,7 @@ impl DropdownStory {
                             h_flex()
                                 .h_24()
                                 .justify_center()
-                                .text_color(cx.theme().muted_foreground)
+                                .text_color(cx.theme().tokens.on_surface_muted)
                                 .child("No Data")
                         })
                 }),
@@ -174,6 +255,8 @@ impl FocusableCycle for DropdownStory {
         vec![
             self.country_dropdown.focus_handle(cx),
             self.fruit_dropdown.focus_handle(cx),
+            self.grouped_dropdown.focus_handle(cx),
+            self.async_dropdown.focus_handle(cx),
             self.simple_dropdown1.focus_handle(cx),
             self.simple_dropdown2.focus_handle(cx),
             self.simple_dropdown3.focus_handle(cx),
@@ -198,15 +281,24 @@ impl Render for DropdownStory {
                     .child(self.country_dropdown.clone())
                     .child(self.fruit_dropdown.clone()),
             )
+            .child(
+                h_flex()
+                    .w_full()
+                    .max_w(px(640.))
+                    .items_center()
+                    .gap_4()
+                    .child(self.grouped_dropdown.clone())
+                    .child(self.async_dropdown.clone()),
+            )
             .child(
                 v_flex()
                     .w_full()
                     .items_center()
                     .p_10()
                     .rounded_lg()
-                    .bg(cx.theme().card)
+                    .bg(cx.theme().tokens.surface_raised)
                     .border_1()
-                    .border_color(cx.theme().border)
+                    .border_color(cx.theme().tokens.outline)
                     .gap_4()
                     .child(format!(
                         "Country: {:?}",
@@ -216,6 +308,14 @@ impl Render for DropdownStory {
                         "fruit: {:?}",
                         self.fruit_dropdown.read(cx).selected_value()
                     ))
+                    .child(format!(
+                        "Grouped: {:?}",
+                        self.grouped_dropdown.read(cx).selected_value()
+                    ))
+                    .child(format!(
+                        "Crate: {:?}",
+                        self.async_dropdown.read(cx).selected_value()
+                    ))
                     .child(format!(
                         "UI: {:?}",
                         self.simple_dropdown1.read(cx).selected_value()
@@ -0,0 +1,86 @@
+use std::{
+    any::{Any, TypeId},
+    cell::Cell,
+    collections::HashMap,
+    rc::Rc,
+};
+
+use gpui::{AppContext, Global, Subscription, ViewContext};
+
+/// Returns false if the subscriber has been released.
+type Listener = Rc<dyn Fn(&dyn Any, &mut AppContext) -> bool>;
+
+/// The typed events published by a story to the other stories, even in the other windows.
+///
+/// e.g.: a detail panel shows the company selected in the [`crate::ListStory`].
+#[derive(Default)]
+pub struct EventBus {
+    listeners: HashMap<TypeId, Vec<(Rc<Cell<bool>>, Listener)>>,
+}
+
+impl Global for EventBus {}
+
+impl EventBus {
+    /// Subscribe to the events of the type `E`, the listener is removed when the returned
+    /// [`Subscription`] is dropped or the view is released.
+    pub fn subscribe<E: 'static, V: 'static>(
+        cx: &mut ViewContext<V>,
+        listener: impl Fn(&mut V, &E, &mut ViewContext<V>) + 'static,
+    ) -> Subscription {
+        let active = Rc::new(Cell::new(true));
+        let window = cx.window_handle();
+        let view = cx.view().downgrade();
+        let listener: Listener = Rc::new(move |event, cx| {
+            let Some(event) = event.downcast_ref::<E>() else {
+                return true;
+            };
+
+            window
+                .update(cx, |_, cx| {
+                    view.update(cx, |view, cx| listener(view, event, cx))
+                        .is_ok()
+                })
+                .unwrap_or(false)
+        });
+
+        cx.default_global::<EventBus>()
+            .listeners
+            .entry(TypeId::of::<E>())
+            .or_default()
+            .push((active.clone(), listener));
+
+        Subscription::new(move || active.set(false))
+    }
+
+    /// Publish the event to the subscribers, they are called after the current update is finished,
+    /// so the publisher can be updated by the subscribers.
+    pub fn publish<E: 'static>(event: E, cx: &mut AppContext) {
+        cx.defer(move |cx| {
+            let Some(listeners) = cx
+                .try_global::<EventBus>()
+                .and_then(|bus| bus.listeners.get(&TypeId::of::<E>()))
+                .cloned()
+            else {
+                return;
+            };
+
+            let mut has_released = false;
+            for (active, listener) in listeners {
+                if active.get() && !listener(&event, cx) {
+                    active.set(false);
+                }
+                has_released |= !active.get();
+            }
+
+            if has_released {
+                if let Some(listeners) = cx
+                    .global_mut::<EventBus>()
+                    .listeners
+                    .get_mut(&TypeId::of::<E>())
+                {
+                    listeners.retain(|(active, _)| active.get());
+                }
+            }
+        });
+    }
+}
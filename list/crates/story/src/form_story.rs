@@ -0,0 +1,183 @@
+use gpui::{
+    div, px, AppContext, FocusHandle, FocusableView, IntoElement, ParentElement as _, Render,
+    SharedString, Styled, View, ViewContext, VisualContext as _, WindowContext,
+};
+use ui::{
+    button::{Button, ButtonStyle},
+    checkbox::Checkbox,
+    h_flex,
+    input::{InputEvent, TextInput},
+    label::Label,
+    number_input::{NumberInput, NumberInputEvent},
+    radio::RadioGroup,
+    switch::Switch,
+    theme::ActiveTheme as _,
+    v_flex, Disableable as _,
+};
+
+use crate::section;
+
+const PLANS: [&str; 3] = ["Free", "Pro", "Enterprise"];
+
+pub struct FormStory {
+    focus_handle: FocusHandle,
+    name_input: View<TextInput>,
+    email_input: View<TextInput>,
+    seats_input: View<NumberInput>,
+    plan_index: Option<usize>,
+    notifications: bool,
+    agree: bool,
+    /// The last change event, for showing the events are emitted.
+    last_change: SharedString,
+}
+
+impl FormStory {
+    pub fn view(cx: &mut WindowContext) -> View<Self> {
+        cx.new_view(Self::new)
+    }
+
+    fn new(cx: &mut ViewContext<Self>) -> Self {
+        let name_input = cx.new_view(|cx| TextInput::new(cx).placeholder("Your name"));
+        let email_input = cx.new_view(|cx| {
+            TextInput::new(cx)
+                .placeholder("name@example.com")
+                .cleanable()
+        });
+
+        let seats_input = cx.new_view(|cx| {
+            let mut input = NumberInput::new(cx).min(1.).max(100.);
+            input.set_value(1., cx);
+            input
+        });
+
+        cx.subscribe(&name_input, |this, _, event: &InputEvent, cx| {
+            if let InputEvent::Change(text) = event {
+                this.set_last_change(format!("Name: {}", text), cx);
+            }
+        })
+        .detach();
+        cx.subscribe(&email_input, |this, _, event: &InputEvent, cx| {
+            if let InputEvent::Change(text) = event {
+                this.set_last_change(format!("Email: {}", text), cx);
+            }
+        })
+        .detach();
+        cx.subscribe(&seats_input, |this, _, event: &NumberInputEvent, cx| {
+            let NumberInputEvent::Change(value) = event;
+            this.set_last_change(format!("Seats: {}", value), cx);
+        })
+        .detach();
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            name_input,
+            email_input,
+            seats_input,
+            plan_index: Some(0),
+            notifications: true,
+            agree: false,
+            last_change: "".into(),
+        }
+    }
+
+    fn set_last_change(&mut self, change: impl Into<SharedString>, cx: &mut ViewContext<Self>) {
+        self.last_change = change.into();
+        cx.notify();
+    }
+
+    fn submit(&mut self, cx: &mut ViewContext<Self>) {
+        let name = self.name_input.read(cx).text();
+        let email = self.email_input.read(cx).text();
+        let plan = self.plan_index.map_or("None", |ix| PLANS[ix]);
+        let seats = self.seats_input.read(cx).value();
+
+        self.set_last_change(
+            format!(
+                "Submitted: {} <{}>, plan: {} x {}, notifications: {}",
+                name, email, plan, seats, self.notifications
+            ),
+            cx,
+        );
+    }
+}
+
+impl FocusableView for FormStory {
+    fn focus_handle(&self, _: &AppContext) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for FormStory {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        fn field(label: &'static str, input: impl IntoElement) -> impl IntoElement {
+            v_flex()
+                .gap_1()
+                .w_full()
+                .child(Label::new(label).text_sm())
+                .child(input)
+        }
+
+        v_flex()
+            .gap_6()
+            .max_w(px(600.))
+            .child(
+                section("Account", cx)
+                    .child(field("Name", self.name_input.clone()))
+                    .child(field("Email", self.email_input.clone())),
+            )
+            .child(
+                section("Plan", cx)
+                    .child(
+                        RadioGroup::horizontal("plan")
+                            .children(PLANS)
+                            .selected_index(self.plan_index)
+                            .on_change(cx.listener(|this, ix: &usize, cx| {
+                                this.plan_index = Some(*ix);
+                                this.set_last_change(format!("Plan: {}", PLANS[*ix]), cx);
+                            })),
+                    )
+                    .child(field(
+                        "Seats",
+                        div().w(px(160.)).child(self.seats_input.clone()),
+                    )),
+            )
+            .child(
+                section("Preferences", cx)
+                    .child(
+                        Switch::new("notifications")
+                            .label("Email notifications")
+                            .checked(self.notifications)
+                            .on_click(cx.listener(|this, checked: &bool, cx| {
+                                this.notifications = *checked;
+                                this.set_last_change(format!("Notifications: {}", checked), cx);
+                            })),
+                    )
+                    .child(
+                        Checkbox::new("agree")
+                            .label("I agree to the terms of service")
+                            .checked(self.agree)
+                            .on_click(cx.listener(|this, checked: &bool, cx| {
+                                this.agree = *checked;
+                                this.set_last_change(format!("Agree: {}", checked), cx);
+                            })),
+                    ),
+            )
+            .child(
+                h_flex()
+                    .gap_3()
+                    .child(
+                        Button::new("submit", cx)
+                            .label("Submit")
+                            .style(ButtonStyle::Primary)
+                            .disabled(!self.agree)
+                            .on_click(cx.listener(|this, _, cx| this.submit(cx))),
+                    )
+                    .child(
+                        div()
+                            .text_sm()
+                            .text_color(cx.theme().tokens.on_surface_muted)
+                            .child(self.last_change.clone()),
+                    ),
+            )
+    }
+}
@@ -0,0 +1,244 @@
+use fake::Fake as _;
+use gpui::{
+    div, px, AnyElement, AppContext, FocusHandle, FocusableView, IntoElement, ParentElement as _,
+    Render, SharedString, Styled as _, Task, View, ViewContext, VisualContext as _, WindowContext,
+};
+use ui::{
+    button::Button,
+    h_flex,
+    list::{List, ListDelegate, ListGroupHeader, ListGroupRow, ListGroups, ListItem},
+    theme::ActiveTheme as _,
+    v_flex, Sizable as _,
+};
+
+/// The height of the rows of the virtualized list, the headers are in the same height.
+const ROW_HEIGHT: f32 = 32.;
+
+#[derive(Clone)]
+struct Contact {
+    name: SharedString,
+    email: SharedString,
+}
+
+impl Contact {
+    fn random() -> Self {
+        let first_name = fake::faker::name::en::FirstName().fake::<String>();
+        let last_name = fake::faker::name::en::LastName().fake::<String>();
+        Self {
+            email: format!("{}.{}@example.com", first_name, last_name)
+                .to_lowercase()
+                .into(),
+            name: format!("{} {}", first_name, last_name).into(),
+        }
+    }
+
+    /// The group of the contact, the first letter of the name.
+    fn initial(&self) -> String {
+        self.name.chars().take(1).collect::<String>().to_uppercase()
+    }
+}
+
+/// The contacts grouped by the first letter, the groups can be collapsed by clicking the headers.
+struct ContactListDelegate {
+    contacts: Vec<Contact>,
+    groups: ListGroups<Contact>,
+    selected_index: Option<usize>,
+}
+
+impl ContactListDelegate {
+    fn new(contacts: Vec<Contact>) -> Self {
+        Self {
+            groups: ListGroups::group_by(contacts.clone(), Contact::initial),
+            contacts,
+            selected_index: None,
+        }
+    }
+
+    fn set_collapsed_all(&mut self, collapsed: bool) {
+        self.groups.set_collapsed_all(collapsed);
+        self.selected_index = None;
+    }
+}
+
+impl ListDelegate for ContactListDelegate {
+    type Item = AnyElement;
+
+    fn items_count(&self) -> usize {
+        self.groups.rows_count()
+    }
+
+    fn perform_search(&mut self, query: &str, _: &mut ViewContext<List<Self>>) -> Task<()> {
+        let query = query.to_lowercase();
+        let contacts = self
+            .contacts
+            .iter()
+            .filter(|contact| contact.name.to_lowercase().contains(&query))
+            .cloned();
+        self.groups = ListGroups::group_by(contacts, Contact::initial);
+
+        Task::Ready(Some(()))
+    }
+
+    fn render_item(&self, ix: usize, cx: &mut ViewContext<List<Self>>) -> Option<Self::Item> {
+        match self.groups.row(ix)? {
+            ListGroupRow::Header(group) => Some(
+                div()
+                    .h(px(ROW_HEIGHT))
+                    .flex()
+                    .items_center()
+                    .bg(cx.theme().list_head)
+                    .child(ListGroupHeader::from(group))
+                    .into_any_element(),
+            ),
+            ListGroupRow::Item(contact) => Some(
+                ListItem::new(("contact", ix))
+                    .selected(self.selected_index == Some(ix))
+                    .child(
+                        h_flex()
+                            .w_full()
+                            .justify_between()
+                            .child(contact.name.clone())
+                            .child(
+                                div()
+                                    .text_sm()
+                                    .text_color(cx.theme().tokens.on_surface_muted)
+                                    .child(contact.email.clone()),
+                            ),
+                    )
+                    .into_any_element(),
+            ),
+        }
+    }
+
+    fn is_group_header(&self, ix: usize) -> bool {
+        self.groups.is_header(ix)
+    }
+
+    fn can_select(&self, ix: usize) -> bool {
+        !self.groups.is_header(ix)
+    }
+
+    fn toggle_group(&mut self, ix: usize, cx: &mut ViewContext<List<Self>>) {
+        if self.groups.toggle(ix) {
+            cx.notify();
+        }
+    }
+
+    fn set_selected_index(&mut self, ix: Option<usize>, cx: &mut ViewContext<List<Self>>) {
+        self.selected_index = ix;
+        cx.notify();
+    }
+}
+
+/// The lists of the grouped contacts with the sticky group headers.
+pub struct GroupedListStory {
+    focus_handle: FocusHandle,
+    list: View<List<ContactListDelegate>>,
+    virtualized_list: View<List<ContactListDelegate>>,
+}
+
+impl GroupedListStory {
+    pub fn view(cx: &mut WindowContext) -> View<Self> {
+        cx.new_view(Self::new)
+    }
+
+    fn new(cx: &mut ViewContext<Self>) -> Self {
+        let mut contacts = (0..300).map(|_| Contact::random()).collect::<Vec<_>>();
+        contacts.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let list = cx.new_view(|cx| {
+            List::new(ContactListDelegate::new(contacts.clone()), cx).sticky_headers(true)
+        });
+        let virtualized_list = cx.new_view(|cx| {
+            List::new(ContactListDelegate::new(contacts), cx)
+                .no_query()
+                .virtualized(px(ROW_HEIGHT))
+                .sticky_headers(true)
+        });
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            list,
+            virtualized_list,
+        }
+    }
+
+    fn set_collapsed_all(&mut self, collapsed: bool, cx: &mut ViewContext<Self>) {
+        for list in [&self.list, &self.virtualized_list] {
+            list.update(cx, |list, cx| {
+                list.set_selected_index(None, cx);
+                list.delegate_mut().set_collapsed_all(collapsed);
+                cx.notify();
+            });
+        }
+    }
+
+    fn render_list(
+        title: &'static str,
+        list: View<List<ContactListDelegate>>,
+        cx: &mut ViewContext<Self>,
+    ) -> impl IntoElement {
+        v_flex()
+            .flex_1()
+            .gap_2()
+            .child(div().text_sm().child(title))
+            .child(
+                div()
+                    .flex_1()
+                    .min_h(px(300.))
+                    .border_1()
+                    .border_color(cx.theme().tokens.outline)
+                    .rounded_md()
+                    .overflow_hidden()
+                    .child(list),
+            )
+    }
+}
+
+impl FocusableView for GroupedListStory {
+    fn focus_handle(&self, _: &AppContext) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for GroupedListStory {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        v_flex()
+            .size_full()
+            .gap_4()
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        div()
+                            .flex_1()
+                            .text_sm()
+                            .text_color(cx.theme().tokens.on_surface_muted)
+                            .child("The header of a group sticks to the top while its contacts are scrolled, click a header to collapse or expand the group."),
+                    )
+                    .child(
+                        Button::new("collapse-all", cx)
+                            .label("Collapse All")
+                            .small()
+                            .on_click(cx.listener(|this, _, cx| this.set_collapsed_all(true, cx))),
+                    )
+                    .child(
+                        Button::new("expand-all", cx)
+                            .label("Expand All")
+                            .small()
+                            .on_click(cx.listener(|this, _, cx| this.set_collapsed_all(false, cx))),
+                    ),
+            )
+            .child(
+                h_flex()
+                    .flex_1()
+                    .gap_4()
+                    .child(Self::render_list("Searchable", self.list.clone(), cx))
+                    .child(Self::render_list(
+                        "Virtualized",
+                        self.virtualized_list.clone(),
+                        cx,
+                    )),
+            )
+    }
+}
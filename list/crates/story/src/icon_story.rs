@@ -52,8 +52,8 @@ impl Render for IconStory {
                     Icon::new(IconName::Plus)
                         .w(rems(3.))
                         .h(rems(3.))
-                        .bg(cx.theme().primary)
-                        .text_color(cx.theme().primary_foreground)
+                        .bg(cx.theme().tokens.accent)
+                        .text_color(cx.theme().tokens.on_accent)
                         .rounded(px(32.)),
                 ),
         )
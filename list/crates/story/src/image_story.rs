@@ -1,4 +1,5 @@
-use gpui::{px, ParentElement as _, Render, Styled, View, VisualContext as _, WindowContext};
+use anyhow::anyhow;
+use gpui::{px, AnyView, ParentElement as _, Render, Styled, Task, WindowContext};
 use ui::{h_flex, svg_img, v_flex, SvgImg};
 
 const GOOGLE_LOGO: &str = include_str!("./fixtures/google.svg");
@@ -11,18 +12,29 @@ pub struct ImageStory {
 }
 
 impl ImageStory {
-    pub fn new(_: &WindowContext) -> Self {
-        let chart = charts_rs::PieChart::from_json(PIE_JSON).unwrap();
-
+    fn new(pie_chart_svg: String) -> Self {
         Self {
             google_logo: svg_img().source(GOOGLE_LOGO.as_bytes(), px(300.), px(300.)),
-            pie_chart: svg_img().source(chart.svg().unwrap().as_bytes(), px(400.), px(400.)),
+            pie_chart: svg_img().source(pie_chart_svg.as_bytes(), px(400.), px(400.)),
             inbox_img: svg_img().source("icons/inbox.svg", px(300.), px(300.)),
         }
     }
 
-    pub fn view(cx: &mut WindowContext) -> View<Self> {
-        cx.new_view(|cx| Self::new(cx))
+    /// Render the chart in the background, then create the view.
+    pub fn load(cx: &mut WindowContext) -> Task<anyhow::Result<AnyView>> {
+        let pie_chart = cx.background_executor().spawn(async move {
+            let chart = charts_rs::PieChart::from_json(PIE_JSON)
+                .map_err(|err| anyhow!("Invalid chart data: {:?}", err))?;
+            chart
+                .svg()
+                .map_err(|err| anyhow!("Failed to render chart: {:?}", err))
+        });
+
+        cx.spawn(|mut cx| async move {
+            let pie_chart_svg = pie_chart.await?;
+            let view = cx.new_view(|_| Self::new(pie_chart_svg))?;
+            Ok(view.into())
+        })
     }
 }
 
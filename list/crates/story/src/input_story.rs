@@ -1,6 +1,6 @@
 use gpui::{actions, div, px, AppContext, FocusHandle, InteractiveElement, IntoElement, KeyBinding, ParentElement as _, Render, SharedString, Styled, View, ViewContext, VisualContext, WindowContext};
 
-use crate::section;
+use crate::{section, UnsavedStory};
 use ui::{
     button::Button,
     checkbox::Checkbox,
@@ -28,6 +28,8 @@ pub fn init(cx: &mut AppContext) {
 
 pub struct InputStory {
     input1: View<TextInput>,
+    /// The saved text of the `input1`, to confirm the unsaved changes before closing.
+    saved_text: SharedString,
     input2: View<TextInput>,
     mash_input: View<TextInput>,
     disabled_input: View<TextInput>,
@@ -60,6 +62,7 @@ impl InputStory {
         });
 
         cx.subscribe(&input1, Self::on_input_event).detach();
+        let saved_text = input1.read(cx).text();
 
         let input2 = cx.new_view(|cx| TextInput::new(cx).placeholder("Enter text here..."));
 
@@ -105,6 +108,7 @@ impl InputStory {
 
         Self {
             input1,
+            saved_text,
             input2,
             mash_input: mask_input,
             disabled_input: cx.new_view(|cx| {
@@ -293,3 +297,13 @@ impl Render for InputStory {
             )
     }
 }
+
+impl UnsavedStory for InputStory {
+    fn has_unsaved_changes(&self, cx: &AppContext) -> bool {
+        self.input1.read(cx).text() != self.saved_text
+    }
+
+    fn save(&mut self, cx: &mut ViewContext<Self>) {
+        self.saved_text = self.input1.read(cx).text();
+    }
+}
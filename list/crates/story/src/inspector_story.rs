@@ -0,0 +1,237 @@
+use gpui::{
+    div, px, AbsoluteLength, DefiniteLength, FocusHandle, FocusableView, Hsla, IntoElement, Length,
+    ParentElement, Pixels, Render, SharedString, Styled, Subscription, View, ViewContext,
+    VisualContext as _, WindowContext,
+};
+use ui::{
+    h_flex,
+    inspector::{InspectedElement, Inspector},
+    theme::{ActiveTheme as _, Colorize as _},
+    v_flex, StyledExt as _,
+};
+
+use crate::section;
+
+/// Show the bounds and the style of the hovered ui component,
+/// the colors are resolved to the names of the theme.
+pub struct InspectorStory {
+    focus_handle: FocusHandle,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl InspectorStory {
+    pub fn view(cx: &mut WindowContext) -> View<Self> {
+        cx.new_view(Self::new)
+    }
+
+    fn new(cx: &mut ViewContext<Self>) -> Self {
+        Inspector::enable(cx);
+        let _subscriptions = vec![
+            cx.observe_global::<Inspector>(|_, cx| cx.notify()),
+            cx.on_release(|_, _, cx| Inspector::disable(cx)),
+        ];
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            _subscriptions,
+        }
+    }
+
+    /// Returns the hex of the color, with the name of the theme color if it's the same.
+    fn color_name(color: Hsla, cx: &WindowContext) -> String {
+        match cx
+            .theme()
+            .colors()
+            .into_iter()
+            .find(|(_, theme_color)| *theme_color == color)
+        {
+            Some((name, _)) => format!("{} ({})", color.to_hex_string(), name),
+            None => color.to_hex_string(),
+        }
+    }
+
+    /// Format the top, right, bottom and left edges, `None` if none of them is set.
+    fn format_edges(edges: [Option<String>; 4]) -> Option<String> {
+        if edges.iter().all(Option::is_none) {
+            return None;
+        }
+
+        Some(
+            edges
+                .map(|edge| edge.unwrap_or_else(|| "-".into()))
+                .join(" "),
+        )
+    }
+
+    fn format_pixels(pixels: Pixels) -> String {
+        format!("{}px", (pixels.0 * 10.).round() / 10.)
+    }
+
+    fn properties(element: &InspectedElement, cx: &WindowContext) -> Vec<(&'static str, String)> {
+        let style = &element.style;
+        let rem_size = element.rem_size;
+        // The percentages of the padding and margin are relative to the width.
+        let base = AbsoluteLength::Pixels(element.bounds.size.width);
+        let definite = |length: Option<DefiniteLength>| {
+            length.map(|length| Self::format_pixels(length.to_pixels(base, rem_size)))
+        };
+        let length = |length: Option<Length>| {
+            length.map(|length| match length {
+                Length::Definite(length) => Self::format_pixels(length.to_pixels(base, rem_size)),
+                Length::Auto => "auto".into(),
+            })
+        };
+        let absolute = |length: Option<AbsoluteLength>| {
+            length.map(|length| Self::format_pixels(length.to_pixels(rem_size)))
+        };
+
+        let mut properties = vec![
+            (
+                "Position",
+                format!(
+                    "{}, {}",
+                    Self::format_pixels(element.bounds.origin.x),
+                    Self::format_pixels(element.bounds.origin.y)
+                ),
+            ),
+            (
+                "Size",
+                format!(
+                    "{} x {}",
+                    Self::format_pixels(element.bounds.size.width),
+                    Self::format_pixels(element.bounds.size.height)
+                ),
+            ),
+            ("Rem Size", Self::format_pixels(rem_size)),
+        ];
+
+        let padding = &style.padding;
+        let margin = &style.margin;
+        let border = &style.border_widths;
+        let radii = &style.corner_radii;
+        let edges = [
+            (
+                "Padding",
+                Self::format_edges([
+                    definite(padding.top),
+                    definite(padding.right),
+                    definite(padding.bottom),
+                    definite(padding.left),
+                ]),
+            ),
+            (
+                "Margin",
+                Self::format_edges([
+                    length(margin.top),
+                    length(margin.right),
+                    length(margin.bottom),
+                    length(margin.left),
+                ]),
+            ),
+            (
+                "Border Width",
+                Self::format_edges([
+                    absolute(border.top),
+                    absolute(border.right),
+                    absolute(border.bottom),
+                    absolute(border.left),
+                ]),
+            ),
+            (
+                "Corner Radius",
+                Self::format_edges([
+                    absolute(radii.top_left),
+                    absolute(radii.top_right),
+                    absolute(radii.bottom_right),
+                    absolute(radii.bottom_left),
+                ]),
+            ),
+        ];
+        properties.extend(
+            edges
+                .into_iter()
+                .filter_map(|(name, value)| value.map(|value| (name, value))),
+        );
+
+        let background = style
+            .background
+            .as_ref()
+            .and_then(|fill| fill.color())
+            .copied();
+        let text = style.text.as_ref();
+        let colors = [
+            ("Background", background),
+            ("Border Color", style.border_color),
+            ("Text Color", text.and_then(|text| text.color)),
+        ];
+        properties.extend(
+            colors
+                .into_iter()
+                .filter_map(|(name, color)| color.map(|color| (name, Self::color_name(color, cx)))),
+        );
+        if let Some(font_size) = text.and_then(|text| text.font_size) {
+            properties.push((
+                "Font Size",
+                Self::format_pixels(font_size.to_pixels(rem_size)),
+            ));
+        }
+
+        properties
+    }
+
+    fn render_property(name: &'static str, value: String, cx: &WindowContext) -> impl IntoElement {
+        h_flex()
+            .w_full()
+            .gap_4()
+            .py_1()
+            .border_b_1()
+            .border_color(cx.theme().tokens.outline)
+            .text_sm()
+            .child(
+                div()
+                    .w(px(120.))
+                    .flex_shrink_0()
+                    .text_color(cx.theme().tokens.on_surface_muted)
+                    .child(name),
+            )
+            .child(div().flex_1().child(value))
+    }
+}
+
+impl FocusableView for InspectorStory {
+    fn focus_handle(&self, _: &gpui::AppContext) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for InspectorStory {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let hovered = Inspector::hovered(cx).cloned();
+        let title: SharedString = hovered
+            .as_ref()
+            .map_or("Nothing inspected".into(), |element| element.name.clone());
+
+        v_flex()
+            .gap_4()
+            .child(
+                div()
+                    .text_sm()
+                    .text_color(cx.theme().tokens.on_surface_muted)
+                    .child(
+                        "Hover a Button, Checkbox, Switch, Tab, Tag or Badge to inspect it, \
+                        hold Alt to keep it while moving here.",
+                    ),
+            )
+            .child(
+                section(div().font_semibold().child(title), cx).child(
+                    v_flex().w_full().children(
+                        hovered
+                            .map(|element| Self::properties(&element, cx))
+                            .unwrap_or_default()
+                            .into_iter()
+                            .map(|(name, value)| Self::render_property(name, value, cx)),
+                    ),
+                ),
+            )
+    }
+}
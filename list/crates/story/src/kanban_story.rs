@@ -0,0 +1,147 @@
+use gpui::{
+    div, IntoElement, ParentElement as _, Render, SharedString, Styled as _, View, ViewContext,
+    VisualContext as _, WindowContext,
+};
+use ui::{
+    avatar::Avatar,
+    chart::chart_color,
+    h_flex,
+    kanban::{CardMove, KanbanCard, KanbanColumn},
+    tag::Badge,
+    theme::ActiveTheme as _,
+    v_flex, Sizable as _,
+};
+
+const COLUMNS: [&str; 4] = ["Backlog", "In Progress", "Review", "Done"];
+const LABELS: [&str; 4] = ["Feature", "Bug", "Docs", "Chore"];
+
+struct Task {
+    id: usize,
+    title: SharedString,
+    description: Option<SharedString>,
+    /// The index of the [`LABELS`].
+    label: usize,
+    assignee: SharedString,
+}
+
+impl Task {
+    fn new(id: usize, title: &'static str, label: usize, assignee: &'static str) -> Self {
+        Self {
+            id,
+            title: title.into(),
+            description: None,
+            label,
+            assignee: assignee.into(),
+        }
+    }
+
+    fn description(mut self, description: &'static str) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+}
+
+/// A kanban board of the tasks, drag the cards to reorder them or to move them to the other columns.
+pub struct KanbanStory {
+    /// The tasks of the [`COLUMNS`].
+    columns: Vec<Vec<Task>>,
+    last_move: Option<SharedString>,
+}
+
+impl KanbanStory {
+    pub fn view(cx: &mut WindowContext) -> View<Self> {
+        cx.new_view(|_| Self::new())
+    }
+
+    fn new() -> Self {
+        Self {
+            columns: vec![
+                vec![
+                    Task::new(1, "Add the kanban story", 0, "Jason Lee")
+                        .description("Drag the cards between the columns."),
+                    Task::new(2, "Crash on the empty table", 1, "Natalie Chen"),
+                    Task::new(3, "Document the dock layout", 2, "Marco Rossi"),
+                ],
+                vec![
+                    Task::new(4, "Keyboard navigation of the table", 0, "Aiko Tanaka")
+                        .description("Arrow keys, page, home and end."),
+                    Task::new(5, "Update the dependencies", 3, "Liam Walker"),
+                ],
+                vec![Task::new(6, "Frozen columns", 0, "Sofia Garcia")],
+                vec![
+                    Task::new(7, "Fix the scrollbar flicker", 1, "Noah Smith"),
+                    Task::new(8, "Translate the stories", 2, "Emma Brown"),
+                ],
+            ],
+            last_move: None,
+        }
+    }
+
+    fn move_card(&mut self, card_move: &CardMove, cx: &mut ViewContext<Self>) {
+        let title = self
+            .columns
+            .get(card_move.from_column)
+            .and_then(|tasks| tasks.get(card_move.from_ix))
+            .map(|task| task.title.clone());
+        if !card_move.apply(&mut self.columns) {
+            return;
+        }
+
+        if let Some(title) = title {
+            self.last_move = Some(
+                format!(
+                    "Moved \"{}\" from {} to {}",
+                    title, COLUMNS[card_move.from_column], COLUMNS[card_move.to_column]
+                )
+                .into(),
+            );
+        }
+        cx.notify();
+    }
+
+    fn render_card(task: &Task, cx: &WindowContext) -> KanbanCard {
+        let card = KanbanCard::new(("kanban-card", task.id), task.title.clone())
+            .child(
+                Badge::new(LABELS[task.label])
+                    .color(chart_color(task.label, cx))
+                    .small(),
+            )
+            .child(div().flex_1())
+            .child(Avatar::new(task.assignee.clone()).xsmall());
+
+        match task.description.clone() {
+            Some(description) => card.description(description),
+            None => card,
+        }
+    }
+}
+
+impl Render for KanbanStory {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        v_flex()
+            .size_full()
+            .gap_3()
+            .child(
+                div()
+                    .text_sm()
+                    .text_color(cx.theme().tokens.on_surface_muted)
+                    .child(self.last_move.clone().unwrap_or_else(|| {
+                        "Drag a card to reorder it, or drop it to another column.".into()
+                    })),
+            )
+            .child(
+                h_flex()
+                    .id("kanban-board")
+                    .flex_1()
+                    .items_start()
+                    .gap_3()
+                    .overflow_x_scroll()
+                    .children(self.columns.iter().enumerate().map(|(ix, tasks)| {
+                        KanbanColumn::new(("kanban-column", ix), ix, COLUMNS[ix])
+                            .h_full()
+                            .cards(tasks.iter().map(|task| Self::render_card(task, cx)))
+                            .on_move(cx.listener(Self::move_card))
+                    })),
+            )
+    }
+}
@@ -1,64 +1,141 @@
+mod avatar_story;
+mod badge;
 mod button_story;
 mod calendar_story;
+mod charts_story;
+mod copyable;
+mod csv_table_story;
+mod data_import;
+mod detail_story;
+mod drawer_story;
 mod dropdown_story;
+mod event_bus;
+mod form_story;
+mod grouped_list_story;
 mod icon_story;
 mod image_story;
 mod input_story;
+mod inspector_story;
+mod kanban_story;
 mod list_story;
+mod log_buffer;
+mod logs_story;
+mod metrics_story;
 mod modal_story;
+mod notes_story;
+mod paged_list_story;
+mod popover_story;
 mod popup_story;
+mod profiler;
+mod profiler_story;
 mod progress_story;
 mod resizable_story;
+mod rich_table_story;
 mod scrollable_story;
+mod search;
+mod setup_wizard_story;
+mod source;
+mod story_registry;
 mod switch_story;
 mod table_story;
+mod tag_story;
 mod text_story;
+mod theme_contrast_story;
+mod theme_editor_story;
 mod tooltip_story;
+mod tree_story;
+mod unsaved;
 mod webview_story;
 
-use std::sync::Arc;
+use std::{rc::Rc, sync::Arc};
 
+pub use avatar_story::AvatarStory;
+pub use badge::{register_badge, BadgeStory};
 pub use button_story::ButtonStory;
 pub use calendar_story::CalendarStory;
+pub use charts_story::ChartsStory;
+pub use copyable::{register_copyable, CopiedRows, CopyableStory};
+pub use csv_table_story::CsvTableStory;
+pub use detail_story::DetailStory;
+pub use drawer_story::DrawerStory;
 pub use dropdown_story::DropdownStory;
+pub use event_bus::EventBus;
+pub use form_story::FormStory;
+pub use grouped_list_story::GroupedListStory;
 pub use icon_story::IconStory;
 pub use image_story::ImageStory;
 pub use input_story::InputStory;
-pub use list_story::ListStory;
+pub use inspector_story::InspectorStory;
+pub use kanban_story::KanbanStory;
+pub use list_story::{CompanySelected, ListStory};
+pub use log_buffer::{LogBuffer, LogRecord, DEFAULT_LOG_CAPACITY};
+pub use logs_story::LogsStory;
+pub use metrics_story::MetricsStory;
 pub use modal_story::ModalStory;
+pub use notes_story::NotesStory;
+pub use paged_list_story::PagedListStory;
+pub use popover_story::PopoverStory;
 pub use popup_story::PopupStory;
+pub use profiler::{profiles, reset_profiles, StoryProfile};
+pub use profiler_story::ProfilerStory;
 pub use progress_story::ProgressStory;
 pub use resizable_story::ResizableStory;
+pub use rich_table_story::RichTableStory;
 pub use scrollable_story::ScrollableStory;
+pub use search::{register_searchable, SearchableStory};
+pub use setup_wizard_story::{SetupFinished, SetupWizardStory};
+pub use story_registry::{register_story, DockPosition, StoryBuild, StoryEntry, StoryRegistry};
 pub use switch_story::SwitchStory;
 pub use table_story::TableStory;
+pub use tag_story::TagStory;
 pub use text_story::TextStory;
+pub use theme_contrast_story::ThemeContrastStory;
+pub use theme_editor_story::ThemeEditorStory;
 pub use tooltip_story::TooltipStory;
+pub use tree_story::TreeStory;
+pub use unsaved::{register_unsaved, UnsavedStory};
 pub use webview_story::WebViewStory;
 
 use gpui::{
-    actions, div, prelude::FluentBuilder as _, px, AnyView, AppContext, Div, EventEmitter,
-    FocusableView, InteractiveElement, IntoElement, ParentElement, Pixels, Render, SharedString,
-    StatefulInteractiveElement, Styled as _, View, ViewContext, VisualContext, WindowContext,
+    actions, div, prelude::FluentBuilder as _, px, AnyElement, AnyView, AppContext, Div,
+    EventEmitter, FocusableView, InteractiveElement, IntoElement, ParentElement, Pixels, Render,
+    SharedString, StatefulInteractiveElement, Styled as _, Subscription, Task, View, ViewContext,
+    VisualContext, WindowContext,
 };
+use rust_i18n::t;
+use serde::{Deserialize, Serialize};
 
 use ui::{
+    button::Button,
     divider::Divider,
-    dock::{Panel, PanelEvent, TabPanel},
+    dock::{self, CloseDecision, Panel, PanelEvent, PanelState, TabPanel},
     h_flex,
     label::Label,
     notification::Notification,
+    perf::perf_scope,
     popup_menu::PopupMenu,
-    v_flex, ContextModal, Placement,
+    skeleton::{Skeleton, SkeletonCard, SkeletonText},
+    task_manager::{TaskEvent, TaskId, TaskManager},
+    theme::ActiveTheme as _,
+    v_flex, ContextModal, IconName, Placement, Sizable as _,
 };
 
+rust_i18n::i18n!("locales", fallback = "en");
+
 pub fn init(cx: &mut AppContext) {
     input_story::init(cx);
     dropdown_story::init(cx);
     popup_story::init(cx);
+    profiler::init(cx);
+    register_unsaved::<InputStory>(cx);
+    register_unsaved::<NotesStory>(cx);
+    register_badge::<ModalStory>(cx);
+    register_copyable::<ListStory>(cx);
+    register_copyable::<TableStory>(cx);
+    register_copyable::<CsvTableStory>(cx);
 }
 
-actions!(story, [PanelInfo]);
+actions!(story, [PanelInfo, CopySelection, CopySelectionAsJson]);
 
 pub fn section(title: impl IntoElement, cx: &WindowContext) -> Div {
     use ui::theme::ActiveTheme;
@@ -71,20 +148,51 @@ pub fn section(title: impl IntoElement, cx: &WindowContext) -> Div {
         .w_full()
         .rounded_lg()
         .border_1()
-        .border_color(theme.border)
+        .border_color(theme.tokens.outline)
         .flex_wrap()
         .justify_around()
         .child(div().flex_none().w_full().child(title))
 }
 
+/// Translate the text by the key in `locales/story.yml`, returns the `fallback` if the key is missing.
+fn translate(key: &str, fallback: &SharedString) -> SharedString {
+    let text = t!(key);
+    if text == key {
+        fallback.clone()
+    } else {
+        text.to_string().into()
+    }
+}
+
+/// The function to load the story view asynchronously, see [`StoryContainer::load_with`].
+pub type StoryLoader = Rc<dyn Fn(&mut WindowContext) -> Task<anyhow::Result<AnyView>>>;
+
+/// The loading status of the story in a [`StoryContainer`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum StoryStatus {
+    Loading,
+    /// The loading has been cancelled in the background tasks.
+    Cancelled,
+    Error(SharedString),
+    Ready,
+}
+
 pub struct StoryContainer {
     focus_handle: gpui::FocusHandle,
     name: SharedString,
+    /// The title renamed by the user in the tab, instead of the translated name.
+    custom_title: Option<SharedString>,
     description: SharedString,
     width: Option<gpui::Pixels>,
     height: Option<gpui::Pixels>,
     story: Option<AnyView>,
+    icon: Option<IconName>,
     closeable: bool,
+    status: StoryStatus,
+    loader: Option<StoryLoader>,
+    /// The loading task in the [`TaskManager`], to cancel it when reloading.
+    load_task: Option<TaskId>,
+    _load_subscription: Option<Subscription>,
 }
 
 #[derive(Debug)]
@@ -92,6 +200,27 @@ pub enum ContainerEvent {
     Close,
 }
 
+/// The saved state of a [`StoryContainer`], the `name` is used to find the story to restore.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StoryState {
+    pub name: String,
+    pub description: String,
+    pub closeable: bool,
+    /// The title renamed by the user, `None` to use the name of the story.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+}
+
+impl StoryState {
+    /// Read the story state from the saved [`PanelState`].
+    pub fn from_panel_state(state: &PanelState) -> Option<Self> {
+        match &state.info {
+            dock::PanelInfo::Panel(value) => serde_json::from_value(value.clone()).ok(),
+            _ => None,
+        }
+    }
+}
+
 impl EventEmitter<ContainerEvent> for StoryContainer {}
 
 impl StoryContainer {
@@ -106,39 +235,64 @@ impl StoryContainer {
         Self {
             focus_handle,
             name: name.into(),
+            custom_title: None,
             description: description.into(),
             width: None,
             height: None,
             story: None,
+            icon: None,
             closeable,
+            status: StoryStatus::Ready,
+            loader: None,
+            load_task: None,
+            _load_subscription: None,
         }
     }
 
-    #[allow(clippy::too_many_arguments)]
-    pub fn add_panel(
+    /// Create a container that shows a spinner until the story is loaded by the `load` task.
+    ///
+    /// If the task fails, the error is shown with a retry button, that calls `load` again.
+    pub fn load_with(
         name: impl Into<SharedString>,
         description: impl Into<SharedString>,
-        story: AnyView,
+        closeable: bool,
+        load: impl Fn(&mut WindowContext) -> Task<anyhow::Result<AnyView>> + 'static,
+        cx: &mut ViewContext<Self>,
+    ) -> Self {
+        let mut this = Self::new(name, description, closeable, cx);
+        this.loader = Some(Rc::new(load));
+        this.reload(cx);
+        this
+    }
+
+    /// Add the container to the `tab_panel`, or split the `tab_panel` by the `placement`.
+    pub fn add_panel(
+        view: View<Self>,
         tab_panel: View<TabPanel>,
         placement: Option<Placement>,
         size: Option<Pixels>,
-        closeable: bool,
         cx: &mut WindowContext,
     ) {
-        let name = name.into();
-        let description = description.into();
-
         tab_panel.update(cx, |panel, cx| {
-            let view = cx.new_view(|cx| Self::new(name, description, closeable, cx).story(story));
             if let Some(placement) = placement {
-                panel.add_panel_at(Arc::new(view.clone()), placement, size, cx);
+                panel.add_panel_at(Arc::new(view), placement, size, cx);
             } else {
-                panel.add_panel(Arc::new(view.clone()), cx);
+                panel.add_panel(Arc::new(view), cx);
             }
-            view
         });
     }
 
+    /// The name of the story, it will not be translated.
+    pub fn name(&self) -> SharedString {
+        self.name.clone()
+    }
+
+    /// Set the title renamed by the user, `None` to show the translated name of the story.
+    pub fn set_custom_title(&mut self, title: Option<SharedString>, cx: &mut ViewContext<Self>) {
+        self.custom_title = title;
+        cx.notify();
+    }
+
     pub fn width(mut self, width: gpui::Pixels) -> Self {
         self.width = Some(width);
         self
@@ -149,31 +303,278 @@ impl StoryContainer {
         self
     }
 
+    /// Set the icon to show on the tab.
+    pub fn icon(mut self, icon: IconName) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
     pub fn story(mut self, story: AnyView) -> Self {
         self.story = Some(story);
+        self.status = StoryStatus::Ready;
         self
     }
 
+    /// Returns the loading status of the story.
+    pub fn status(&self) -> &StoryStatus {
+        &self.status
+    }
+
+    /// Load the story again by the loader of [`StoryContainer::load_with`], do nothing if there is no loader.
+    pub fn reload(&mut self, cx: &mut ViewContext<Self>) {
+        let Some(loader) = self.loader.clone() else {
+            return;
+        };
+
+        self.status = StoryStatus::Loading;
+        let load = loader(cx);
+        let view = cx.view().downgrade();
+        let mut async_cx = cx.to_async();
+        let task_manager = TaskManager::global(cx);
+        let name = self.title(cx);
+        let previous_task = self.load_task.take();
+
+        let task_id = task_manager.update(cx, |task_manager, cx| {
+            if let Some(id) = previous_task {
+                task_manager.cancel(id, cx);
+            }
+            task_manager.spawn(
+                name,
+                move |_| async move {
+                    let result = load.await;
+                    let _ = view.update(&mut async_cx, |this, cx| {
+                        match result {
+                            Ok(story) => {
+                                this.story = Some(story);
+                                this.status = StoryStatus::Ready;
+                            }
+                            Err(err) => this.status = StoryStatus::Error(err.to_string().into()),
+                        }
+                        this.load_task = None;
+                        cx.notify();
+                    });
+                },
+                cx,
+            )
+        });
+        self.load_task = Some(task_id);
+        self._load_subscription = Some(cx.subscribe(
+            &task_manager,
+            move |this, _, event: &TaskEvent, cx| {
+                if *event == TaskEvent::Cancelled(task_id) {
+                    this.load_task = None;
+                    this.status = StoryStatus::Cancelled;
+                    cx.notify();
+                }
+            },
+        ));
+        cx.notify();
+    }
+
+    /// Returns the view of the story.
+    pub fn story_view(&self) -> Option<AnyView> {
+        self.story.clone()
+    }
+
+    /// Returns the item labels of the story for the global search, see [`register_searchable`].
+    pub fn search_items(&self, cx: &AppContext) -> Vec<SharedString> {
+        self.story
+            .as_ref()
+            .map(|story| search::search_items(story, cx))
+            .unwrap_or_default()
+    }
+
+    /// Select and scroll to the item at the index of the [`StoryContainer::search_items`].
+    pub fn reveal_item(&self, ix: usize, cx: &mut WindowContext) {
+        if let Some(story) = self.story.clone() {
+            search::reveal_item(&story, ix, cx);
+        }
+    }
+
+    fn render_status(&self, cx: &mut ViewContext<Self>) -> Option<AnyElement> {
+        let content = match &self.status {
+            StoryStatus::Ready => return None,
+            StoryStatus::Loading => return Some(Self::render_loading().into_any_element()),
+            StoryStatus::Cancelled => h_flex()
+                .gap_3()
+                .child(t!("Story.Loading cancelled").to_string())
+                .child(
+                    Button::new("retry-story", cx)
+                        .small()
+                        .label(t!("Story.Retry").to_string())
+                        .on_click(cx.listener(|this, _, cx| this.reload(cx))),
+                ),
+            StoryStatus::Error(message) => v_flex()
+                .items_center()
+                .gap_3()
+                .child(
+                    Label::new(format!("{}: {}", t!("Story.Failed to load"), message))
+                        .text_color(cx.theme().tokens.danger),
+                )
+                .child(
+                    Button::new("retry-story", cx)
+                        .small()
+                        .label(t!("Story.Retry").to_string())
+                        .on_click(cx.listener(|this, _, cx| this.reload(cx))),
+                ),
+        };
+
+        Some(
+            h_flex()
+                .flex_1()
+                .justify_center()
+                .p_4()
+                .text_sm()
+                .text_color(cx.theme().tokens.on_surface_muted)
+                .child(content)
+                .into_any_element(),
+        )
+    }
+
+    /// The skeleton of a story, shown until the story is loaded.
+    fn render_loading() -> impl IntoElement {
+        v_flex()
+            .p_4()
+            .gap_4()
+            .child(Skeleton::new().w(px(240.)).h_6())
+            .child(SkeletonText::new(3))
+            .child(
+                h_flex()
+                    .gap_4()
+                    .flex_wrap()
+                    .child(SkeletonCard::new())
+                    .child(SkeletonCard::new()),
+            )
+    }
+
+    fn open_source(&mut self, cx: &mut ViewContext<Self>) {
+        let Some(source) = source::story_source(&self.name) else {
+            return;
+        };
+        let title = format!(
+            "{} - {}",
+            t!("Story.Source"),
+            translate(&format!("Story.{}.title", self.name), &self.name)
+        );
+
+        cx.open_drawer(move |drawer, cx| {
+            drawer
+                .margin_top(px(33.))
+                .placement(Placement::Right)
+                .size(px(640.))
+                .title(title.clone())
+                .child(source::render_source(source, cx))
+        });
+    }
+
     fn on_action_panel_info(&mut self, _: &PanelInfo, cx: &mut ViewContext<Self>) {
         struct Info;
         let note = Notification::new(format!("You have clicked panel info on: {}", self.name))
             .id::<Info>();
         cx.push_notification(note);
     }
+
+    fn on_action_copy_selection(&mut self, _: &CopySelection, cx: &mut ViewContext<Self>) {
+        self.copy_selection(false, cx);
+    }
+
+    fn on_action_copy_selection_as_json(
+        &mut self,
+        _: &CopySelectionAsJson,
+        cx: &mut ViewContext<Self>,
+    ) {
+        self.copy_selection(true, cx);
+    }
+
+    fn copy_selection(&mut self, as_json: bool, cx: &mut ViewContext<Self>) {
+        let Some(story) = self.story.clone() else {
+            return;
+        };
+
+        if copyable::copy_selection(&story, as_json, cx) {
+            struct Copied;
+            let message = if as_json {
+                t!("Story.Copied as JSON")
+            } else {
+                t!("Story.Copied")
+            };
+            cx.push_notification(Notification::success(message.to_string()).id::<Copied>());
+        } else {
+            cx.propagate();
+        }
+    }
 }
 
 impl Panel for StoryContainer {
+    fn panel_name(&self) -> &'static str {
+        "StoryContainer"
+    }
+
     fn title(&self, _cx: &WindowContext) -> SharedString {
-        self.name.clone()
+        match &self.custom_title {
+            Some(title) => title.clone(),
+            None => translate(&format!("Story.{}.title", self.name), &self.name),
+        }
+    }
+
+    fn icon(&self, _cx: &WindowContext) -> Option<IconName> {
+        self.icon.clone()
+    }
+
+    fn badge(&self, cx: &WindowContext) -> Option<dock::PanelBadge> {
+        self.story
+            .as_ref()
+            .and_then(|story| badge::badge(story, cx))
     }
 
     fn closeable(&self, _cx: &WindowContext) -> bool {
         self.closeable
     }
 
+    fn can_close(&self, cx: &WindowContext) -> CloseDecision {
+        match &self.story {
+            Some(story) if unsaved::has_unsaved_changes(story, cx) => {
+                CloseDecision::Confirm(t!("Story.Unsaved changes").to_string().into())
+            }
+            _ => CloseDecision::Close,
+        }
+    }
+
+    fn save(&mut self, cx: &mut ViewContext<Self>) {
+        if let Some(story) = &self.story {
+            unsaved::save(story, cx);
+        }
+    }
+
+    /// Only the notes are renamed by double-click, the double-click zooms the other stories.
+    fn renameable(&self, _cx: &WindowContext) -> bool {
+        self.story.as_ref().map_or(false, |story| {
+            story.clone().downcast::<NotesStory>().is_ok()
+        })
+    }
+
+    fn can_rename(&self, _cx: &WindowContext) -> bool {
+        true
+    }
+
+    fn rename(&mut self, title: SharedString, cx: &mut ViewContext<Self>) {
+        self.set_custom_title(Some(title), cx);
+    }
+
     fn popup_menu(&self, menu: PopupMenu, _cx: &WindowContext) -> PopupMenu {
         menu.track_focus(&self.focus_handle)
-            .menu("Info", Box::new(PanelInfo))
+            .menu(t!("Story.Info"), Box::new(PanelInfo))
+    }
+
+    fn dump(&self, _cx: &AppContext) -> PanelState {
+        let state = StoryState {
+            name: self.name.to_string(),
+            description: self.description.to_string(),
+            closeable: self.closeable,
+            title: self.custom_title.as_ref().map(|title| title.to_string()),
+        };
+
+        PanelState::new(self, serde_json::to_value(state).unwrap_or_default())
     }
 }
 
@@ -189,17 +590,42 @@ impl Render for StoryContainer {
             .id("story-container")
             .size_full()
             .overflow_scroll()
+            .key_context("StoryContainer")
             .track_focus(&self.focus_handle)
             .on_action(cx.listener(Self::on_action_panel_info))
+            .on_action(cx.listener(Self::on_action_copy_selection))
+            .on_action(cx.listener(Self::on_action_copy_selection_as_json))
             .child(
                 div()
                     .flex()
                     .flex_col()
                     .gap_4()
                     .p_4()
-                    .child(Label::new(self.description.clone()).text_size(px(16.0)))
-                    .child(Divider::horizontal().label("This is a divider")),
+                    .child(
+                        h_flex()
+                            .justify_between()
+                            .gap_4()
+                            .child(
+                                Label::new(translate(
+                                    &format!("Story.{}.description", self.name),
+                                    &self.description,
+                                ))
+                                .text_size(px(16.0)),
+                            )
+                            .when(source::story_source(&self.name).is_some(), |this| {
+                                this.child(
+                                    Button::new("view-source", cx)
+                                        .small()
+                                        .ghost()
+                                        .icon(IconName::File)
+                                        .label(t!("Story.View Source").to_string())
+                                        .on_click(cx.listener(|this, _, cx| this.open_source(cx))),
+                                )
+                            }),
+                    )
+                    .child(Divider::horizontal().label(t!("Story.This is a divider"))),
             )
+            .children(self.render_status(cx))
             .when_some(self.story.clone(), |this, story| {
                 this.child(
                     v_flex()
@@ -207,7 +633,11 @@ impl Render for StoryContainer {
                         .overflow_scroll()
                         .size_full()
                         .p_4()
-                        .child(story),
+                        .child({
+                            let name = self.name.clone();
+                            perf_scope(self.name.clone(), story)
+                                .on_measure(move |timings, cx| profiler::record(&name, timings, cx))
+                        }),
                 )
             })
     }
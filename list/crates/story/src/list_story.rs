@@ -1,23 +1,40 @@
 use core::time;
+use std::{collections::BTreeSet, ops::Range};
 
 use fake::Fake;
 use gpui::{
-    actions, div, px, ElementId, FocusHandle, FocusableView, InteractiveElement, IntoElement,
-    ParentElement, Render, RenderOnce, Styled, Task, Timer, View, ViewContext, VisualContext,
-    WindowContext,
+    actions, div, px, AppContext, ClickEvent, ElementId, FocusHandle, FocusableView,
+    InteractiveElement, IntoElement, ParentElement, Render, RenderOnce, SharedString, Styled, Task,
+    Timer, View, ViewContext, VisualContext, WindowContext,
 };
 
 use ui::{
+    button::Button,
+    fuzzy::fuzzy_match,
     h_flex,
     label::Label,
     list::ListItem,
-    list::{List, ListDelegate},
+    list::{List, ListDelegate, ListEvent},
     theme::{hsl, ActiveTheme},
-    v_flex,
+    v_flex, Sizable as _,
+};
+
+use crate::{
+    data_import::{import_data, ImportedData},
+    CopiedRows, CopyableStory, EventBus, SearchableStory,
 };
 
 actions!(list_story, [SelectedCompany]);
 
+/// Published to the [`EventBus`] when a company is selected or confirmed in the list.
+#[derive(Debug, Clone)]
+pub struct CompanySelected {
+    pub name: SharedString,
+    pub industry: SharedString,
+    pub last_done: f64,
+    pub change_percent: f64,
+}
+
 #[derive(Clone)]
 struct Company {
     name: String,
@@ -33,8 +50,47 @@ impl Company {
     }
 
     fn change_percent(&self) -> f64 {
+        if self.prev_close == 0.0 {
+            return 0.0;
+        }
+
         (self.last_done - self.prev_close) / self.prev_close
     }
+
+    /// Create the companies from the imported rows.
+    ///
+    /// The columns are found by the headers: `name`, `industry`, `last_done` and `prev_close`,
+    /// the first columns are used as the name and industry if the headers are missing.
+    fn from_imported(data: &ImportedData) -> Vec<Self> {
+        let name_ix = data.col_ix("name").unwrap_or(0);
+        let industry_ix = data
+            .col_ix("industry")
+            .or_else(|| (0..data.headers.len()).find(|ix| *ix != name_ix));
+        let last_done_ix = data.col_ix("last_done");
+        let prev_close_ix = data.col_ix("prev_close");
+
+        data.rows
+            .iter()
+            .map(|row| {
+                let field = |ix: Option<usize>| ix.and_then(|ix| row.get(ix));
+                let number = |ix: Option<usize>| {
+                    field(ix).and_then(|value| value.trim().parse::<f64>().ok())
+                };
+                let last_done = number(last_done_ix).unwrap_or_default();
+
+                Company {
+                    name: field(Some(name_ix))
+                        .map(ToString::to_string)
+                        .unwrap_or_default(),
+                    industry: field(industry_ix)
+                        .map(ToString::to_string)
+                        .unwrap_or_default(),
+                    last_done,
+                    prev_close: number(prev_close_ix).unwrap_or(last_done),
+                }
+            })
+            .collect()
+    }
 }
 
 #[derive(IntoElement)]
@@ -42,13 +98,21 @@ struct CompanyListItem {
     base: ListItem,
     ix: usize,
     company: Company,
+    highlights: Vec<Range<usize>>,
     selected: bool,
 }
 
 impl CompanyListItem {
-    pub fn new(id: impl Into<ElementId>, company: Company, ix: usize, selected: bool) -> Self {
+    pub fn new(
+        id: impl Into<ElementId>,
+        company: Company,
+        highlights: Vec<Range<usize>>,
+        ix: usize,
+        selected: bool,
+    ) -> Self {
         CompanyListItem {
             company,
+            highlights,
             ix,
             base: ListItem::new(id),
             selected,
@@ -59,15 +123,15 @@ impl CompanyListItem {
 impl RenderOnce for CompanyListItem {
     fn render(self, cx: &mut WindowContext) -> impl IntoElement {
         let text_color = if self.selected {
-            cx.theme().accent_foreground
+            cx.theme().tokens.on_highlight
         } else {
-            cx.theme().foreground
+            cx.theme().tokens.on_surface
         };
 
         let trend_color = match self.company.change_percent() {
             change if change > 0.0 => hsl(0.0, 79.0, 53.0),
             change if change < 0.0 => hsl(100.0, 79.0, 53.0),
-            _ => cx.theme().foreground,
+            _ => cx.theme().tokens.on_surface,
         };
 
         let bg_color = if self.selected {
@@ -95,7 +159,11 @@ impl RenderOnce for CompanyListItem {
                             .max_w(px(500.))
                             .overflow_x_hidden()
                             .flex_nowrap()
-                            .child(Label::new(self.company.name.clone()).whitespace_nowrap())
+                            .child(
+                                Label::new(self.company.name.clone())
+                                    .highlights(self.highlights)
+                                    .whitespace_nowrap(),
+                            )
                             .child(
                                 div().text_sm().overflow_x_hidden().child(
                                     Label::new(self.company.industry.clone())
@@ -133,8 +201,13 @@ impl RenderOnce for CompanyListItem {
 
 struct CompanyListDelegate {
     companies: Vec<Company>,
-    matched_companies: Vec<Company>,
+    /// The index of the matched companies with the matched ranges of the name, sorted by the score.
+    matches: Vec<(usize, Vec<Range<usize>>)>,
+    /// The last query, to match the reloaded companies.
+    query: String,
     selected_index: usize,
+    /// All the selected items by Shift-click or Cmd/Ctrl-click.
+    selected_indices: BTreeSet<usize>,
     confirmed_index: Option<usize>,
 }
 
@@ -142,7 +215,7 @@ impl ListDelegate for CompanyListDelegate {
     type Item = CompanyListItem;
 
     fn items_count(&self) -> usize {
-        self.matched_companies.len()
+        self.matches.len()
     }
 
     fn confirmed_index(&self) -> Option<usize> {
@@ -150,16 +223,35 @@ impl ListDelegate for CompanyListDelegate {
     }
 
     fn perform_search(&mut self, query: &str, _: &mut ViewContext<List<Self>>) -> Task<()> {
-        self.matched_companies = self
+        self.query = query.to_string();
+        let mut matches = self
             .companies
             .iter()
-            .filter(|company| company.name.to_lowercase().contains(&query.to_lowercase()))
-            .cloned()
-            .collect();
+            .enumerate()
+            .filter_map(|(ix, company)| fuzzy_match(query, &company.name).map(|m| (ix, m)))
+            .collect::<Vec<_>>();
+        matches.sort_by(|a, b| b.1.score.cmp(&a.1.score));
+
+        self.matches = matches.into_iter().map(|(ix, m)| (ix, m.ranges)).collect();
 
         Task::Ready(Some(()))
     }
 
+    fn refresh(&mut self, cx: &mut ViewContext<List<Self>>) -> Task<()> {
+        // Pretend to reload the companies from a server.
+        cx.spawn(|list, mut cx| async move {
+            Timer::after(time::Duration::from_secs(1)).await;
+            let companies = (0..10_000).map(|_| random_company()).collect::<Vec<_>>();
+            let _ = list.update(&mut cx, |list, cx| {
+                let delegate = list.delegate_mut();
+                delegate.companies = companies;
+                delegate.confirmed_index = None;
+                let query = delegate.query.clone();
+                delegate.perform_search(&query, cx);
+            });
+        })
+    }
+
     fn confirm(&mut self, ix: Option<usize>, cx: &mut ViewContext<List<Self>>) {
         self.confirmed_index = ix;
         if let Some(_) = ix {
@@ -174,10 +266,24 @@ impl ListDelegate for CompanyListDelegate {
         }
     }
 
+    fn set_selected_indices(&mut self, indices: &[usize], cx: &mut ViewContext<List<Self>>) {
+        self.selected_indices = indices.iter().copied().collect();
+        cx.notify();
+    }
+
     fn render_item(&self, ix: usize, _cx: &mut ViewContext<List<Self>>) -> Option<Self::Item> {
-        let selected = ix == self.selected_index || Some(ix) == self.confirmed_index;
-        if let Some(company) = self.matched_companies.get(ix) {
-            return Some(CompanyListItem::new(ix, company.clone(), ix, selected));
+        let selected = ix == self.selected_index
+            || Some(ix) == self.confirmed_index
+            || self.selected_indices.contains(&ix);
+        let (company_ix, highlights) = self.matches.get(ix)?;
+        if let Some(company) = self.companies.get(*company_ix) {
+            return Some(CompanyListItem::new(
+                ix,
+                company.clone(),
+                highlights.clone(),
+                ix,
+                selected,
+            ));
         }
 
         None
@@ -185,8 +291,24 @@ impl ListDelegate for CompanyListDelegate {
 }
 
 impl CompanyListDelegate {
+    /// Move the company in the matches from the `from` index to the `to` index.
+    ///
+    /// The list only can be reordered without a query, so the matches are in the order of the companies.
+    fn move_company(&mut self, from: usize, to: usize) {
+        let company = self.companies.remove(from);
+        self.companies.insert(to, company);
+        self.matches = (0..self.companies.len()).map(|ix| (ix, vec![])).collect();
+        self.confirmed_index = None;
+    }
+
     fn selected_company(&self) -> Option<Company> {
-        self.companies.get(self.selected_index).cloned()
+        self.company(self.selected_index)
+    }
+
+    /// Returns the company of the item at the index.
+    fn company(&self, ix: usize) -> Option<Company> {
+        let (ix, _) = self.matches.get(ix)?;
+        self.companies.get(*ix).cloned()
     }
 }
 
@@ -194,6 +316,8 @@ pub struct ListStory {
     focus_handle: FocusHandle,
     company_list: View<List<CompanyListDelegate>>,
     selected_company: Option<Company>,
+    /// The names of the multi-selected companies.
+    selection: Vec<String>,
 }
 
 impl ListStory {
@@ -202,21 +326,53 @@ impl ListStory {
     }
 
     fn new(cx: &mut ViewContext<Self>) -> Self {
-        let companies = (0..1_000)
+        let companies = (0..10_000)
             .map(|_| random_company())
             .collect::<Vec<Company>>();
 
         let company_list = cx.new_view(|cx| {
             List::new(
                 CompanyListDelegate {
-                    matched_companies: companies.clone(),
+                    matches: (0..companies.len()).map(|ix| (ix, vec![])).collect(),
                     companies,
+                    query: String::new(),
                     selected_index: 0,
+                    selected_indices: BTreeSet::new(),
                     confirmed_index: None,
                 },
                 cx,
             )
+            .virtualized(px(56.))
+            .reorderable(true)
+            .multi_select(true)
+            .refreshable(true)
         });
+        cx.subscribe(
+            &company_list,
+            |this, company_list, event: &ListEvent, cx| match event {
+                ListEvent::Reordered { from, to } => {
+                    let (from, to) = (*from, *to);
+                    company_list.update(cx, |list, cx| {
+                        list.delegate_mut().move_company(from, to);
+                        cx.notify();
+                    });
+                }
+                ListEvent::SelectionChanged(indices) => {
+                    let delegate = company_list.read(cx).delegate();
+                    let companies = indices
+                        .iter()
+                        .filter_map(|ix| delegate.company(*ix))
+                        .collect::<Vec<_>>();
+                    // Show the company in the detail panel, unless multiple companies are selected.
+                    if let [company] = companies.as_slice() {
+                        Self::publish_company(company, cx);
+                    }
+                    this.selection = companies.into_iter().map(|company| company.name).collect();
+                    cx.notify();
+                }
+            },
+        )
+        .detach();
 
         // Spawn a background to random refresh the list
         cx.spawn(move |this, mut cx| async move {
@@ -243,15 +399,110 @@ impl ListStory {
             focus_handle: cx.focus_handle(),
             company_list,
             selected_company: None,
+            selection: Vec::new(),
         }
     }
 
+    fn import_data(&mut self, _: &ClickEvent, cx: &mut ViewContext<Self>) {
+        import_data(cx, |this, data, cx| {
+            let companies = Company::from_imported(&data);
+            this.company_list.update(cx, |list, cx| {
+                let delegate = list.delegate_mut();
+                delegate.matches = (0..companies.len()).map(|ix| (ix, vec![])).collect();
+                delegate.companies = companies;
+                delegate.selected_index = 0;
+                delegate.selected_indices.clear();
+                delegate.confirmed_index = None;
+                cx.notify();
+            });
+            this.selected_company = None;
+            this.selection.clear();
+            cx.notify();
+        });
+    }
+
+    /// Returns the number of the companies matched the query.
+    pub fn items_count(&self, cx: &AppContext) -> usize {
+        self.company_list.read(cx).delegate().matches.len()
+    }
+
     fn selected_company(&mut self, _: &SelectedCompany, cx: &mut ViewContext<Self>) {
         let picker = self.company_list.read(cx);
         if let Some(company) = picker.delegate().selected_company() {
+            Self::publish_company(&company, cx);
             self.selected_company = Some(company);
         }
     }
+
+    fn publish_company(company: &Company, cx: &mut AppContext) {
+        EventBus::publish(
+            CompanySelected {
+                name: company.name.clone().into(),
+                industry: company.industry.clone().into(),
+                last_done: company.last_done,
+                change_percent: company.change_percent(),
+            },
+            cx,
+        );
+    }
+}
+
+impl SearchableStory for ListStory {
+    /// The names of the companies currently listed.
+    fn search_items(&self, cx: &AppContext) -> Vec<SharedString> {
+        let delegate = self.company_list.read(cx).delegate();
+        delegate
+            .matches
+            .iter()
+            .filter_map(|(ix, _)| delegate.companies.get(*ix))
+            .map(|company| company.name.clone().into())
+            .collect()
+    }
+
+    fn reveal_item(&mut self, ix: usize, cx: &mut ViewContext<Self>) {
+        self.company_list.update(cx, |list, cx| {
+            list.set_selected_index(Some(ix), cx);
+            list.scroll_to_selected_item(cx);
+            list.focus(cx);
+            cx.notify();
+        });
+    }
+}
+
+impl CopyableStory for ListStory {
+    /// The multi-selected companies, or the selected company.
+    fn copy_selection(&self, cx: &AppContext) -> Option<CopiedRows> {
+        let delegate = self.company_list.read(cx).delegate();
+        let companies = if delegate.selected_indices.is_empty() {
+            delegate.selected_company().into_iter().collect::<Vec<_>>()
+        } else {
+            delegate
+                .selected_indices
+                .iter()
+                .filter_map(|ix| delegate.company(*ix))
+                .collect()
+        };
+        if companies.is_empty() {
+            return None;
+        }
+
+        let headers = ["Name", "Industry", "Last Done", "Change"]
+            .into_iter()
+            .map(SharedString::from)
+            .collect();
+        let rows = companies
+            .iter()
+            .map(|company| {
+                vec![
+                    company.name.clone().into(),
+                    company.industry.clone().into(),
+                    format!("{:.2}", company.last_done).into(),
+                    format!("{:.2}%", company.change_percent()).into(),
+                ]
+            })
+            .collect();
+        Some(CopiedRows::new(headers, rows))
+    }
 }
 
 fn random_company() -> Company {
@@ -273,14 +524,48 @@ impl FocusableView for ListStory {
 
 impl Render for ListStory {
     fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
-        div()
+        v_flex()
             .track_focus(&self.focus_handle)
             .on_action(cx.listener(Self::selected_company))
             .size_full()
-            .gap_4()
-            .border_1()
-            .border_color(cx.theme().border)
-            .rounded_md()
-            .child(self.company_list.clone())
+            .gap_2()
+            .child(
+                h_flex()
+                    .items_center()
+                    .gap_2()
+                    .child(
+                        Button::new("import-data", cx)
+                            .label("Import Data…")
+                            .small()
+                            .on_click(cx.listener(Self::import_data)),
+                    )
+                    .child(
+                        Label::new(format!("{} companies", self.items_count(cx)))
+                            .text_sm()
+                            .text_color(cx.theme().tokens.on_surface_muted),
+                    ),
+            )
+            .child(
+                div()
+                    .flex_1()
+                    .overflow_hidden()
+                    .border_1()
+                    .border_color(cx.theme().tokens.outline)
+                    .rounded_md()
+                    .child(self.company_list.clone()),
+            )
+            .child(
+                Label::new(if self.selection.is_empty() {
+                    "Shift-click or Cmd/Ctrl-click to select multiple companies.".to_string()
+                } else {
+                    format!(
+                        "{} selected: {}",
+                        self.selection.len(),
+                        self.selection.join(", ")
+                    )
+                })
+                .text_sm()
+                .text_color(cx.theme().tokens.on_surface_muted),
+            )
     }
 }
@@ -0,0 +1,87 @@
+use std::{collections::VecDeque, sync::Mutex, time::SystemTime};
+
+use gpui::SharedString;
+use log::Level;
+
+/// The default number of the records kept in a [`LogBuffer`].
+pub const DEFAULT_LOG_CAPACITY: usize = 5000;
+
+/// A log record captured by the logger of the app.
+#[derive(Debug, Clone)]
+pub struct LogRecord {
+    pub time: SystemTime,
+    pub level: Level,
+    /// The module path of the record, e.g.: `gpui_app::story_workspace`.
+    pub target: SharedString,
+    pub message: SharedString,
+}
+
+impl LogRecord {
+    /// Returns true if the target or the message contains the query, case insensitive.
+    pub fn matches(&self, query: &str) -> bool {
+        let query = query.to_lowercase();
+        self.message.to_lowercase().contains(&query) || self.target.to_lowercase().contains(&query)
+    }
+}
+
+#[derive(Default)]
+struct LogBufferInner {
+    records: VecDeque<LogRecord>,
+    /// Increased on every change, to know if the records have changed since the last read.
+    generation: usize,
+}
+
+/// The ring buffer of the latest log records, the oldest records are dropped when it's full.
+///
+/// It's written by the logger from any thread, and read by the [`crate::LogsStory`].
+pub struct LogBuffer {
+    capacity: usize,
+    inner: Mutex<LogBufferInner>,
+}
+
+impl Default for LogBuffer {
+    fn default() -> Self {
+        Self::new(DEFAULT_LOG_CAPACITY)
+    }
+}
+
+impl LogBuffer {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            inner: Mutex::new(LogBufferInner::default()),
+        }
+    }
+
+    pub fn push(&self, record: LogRecord) {
+        let Ok(mut inner) = self.inner.lock() else {
+            return;
+        };
+
+        if inner.records.len() >= self.capacity {
+            inner.records.pop_front();
+        }
+        inner.records.push_back(record);
+        inner.generation += 1;
+    }
+
+    pub fn clear(&self) {
+        if let Ok(mut inner) = self.inner.lock() {
+            inner.records.clear();
+            inner.generation += 1;
+        }
+    }
+
+    /// Returns the number of the changes, compare it with the last one to know if the records have changed.
+    pub fn generation(&self) -> usize {
+        self.inner.lock().map_or(0, |inner| inner.generation)
+    }
+
+    /// Returns the records from the oldest to the latest.
+    pub fn records(&self) -> Vec<LogRecord> {
+        self.inner
+            .lock()
+            .map(|inner| inner.records.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+}
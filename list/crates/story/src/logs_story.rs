@@ -0,0 +1,269 @@
+use std::{sync::Arc, time::Duration};
+
+use gpui::{
+    div, prelude::FluentBuilder as _, uniform_list, Hsla, InteractiveElement as _, IntoElement,
+    ParentElement as _, Render, SharedString, Styled as _, Subscription, Task,
+    UniformListScrollHandle, View, ViewContext, VisualContext as _, WindowContext,
+};
+use log::Level;
+use ui::{
+    button::Button,
+    h_flex,
+    input::{InputEvent, TextInput},
+    theme::ActiveTheme as _,
+    v_flex, IconName, Selectable as _, Sizable as _,
+};
+
+use crate::{
+    log_buffer::{LogBuffer, LogRecord},
+    source::MONOSPACE_FONT,
+};
+
+/// The interval to check the new records of the [`LogBuffer`].
+const REFRESH_INTERVAL: Duration = Duration::from_millis(250);
+const LEVELS: [Level; 5] = [
+    Level::Error,
+    Level::Warn,
+    Level::Info,
+    Level::Debug,
+    Level::Trace,
+];
+
+fn level_color(level: Level, cx: &WindowContext) -> Hsla {
+    match level {
+        Level::Error => cx.theme().tokens.danger,
+        Level::Warn => ui::yellow_500(),
+        Level::Info => ui::blue_500(),
+        Level::Debug | Level::Trace => cx.theme().tokens.on_surface_muted,
+    }
+}
+
+/// The log records of the app captured in a [`LogBuffer`], filtered by the level and the search,
+/// and scrolled to the latest record in the follow mode.
+pub struct LogsStory {
+    buffer: Arc<LogBuffer>,
+    /// Show the records of this level and the more severe levels.
+    max_level: Level,
+    search_input: View<TextInput>,
+    /// The records matched by the level and the search.
+    records: Vec<LogRecord>,
+    /// The generation of the buffer that the records are filtered from.
+    generation: usize,
+    /// Keep scrolling to the latest record when new records come.
+    follow: bool,
+    scroll_handle: UniformListScrollHandle,
+    _refresh_task: Task<()>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl LogsStory {
+    pub fn view(buffer: Arc<LogBuffer>, cx: &mut WindowContext) -> View<Self> {
+        cx.new_view(|cx| Self::new(buffer, cx))
+    }
+
+    fn new(buffer: Arc<LogBuffer>, cx: &mut ViewContext<Self>) -> Self {
+        let search_input = cx.new_view(|cx| {
+            TextInput::new(cx)
+                .placeholder("Search the target or the message...")
+                .cleanable()
+                .small()
+        });
+        let _subscriptions =
+            vec![
+                cx.subscribe(&search_input, |this, _, event: &InputEvent, cx| {
+                    if let InputEvent::Change(_) = event {
+                        this.filter(cx);
+                    }
+                }),
+            ];
+
+        let _refresh_task = cx.spawn(|this, mut cx| async move {
+            loop {
+                cx.background_executor().timer(REFRESH_INTERVAL).await;
+                let result = this.update(&mut cx, |this, cx| {
+                    if this.buffer.generation() != this.generation {
+                        this.filter(cx);
+                    }
+                });
+                if result.is_err() {
+                    return;
+                }
+            }
+        });
+
+        let mut this = Self {
+            buffer,
+            max_level: Level::Info,
+            search_input,
+            records: Vec::new(),
+            generation: 0,
+            follow: true,
+            scroll_handle: UniformListScrollHandle::new(),
+            _refresh_task,
+            _subscriptions,
+        };
+        this.filter(cx);
+        this
+    }
+
+    /// Filter the records of the buffer by the level and the search again.
+    fn filter(&mut self, cx: &mut ViewContext<Self>) {
+        let query = self.search_input.read(cx).text();
+        let query = query.trim();
+
+        self.generation = self.buffer.generation();
+        self.records = self
+            .buffer
+            .records()
+            .into_iter()
+            .filter(|record| record.level <= self.max_level)
+            .filter(|record| query.is_empty() || record.matches(query))
+            .collect();
+
+        if self.follow && !self.records.is_empty() {
+            self.scroll_handle.scroll_to_item(self.records.len() - 1);
+        }
+        cx.notify();
+    }
+
+    fn set_max_level(&mut self, level: Level, cx: &mut ViewContext<Self>) {
+        self.max_level = level;
+        self.filter(cx);
+    }
+
+    fn toggle_follow(&mut self, cx: &mut ViewContext<Self>) {
+        self.follow = !self.follow;
+        if self.follow && !self.records.is_empty() {
+            self.scroll_handle.scroll_to_item(self.records.len() - 1);
+        }
+        cx.notify();
+    }
+
+    fn clear(&mut self, cx: &mut ViewContext<Self>) {
+        self.buffer.clear();
+        self.filter(cx);
+    }
+
+    fn render_record(&self, ix: usize, cx: &WindowContext) -> impl IntoElement {
+        let record = &self.records[ix];
+        let time = chrono::DateTime::<chrono::Local>::from(record.time);
+
+        h_flex()
+            .id(("log-record", ix))
+            .w_full()
+            .gap_3()
+            .px_2()
+            .py_0p5()
+            .when(ix % 2 == 1, |this| this.bg(cx.theme().table_even))
+            .child(
+                div()
+                    .flex_shrink_0()
+                    .text_color(cx.theme().tokens.on_surface_muted)
+                    .child(time.format("%H:%M:%S%.3f").to_string()),
+            )
+            .child(
+                div()
+                    .w_12()
+                    .flex_shrink_0()
+                    .text_color(level_color(record.level, cx))
+                    .child(record.level.as_str()),
+            )
+            .child(
+                div()
+                    .w_48()
+                    .flex_shrink_0()
+                    .overflow_hidden()
+                    .text_ellipsis()
+                    .text_color(cx.theme().tokens.on_surface_muted)
+                    .child(record.target.clone()),
+            )
+            .child(
+                div()
+                    .flex_1()
+                    .overflow_hidden()
+                    .text_ellipsis()
+                    .child(record.message.clone()),
+            )
+    }
+}
+
+impl Render for LogsStory {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let view = cx.view().clone();
+        let records_count = self.records.len();
+
+        v_flex()
+            .size_full()
+            .gap_3()
+            .child(
+                h_flex()
+                    .gap_2()
+                    .flex_wrap()
+                    .children(LEVELS.iter().enumerate().map(|(ix, level)| {
+                        let level = *level;
+                        Button::new(("log-level", ix), cx)
+                            .label(level.as_str())
+                            .xsmall()
+                            .selected(level == self.max_level)
+                            .on_click(cx.listener(move |this, _, cx| this.set_max_level(level, cx)))
+                    }))
+                    .child(div().w_64().child(self.search_input.clone()))
+                    .child(div().flex_1())
+                    .child(
+                        div()
+                            .text_sm()
+                            .text_color(cx.theme().tokens.on_surface_muted)
+                            .child(format!("{} records", records_count)),
+                    )
+                    .child(
+                        Button::new("follow-logs", cx)
+                            .icon(IconName::ArrowDown)
+                            .label("Follow")
+                            .xsmall()
+                            .selected(self.follow)
+                            .on_click(cx.listener(|this, _, cx| this.toggle_follow(cx))),
+                    )
+                    .child(
+                        Button::new("clear-logs", cx)
+                            .icon(IconName::Delete)
+                            .label("Clear")
+                            .xsmall()
+                            .on_click(cx.listener(|this, _, cx| this.clear(cx))),
+                    ),
+            )
+            .child(
+                v_flex()
+                    .flex_1()
+                    .border_1()
+                    .border_color(cx.theme().tokens.outline)
+                    .rounded_md()
+                    .overflow_hidden()
+                    .font_family(MONOSPACE_FONT)
+                    .text_xs()
+                    .map(|this| {
+                        if records_count == 0 {
+                            this.items_center().justify_center().child(
+                                div()
+                                    .text_color(cx.theme().tokens.on_surface_muted)
+                                    .child("No log records."),
+                            )
+                        } else {
+                            this.child(
+                                uniform_list(
+                                    view,
+                                    "log-records",
+                                    records_count,
+                                    |this, range, cx| {
+                                        range
+                                            .map(|ix| this.render_record(ix, cx))
+                                            .collect::<Vec<_>>()
+                                    },
+                                )
+                                .flex_grow()
+                                .track_scroll(self.scroll_handle.clone()),
+                            )
+                        }
+                    }),
+            )
+    }
+}
@@ -0,0 +1,230 @@
+use std::{collections::VecDeque, time::Duration};
+
+use fake::Fake;
+use gpui::{
+    div, px, IntoElement, ParentElement as _, Pixels, Render, SharedString, Styled as _, Task,
+    View, ViewContext, VisualContext as _, WindowContext,
+};
+use ui::{
+    chart::Sparkline,
+    divider::Divider,
+    h_flex,
+    list::ListItem,
+    table::{Table, TableDelegate},
+    theme::ActiveTheme as _,
+    v_flex,
+};
+
+/// The number of the samples kept in the history of a metric.
+const HISTORY: usize = 30;
+/// The interval to add a new sample to the metrics.
+const UPDATE_INTERVAL: Duration = Duration::from_secs(1);
+
+struct Metric {
+    name: SharedString,
+    unit: &'static str,
+    /// The value to simulate the samples around, and the max change of a sample.
+    base: f32,
+    spread: f32,
+    history: VecDeque<f32>,
+}
+
+impl Metric {
+    fn new(name: &'static str, unit: &'static str, base: f32, spread: f32) -> Self {
+        let mut metric = Self {
+            name: name.into(),
+            unit,
+            base,
+            spread,
+            history: VecDeque::with_capacity(HISTORY),
+        };
+        for _ in 0..HISTORY {
+            metric.sample();
+        }
+        metric
+    }
+
+    /// Add a random sample, it's pulled back to the `base` to not drift away.
+    fn sample(&mut self) {
+        let last = self.history.back().copied().unwrap_or(self.base);
+        let value = last + (self.base - last) * 0.2 + (-self.spread..self.spread).fake::<f32>();
+        self.history.push_back(value.max(0.));
+        if self.history.len() > HISTORY {
+            self.history.pop_front();
+        }
+    }
+
+    fn current(&self) -> f32 {
+        self.history.back().copied().unwrap_or_default()
+    }
+
+    /// Returns the change of the current value from the first sample, in percent.
+    fn change(&self) -> f32 {
+        match self.history.front() {
+            Some(first) if *first > 0. => (self.current() - first) / first * 100.,
+            _ => 0.,
+        }
+    }
+
+    fn sparkline(&self) -> Sparkline {
+        Sparkline::new(self.history.iter().copied())
+    }
+}
+
+struct MetricsTableDelegate {
+    metrics: Vec<Metric>,
+}
+
+impl MetricsTableDelegate {
+    fn new() -> Self {
+        Self {
+            metrics: vec![
+                Metric::new("Requests", "req/s", 1200., 80.),
+                Metric::new("Latency p50", "ms", 45., 4.),
+                Metric::new("Latency p99", "ms", 180., 25.),
+                Metric::new("Error rate", "%", 0.8, 0.15),
+                Metric::new("CPU", "%", 42., 6.),
+                Metric::new("Memory", "GB", 12.5, 0.3),
+                Metric::new("Disk I/O", "MB/s", 85., 15.),
+                Metric::new("Connections", "", 640., 40.),
+            ],
+        }
+    }
+}
+
+impl TableDelegate for MetricsTableDelegate {
+    fn cols_count(&self) -> usize {
+        4
+    }
+
+    fn rows_count(&self) -> usize {
+        self.metrics.len()
+    }
+
+    fn col_name(&self, col_ix: usize) -> SharedString {
+        match col_ix {
+            0 => "Metric",
+            1 => "Value",
+            2 => "Change",
+            _ => "Trend",
+        }
+        .into()
+    }
+
+    fn col_width(&self, col_ix: usize) -> Option<Pixels> {
+        Some(px(if col_ix == 3 { 160. } else { 120. }))
+    }
+
+    fn render_td(
+        &self,
+        row_ix: usize,
+        col_ix: usize,
+        cx: &mut ViewContext<Table<Self>>,
+    ) -> impl IntoElement {
+        let Some(metric) = self.metrics.get(row_ix) else {
+            return div().into_any_element();
+        };
+
+        match col_ix {
+            0 => div().child(metric.name.clone()).into_any_element(),
+            1 => div()
+                .child(format!("{:.1} {}", metric.current(), metric.unit))
+                .into_any_element(),
+            2 => {
+                let change = metric.change();
+                let color = if change >= 0. {
+                    ui::green_500()
+                } else {
+                    cx.theme().tokens.danger
+                };
+                div()
+                    .text_color(color)
+                    .child(format!("{:+.1}%", change))
+                    .into_any_element()
+            }
+            _ => h_flex()
+                .h_full()
+                .child(metric.sparkline().size(px(140.), px(18.)))
+                .into_any_element(),
+        }
+    }
+}
+
+/// The live metrics with the [`Sparkline`] in the list items and the table cells.
+pub struct MetricsStory {
+    table: View<Table<MetricsTableDelegate>>,
+    _update_task: Task<()>,
+}
+
+impl MetricsStory {
+    pub fn view(cx: &mut WindowContext) -> View<Self> {
+        cx.new_view(Self::new)
+    }
+
+    fn new(cx: &mut ViewContext<Self>) -> Self {
+        let table = cx.new_view(|cx| Table::new(MetricsTableDelegate::new(), cx));
+
+        let _update_task = cx.spawn(|this, mut cx| async move {
+            loop {
+                cx.background_executor().timer(UPDATE_INTERVAL).await;
+                let result = this.update(&mut cx, |this, cx| {
+                    this.table.update(cx, |table, cx| {
+                        for metric in table.delegate_mut().metrics.iter_mut() {
+                            metric.sample();
+                        }
+                        cx.notify();
+                    });
+                    cx.notify();
+                });
+                if result.is_err() {
+                    return;
+                }
+            }
+        });
+
+        Self {
+            table,
+            _update_task,
+        }
+    }
+}
+
+impl Render for MetricsStory {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let metrics = &self.table.read(cx).delegate().metrics;
+
+        // The first metrics are pinned as the list items, the sparkline is the suffix.
+        let pinned = metrics
+            .iter()
+            .take(3)
+            .enumerate()
+            .map(|(ix, metric)| {
+                let values = metric.history.iter().copied().collect::<Vec<_>>();
+                let color = ui::chart::chart_color(ix, cx);
+
+                ListItem::new(("pinned-metric", ix))
+                    .child(
+                        v_flex().child(metric.name.clone()).child(
+                            div()
+                                .text_xs()
+                                .text_color(cx.theme().tokens.on_surface_muted)
+                                .child(format!("{:.1} {}", metric.current(), metric.unit)),
+                        ),
+                    )
+                    .suffix(move |_| {
+                        Sparkline::new(values.clone())
+                            .color(color)
+                            .area(true)
+                            .size(px(120.), px(28.))
+                    })
+            })
+            .collect::<Vec<_>>();
+
+        v_flex()
+            .size_full()
+            .gap_3()
+            .child(v_flex().w(px(360.)).children(pinned))
+            .child(Divider::horizontal().label("All Metrics"))
+            .child(div().flex_1().min_h(px(280.)).child(self.table.clone()))
+    }
+}
@@ -7,10 +7,13 @@ use gpui::{
     WindowContext,
 };
 
+use crate::BadgeStory;
 use ui::{
+    accessibility::Accessible as _,
     button::{Button, ButtonStyle},
     checkbox::Checkbox,
     date_picker::DatePicker,
+    dock::PanelBadge,
     h_flex,
     input::TextInput,
     list::{List, ListDelegate, ListItem},
@@ -19,6 +22,9 @@ use ui::{
     v_flex, ContextModal as _, Icon, IconName, Placement,
 };
 
+/// The id of the notification with the actions, to dismiss it by the id.
+struct ActionsNotification;
+
 pub struct ListItemDeletegate {
     story: WeakView<ModalStory>,
     confirmed_index: Option<usize>,
@@ -65,6 +71,7 @@ impl ListDelegate for ListItemDeletegate {
 
         if let Some(item) = self.matches.get(ix) {
             let list_item = ListItem::new(("item", ix))
+                .accessible_label(item.to_string())
                 .check_icon(ui::IconName::Check)
                 .confirmed(confirmed)
                 .selected(selected)
@@ -100,14 +107,14 @@ impl ListDelegate for ListItemDeletegate {
             .child(
                 Icon::new(IconName::Inbox)
                     .size(px(50.))
-                    .text_color(cx.theme().muted_foreground),
+                    .text_color(cx.theme().tokens.on_surface_muted),
             )
             .child("No matches found")
             .items_center()
             .justify_center()
             .p_3()
-            .bg(cx.theme().muted)
-            .text_color(cx.theme().muted_foreground)
+            .bg(cx.theme().tokens.surface_muted)
+            .text_color(cx.theme().tokens.on_surface_muted)
     }
 
     fn cancel(&mut self, cx: &mut ViewContext<List<Self>>) {
@@ -267,7 +274,7 @@ impl ModalStory {
                 .child(
                     div()
                         .border_1()
-                        .border_color(cx.theme().border)
+                        .border_color(cx.theme().tokens.outline)
                         .rounded_md()
                         .size_full()
                         .flex_1()
@@ -306,11 +313,12 @@ impl ModalStory {
         let date_picker = self.date_picker.clone();
         let view = cx.view().clone();
 
-        input1.focus_handle(cx).focus(cx);
+        // The input is focused when the modal is opened, as the first tab stop.
         cx.open_modal(move |modal, cx| {
             modal
                 .margin_top(px(33.))
                 .title("Form Modal")
+                .accessible_label("Form Modal")
                 .overlay(overlay)
                 .show_close(modal_show_close)
                 .when(!modal_padding, |this| this.p(px(0.)))
@@ -336,22 +344,54 @@ impl ModalStory {
                                     let date_picker = date_picker.clone();
 
                                     move |_, cx| {
-                                        cx.close_modal();
+                                        let value = format!(
+                                            "Hello, {}, date: {}",
+                                            input1.read(cx).text(),
+                                            date_picker.read(cx).date()
+                                        );
+                                        Self::confirm_submit(view.clone(), value.into(), cx);
+                                    }
+                                }),
+                        )
+                        .child(Button::new("cancel", cx).label("Cancel").on_click(|_, cx| {
+                            cx.close_modal();
+                        })),
+                )
+        });
+    }
+}
 
-                                        view.update(cx, |view, cx| {
-                                            view.selected_value = Some(
-                                                format!(
-                                                    "Hello, {}, date: {}",
-                                                    input1.read(cx).text(),
-                                                    date_picker.read(cx).date()
-                                                )
-                                                .into(),
-                                            )
+impl ModalStory {
+    /// Open a confirm modal on top of the form modal, the form is kept if the submit is canceled.
+    fn confirm_submit(view: View<Self>, value: SharedString, cx: &mut WindowContext) {
+        cx.open_modal(move |modal, cx| {
+            modal
+                .title("Submit the form?")
+                .accessible_label("Submit the form?")
+                .width(px(360.))
+                .child(format!("The value will be: {}", value))
+                .footer(
+                    h_flex()
+                        .gap_6()
+                        .items_center()
+                        .child(
+                            Button::new("submit", cx)
+                                .primary()
+                                .label("Submit")
+                                .on_click({
+                                    let view = view.clone();
+                                    let value = value.clone();
+                                    move |_, cx| {
+                                        // Close the confirm and the form modals.
+                                        cx.close_modal();
+                                        cx.close_modal();
+                                        view.update(cx, |view, _| {
+                                            view.selected_value = Some(value.clone())
                                         });
                                     }
                                 }),
                         )
-                        .child(Button::new("cancel", cx).label("Cancel").on_click(|_, cx| {
+                        .child(Button::new("back", cx).label("Back").on_click(|_, cx| {
                             cx.close_modal();
                         })),
                 )
@@ -365,6 +405,15 @@ impl FocusableView for ModalStory {
     }
 }
 
+impl BadgeStory for ModalStory {
+    fn badge(&self, cx: &WindowContext) -> Option<PanelBadge> {
+        match cx.unread_notifications_count() {
+            0 => None,
+            count => Some(PanelBadge::Count(count)),
+        }
+    }
+}
+
 impl Render for ModalStory {
     fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
         div().size_full().child(
@@ -512,6 +561,38 @@ impl Render for ModalStory {
                                         ),
                                     )
                                 })),
+                        )
+                        .child(
+                            Button::new("show-notify-actions", cx)
+                                .label("Notification with Actions")
+                                .on_click(cx.listener(|_, _, cx| {
+                                    cx.push_notification(
+                                        Notification::new("The file has been moved to trash.")
+                                            .id::<ActionsNotification>()
+                                            .autohide(false)
+                                            .action(
+                                                "Undo",
+                                                cx.listener(|view, _, cx| {
+                                                    view.selected_value =
+                                                        Some("File restored".into());
+                                                    cx.notify();
+                                                }),
+                                            )
+                                            .action(
+                                                "Open Trash",
+                                                cx.listener(|view, _, cx| {
+                                                    view.selected_value =
+                                                        Some("Trash opened".into());
+                                                    cx.notify();
+                                                }),
+                                            ),
+                                    )
+                                })),
+                        )
+                        .child(
+                            Button::new("dismiss-notify-actions", cx)
+                                .label("Dismiss Actions Notification")
+                                .on_click(|_, cx| cx.remove_notification::<ActionsNotification>()),
                         ),
                 ),
         )
@@ -0,0 +1,196 @@
+use std::{path::PathBuf, time::Duration};
+
+use gpui::{
+    div, px, AppContext, IntoElement, ParentElement as _, Render, SharedString, Styled as _,
+    Subscription, Task, View, ViewContext, VisualContext as _, WindowContext,
+};
+use ui::{
+    button::Button,
+    file_dialog::{self, SaveOptions},
+    h_flex,
+    input::{InputEvent, TextInput},
+    notification::Notification,
+    theme::ActiveTheme as _,
+    v_flex, ContextModal as _, IconName, Sizable as _,
+};
+
+use crate::UnsavedStory;
+
+/// The delay to save the notes after the last change.
+const AUTOSAVE_DELAY: Duration = Duration::from_millis(800);
+
+/// A scratchpad to write the notes in a multi-line [`TextInput`], saved to the file automatically.
+pub struct NotesStory {
+    path: PathBuf,
+    editor: View<TextInput>,
+    /// The text written to the file, to know if there are unsaved changes.
+    saved_text: SharedString,
+    _autosave_task: Option<Task<()>>,
+    _write_task: Option<Task<()>>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl NotesStory {
+    /// Create the notes, the text is read from the `path` if it exists.
+    pub fn view(path: PathBuf, cx: &mut WindowContext) -> View<Self> {
+        cx.new_view(|cx| Self::new(path, cx))
+    }
+
+    fn new(path: PathBuf, cx: &mut ViewContext<Self>) -> Self {
+        let saved_text: SharedString = std::fs::read_to_string(&path).unwrap_or_default().into();
+        let editor = cx.new_view(|cx| {
+            let mut input = TextInput::new(cx)
+                .multi_line()
+                .placeholder("Write something down...");
+            input.set_text(saved_text.clone(), cx);
+            input
+        });
+
+        let _subscriptions = vec![cx.subscribe(&editor, |this, _, event: &InputEvent, cx| {
+            if let InputEvent::Change(_) = event {
+                this.schedule_save(cx);
+            }
+        })];
+
+        Self {
+            path,
+            editor,
+            saved_text,
+            _autosave_task: None,
+            _write_task: None,
+            _subscriptions,
+        }
+    }
+
+    /// Returns the text of the notes, including the changes not saved yet.
+    pub fn text(&self, cx: &AppContext) -> SharedString {
+        self.editor.read(cx).text()
+    }
+
+    /// Save the notes after the [`AUTOSAVE_DELAY`], the pending save is replaced by the new one.
+    fn schedule_save(&mut self, cx: &mut ViewContext<Self>) {
+        cx.notify();
+        self._autosave_task = Some(cx.spawn(|this, mut cx| async move {
+            cx.background_executor().timer(AUTOSAVE_DELAY).await;
+            let _ = this.update(&mut cx, |this, cx| this.save_now(cx));
+        }));
+    }
+
+    /// Write the text to the file in the background.
+    fn save_now(&mut self, cx: &mut ViewContext<Self>) {
+        let text = self.editor.read(cx).text();
+        if text == self.saved_text {
+            return;
+        }
+
+        let path = self.path.clone();
+        let write = cx.background_executor().spawn({
+            let text = text.clone();
+            async move {
+                if let Some(dir) = path.parent() {
+                    std::fs::create_dir_all(dir)?;
+                }
+                std::fs::write(&path, text.as_bytes())
+            }
+        });
+
+        self._write_task = Some(cx.spawn(|this, mut cx| async move {
+            let result = write.await;
+            let _ = this.update(&mut cx, |this, cx| {
+                match result {
+                    Ok(_) => this.saved_text = text,
+                    Err(err) => cx.push_notification(Notification::error(format!(
+                        "Failed to save the notes: {}",
+                        err
+                    ))),
+                }
+                cx.notify();
+            });
+        }));
+    }
+
+    /// Pick a path by the save dialog, and write a copy of the notes to it.
+    fn export(&mut self, cx: &mut ViewContext<Self>) {
+        let text = self.editor.read(cx).text();
+        let path = file_dialog::prompt_save(SaveOptions::new().extension("md"), cx);
+
+        cx.spawn(|this, mut cx| async move {
+            let result = match path.await {
+                Ok(Some(path)) => cx
+                    .background_executor()
+                    .spawn(async move { std::fs::write(&path, text.as_bytes()).map(|_| path) })
+                    .await
+                    .map_err(anyhow::Error::from),
+                Ok(None) => return,
+                Err(err) => Err(err),
+            };
+
+            let _ = this.update(&mut cx, |_, cx| match result {
+                Ok(path) => cx.push_notification(Notification::success(format!(
+                    "Notes exported to {}",
+                    path.display()
+                ))),
+                Err(err) => cx.push_notification(Notification::error(format!(
+                    "Failed to export the notes: {}",
+                    err
+                ))),
+            });
+        })
+        .detach();
+    }
+}
+
+impl UnsavedStory for NotesStory {
+    fn has_unsaved_changes(&self, cx: &AppContext) -> bool {
+        self.editor.read(cx).text() != self.saved_text
+    }
+
+    fn save(&mut self, cx: &mut ViewContext<Self>) {
+        self.save_now(cx);
+    }
+}
+
+impl Render for NotesStory {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let text = self.editor.read(cx).text();
+        let status = if text == self.saved_text {
+            "Saved"
+        } else {
+            "Editing..."
+        };
+        let words = text.split_whitespace().count();
+
+        v_flex()
+            .size_full()
+            .gap_2()
+            .child(
+                h_flex()
+                    .items_center()
+                    .gap_4()
+                    .text_sm()
+                    .text_color(cx.theme().tokens.on_surface_muted)
+                    .child(div().flex_1().child(self.path.display().to_string()))
+                    .child(format!(
+                        "{} words, {} characters",
+                        words,
+                        text.chars().count()
+                    ))
+                    .child(status)
+                    .child(
+                        Button::new("export-notes", cx)
+                            .icon(IconName::ArrowUp)
+                            .label("Export...")
+                            .small()
+                            .on_click(cx.listener(|this, _, cx| this.export(cx))),
+                    )
+                    .child(
+                        Button::new("save-notes", cx)
+                            .icon(IconName::Check)
+                            .label("Save")
+                            .small()
+                            .on_click(cx.listener(|this, _, cx| this.save_now(cx))),
+                    ),
+            )
+            .child(div().flex_1().min_h(px(240.)).child(self.editor.clone()))
+    }
+}
@@ -0,0 +1,121 @@
+use std::time::Duration;
+
+use gpui::{
+    div, px, AppContext, FocusHandle, FocusableView, IntoElement, ParentElement, Render,
+    SharedString, Styled, Task, View, ViewContext, VisualContext as _, WindowContext,
+};
+use ui::{
+    h_flex,
+    list::{DataSource, List, ListItem, PagedListDelegate},
+    theme::ActiveTheme as _,
+    v_flex,
+};
+
+/// A synthetic source of the rows, to simulate loading a large dataset from the network.
+struct SyntheticSource {
+    total: usize,
+    delay: Duration,
+}
+
+struct SyntheticRow {
+    id: usize,
+    title: SharedString,
+}
+
+impl DataSource for SyntheticSource {
+    type Item = SyntheticRow;
+
+    fn load_page(
+        &self,
+        offset: usize,
+        limit: usize,
+        cx: &mut AppContext,
+    ) -> Task<Vec<SyntheticRow>> {
+        let end = (offset + limit).min(self.total);
+        let timer = cx.background_executor().timer(self.delay);
+
+        cx.background_executor().spawn(async move {
+            timer.await;
+            (offset..end)
+                .map(|id| SyntheticRow {
+                    id,
+                    title: format!("Synthetic row {}", id + 1).into(),
+                })
+                .collect()
+        })
+    }
+}
+
+pub struct PagedListStory {
+    focus_handle: FocusHandle,
+    list: View<List<PagedListDelegate<SyntheticSource>>>,
+}
+
+impl PagedListStory {
+    pub fn view(cx: &mut WindowContext) -> View<Self> {
+        cx.new_view(Self::new)
+    }
+
+    fn new(cx: &mut ViewContext<Self>) -> Self {
+        let source = SyntheticSource {
+            total: 1_000_000,
+            delay: Duration::from_millis(300),
+        };
+        let delegate = PagedListDelegate::new(source, |row, ix, cx| {
+            ListItem::new(("row", ix)).child(
+                h_flex().justify_between().child(row.title.clone()).child(
+                    div()
+                        .text_sm()
+                        .text_color(cx.theme().tokens.on_surface_muted)
+                        .child(format!("#{}", row.id)),
+                ),
+            )
+        })
+        .page_size(200);
+
+        let list = cx.new_view(|cx| List::new(delegate, cx).no_query().virtualized(px(32.)));
+        // Update the loaded count.
+        cx.observe(&list, |_, _, cx| cx.notify()).detach();
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            list,
+        }
+    }
+}
+
+impl FocusableView for PagedListStory {
+    fn focus_handle(&self, _: &AppContext) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for PagedListStory {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let delegate = self.list.read(cx).delegate();
+        let loaded = delegate.items().len();
+        let total = delegate.source().total;
+
+        v_flex()
+            .size_full()
+            .gap_4()
+            .child(
+                div()
+                    .text_sm()
+                    .text_color(cx.theme().tokens.on_surface_muted)
+                    .child(format!(
+                        "{} of {} rows loaded, the next page is loaded when scrolled near the end.",
+                        loaded, total
+                    )),
+            )
+            .child(
+                div()
+                    .flex_1()
+                    .min_h(px(300.))
+                    .border_1()
+                    .border_color(cx.theme().tokens.outline)
+                    .rounded_md()
+                    .child(self.list.clone()),
+            )
+    }
+}
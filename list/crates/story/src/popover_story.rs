@@ -0,0 +1,117 @@
+use gpui::{
+    div, px, AnchorCorner, AppContext, FocusHandle, FocusableView, IntoElement, ParentElement,
+    Render, Styled, View, ViewContext, VisualContext as _, WindowContext,
+};
+use ui::{
+    button::Button,
+    color_picker::ColorPicker,
+    divider::Divider,
+    h_flex,
+    popover::{Popover, PopoverContent},
+    theme::ActiveTheme as _,
+    v_flex, Sizable as _,
+};
+
+pub struct PopoverStory {
+    focus_handle: FocusHandle,
+    color_picker: View<ColorPicker>,
+}
+
+impl PopoverStory {
+    pub fn view(cx: &mut WindowContext) -> View<Self> {
+        cx.new_view(Self::new)
+    }
+
+    fn new(cx: &mut ViewContext<Self>) -> Self {
+        Self {
+            focus_handle: cx.focus_handle(),
+            color_picker: cx.new_view(|cx| ColorPicker::new("popover-color-picker", cx)),
+        }
+    }
+
+    /// A popover with the `TopLeft` anchor, it is flipped when there is no room below or right.
+    fn popover(id: &'static str, label: &'static str, cx: &mut WindowContext) -> impl IntoElement {
+        Popover::new(id)
+            .trigger(Button::new(id, cx).label(label).small())
+            .content(move |cx| {
+                cx.new_view(|cx| {
+                    PopoverContent::new(cx, move |cx| {
+                        v_flex()
+                            .gap_2()
+                            .w(px(240.))
+                            .child(format!("The popover of {}.", label))
+                            .child(Divider::horizontal())
+                            .child(
+                                div()
+                                    .text_sm()
+                                    .text_color(cx.theme().tokens.on_surface_muted)
+                                    .child("Click outside or press Escape to dismiss."),
+                            )
+                            .into_any()
+                    })
+                })
+            })
+    }
+}
+
+impl FocusableView for PopoverStory {
+    fn focus_handle(&self, _: &AppContext) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for PopoverStory {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        v_flex()
+            .size_full()
+            .gap_4()
+            .child(
+                h_flex()
+                    .gap_3()
+                    .child(
+                        Popover::new("anchor-top-right")
+                            .anchor(AnchorCorner::TopRight)
+                            .trigger(Button::new("anchor-top-right", cx).label("Top Right"))
+                            .content(|cx| {
+                                cx.new_view(|cx| {
+                                    PopoverContent::new(cx, |_| {
+                                        div().child("Anchored at the top right corner.").into_any()
+                                    })
+                                })
+                            }),
+                    )
+                    .child(self.color_picker.clone()),
+            )
+            .child(
+                div()
+                    .text_sm()
+                    .text_color(cx.theme().tokens.on_surface_muted)
+                    .child(
+                        "The popovers below all use the TopLeft anchor, \
+                        they flip to the other side when near the window edge.",
+                    ),
+            )
+            .child(
+                v_flex()
+                    .flex_1()
+                    .min_h(px(300.))
+                    .p_2()
+                    .justify_between()
+                    .border_1()
+                    .border_color(cx.theme().tokens.outline)
+                    .rounded_md()
+                    .child(
+                        h_flex()
+                            .justify_between()
+                            .child(Self::popover("flip-top-left", "Top Left", cx))
+                            .child(Self::popover("flip-top-right", "Top Right", cx)),
+                    )
+                    .child(
+                        h_flex()
+                            .justify_between()
+                            .child(Self::popover("flip-bottom-left", "Bottom Left", cx))
+                            .child(Self::popover("flip-bottom-right", "Bottom Right", cx)),
+                    ),
+            )
+    }
+}
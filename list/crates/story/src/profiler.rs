@@ -0,0 +1,79 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc, time::Duration};
+
+use gpui::{AppContext, Global, SharedString, WindowContext};
+use ui::perf::RenderTimings;
+
+/// The render timings of a story, recorded by the [`crate::StoryContainer`] on every draw.
+#[derive(Debug, Clone, Default)]
+pub struct StoryProfile {
+    pub name: SharedString,
+    pub renders: u32,
+    pub last: RenderTimings,
+    /// The sum of the timings of all the renders.
+    pub total: RenderTimings,
+    /// The max total time of a render.
+    pub max: Duration,
+}
+
+impl StoryProfile {
+    fn record(&mut self, timings: &RenderTimings) {
+        self.renders += 1;
+        self.last = *timings;
+        self.total.render += timings.render;
+        self.total.prepaint += timings.prepaint;
+        self.total.paint += timings.paint;
+        self.max = self.max.max(timings.total());
+    }
+
+    /// Returns the average timings of a render.
+    pub fn average(&self) -> RenderTimings {
+        let renders = self.renders.max(1);
+        RenderTimings {
+            render: self.total.render / renders,
+            prepaint: self.total.prepaint / renders,
+            paint: self.total.paint / renders,
+        }
+    }
+}
+
+/// The profiles by the story name, it's a `RefCell` to record in the paint without updating the global.
+#[derive(Default)]
+struct RenderProfiler {
+    stories: Rc<RefCell<HashMap<SharedString, StoryProfile>>>,
+}
+
+impl Global for RenderProfiler {}
+
+pub(crate) fn init(cx: &mut AppContext) {
+    cx.set_global(RenderProfiler::default());
+}
+
+/// Add the timings of a render of the story.
+pub(crate) fn record(name: &SharedString, timings: &RenderTimings, cx: &WindowContext) {
+    let Some(profiler) = cx.try_global::<RenderProfiler>() else {
+        return;
+    };
+
+    profiler
+        .stories
+        .borrow_mut()
+        .entry(name.clone())
+        .or_insert_with(|| StoryProfile {
+            name: name.clone(),
+            ..Default::default()
+        })
+        .record(timings);
+}
+
+/// Returns the profiles of the stories rendered since the start, or the last [`reset_profiles`].
+pub fn profiles(cx: &AppContext) -> Vec<StoryProfile> {
+    cx.try_global::<RenderProfiler>()
+        .map(|profiler| profiler.stories.borrow().values().cloned().collect())
+        .unwrap_or_default()
+}
+
+pub fn reset_profiles(cx: &AppContext) {
+    if let Some(profiler) = cx.try_global::<RenderProfiler>() {
+        profiler.stories.borrow_mut().clear();
+    }
+}
@@ -0,0 +1,273 @@
+use std::time::Duration;
+
+use gpui::{
+    div, prelude::FluentBuilder as _, px, relative, InteractiveElement as _, IntoElement,
+    ParentElement as _, Pixels, Render, SharedString, StatefulInteractiveElement as _, Styled as _,
+    Task, View, ViewContext, VisualContext as _, WindowContext,
+};
+use ui::{
+    button::Button,
+    chart::{chart_color, Legend},
+    divider::Divider,
+    h_flex,
+    perf::RenderTimings,
+    table::{ColSort, Table, TableDelegate},
+    theme::ActiveTheme as _,
+    tooltip::Tooltip,
+    v_flex, IconName, Sizable as _,
+};
+
+use crate::profiler::{self, StoryProfile};
+
+/// The interval to refresh the profiles.
+const REFRESH_INTERVAL: Duration = Duration::from_secs(1);
+const COLUMNS: [&str; 6] = ["Story", "Renders", "Last", "Average", "Max", "Total"];
+const PHASES: [&str; 3] = ["Render & Layout", "Prepaint", "Paint"];
+
+fn format_duration(duration: Duration) -> String {
+    format!("{:.2}ms", duration.as_secs_f64() * 1000.)
+}
+
+fn phases(timings: &RenderTimings) -> [Duration; 3] {
+    [timings.render, timings.prepaint, timings.paint]
+}
+
+struct ProfilesTableDelegate {
+    profiles: Vec<StoryProfile>,
+    sorts: Vec<ColSort>,
+}
+
+impl ProfilesTableDelegate {
+    fn new() -> Self {
+        let mut sorts = vec![ColSort::Default; COLUMNS.len()];
+        // The slowest stories are the first by default.
+        sorts[3] = ColSort::Descending;
+
+        Self {
+            profiles: vec![],
+            sorts,
+        }
+    }
+
+    fn set_profiles(&mut self, profiles: Vec<StoryProfile>) {
+        self.profiles = profiles;
+        self.sort();
+    }
+
+    fn sort(&mut self) {
+        let Some((col_ix, sort)) = self
+            .sorts
+            .iter()
+            .enumerate()
+            .find(|(_, sort)| **sort != ColSort::Default)
+            .map(|(ix, sort)| (ix, *sort))
+        else {
+            self.profiles.sort_by(|a, b| a.name.cmp(&b.name));
+            return;
+        };
+
+        self.profiles.sort_by(|a, b| {
+            let ordering = match col_ix {
+                0 => a.name.cmp(&b.name),
+                1 => a.renders.cmp(&b.renders),
+                2 => a.last.total().cmp(&b.last.total()),
+                3 => a.average().total().cmp(&b.average().total()),
+                4 => a.max.cmp(&b.max),
+                _ => a.total.total().cmp(&b.total.total()),
+            };
+            match sort {
+                ColSort::Descending => ordering.reverse(),
+                _ => ordering,
+            }
+        });
+    }
+}
+
+impl TableDelegate for ProfilesTableDelegate {
+    fn cols_count(&self) -> usize {
+        COLUMNS.len()
+    }
+
+    fn rows_count(&self) -> usize {
+        self.profiles.len()
+    }
+
+    fn col_name(&self, col_ix: usize) -> SharedString {
+        COLUMNS[col_ix].into()
+    }
+
+    fn col_width(&self, col_ix: usize) -> Option<Pixels> {
+        Some(px(if col_ix == 0 { 160. } else { 100. }))
+    }
+
+    fn col_sort(&self, col_ix: usize) -> Option<ColSort> {
+        self.sorts.get(col_ix).copied()
+    }
+
+    fn perform_sort(&mut self, col_ix: usize, sort: ColSort, _: &mut ViewContext<Table<Self>>) {
+        for (ix, col_sort) in self.sorts.iter_mut().enumerate() {
+            *col_sort = if ix == col_ix { sort } else { ColSort::Default };
+        }
+        self.sort();
+    }
+
+    fn render_td(
+        &self,
+        row_ix: usize,
+        col_ix: usize,
+        _: &mut ViewContext<Table<Self>>,
+    ) -> impl IntoElement {
+        let Some(profile) = self.profiles.get(row_ix) else {
+            return String::new();
+        };
+
+        match col_ix {
+            0 => profile.name.to_string(),
+            1 => profile.renders.to_string(),
+            2 => format_duration(profile.last.total()),
+            3 => format_duration(profile.average().total()),
+            4 => format_duration(profile.max),
+            _ => format_duration(profile.total.total()),
+        }
+    }
+}
+
+/// The render time of the stories recorded by the [`crate::StoryContainer`], to find the slow stories.
+pub struct ProfilerStory {
+    table: View<Table<ProfilesTableDelegate>>,
+    _refresh_task: Task<()>,
+}
+
+impl ProfilerStory {
+    pub fn view(cx: &mut WindowContext) -> View<Self> {
+        cx.new_view(Self::new)
+    }
+
+    fn new(cx: &mut ViewContext<Self>) -> Self {
+        let table = cx.new_view(|cx| Table::new(ProfilesTableDelegate::new(), cx));
+
+        let _refresh_task = cx.spawn(|this, mut cx| async move {
+            loop {
+                let result = this.update(&mut cx, |this, cx| this.refresh(cx));
+                if result.is_err() {
+                    return;
+                }
+                cx.background_executor().timer(REFRESH_INTERVAL).await;
+            }
+        });
+
+        Self {
+            table,
+            _refresh_task,
+        }
+    }
+
+    fn refresh(&mut self, cx: &mut ViewContext<Self>) {
+        let profiles = profiler::profiles(cx);
+        self.table.update(cx, |table, cx| {
+            table.delegate_mut().set_profiles(profiles);
+            cx.notify();
+        });
+        cx.notify();
+    }
+
+    fn reset(&mut self, cx: &mut ViewContext<Self>) {
+        profiler::reset_profiles(cx);
+        self.refresh(cx);
+    }
+
+    /// The average time of the phases of each story as the stacked bars, the slowest is the first.
+    fn render_breakdown(&self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let mut profiles = self.table.read(cx).delegate().profiles.clone();
+        profiles.sort_by_key(|profile| std::cmp::Reverse(profile.average().total()));
+        let max = profiles
+            .first()
+            .map(|profile| profile.average().total())
+            .unwrap_or_default()
+            .max(Duration::from_micros(1));
+        let colors = [chart_color(0, cx), chart_color(1, cx), chart_color(2, cx)];
+
+        v_flex()
+            .gap_1()
+            .child(Legend::new(
+                colors
+                    .iter()
+                    .zip(PHASES)
+                    .map(|(color, phase)| (*color, phase.into())),
+            ))
+            .children(profiles.into_iter().enumerate().map(|(row_ix, profile)| {
+                let average = profile.average();
+
+                h_flex()
+                    .gap_2()
+                    .text_xs()
+                    .child(
+                        div()
+                            .w(px(160.))
+                            .flex_none()
+                            .overflow_hidden()
+                            .child(profile.name.clone()),
+                    )
+                    .child(h_flex().flex_1().h(px(14.)).children(
+                        phases(&average).into_iter().zip(colors).enumerate().map(
+                            |(phase_ix, (duration, color))| {
+                                let meta =
+                                    format!("{}: {}", PHASES[phase_ix], format_duration(duration));
+                                let name = profile.name.clone();
+
+                                div()
+                                    .id(("phase", row_ix * PHASES.len() + phase_ix))
+                                    .h_full()
+                                    .w(relative(
+                                        (duration.as_secs_f64() / max.as_secs_f64()) as f32,
+                                    ))
+                                    .bg(color)
+                                    .tooltip(move |cx| {
+                                        Tooltip::with_meta(name.clone(), None, meta.clone(), cx)
+                                    })
+                            },
+                        ),
+                    ))
+                    .child(
+                        div()
+                            .w(px(64.))
+                            .flex_none()
+                            .text_right()
+                            .text_color(cx.theme().tokens.on_surface_muted)
+                            .child(format_duration(average.total())),
+                    )
+            }))
+    }
+}
+
+impl Render for ProfilerStory {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let is_empty = self.table.read(cx).delegate().profiles.is_empty();
+
+        v_flex()
+            .size_full()
+            .gap_3()
+            .child(
+                h_flex()
+                    .gap_4()
+                    .items_center()
+                    .child(
+                        div()
+                            .flex_1()
+                            .text_sm()
+                            .text_color(cx.theme().tokens.on_surface_muted)
+                            .child("The render time of the stories since the app started, open some stories to profile them."),
+                    )
+                    .child(
+                        Button::new("reset-profiles", cx)
+                            .icon(IconName::Delete)
+                            .label("Reset")
+                            .small()
+                            .on_click(cx.listener(|this, _, cx| this.reset(cx))),
+                    ),
+            )
+            .child(div().h(px(240.)).child(self.table.clone()))
+            .child(Divider::horizontal().label("Average Breakdown"))
+            .when(!is_empty, |this| this.child(self.render_breakdown(cx)))
+    }
+}
@@ -1,6 +1,8 @@
+use std::time::Duration;
+
 use gpui::{
-    div, px, IntoElement, ParentElement, Render, Styled, View, ViewContext, VisualContext,
-    WindowContext,
+    div, prelude::FluentBuilder as _, px, IntoElement, ParentElement, Render, Styled, Task, View,
+    ViewContext, VisualContext, WindowContext,
 };
 use ui::{
     button::Button,
@@ -8,17 +10,30 @@ use ui::{
     h_flex,
     indicator::Indicator,
     progress::Progress,
-    skeleton::Skeleton,
+    skeleton::{Skeleton, SkeletonCard, SkeletonText},
     slider::{Slider, SliderEvent},
-    v_flex, IconName, Sizable,
+    theme::ActiveTheme as _,
+    v_flex, Disableable as _, IconName, Sizable,
 };
 
+/// The state of the simulated long-running task.
+#[derive(Clone, Copy, PartialEq)]
+enum TaskState {
+    Idle,
+    /// Preparing, the progress is unknown.
+    Preparing,
+    Running(f32),
+    Done,
+}
+
 pub struct ProgressStory {
     value: f32,
     slider1: View<Slider>,
     slider1_value: f32,
     slider2: View<Slider>,
     slider2_value: f32,
+    task_state: TaskState,
+    _task: Task<()>,
 }
 
 impl ProgressStory {
@@ -57,12 +72,98 @@ impl ProgressStory {
             slider2_value: 1.,
             slider1,
             slider2,
+            task_state: TaskState::Idle,
+            _task: Task::ready(()),
         }
     }
 
     pub fn set_value(&mut self, value: f32) {
         self.value = value;
     }
+
+    /// Simulate a long-running task in background, it reports the progress to the view.
+    fn start_task(&mut self, cx: &mut ViewContext<Self>) {
+        self.task_state = TaskState::Preparing;
+        cx.notify();
+
+        self._task = cx.spawn(|this, mut cx| async move {
+            cx.background_executor()
+                .timer(Duration::from_millis(1500))
+                .await;
+
+            for i in 0..=100 {
+                let state = if i == 100 {
+                    TaskState::Done
+                } else {
+                    TaskState::Running(i as f32)
+                };
+                let result = this.update(&mut cx, |this, cx| {
+                    this.task_state = state;
+                    cx.notify();
+                });
+                if result.is_err() {
+                    return;
+                }
+
+                cx.background_executor()
+                    .timer(Duration::from_millis(50))
+                    .await;
+            }
+        });
+    }
+
+    fn cancel_task(&mut self, cx: &mut ViewContext<Self>) {
+        // Drop the task to cancel it.
+        self._task = Task::ready(());
+        self.task_state = TaskState::Idle;
+        cx.notify();
+    }
+
+    fn render_task(&self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let running = matches!(
+            self.task_state,
+            TaskState::Preparing | TaskState::Running(_)
+        );
+        let (progress, status) = match self.task_state {
+            TaskState::Idle => (Progress::new(), "Click Start to run the task.".to_string()),
+            TaskState::Preparing => (
+                Progress::new().indeterminate(true),
+                "Preparing...".to_string(),
+            ),
+            TaskState::Running(value) => {
+                (Progress::new().value(value), format!("Running {}%", value))
+            }
+            TaskState::Done => (Progress::new().value(100.), "Done".to_string()),
+        };
+
+        v_flex()
+            .w_1_2()
+            .gap_3()
+            .child(
+                h_flex()
+                    .gap_x_2()
+                    .child(
+                        Button::new("start-task", cx)
+                            .label("Start")
+                            .disabled(running)
+                            .on_click(cx.listener(|this, _, cx| this.start_task(cx))),
+                    )
+                    .child(
+                        Button::new("cancel-task", cx)
+                            .label("Cancel")
+                            .disabled(!running)
+                            .on_click(cx.listener(|this, _, cx| this.cancel_task(cx))),
+                    )
+                    .when(running, |this| this.child(Indicator::new().small()))
+                    .child(
+                        div()
+                            .text_sm()
+                            .text_color(cx.theme().tokens.on_surface_muted)
+                            .child(status),
+                    ),
+            )
+            .child(progress)
+    }
 }
 
 impl Render for ProgressStory {
@@ -135,6 +236,8 @@ impl Render for ProgressStory {
                     )
                     .child(Indicator::new().with_size(px(64.))),
             )
+            .child(Divider::horizontal().mt_10().label("Task"))
+            .child(self.render_task(cx))
             .child(Divider::horizontal().mt_10().label("Slider"))
             .child(self.slider1.clone())
             .child(format!("Slider 1: {}", self.slider1_value))
@@ -149,7 +252,7 @@ impl Render for ProgressStory {
                 h_flex()
                     .mt_5()
                     .gap_4()
-                    .child(Skeleton::new().size_12().rounded_full())
+                    .child(Skeleton::avatar().size_12())
                     .child(
                         v_flex()
                             .gap_2()
@@ -157,5 +260,12 @@ impl Render for ProgressStory {
                             .child(Skeleton::new().w(px(240.)).h_4()),
                     ),
             )
+            .child(
+                h_flex()
+                    .gap_4()
+                    .items_start()
+                    .child(SkeletonCard::new())
+                    .child(div().w(px(320.)).child(SkeletonText::new(4))),
+            )
     }
 }
@@ -23,7 +23,7 @@ impl ResizableStory {
             div()
                 .p_4()
                 .border_1()
-                .border_color(cx.theme().border)
+                .border_color(cx.theme().tokens.outline)
                 .size_full()
                 .child(content.into())
                 .into_any_element()
@@ -37,6 +37,7 @@ impl ResizableStory {
                         .child(
                             resizable_panel()
                                 .size(px(300.))
+                                .min_size(px(120.))
                                 .content(|cx| panel_box("Left 1 (Min 120px)", cx)),
                             cx,
                         )
@@ -73,7 +74,8 @@ impl ResizableStory {
                 .child(
                     resizable_panel()
                         .size(px(300.))
-                        .content(|cx| panel_box("Left 2", cx)),
+                        .max_size(px(500.))
+                        .content(|cx| panel_box("Left 2 (Max 500px)", cx)),
                     cx,
                 )
                 .child(
@@ -0,0 +1,212 @@
+use std::time::Duration;
+
+use fake::Fake;
+use gpui::{
+    div, px, Hsla, IntoElement, ParentElement as _, Render, SharedString, Styled as _, Task, View,
+    ViewContext, VisualContext as _, WindowContext,
+};
+use ui::{
+    avatar::Avatar,
+    button::Button,
+    chart::Sparkline,
+    column_table::{ColumnTableDelegate, TableColumn},
+    h_flex,
+    progress::Progress,
+    table::Table,
+    tag::Badge,
+    theme::ActiveTheme as _,
+    v_flex, Sizable as _,
+};
+
+/// The number of the samples of the traffic sparkline.
+const TRAFFIC_SAMPLES: usize = 20;
+/// The interval to sample the traffic and the CPU of the running services.
+const UPDATE_INTERVAL: Duration = Duration::from_secs(1);
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum ServiceStatus {
+    Running,
+    Degraded,
+    Stopped,
+}
+
+impl ServiceStatus {
+    fn label(&self) -> &'static str {
+        match self {
+            Self::Running => "Running",
+            Self::Degraded => "Degraded",
+            Self::Stopped => "Stopped",
+        }
+    }
+
+    fn color(&self, cx: &WindowContext) -> Hsla {
+        match self {
+            Self::Running => ui::green_500(),
+            Self::Degraded => ui::yellow_500(),
+            Self::Stopped => cx.theme().tokens.danger,
+        }
+    }
+}
+
+struct Service {
+    name: SharedString,
+    owner: SharedString,
+    status: ServiceStatus,
+    /// The requests per second of the last samples.
+    traffic: Vec<f32>,
+    /// The CPU usage in percent.
+    cpu: f32,
+}
+
+impl Service {
+    fn new(name: &'static str, owner: &'static str, status: ServiceStatus) -> Self {
+        let mut service = Self {
+            name: name.into(),
+            owner: owner.into(),
+            status,
+            traffic: Vec::with_capacity(TRAFFIC_SAMPLES),
+            cpu: 0.,
+        };
+        for _ in 0..TRAFFIC_SAMPLES {
+            service.sample();
+        }
+        service
+    }
+
+    /// Add a random sample of the traffic and the CPU, a stopped service has no traffic.
+    fn sample(&mut self) {
+        let (traffic, cpu) = match self.status {
+            ServiceStatus::Running => ((200.0..400.0).fake::<f32>(), (20.0..60.0).fake::<f32>()),
+            ServiceStatus::Degraded => ((20.0..200.0).fake::<f32>(), (80.0..100.0).fake::<f32>()),
+            ServiceStatus::Stopped => (0., 0.),
+        };
+        self.traffic.push(traffic);
+        if self.traffic.len() > TRAFFIC_SAMPLES {
+            self.traffic.remove(0);
+        }
+        self.cpu = cpu;
+    }
+
+    fn restart(&mut self) {
+        self.status = ServiceStatus::Running;
+        self.sample();
+    }
+}
+
+fn columns() -> Vec<TableColumn<Service>> {
+    vec![
+        TableColumn::new("name", "Service", |_, service: &Service, _| {
+            h_flex()
+                .gap_2()
+                .child(Avatar::new(service.owner.clone()).xsmall())
+                .child(service.name.clone())
+        })
+        .width(px(200.))
+        .sortable(|a, b| a.name.cmp(&b.name)),
+        TableColumn::new("status", "Status", |_, service: &Service, cx| {
+            Badge::new(service.status.label()).color(service.status.color(cx))
+        })
+        .width(px(120.))
+        .sortable(|a, b| a.status.cmp(&b.status)),
+        TableColumn::new("traffic", "Traffic", |_, service: &Service, _| {
+            h_flex().h_full().child(
+                Sparkline::new(service.traffic.iter().copied())
+                    .area(true)
+                    .size(px(140.), px(18.)),
+            )
+        })
+        .width(px(160.)),
+        TableColumn::new("cpu", "CPU", |_, service: &Service, _| {
+            h_flex()
+                .gap_2()
+                .child(div().w_20().child(Progress::new().value(service.cpu)))
+                .child(format!("{:.0}%", service.cpu))
+        })
+        .width(px(160.))
+        .sortable(|a, b| a.cpu.total_cmp(&b.cpu)),
+        TableColumn::text("owner", "Owner", |service: &Service| service.owner.clone())
+            .width(px(140.)),
+        TableColumn::new("actions", "", |row_ix, service: &Service, cx| {
+            Button::new(("restart", row_ix), cx)
+                .label(if service.status == ServiceStatus::Stopped {
+                    "Start"
+                } else {
+                    "Restart"
+                })
+                .xsmall()
+                .on_click(cx.listener(move |table, _, cx| {
+                    if let Some(service) = table.delegate_mut().rows_mut().get_mut(row_ix) {
+                        service.restart();
+                    }
+                    cx.notify();
+                }))
+        })
+        .width(px(100.)),
+    ]
+}
+
+/// A table of the services with the [`TableColumn`]s that render the badges, the sparklines,
+/// the progress bars and the buttons in the cells.
+pub struct RichTableStory {
+    table: View<Table<ColumnTableDelegate<Service>>>,
+    _update_task: Task<()>,
+}
+
+impl RichTableStory {
+    pub fn view(cx: &mut WindowContext) -> View<Self> {
+        cx.new_view(Self::new)
+    }
+
+    fn new(cx: &mut ViewContext<Self>) -> Self {
+        let services = vec![
+            Service::new("api-gateway", "Jason Lee", ServiceStatus::Running),
+            Service::new("auth", "Natalie Chen", ServiceStatus::Running),
+            Service::new("billing", "Marco Rossi", ServiceStatus::Degraded),
+            Service::new("search", "Aiko Tanaka", ServiceStatus::Running),
+            Service::new("notifications", "Liam Walker", ServiceStatus::Stopped),
+            Service::new("reports", "Sofia Garcia", ServiceStatus::Running),
+            Service::new("media", "Noah Smith", ServiceStatus::Degraded),
+            Service::new("webhooks", "Emma Brown", ServiceStatus::Running),
+        ];
+        let table = cx.new_view(|cx| {
+            Table::new(ColumnTableDelegate::new(columns(), services), cx).layout_id("services", cx)
+        });
+
+        let _update_task = cx.spawn(|this, mut cx| async move {
+            loop {
+                cx.background_executor().timer(UPDATE_INTERVAL).await;
+                let result = this.update(&mut cx, |this, cx| {
+                    this.table.update(cx, |table, cx| {
+                        for service in table.delegate_mut().rows_mut() {
+                            service.sample();
+                        }
+                        cx.notify();
+                    });
+                });
+                if result.is_err() {
+                    return;
+                }
+            }
+        });
+
+        Self {
+            table,
+            _update_task,
+        }
+    }
+}
+
+impl Render for RichTableStory {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        v_flex()
+            .size_full()
+            .gap_2()
+            .child(
+                div()
+                    .text_sm()
+                    .text_color(cx.theme().tokens.on_surface_muted)
+                    .child("Each column renders its cells by a closure, drag the headers to reorder the columns, right click them to hide the columns."),
+            )
+            .child(self.table.clone())
+    }
+}
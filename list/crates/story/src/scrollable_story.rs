@@ -7,9 +7,9 @@ use gpui::{
 };
 use ui::button::Button;
 use ui::divider::Divider;
-use ui::scroll::{Scrollbar, ScrollbarAxis, ScrollbarState};
-use ui::theme::ActiveTheme;
-use ui::{h_flex, v_flex, StyledExt as _};
+use ui::scroll::{Scrollbar, ScrollbarAxis, ScrollbarShow, ScrollbarState};
+use ui::theme::{ActiveTheme, Theme};
+use ui::{h_flex, v_flex, Selectable as _, StyledExt as _};
 
 pub struct ScrollableStory {
     scroll_handle: ScrollHandle,
@@ -58,6 +58,11 @@ impl ScrollableStory {
         self.axis = axis;
         cx.notify();
     }
+
+    pub fn change_scrollbar_show(&mut self, show: ScrollbarShow, cx: &mut ViewContext<Self>) {
+        cx.global_mut::<Theme>().scrollbar_show = show;
+        cx.refresh();
+    }
 }
 
 impl Render for ScrollableStory {
@@ -123,11 +128,31 @@ impl Render for ScrollableStory {
                             })),
                     ),
             )
+            .child(
+                h_flex().gap_2().children(
+                    [
+                        ("Hover", ScrollbarShow::Hover),
+                        ("Scrolling", ScrollbarShow::Scrolling),
+                        ("Always", ScrollbarShow::Always),
+                    ]
+                    .into_iter()
+                    .map(|(label, show)| {
+                        Button::new(label, cx)
+                            .label(label)
+                            .selected(cx.theme().scrollbar_show == show)
+                            .on_click(
+                                cx.listener(move |view, _, cx| {
+                                    view.change_scrollbar_show(show, cx)
+                                }),
+                            )
+                    }),
+                ),
+            )
             .child(
                 div()
                     .w_full()
                     .border_1()
-                    .border_color(cx.theme().border)
+                    .border_color(cx.theme().tokens.outline)
                     .child(
                         div()
                             .relative()
@@ -145,7 +170,9 @@ impl Render for ScrollableStory {
                                             .gap_1()
                                             .w(self.test_width)
                                             .children(self.items.iter().map(|s| {
-                                                div().bg(cx.theme().card).child(s.clone())
+                                                div()
+                                                    .bg(cx.theme().tokens.surface_raised)
+                                                    .child(s.clone())
                                             }))
                                             .child({
                                                 let view = cx.view().clone();
@@ -188,7 +215,7 @@ impl Render for ScrollableStory {
                 div()
                     .relative()
                     .border_1()
-                    .border_color(cx.theme().border)
+                    .border_color(cx.theme().tokens.outline)
                     .w_full()
                     .flex_1()
                     .overflow_hidden()
@@ -201,11 +228,9 @@ impl Render for ScrollableStory {
                             .w(test_width)
                             .gap_1()
                             .child("Hello world")
-                            .children(
-                                items
-                                    .iter()
-                                    .map(|s| div().bg(cx.theme().card).child(s.clone())),
-                            ),
+                            .children(items.iter().map(|s| {
+                                div().bg(cx.theme().tokens.surface_raised).child(s.clone())
+                            })),
                     )
             })
     }
@@ -0,0 +1,69 @@
+use std::{any::TypeId, collections::HashMap, rc::Rc};
+
+use gpui::{AnyView, AppContext, Global, SharedString, ViewContext, WindowContext};
+
+/// A story that has items can be found by the global search.
+pub trait SearchableStory: 'static + Sized {
+    /// Returns the labels of the items to search.
+    fn search_items(&self, cx: &AppContext) -> Vec<SharedString>;
+
+    /// Select and scroll to the item at the index of the [`SearchableStory::search_items`].
+    fn reveal_item(&mut self, ix: usize, cx: &mut ViewContext<Self>);
+}
+
+struct SearchAdapter {
+    search_items: Rc<dyn Fn(&AnyView, &AppContext) -> Vec<SharedString>>,
+    reveal_item: Rc<dyn Fn(&AnyView, usize, &mut WindowContext)>,
+}
+
+/// The registry of the searchable stories by the view type.
+#[derive(Default)]
+struct SearchRegistry {
+    items: HashMap<TypeId, SearchAdapter>,
+}
+
+impl Global for SearchRegistry {}
+
+/// Register a story view type, that the items of it can be found by the global search.
+pub fn register_searchable<S: SearchableStory>(cx: &mut AppContext) {
+    cx.default_global::<SearchRegistry>().items.insert(
+        TypeId::of::<S>(),
+        SearchAdapter {
+            search_items: Rc::new(|view, cx| {
+                view.clone()
+                    .downcast::<S>()
+                    .map(|view| view.read(cx).search_items(cx))
+                    .unwrap_or_default()
+            }),
+            reveal_item: Rc::new(|view, ix, cx| {
+                if let Ok(view) = view.clone().downcast::<S>() {
+                    view.update(cx, |view, cx| view.reveal_item(ix, cx));
+                }
+            }),
+        },
+    );
+}
+
+/// Returns the searchable items of the story view, empty if the view type is not registered.
+pub(crate) fn search_items(view: &AnyView, cx: &AppContext) -> Vec<SharedString> {
+    let Some(adapter) = cx
+        .try_global::<SearchRegistry>()
+        .and_then(|registry| registry.items.get(&view.entity_type()))
+    else {
+        return vec![];
+    };
+
+    (adapter.search_items)(view, cx)
+}
+
+pub(crate) fn reveal_item(view: &AnyView, ix: usize, cx: &mut WindowContext) {
+    let Some(reveal) = cx
+        .try_global::<SearchRegistry>()
+        .and_then(|registry| registry.items.get(&view.entity_type()))
+        .map(|adapter| adapter.reveal_item.clone())
+    else {
+        return;
+    };
+
+    reveal(view, ix, cx);
+}
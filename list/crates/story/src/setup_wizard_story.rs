@@ -0,0 +1,281 @@
+use gpui::{
+    div, prelude::FluentBuilder as _, Context as _, IntoElement, Model, ParentElement as _, Render,
+    SharedString, Styled as _, View, ViewContext, VisualContext as _, WindowContext,
+};
+use ui::{
+    button::Button,
+    h_flex,
+    radio::RadioGroup,
+    stepper::Stepper,
+    theme::{ActiveTheme as _, Theme, ThemeModeSetting},
+    v_flex,
+    wizard::{Wizard, WizardStep},
+    Disableable as _, Sizable as _,
+};
+
+use crate::{section, EventBus};
+
+const THEME_MODES: [(ThemeModeSetting, &str); 3] = [
+    (ThemeModeSetting::System, "System"),
+    (ThemeModeSetting::Light, "Light"),
+    (ThemeModeSetting::Dark, "Dark"),
+];
+
+/// The locales that the stories have been translated to, with the display names.
+const LOCALES: [(&str, &str); 3] = [
+    ("en", "English"),
+    ("zh-CN", "简体中文"),
+    ("zh-HK", "繁體中文"),
+];
+
+const STEPS: [&str; 3] = ["Theme", "Locale", "Confirm"];
+
+/// Published to the [`EventBus`] when the setup wizard is finished, to apply it to the settings.
+#[derive(Debug, Clone)]
+pub struct SetupFinished {
+    pub theme_mode: ThemeModeSetting,
+    pub locale: SharedString,
+}
+
+/// The choices of the setup wizard, shared by the steps.
+struct SetupState {
+    theme_mode: Option<usize>,
+    locale: Option<usize>,
+}
+
+impl SetupState {
+    fn summary(&self) -> Option<(&'static str, &'static str)> {
+        let (_, theme_mode) = THEME_MODES.get(self.theme_mode?)?;
+        let (_, locale) = LOCALES.get(self.locale?)?;
+        Some((theme_mode, locale))
+    }
+
+    fn finished(&self) -> Option<SetupFinished> {
+        let (theme_mode, _) = THEME_MODES.get(self.theme_mode?)?;
+        let (locale, _) = LOCALES.get(self.locale?)?;
+        Some(SetupFinished {
+            theme_mode: *theme_mode,
+            locale: (*locale).into(),
+        })
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum SetupPage {
+    Theme,
+    Locale,
+    Confirm,
+}
+
+/// The content of a step, it's a view to be updated when the choices are changed.
+struct SetupPageView {
+    page: SetupPage,
+    state: Model<SetupState>,
+}
+
+impl SetupPageView {
+    fn new(page: SetupPage, state: Model<SetupState>, cx: &mut ViewContext<Self>) -> Self {
+        cx.observe(&state, |_, _, cx| cx.notify()).detach();
+        Self { page, state }
+    }
+}
+
+impl Render for SetupPageView {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let state = self.state.read(cx);
+        let model = self.state.clone();
+
+        let content = match self.page {
+            SetupPage::Theme => RadioGroup::vertical("setup-theme")
+                .children(THEME_MODES.map(|(_, label)| label))
+                .selected_index(state.theme_mode)
+                .on_change(move |ix, cx| {
+                    model.update(cx, |state, cx| {
+                        state.theme_mode = Some(*ix);
+                        cx.notify();
+                    })
+                })
+                .into_any_element(),
+            SetupPage::Locale => RadioGroup::vertical("setup-locale")
+                .children(LOCALES.map(|(_, name)| name))
+                .selected_index(state.locale)
+                .on_change(move |ix, cx| {
+                    model.update(cx, |state, cx| {
+                        state.locale = Some(*ix);
+                        cx.notify();
+                    })
+                })
+                .into_any_element(),
+            SetupPage::Confirm => {
+                let (theme_mode, locale) = state.summary().unwrap_or_default();
+                v_flex()
+                    .gap_2()
+                    .text_sm()
+                    .child("The settings will be applied when you finish.")
+                    .child(format!("Theme: {}", theme_mode))
+                    .child(format!("Locale: {}", locale))
+                    .into_any_element()
+            }
+        };
+
+        v_flex()
+            .gap_3()
+            .child(
+                div()
+                    .text_sm()
+                    .text_color(cx.theme().tokens.on_surface_muted)
+                    .child(match self.page {
+                        SetupPage::Theme => "Choose the appearance of the app.",
+                        SetupPage::Locale => "Choose the language of the app.",
+                        SetupPage::Confirm => "Review the choices.",
+                    }),
+            )
+            .child(content)
+    }
+}
+
+/// A [`Stepper`] and a [`Wizard`] that configures the theme and the locale in three steps.
+pub struct SetupWizardStory {
+    selected_step: usize,
+    last_setup: Option<SharedString>,
+}
+
+impl SetupWizardStory {
+    pub fn view(cx: &mut WindowContext) -> View<Self> {
+        cx.new_view(|_| Self {
+            selected_step: 0,
+            last_setup: None,
+        })
+    }
+
+    fn open_wizard(&mut self, cx: &mut ViewContext<Self>) {
+        // Start with the current settings.
+        let theme_mode = THEME_MODES
+            .iter()
+            .position(|(mode, _)| *mode == Theme::mode_setting(cx));
+        let locale = LOCALES
+            .iter()
+            .position(|(locale, _)| *locale == &*rust_i18n::locale());
+        let state = cx.new_model(|_| SetupState { theme_mode, locale });
+        let page = |page, cx: &mut ViewContext<Self>| {
+            let state = state.clone();
+            cx.new_view(|cx| SetupPageView::new(page, state, cx))
+        };
+        let theme_page = page(SetupPage::Theme, cx);
+        let locale_page = page(SetupPage::Locale, cx);
+        let confirm_page = page(SetupPage::Confirm, cx);
+        let view = cx.view().clone();
+
+        Wizard::new("Setup Wizard")
+            .step(
+                WizardStep::new(STEPS[0], move |_| theme_page.clone()).validate({
+                    let state = state.clone();
+                    move |cx| match state.read(cx).theme_mode {
+                        Some(_) => Ok(()),
+                        None => Err("Please choose a theme.".into()),
+                    }
+                }),
+            )
+            .step(
+                WizardStep::new(STEPS[1], move |_| locale_page.clone()).validate({
+                    let state = state.clone();
+                    move |cx| match state.read(cx).locale {
+                        Some(_) => Ok(()),
+                        None => Err("Please choose a locale.".into()),
+                    }
+                }),
+            )
+            .step(WizardStep::new(STEPS[2], move |_| confirm_page.clone()))
+            .on_finish(move |cx| {
+                let Some(setup) = state.read(cx).finished() else {
+                    return;
+                };
+                view.update(cx, |this, cx| {
+                    this.last_setup =
+                        Some(format!("Finished: {:?}, {}", setup.theme_mode, setup.locale).into());
+                    cx.notify();
+                });
+                EventBus::publish(setup, cx);
+            })
+            .on_cancel({
+                let view = cx.view().clone();
+                move |cx| {
+                    view.update(cx, |this, cx| {
+                        this.last_setup = Some("Cancelled".into());
+                        cx.notify();
+                    })
+                }
+            })
+            .open(cx);
+    }
+
+    fn set_step(&mut self, ix: usize, cx: &mut ViewContext<Self>) {
+        self.selected_step = ix.min(STEPS.len() - 1);
+        cx.notify();
+    }
+}
+
+impl Render for SetupWizardStory {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let step = self.selected_step;
+
+        v_flex()
+            .gap_6()
+            .child(
+                section("Stepper", cx).child(
+                    v_flex()
+                        .w_full()
+                        .gap_4()
+                        .child(
+                            Stepper::new("stepper")
+                                .items(STEPS)
+                                .selected_index(step)
+                                .on_click(
+                                    cx.listener(|this, ix: &usize, cx| this.set_step(*ix, cx)),
+                                ),
+                        )
+                        .child(
+                            h_flex()
+                                .gap_2()
+                                .justify_end()
+                                .child(
+                                    Button::new("stepper-back", cx)
+                                        .label("Back")
+                                        .small()
+                                        .disabled(step == 0)
+                                        .on_click(cx.listener(move |this, _, cx| {
+                                            this.set_step(step.saturating_sub(1), cx)
+                                        })),
+                                )
+                                .child(
+                                    Button::new("stepper-next", cx)
+                                        .label("Next")
+                                        .small()
+                                        .primary()
+                                        .disabled(step + 1 >= STEPS.len())
+                                        .on_click(cx.listener(move |this, _, cx| {
+                                            this.set_step(step + 1, cx)
+                                        })),
+                                ),
+                        ),
+                ),
+            )
+            .child(
+                section("Setup Wizard", cx)
+                    .child(
+                        Button::new("open-setup-wizard", cx)
+                            .label("Open Setup Wizard")
+                            .primary()
+                            .on_click(cx.listener(|this, _, cx| this.open_wizard(cx))),
+                    )
+                    .when_some(self.last_setup.clone(), |this, last_setup| {
+                        this.child(
+                            div()
+                                .text_sm()
+                                .text_color(cx.theme().tokens.on_surface_muted)
+                                .child(last_setup),
+                        )
+                    }),
+            )
+    }
+}
@@ -0,0 +1,186 @@
+use std::ops::Range;
+
+use gpui::{
+    div, px, HighlightStyle, InteractiveElement as _, IntoElement, ParentElement as _,
+    StatefulInteractiveElement as _, Styled as _, StyledText, WindowContext,
+};
+use ui::theme::ActiveTheme as _;
+
+pub(crate) const MONOSPACE_FONT: &str = if cfg!(target_os = "macos") {
+    "Menlo"
+} else if cfg!(target_os = "windows") {
+    "Consolas"
+} else {
+    "monospace"
+};
+
+const KEYWORDS: &[&str] = &[
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "false",
+    "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref",
+    "return", "self", "Self", "static", "struct", "super", "trait", "true", "type", "unsafe",
+    "use", "where", "while",
+];
+
+/// Returns the source code of the story by the name in the [`crate::StoryContainer`].
+pub fn story_source(name: &str) -> Option<&'static str> {
+    let source = match name {
+        "Buttons" => include_str!("button_story.rs"),
+        "Input" => include_str!("input_story.rs"),
+        "Inspector" => include_str!("inspector_story.rs"),
+        "Text" => include_str!("text_story.rs"),
+        "Switch" => include_str!("switch_story.rs"),
+        "Dropdowns" => include_str!("dropdown_story.rs"),
+        "Form" => include_str!("form_story.rs"),
+        "Modal" => include_str!("modal_story.rs"),
+        "Drawer" => include_str!("drawer_story.rs"),
+        "Popup" => include_str!("popup_story.rs"),
+        "Popover" => include_str!("popover_story.rs"),
+        "Tooltip" => include_str!("tooltip_story.rs"),
+        "List" => include_str!("list_story.rs"),
+        "Detail" => include_str!("detail_story.rs"),
+        "PagedList" => include_str!("paged_list_story.rs"),
+        "GroupedList" => include_str!("grouped_list_story.rs"),
+        "Icon" => include_str!("icon_story.rs"),
+        "Image" => include_str!("image_story.rs"),
+        "SetupWizard" => include_str!("setup_wizard_story.rs"),
+        "Table" => include_str!("table_story.rs"),
+        "RichTable" => include_str!("rich_table_story.rs"),
+        "Kanban" => include_str!("kanban_story.rs"),
+        "Tag" => include_str!("tag_story.rs"),
+        "Tree" => include_str!("tree_story.rs"),
+        "Progress" => include_str!("progress_story.rs"),
+        "Avatar" => include_str!("avatar_story.rs"),
+        "Charts" => include_str!("charts_story.rs"),
+        "Metrics" => include_str!("metrics_story.rs"),
+        "Profiler" => include_str!("profiler_story.rs"),
+        "Resizable" => include_str!("resizable_story.rs"),
+        "Scrollable" => include_str!("scrollable_story.rs"),
+        "Calendar" => include_str!("calendar_story.rs"),
+        "ThemeEditor" => include_str!("theme_editor_story.rs"),
+        "ThemeContrast" => include_str!("theme_contrast_story.rs"),
+        "Notes" => include_str!("notes_story.rs"),
+        "Logs" => include_str!("logs_story.rs"),
+        _ => return None,
+    };
+
+    Some(source)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TokenKind {
+    Comment,
+    String,
+    Number,
+    Keyword,
+    Type,
+    Macro,
+    Attribute,
+}
+
+impl TokenKind {
+    fn style(&self) -> HighlightStyle {
+        let color = match self {
+            TokenKind::Comment => ui::gray_500(),
+            TokenKind::String => ui::green_600(),
+            TokenKind::Number => ui::orange_500(),
+            TokenKind::Keyword => ui::violet_500(),
+            TokenKind::Type => ui::amber_600(),
+            TokenKind::Macro => ui::blue_500(),
+            TokenKind::Attribute => ui::cyan_600(),
+        };
+
+        HighlightStyle {
+            color: Some(color),
+            ..Default::default()
+        }
+    }
+}
+
+/// Split the Rust source into the tokens to highlight, the plain text is not included.
+///
+/// This is not a full lexer, just good enough to show the source of the stories.
+fn tokenize(source: &str) -> Vec<(Range<usize>, TokenKind)> {
+    let bytes = source.as_bytes();
+    let len = bytes.len();
+    let mut tokens = vec![];
+    let mut ix = 0;
+
+    while ix < len {
+        let start = ix;
+        let c = bytes[ix];
+
+        let kind = if source[ix..].starts_with("//") {
+            ix = source[ix..].find('\n').map_or(len, |n| ix + n);
+            Some(TokenKind::Comment)
+        } else if c == b'"' {
+            ix += 1;
+            while ix < len && bytes[ix] != b'"' {
+                if bytes[ix] == b'\\' {
+                    ix += 1;
+                }
+                ix += 1;
+            }
+            ix = (ix + 1).min(len);
+            Some(TokenKind::String)
+        } else if source[ix..].starts_with("#[") {
+            let line_end = source[ix..].find('\n').map_or(len, |n| ix + n);
+            ix = source[ix..line_end]
+                .rfind(']')
+                .map_or(line_end, |n| ix + n + 1);
+            Some(TokenKind::Attribute)
+        } else if c.is_ascii_digit() {
+            while ix < len
+                && (bytes[ix].is_ascii_alphanumeric()
+                    || bytes[ix] == b'_'
+                    || (bytes[ix] == b'.' && bytes.get(ix + 1).map_or(false, u8::is_ascii_digit)))
+            {
+                ix += 1;
+            }
+            Some(TokenKind::Number)
+        } else if c.is_ascii_alphabetic() || c == b'_' {
+            while ix < len && (bytes[ix].is_ascii_alphanumeric() || bytes[ix] == b'_') {
+                ix += 1;
+            }
+            let word = &source[start..ix];
+            if bytes.get(ix) == Some(&b'!') && bytes.get(ix + 1) != Some(&b'=') {
+                ix += 1;
+                Some(TokenKind::Macro)
+            } else if KEYWORDS.contains(&word) {
+                Some(TokenKind::Keyword)
+            } else if c.is_ascii_uppercase() {
+                Some(TokenKind::Type)
+            } else {
+                None
+            }
+        } else {
+            ix += source[ix..].chars().next().map_or(1, char::len_utf8);
+            None
+        };
+
+        if let Some(kind) = kind {
+            tokens.push((start..ix, kind));
+        }
+    }
+
+    tokens
+}
+
+/// Render the Rust source with the syntax highlighting, in a monospace font.
+pub fn render_source(source: &'static str, cx: &WindowContext) -> impl IntoElement {
+    let mut text_style = cx.text_style();
+    text_style.color = cx.theme().tokens.on_surface;
+    text_style.font_family = MONOSPACE_FONT.into();
+    text_style.font_size = px(12.).into();
+
+    let highlights = tokenize(source)
+        .into_iter()
+        .map(|(range, kind)| (range, kind.style()));
+
+    div()
+        .id("story-source")
+        .overflow_x_scroll()
+        .font_family(MONOSPACE_FONT)
+        .text_xs()
+        .whitespace_nowrap()
+        .child(StyledText::new(source).with_highlights(&text_style, highlights))
+}
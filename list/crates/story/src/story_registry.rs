@@ -0,0 +1,192 @@
+use std::rc::Rc;
+
+use gpui::{
+    AnyView, AppContext, Global, Pixels, SharedString, Task, View, VisualContext as _,
+    WindowContext,
+};
+use ui::{IconName, Placement};
+
+use crate::{search, SearchableStory, StoryContainer};
+
+/// The TabPanel to add the story into in the default layout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DockPosition {
+    Left,
+    Center,
+    Right,
+}
+
+/// A story that can be shown in the workspace, see [`register_story`].
+#[derive(Clone)]
+pub struct StoryEntry {
+    /// The name is used to restore the story from the saved layout, so it must be unique.
+    pub name: SharedString,
+    pub description: SharedString,
+    pub build: StoryBuild,
+    pub position: DockPosition,
+    /// Split the TabPanel at the placement for the story, with the size of the new TabPanel.
+    pub split: Option<(Placement, Option<Pixels>)>,
+    pub closeable: bool,
+    /// The icon to show on the tab.
+    pub icon: Option<IconName>,
+    /// Register the view type of the story to the global search, see [`StoryEntry::searchable`].
+    searchable: Option<fn(&mut AppContext)>,
+}
+
+/// How to create the view of a [`StoryEntry`].
+#[derive(Clone)]
+pub enum StoryBuild {
+    View(Rc<dyn Fn(&mut WindowContext) -> AnyView>),
+    /// Load the view asynchronously, a spinner is shown until it is loaded.
+    Load(Rc<dyn Fn(&mut WindowContext) -> Task<anyhow::Result<AnyView>>>),
+}
+
+impl StoryBuild {
+    /// Create the view of the story by the `build`, it can capture the state to build the view.
+    pub fn view(build: impl Fn(&mut WindowContext) -> AnyView + 'static) -> Self {
+        Self::View(Rc::new(build))
+    }
+
+    /// Load the view of the story by the `load` task, see [`StoryContainer::load_with`].
+    pub fn load(
+        load: impl Fn(&mut WindowContext) -> Task<anyhow::Result<AnyView>> + 'static,
+    ) -> Self {
+        Self::Load(Rc::new(load))
+    }
+}
+
+impl StoryEntry {
+    /// Create a closeable story in the center TabPanel.
+    pub fn new(
+        name: impl Into<SharedString>,
+        description: impl Into<SharedString>,
+        build: StoryBuild,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            description: description.into(),
+            build,
+            position: DockPosition::Center,
+            split: None,
+            closeable: true,
+            icon: None,
+            searchable: None,
+        }
+    }
+
+    /// Set the TabPanel to add the story in the default layout, default: `DockPosition::Center`.
+    pub fn position(mut self, position: DockPosition) -> Self {
+        self.position = position;
+        self
+    }
+
+    /// Split the TabPanel at the placement to add the story, instead of adding it as a tab.
+    pub fn split(mut self, placement: Placement, size: Option<Pixels>) -> Self {
+        self.split = Some((placement, size));
+        self
+    }
+
+    /// Set the story can be closed, default: true
+    pub fn closeable(mut self, closeable: bool) -> Self {
+        self.closeable = closeable;
+        self
+    }
+
+    /// Set the icon to show on the tab, default: None
+    pub fn icon(mut self, icon: IconName) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    /// Search the items of the story by the global search, even if the story is not opened.
+    ///
+    /// The view of the story must be the [`SearchableStory`] `S`.
+    pub fn searchable<S: SearchableStory>(mut self) -> Self {
+        self.searchable = Some(search::register_searchable::<S>);
+        self
+    }
+
+    pub fn is_searchable(&self) -> bool {
+        self.searchable.is_some()
+    }
+
+    /// Create a [`StoryContainer`] with the view of the story.
+    pub fn container(&self, closeable: bool, cx: &mut WindowContext) -> View<StoryContainer> {
+        let (name, description, icon) = (self.name.clone(), self.description.clone(), self.icon);
+        let with_icon = move |container: StoryContainer| match icon {
+            Some(icon) => container.icon(icon),
+            None => container,
+        };
+        match &self.build {
+            StoryBuild::View(build) => {
+                let story = build(cx);
+                cx.new_view(move |cx| {
+                    with_icon(StoryContainer::new(name, description, closeable, cx).story(story))
+                })
+            }
+            StoryBuild::Load(load) => {
+                let load = load.clone();
+                cx.new_view(move |cx| {
+                    with_icon(StoryContainer::load_with(
+                        name,
+                        description,
+                        closeable,
+                        move |cx| load(cx),
+                        cx,
+                    ))
+                })
+            }
+        }
+    }
+}
+
+/// The stories to show in the workspace, use [`register_story`] to register more.
+///
+/// The default layout is built from the stories in the registered order.
+#[derive(Default)]
+pub struct StoryRegistry {
+    stories: Vec<StoryEntry>,
+}
+
+impl Global for StoryRegistry {}
+
+impl StoryRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a story, the registered one with the same name will be replaced.
+    pub fn register(&mut self, story: StoryEntry) {
+        if let Some(ix) = self.stories.iter().position(|s| s.name == story.name) {
+            self.stories[ix] = story;
+        } else {
+            self.stories.push(story);
+        }
+    }
+
+    pub fn stories(&self) -> &[StoryEntry] {
+        &self.stories
+    }
+
+    pub fn find(&self, name: &str) -> Option<StoryEntry> {
+        self.stories
+            .iter()
+            .find(|story| story.name == name)
+            .cloned()
+    }
+
+    /// Returns the global registry, `None` if no story has been registered.
+    pub fn global(cx: &AppContext) -> Option<&Self> {
+        cx.try_global::<Self>()
+    }
+}
+
+/// Register a story to show in the workspace, the registered one with the same name will be replaced.
+///
+/// Call it before the windows are opened, so the story is in the default layout.
+pub fn register_story(story: StoryEntry, cx: &mut AppContext) {
+    if let Some(register_searchable) = story.searchable {
+        register_searchable(cx);
+    }
+    cx.default_global::<StoryRegistry>().register(story);
+}
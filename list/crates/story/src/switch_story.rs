@@ -50,7 +50,7 @@ impl Render for SwitchStory {
                 .w_full()
                 .rounded_lg()
                 .border_1()
-                .border_color(theme.border)
+                .border_color(theme.tokens.outline)
         }
 
         v_flex().gap_6()
@@ -63,7 +63,7 @@ impl Render for SwitchStory {
                     card(cx)
                     .child(
                         title("Marketing emails").child(
-                            Label::new("Receive emails about new products, features, and more.").text_color(theme.muted_foreground)
+                            Label::new("Receive emails about new products, features, and more.").text_color(theme.tokens.on_surface_muted)
                         )
                     )
                         .child(
@@ -81,7 +81,7 @@ impl Render for SwitchStory {
                     card(cx)
                     .child(
                         title("Security emails").child(
-                            Label::new("Receive emails about your account security. When turn off, you never recive email again.").text_color(theme.muted_foreground)
+                            Label::new("Receive emails about your account security. When turn off, you never recive email again.").text_color(theme.tokens.on_surface_muted)
                         )
                     )
                         .child(
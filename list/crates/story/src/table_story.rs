@@ -2,18 +2,25 @@ use std::time::Duration;
 
 use fake::Fake;
 use gpui::{
-    div, img, IntoElement, ParentElement, Pixels, Render, SharedString, Styled, Timer, View,
-    ViewContext, VisualContext as _, WindowContext,
+    div, img, AppContext, ClickEvent, IntoElement, ParentElement, Pixels, Render, SharedString,
+    Styled, Timer, View, ViewContext, VisualContext as _, WindowContext,
 };
 use ui::{
+    button::Button,
     checkbox::Checkbox,
+    empty_state::EmptyState,
     h_flex,
     indicator::Indicator,
     label::Label,
     prelude::FluentBuilder as _,
     table::{ColSort, Table, TableDelegate, TableEvent},
     theme::ActiveTheme as _,
-    v_flex, Icon, IconName, Selectable,
+    v_flex, Icon, IconName, Selectable, Sizable as _,
+};
+
+use crate::{
+    copyable::table_selection, csv_table_story::DataTableDelegate, data_import::import_data,
+    CopiedRows, CopyableStory,
 };
 
 struct Customer {
@@ -38,6 +45,30 @@ impl Customer {
         let avatar_url = format!("https://i.pravatar.cc/40?image={}", image_id);
         img(avatar_url).size_5().rounded_full()
     }
+
+    /// Returns the text of the column to copy.
+    fn text(&self, col_id: &str) -> SharedString {
+        match col_id {
+            "id" => self.id.to_string().into(),
+            "login" => self.login.clone().into(),
+            "first_name" => self.first_name.clone().into(),
+            "last_name" => self.last_name.clone().into(),
+            "company" => self.company.clone().into(),
+            "city" => self.city.clone().into(),
+            "country" => self.country.clone().into(),
+            "email" => self.email.clone().into(),
+            "phone" => self.phone.clone().into(),
+            "gender" => match self.gender {
+                0 => "Male".into(),
+                1 => "Female".into(),
+                _ => "".into(),
+            },
+            "age" => self.age.to_string().into(),
+            "verified" => if self.verified { "Yes" } else { "No" }.into(),
+            "confirmed" => if self.confirmed { "Yes" } else { "No" }.into(),
+            _ => "".into(),
+        }
+    }
 }
 
 fn randome_customers(size: usize) -> Vec<Customer> {
@@ -90,6 +121,7 @@ struct CustomerTableDelegate {
     col_selection: bool,
     loading: bool,
     is_eof: bool,
+    error: Option<SharedString>,
 }
 
 impl CustomerTableDelegate {
@@ -119,8 +151,22 @@ impl CustomerTableDelegate {
             col_selection: true,
             loading: false,
             is_eof: false,
+            error: None,
+        }
+    }
+
+    fn cell(&self, row_ix: usize, col_ix: usize) -> SharedString {
+        match (self.customers.get(row_ix), self.columns.get(col_ix)) {
+            (Some(customer), Some(col)) => customer.text(&col.id),
+            _ => "".into(),
         }
     }
+
+    fn reload(&mut self) {
+        self.customers = randome_customers(5000);
+        self.is_eof = false;
+        self.error = None;
+    }
 }
 
 impl TableDelegate for CustomerTableDelegate {
@@ -140,6 +186,13 @@ impl TableDelegate for CustomerTableDelegate {
         }
     }
 
+    fn col_key(&self, col_ix: usize) -> SharedString {
+        match self.columns.get(col_ix) {
+            Some(col) => col.id.clone(),
+            None => self.col_name(col_ix),
+        }
+    }
+
     fn col_width(&self, col_ix: usize) -> Option<Pixels> {
         if let Some(col) = self.columns.get(col_ix) {
             Some(
@@ -207,7 +260,7 @@ impl TableDelegate for CustomerTableDelegate {
             "phone" => customer.phone.clone().into_any_element(),
             "gender" => match customer.gender {
                 0 => "Male",
-                1 => "Famale",
+                1 => "Female",
                 _ => "",
             }
             .into_any_element(),
@@ -221,7 +274,7 @@ impl TableDelegate for CustomerTableDelegate {
                 false => div().into_any_element(),
             },
             _ => Label::new("--")
-                .text_color(cx.theme().muted_foreground)
+                .text_color(cx.theme().tokens.on_surface_muted)
                 .into_any_element(),
         }
     }
@@ -230,6 +283,11 @@ impl TableDelegate for CustomerTableDelegate {
         self.loop_selection
     }
 
+    fn type_ahead_text(&self, row_ix: usize) -> Option<SharedString> {
+        let customer = self.customers.get(row_ix)?;
+        Some(customer.login.clone().into())
+    }
+
     fn can_move_col(&self, _: usize) -> bool {
         self.col_order
     }
@@ -323,16 +381,50 @@ impl TableDelegate for CustomerTableDelegate {
                 _ => {}
             }
 
-            for col in self.columns.iter_mut() {
-                if let Some(ColSort::Ascending) = col.sort {
+            for (ix, col) in self.columns.iter_mut().enumerate() {
+                if ix != col_ix && col.sort.is_some() {
                     col.sort = Some(ColSort::Default);
                 }
             }
         }
     }
 
+    fn render_empty(&self, cx: &mut ViewContext<Table<Self>>) -> impl IntoElement {
+        EmptyState::new("No customers")
+            .description("There is no customer yet.")
+            .action(
+                Button::new("reload", cx)
+                    .label("Reload")
+                    .on_click(cx.listener(|table, _, cx| {
+                        table.delegate_mut().reload();
+                        cx.notify();
+                    })),
+            )
+    }
+
+    fn error(&self) -> Option<SharedString> {
+        self.error.clone()
+    }
+
+    fn render_error(
+        &self,
+        error: SharedString,
+        cx: &mut ViewContext<Table<Self>>,
+    ) -> impl IntoElement {
+        EmptyState::error("Failed to load customers")
+            .description(error)
+            .action(
+                Button::new("retry", cx)
+                    .label("Retry")
+                    .on_click(cx.listener(|table, _, cx| {
+                        table.delegate_mut().reload();
+                        cx.notify();
+                    })),
+            )
+    }
+
     fn can_load_more(&self) -> bool {
-        return !self.loading && !self.is_eof;
+        return !self.loading && !self.is_eof && self.error.is_none();
     }
 
     fn load_more_threshold(&self) -> usize {
@@ -356,10 +448,32 @@ impl TableDelegate for CustomerTableDelegate {
         })
         .detach();
     }
+
+    fn render_bulk_actions(
+        &self,
+        rows: &[usize],
+        cx: &mut ViewContext<Table<Self>>,
+    ) -> impl IntoElement {
+        let rows = rows.to_vec();
+        Button::new("delete-customers", cx)
+            .label(format!("Delete {} customers", rows.len()))
+            .danger()
+            .xsmall()
+            .on_click(cx.listener(move |table, _, cx| {
+                // Remove from the end, to keep the indices of the rest.
+                for &row_ix in rows.iter().rev() {
+                    table.delegate_mut().customers.remove(row_ix);
+                }
+                table.clear_checked_rows(cx);
+                cx.notify();
+            }))
+    }
 }
 
 pub struct TableStory {
     table: View<Table<CustomerTableDelegate>>,
+    /// The table of the imported data, shown instead of the customers.
+    imported: Option<View<Table<DataTableDelegate>>>,
 }
 
 impl TableStory {
@@ -369,11 +483,33 @@ impl TableStory {
 
     fn new(cx: &mut ViewContext<Self>) -> Self {
         let delegate = CustomerTableDelegate::new(5000);
-        let table = cx.new_view(|cx| Table::new(delegate, cx));
+        let table = cx.new_view(|cx| {
+            Table::new(delegate, cx)
+                .checkable(true)
+                .frozen_cols(1)
+                .layout_id("customers", cx)
+        });
 
         cx.subscribe(&table, Self::on_table_event).detach();
 
-        Self { table }
+        Self {
+            table,
+            imported: None,
+        }
+    }
+
+    fn import_data(&mut self, _: &ClickEvent, cx: &mut ViewContext<Self>) {
+        import_data(cx, |this, data, cx| {
+            let delegate = DataTableDelegate::new(data);
+            // Keep the first column visible for the wide data.
+            this.imported = Some(cx.new_view(|cx| Table::new(delegate, cx).frozen_cols(1)));
+            cx.notify();
+        });
+    }
+
+    fn clear_imported(&mut self, _: &ClickEvent, cx: &mut ViewContext<Self>) {
+        self.imported = None;
+        cx.notify();
     }
 
     fn toggle_loop_selection(&mut self, checked: &bool, cx: &mut ViewContext<Self>) {
@@ -416,11 +552,32 @@ impl TableStory {
         });
     }
 
+    fn toggle_empty(&mut self, checked: &bool, cx: &mut ViewContext<Self>) {
+        let table = self.table.clone();
+        table.update(cx, |table, cx| {
+            if *checked {
+                table.delegate_mut().customers.clear();
+                table.delegate_mut().is_eof = true;
+            } else {
+                table.delegate_mut().reload();
+            }
+            cx.notify();
+        });
+    }
+
+    fn toggle_error(&mut self, checked: &bool, cx: &mut ViewContext<Self>) {
+        let table = self.table.clone();
+        table.update(cx, |table, cx| {
+            table.delegate_mut().error = checked.then(|| "Connection timed out.".into());
+            cx.notify();
+        });
+    }
+
     fn on_table_event(
         &mut self,
         _: View<Table<CustomerTableDelegate>>,
         event: &TableEvent,
-        _cx: &mut ViewContext<Self>,
+        cx: &mut ViewContext<Self>,
     ) {
         match event {
             TableEvent::ColWidthsChanged(col_widths) => {
@@ -428,12 +585,57 @@ impl TableStory {
             }
             TableEvent::SelectCol(ix) => println!("Select col: {}", ix),
             TableEvent::SelectRow(ix) => println!("Select row: {}", ix),
+            TableEvent::SelectCell(row_ix, col_ix) => {
+                println!("Select cell: {}, {}", row_ix, col_ix)
+            }
+            TableEvent::ConfirmRow(ix) => println!("Confirm row: {}", ix),
+            TableEvent::CheckedRowsChanged(rows) => println!("Checked rows: {:?}", rows),
+        }
+        cx.notify();
+    }
+}
+
+impl CopyableStory for TableStory {
+    fn copy_selection(&self, cx: &AppContext) -> Option<CopiedRows> {
+        match &self.imported {
+            Some(imported) => table_selection(imported.read(cx), DataTableDelegate::cell),
+            None => table_selection(self.table.read(cx), CustomerTableDelegate::cell),
         }
     }
 }
 
 impl Render for TableStory {
     fn render(&mut self, cx: &mut ViewContext<Self>) -> impl gpui::IntoElement {
+        if let Some(imported) = self.imported.clone() {
+            let delegate = imported.read(cx).delegate();
+            let summary = format!(
+                "Imported {} rows, {} columns",
+                delegate.rows_count(),
+                delegate.cols_count()
+            );
+
+            return v_flex()
+                .size_full()
+                .gap_2()
+                .child(
+                    h_flex()
+                        .items_center()
+                        .gap_2()
+                        .child(
+                            Button::new("clear-imported", cx)
+                                .label("Show Customers")
+                                .small()
+                                .on_click(cx.listener(Self::clear_imported)),
+                        )
+                        .child(summary),
+                )
+                .child(imported);
+        }
+
+        let import_button = Button::new("import-data", cx)
+            .label("Import Data…")
+            .small()
+            .on_click(cx.listener(Self::import_data));
         let delegate = self.table.read(cx).delegate();
 
         v_flex()
@@ -443,6 +645,7 @@ impl Render for TableStory {
                 h_flex()
                     .items_center()
                     .gap_2()
+                    .child(import_button)
                     .child(
                         Checkbox::new("loop-selection")
                             .label("Loop Selection")
@@ -473,10 +676,34 @@ impl Render for TableStory {
                             .selected(delegate.col_selection)
                             .on_click(cx.listener(Self::toggle_col_selection)),
                     )
+                    .child(
+                        Checkbox::new("empty")
+                            .label("Empty")
+                            .selected(delegate.customers.is_empty())
+                            .on_click(cx.listener(Self::toggle_empty)),
+                    )
+                    .child(
+                        Checkbox::new("error")
+                            .label("Error")
+                            .selected(delegate.error.is_some())
+                            .on_click(cx.listener(Self::toggle_error)),
+                    )
                     .when(delegate.loading, |this| {
                         this.child(h_flex().gap_1().child(Indicator::new()).child("Loading..."))
                     })
                     .child(format!("Total Rows: {}", delegate.rows_count()))
+                    .when_some(
+                        self.table
+                            .read(cx)
+                            .selected_row()
+                            .and_then(|ix| delegate.customers.get(ix)),
+                        |this, customer| {
+                            this.child(format!(
+                                "Selected: {} {}",
+                                customer.first_name, customer.last_name
+                            ))
+                        },
+                    )
                     .when(delegate.is_eof, |this| this.child("Is loaded all data.")),
             )
             .child(self.table.clone())
@@ -0,0 +1,164 @@
+use gpui::{
+    div, prelude::FluentBuilder as _, IntoElement, ParentElement as _, Render, SharedString,
+    Styled as _, View, ViewContext, VisualContext as _, WindowContext,
+};
+use ui::{
+    avatar::Avatar,
+    button::Button,
+    chart::chart_color,
+    h_flex,
+    tag::{Badge, Tag, TagInput, TagInputEvent},
+    theme::ActiveTheme as _,
+    v_flex, IconName, Sizable as _,
+};
+
+use crate::section;
+
+const LANGUAGES: [&str; 6] = ["Rust", "Go", "Python", "TypeScript", "Swift", "Zig"];
+
+/// The badges, the tags and the chips, with the removable ones and a [`TagInput`].
+pub struct TagStory {
+    tags: Vec<SharedString>,
+    tag_input: View<TagInput>,
+    last_event: Option<SharedString>,
+}
+
+impl TagStory {
+    pub fn view(cx: &mut WindowContext) -> View<Self> {
+        cx.new_view(Self::new)
+    }
+
+    fn new(cx: &mut ViewContext<Self>) -> Self {
+        let tag_input = cx.new_view(|cx| {
+            let mut input = TagInput::new(cx).placeholder("Type and press enter...", cx);
+            input.set_tags(["gpui", "ui", "desktop"], cx);
+            input
+        });
+        cx.subscribe(&tag_input, |this, _, event: &TagInputEvent, cx| {
+            this.last_event = Some(
+                match event {
+                    TagInputEvent::Add(tag) => format!("Added: {}", tag),
+                    TagInputEvent::Remove(tag) => format!("Removed: {}", tag),
+                }
+                .into(),
+            );
+            cx.notify();
+        })
+        .detach();
+
+        Self {
+            tags: Self::default_tags(),
+            tag_input,
+            last_event: None,
+        }
+    }
+
+    fn default_tags() -> Vec<SharedString> {
+        LANGUAGES
+            .iter()
+            .map(|name| SharedString::from(*name))
+            .collect()
+    }
+
+    fn remove_tag(&mut self, ix: usize, cx: &mut ViewContext<Self>) {
+        if ix < self.tags.len() {
+            self.tags.remove(ix);
+        }
+        cx.notify();
+    }
+}
+
+impl Render for TagStory {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        v_flex()
+            .gap_6()
+            .child(
+                section("Badge", cx)
+                    .child(Badge::new("New"))
+                    .child(Badge::new("Beta").outline())
+                    .child(Badge::new("Stable").color(ui::green_500()))
+                    .child(Badge::new("Deprecated").color(cx.theme().tokens.danger))
+                    .child(Badge::count(8, 99).small().color(cx.theme().tokens.accent))
+                    .child(Badge::count(128, 99).color(cx.theme().tokens.danger))
+                    .child(Badge::count(1200, 999).large().outline()),
+            )
+            .child(
+                section("Tag", cx)
+                    .child(Tag::new("tag-default", "Default"))
+                    .child(Tag::new("tag-outline", "Outline").outline())
+                    .child(Tag::new("tag-icon", "Starred").icon(IconName::Star))
+                    .child(
+                        Tag::new("tag-color-outline", "Info")
+                            .outline()
+                            .color(cx.theme().tokens.accent),
+                    )
+                    .child(Tag::new("tag-small", "Small").small())
+                    .child(Tag::new("tag-large", "Large").large()),
+            )
+            .child(
+                section("Color-coded", cx).children(LANGUAGES.iter().enumerate().map(
+                    |(ix, name)| {
+                        Tag::new(("tag-color", ix), *name)
+                            .color(chart_color(ix, cx))
+                            .when(ix % 2 == 1, |this| this.outline())
+                    },
+                )),
+            )
+            .child(
+                section("Removable", cx)
+                    .children(self.tags.iter().enumerate().map(|(ix, tag)| {
+                        Tag::new(("tag-removable", ix), tag.clone())
+                            .on_remove(cx.listener(move |this, _, cx| this.remove_tag(ix, cx)))
+                    }))
+                    .child(
+                        Button::new("reset-tags", cx)
+                            .label("Reset")
+                            .small()
+                            .on_click(cx.listener(|this, _, cx| {
+                                this.tags = Self::default_tags();
+                                cx.notify();
+                            })),
+                    ),
+            )
+            .child(
+                section("Chip", cx)
+                    .child(Tag::chip("chip-default", "Filter"))
+                    .child(Tag::chip("chip-outline", "Outline").outline())
+                    .child(
+                        Tag::chip("chip-user", "Jason Lee").avatar(
+                            Avatar::new("Jason Lee").src("https://i.pravatar.cc/80?image=12"),
+                        ),
+                    )
+                    .child(
+                        Tag::chip("chip-initials", "Mary Smith")
+                            .avatar(Avatar::new("Mary Smith"))
+                            .outline(),
+                    ),
+            )
+            .child(
+                section("Tag Input", cx).child(
+                    v_flex()
+                        .w_full()
+                        .gap_2()
+                        .child(self.tag_input.clone())
+                        .child(
+                            h_flex()
+                                .gap_2()
+                                .text_sm()
+                                .text_color(cx.theme().tokens.on_surface_muted)
+                                .child(format!(
+                                    "Tags: {}",
+                                    self.tag_input
+                                        .read(cx)
+                                        .tags()
+                                        .iter()
+                                        .map(|tag| tag.as_ref())
+                                        .collect::<Vec<_>>()
+                                        .join(", ")
+                                ))
+                                .children(self.last_event.clone().map(|event| div().child(event))),
+                        ),
+                ),
+            )
+    }
+}
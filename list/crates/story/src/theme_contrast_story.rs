@@ -0,0 +1,208 @@
+use gpui::{
+    div, px, FocusHandle, FocusableView, Hsla, IntoElement, ParentElement, Render, SharedString,
+    Styled, View, ViewContext, VisualContext as _, WindowContext,
+};
+use ui::{
+    h_flex,
+    radio::Radio,
+    theme::{ActiveTheme as _, Theme, ThemeMode},
+    v_flex, ColorExt as _, StyledExt as _,
+};
+
+use crate::section;
+
+/// The `(foreground, background)` color names of the theme to check the contrast of the text.
+const TEXT_PAIRS: &[(&str, &str)] = &[
+    ("on_surface", "surface"),
+    ("on_surface_raised", "surface_raised"),
+    ("on_overlay", "overlay"),
+    ("on_accent", "accent"),
+    ("on_accent", "accent_hover"),
+    ("on_neutral", "neutral"),
+    ("on_neutral", "neutral_hover"),
+    ("on_danger", "danger"),
+    ("on_surface_muted", "surface_muted"),
+    ("on_surface_muted", "surface"),
+    ("on_highlight", "highlight"),
+    ("tab_foreground", "tab_bar"),
+    ("on_surface", "list_active"),
+    ("on_surface", "selection"),
+    ("link", "surface"),
+];
+
+/// The theme to show in the story.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Preview {
+    /// The active theme of the app.
+    Current,
+    HighContrast(ThemeMode),
+}
+
+/// Show the palette of a theme and the WCAG contrast ratios of its text colors.
+pub struct ThemeContrastStory {
+    focus_handle: FocusHandle,
+    preview: Preview,
+}
+
+impl ThemeContrastStory {
+    pub fn view(cx: &mut WindowContext) -> View<Self> {
+        cx.new_view(Self::new)
+    }
+
+    fn new(cx: &mut ViewContext<Self>) -> Self {
+        Self {
+            focus_handle: cx.focus_handle(),
+            preview: Preview::Current,
+        }
+    }
+
+    fn preview_theme(&self, cx: &WindowContext) -> Theme {
+        match self.preview {
+            Preview::Current => cx.theme().clone(),
+            Preview::HighContrast(mode) => Theme::high_contrast(mode),
+        }
+    }
+
+    fn render_pair(
+        fg_name: &'static str,
+        bg_name: &'static str,
+        theme: &Theme,
+        cx: &WindowContext,
+    ) -> impl IntoElement {
+        let color = |name: &str| {
+            theme
+                .colors()
+                .into_iter()
+                .find(|(n, _)| *n == name)
+                .map_or(Hsla::default(), |(_, color)| color)
+        };
+        let (fg, bg) = (color(fg_name), color(bg_name));
+        let ratio = fg.contrast_ratio(bg);
+        let (grade, passed) = if ratio >= 7. {
+            ("AAA", true)
+        } else if ratio >= 4.5 {
+            ("AA", true)
+        } else if ratio >= 3. {
+            ("AA Large", false)
+        } else {
+            ("Fail", false)
+        };
+
+        h_flex()
+            .gap_4()
+            .w_full()
+            .child(
+                div()
+                    .w(px(200.))
+                    .px_3()
+                    .py_2()
+                    .rounded(px(theme.radius))
+                    .border_1()
+                    .border_color(theme.tokens.outline)
+                    .bg(bg)
+                    .text_color(fg)
+                    .child("The quick brown fox"),
+            )
+            .child(
+                v_flex()
+                    .flex_1()
+                    .text_sm()
+                    .child(format!("{} on {}", fg_name, bg_name))
+                    .child(
+                        div()
+                            .text_xs()
+                            .text_color(cx.theme().tokens.on_surface_muted)
+                            .child(format!("{} on {}", fg.to_hex_string(), bg.to_hex_string())),
+                    ),
+            )
+            .child(
+                div()
+                    .w(px(64.))
+                    .text_sm()
+                    .font_semibold()
+                    .child(format!("{:.2}:1", ratio)),
+            )
+            .child(
+                div()
+                    .w(px(72.))
+                    .text_xs()
+                    .text_color(if passed {
+                        cx.theme().tokens.on_surface
+                    } else {
+                        cx.theme().tokens.danger
+                    })
+                    .child(grade),
+            )
+    }
+
+    fn render_swatch(name: &'static str, color: Hsla, theme: &Theme) -> impl IntoElement {
+        v_flex()
+            .w(px(120.))
+            .gap_1()
+            .child(
+                div()
+                    .h_8()
+                    .rounded(px(theme.radius))
+                    .border_1()
+                    .border_color(theme.tokens.outline)
+                    .bg(color),
+            )
+            .child(div().text_xs().child(name))
+            .child(div().text_xs().child(color.to_hex_string()))
+    }
+}
+
+impl FocusableView for ThemeContrastStory {
+    fn focus_handle(&self, _: &gpui::AppContext) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for ThemeContrastStory {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let theme = self.preview_theme(cx);
+        let previews = [
+            (Preview::Current, "Current Theme"),
+            (
+                Preview::HighContrast(ThemeMode::Light),
+                "High Contrast Light",
+            ),
+            (Preview::HighContrast(ThemeMode::Dark), "High Contrast Dark"),
+        ];
+
+        v_flex()
+            .gap_6()
+            .child(
+                h_flex()
+                    .gap_4()
+                    .children(previews.into_iter().map(|(preview, label)| {
+                        Radio::new(SharedString::from(format!("preview-{:?}", preview)))
+                            .label(label)
+                            .checked(self.preview == preview)
+                            .on_click(cx.listener(move |this, _, cx| {
+                                this.preview = preview;
+                                cx.notify();
+                            }))
+                    })),
+            )
+            .child(
+                section("Text Contrast", cx).child(
+                    v_flex().gap_2().w_full().children(
+                        TEXT_PAIRS
+                            .iter()
+                            .map(|(fg, bg)| Self::render_pair(fg, bg, &theme, cx)),
+                    ),
+                ),
+            )
+            .child(
+                section("Palette", cx).child(
+                    h_flex().flex_wrap().gap_3().children(
+                        theme
+                            .colors()
+                            .into_iter()
+                            .map(|(name, color)| Self::render_swatch(name, color, &theme)),
+                    ),
+                ),
+            )
+    }
+}
@@ -0,0 +1,430 @@
+use gpui::{
+    div, px, ClipboardItem, FocusHandle, FocusableView, Hsla, IntoElement, ParentElement, Render,
+    SharedString, Styled, Subscription, View, ViewContext, VisualContext as _, WindowContext,
+};
+use ui::{
+    button::Button,
+    color_picker::{ColorPicker, ColorPickerEvent},
+    h_flex,
+    list::ListItem,
+    notification::Notification,
+    radio::Radio,
+    slider::{Slider, SliderEvent},
+    tab::Tab,
+    theme::{ActiveTheme as _, ComponentKind, ComponentStyle, Theme},
+    v_flex, ContextModal as _, IconName, Selectable as _, Sizable as _,
+};
+
+use crate::section;
+
+/// The colors of a [`ComponentStyle`] that can be overridden.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum OverrideColor {
+    Background,
+    Foreground,
+    Border,
+}
+
+impl OverrideColor {
+    const ALL: [Self; 3] = [Self::Background, Self::Foreground, Self::Border];
+
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Background => "Background",
+            Self::Foreground => "Foreground",
+            Self::Border => "Border",
+        }
+    }
+
+    fn get(&self, style: &ComponentStyle) -> Option<Hsla> {
+        match self {
+            Self::Background => style.background,
+            Self::Foreground => style.foreground,
+            Self::Border => style.border,
+        }
+    }
+
+    fn set(&self, style: &mut ComponentStyle, value: Option<Hsla>) {
+        match self {
+            Self::Background => style.background = value,
+            Self::Foreground => style.foreground = value,
+            Self::Border => style.border = value,
+        }
+    }
+}
+
+/// The controls to override the style of the selected component.
+struct OverrideEditor {
+    kind: ComponentKind,
+    radius_slider: View<Slider>,
+    padding_x_slider: View<Slider>,
+    padding_y_slider: View<Slider>,
+    /// The pickers in the order of [`OverrideColor::ALL`].
+    pickers: Vec<View<ColorPicker>>,
+}
+
+/// Edit all the colors of the global [`Theme`], the changes are applied to the app immediately.
+pub struct ThemeEditorStory {
+    focus_handle: FocusHandle,
+    /// The pickers of the theme colors, in the order of [`Theme::colors`].
+    pickers: Vec<View<ColorPicker>>,
+    radius_slider: View<Slider>,
+    font_size_slider: View<Slider>,
+    overrides: OverrideEditor,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl ThemeEditorStory {
+    pub fn view(cx: &mut WindowContext) -> View<Self> {
+        cx.new_view(Self::new)
+    }
+
+    fn new(cx: &mut ViewContext<Self>) -> Self {
+        let mut _subscriptions = vec![];
+        let pickers = cx
+            .theme()
+            .colors()
+            .into_iter()
+            .map(|(name, color)| {
+                let picker = cx.new_view(|cx| {
+                    let mut picker = ColorPicker::new(name, cx).small().label(name);
+                    picker.set_value(color, cx);
+                    picker
+                });
+                _subscriptions.push(cx.subscribe(
+                    &picker,
+                    move |_, _, ev: &ColorPickerEvent, cx| match ev {
+                        ColorPickerEvent::Change(color) => {
+                            if let Some(color) = *color {
+                                cx.global_mut::<Theme>().set_color(name, color);
+                                cx.refresh();
+                            }
+                        }
+                    },
+                ));
+                picker
+            })
+            .collect();
+
+        let radius = cx.theme().radius;
+        let radius_slider = cx.new_view(|_| {
+            Slider::horizontal()
+                .min(0.)
+                .max(16.)
+                .step(1.)
+                .default_value(radius)
+        });
+        _subscriptions.push(
+            cx.subscribe(&radius_slider, |_, _, ev: &SliderEvent, cx| match ev {
+                SliderEvent::Change(value) => {
+                    cx.global_mut::<Theme>().radius = *value;
+                    cx.refresh();
+                }
+            }),
+        );
+
+        let font_size = cx.theme().font_size;
+        let font_size_slider = cx.new_view(|_| {
+            Slider::horizontal()
+                .min(10.)
+                .max(24.)
+                .step(1.)
+                .default_value(font_size)
+        });
+        _subscriptions.push(cx.subscribe(
+            &font_size_slider,
+            |_, _, ev: &SliderEvent, cx| match ev {
+                SliderEvent::Change(value) => {
+                    cx.global_mut::<Theme>().font_size = *value;
+                    cx.refresh();
+                }
+            },
+        ));
+
+        let overrides = Self::new_override_editor(&mut _subscriptions, cx);
+
+        // Keep the pickers in sync when the theme is changed by others, e.g.: the theme mode.
+        _subscriptions
+            .push(cx.observe_global::<Theme>(|this: &mut Self, cx| this.sync_pickers(cx)));
+
+        let mut this = Self {
+            focus_handle: cx.focus_handle(),
+            pickers,
+            radius_slider,
+            font_size_slider,
+            overrides,
+            _subscriptions,
+        };
+        this.sync_overrides(cx);
+        this
+    }
+
+    fn new_override_editor(
+        subscriptions: &mut Vec<Subscription>,
+        cx: &mut ViewContext<Self>,
+    ) -> OverrideEditor {
+        let mut new_slider =
+            |max: f32, update: fn(&mut ComponentStyle, f32), cx: &mut ViewContext<Self>| {
+                let slider = cx.new_view(|_| Slider::horizontal().min(0.).max(max).step(1.));
+                subscriptions.push(cx.subscribe(&slider, move |this, _, ev: &SliderEvent, cx| {
+                    match ev {
+                        SliderEvent::Change(value) => {
+                            this.update_override(|style| update(style, *value), cx)
+                        }
+                    }
+                }));
+                slider
+            };
+        let radius_slider = new_slider(16., |style, value| style.radius = Some(value), cx);
+        let padding_x_slider = new_slider(24., |style, value| style.padding_x = Some(value), cx);
+        let padding_y_slider = new_slider(16., |style, value| style.padding_y = Some(value), cx);
+
+        let pickers = OverrideColor::ALL
+            .into_iter()
+            .map(|color| {
+                let picker = cx.new_view(|cx| {
+                    ColorPicker::new(SharedString::from(format!("override-{:?}", color)), cx)
+                        .small()
+                        .label(color.name())
+                });
+                subscriptions.push(cx.subscribe(
+                    &picker,
+                    move |this, _, ev: &ColorPickerEvent, cx| match ev {
+                        ColorPickerEvent::Change(value) => {
+                            this.update_override(|style| color.set(style, *value), cx)
+                        }
+                    },
+                ));
+                picker
+            })
+            .collect();
+
+        OverrideEditor {
+            kind: ComponentKind::Button,
+            radius_slider,
+            padding_x_slider,
+            padding_y_slider,
+            pickers,
+        }
+    }
+
+    /// The default padding of the component, to show in the sliders when it's not overridden.
+    fn default_padding(kind: ComponentKind) -> (f32, f32) {
+        match kind {
+            ComponentKind::Button => (16., 8.),
+            ComponentKind::Tab => (12., 6.),
+            ComponentKind::ListItem => (8., 4.),
+        }
+    }
+
+    fn update_override(&mut self, f: impl FnOnce(&mut ComponentStyle), cx: &mut ViewContext<Self>) {
+        let kind = self.overrides.kind;
+        cx.global_mut::<Theme>().style_overrides.update(kind, f);
+        cx.refresh();
+    }
+
+    fn select_override(&mut self, kind: ComponentKind, cx: &mut ViewContext<Self>) {
+        self.overrides.kind = kind;
+        self.sync_overrides(cx);
+        cx.notify();
+    }
+
+    fn reset_override(&mut self, cx: &mut ViewContext<Self>) {
+        let kind = self.overrides.kind;
+        cx.global_mut::<Theme>().style_overrides.remove(kind);
+        cx.refresh();
+    }
+
+    /// Show the overridden style of the selected component, or the defaults if not overridden.
+    fn sync_overrides(&mut self, cx: &mut ViewContext<Self>) {
+        let kind = self.overrides.kind;
+        let style = cx
+            .theme()
+            .style_overrides
+            .get(kind)
+            .copied()
+            .unwrap_or_default();
+        let (padding_x, padding_y) = Self::default_padding(kind);
+        let values = [
+            (
+                &self.overrides.radius_slider,
+                style.radius.unwrap_or(cx.theme().radius),
+            ),
+            (
+                &self.overrides.padding_x_slider,
+                style.padding_x.unwrap_or(padding_x),
+            ),
+            (
+                &self.overrides.padding_y_slider,
+                style.padding_y.unwrap_or(padding_y),
+            ),
+        ];
+        for (slider, value) in values {
+            slider.update(cx, |slider, cx| slider.set_value(value, cx));
+        }
+
+        let transparent = cx.theme().transparent;
+        for (picker, color) in self.overrides.pickers.iter().zip(OverrideColor::ALL) {
+            let value = color.get(&style).unwrap_or(transparent);
+            picker.update(cx, |picker, cx| picker.set_value(value, cx));
+        }
+    }
+
+    fn sync_pickers(&mut self, cx: &mut ViewContext<Self>) {
+        let colors = cx.theme().colors();
+        for (picker, (_, color)) in self.pickers.iter().zip(colors) {
+            picker.update(cx, |picker, cx| picker.set_value(color, cx));
+        }
+
+        let (radius, font_size) = (cx.theme().radius, cx.theme().font_size);
+        self.radius_slider
+            .update(cx, |slider, cx| slider.set_value(radius, cx));
+        self.font_size_slider
+            .update(cx, |slider, cx| slider.set_value(font_size, cx));
+        self.sync_overrides(cx);
+    }
+
+    fn render_overrides(&self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let kind = self.overrides.kind;
+        let slider = |label: &'static str, slider: &View<Slider>, value: f32| {
+            h_flex()
+                .w(px(220.))
+                .gap_3()
+                .child(div().flex_1().child(slider.clone()))
+                .child(format!("{}: {}px", label, value))
+        };
+        let overrides = &self.overrides;
+
+        section("Component Overrides", cx)
+            .child(
+                h_flex()
+                    .gap_4()
+                    .children(ComponentKind::ALL.into_iter().map(|item| {
+                        Radio::new(SharedString::from(format!("override-kind-{}", item.name())))
+                            .label(item.name())
+                            .checked(kind == item)
+                            .on_click(
+                                cx.listener(move |this, _, cx| this.select_override(item, cx)),
+                            )
+                    }))
+                    .child(
+                        Button::new("reset-override", cx)
+                            .small()
+                            .label("Reset Component")
+                            .on_click(cx.listener(|this, _, cx| this.reset_override(cx))),
+                    ),
+            )
+            .child(
+                h_flex()
+                    .flex_wrap()
+                    .gap_3()
+                    .child(slider(
+                        "Radius",
+                        &overrides.radius_slider,
+                        overrides.radius_slider.read(cx).value(),
+                    ))
+                    .child(slider(
+                        "Padding X",
+                        &overrides.padding_x_slider,
+                        overrides.padding_x_slider.read(cx).value(),
+                    ))
+                    .child(slider(
+                        "Padding Y",
+                        &overrides.padding_y_slider,
+                        overrides.padding_y_slider.read(cx).value(),
+                    )),
+            )
+            .child(
+                h_flex().flex_wrap().gap_3().children(
+                    overrides
+                        .pickers
+                        .iter()
+                        .map(|picker| div().w(px(220.)).child(picker.clone())),
+                ),
+            )
+            .child(
+                h_flex()
+                    .gap_4()
+                    .items_center()
+                    .child(Button::new("override-preview-button", cx).label("Button"))
+                    .child(Tab::new("override-preview-tab", "Tab").selected(true))
+                    .child(
+                        div()
+                            .w(px(200.))
+                            .child(ListItem::new("override-preview-list-item").child("List Item")),
+                    ),
+            )
+    }
+
+    fn copy_json(&mut self, cx: &mut ViewContext<Self>) {
+        cx.write_to_clipboard(ClipboardItem::new_string(cx.theme().to_json()));
+        cx.push_notification(Notification::success("The theme JSON has been copied."));
+    }
+
+    fn reset(&mut self, cx: &mut ViewContext<Self>) {
+        Theme::set_mode_setting(Theme::mode_setting(cx), cx);
+    }
+}
+
+impl FocusableView for ThemeEditorStory {
+    fn focus_handle(&self, _: &gpui::AppContext) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for ThemeEditorStory {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        v_flex()
+            .gap_6()
+            .child(
+                h_flex()
+                    .gap_3()
+                    .child(
+                        Button::new("copy-theme-json", cx)
+                            .small()
+                            .icon(IconName::Copy)
+                            .label("Copy JSON")
+                            .on_click(cx.listener(|this, _, cx| this.copy_json(cx))),
+                    )
+                    .child(
+                        Button::new("reset-theme", cx)
+                            .small()
+                            .label("Reset")
+                            .on_click(cx.listener(|this, _, cx| this.reset(cx))),
+                    )
+                    .child(
+                        div()
+                            .text_sm()
+                            .text_color(cx.theme().tokens.on_surface_muted)
+                            .child("Use \"Save Theme\" in the title bar to export to a file."),
+                    ),
+            )
+            .child(
+                section("Layout", cx)
+                    .child(
+                        h_flex()
+                            .w(px(220.))
+                            .gap_3()
+                            .child(div().flex_1().child(self.radius_slider.clone()))
+                            .child(format!("Radius: {}px", cx.theme().radius)),
+                    )
+                    .child(
+                        h_flex()
+                            .w(px(220.))
+                            .gap_3()
+                            .child(div().flex_1().child(self.font_size_slider.clone()))
+                            .child(format!("Font Size: {}px", cx.theme().font_size)),
+                    ),
+            )
+            .child(self.render_overrides(cx))
+            .child(
+                section("Colors", cx).child(
+                    h_flex().flex_wrap().gap_3().children(
+                        self.pickers
+                            .iter()
+                            .map(|picker| div().w(px(220.)).child(picker.clone())),
+                    ),
+                ),
+            )
+    }
+}
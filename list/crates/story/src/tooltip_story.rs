@@ -1,6 +1,8 @@
+use std::time::Duration;
+
 use gpui::{
-    div, CursorStyle, InteractiveElement, ParentElement, Render, StatefulInteractiveElement,
-    Styled, View, VisualContext as _, WindowContext,
+    div, CursorStyle, InteractiveElement, ParentElement, Render, SharedString,
+    StatefulInteractiveElement, Styled, View, VisualContext as _, WindowContext,
 };
 
 use ui::{
@@ -8,8 +10,8 @@ use ui::{
     checkbox::Checkbox,
     h_flex,
     label::Label,
-    tooltip::Tooltip,
-    v_flex,
+    tooltip::{Tooltip, TooltipManager},
+    v_flex, Placement, Selectable as _, Sizable as _,
 };
 
 pub struct TooltipStory;
@@ -26,9 +28,45 @@ impl TooltipStory {
 
 impl Render for TooltipStory {
     fn render(&mut self, cx: &mut gpui::ViewContext<Self>) -> impl gpui::IntoElement {
+        let manager = TooltipManager::global(cx);
+
         v_flex()
             .p_4()
             .gap_5()
+            .child(
+                h_flex().gap_2().child(Label::new("Placement")).children(
+                    [
+                        Placement::Top,
+                        Placement::Bottom,
+                        Placement::Left,
+                        Placement::Right,
+                    ]
+                    .into_iter()
+                    .map(|placement| {
+                        Button::new(SharedString::from(placement.to_string()), cx)
+                            .label(placement.to_string())
+                            .small()
+                            .selected(manager.placement == placement)
+                            .on_click(cx.listener(move |_, _, cx| {
+                                TooltipManager::set_placement(placement, cx);
+                                cx.notify();
+                            }))
+                    }),
+                ),
+            )
+            .child(h_flex().gap_2().child(Label::new("Delay")).children(
+                [0, 300, 1000].into_iter().map(|ms| {
+                    let delay = Duration::from_millis(ms);
+                    Button::new(("delay", ms as usize), cx)
+                        .label(format!("{}ms", ms))
+                        .small()
+                        .selected(manager.delay == delay)
+                        .on_click(cx.listener(move |_, _, cx| {
+                            TooltipManager::set_delay(delay, cx);
+                            cx.notify();
+                        }))
+                }),
+            ))
             .child(
                 div()
                     .cursor(CursorStyle::PointingHand)
@@ -55,5 +93,19 @@ impl Render for TooltipStory {
                     .id("tooltip-4")
                     .tooltip(|cx| Tooltip::new("Checked!", cx)),
             )
+            .child(
+                div()
+                    .cursor(CursorStyle::PointingHand)
+                    .child(Label::new("Hover me for the details"))
+                    .id("tooltip-5")
+                    .tooltip(|cx| {
+                        Tooltip::with_meta(
+                            "Rich tooltip",
+                            None,
+                            "With a secondary line of the help text.",
+                            cx,
+                        )
+                    }),
+            )
     }
 }
@@ -0,0 +1,125 @@
+use std::time::Duration;
+
+use gpui::{
+    IntoElement, ParentElement, Render, SharedString, Styled, Timer, View, ViewContext,
+    VisualContext as _, WindowContext,
+};
+use ui::{
+    h_flex,
+    prelude::FluentBuilder as _,
+    theme::ActiveTheme as _,
+    tree::{TreeEvent, TreeItem, TreeView},
+    v_flex, IconName,
+};
+
+pub struct TreeStory {
+    tree: View<TreeView>,
+    selected: Vec<SharedString>,
+    confirmed: Option<SharedString>,
+}
+
+impl TreeStory {
+    pub fn view(cx: &mut WindowContext) -> View<Self> {
+        cx.new_view(Self::new)
+    }
+
+    fn new(cx: &mut ViewContext<Self>) -> Self {
+        let tree = cx.new_view(|cx| {
+            TreeView::new(Self::items(), cx)
+                .multiple(true)
+                .on_load_children(|item, cx| {
+                    let id = item.id().clone();
+                    cx.spawn(|_| async move {
+                        // Simulate reading a directory, delay 1s to load children.
+                        Timer::after(Duration::from_secs(1)).await;
+                        if id.ends_with("private") {
+                            return Err(anyhow::anyhow!("Permission denied: {}", id));
+                        }
+
+                        Ok((1..=5)
+                            .map(|ix| {
+                                let child_id = format!("{}/file-{}.rs", id, ix);
+                                TreeItem::new(child_id, format!("file-{}.rs", ix))
+                                    .icon(IconName::File)
+                            })
+                            .chain(Some(
+                                TreeItem::new(format!("{}/nested", id), "nested")
+                                    .icon(IconName::Folder)
+                                    .lazy(),
+                            ))
+                            .collect())
+                    })
+                })
+        });
+
+        cx.subscribe(&tree, Self::on_tree_event).detach();
+
+        Self {
+            tree,
+            selected: vec![],
+            confirmed: None,
+        }
+    }
+
+    fn items() -> Vec<TreeItem> {
+        let dir = |id: &str, label: &str| {
+            TreeItem::new(id.to_string(), label.to_string()).icon(IconName::Folder)
+        };
+        let file = |id: &str, label: &str| {
+            TreeItem::new(id.to_string(), label.to_string()).icon(IconName::File)
+        };
+
+        vec![
+            dir("crates", "crates").expanded(true).children(vec![
+                dir("crates/ui", "ui").children(vec![
+                    file("crates/ui/src/lib.rs", "lib.rs"),
+                    file("crates/ui/src/tree.rs", "tree.rs"),
+                    file("crates/ui/src/list.rs", "list.rs"),
+                ]),
+                dir("crates/story", "story").lazy(),
+                dir("crates/app", "app").lazy(),
+            ]),
+            dir("assets", "assets").lazy(),
+            dir("private", "private (Load Error)").lazy(),
+            file("Cargo.toml", "Cargo.toml"),
+            file("README.md", "README.md"),
+        ]
+    }
+
+    fn on_tree_event(&mut self, _: View<TreeView>, event: &TreeEvent, cx: &mut ViewContext<Self>) {
+        match event {
+            TreeEvent::Select(ids) => self.selected = ids.clone(),
+            TreeEvent::Confirm(id) => self.confirmed = Some(id.clone()),
+        }
+        cx.notify();
+    }
+}
+
+impl Render for TreeStory {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        v_flex()
+            .size_full()
+            .gap_3()
+            .child(
+                h_flex()
+                    .gap_3()
+                    .text_sm()
+                    .text_color(cx.theme().tokens.on_surface_muted)
+                    .child(format!("Selected: {}", self.selected.len()))
+                    .when_some(self.confirmed.clone(), |this, id| {
+                        this.child(format!("Confirmed: {}", id))
+                    }),
+            )
+            .child(
+                v_flex()
+                    .flex_1()
+                    .w_full()
+                    .max_w_96()
+                    .border_1()
+                    .border_color(cx.theme().tokens.outline)
+                    .rounded_md()
+                    .overflow_hidden()
+                    .child(self.tree.clone()),
+            )
+    }
+}
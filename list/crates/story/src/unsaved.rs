@@ -0,0 +1,63 @@
+use std::{any::TypeId, collections::HashMap, rc::Rc};
+
+use gpui::{AnyView, AppContext, Global, ViewContext, WindowContext};
+
+/// A story that has unsaved changes to confirm before the panel is closed.
+pub trait UnsavedStory: 'static + Sized {
+    /// Returns true if there are changes not saved.
+    fn has_unsaved_changes(&self, cx: &AppContext) -> bool;
+
+    /// Save the changes, called when "Save" is chosen before closing.
+    fn save(&mut self, cx: &mut ViewContext<Self>);
+}
+
+struct UnsavedAdapter {
+    has_unsaved_changes: Rc<dyn Fn(&AnyView, &AppContext) -> bool>,
+    save: Rc<dyn Fn(&AnyView, &mut WindowContext)>,
+}
+
+/// The registry of the stories with unsaved changes by the view type.
+#[derive(Default)]
+struct UnsavedRegistry {
+    items: HashMap<TypeId, UnsavedAdapter>,
+}
+
+impl Global for UnsavedRegistry {}
+
+/// Register a story view type, to confirm the unsaved changes of it before closing.
+pub fn register_unsaved<S: UnsavedStory>(cx: &mut AppContext) {
+    cx.default_global::<UnsavedRegistry>().items.insert(
+        TypeId::of::<S>(),
+        UnsavedAdapter {
+            has_unsaved_changes: Rc::new(|view, cx| {
+                view.clone()
+                    .downcast::<S>()
+                    .map_or(false, |view| view.read(cx).has_unsaved_changes(cx))
+            }),
+            save: Rc::new(|view, cx| {
+                if let Ok(view) = view.clone().downcast::<S>() {
+                    view.update(cx, |view, cx| view.save(cx));
+                }
+            }),
+        },
+    );
+}
+
+/// Returns true if the story view has unsaved changes, false if the view type is not registered.
+pub(crate) fn has_unsaved_changes(view: &AnyView, cx: &AppContext) -> bool {
+    cx.try_global::<UnsavedRegistry>()
+        .and_then(|registry| registry.items.get(&view.entity_type()))
+        .map_or(false, |adapter| (adapter.has_unsaved_changes)(view, cx))
+}
+
+pub(crate) fn save(view: &AnyView, cx: &mut WindowContext) {
+    let Some(save) = cx
+        .try_global::<UnsavedRegistry>()
+        .and_then(|registry| registry.items.get(&view.entity_type()))
+        .map(|adapter| adapter.save.clone())
+    else {
+        return;
+    };
+
+    save(view, cx);
+}
@@ -103,7 +103,7 @@ impl Render for WebViewStory {
                 div()
                     .size_full()
                     .border_1()
-                    .border_color(cx.theme().border)
+                    .border_color(cx.theme().tokens.outline)
                     .child(self.webview.clone()),
             )
     }
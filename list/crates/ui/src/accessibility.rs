@@ -0,0 +1,68 @@
+use gpui::{SharedString, WindowContext};
+
+use crate::Root;
+
+/// The role of a component for the assistive technologies, like the ARIA roles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccessibleRole {
+    Button,
+    Tab,
+    ListItem,
+    Dialog,
+}
+
+impl AccessibleRole {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Button => "button",
+            Self::Tab => "tab",
+            Self::ListItem => "listitem",
+            Self::Dialog => "dialog",
+        }
+    }
+}
+
+/// The accessibility metadata reported by a component on render.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AccessibleNode {
+    pub role: AccessibleRole,
+    /// The text to announce, `None` if the component has no text and no label is set.
+    pub label: Option<SharedString>,
+    pub selected: bool,
+    pub disabled: bool,
+}
+
+impl AccessibleNode {
+    pub fn new(role: AccessibleRole, label: Option<SharedString>) -> Self {
+        Self {
+            role,
+            label,
+            selected: false,
+            disabled: false,
+        }
+    }
+
+    pub fn selected(mut self, selected: bool) -> Self {
+        self.selected = selected;
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+/// The components with an accessible role, the label is used instead of their visible content.
+pub trait Accessible: Sized {
+    /// Set the label to announce, e.g.: the purpose of an icon button or a tab with a custom element.
+    fn accessible_label(self, label: impl Into<SharedString>) -> Self;
+}
+
+/// Add the node to the accessibility tree of the window, this must be called on render.
+///
+/// gpui doesn't expose the platform accessibility API yet, so the nodes are collected by the [`Root`]
+/// in the render order, see [`Root::accessible_nodes`].
+pub(crate) fn register_accessible(node: AccessibleNode, cx: &mut WindowContext) {
+    Root::update(cx, move |root, _| root.accessible_nodes.push(node));
+}
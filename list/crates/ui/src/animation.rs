@@ -1,3 +1,7 @@
+use std::rc::Rc;
+
+use gpui::{AppContext, Global};
+
 /// A cubic bezier function like CSS `cubic-bezier`.
 ///
 /// Builder:
@@ -17,3 +21,26 @@ pub fn cubic_bezier(x1: f32, y1: f32, x2: f32, y2: f32) -> impl Fn(f32) -> f32 {
         y
     }
 }
+
+/// The easing function of the animations, e.g.: [`crate::drawer::Drawer::easing`].
+pub type Easing = Rc<dyn Fn(f32) -> f32>;
+
+/// The default easing of the Drawer and Modal, it starts fast and slows down to the end.
+pub(crate) fn default_easing() -> Easing {
+    Rc::new(cubic_bezier(0.32, 0.72, 0., 1.))
+}
+
+struct ReduceMotion(bool);
+
+impl Global for ReduceMotion {}
+
+/// Set to true to open and close the Drawer and Modal without the animations, default is false.
+pub fn set_reduce_motion(reduce_motion: bool, cx: &mut AppContext) {
+    cx.set_global(ReduceMotion(reduce_motion));
+}
+
+/// Returns true if the animations are disabled by [`set_reduce_motion`].
+pub fn reduce_motion(cx: &AppContext) -> bool {
+    cx.try_global::<ReduceMotion>()
+        .map_or(false, |reduce_motion| reduce_motion.0)
+}
@@ -0,0 +1,248 @@
+use gpui::{
+    div, img, prelude::FluentBuilder as _, px, relative, Div, Hsla, ImageSource, IntoElement,
+    ParentElement as _, Pixels, RenderOnce, SharedString, Styled, WindowContext,
+};
+
+use crate::{chart::chart_color, h_flex, theme::ActiveTheme as _, Sizable, Size};
+
+/// The online status of the user, shown as a dot at the bottom right of the [`Avatar`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AvatarStatus {
+    Online,
+    Away,
+    Busy,
+    Offline,
+}
+
+impl AvatarStatus {
+    fn color(self, cx: &WindowContext) -> Hsla {
+        match self {
+            Self::Online => crate::green_500(),
+            Self::Away => crate::amber_500(),
+            Self::Busy => crate::red_500(),
+            Self::Offline => cx.theme().tokens.on_surface_muted,
+        }
+    }
+}
+
+/// Returns the initials of the name, the first letters of the first and the last words.
+fn initials(name: &str) -> String {
+    let mut words = name.split_whitespace();
+    let first = words.next().and_then(|word| word.chars().next());
+    let last = words.last().and_then(|word| word.chars().next());
+
+    first
+        .into_iter()
+        .chain(last)
+        .flat_map(char::to_uppercase)
+        .collect()
+}
+
+/// Returns the index of the background color of the initials, the same name has the same color.
+fn color_ix(name: &str) -> usize {
+    name.bytes().fold(0usize, |hash, byte| {
+        hash.wrapping_mul(31).wrapping_add(byte as usize)
+    })
+}
+
+fn avatar_size(size: Size) -> Pixels {
+    match size {
+        Size::Size(size) => size,
+        Size::XSmall => px(20.),
+        Size::Small => px(24.),
+        Size::Medium => px(32.),
+        Size::Large => px(40.),
+    }
+}
+
+/// A round image of the user, the initials of the name are shown if there is no image,
+/// or the image failed to load.
+#[derive(IntoElement)]
+pub struct Avatar {
+    base: Div,
+    name: SharedString,
+    src: Option<ImageSource>,
+    status: Option<AvatarStatus>,
+    size: Size,
+}
+
+impl Avatar {
+    pub fn new(name: impl Into<SharedString>) -> Self {
+        Self {
+            base: div(),
+            name: name.into(),
+            src: None,
+            status: None,
+            size: Size::Medium,
+        }
+    }
+
+    /// Set the image of the avatar, e.g.: an url or a path.
+    pub fn src(mut self, src: impl Into<ImageSource>) -> Self {
+        self.src = Some(src.into());
+        self
+    }
+
+    /// Show the status dot, default is `None` to hide it.
+    pub fn status(mut self, status: impl Into<Option<AvatarStatus>>) -> Self {
+        self.status = status.into();
+        self
+    }
+}
+
+impl Sizable for Avatar {
+    fn with_size(mut self, size: impl Into<Size>) -> Self {
+        self.size = size.into();
+        self
+    }
+}
+
+impl Styled for Avatar {
+    fn style(&mut self) -> &mut gpui::StyleRefinement {
+        self.base.style()
+    }
+}
+
+impl RenderOnce for Avatar {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let size = avatar_size(self.size);
+
+        self.base
+            .relative()
+            .flex_none()
+            .size(size)
+            .rounded_full()
+            .child(
+                h_flex()
+                    .size_full()
+                    .rounded_full()
+                    .justify_center()
+                    .bg(chart_color(color_ix(&self.name), cx))
+                    .text_color(crate::white())
+                    .text_size(size * 0.4)
+                    .line_height(relative(1.))
+                    .child(initials(&self.name)),
+            )
+            // Over the initials, so the initials are still shown if the image is not loaded.
+            .when_some(self.src, |this, src| {
+                this.child(img(src).absolute().inset_0().size_full().rounded_full())
+            })
+            .when_some(self.status, |this, status| {
+                let dot_size = (size * 0.3).max(px(8.));
+                this.child(
+                    div()
+                        .absolute()
+                        .right_0()
+                        .bottom_0()
+                        .size(dot_size)
+                        .rounded_full()
+                        .border_2()
+                        .border_color(cx.theme().tokens.surface)
+                        .bg(status.color(cx)),
+                )
+            })
+    }
+}
+
+/// The overlapping avatars, the avatars more than the `max` are shown as a `+N` avatar.
+#[derive(IntoElement)]
+pub struct AvatarGroup {
+    avatars: Vec<Avatar>,
+    max: Option<usize>,
+    size: Size,
+}
+
+impl AvatarGroup {
+    pub fn new() -> Self {
+        Self {
+            avatars: Vec::new(),
+            max: None,
+            size: Size::Medium,
+        }
+    }
+
+    pub fn child(mut self, avatar: Avatar) -> Self {
+        self.avatars.push(avatar);
+        self
+    }
+
+    pub fn children(mut self, avatars: impl IntoIterator<Item = Avatar>) -> Self {
+        self.avatars.extend(avatars);
+        self
+    }
+
+    /// Set the max number of the avatars to show, default is `None` to show all.
+    pub fn max(mut self, max: usize) -> Self {
+        self.max = Some(max);
+        self
+    }
+}
+
+impl Sizable for AvatarGroup {
+    fn with_size(mut self, size: impl Into<Size>) -> Self {
+        self.size = size.into();
+        self
+    }
+}
+
+impl RenderOnce for AvatarGroup {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let size = avatar_size(self.size);
+        let overlap = size * 0.25;
+        let max = self.max.unwrap_or(self.avatars.len());
+        let overflow = self.avatars.len().saturating_sub(max);
+        let ring = cx.theme().tokens.surface;
+
+        h_flex()
+            .children(
+                self.avatars
+                    .into_iter()
+                    .take(max)
+                    .enumerate()
+                    .map(|(ix, avatar)| {
+                        avatar
+                            .with_size(size)
+                            .border_2()
+                            .border_color(ring)
+                            .when(ix > 0, |this| this.ml(-overlap))
+                    }),
+            )
+            .when(overflow > 0, |this| {
+                this.child(
+                    h_flex()
+                        .flex_none()
+                        .size(size)
+                        .rounded_full()
+                        .justify_center()
+                        .border_2()
+                        .border_color(ring)
+                        .bg(cx.theme().tokens.surface_muted)
+                        .text_color(cx.theme().tokens.on_surface_muted)
+                        .text_size(size * 0.35)
+                        .line_height(relative(1.))
+                        .when(max > 0, |this| this.ml(-overlap))
+                        .child(format!("+{}", overflow)),
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{color_ix, initials};
+
+    #[test]
+    fn test_initials() {
+        assert_eq!(initials("Jason Lee"), "JL");
+        assert_eq!(initials("jason"), "J");
+        assert_eq!(initials("  Mary Ann  Smith "), "MS");
+        assert_eq!(initials("李 小龙"), "李小");
+        assert_eq!(initials(""), "");
+    }
+
+    #[test]
+    fn test_color_ix() {
+        assert_eq!(color_ix("Jason Lee"), color_ix("Jason Lee"));
+        assert_ne!(color_ix("Jason Lee"), color_ix("Mary Smith"));
+    }
+}
@@ -0,0 +1,120 @@
+use gpui::{
+    div, prelude::FluentBuilder as _, ClickEvent, ElementId, InteractiveElement, IntoElement,
+    ParentElement, RenderOnce, SharedString, StatefulInteractiveElement, Styled, WindowContext,
+};
+
+use crate::{h_flex, theme::ActiveTheme as _, Icon, IconName};
+
+/// A segment of the [`Breadcrumb`].
+#[derive(IntoElement)]
+pub struct BreadcrumbItem {
+    id: ElementId,
+    label: SharedString,
+    icon: Option<Icon>,
+    on_click: Option<Box<dyn Fn(&ClickEvent, &mut WindowContext) + 'static>>,
+    is_last: bool,
+}
+
+impl BreadcrumbItem {
+    pub fn new(id: impl Into<ElementId>, label: impl Into<SharedString>) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            icon: None,
+            on_click: None,
+            is_last: false,
+        }
+    }
+
+    pub fn icon(mut self, icon: impl Into<Icon>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    pub fn on_click(mut self, handler: impl Fn(&ClickEvent, &mut WindowContext) + 'static) -> Self {
+        self.on_click = Some(Box::new(handler));
+        self
+    }
+
+    fn is_last(mut self, is_last: bool) -> Self {
+        self.is_last = is_last;
+        self
+    }
+}
+
+impl RenderOnce for BreadcrumbItem {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let clickable = self.on_click.is_some();
+
+        h_flex()
+            .id(self.id)
+            .gap_1()
+            .px_1()
+            .rounded(cx.theme().radius)
+            .text_color(if self.is_last {
+                cx.theme().tokens.on_surface
+            } else {
+                cx.theme().tokens.on_surface_muted
+            })
+            .when_some(self.icon, |this, icon| this.child(icon.size_3()))
+            .child(self.label)
+            .when_some(self.on_click, |this, on_click| {
+                this.on_click(move |event, cx| {
+                    cx.stop_propagation();
+                    on_click(event, cx);
+                })
+            })
+            .when(clickable, |this| {
+                this.cursor_pointer().hover(|this| {
+                    this.bg(cx.theme().tokens.highlight)
+                        .text_color(cx.theme().tokens.on_highlight)
+                })
+            })
+    }
+}
+
+/// A navigation path of the items, separated by a chevron, the last item is the current one.
+#[derive(IntoElement)]
+pub struct Breadcrumb {
+    items: Vec<BreadcrumbItem>,
+}
+
+impl Breadcrumb {
+    pub fn new() -> Self {
+        Self { items: vec![] }
+    }
+
+    pub fn item(mut self, item: BreadcrumbItem) -> Self {
+        self.items.push(item);
+        self
+    }
+
+    pub fn items(mut self, items: impl IntoIterator<Item = BreadcrumbItem>) -> Self {
+        self.items.extend(items);
+        self
+    }
+}
+
+impl RenderOnce for Breadcrumb {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let len = self.items.len();
+
+        h_flex()
+            .gap_0p5()
+            .text_sm()
+            .children(self.items.into_iter().enumerate().map(|(ix, item)| {
+                div()
+                    .flex()
+                    .items_center()
+                    .gap_0p5()
+                    .when(ix > 0, |this| {
+                        this.child(
+                            Icon::new(IconName::ChevronRight)
+                                .size_3()
+                                .text_color(cx.theme().tokens.on_surface_muted),
+                        )
+                    })
+                    .child(item.is_last(ix + 1 == len))
+            }))
+    }
+}
@@ -1,14 +1,19 @@
+use std::{cell::Cell, rc::Rc};
+
 use crate::{
+    accessibility::{register_accessible, Accessible, AccessibleNode, AccessibleRole},
+    focusable::register_tab_stop,
     h_flex,
     indicator::Indicator,
-    theme::{ActiveTheme, Colorize as _},
+    inspector::InspectorExt as _,
+    theme::{ActiveTheme, Colorize as _, ComponentKind, StyleOverridesExt as _},
     tooltip::Tooltip,
-    Disableable, Icon, Selectable, Sizable, Size,
+    Disableable, Icon, Selectable, Sizable, Size, StyledExt as _,
 };
 use gpui::{
-    div, prelude::FluentBuilder as _, px, relative, AnyElement, ClickEvent, Div, ElementId,
+    div, prelude::FluentBuilder as _, px, relative, Action, AnyElement, ClickEvent, Div, ElementId,
     FocusHandle, Hsla, InteractiveElement, IntoElement, MouseButton, ParentElement, Pixels,
-    RenderOnce, SharedString, StatefulInteractiveElement as _, Styled, WindowContext,
+    RenderOnce, SharedString, StatefulInteractiveElement as _, Styled, Task, WindowContext,
 };
 
 pub enum ButtonRounded {
@@ -37,11 +42,11 @@ pub struct ButtonCustomStyle {
 impl ButtonCustomStyle {
     pub fn new(cx: &WindowContext) -> Self {
         Self {
-            color: cx.theme().secondary,
-            foreground: cx.theme().secondary_foreground,
-            border: cx.theme().border,
-            hover: cx.theme().secondary_hover,
-            active: cx.theme().secondary_active,
+            color: cx.theme().tokens.neutral,
+            foreground: cx.theme().tokens.on_neutral,
+            border: cx.theme().tokens.outline,
+            hover: cx.theme().neutral_hover,
+            active: cx.theme().neutral_active,
         }
     }
 
@@ -97,12 +102,29 @@ impl ButtonStyle {
     }
 }
 
+/// Whether the async click handler of a [`Button`] is running, see [`Button::on_click_async`].
+///
+/// Keep it in the view, the clones share the same state.
+#[derive(Debug, Clone, Default)]
+pub struct LoadingState(Rc<Cell<bool>>);
+
+impl LoadingState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_loading(&self) -> bool {
+        self.0.get()
+    }
+}
+
 #[derive(IntoElement)]
 pub struct Button {
     pub base: Div,
     id: ElementId,
     focus_handle: FocusHandle,
     icon: Option<Icon>,
+    suffix_icon: Option<Icon>,
     label: Option<SharedString>,
     children: Vec<AnyElement>,
     disabled: bool,
@@ -112,8 +134,12 @@ pub struct Button {
     size: Size,
     compact: bool,
     tooltip: Option<SharedString>,
-    on_click: Option<Box<dyn Fn(&ClickEvent, &mut WindowContext) + 'static>>,
+    tooltip_action: Option<Box<dyn Action>>,
+    on_click: Option<Rc<dyn Fn(&ClickEvent, &mut WindowContext) + 'static>>,
     loading: bool,
+    loading_state: Option<LoadingState>,
+    tab_stop: bool,
+    accessible_label: Option<SharedString>,
 }
 
 impl From<Button> for AnyElement {
@@ -129,6 +155,7 @@ impl Button {
             focus_handle: cx.focus_handle(),
             id: id.into(),
             icon: None,
+            suffix_icon: None,
             label: None,
             disabled: false,
             selected: false,
@@ -136,8 +163,12 @@ impl Button {
             rounded: ButtonRounded::Medium,
             size: Size::Medium,
             tooltip: None,
+            tooltip_action: None,
             on_click: None,
             loading: false,
+            loading_state: None,
+            tab_stop: false,
+            accessible_label: None,
             compact: false,
             children: Vec::new(),
         }
@@ -149,7 +180,13 @@ impl Button {
         self
     }
 
-    /// With the secondary style for the Button.
+    /// With the secondary style for the Button, it's the default style.
+    pub fn secondary(mut self) -> Self {
+        self.style = ButtonStyle::Secondary;
+        self
+    }
+
+    /// With the danger style for the Button, for the destructive actions.
     pub fn danger(mut self) -> Self {
         self.style = ButtonStyle::Danger;
         self
@@ -203,12 +240,29 @@ impl Button {
         self
     }
 
+    /// Set the icon after the label, e.g.: a chevron of a dropdown button.
+    pub fn suffix_icon(mut self, icon: impl Into<Icon>) -> Self {
+        self.suffix_icon = Some(icon.into());
+        self
+    }
+
     /// Set the tooltip of the button.
     pub fn tooltip(mut self, tooltip: impl Into<SharedString>) -> Self {
         self.tooltip = Some(tooltip.into());
         self
     }
 
+    /// Set the tooltip of the button, with the keybinding of the action as a hint.
+    pub fn tooltip_with_action(
+        mut self,
+        tooltip: impl Into<SharedString>,
+        action: &dyn Action,
+    ) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self.tooltip_action = Some(action.boxed_clone());
+        self
+    }
+
     /// Set the ButtonStyle
     pub fn style(mut self, style: ButtonStyle) -> Self {
         self.style = style;
@@ -228,7 +282,54 @@ impl Button {
     }
 
     pub fn on_click(mut self, handler: impl Fn(&ClickEvent, &mut WindowContext) + 'static) -> Self {
-        self.on_click = Some(Box::new(handler));
+        self.on_click = Some(Rc::new(handler));
+        self
+    }
+
+    /// Run the async task returned by the handler on click, the button shows the loading indicator
+    /// and ignores the clicks until the task is done.
+    ///
+    /// The `state` must be kept by the parent view to keep the loading state between the renders.
+    pub fn on_click_async(
+        mut self,
+        state: &LoadingState,
+        handler: impl Fn(&ClickEvent, &mut WindowContext) -> Task<()> + 'static,
+    ) -> Self {
+        let state = state.clone();
+        self.loading_state = Some(state.clone());
+        self.on_click = Some(Rc::new(move |event, cx| {
+            if state.is_loading() {
+                return;
+            }
+
+            let task = handler(event, cx);
+            state.0.set(true);
+            cx.refresh();
+            let state = state.clone();
+            cx.spawn(|mut cx| async move {
+                task.await;
+                state.0.set(false);
+                cx.update(|cx| cx.refresh()).ok();
+            })
+            .detach();
+        }));
+        self
+    }
+
+    /// Use the `focus_handle` for the button, and add it to the Tab / Shift-Tab focus traversal.
+    ///
+    /// The focus handle must be kept by the parent view, then the button can be clicked by
+    /// `enter` or `space` when focused.
+    pub fn tab_stop(mut self, focus_handle: &FocusHandle) -> Self {
+        self.focus_handle = focus_handle.clone();
+        self.tab_stop = true;
+        self
+    }
+}
+
+impl Accessible for Button {
+    fn accessible_label(mut self, label: impl Into<SharedString>) -> Self {
+        self.accessible_label = Some(label.into());
         self
     }
 }
@@ -267,10 +368,28 @@ impl ParentElement for Button {
 }
 
 impl RenderOnce for Button {
-    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+    fn render(mut self, cx: &mut WindowContext) -> impl IntoElement {
+        if let Some(state) = &self.loading_state {
+            self.loading |= state.is_loading();
+        }
         let style: ButtonStyle = self.style;
         let normal_style = style.normal(cx);
         let focused = self.focus_handle.is_focused(cx);
+        if self.tab_stop {
+            register_tab_stop(&self.focus_handle, cx);
+        }
+        // An icon button is announced by its tooltip if no label is set.
+        let accessible_label = self
+            .accessible_label
+            .clone()
+            .or_else(|| self.label.clone())
+            .or_else(|| self.tooltip.clone());
+        register_accessible(
+            AccessibleNode::new(AccessibleRole::Button, accessible_label)
+                .selected(self.selected)
+                .disabled(self.disabled),
+            cx,
+        );
         let icon_size = match self.size {
             Size::Size(v) => Size::Size(v * 0.75),
             _ => self.size,
@@ -318,6 +437,9 @@ impl RenderOnce for Button {
                 ButtonRounded::None => this.rounded_none(),
             })
             .text_color(normal_style.fg)
+            .border_color(normal_style.border)
+            .bg(normal_style.bg)
+            .style_overrides(ComponentKind::Button, cx)
             .when(self.selected, |this| {
                 let selected_style = style.selected(cx);
                 this.bg(selected_style.bg)
@@ -325,14 +447,12 @@ impl RenderOnce for Button {
                     .text_color(selected_style.fg)
             })
             .when(!self.disabled && !self.selected, |this| {
-                this.border_color(normal_style.border)
-                    .bg(normal_style.bg)
-                    .when(normal_style.underline, |this| this.text_decoration_1())
+                this.when(normal_style.underline, |this| this.text_decoration_1())
                     .hover(|this| {
                         let hover_style = style.hovered(cx);
                         this.bg(hover_style.bg)
                             .border_color(hover_style.border)
-                            .text_color(crate::red_400())
+                            .text_color(hover_style.fg)
                     })
                     .active(|this| {
                         let active_style = style.active(cx);
@@ -341,7 +461,8 @@ impl RenderOnce for Button {
                             .text_color(active_style.fg)
                     })
             })
-            .when(focused, |this| this.border_color(cx.theme().ring))
+            .when(focused, |this| this.border_color(cx.theme().tokens.focus))
+            .focus_ring(focused && self.tab_stop, cx)
             .when_some(
                 self.on_click.filter(|_| !self.disabled && !self.loading),
                 |this, on_click| {
@@ -349,6 +470,15 @@ impl RenderOnce for Button {
                         cx.prevent_default();
                         cx.stop_propagation()
                     })
+                    .on_key_down({
+                        let on_click = on_click.clone();
+                        move |event, cx| {
+                            if matches!(event.keystroke.key.as_str(), "enter" | "space") {
+                                cx.stop_propagation();
+                                (on_click)(&ClickEvent::default(), cx);
+                            }
+                        }
+                    })
                     .on_click(move |event, cx| {
                         (on_click)(event, cx);
                     })
@@ -384,11 +514,19 @@ impl RenderOnce for Button {
                     })
                     .when_some(self.label, |this, label| this.child(label))
                     .children(self.children)
+                    .when_some(self.suffix_icon, |this, icon| {
+                        this.child(icon.with_size(icon_size))
+                    })
             })
             .when(self.loading, |this| this.bg(normal_style.bg.opacity(0.8)))
             .when_some(self.tooltip.clone(), |this, tooltip| {
-                this.tooltip(move |cx| Tooltip::new(tooltip.clone(), cx))
+                let action = self.tooltip_action;
+                this.tooltip(move |cx| match &action {
+                    Some(action) => Tooltip::for_action(tooltip.clone(), action.as_ref(), cx),
+                    None => Tooltip::new(tooltip.clone(), cx),
+                })
             })
+            .inspect("Button", cx)
     }
 }
 
@@ -402,9 +540,9 @@ struct ButtonStyles {
 impl ButtonStyle {
     fn bg_color(&self, cx: &WindowContext) -> Hsla {
         match self {
-            ButtonStyle::Primary => cx.theme().primary,
-            ButtonStyle::Secondary => cx.theme().secondary,
-            ButtonStyle::Danger => cx.theme().destructive,
+            ButtonStyle::Primary => cx.theme().tokens.accent,
+            ButtonStyle::Secondary => cx.theme().tokens.neutral,
+            ButtonStyle::Danger => cx.theme().tokens.danger,
             ButtonStyle::Outline | ButtonStyle::Ghost | ButtonStyle::Link | ButtonStyle::Text => {
                 cx.theme().transparent
             }
@@ -414,23 +552,23 @@ impl ButtonStyle {
 
     fn text_color(&self, cx: &WindowContext) -> Hsla {
         match self {
-            ButtonStyle::Primary => cx.theme().primary_foreground,
+            ButtonStyle::Primary => cx.theme().tokens.on_accent,
             ButtonStyle::Secondary | ButtonStyle::Outline | ButtonStyle::Ghost => {
-                cx.theme().secondary_foreground
+                cx.theme().tokens.on_neutral
             }
-            ButtonStyle::Danger => cx.theme().destructive_foreground,
-            ButtonStyle::Link => cx.theme().link,
-            ButtonStyle::Text => cx.theme().foreground,
+            ButtonStyle::Danger => cx.theme().tokens.on_danger,
+            ButtonStyle::Link => cx.theme().tokens.link,
+            ButtonStyle::Text => cx.theme().tokens.on_surface,
             ButtonStyle::Custom(colors) => colors.foreground,
         }
     }
 
     fn border_color(&self, cx: &WindowContext) -> Hsla {
         match self {
-            ButtonStyle::Primary => cx.theme().primary,
-            ButtonStyle::Secondary => cx.theme().border,
-            ButtonStyle::Danger => cx.theme().destructive,
-            ButtonStyle::Outline => cx.theme().border,
+            ButtonStyle::Primary => cx.theme().tokens.accent,
+            ButtonStyle::Secondary => cx.theme().tokens.outline,
+            ButtonStyle::Danger => cx.theme().tokens.danger,
+            ButtonStyle::Outline => cx.theme().tokens.outline,
             ButtonStyle::Ghost | ButtonStyle::Link | ButtonStyle::Text => cx.theme().transparent,
             ButtonStyle::Custom(colors) => colors.border,
         }
@@ -459,10 +597,10 @@ impl ButtonStyle {
 
     fn hovered(&self, cx: &WindowContext) -> ButtonStyles {
         let bg = match self {
-            ButtonStyle::Primary => cx.theme().primary_hover,
-            ButtonStyle::Secondary | ButtonStyle::Outline => cx.theme().secondary_hover,
-            ButtonStyle::Danger => cx.theme().destructive_hover,
-            ButtonStyle::Ghost => cx.theme().secondary_hover,
+            ButtonStyle::Primary => cx.theme().accent_hover,
+            ButtonStyle::Secondary | ButtonStyle::Outline => cx.theme().neutral_hover,
+            ButtonStyle::Danger => cx.theme().danger_hover,
+            ButtonStyle::Ghost => cx.theme().neutral_hover,
             ButtonStyle::Link => cx.theme().transparent,
             ButtonStyle::Text => cx.theme().transparent,
             ButtonStyle::Custom(colors) => colors.hover,
@@ -484,11 +622,11 @@ impl ButtonStyle {
 
     fn active(&self, cx: &WindowContext) -> ButtonStyles {
         let bg = match self {
-            ButtonStyle::Primary => cx.theme().primary_active,
+            ButtonStyle::Primary => cx.theme().accent_active,
             ButtonStyle::Secondary | ButtonStyle::Outline | ButtonStyle::Ghost => {
-                cx.theme().secondary_active
+                cx.theme().neutral_active
             }
-            ButtonStyle::Danger => cx.theme().destructive_active,
+            ButtonStyle::Danger => cx.theme().danger_active,
             ButtonStyle::Link => cx.theme().transparent,
             ButtonStyle::Text => cx.theme().transparent,
             ButtonStyle::Custom(colors) => colors.active,
@@ -496,7 +634,7 @@ impl ButtonStyle {
         let border = self.border_color(cx);
         let fg = match self {
             ButtonStyle::Link => cx.theme().link_active,
-            ButtonStyle::Text => cx.theme().foreground.opacity(0.7),
+            ButtonStyle::Text => cx.theme().tokens.on_surface.opacity(0.7),
             _ => self.text_color(cx),
         };
         let underline = self.underline(cx);
@@ -511,11 +649,11 @@ impl ButtonStyle {
 
     fn selected(&self, cx: &WindowContext) -> ButtonStyles {
         let bg = match self {
-            ButtonStyle::Primary => cx.theme().primary_active,
+            ButtonStyle::Primary => cx.theme().accent_active,
             ButtonStyle::Secondary | ButtonStyle::Outline | ButtonStyle::Ghost => {
-                cx.theme().secondary_active
+                cx.theme().neutral_active
             }
-            ButtonStyle::Danger => cx.theme().destructive_active,
+            ButtonStyle::Danger => cx.theme().danger_active,
             ButtonStyle::Link => cx.theme().transparent,
             ButtonStyle::Text => cx.theme().transparent,
             ButtonStyle::Custom(colors) => colors.active,
@@ -523,7 +661,7 @@ impl ButtonStyle {
         let border = self.border_color(cx);
         let fg = match self {
             ButtonStyle::Link => cx.theme().link_active,
-            ButtonStyle::Text => cx.theme().foreground.opacity(0.7),
+            ButtonStyle::Text => cx.theme().tokens.on_surface.opacity(0.7),
             _ => self.text_color(cx),
         };
         let underline = self.underline(cx);
@@ -539,13 +677,13 @@ impl ButtonStyle {
     fn disabled(&self, cx: &WindowContext) -> ButtonStyles {
         let bg = match self {
             ButtonStyle::Link | ButtonStyle::Ghost | ButtonStyle::Text => cx.theme().transparent,
-            _ => cx.theme().secondary.darken(0.2).grayscale(),
+            _ => cx.theme().tokens.neutral.darken(0.2).grayscale(),
         };
         let fg = match self {
             ButtonStyle::Link | ButtonStyle::Text | ButtonStyle::Ghost => {
-                cx.theme().link.grayscale()
+                cx.theme().tokens.link.grayscale()
             }
-            _ => cx.theme().secondary_foreground.darken(0.2).grayscale(),
+            _ => cx.theme().tokens.on_neutral.darken(0.2).grayscale(),
         };
 
         let border = bg;
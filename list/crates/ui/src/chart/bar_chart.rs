@@ -0,0 +1,141 @@
+use gpui::{
+    div, prelude::FluentBuilder as _, px, relative, ElementId, InteractiveElement as _,
+    IntoElement, ParentElement as _, Pixels, RenderOnce, SharedString,
+    StatefulInteractiveElement as _, Styled as _, WindowContext,
+};
+
+use crate::{h_flex, theme::ActiveTheme as _, theme::Colorize as _, tooltip::Tooltip, v_flex};
+
+use super::{format_value, render_grid, render_y_axis, series_max, ChartSeries, Legend};
+
+/// The width of the y axis labels.
+const AXIS_WIDTH: Pixels = px(36.);
+
+/// A vertical bar chart, the bars of the series are grouped by the labels.
+///
+/// The value of a bar is shown in the tooltip on hover.
+#[derive(IntoElement)]
+pub struct BarChart {
+    id: ElementId,
+    labels: Vec<SharedString>,
+    series: Vec<ChartSeries>,
+    height: Pixels,
+    legend: bool,
+}
+
+impl BarChart {
+    /// Create a bar chart with the labels of the x axis.
+    pub fn new(
+        id: impl Into<ElementId>,
+        labels: impl IntoIterator<Item = impl Into<SharedString>>,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            labels: labels.into_iter().map(Into::into).collect(),
+            series: Vec::new(),
+            height: px(200.),
+            legend: true,
+        }
+    }
+
+    /// Add a series, it has a bar for each label.
+    pub fn series(mut self, series: ChartSeries) -> Self {
+        self.series.push(series);
+        self
+    }
+
+    /// Set the height of the plot area, default is 200px.
+    pub fn height(mut self, height: impl Into<Pixels>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Show the legend of the series below the chart, default is true.
+    pub fn legend(mut self, legend: bool) -> Self {
+        self.legend = legend;
+        self
+    }
+}
+
+impl RenderOnce for BarChart {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let max = series_max(&self.series);
+        let colors = self
+            .series
+            .iter()
+            .enumerate()
+            .map(|(ix, series)| series.color_or_default(ix, cx))
+            .collect::<Vec<_>>();
+        let legend = Legend::new(
+            self.series
+                .iter()
+                .zip(colors.iter())
+                .map(|(series, color)| (*color, series.name.clone())),
+        );
+
+        let groups = self.labels.iter().enumerate().map(|(label_ix, label)| {
+            h_flex()
+                .flex_1()
+                .h_full()
+                .px_2()
+                .gap(px(2.))
+                .items_end()
+                .justify_center()
+                .children(self.series.iter().zip(colors.iter()).enumerate().map(
+                    |(series_ix, (series, color))| {
+                        let value = series.value(label_ix);
+                        let label = label.clone();
+                        let meta = format!("{}: {}", series.name, format_value(value));
+                        let hover_color = color.opacity(0.8);
+
+                        div()
+                            .id(("bar", label_ix * self.series.len() + series_ix))
+                            .flex_1()
+                            .max_w(px(32.))
+                            .h(relative((value / max) as f32))
+                            .rounded_t(px(2.))
+                            .bg(*color)
+                            .hover(move |this| this.bg(hover_color))
+                            .tooltip(move |cx| {
+                                Tooltip::with_meta(label.clone(), None, meta.clone(), cx)
+                            })
+                    },
+                ))
+        });
+
+        v_flex()
+            .id(self.id)
+            .w_full()
+            .gap_2()
+            .child(
+                h_flex()
+                    .h(self.height)
+                    .gap_2()
+                    .child(div().w(AXIS_WIDTH).h_full().child(render_y_axis(max, cx)))
+                    .child(
+                        div()
+                            .relative()
+                            .flex_1()
+                            .h_full()
+                            .child(render_grid(cx))
+                            .child(h_flex().absolute().size_full().children(groups)),
+                    ),
+            )
+            .child(
+                h_flex()
+                    .pl(AXIS_WIDTH + px(8.))
+                    .text_xs()
+                    .text_color(cx.theme().tokens.on_surface_muted)
+                    .children(self.labels.iter().map(|label| {
+                        div()
+                            .flex_1()
+                            .overflow_hidden()
+                            .text_center()
+                            .child(label.clone())
+                    })),
+            )
+            .when(self.legend && !self.series.is_empty(), |this| {
+                this.child(legend)
+            })
+    }
+}
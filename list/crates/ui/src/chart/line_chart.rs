@@ -0,0 +1,208 @@
+use gpui::{
+    canvas, div, prelude::FluentBuilder as _, px, relative, ElementId, InteractiveElement as _,
+    IntoElement, ParentElement as _, Pixels, RenderOnce, SharedString,
+    StatefulInteractiveElement as _, Styled as _, WindowContext,
+};
+
+use crate::{h_flex, theme::ActiveTheme as _, theme::Colorize as _, tooltip::Tooltip, v_flex};
+
+use super::{
+    format_value, paint_line, paint_polygon, point_in, render_grid, render_y_axis, series_max,
+    ChartSeries, Legend,
+};
+
+/// The width of the y axis labels.
+const AXIS_WIDTH: Pixels = px(36.);
+/// The width of the line.
+const LINE_WIDTH: Pixels = px(2.);
+/// The size of the dot of a point.
+const DOT_SIZE: Pixels = px(8.);
+
+/// A line chart, each series is a line through its values from the first label to the last.
+///
+/// The value of a point is shown in the tooltip on hover.
+#[derive(IntoElement)]
+pub struct LineChart {
+    id: ElementId,
+    labels: Vec<SharedString>,
+    series: Vec<ChartSeries>,
+    height: Pixels,
+    fill: bool,
+    legend: bool,
+}
+
+impl LineChart {
+    /// Create a line chart with the labels of the x axis.
+    pub fn new(
+        id: impl Into<ElementId>,
+        labels: impl IntoIterator<Item = impl Into<SharedString>>,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            labels: labels.into_iter().map(Into::into).collect(),
+            series: Vec::new(),
+            height: px(200.),
+            fill: false,
+            legend: true,
+        }
+    }
+
+    /// Add a series, it has a point for each label.
+    pub fn series(mut self, series: ChartSeries) -> Self {
+        self.series.push(series);
+        self
+    }
+
+    /// Set the height of the plot area, default is 200px.
+    pub fn height(mut self, height: impl Into<Pixels>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Fill the area below the lines, default is false.
+    pub fn fill(mut self, fill: bool) -> Self {
+        self.fill = fill;
+        self
+    }
+
+    /// Show the legend of the series below the chart, default is true.
+    pub fn legend(mut self, legend: bool) -> Self {
+        self.legend = legend;
+        self
+    }
+
+    /// Returns the ratio of the x position of the point at `ix`, the points are centered to the labels.
+    fn x_ratio(&self, ix: usize) -> f32 {
+        (ix as f32 + 0.5) / self.labels.len().max(1) as f32
+    }
+}
+
+impl RenderOnce for LineChart {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let max = series_max(&self.series);
+        let colors = self
+            .series
+            .iter()
+            .enumerate()
+            .map(|(ix, series)| series.color_or_default(ix, cx))
+            .collect::<Vec<_>>();
+        let legend = Legend::new(
+            self.series
+                .iter()
+                .zip(colors.iter())
+                .map(|(series, color)| (*color, series.name.clone())),
+        );
+
+        // The ratio of the points in the plot area, by the series.
+        let lines = self
+            .series
+            .iter()
+            .map(|series| {
+                (0..self.labels.len())
+                    .map(|ix| (self.x_ratio(ix), 1. - (series.value(ix) / max) as f32))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let background = cx.theme().tokens.surface;
+        let dots = lines
+            .iter()
+            .zip(self.series.iter().zip(colors.iter()))
+            .enumerate()
+            .flat_map(|(series_ix, (points, (series, color)))| {
+                let labels = &self.labels;
+                let series_len = self.series.len();
+                let color = *color;
+                points.iter().enumerate().map(move |(ix, (x, y))| {
+                    let label = labels[ix].clone();
+                    let meta = format!("{}: {}", series.name, format_value(series.value(ix)));
+
+                    div()
+                        .id(("point", ix * series_len + series_ix))
+                        .absolute()
+                        .left(relative(*x))
+                        .top(relative(*y))
+                        .ml(-DOT_SIZE / 2.)
+                        .mt(-DOT_SIZE / 2.)
+                        .size(DOT_SIZE)
+                        .rounded_full()
+                        .border_2()
+                        .border_color(color)
+                        .bg(background)
+                        .hover(|this| this.bg(color))
+                        .tooltip(move |cx| {
+                            Tooltip::with_meta(label.clone(), None, meta.clone(), cx)
+                        })
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let fill = self.fill;
+        let plot = canvas(
+            move |_, _| (),
+            move |bounds, _, cx| {
+                for (points, color) in lines.iter().zip(colors.iter()) {
+                    for pair in points.windows(2) {
+                        let (x0, y0) = pair[0];
+                        let (x1, y1) = pair[1];
+                        let start = point_in(&bounds, x0, y0);
+                        let end = point_in(&bounds, x1, y1);
+
+                        if fill {
+                            // The area below a segment is a trapezoid, it's always convex.
+                            paint_polygon(
+                                &[
+                                    start,
+                                    end,
+                                    point_in(&bounds, x1, 1.),
+                                    point_in(&bounds, x0, 1.),
+                                ],
+                                color.opacity(0.15),
+                                cx,
+                            );
+                        }
+                        paint_line(start, end, LINE_WIDTH, *color, cx);
+                    }
+                }
+            },
+        )
+        .absolute()
+        .size_full();
+
+        v_flex()
+            .id(self.id)
+            .w_full()
+            .gap_2()
+            .child(
+                h_flex()
+                    .h(self.height)
+                    .gap_2()
+                    .child(div().w(AXIS_WIDTH).h_full().child(render_y_axis(max, cx)))
+                    .child(
+                        div()
+                            .relative()
+                            .flex_1()
+                            .h_full()
+                            .child(render_grid(cx))
+                            .child(plot)
+                            .children(dots),
+                    ),
+            )
+            .child(
+                h_flex()
+                    .pl(AXIS_WIDTH + px(8.))
+                    .text_xs()
+                    .text_color(cx.theme().tokens.on_surface_muted)
+                    .children(self.labels.iter().map(|label| {
+                        div()
+                            .flex_1()
+                            .overflow_hidden()
+                            .text_center()
+                            .child(label.clone())
+                    })),
+            )
+            .when(self.legend && !self.series.is_empty(), |this| {
+                this.child(legend)
+            })
+    }
+}
@@ -0,0 +1,255 @@
+//! The simple charts drawn by gpui, with the colors of the theme.
+//!
+//! - [`BarChart`] to compare the values of the categories, grouped by the series.
+//! - [`LineChart`] to show the trend of the series.
+//! - [`PieChart`] to show the proportion of the parts.
+//! - [`Sparkline`] a tiny line without the axes, to show the trend inline, e.g.: in a table cell.
+//!
+//! The values must be non-negative, the negative values are drawn as zero.
+
+mod bar_chart;
+mod line_chart;
+mod pie_chart;
+mod sparkline;
+
+pub use bar_chart::BarChart;
+pub use line_chart::LineChart;
+pub use pie_chart::{PieChart, PieSlice};
+pub use sparkline::Sparkline;
+
+use gpui::{
+    div, point, px, relative, Bounds, Hsla, IntoElement, ParentElement as _, Path, Pixels, Point,
+    RenderOnce, SharedString, Styled as _, WindowContext,
+};
+
+use crate::{h_flex, theme::ActiveTheme as _, v_flex};
+
+/// The number of the horizontal grid lines, including the baseline.
+const TICKS: usize = 5;
+
+/// Returns the default color of the series or slice at `ix`, by the chart colors of the theme.
+pub fn chart_color(ix: usize, cx: &WindowContext) -> Hsla {
+    let theme = cx.theme();
+    let colors = [
+        theme.chart_1,
+        theme.chart_2,
+        theme.chart_3,
+        theme.chart_4,
+        theme.chart_5,
+        theme.chart_6,
+        theme.chart_7,
+        theme.chart_8,
+    ];
+
+    colors[ix % colors.len()]
+}
+
+/// A named series of values, one value per label of the chart.
+#[derive(Debug, Clone)]
+pub struct ChartSeries {
+    pub(crate) name: SharedString,
+    pub(crate) values: Vec<f64>,
+    pub(crate) color: Option<Hsla>,
+}
+
+impl ChartSeries {
+    pub fn new(name: impl Into<SharedString>, values: impl IntoIterator<Item = f64>) -> Self {
+        Self {
+            name: name.into(),
+            values: values.into_iter().collect(),
+            color: None,
+        }
+    }
+
+    /// Set the color of the series, default is the [`chart_color`] by the index of the series.
+    pub fn color(mut self, color: impl Into<Hsla>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    pub(crate) fn color_or_default(&self, ix: usize, cx: &WindowContext) -> Hsla {
+        self.color.unwrap_or_else(|| chart_color(ix, cx))
+    }
+
+    /// Returns the value at `ix`, zero if it's missing or negative.
+    pub(crate) fn value(&self, ix: usize) -> f64 {
+        self.values.get(ix).copied().unwrap_or_default().max(0.)
+    }
+}
+
+/// Returns the max of the y axis, the `value` is rounded up to 1, 2, 2.5 or 5 times a power of 10.
+pub(crate) fn nice_max(value: f64) -> f64 {
+    if !value.is_finite() || value <= 0. {
+        return 1.;
+    }
+
+    let magnitude = 10f64.powf(value.log10().floor());
+    let step = [1., 2., 2.5, 5., 10.]
+        .into_iter()
+        .find(|step| step * magnitude >= value)
+        .unwrap_or(10.);
+    step * magnitude
+}
+
+/// Format the value for the axis and the tooltip, without the useless decimals.
+pub(crate) fn format_value(value: f64) -> String {
+    if value.fract() == 0. || value.abs() >= 100. {
+        format!("{:.0}", value)
+    } else {
+        let text = format!("{:.2}", value);
+        text.trim_end_matches('0').trim_end_matches('.').to_string()
+    }
+}
+
+/// Returns the max of the y axis for the series.
+pub(crate) fn series_max(series: &[ChartSeries]) -> f64 {
+    nice_max(
+        series
+            .iter()
+            .flat_map(|series| series.values.iter().copied())
+            .fold(0., f64::max),
+    )
+}
+
+/// The labels of the y axis, from the `max` at the top to zero at the bottom.
+pub(crate) fn render_y_axis(max: f64, cx: &WindowContext) -> impl IntoElement {
+    v_flex()
+        .h_full()
+        .justify_between()
+        .items_end()
+        .text_xs()
+        .text_color(cx.theme().tokens.on_surface_muted)
+        .children((0..TICKS).map(|ix| {
+            let value = max * (TICKS - 1 - ix) as f64 / (TICKS - 1) as f64;
+            // Center the label to the grid line.
+            div()
+                .h(px(0.))
+                .flex()
+                .items_center()
+                .child(format_value(value))
+        }))
+}
+
+/// The horizontal grid lines of the plot area, the last one is the baseline.
+pub(crate) fn render_grid(cx: &WindowContext) -> impl IntoElement {
+    div().absolute().size_full().children((0..TICKS).map(|ix| {
+        let color = if ix == TICKS - 1 {
+            cx.theme().tokens.outline
+        } else {
+            cx.theme().tokens.outline.opacity(0.5)
+        };
+
+        div()
+            .absolute()
+            .left_0()
+            .right_0()
+            .top(relative(ix as f32 / (TICKS - 1) as f32))
+            .h(px(1.))
+            .bg(color)
+    }))
+}
+
+/// Fill the polygon of the `points` by the triangles from the first point, so it must be convex.
+pub(crate) fn paint_polygon(points: &[Point<Pixels>], color: Hsla, cx: &mut WindowContext) {
+    let Some((start, rest)) = points.split_first() else {
+        return;
+    };
+
+    let mut path = Path::new(*start);
+    for point in rest {
+        path.line_to(*point);
+    }
+    path.line_to(*start);
+    cx.paint_path(path, color);
+}
+
+/// Paint the straight line from `start` to `end` as a thin quad.
+pub(crate) fn paint_line(
+    start: Point<Pixels>,
+    end: Point<Pixels>,
+    width: Pixels,
+    color: Hsla,
+    cx: &mut WindowContext,
+) {
+    let dx = (end.x - start.x).0;
+    let dy = (end.y - start.y).0;
+    let len = (dx * dx + dy * dy).sqrt();
+    if len == 0. {
+        return;
+    }
+
+    // The offset perpendicular to the line, by the half of the width.
+    let half = width.0 / 2.;
+    let offset = point(px(-dy / len * half), px(dx / len * half));
+    paint_polygon(
+        &[start + offset, end + offset, end - offset, start - offset],
+        color,
+        cx,
+    );
+}
+
+/// Returns the point in the `bounds` by the ratio of the width and height, from the top-left.
+pub(crate) fn point_in(bounds: &Bounds<Pixels>, x: f32, y: f32) -> Point<Pixels> {
+    point(
+        bounds.origin.x + bounds.size.width * x,
+        bounds.origin.y + bounds.size.height * y,
+    )
+}
+
+/// The legend of the chart, a color swatch and the label of each series or slice.
+#[derive(IntoElement)]
+pub struct Legend {
+    items: Vec<(Hsla, SharedString)>,
+}
+
+impl Legend {
+    pub fn new(items: impl IntoIterator<Item = (Hsla, SharedString)>) -> Self {
+        Self {
+            items: items.into_iter().collect(),
+        }
+    }
+}
+
+impl RenderOnce for Legend {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        h_flex()
+            .flex_wrap()
+            .justify_center()
+            .gap_x_4()
+            .gap_y_1()
+            .text_xs()
+            .text_color(cx.theme().tokens.on_surface_muted)
+            .children(self.items.into_iter().map(|(color, label)| {
+                h_flex()
+                    .gap_1()
+                    .child(div().size(px(10.)).rounded_sm().bg(color))
+                    .child(label)
+            }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{format_value, nice_max};
+
+    #[test]
+    fn test_nice_max() {
+        assert_eq!(nice_max(0.), 1.);
+        assert_eq!(nice_max(-5.), 1.);
+        assert_eq!(nice_max(1.), 1.);
+        assert_eq!(nice_max(7.), 10.);
+        assert_eq!(nice_max(18.), 20.);
+        assert_eq!(nice_max(21.), 25.);
+        assert_eq!(nice_max(420.), 500.);
+        assert_eq!(nice_max(0.3), 0.5);
+    }
+
+    #[test]
+    fn test_format_value() {
+        assert_eq!(format_value(0.), "0");
+        assert_eq!(format_value(25.), "25");
+        assert_eq!(format_value(2.5), "2.5");
+        assert_eq!(format_value(0.126), "0.13");
+        assert_eq!(format_value(123.45), "123");
+    }
+}
@@ -0,0 +1,241 @@
+use std::f32::consts::{FRAC_PI_2, TAU};
+
+use gpui::{
+    canvas, div, point, prelude::FluentBuilder as _, px, Bounds, ElementId, Hsla,
+    InteractiveElement as _, IntoElement, ParentElement as _, Pixels, Point, RenderOnce,
+    SharedString, StatefulInteractiveElement as _, Styled as _, WindowContext,
+};
+
+use crate::{theme::ActiveTheme as _, tooltip::Tooltip, v_flex, StyledExt as _};
+
+use super::{chart_color, format_value, paint_polygon, Legend};
+
+/// The max angle of a step of the arc, the arcs are drawn by the straight lines.
+const ARC_STEP: f32 = TAU / 180.;
+/// The slices smaller than this ratio have no percentage label.
+const MIN_LABEL_RATIO: f64 = 0.04;
+
+/// A part of the [`PieChart`].
+#[derive(Debug, Clone)]
+pub struct PieSlice {
+    label: SharedString,
+    value: f64,
+    color: Option<Hsla>,
+}
+
+impl PieSlice {
+    pub fn new(label: impl Into<SharedString>, value: f64) -> Self {
+        Self {
+            label: label.into(),
+            value,
+            color: None,
+        }
+    }
+
+    /// Set the color of the slice, default is the [`chart_color`] by the index of the slice.
+    pub fn color(mut self, color: impl Into<Hsla>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+}
+
+/// A pie chart, or a donut chart with the [`PieChart::donut`].
+///
+/// The percentage of the slice is shown on it, and the value is shown in the tooltip on hover.
+#[derive(IntoElement)]
+pub struct PieChart {
+    id: ElementId,
+    slices: Vec<PieSlice>,
+    size: Pixels,
+    inner_radius: f32,
+    legend: bool,
+}
+
+impl PieChart {
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            slices: Vec::new(),
+            size: px(200.),
+            inner_radius: 0.,
+            legend: true,
+        }
+    }
+
+    /// Add a slice, the slices are drawn clockwise from the top.
+    pub fn slice(mut self, slice: PieSlice) -> Self {
+        self.slices.push(slice);
+        self
+    }
+
+    /// Add the slices.
+    pub fn slices(mut self, slices: impl IntoIterator<Item = PieSlice>) -> Self {
+        self.slices.extend(slices);
+        self
+    }
+
+    /// Set the diameter of the pie, default is 200px.
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = size.into();
+        self
+    }
+
+    /// Draw a donut, the `inner_radius` is the ratio of the radius of the hole, range of 0.0..1.0
+    pub fn donut(mut self, inner_radius: f32) -> Self {
+        self.inner_radius = inner_radius.clamp(0., 0.9);
+        self
+    }
+
+    /// Show the legend of the slices below the chart, default is true.
+    pub fn legend(mut self, legend: bool) -> Self {
+        self.legend = legend;
+        self
+    }
+}
+
+/// Returns the point on the circle, the angle is clockwise from the right.
+fn point_on_circle(center: Point<Pixels>, radius: f32, angle: f32) -> Point<Pixels> {
+    center + point(px(radius * angle.cos()), px(radius * angle.sin()))
+}
+
+/// Paint a slice from the `start` to the `end` angle, the `inner` and `outer` are the radius.
+fn paint_slice(
+    bounds: Bounds<Pixels>,
+    start: f32,
+    end: f32,
+    inner: f32,
+    outer: f32,
+    color: Hsla,
+    cx: &mut WindowContext,
+) {
+    let center = bounds.center();
+    let steps = ((end - start) / ARC_STEP).ceil().max(1.) as usize;
+    let angles = (0..=steps)
+        .map(|ix| start + (end - start) * ix as f32 / steps as f32)
+        .collect::<Vec<_>>();
+
+    if inner == 0. {
+        // A fan of the triangles from the center.
+        let mut points = vec![center];
+        points.extend(
+            angles
+                .iter()
+                .map(|angle| point_on_circle(center, outer, *angle)),
+        );
+        paint_polygon(&points, color, cx);
+    } else {
+        // The ring is not convex, paint it by the quads of the steps.
+        for pair in angles.windows(2) {
+            paint_polygon(
+                &[
+                    point_on_circle(center, inner, pair[0]),
+                    point_on_circle(center, outer, pair[0]),
+                    point_on_circle(center, outer, pair[1]),
+                    point_on_circle(center, inner, pair[1]),
+                ],
+                color,
+                cx,
+            );
+        }
+    }
+}
+
+impl RenderOnce for PieChart {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let total: f64 = self.slices.iter().map(|slice| slice.value.max(0.)).sum();
+        let radius = self.size.0 / 2.;
+        let inner_ratio = self.inner_radius;
+
+        // The start and end angle of the slices, from the top.
+        let mut angle = -FRAC_PI_2;
+        let arcs = self
+            .slices
+            .iter()
+            .enumerate()
+            .map(|(ix, slice)| {
+                let ratio = if total > 0. {
+                    slice.value.max(0.) / total
+                } else {
+                    0.
+                };
+                let start = angle;
+                angle += ratio as f32 * TAU;
+                let color = slice.color.unwrap_or_else(|| chart_color(ix, cx));
+                (start, angle, ratio, color)
+            })
+            .collect::<Vec<_>>();
+
+        let legend = Legend::new(self.slices.iter().zip(arcs.iter()).map(
+            |(slice, (_, _, ratio, color))| {
+                let label = format!("{} {:.0}%", slice.label, ratio * 100.);
+                (*color, label.into())
+            },
+        ));
+
+        // The percentage labels at the middle of the slices.
+        let label_radius = radius * (1. + inner_ratio) / 2.;
+        let labels = self
+            .slices
+            .iter()
+            .zip(arcs.iter())
+            .enumerate()
+            .filter(|(_, (_, (_, _, ratio, _)))| *ratio >= MIN_LABEL_RATIO)
+            .map(|(ix, (slice, (start, end, ratio, _)))| {
+                let center = point(px(radius), px(radius));
+                let position = point_on_circle(center, label_radius, (start + end) / 2.);
+                let label = slice.label.clone();
+                let meta = format_value(slice.value);
+
+                div()
+                    .id(("slice", ix))
+                    .absolute()
+                    .left(position.x)
+                    .top(position.y)
+                    .w(px(40.))
+                    .ml(px(-20.))
+                    .mt(px(-8.))
+                    .text_center()
+                    .text_xs()
+                    .font_semibold()
+                    .text_color(crate::white())
+                    .child(format!("{:.0}%", ratio * 100.))
+                    .tooltip(move |cx| Tooltip::with_meta(label.clone(), None, meta.clone(), cx))
+            })
+            .collect::<Vec<_>>();
+
+        let empty_color = cx.theme().tokens.surface_muted;
+        let plot = canvas(
+            move |_, _| (),
+            move |bounds, _, cx| {
+                let inner = radius * inner_ratio;
+                if total <= 0. {
+                    paint_slice(bounds, 0., TAU, inner, radius, empty_color, cx);
+                    return;
+                }
+
+                for (start, end, _, color) in arcs {
+                    if end > start {
+                        paint_slice(bounds, start, end, inner, radius, color, cx);
+                    }
+                }
+            },
+        )
+        .absolute()
+        .size_full();
+
+        v_flex()
+            .id(self.id)
+            .items_center()
+            .gap_3()
+            .child(
+                div()
+                    .relative()
+                    .size(self.size)
+                    .child(plot)
+                    .children(labels),
+            )
+            .when(self.legend && !self.slices.is_empty(), |this| {
+                this.child(legend)
+            })
+    }
+}
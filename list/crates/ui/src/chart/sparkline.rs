@@ -0,0 +1,130 @@
+use gpui::{
+    canvas, div, px, Hsla, IntoElement, ParentElement as _, Pixels, RenderOnce, Styled as _,
+    WindowContext,
+};
+
+use crate::theme::Colorize as _;
+
+use super::{chart_color, paint_line, paint_polygon, point_in};
+
+/// A tiny line chart without the axes, the line is scaled from the min to the max of the values.
+///
+/// It's light enough to render in the list items and table cells.
+#[derive(IntoElement)]
+pub struct Sparkline {
+    values: Vec<f32>,
+    color: Option<Hsla>,
+    area: bool,
+    width: Pixels,
+    height: Pixels,
+}
+
+impl Sparkline {
+    pub fn new(values: impl IntoIterator<Item = f32>) -> Self {
+        Self {
+            values: values.into_iter().collect(),
+            color: None,
+            area: false,
+            width: px(80.),
+            height: px(20.),
+        }
+    }
+
+    /// Set the color of the line, default is the first [`super::chart_color`].
+    pub fn color(mut self, color: impl Into<Hsla>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Fill the area below the line, default is false.
+    pub fn area(mut self, area: bool) -> Self {
+        self.area = area;
+        self
+    }
+
+    /// Set the size, default is 80x20px.
+    pub fn size(mut self, width: impl Into<Pixels>, height: impl Into<Pixels>) -> Self {
+        self.width = width.into();
+        self.height = height.into();
+        self
+    }
+}
+
+/// Returns the ratio of the y position of the values from the top, the max is 0.0 and the min is 1.0.
+///
+/// The values are in the middle if they are all the same.
+fn y_ratios(values: &[f32]) -> Vec<f32> {
+    let values = values
+        .iter()
+        .map(|value| if value.is_finite() { *value } else { 0. })
+        .collect::<Vec<_>>();
+    let min = values.iter().copied().fold(f32::INFINITY, f32::min);
+    let max = values.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+
+    values
+        .iter()
+        .map(|value| {
+            if max > min {
+                1. - (value - min) / (max - min)
+            } else {
+                0.5
+            }
+        })
+        .collect()
+}
+
+impl RenderOnce for Sparkline {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let color = self.color.unwrap_or_else(|| chart_color(0, cx));
+        let area = self.area;
+        let ratios = y_ratios(&self.values);
+
+        div().flex_none().w(self.width).h(self.height).child(
+            canvas(
+                move |_, _| (),
+                move |bounds, _, cx| {
+                    // Keep the line inside the bounds by the half of the line width.
+                    let bounds = bounds.dilate(px(-1.));
+                    let steps = ratios.len().saturating_sub(1).max(1) as f32;
+
+                    for (ix, pair) in ratios.windows(2).enumerate() {
+                        let x0 = ix as f32 / steps;
+                        let x1 = (ix + 1) as f32 / steps;
+                        let start = point_in(&bounds, x0, pair[0]);
+                        let end = point_in(&bounds, x1, pair[1]);
+
+                        if area {
+                            paint_polygon(
+                                &[
+                                    start,
+                                    end,
+                                    point_in(&bounds, x1, 1.),
+                                    point_in(&bounds, x0, 1.),
+                                ],
+                                color.opacity(0.2),
+                                cx,
+                            );
+                        }
+                        paint_line(start, end, px(1.5), color, cx);
+                    }
+                },
+            )
+            .size_full(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::y_ratios;
+
+    #[test]
+    fn test_y_ratios() {
+        assert_eq!(y_ratios(&[]), Vec::<f32>::new());
+        assert_eq!(y_ratios(&[3.]), vec![0.5]);
+        assert_eq!(y_ratios(&[2., 2., 2.]), vec![0.5, 0.5, 0.5]);
+        assert_eq!(y_ratios(&[0., 5., 10.]), vec![1., 0.5, 0.]);
+        assert_eq!(y_ratios(&[-10., 10.]), vec![1., 0.]);
+        assert_eq!(y_ratios(&[f32::NAN, 4.]), vec![1., 0.]);
+    }
+}
@@ -1,19 +1,21 @@
 use gpui::{
-    div, prelude::FluentBuilder as _, relative, svg, ElementId, InteractiveElement, IntoElement,
+    div, prelude::FluentBuilder as _, relative, ElementId, InteractiveElement, IntoElement,
     ParentElement, RenderOnce, SharedString, StatefulInteractiveElement as _, Styled as _,
     WindowContext,
 };
 
 use crate::{
     h_flex,
+    inspector::InspectorExt as _,
     theme::{ActiveTheme, Colorize as _},
-    v_flex, Disableable, IconName, Selectable,
+    v_flex, Disableable, Icon, IconName, Selectable,
 };
 
 #[derive(IntoElement)]
 pub struct Checkbox {
     id: ElementId,
     checked: bool,
+    indeterminate: bool,
     disabled: bool,
     label: Option<SharedString>,
     on_click: Option<Box<dyn Fn(&bool, &mut WindowContext) + 'static>>,
@@ -24,6 +26,7 @@ impl Checkbox {
         Self {
             id: id.into(),
             checked: false,
+            indeterminate: false,
             disabled: false,
             label: None,
             on_click: None,
@@ -40,6 +43,14 @@ impl Checkbox {
         self
     }
 
+    /// Show a dash instead of the check mark, e.g.: some of the items are checked by a "select all" checkbox.
+    ///
+    /// Clicking an indeterminate checkbox checks it.
+    pub fn indeterminate(mut self, indeterminate: bool) -> Self {
+        self.indeterminate = indeterminate;
+        self
+    }
+
     pub fn on_click(mut self, handler: impl Fn(&bool, &mut WindowContext) + 'static) -> Self {
         self.on_click = Some(Box::new(handler));
         self
@@ -67,11 +78,11 @@ impl RenderOnce for Checkbox {
 
         let (color, icon_color) = if self.disabled {
             (
-                theme.primary.opacity(0.5),
-                theme.primary_foreground.opacity(0.5),
+                theme.tokens.accent.opacity(0.5),
+                theme.tokens.on_accent.opacity(0.5),
             )
         } else {
-            (theme.primary, theme.primary_foreground)
+            (theme.tokens.accent, theme.tokens.on_accent)
         };
 
         h_flex()
@@ -87,7 +98,7 @@ impl RenderOnce for Checkbox {
                     .rounded_sm()
                     .size_4()
                     .flex_shrink_0()
-                    .map(|this| match self.checked {
+                    .map(|this| match self.checked || self.indeterminate {
                         false => this.bg(theme.transparent),
                         _ => this.bg(color),
                     })
@@ -96,24 +107,29 @@ impl RenderOnce for Checkbox {
                             return this;
                         }
 
-                        this.border_color(theme.primary.divide(0.9))
+                        this.border_color(theme.tokens.accent.divide(0.9))
                     })
                     .child(
-                        svg()
+                        Icon::empty()
                             .absolute()
                             .top_px()
                             .left_px()
                             .size_3()
                             .text_color(icon_color)
-                            .map(|this| match self.checked {
-                                true => this.path(IconName::Check.path()),
-                                _ => this,
+                            .map(|this| {
+                                if self.indeterminate {
+                                    this.path(IconName::Minus.path())
+                                } else if self.checked {
+                                    this.path(IconName::Check.path())
+                                } else {
+                                    this
+                                }
                             }),
                     ),
             )
             .map(|this| {
                 if let Some(label) = self.label {
-                    this.text_color(cx.theme().foreground).child(
+                    this.text_color(cx.theme().tokens.on_surface).child(
                         div()
                             .w_full()
                             .overflow_hidden()
@@ -134,5 +150,6 @@ impl RenderOnce for Checkbox {
                     })
                 },
             )
+            .inspect("Checkbox", cx)
     }
 }
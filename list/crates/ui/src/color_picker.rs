@@ -1,23 +1,29 @@
 use gpui::{
-    anchored, canvas, deferred, div, prelude::FluentBuilder as _, px, relative, AnchorCorner,
-    AppContext, Bounds, ElementId, EventEmitter, FocusHandle, FocusableView, Hsla,
-    InteractiveElement as _, IntoElement, KeyBinding, MouseButton, ParentElement, Pixels, Point,
-    Render, SharedString, StatefulInteractiveElement as _, Styled, View, ViewContext,
-    VisualContext,
+    anchored, canvas, deferred, div, prelude::FluentBuilder as _, px, relative, rgb, AnchorCorner,
+    AppContext, Bounds, ElementId, EventEmitter, FocusHandle, FocusableView, Global, Hsla,
+    InteractiveElement as _, IntoElement, KeyBinding, KeyDownEvent, MouseButton, ParentElement,
+    Pixels, Point, Render, SharedString, StatefulInteractiveElement as _, Styled, View,
+    ViewContext, VisualContext,
 };
+use rust_i18n::t;
 
 use crate::{
+    button::Button,
     divider::Divider,
     h_flex,
     input::{InputEvent, TextInput},
     popover::Escape,
     theme::{ActiveTheme as _, Colorize},
     tooltip::Tooltip,
-    v_flex, ColorExt as _, Sizable, Size, StyleSized,
+    v_flex, ColorExt as _, Selectable as _, Sizable, Size, StyleSized, StyledExt as _,
+    TabStop as _,
 };
 
 const KEY_CONTEXT: &'static str = "ColorPicker";
 
+/// The max number of the [`RecentColors`], that fills a row of the color picker.
+const MAX_RECENT_COLORS: usize = 11;
+
 pub fn init(cx: &mut AppContext) {
     cx.bind_keys([KeyBinding::new("escape", Escape, Some(KEY_CONTEXT))])
 }
@@ -27,6 +33,86 @@ pub enum ColorPickerEvent {
     Change(Option<Hsla>),
 }
 
+/// The recently picked colors of all the color pickers, the latest is the first.
+///
+/// Set this global on startup to restore the persisted colors, and observe it to save them.
+#[derive(Debug, Clone, Default)]
+pub struct RecentColors(pub Vec<Hsla>);
+
+impl Global for RecentColors {}
+
+impl RecentColors {
+    /// Add the color to the front, the same color (by the hex string) is moved instead of duplicated.
+    pub fn push(&mut self, color: Hsla) {
+        let hex = color.to_hex_string();
+        self.0.retain(|c| c.to_hex_string() != hex);
+        self.0.insert(0, color);
+        self.0.truncate(MAX_RECENT_COLORS);
+    }
+}
+
+/// A named set of colors, shown as rows in the color picker.
+#[derive(Debug, Clone)]
+pub struct ColorPreset {
+    pub name: SharedString,
+    pub colors: Vec<Vec<Hsla>>,
+}
+
+impl ColorPreset {
+    /// Create a custom preset, each of the `colors` is a row.
+    pub fn new(name: impl Into<SharedString>, colors: Vec<Vec<Hsla>>) -> Self {
+        Self {
+            name: name.into(),
+            colors,
+        }
+    }
+
+    /// The Tailwind CSS colors, from dark to light.
+    pub fn tailwind() -> Self {
+        let colors = color_palettes()
+            .into_iter()
+            .map(|row| row.into_iter().rev().collect())
+            .collect();
+        Self::new("Tailwind", colors)
+    }
+
+    /// The Material Design colors, the shades 900, 700, 500, 300, 100.
+    pub fn material() -> Self {
+        let colors = [
+            [0xB71C1C, 0xD32F2F, 0xF44336, 0xE57373, 0xFFCDD2], // red
+            [0x880E4F, 0xC2185B, 0xE91E63, 0xF06292, 0xF8BBD0], // pink
+            [0x4A148C, 0x7B1FA2, 0x9C27B0, 0xBA68C8, 0xE1BEE7], // purple
+            [0x1A237E, 0x303F9F, 0x3F51B5, 0x7986CB, 0xC5CAE9], // indigo
+            [0x0D47A1, 0x1976D2, 0x2196F3, 0x64B5F6, 0xBBDEFB], // blue
+            [0x006064, 0x0097A7, 0x00BCD4, 0x4DD0E1, 0xB2EBF2], // cyan
+            [0x004D40, 0x00796B, 0x009688, 0x4DB6AC, 0xB2DFDB], // teal
+            [0x1B5E20, 0x388E3C, 0x4CAF50, 0x81C784, 0xC8E6C9], // green
+            [0xFF6F00, 0xFFA000, 0xFFC107, 0xFFD54F, 0xFFECB3], // amber
+            [0xE65100, 0xF57C00, 0xFF9800, 0xFFB74D, 0xFFE0B2], // orange
+            [0x212121, 0x616161, 0x9E9E9E, 0xE0E0E0, 0xF5F5F5], // grey
+        ]
+        .iter()
+        .map(|row| row.iter().map(|hex| rgb(*hex).into()).collect())
+        .collect();
+        Self::new("Material", colors)
+    }
+}
+
+/// Parse the hex color typed by the user, the `#` is optional, and the 3 digits shorthand is supported.
+fn parse_color_input(text: &str) -> Option<Hsla> {
+    let hex = text.trim().trim_start_matches('#');
+    if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    let hex = if hex.len() == 3 {
+        hex.chars().flat_map(|c| [c, c]).collect::<String>()
+    } else {
+        hex.to_string()
+    };
+    Hsla::parse_hex_string(&hex).ok()
+}
+
 fn color_palettes() -> Vec<Vec<Hsla>> {
     use crate::colors::DEFAULT_COLOR;
     use itertools::Itertools as _;
@@ -65,6 +151,10 @@ pub struct ColorPicker {
     size: Size,
     anchor: AnchorCorner,
     color_input: View<TextInput>,
+    /// Whether the text of the `color_input` is not a valid hex color.
+    invalid_input: bool,
+    presets: Vec<ColorPreset>,
+    active_preset: usize,
 
     open: bool,
     bounds: Bounds<Pixels>,
@@ -76,14 +166,17 @@ impl ColorPicker {
 
         cx.subscribe(&color_input, |this, _, ev: &InputEvent, cx| match ev {
             InputEvent::Change(value) => {
-                if let Ok(color) = Hsla::parse_hex_string(value) {
+                let color = parse_color_input(value);
+                this.invalid_input = color.is_none() && !value.trim().is_empty();
+                if let Some(color) = color {
                     this.value = Some(color);
                     this.hovered_color = Some(color);
                 }
+                cx.notify();
             }
             InputEvent::PressEnter => {
                 let val = this.color_input.read(cx).text();
-                if let Ok(color) = Hsla::parse_hex_string(&val) {
+                if let Some(color) = parse_color_input(&val) {
                     this.open = false;
                     this.update_value(Some(color), true, cx);
                 }
@@ -114,6 +207,9 @@ impl ColorPicker {
             label: None,
             anchor: AnchorCorner::TopLeft,
             color_input,
+            invalid_input: false,
+            presets: vec![ColorPreset::tailwind(), ColorPreset::material()],
+            active_preset: 0,
             open: false,
             bounds: Bounds::default(),
         }
@@ -128,6 +224,15 @@ impl ColorPicker {
         self
     }
 
+    /// Set the presets to pick the colors from, default is Tailwind and Material.
+    ///
+    /// Use [`ColorPreset::new`] to add a custom preset.
+    pub fn presets(mut self, presets: Vec<ColorPreset>) -> Self {
+        self.presets = presets;
+        self.active_preset = 0;
+        self
+    }
+
     /// Set current color value.
     pub fn set_value(&mut self, value: Hsla, cx: &mut ViewContext<Self>) {
         self.update_value(Some(value), false, cx)
@@ -165,9 +270,23 @@ impl ColorPicker {
         cx.notify();
     }
 
+    /// Toggle the picker by `enter` or `space` when focused.
+    fn on_key_down(&mut self, event: &KeyDownEvent, cx: &mut ViewContext<Self>) {
+        if !self.focus_handle.is_focused(cx) {
+            return;
+        }
+
+        if matches!(event.keystroke.key.as_str(), "enter" | "space") {
+            self.open = !self.open;
+            cx.stop_propagation();
+            cx.notify();
+        }
+    }
+
     fn update_value(&mut self, value: Option<Hsla>, emit: bool, cx: &mut ViewContext<Self>) {
         self.value = value;
         self.hovered_color = value;
+        self.invalid_input = false;
         self.color_input.update(cx, |view, cx| {
             if let Some(value) = value {
                 view.set_text(value.to_hex_string(), cx);
@@ -176,6 +295,9 @@ impl ColorPicker {
             }
         });
         if emit {
+            if let Some(color) = value {
+                cx.default_global::<RecentColors>().push(color);
+            }
             cx.emit(ColorPickerEvent::Change(value));
         }
         cx.notify();
@@ -218,6 +340,11 @@ impl ColorPicker {
     }
 
     fn render_colors(&self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let recent_colors = cx
+            .try_global::<RecentColors>()
+            .map(|recent| recent.0.clone())
+            .unwrap_or_default();
+
         v_flex()
             .gap_3()
             .child(
@@ -227,35 +354,75 @@ impl ColorPicker {
                         .map(|color| self.render_item(*color, true, cx)),
                 ),
             )
-            .child(Divider::horizontal())
-            .child(
-                v_flex()
-                    .gap_1()
-                    .children(color_palettes().iter().map(|sub_colors| {
-                        h_flex().gap_1().children(
-                            sub_colors
-                                .iter()
-                                .rev()
-                                .map(|color| self.render_item(*color, true, cx)),
-                        )
-                    })),
-            )
-            .when_some(self.hovered_color, |this, hovered_color| {
-                this.child(Divider::horizontal()).child(
-                    h_flex()
-                        .gap_2()
-                        .items_center()
+            .when(!recent_colors.is_empty(), |this| {
+                this.child(
+                    v_flex()
+                        .gap_1()
                         .child(
                             div()
-                                .bg(hovered_color)
-                                .border_1()
-                                .border_color(hovered_color.darken(0.2))
-                                .size_5()
-                                .rounded(px(cx.theme().radius)),
+                                .text_xs()
+                                .text_color(cx.theme().tokens.on_surface_muted)
+                                .child(t!("ColorPicker.Recent").to_string()),
                         )
-                        .child(self.color_input.clone()),
+                        .child(
+                            h_flex().gap_1().children(
+                                recent_colors
+                                    .iter()
+                                    .map(|color| self.render_item(*color, true, cx)),
+                            ),
+                        ),
+                )
+            })
+            .child(Divider::horizontal())
+            .when(self.presets.len() > 1, |this| {
+                this.child(
+                    h_flex()
+                        .gap_1()
+                        .children(self.presets.iter().enumerate().map(|(ix, preset)| {
+                            Button::new(("color-preset", ix), cx)
+                                .xsmall()
+                                .ghost()
+                                .label(preset.name.clone())
+                                .selected(ix == self.active_preset)
+                                .on_click(cx.listener(move |view, _, cx| {
+                                    view.active_preset = ix;
+                                    cx.notify();
+                                }))
+                        })),
                 )
             })
+            .when_some(self.presets.get(self.active_preset), |this, preset| {
+                this.child(v_flex().gap_1().children(preset.colors.iter().map(|row| {
+                    h_flex()
+                        .gap_1()
+                        .children(row.iter().map(|color| self.render_item(*color, true, cx)))
+                })))
+            })
+            .when_some(self.hovered_color, |this, hovered_color| {
+                this.child(Divider::horizontal())
+                    .child(
+                        h_flex()
+                            .gap_2()
+                            .items_center()
+                            .child(
+                                div()
+                                    .bg(hovered_color)
+                                    .border_1()
+                                    .border_color(hovered_color.darken(0.2))
+                                    .size_5()
+                                    .rounded(px(cx.theme().radius)),
+                            )
+                            .child(self.color_input.clone()),
+                    )
+                    .when(self.invalid_input, |this| {
+                        this.child(
+                            div()
+                                .text_xs()
+                                .text_color(cx.theme().tokens.danger)
+                                .child(t!("ColorPicker.Invalid hex color").to_string()),
+                        )
+                    })
+            })
     }
 
     fn resolved_corner(&self, bounds: Bounds<Pixels>) -> Point<Pixels> {
@@ -292,12 +459,14 @@ impl Render for ColorPicker {
         .into();
 
         let view = cx.view().clone();
+        let focused = self.focus_handle.is_focused(cx);
 
         div()
             .id(self.id.clone())
             .key_context(KEY_CONTEXT)
-            .track_focus(&self.focus_handle)
+            .tab_stop(&self.focus_handle, cx)
             .on_action(cx.listener(Self::on_escape))
+            .on_key_down(cx.listener(Self::on_key_down))
             .child(
                 h_flex()
                     .id("color-picker-input")
@@ -309,18 +478,26 @@ impl Render for ColorPicker {
                     .child(
                         div()
                             .id("color-picker-square")
-                            .bg(cx.theme().background)
+                            .bg(cx.theme().tokens.surface)
                             .border_1()
                             .border_color(cx.theme().input)
                             .rounded(px(cx.theme().radius))
-                            .bg(cx.theme().background)
+                            .bg(cx.theme().tokens.surface)
                             .shadow_sm()
                             .overflow_hidden()
                             .size_with(self.size)
                             .when_some(self.value, |this, value| {
                                 this.bg(value).border_color(value.darken(0.3))
                             })
-                            .tooltip(move |cx| Tooltip::new(display_title.clone(), cx)),
+                            .focus_ring(focused && !self.open, cx)
+                            .tooltip(move |cx| {
+                                Tooltip::with_meta(
+                                    display_title.clone(),
+                                    None,
+                                    t!("ColorPicker.Click to pick a color").to_string(),
+                                    cx,
+                                )
+                            }),
                     )
                     .when_some(self.label.clone(), |this, label| this.child(label))
                     .on_click(cx.listener(Self::toggle_picker))
@@ -357,10 +534,10 @@ impl Render for ColorPicker {
                                     .rounded_lg()
                                     .p_3()
                                     .border_1()
-                                    .border_color(cx.theme().border)
+                                    .border_color(cx.theme().tokens.outline)
                                     .shadow_lg()
                                     .rounded_lg()
-                                    .bg(cx.theme().background)
+                                    .bg(cx.theme().tokens.surface)
                                     .on_mouse_up_out(
                                         MouseButton::Left,
                                         cx.listener(|view, _, cx| view.on_escape(&Escape, cx)),
@@ -373,3 +550,45 @@ impl Render for ColorPicker {
             })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use gpui::{rgb, Hsla};
+
+    use super::*;
+
+    #[test]
+    fn test_parse_color_input() {
+        let color: Hsla = rgb(0xf8fafc).into();
+        assert_eq!(parse_color_input("#F8FAFC"), Some(color));
+        assert_eq!(parse_color_input("f8fafc"), Some(color));
+        assert_eq!(parse_color_input(" #f8fafc "), Some(color));
+
+        let color: Hsla = rgb(0xffaa00).into();
+        assert_eq!(parse_color_input("#fa0"), Some(color));
+
+        assert_eq!(parse_color_input(""), None);
+        assert_eq!(parse_color_input("#f8fa"), None);
+        assert_eq!(parse_color_input("#ggaa00"), None);
+        assert_eq!(parse_color_input("#ffé"), None);
+    }
+
+    #[test]
+    fn test_recent_colors_push() {
+        let red: Hsla = rgb(0xff0000).into();
+        let green: Hsla = rgb(0x00ff00).into();
+
+        let mut recent = RecentColors::default();
+        recent.push(red);
+        recent.push(green);
+        assert_eq!(recent.0, vec![green, red]);
+
+        recent.push(red);
+        assert_eq!(recent.0, vec![red, green]);
+
+        for i in 0..MAX_RECENT_COLORS * 2 {
+            recent.push(rgb(i as u32).into());
+        }
+        assert_eq!(recent.0.len(), MAX_RECENT_COLORS);
+    }
+}
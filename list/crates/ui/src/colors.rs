@@ -6,9 +6,16 @@ use serde::{de::Error, Deserialize, Deserializer};
 use crate::theme::hsl;
 use anyhow::Result;
 
-pub(crate) trait ColorExt {
+/// Convert the color from and to the hex string, e.g.: `#F8FAFC`, `#0413FCAA`.
+pub trait ColorExt {
     fn to_hex_string(&self) -> String;
     fn parse_hex_string(hex: &str) -> Result<Hsla>;
+    /// The relative luminance of the color in WCAG 2, from 0 for black to 1 for white, the alpha is ignored.
+    fn luminance(&self) -> f32;
+    /// The WCAG 2 contrast ratio between the two colors, from 1 to 21.
+    ///
+    /// The text should be at least 4.5 (AA) or 7 (AAA) to its background, 3 for the large text and the UI parts.
+    fn contrast_ratio(&self, other: Hsla) -> f32;
 }
 
 impl ColorExt for Hsla {
@@ -53,6 +60,24 @@ impl ColorExt for Hsla {
         let color: Hsla = v.into();
         Ok(color)
     }
+
+    fn luminance(&self) -> f32 {
+        fn channel(c: f32) -> f32 {
+            if c <= 0.03928 {
+                c / 12.92
+            } else {
+                ((c + 0.055) / 1.055).powf(2.4)
+            }
+        }
+
+        let rgb = self.to_rgb();
+        0.2126 * channel(rgb.r) + 0.7152 * channel(rgb.g) + 0.0722 * channel(rgb.b)
+    }
+
+    fn contrast_ratio(&self, other: Hsla) -> f32 {
+        let (a, b) = (self.luminance(), other.luminance());
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
+    }
 }
 
 pub(crate) static DEFAULT_COLOR: once_cell::sync::Lazy<ShadcnColors> =
@@ -277,4 +302,15 @@ mod tests {
         let color: Hsla = Hsla::parse_hex_string("#0413FCAA").unwrap();
         assert_eq!(color, rgba(0x0413fcaa).into());
     }
+
+    #[test]
+    fn test_contrast_ratio() {
+        assert!((black().contrast_ratio(white()) - 21.).abs() < 0.01);
+        assert!((white().contrast_ratio(black()) - 21.).abs() < 0.01);
+        assert!((red_500().contrast_ratio(red_500()) - 1.).abs() < 0.01);
+
+        // #767676 is the lightest gray that has 4.5:1 to white.
+        let gray: Hsla = rgb(0x767676).into();
+        assert!((gray.contrast_ratio(white()) - 4.54).abs() < 0.01);
+    }
 }
@@ -0,0 +1,215 @@
+use std::{cmp::Ordering, rc::Rc};
+
+use gpui::{AnyElement, IntoElement, Pixels, SharedString, ViewContext};
+
+use crate::table::{ColSort, Table, TableDelegate};
+
+type RenderCell<T> =
+    Rc<dyn Fn(usize, &T, &mut ViewContext<Table<ColumnTableDelegate<T>>>) -> AnyElement>;
+
+/// A column of a [`ColumnTableDelegate`], the cells are rendered by a closure of the row,
+/// so a cell can be any element, e.g.: a badge, a sparkline, a button or a progress bar.
+pub struct TableColumn<T: 'static> {
+    key: SharedString,
+    name: SharedString,
+    width: Option<Pixels>,
+    render: RenderCell<T>,
+    /// The text of the [`TableColumn::text`] column, to jump to the row by typing.
+    text: Option<Rc<dyn Fn(&T) -> SharedString>>,
+    compare: Option<Rc<dyn Fn(&T, &T) -> Ordering>>,
+}
+
+impl<T: 'static> TableColumn<T> {
+    /// Create a column, the `render` is called with the index and the data of the row for each cell.
+    ///
+    /// The `key` is the unique key of the column to save the column layout, see [`Table::layout_id`].
+    pub fn new<F, E>(key: impl Into<SharedString>, name: impl Into<SharedString>, render: F) -> Self
+    where
+        F: Fn(usize, &T, &mut ViewContext<Table<ColumnTableDelegate<T>>>) -> E + 'static,
+        E: IntoElement,
+    {
+        Self {
+            key: key.into(),
+            name: name.into(),
+            width: None,
+            render: Rc::new(move |row_ix, row, cx| render(row_ix, row, cx).into_any_element()),
+            text: None,
+            compare: None,
+        }
+    }
+
+    /// Create a column of the text of the row.
+    ///
+    /// The rows can be jumped to by typing the text of the first text column.
+    pub fn text(
+        key: impl Into<SharedString>,
+        name: impl Into<SharedString>,
+        text: impl Fn(&T) -> SharedString + 'static,
+    ) -> Self {
+        let text: Rc<dyn Fn(&T) -> SharedString> = Rc::new(text);
+        let mut this = Self::new(key, name, {
+            let text = text.clone();
+            move |_, row, _| text(row)
+        });
+        this.text = Some(text);
+        this
+    }
+
+    /// Set the width of the column, default is the auto width.
+    pub fn width(mut self, width: impl Into<Pixels>) -> Self {
+        self.width = Some(width.into());
+        self
+    }
+
+    /// Allow to sort the rows by the column with the `compare` of the ascending order.
+    pub fn sortable(mut self, compare: impl Fn(&T, &T) -> Ordering + 'static) -> Self {
+        self.compare = Some(Rc::new(compare));
+        self
+    }
+
+    pub fn key(&self) -> &SharedString {
+        &self.key
+    }
+
+    pub fn name(&self) -> &SharedString {
+        &self.name
+    }
+}
+
+/// A [`TableDelegate`] of the rows and the [`TableColumn`]s that render the cells.
+///
+/// The columns can be reordered by dragging the headers, and sorted if they are sortable.
+pub struct ColumnTableDelegate<T: 'static> {
+    columns: Vec<TableColumn<T>>,
+    rows: Vec<T>,
+}
+
+impl<T: 'static> ColumnTableDelegate<T> {
+    pub fn new(columns: Vec<TableColumn<T>>, rows: Vec<T>) -> Self {
+        Self { columns, rows }
+    }
+
+    pub fn columns(&self) -> &[TableColumn<T>] {
+        &self.columns
+    }
+
+    pub fn rows(&self) -> &[T] {
+        &self.rows
+    }
+
+    /// Returns the rows to update, notify the table after they are updated.
+    pub fn rows_mut(&mut self) -> &mut Vec<T> {
+        &mut self.rows
+    }
+
+    /// Sort the rows by the column, the [`ColSort::Default`] keeps the current order.
+    fn sort_rows(&mut self, col_ix: usize, sort: ColSort) {
+        let Some(compare) = self.columns.get(col_ix).and_then(|col| col.compare.clone()) else {
+            return;
+        };
+
+        match sort {
+            ColSort::Ascending => self.rows.sort_by(|a, b| compare(a, b)),
+            ColSort::Descending => self.rows.sort_by(|a, b| compare(b, a)),
+            ColSort::Default => {}
+        }
+    }
+}
+
+impl<T: 'static> TableDelegate for ColumnTableDelegate<T> {
+    fn cols_count(&self) -> usize {
+        self.columns.len()
+    }
+
+    fn rows_count(&self) -> usize {
+        self.rows.len()
+    }
+
+    fn col_name(&self, col_ix: usize) -> SharedString {
+        self.columns
+            .get(col_ix)
+            .map(|col| col.name.clone())
+            .unwrap_or_default()
+    }
+
+    fn col_key(&self, col_ix: usize) -> SharedString {
+        self.columns
+            .get(col_ix)
+            .map(|col| col.key.clone())
+            .unwrap_or_default()
+    }
+
+    fn col_width(&self, col_ix: usize) -> Option<Pixels> {
+        self.columns.get(col_ix).and_then(|col| col.width)
+    }
+
+    fn col_sort(&self, col_ix: usize) -> Option<ColSort> {
+        self.columns
+            .get(col_ix)
+            .and_then(|col| col.compare.as_ref())
+            .map(|_| ColSort::Default)
+    }
+
+    fn perform_sort(&mut self, col_ix: usize, sort: ColSort, _: &mut ViewContext<Table<Self>>) {
+        self.sort_rows(col_ix, sort);
+    }
+
+    fn render_td(
+        &self,
+        row_ix: usize,
+        col_ix: usize,
+        cx: &mut ViewContext<Table<Self>>,
+    ) -> impl IntoElement {
+        let render = self.columns.get(col_ix).map(|col| col.render.clone());
+        match (render, self.rows.get(row_ix)) {
+            (Some(render), Some(row)) => render(row_ix, row, cx),
+            _ => SharedString::default().into_any_element(),
+        }
+    }
+
+    fn type_ahead_text(&self, row_ix: usize) -> Option<SharedString> {
+        let text = self.columns.iter().find_map(|col| col.text.as_ref())?;
+        let row = self.rows.get(row_ix)?;
+        Some(text(row))
+    }
+
+    fn can_move_col(&self, _: usize) -> bool {
+        true
+    }
+
+    fn move_col(&mut self, col_ix: usize, to_ix: usize) {
+        let col = self.columns.remove(col_ix);
+        self.columns.insert(to_ix, col);
+    }
+
+    fn can_load_more(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ColumnTableDelegate, TableColumn};
+    use crate::table::ColSort;
+
+    #[test]
+    fn test_sort_rows() {
+        let mut delegate = ColumnTableDelegate::new(
+            vec![
+                TableColumn::text("name", "Name", |row: &(&str, u32)| row.0.into()),
+                TableColumn::text("age", "Age", |row: &(&str, u32)| row.1.to_string().into())
+                    .sortable(|a, b| a.1.cmp(&b.1)),
+            ],
+            vec![("b", 2), ("a", 3), ("c", 1)],
+        );
+
+        delegate.sort_rows(1, ColSort::Ascending);
+        assert_eq!(delegate.rows(), [("c", 1), ("b", 2), ("a", 3)]);
+        delegate.sort_rows(1, ColSort::Descending);
+        assert_eq!(delegate.rows(), [("a", 3), ("b", 2), ("c", 1)]);
+
+        // The column is not sortable.
+        delegate.sort_rows(0, ColSort::Ascending);
+        assert_eq!(delegate.rows(), [("a", 3), ("b", 2), ("c", 1)]);
+    }
+}
@@ -56,7 +56,7 @@ impl RenderOnce for Divider {
                         Axis::Vertical => this.v_flex().w_0().h_full().border_l_1(),
                         Axis::Horizontal => this.h_flex().h_0().w_full().border_b_1(),
                     })
-                    .border_color(cx.theme().border),
+                    .border_color(cx.theme().tokens.outline),
             )
             .when_some(self.label, |this, label| {
                 this.child(
@@ -65,8 +65,8 @@ impl RenderOnce for Divider {
                         .py_1()
                         .mx_auto()
                         .text_xs()
-                        .bg(cx.theme().background)
-                        .text_color(theme.muted_foreground)
+                        .bg(cx.theme().tokens.surface)
+                        .text_color(theme.tokens.on_surface_muted)
                         .child(label),
                 )
             })
@@ -0,0 +1,365 @@
+use std::sync::Arc;
+
+use gpui::{
+    div, point, px, size, AppContext, Bounds, DragMoveEvent, EntityId, InteractiveElement as _,
+    IntoElement, MouseButton, MouseDownEvent, ParentElement as _, Pixels, Point, Render,
+    StatefulInteractiveElement as _, Styled as _, Subscription, View, ViewContext,
+    VisualContext as _,
+};
+use rust_i18n::t;
+
+use crate::{button::Button, h_flex, theme::ActiveTheme as _, v_flex, IconName, Sizable as _};
+
+use super::{
+    DockArea, DockEvent, FloatingPanelState, Panel as _, PanelEvent, PanelView, TabPanel,
+    DROP_ZONE_SIZE,
+};
+
+const DEFAULT_WIDTH: Pixels = px(480.);
+const DEFAULT_HEIGHT: Pixels = px(320.);
+const MIN_WIDTH: Pixels = px(200.);
+const MIN_HEIGHT: Pixels = px(120.);
+const TITLE_BAR_HEIGHT: Pixels = px(28.);
+const RESIZE_HANDLE_SIZE: Pixels = px(12.);
+/// The offset of a new floating panel from the last one, to not cover it entirely.
+const CASCADE_OFFSET: Pixels = px(24.);
+
+/// A TabPanel floating above the docked layout, it's moved by the title bar and resized by the corner.
+pub(super) struct FloatingPanel {
+    tab_panel: View<TabPanel>,
+    /// The bounds relative to the dock area.
+    bounds: Bounds<Pixels>,
+    /// The mouse position and the bounds when it started to be moved or resized.
+    drag_start: Option<(Point<Pixels>, Bounds<Pixels>)>,
+    /// True if it's being moved or resized.
+    dragging: bool,
+    _subscriptions: Vec<Subscription>,
+}
+
+/// Move a [`FloatingPanel`] by dragging the title bar, or resize it by dragging the corner.
+#[derive(Clone, Render)]
+pub(super) struct DragFloatingPanel {
+    tab_panel: EntityId,
+    resize: bool,
+}
+
+impl DockArea {
+    /// Returns the TabPanels floating above the docked layout, the last one is on the top.
+    pub fn floating_tab_panels(&self) -> Vec<View<TabPanel>> {
+        self.floating_panels
+            .iter()
+            .map(|floating| floating.tab_panel.clone())
+            .collect()
+    }
+
+    /// Returns true if the TabPanel is floating above the docked layout.
+    pub fn is_floating(&self, tab_panel: &View<TabPanel>) -> bool {
+        self.floating_panels
+            .iter()
+            .any(|floating| &floating.tab_panel == tab_panel)
+    }
+
+    /// Float the panel in a new TabPanel above the docked layout, inside the window.
+    pub(super) fn float_panel_in_area(
+        &mut self,
+        panel: Arc<dyn PanelView>,
+        cx: &mut ViewContext<Self>,
+    ) {
+        let offset = DROP_ZONE_SIZE + CASCADE_OFFSET * self.floating_panels.len() as f32;
+        let bounds = Bounds::new(point(offset, offset), size(DEFAULT_WIDTH, DEFAULT_HEIGHT));
+
+        let dock_area = cx.view().downgrade();
+        let tab_panel = cx.new_view(|cx| TabPanel::new(None, dock_area, cx));
+        tab_panel.update(cx, |view, cx| view.add_panel(panel.clone(), cx));
+        self.add_floating_tab_panel(tab_panel, bounds, cx);
+
+        panel.focus_handle(cx).focus(cx);
+        cx.emit(DockEvent::LayoutChanged);
+        cx.notify();
+    }
+
+    /// Move the panel of a floating TabPanel back to the docked layout.
+    pub(super) fn dock_panel(&mut self, panel: Arc<dyn PanelView>, cx: &mut ViewContext<Self>) {
+        self.add_panel_to_first_tab_panel(panel.clone(), cx);
+        panel.focus_handle(cx).focus(cx);
+        cx.emit(DockEvent::LayoutChanged);
+        cx.notify();
+    }
+
+    /// Move all the panels of the floating TabPanel back to the docked layout.
+    fn dock_floating_tab_panel(&mut self, tab_panel: &View<TabPanel>, cx: &mut ViewContext<Self>) {
+        let panels = tab_panel.read(cx).panels().to_vec();
+        tab_panel.update(cx, |view, cx| {
+            for panel in &panels {
+                view.detach_panel(panel.clone(), cx);
+            }
+        });
+        for panel in panels {
+            self.add_panel_to_first_tab_panel(panel, cx);
+        }
+
+        self.remove_floating_tab_panel(tab_panel, cx);
+    }
+
+    fn add_floating_tab_panel(
+        &mut self,
+        tab_panel: View<TabPanel>,
+        bounds: Bounds<Pixels>,
+        cx: &mut ViewContext<Self>,
+    ) {
+        tab_panel.update(cx, |view, _| view.set_floating(true));
+        let _subscriptions = vec![
+            // Remove it once the last tab has been closed or moved out.
+            cx.observe(&tab_panel, |this, tab_panel, cx| {
+                if tab_panel.read(cx).panels().is_empty() {
+                    this.remove_floating_tab_panel(&tab_panel, cx);
+                }
+            }),
+            cx.subscribe(&tab_panel, |_, _, event: &PanelEvent, cx| match event {
+                PanelEvent::LayoutChanged | PanelEvent::Renamed(_) => {
+                    cx.emit(DockEvent::LayoutChanged)
+                }
+                // The floating TabPanels can't be zoomed in.
+                PanelEvent::ZoomIn | PanelEvent::ZoomOut => {}
+            }),
+        ];
+
+        self.floating_panels.push(FloatingPanel {
+            tab_panel,
+            bounds,
+            drag_start: None,
+            dragging: false,
+            _subscriptions,
+        });
+    }
+
+    fn remove_floating_tab_panel(
+        &mut self,
+        tab_panel: &View<TabPanel>,
+        cx: &mut ViewContext<Self>,
+    ) {
+        let len = self.floating_panels.len();
+        self.floating_panels
+            .retain(|floating| &floating.tab_panel != tab_panel);
+        if self.floating_panels.len() != len {
+            cx.emit(DockEvent::LayoutChanged);
+            cx.notify();
+        }
+    }
+
+    /// Raise the floating panel to the top, and remember where it's started to be moved or resized.
+    fn on_floating_mouse_down(
+        &mut self,
+        tab_panel: EntityId,
+        event: &MouseDownEvent,
+        cx: &mut ViewContext<Self>,
+    ) {
+        let Some(ix) = self
+            .floating_panels
+            .iter()
+            .position(|floating| floating.tab_panel.entity_id() == tab_panel)
+        else {
+            return;
+        };
+
+        let mut floating = self.floating_panels.remove(ix);
+        floating.drag_start = Some((event.position, floating.bounds));
+        self.floating_panels.push(floating);
+        cx.notify();
+    }
+
+    pub(super) fn on_floating_drag_move(
+        &mut self,
+        event: &DragMoveEvent<DragFloatingPanel>,
+        cx: &mut ViewContext<Self>,
+    ) {
+        let drag = event.drag(cx).clone();
+        let area = event.bounds.size;
+        let Some(floating) = self
+            .floating_panels
+            .iter_mut()
+            .find(|floating| floating.tab_panel.entity_id() == drag.tab_panel)
+        else {
+            return;
+        };
+        let Some((start, bounds)) = floating.drag_start else {
+            return;
+        };
+
+        floating.dragging = true;
+        let delta = event.event.position - start;
+        if drag.resize {
+            let width = (bounds.size.width + delta.x)
+                .min(area.width - bounds.origin.x)
+                .max(MIN_WIDTH);
+            let height = (bounds.size.height + delta.y)
+                .min(area.height - bounds.origin.y)
+                .max(MIN_HEIGHT);
+            floating.bounds.size = size(width, height);
+        } else {
+            // Keep the title bar inside the dock area, to be able to move it back.
+            let x = (bounds.origin.x + delta.x)
+                .min(area.width - MIN_WIDTH)
+                .max(px(0.));
+            let y = (bounds.origin.y + delta.y)
+                .min(area.height - TITLE_BAR_HEIGHT)
+                .max(px(0.));
+            floating.bounds.origin = point(x, y);
+        }
+        cx.notify();
+    }
+
+    /// Finish moving or resizing the floating panels, the layout is changed if it was being dragged.
+    pub(super) fn on_floating_drop(&mut self, _: &DragFloatingPanel, cx: &mut ViewContext<Self>) {
+        let mut changed = false;
+        for floating in self.floating_panels.iter_mut() {
+            floating.drag_start = None;
+            changed |= std::mem::take(&mut floating.dragging);
+        }
+
+        if changed {
+            cx.emit(DockEvent::LayoutChanged);
+            cx.notify();
+        }
+    }
+
+    /// Returns true if a floating panel is being moved or resized.
+    pub(super) fn is_dragging_floating_panel(&self) -> bool {
+        self.floating_panels
+            .iter()
+            .any(|floating| floating.dragging)
+    }
+
+    pub(super) fn dump_floating_panels(&self, cx: &AppContext) -> Vec<FloatingPanelState> {
+        self.floating_panels
+            .iter()
+            .map(|floating| FloatingPanelState {
+                panel: floating.tab_panel.read(cx).dump(cx),
+                x: floating.bounds.origin.x.0,
+                y: floating.bounds.origin.y.0,
+                width: floating.bounds.size.width.0,
+                height: floating.bounds.size.height.0,
+            })
+            .collect()
+    }
+
+    /// Replace the floating panels with the states, the panels can't be restored are skipped.
+    pub(super) fn load_floating_panels(
+        &mut self,
+        states: &[FloatingPanelState],
+        cx: &mut ViewContext<Self>,
+    ) {
+        self.floating_panels.clear();
+
+        let dock_area = cx.view().downgrade();
+        for state in states {
+            let Some(tab_panel) = state.panel.to_tab_panel(dock_area.clone(), cx) else {
+                continue;
+            };
+            let bounds = Bounds::new(
+                point(px(state.x), px(state.y)),
+                size(
+                    px(state.width).max(MIN_WIDTH),
+                    px(state.height).max(MIN_HEIGHT),
+                ),
+            );
+            self.add_floating_tab_panel(tab_panel, bounds, cx);
+        }
+    }
+
+    pub(super) fn render_floating_panels(&self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        div().children(self.floating_panels.iter().map(|floating| {
+            let tab_panel = floating.tab_panel.clone();
+            let entity_id = tab_panel.entity_id();
+            let bounds = floating.bounds;
+
+            v_flex()
+                .id(("floating-panel", entity_id))
+                .absolute()
+                .occlude()
+                .left(bounds.origin.x)
+                .top(bounds.origin.y)
+                .w(bounds.size.width)
+                .h(bounds.size.height)
+                .border_1()
+                .border_color(cx.theme().tokens.outline)
+                .rounded_md()
+                .shadow_lg()
+                .overflow_hidden()
+                .bg(cx.theme().tokens.surface)
+                .on_mouse_down(
+                    MouseButton::Left,
+                    cx.listener(move |this, event, cx| {
+                        this.on_floating_mouse_down(entity_id, event, cx)
+                    }),
+                )
+                .on_drop(cx.listener(Self::on_floating_drop))
+                .child(
+                    h_flex()
+                        .id("floating-panel-title-bar")
+                        .h(TITLE_BAR_HEIGHT)
+                        .flex_shrink_0()
+                        .pl_2()
+                        .pr_1()
+                        .gap_2()
+                        .border_b_1()
+                        .border_color(cx.theme().tokens.outline)
+                        .bg(cx.theme().tab_bar)
+                        .cursor_grab()
+                        .text_xs()
+                        .text_color(cx.theme().tokens.on_surface_muted)
+                        .child(
+                            div()
+                                .flex_1()
+                                .overflow_hidden()
+                                .text_ellipsis()
+                                .child(tab_panel.read(cx).title(cx)),
+                        )
+                        .child(
+                            Button::new(("floating-panel-dock", entity_id), cx)
+                                .icon(IconName::Minimize)
+                                .xsmall()
+                                .ghost()
+                                .tooltip(t!("Dock.Dock Panel"))
+                                .on_click(cx.listener({
+                                    let tab_panel = tab_panel.clone();
+                                    move |this, _, cx| this.dock_floating_tab_panel(&tab_panel, cx)
+                                })),
+                        )
+                        .on_drag(
+                            DragFloatingPanel {
+                                tab_panel: entity_id,
+                                resize: false,
+                            },
+                            |drag, cx| {
+                                cx.stop_propagation();
+                                cx.new_view(|_| drag.clone())
+                            },
+                        ),
+                )
+                .child(div().flex_1().overflow_hidden().child(tab_panel))
+                .child(
+                    div()
+                        .id("floating-panel-resize-handle")
+                        .absolute()
+                        .right_0()
+                        .bottom_0()
+                        .size(RESIZE_HANDLE_SIZE)
+                        .border_r_2()
+                        .border_b_2()
+                        .border_color(cx.theme().tokens.on_surface_muted.opacity(0.5))
+                        .rounded_br_md()
+                        .on_drag(
+                            DragFloatingPanel {
+                                tab_panel: entity_id,
+                                resize: true,
+                            },
+                            |drag, cx| {
+                                cx.stop_propagation();
+                                cx.new_view(|_| drag.clone())
+                            },
+                        ),
+                )
+        }))
+    }
+}
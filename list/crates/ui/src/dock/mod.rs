@@ -1,37 +1,160 @@
+mod floating_panel;
 mod panel;
 mod stack_panel;
+mod state;
 mod tab_panel;
 
+use std::sync::Arc;
+
 use gpui::{
-    actions, div, prelude::FluentBuilder, AnyView, InteractiveElement as _, IntoElement,
-    ParentElement as _, Render, SharedString, Styled, View, ViewContext,
+    actions, div, prelude::FluentBuilder, px, AnyView, AppContext, EventEmitter, FocusHandle,
+    FocusableView, InteractiveElement as _, IntoElement, ParentElement as _, Pixels, Render,
+    SharedString, Styled, Subscription, View, ViewContext, VisualContext as _, WeakView,
+    WindowContext,
 };
 pub use panel::*;
 pub use stack_panel::*;
+pub use state::*;
 pub use tab_panel::*;
 
-actions!(dock, [ToggleZoom, ClosePanel]);
+use floating_panel::FloatingPanel;
+
+use crate::{
+    drag_drop::{DragDropExt as _, DropIndicator, DropTarget},
+    AxisExt as _, Placement,
+};
+
+actions!(
+    dock,
+    [
+        ToggleZoom,
+        ClosePanel,
+        CloseOtherPanels,
+        CloseAllPanels,
+        MoveToOtherPanel,
+        FloatPanel,
+        PinPanel,
+        ToggleFloating,
+        NextTab,
+        PrevTab,
+        SplitRight,
+        SplitDown,
+        RenamePanel,
+        ZoomInPanel,
+        ZoomOutPanel,
+        ResetPanelZoom
+    ]
+);
+
+/// The size of the drop zones at the edges of the dock area.
+const DROP_ZONE_SIZE: Pixels = px(36.);
+/// The maximum number of the closed panels to remember for reopening.
+const MAX_CLOSED_PANELS: usize = 20;
+
+pub enum DockEvent {
+    /// The panel has been removed from the dock area to float in a new window,
+    /// the window should be opened by the subscriber with the state.
+    FloatPanel(PanelState),
+    /// The panel has been removed from the dock area to be kept visible in a small always-on-top window,
+    /// the window should be opened by the subscriber with the state.
+    PinPanel(PanelState),
+    /// The focused panel or the active tab of the focused TabPanel has changed,
+    /// use [`DockArea::active_panel`] to get the new one.
+    ActivePanelChanged,
+    /// The panels have been moved or resized by the user, use [`DockArea::dump`] to get the new layout.
+    LayoutChanged,
+}
+
+/// A panel closed by the user, to be reopened at the same position.
+struct ClosedPanel {
+    state: PanelState,
+    tab_panel: WeakView<TabPanel>,
+    ix: usize,
+}
 
 /// The main area of the dock.
 pub struct DockArea {
     id: SharedString,
+    focus_handle: FocusHandle,
     root: View<StackPanel>,
     zoom_view: Option<AnyView>,
+    floatable: bool,
+    proportional: bool,
+    /// The active panel of the last focused TabPanel.
+    active_panel: Option<Arc<dyn PanelView>>,
+    /// The last focused TabPanel, which the `active_panel` is in.
+    active_tab_panel: Option<WeakView<TabPanel>>,
+    /// The closed panels, the last one is the most recently closed.
+    closed_panels: Vec<ClosedPanel>,
+    /// The TabPanels floating above the root, the last one is on the top.
+    floating_panels: Vec<FloatingPanel>,
+    _root_subscription: Subscription,
 }
 
 impl DockArea {
     pub fn new(
         id: impl Into<SharedString>,
         root: View<StackPanel>,
-        _cx: &mut ViewContext<Self>,
+        cx: &mut ViewContext<Self>,
     ) -> Self {
         Self {
             id: id.into(),
+            focus_handle: cx.focus_handle(),
+            _root_subscription: Self::subscribe_root(&root, cx),
             root,
             zoom_view: None,
+            floatable: false,
+            proportional: false,
+            active_panel: None,
+            active_tab_panel: None,
+            closed_panels: Vec::new(),
+            floating_panels: Vec::new(),
         }
     }
 
+    /// Forward the [`PanelEvent::LayoutChanged`] of the root StackPanel, e.g.: the side panels are resized.
+    fn subscribe_root(root: &View<StackPanel>, cx: &mut ViewContext<Self>) -> Subscription {
+        cx.subscribe(root, |_, _, event: &PanelEvent, cx| {
+            if let PanelEvent::LayoutChanged = event {
+                cx.emit(DockEvent::LayoutChanged);
+            }
+        })
+    }
+
+    fn set_root(&mut self, root: View<StackPanel>, cx: &mut ViewContext<Self>) {
+        let proportional = self.proportional;
+        root.update(cx, |root, cx| root.set_proportional(proportional, cx));
+        self._root_subscription = Self::subscribe_root(&root, cx);
+        self.root = root;
+    }
+
+    /// Allow the panels to be floated into a new window by the tab menu, default: false
+    ///
+    /// The [`DockEvent::FloatPanel`] will be emitted when a panel is floated,
+    /// and the [`DockEvent::PinPanel`] when it's pinned in picture-in-picture.
+    pub fn floatable(mut self, floatable: bool) -> Self {
+        self.floatable = floatable;
+        self
+    }
+
+    pub fn is_floatable(&self) -> bool {
+        self.floatable
+    }
+
+    /// Keep the panels in the proportions of the dock area when the window is resized, default: false
+    ///
+    /// By default the panels keep their sizes in pixels, and only the center panel is resized with the window.
+    pub fn set_proportional(&mut self, proportional: bool, cx: &mut ViewContext<Self>) {
+        self.proportional = proportional;
+        self.root
+            .update(cx, |root, cx| root.set_proportional(proportional, cx));
+        cx.notify();
+    }
+
+    pub fn is_proportional(&self) -> bool {
+        self.proportional
+    }
+
     /// Returns the ID of the dock area.
     pub fn id(&self) -> SharedString {
         self.id.clone()
@@ -47,17 +170,352 @@ impl DockArea {
         cx.notify();
     }
 
+    /// Returns true if a panel is zoomed in to fill the dock area.
+    pub fn is_zoomed(&self) -> bool {
+        self.zoom_view.is_some()
+    }
+
+    /// Returns the active panel of the last focused TabPanel.
+    pub fn active_panel(&self) -> Option<Arc<dyn PanelView>> {
+        self.active_panel.clone()
+    }
+
+    /// Returns the last focused TabPanel, `None` if it has been removed.
+    pub fn active_tab_panel(&self) -> Option<View<TabPanel>> {
+        self.active_tab_panel.as_ref()?.upgrade()
+    }
+
+    pub(super) fn set_active_panel(
+        &mut self,
+        panel: Option<Arc<dyn PanelView>>,
+        tab_panel: WeakView<TabPanel>,
+        cx: &mut ViewContext<Self>,
+    ) {
+        if self.active_panel.as_ref().map(|p| p.view()) == panel.as_ref().map(|p| p.view()) {
+            return;
+        }
+
+        self.active_panel = panel;
+        self.active_tab_panel = Some(tab_panel);
+        cx.emit(DockEvent::ActivePanelChanged);
+        cx.notify();
+    }
+
+    /// Forget the active panel if it's the removed one, so it's not kept alive by the DockArea.
+    pub(super) fn remove_active_panel(&mut self, panel: &AnyView, cx: &mut ViewContext<Self>) {
+        if self.active_panel.as_ref().map(|p| p.view()).as_ref() != Some(panel) {
+            return;
+        }
+
+        self.active_panel = None;
+        cx.emit(DockEvent::ActivePanelChanged);
+        cx.notify();
+    }
+
+    /// Focus the dock area itself, e.g.: to navigate out of the focused panel.
+    pub fn focus(&self, cx: &mut WindowContext) {
+        self.focus_handle.focus(cx);
+    }
+
     /// Returns the root stack panel.
     pub fn root(&self) -> View<StackPanel> {
         self.root.clone()
     }
+
+    /// Returns all the TabPanels in the dock area, the floating ones are after the docked ones.
+    pub fn tab_panels(&self, cx: &AppContext) -> Vec<View<TabPanel>> {
+        let mut tab_panels = self.root.read(cx).tab_panels(cx);
+        tab_panels.extend(self.floating_tab_panels());
+        tab_panels
+    }
+
+    /// Returns the index of the side panel in the root StackPanel by the placement.
+    ///
+    /// Returns `None` if the root is not in the axis of the placement, or there is no side panel.
+    fn side_panel_ix(&self, placement: Placement, cx: &AppContext) -> Option<usize> {
+        let root = self.root.read(cx);
+        if root.axis.is_horizontal() != placement.is_horizontal() || root.panels_len() < 2 {
+            return None;
+        }
+
+        match placement {
+            Placement::Left | Placement::Top => Some(0),
+            Placement::Right | Placement::Bottom => Some(root.panels_len() - 1),
+        }
+    }
+
+    /// Returns true if the side panel of the placement is collapsed.
+    pub fn is_side_panel_collapsed(&self, placement: Placement, cx: &AppContext) -> bool {
+        self.side_panel_ix(placement, cx)
+            .map_or(false, |ix| self.root.read(cx).is_panel_collapsed(ix, cx))
+    }
+
+    /// Returns the TabPanels in the side panel of the placement, empty if there is no side panel.
+    pub fn side_tab_panels(&self, placement: Placement, cx: &AppContext) -> Vec<View<TabPanel>> {
+        self.side_panel_ix(placement, cx)
+            .map_or(Vec::new(), |ix| self.root.read(cx).tab_panels_at(ix, cx))
+    }
+
+    /// Collapse or expand the side panel of the placement, e.g.: `Placement::Left` for the left panel.
+    pub fn toggle_side_panel(&mut self, placement: Placement, cx: &mut ViewContext<Self>) {
+        let Some(ix) = self.side_panel_ix(placement, cx) else {
+            return;
+        };
+
+        let collapsed = self.is_side_panel_collapsed(placement, cx);
+        self.root
+            .update(cx, |root, cx| root.set_panel_collapsed(ix, !collapsed, cx));
+        cx.notify();
+    }
+
+    /// Find the first panel that matches the predicate, then activate and focus it.
+    ///
+    /// Returns `false` if there is no panel matched.
+    pub fn activate_panel<F>(&mut self, predicate: F, cx: &mut ViewContext<Self>) -> bool
+    where
+        F: Fn(&Arc<dyn PanelView>, &WindowContext) -> bool,
+    {
+        for tab_panel in self.tab_panels(cx) {
+            let panel = tab_panel
+                .read(cx)
+                .panels()
+                .iter()
+                .find(|panel| predicate(panel, cx))
+                .cloned();
+
+            if let Some(panel) = panel {
+                tab_panel.update(cx, |tab_panel, cx| tab_panel.activate_panel(&panel, cx));
+                panel.focus_handle(cx).focus(cx);
+                return true;
+            }
+        }
+
+        false
+    }
+
+    /// Emit [`DockEvent::FloatPanel`] with the state of the panel to open it in a new window,
+    /// the panel must have been detached from its [`TabPanel`] by the caller.
+    pub(super) fn float_panel(&mut self, panel: Arc<dyn PanelView>, cx: &mut ViewContext<Self>) {
+        cx.emit(DockEvent::FloatPanel(panel.dump(cx)));
+        cx.notify();
+    }
+
+    /// Emit [`DockEvent::PinPanel`] with the state of the panel to open it in an always-on-top window,
+    /// the panel must have been detached from its [`TabPanel`] by the caller.
+    pub(super) fn pin_panel(&mut self, panel: Arc<dyn PanelView>, cx: &mut ViewContext<Self>) {
+        cx.emit(DockEvent::PinPanel(panel.dump(cx)));
+        cx.notify();
+    }
+
+    /// Restore a panel from the state and add it to the first TabPanel, e.g.: dock back a floating panel.
+    ///
+    /// Returns `false` if the panel can't be restored by the [`register_panel`] builders.
+    pub fn add_panel_from_state(&mut self, state: &PanelState, cx: &mut ViewContext<Self>) -> bool {
+        let dock_area = cx.view().downgrade();
+        let Some(panel) = PanelRegistry::build_panel(state, dock_area, cx) else {
+            return false;
+        };
+
+        self.add_panel_to_first_tab_panel(panel.clone(), cx);
+        panel.focus_handle(cx).focus(cx);
+        cx.notify();
+        true
+    }
+
+    fn add_panel_to_first_tab_panel(
+        &mut self,
+        panel: Arc<dyn PanelView>,
+        cx: &mut ViewContext<Self>,
+    ) {
+        if let Some(tab_panel) = self.root.read(cx).tab_panels(cx).first() {
+            tab_panel.update(cx, |view, cx| view.add_panel(panel, cx));
+        } else {
+            let dock_area = cx.view().downgrade();
+            let tab_panel = cx.new_view(|cx| TabPanel::new(None, dock_area.clone(), cx));
+            tab_panel.update(cx, |view, cx| view.add_panel(panel, cx));
+            self.root.update(cx, |view, cx| {
+                view.add_panel(tab_panel, None, dock_area, cx)
+            });
+        }
+    }
+
+    pub(super) fn push_closed_panel(
+        &mut self,
+        state: PanelState,
+        tab_panel: WeakView<TabPanel>,
+        ix: usize,
+    ) {
+        if self.closed_panels.len() >= MAX_CLOSED_PANELS {
+            self.closed_panels.remove(0);
+        }
+        self.closed_panels.push(ClosedPanel {
+            state,
+            tab_panel,
+            ix,
+        });
+    }
+
+    /// Returns true if there is a closed panel to reopen.
+    pub fn has_closed_panels(&self) -> bool {
+        !self.closed_panels.is_empty()
+    }
+
+    /// Recreate the most recently closed panel at its prior position and focus it.
+    ///
+    /// The panel is added to the first TabPanel if its TabPanel has been removed.
+    /// Returns `false` if there is no closed panel, or it can't be restored by the [`register_panel`] builders.
+    pub fn reopen_closed_panel(&mut self, cx: &mut ViewContext<Self>) -> bool {
+        let Some(closed) = self.closed_panels.pop() else {
+            return false;
+        };
+        let dock_area = cx.view().downgrade();
+        let Some(panel) = PanelRegistry::build_panel(&closed.state, dock_area, cx) else {
+            return false;
+        };
+
+        let tab_panel = closed
+            .tab_panel
+            .upgrade()
+            .filter(|tab_panel| self.tab_panels(cx).contains(tab_panel));
+        if let Some(tab_panel) = tab_panel {
+            tab_panel.update(cx, |view, cx| {
+                let ix = closed.ix.min(view.panels().len());
+                view.insert_panel_at(panel.clone(), ix, cx)
+            });
+        } else {
+            self.add_panel_to_first_tab_panel(panel.clone(), cx);
+        }
+
+        panel.focus_handle(cx).focus(cx);
+        cx.emit(DockEvent::LayoutChanged);
+        cx.notify();
+        true
+    }
+
+    /// Dump the layout of the dock area, to save and restore it later by [`DockArea::load`].
+    pub fn dump(&self, cx: &AppContext) -> DockAreaState {
+        DockAreaState {
+            version: None,
+            center: self.root.read(cx).dump(cx),
+            floating: self.dump_floating_panels(cx),
+        }
+    }
+
+    /// Restore the layout from a [`DockAreaState`].
+    ///
+    /// The panels (except `StackPanel` and `TabPanel`) must have been registered by [`register_panel`],
+    /// the panels without a builder will be skipped.
+    pub fn load(&mut self, state: &DockAreaState, cx: &mut ViewContext<Self>) {
+        let dock_area = cx.view().downgrade();
+        let root = state.center.to_stack_panel(dock_area, cx);
+        self.replace_root(root, cx);
+        self.load_floating_panels(&state.floating, cx);
+    }
+
+    /// Replace all the panels with the `root`, e.g.: to build another layout from scratch.
+    pub fn replace_root(&mut self, root: View<StackPanel>, cx: &mut ViewContext<Self>) {
+        self.zoom_view = None;
+        self.floating_panels.clear();
+        self.set_root(root, cx);
+        cx.notify();
+    }
+
+    /// Move the dragged panel to a new TabPanel at the edge of the dock area.
+    fn on_drop_at_edge(
+        &mut self,
+        drag: &DragPanel,
+        placement: Placement,
+        cx: &mut ViewContext<Self>,
+    ) {
+        let panel = drag.panel.clone();
+        drag.tab_panel.update(cx, |view, cx| {
+            view.detach_panel(panel.clone(), cx);
+            view.remove_self_if_empty(cx);
+        });
+
+        let dock_area = cx.view().downgrade();
+        let new_tab_panel = cx.new_view(|cx| TabPanel::new(None, dock_area.clone(), cx));
+        new_tab_panel.update(cx, |view, cx| view.add_panel(panel, cx));
+
+        let root = self.root.clone();
+        if root.read(cx).axis.is_horizontal() == placement.is_horizontal() {
+            root.update(cx, |view, cx| match placement {
+                Placement::Left | Placement::Top => {
+                    view.insert_panel_before(new_tab_panel, 0, None, dock_area, cx)
+                }
+                Placement::Right | Placement::Bottom => {
+                    view.add_panel(new_tab_panel, None, dock_area, cx)
+                }
+            });
+        } else {
+            // Wrap the current root into a new root with the axis of the placement.
+            let new_root = cx.new_view(|cx| StackPanel::new(placement.axis(), cx));
+            new_root.update(cx, |view, cx| match placement {
+                Placement::Left | Placement::Top => {
+                    view.add_panel(new_tab_panel, None, dock_area.clone(), cx);
+                    view.add_panel(root, None, dock_area, cx);
+                }
+                Placement::Right | Placement::Bottom => {
+                    view.add_panel(root, None, dock_area.clone(), cx);
+                    view.add_panel(new_tab_panel, None, dock_area, cx);
+                }
+            });
+            self.set_root(new_root, cx);
+        }
+
+        cx.emit(DockEvent::LayoutChanged);
+        cx.notify();
+    }
+
+    /// Render the drop zones at the edges, only visible when a panel is dragging, not moving a floating one.
+    fn render_drop_zones(&self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let zones = [
+            Placement::Left,
+            Placement::Right,
+            Placement::Top,
+            Placement::Bottom,
+        ];
+
+        let visible =
+            cx.has_active_drag() && self.zoom_view.is_none() && !self.is_dragging_floating_panel();
+
+        div().when(visible, |this| {
+            this.children(zones.into_iter().map(|placement| {
+                div()
+                    .id(SharedString::from(format!("drop-zone-{}", placement)))
+                    .absolute()
+                    .occlude()
+                    .map(|this| match placement {
+                        Placement::Left => this.left_0().top_0().bottom_0().w(DROP_ZONE_SIZE),
+                        Placement::Right => this.right_0().top_0().bottom_0().w(DROP_ZONE_SIZE),
+                        Placement::Top => this.top_0().left_0().right_0().h(DROP_ZONE_SIZE),
+                        Placement::Bottom => this.bottom_0().left_0().right_0().h(DROP_ZONE_SIZE),
+                    })
+                    .drop_target(
+                        DropTarget::new(cx.listener(move |this, drag: &DragPanel, cx| {
+                            this.on_drop_at_edge(drag, placement, cx)
+                        }))
+                        .indicator(|_, _| DropIndicator::Outline),
+                    )
+            }))
+        })
+    }
+}
+
+impl EventEmitter<DockEvent> for DockArea {}
+
+impl FocusableView for DockArea {
+    fn focus_handle(&self, _: &AppContext) -> FocusHandle {
+        self.focus_handle.clone()
+    }
 }
 
 impl Render for DockArea {
-    fn render(&mut self, _: &mut ViewContext<Self>) -> impl IntoElement {
-        // println!("Rendering dock area");
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
         div()
             .id("dock-area")
+            .track_focus(&self.focus_handle)
+            .relative()
             .size_full()
             .overflow_hidden()
             .map(|this| {
@@ -67,5 +525,11 @@ impl Render for DockArea {
                     this.child(self.root.clone())
                 }
             })
+            .on_drag_move(cx.listener(Self::on_floating_drag_move))
+            .on_drop(cx.listener(Self::on_floating_drop))
+            .when(self.zoom_view.is_none(), |this| {
+                this.child(self.render_floating_panels(cx))
+            })
+            .child(self.render_drop_zones(cx))
     }
 }
@@ -1,47 +1,166 @@
-use gpui::{AnyView, EventEmitter, FocusableView, SharedString, View, WindowContext};
+use gpui::{
+    AnyView, AppContext, EventEmitter, FocusHandle, FocusableView, SharedString, View, ViewContext,
+    WindowContext,
+};
 use rust_i18n::t;
 
-use crate::popup_menu::PopupMenu;
+use crate::{popup_menu::PopupMenu, IconName};
 
-use super::PanelEvent;
+use super::{PanelEvent, PanelState};
+
+/// The decision of [`Panel::can_close`] when the panel is going to be closed.
+pub enum CloseDecision {
+    /// Close the panel.
+    Close,
+    /// Ask the user to Save / Discard / Cancel with the message, e.g.: the panel has unsaved changes.
+    Confirm(SharedString),
+    /// Keep the panel open.
+    Keep,
+}
+
+/// The badge shown on the tab of the panel, see [`Panel::badge`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PanelBadge {
+    /// Show the count, e.g.: the unread messages.
+    Count(usize),
+    /// Show a dot, e.g.: the panel has new content.
+    Dot,
+}
 
 pub trait Panel: EventEmitter<PanelEvent> + FocusableView {
+    /// The name of the panel, used to find the builder in the [`super::PanelRegistry`]
+    /// when restoring the layout.
+    fn panel_name(&self) -> &'static str;
+
     /// The title of the panel, default is `None`.
     fn title(&self, _cx: &WindowContext) -> SharedString {
         t!("Dock.Unnamed").into()
     }
 
+    /// The icon shown before the title on the tab, default is `None`.
+    fn icon(&self, _cx: &WindowContext) -> Option<IconName> {
+        None
+    }
+
+    /// The badge shown after the title on the tab, default is `None`.
+    ///
+    /// The tab is rendered with the panel, call `cx.notify()` in the panel to update the badge.
+    fn badge(&self, _cx: &WindowContext) -> Option<PanelBadge> {
+        None
+    }
+
     /// Whether the panel can be closed, default is `true`.
     fn closeable(&self, _cx: &WindowContext) -> bool {
         true
     }
 
+    /// Intercept the close of a [`Panel::closeable`] panel, default is [`CloseDecision::Close`].
+    fn can_close(&self, _cx: &WindowContext) -> CloseDecision {
+        CloseDecision::Close
+    }
+
+    /// Save the unsaved changes, called when "Save" is chosen in the confirm of [`CloseDecision::Confirm`].
+    fn save(&mut self, _cx: &mut ViewContext<Self>) {}
+
+    /// Whether the title can be renamed inline by double-clicking the tab,
+    /// otherwise the double-click toggles the zoom, default is `false`.
+    fn renameable(&self, _cx: &WindowContext) -> bool {
+        false
+    }
+
+    /// Whether the title can be renamed by the `RenamePanel` action or the context menu of the tab,
+    /// default is [`Panel::renameable`].
+    fn can_rename(&self, cx: &WindowContext) -> bool {
+        self.renameable(cx)
+    }
+
+    /// Called when the tab of a [`Panel::can_rename`] panel has been renamed,
+    /// keep the title to return it in [`Panel::title`] and [`Panel::dump`].
+    fn rename(&mut self, _title: SharedString, _cx: &mut ViewContext<Self>) {}
+
     /// The addition popup menu of the panel, default is `None`.
     fn popup_menu(&self, this: PopupMenu, _cx: &WindowContext) -> PopupMenu {
         this
     }
+
+    /// Dump the panel state for saving the layout, default is an empty info.
+    fn dump(&self, _cx: &AppContext) -> PanelState {
+        PanelState::new(self, serde_json::Value::Null)
+    }
 }
 
 pub trait PanelView: 'static + Send + Sync {
+    fn panel_name(&self, cx: &AppContext) -> &'static str;
+
     /// The title of the panel, default is `None`.
     fn title(&self, _cx: &WindowContext) -> SharedString;
 
+    fn icon(&self, cx: &WindowContext) -> Option<IconName>;
+
+    fn badge(&self, cx: &WindowContext) -> Option<PanelBadge>;
+
     fn closeable(&self, cx: &WindowContext) -> bool;
 
+    fn can_close(&self, cx: &WindowContext) -> CloseDecision;
+
+    fn save(&self, cx: &mut WindowContext);
+
+    fn renameable(&self, cx: &WindowContext) -> bool;
+
+    fn can_rename(&self, cx: &WindowContext) -> bool;
+
+    fn rename(&self, title: SharedString, cx: &mut WindowContext);
+
     fn popup_menu(&self, menu: PopupMenu, cx: &WindowContext) -> PopupMenu;
 
     fn view(&self) -> AnyView;
+
+    fn focus_handle(&self, cx: &AppContext) -> FocusHandle;
+
+    fn dump(&self, cx: &AppContext) -> PanelState;
 }
 
 impl<T: Panel> PanelView for View<T> {
+    fn panel_name(&self, cx: &AppContext) -> &'static str {
+        self.read(cx).panel_name()
+    }
+
     fn title(&self, cx: &WindowContext) -> SharedString {
         self.read(cx).title(cx)
     }
 
+    fn icon(&self, cx: &WindowContext) -> Option<IconName> {
+        self.read(cx).icon(cx)
+    }
+
+    fn badge(&self, cx: &WindowContext) -> Option<PanelBadge> {
+        self.read(cx).badge(cx)
+    }
+
     fn closeable(&self, cx: &WindowContext) -> bool {
         self.read(cx).closeable(cx)
     }
 
+    fn can_close(&self, cx: &WindowContext) -> CloseDecision {
+        self.read(cx).can_close(cx)
+    }
+
+    fn save(&self, cx: &mut WindowContext) {
+        self.update(cx, |view, cx| view.save(cx))
+    }
+
+    fn renameable(&self, cx: &WindowContext) -> bool {
+        self.read(cx).renameable(cx)
+    }
+
+    fn can_rename(&self, cx: &WindowContext) -> bool {
+        self.read(cx).can_rename(cx)
+    }
+
+    fn rename(&self, title: SharedString, cx: &mut WindowContext) {
+        self.update(cx, |view, cx| view.rename(title, cx))
+    }
+
     fn popup_menu(&self, menu: PopupMenu, cx: &WindowContext) -> PopupMenu {
         self.read(cx).popup_menu(menu, cx)
     }
@@ -49,6 +168,14 @@ impl<T: Panel> PanelView for View<T> {
     fn view(&self) -> AnyView {
         self.clone().into()
     }
+
+    fn focus_handle(&self, cx: &AppContext) -> FocusHandle {
+        self.read(cx).focus_handle(cx)
+    }
+
+    fn dump(&self, cx: &AppContext) -> PanelState {
+        self.read(cx).dump(cx)
+    }
 }
 
 impl From<&dyn PanelView> for AnyView {
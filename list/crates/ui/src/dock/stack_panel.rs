@@ -2,12 +2,15 @@ use std::sync::Arc;
 
 use crate::{
     h_flex,
-    resizable::{h_resizable, resizable_panel, v_resizable, ResizablePanel, ResizablePanelGroup},
+    resizable::{
+        h_resizable, resizable_panel, v_resizable, ResizablePanel, ResizablePanelEvent,
+        ResizablePanelGroup,
+    },
     theme::ActiveTheme,
     Placement,
 };
 
-use super::{DockArea, Panel, PanelEvent, PanelView, TabPanel};
+use super::{DockArea, DockEvent, Panel, PanelEvent, PanelInfo, PanelState, PanelView, TabPanel};
 use gpui::{
     prelude::FluentBuilder as _, AppContext, Axis, DismissEvent, Entity, EventEmitter, FocusHandle,
     FocusableView, IntoElement, ParentElement, Pixels, Render, Styled, View, ViewContext,
@@ -15,6 +18,55 @@ use gpui::{
 };
 use smallvec::SmallVec;
 
+/// The size of a panel in the [`StackPanel`], along the axis of the stack.
+///
+/// `Option<Pixels>` can be used for the initial size only.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct PanelSize {
+    size: Option<Pixels>,
+    min_size: Option<Pixels>,
+    max_size: Option<Pixels>,
+}
+
+impl PanelSize {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the initial size, default is to fill the rest space.
+    pub fn size(mut self, size: Pixels) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Set the min size when resizing by the splitter or the window, default is 100px.
+    pub fn min_size(mut self, size: Pixels) -> Self {
+        self.min_size = Some(size);
+        self
+    }
+
+    /// Set the max size when resizing by the splitter or the window, default is no limit.
+    pub fn max_size(mut self, size: Pixels) -> Self {
+        self.max_size = Some(size);
+        self
+    }
+}
+
+impl From<Option<Pixels>> for PanelSize {
+    fn from(size: Option<Pixels>) -> Self {
+        Self {
+            size,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<Pixels> for PanelSize {
+    fn from(size: Pixels) -> Self {
+        Self::new().size(size)
+    }
+}
+
 pub struct StackPanel {
     pub(super) parent: Option<View<StackPanel>>,
     pub(super) axis: Axis,
@@ -24,25 +76,56 @@ pub struct StackPanel {
 }
 
 impl Panel for StackPanel {
+    fn panel_name(&self) -> &'static str {
+        "StackPanel"
+    }
+
     fn title(&self, _cx: &gpui::WindowContext) -> gpui::SharedString {
         "StackPanel".into()
     }
+
+    fn dump(&self, cx: &AppContext) -> PanelState {
+        let panel_group = self.panel_group.read(cx);
+        let sizes = panel_group.sizes(cx);
+        let (min_sizes, max_sizes) = panel_group
+            .size_limits(cx)
+            .into_iter()
+            .map(|(min_size, max_size)| (min_size.0, max_size.map(|size| size.0)))
+            .unzip();
+
+        PanelState {
+            panel_name: self.panel_name().to_string(),
+            children: self.panels.iter().map(|panel| panel.dump(cx)).collect(),
+            info: PanelInfo::Stack {
+                sizes: sizes.iter().map(|size| size.0).collect(),
+                axis: self.axis.into(),
+                min_sizes,
+                max_sizes,
+            },
+        }
+    }
 }
 
 impl StackPanel {
     pub fn new(axis: Axis, cx: &mut ViewContext<Self>) -> Self {
+        let panel_group = cx.new_view(|cx| {
+            if axis == Axis::Horizontal {
+                h_resizable(cx)
+            } else {
+                v_resizable(cx)
+            }
+        });
+        cx.subscribe(&panel_group, |_, _, _: &ResizablePanelEvent, cx| {
+            cx.emit(PanelEvent::LayoutChanged)
+        })
+        .detach();
+
         Self {
             axis,
             parent: None,
             focus_handle: cx.focus_handle(),
             panels: SmallVec::new(),
-            panel_group: cx.new_view(|cx| {
-                if axis == Axis::Horizontal {
-                    h_resizable(cx)
-                } else {
-                    v_resizable(cx)
-                }
-            }),
+            panel_group,
         }
     }
 
@@ -55,6 +138,28 @@ impl StackPanel {
         self.panels.len()
     }
 
+    /// Returns all the TabPanels in the stack, including the nested stack panels.
+    pub(super) fn tab_panels(&self, cx: &AppContext) -> Vec<View<TabPanel>> {
+        (0..self.panels.len())
+            .flat_map(|ix| self.tab_panels_at(ix, cx))
+            .collect()
+    }
+
+    /// Returns the TabPanels of the panel at the index, including the nested stack panels.
+    pub(super) fn tab_panels_at(&self, ix: usize, cx: &AppContext) -> Vec<View<TabPanel>> {
+        let Some(panel) = self.panels.get(ix) else {
+            return Vec::new();
+        };
+
+        if let Ok(tab_panel) = panel.view().downcast::<TabPanel>() {
+            vec![tab_panel]
+        } else if let Ok(stack_panel) = panel.view().downcast::<StackPanel>() {
+            stack_panel.read(cx).tab_panels(cx)
+        } else {
+            Vec::new()
+        }
+    }
+
     /// Return the index of the panel.
     pub(crate) fn index_of_panel<P>(&self, panel: &View<P>) -> Option<usize>
     where
@@ -70,7 +175,7 @@ impl StackPanel {
     pub fn add_panel<P>(
         &mut self,
         panel: View<P>,
-        size: Option<Pixels>,
+        size: impl Into<PanelSize>,
         dock_area: WeakView<DockArea>,
         cx: &mut ViewContext<Self>,
     ) where
@@ -83,7 +188,7 @@ impl StackPanel {
         &mut self,
         panel: View<P>,
         placement: Placement,
-        size: Option<Pixels>,
+        size: impl Into<PanelSize>,
         dock_area: WeakView<DockArea>,
         cx: &mut ViewContext<Self>,
     ) where
@@ -97,7 +202,7 @@ impl StackPanel {
         panel: View<P>,
         ix: usize,
         placement: Placement,
-        size: Option<Pixels>,
+        size: impl Into<PanelSize>,
         dock_area: WeakView<DockArea>,
         cx: &mut ViewContext<Self>,
     ) where
@@ -118,7 +223,7 @@ impl StackPanel {
         &mut self,
         panel: View<P>,
         ix: usize,
-        size: Option<Pixels>,
+        size: impl Into<PanelSize>,
         dock_area: WeakView<DockArea>,
         cx: &mut ViewContext<Self>,
     ) where
@@ -132,7 +237,7 @@ impl StackPanel {
         &mut self,
         panel: View<P>,
         ix: usize,
-        size: Option<Pixels>,
+        size: impl Into<PanelSize>,
         dock_area: WeakView<DockArea>,
         cx: &mut ViewContext<Self>,
     ) where
@@ -141,20 +246,22 @@ impl StackPanel {
         self.insert_panel(panel, ix + 1, size, dock_area, cx);
     }
 
-    fn new_resizable_panel<P>(panel: View<P>, size: Option<Pixels>) -> ResizablePanel
+    fn new_resizable_panel<P>(panel: View<P>, size: PanelSize) -> ResizablePanel
     where
         P: Panel,
     {
         resizable_panel()
             .content_view(panel.into())
-            .when_some(size, |this, size| this.size(size))
+            .when_some(size.size, |this, size| this.size(size))
+            .when_some(size.min_size, |this, size| this.min_size(size))
+            .when_some(size.max_size, |this, size| this.max_size(size))
     }
 
     fn insert_panel<P>(
         &mut self,
         panel: View<P>,
         ix: usize,
-        size: Option<Pixels>,
+        size: impl Into<PanelSize>,
         dock_area: WeakView<DockArea>,
         cx: &mut ViewContext<Self>,
     ) where
@@ -174,6 +281,9 @@ impl StackPanel {
             PanelEvent::ZoomOut => {
                 let _ = dock_area.update(cx, |dock, cx| dock.set_zoomed_out(cx));
             }
+            PanelEvent::LayoutChanged | PanelEvent::Renamed(_) => {
+                let _ = dock_area.update(cx, |_, cx| cx.emit(DockEvent::LayoutChanged));
+            }
         })
         .detach();
 
@@ -186,7 +296,11 @@ impl StackPanel {
                 if let Ok(tab_panel) = panel.view().downcast::<TabPanel>() {
                     tab_panel.update(cx, |tab_panel, _| tab_panel.set_parent(view));
                 } else if let Ok(stack_panel) = panel.view().downcast::<Self>() {
-                    stack_panel.update(cx, |stack_panel, _| stack_panel.parent = Some(view));
+                    let proportional = view.read(cx).is_proportional(cx);
+                    stack_panel.update(cx, |stack_panel, cx| {
+                        stack_panel.parent = Some(view);
+                        stack_panel.set_proportional(proportional, cx);
+                    });
                 }
             }
         });
@@ -199,7 +313,7 @@ impl StackPanel {
 
         self.panels.insert(ix, Arc::new(panel.clone()));
         self.panel_group.update(cx, |view, cx| {
-            view.insert_child(Self::new_resizable_panel(panel, size), ix, cx)
+            view.insert_child(Self::new_resizable_panel(panel, size.into()), ix, cx)
         });
 
         cx.notify();
@@ -232,9 +346,15 @@ impl StackPanel {
         P: Panel,
     {
         if let Some(ix) = self.index_of_panel(&old_panel) {
+            let proportional = self.is_proportional(cx);
+            new_panel.update(cx, |view, cx| view.set_proportional(proportional, cx));
             self.panels[ix] = Arc::new(new_panel.clone());
             self.panel_group.update(cx, |view, cx| {
-                view.replace_child(Self::new_resizable_panel(new_panel.clone(), None), ix, cx);
+                view.replace_child(
+                    Self::new_resizable_panel(new_panel.clone(), PanelSize::new()),
+                    ix,
+                    cx,
+                );
             });
         }
     }
@@ -259,6 +379,35 @@ impl StackPanel {
         cx.notify();
     }
 
+    /// Collapse or expand the panel at the index, e.g.: the side panels of the [`DockArea`].
+    pub fn set_panel_collapsed(&mut self, ix: usize, collapsed: bool, cx: &mut ViewContext<Self>) {
+        self.panel_group
+            .update(cx, |view, cx| view.set_collapsed(ix, collapsed, cx));
+        cx.notify();
+    }
+
+    /// Returns true if the panel at the index is collapsed.
+    pub fn is_panel_collapsed(&self, ix: usize, cx: &AppContext) -> bool {
+        self.panel_group.read(cx).is_collapsed(ix, cx)
+    }
+
+    /// Keep the panels in the proportions of the stack size when it is resized, including the nested stacks.
+    ///
+    /// See [`ResizablePanelGroup::proportional`].
+    pub fn set_proportional(&mut self, proportional: bool, cx: &mut ViewContext<Self>) {
+        self.panel_group
+            .update(cx, |view, cx| view.set_proportional(proportional, cx));
+        for panel in &self.panels {
+            if let Ok(stack_panel) = panel.view().downcast::<Self>() {
+                stack_panel.update(cx, |view, cx| view.set_proportional(proportional, cx));
+            }
+        }
+    }
+
+    pub fn is_proportional(&self, cx: &AppContext) -> bool {
+        self.panel_group.read(cx).is_proportional()
+    }
+
     /// Remove all panels from the stack.
     pub(super) fn remove_all_panels(&mut self, cx: &mut ViewContext<Self>) {
         self.panels.clear();
@@ -0,0 +1,214 @@
+use std::{collections::HashMap, rc::Rc, sync::Arc};
+
+use gpui::{px, AppContext, Axis, Global, View, VisualContext as _, WeakView, WindowContext};
+use serde::{Deserialize, Serialize};
+
+use super::{DockArea, Panel, PanelSize, PanelView, StackPanel, TabPanel};
+
+/// The serialized state of a [`DockArea`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DockAreaState {
+    /// The version of the layout, used to discard the state if the default layout has changed.
+    #[serde(default)]
+    pub version: Option<usize>,
+    pub center: PanelState,
+    /// The TabPanels floating above the center, from the bottom to the top.
+    #[serde(default)]
+    pub floating: Vec<FloatingPanelState>,
+}
+
+/// The serialized state of a TabPanel floating inside the [`DockArea`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FloatingPanelState {
+    /// The state of the TabPanel.
+    pub panel: PanelState,
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+/// The serialized state of a panel in the dock tree.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PanelState {
+    pub panel_name: String,
+    #[serde(default)]
+    pub children: Vec<PanelState>,
+    pub info: PanelInfo,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PanelInfo {
+    Stack {
+        sizes: Vec<f32>,
+        axis: StackAxis,
+        /// The min sizes of the children, empty for the default.
+        #[serde(default)]
+        min_sizes: Vec<f32>,
+        /// The max sizes of the children, `None` for no limit.
+        #[serde(default)]
+        max_sizes: Vec<Option<f32>>,
+    },
+    Tabs {
+        active_index: usize,
+    },
+    Panel(serde_json::Value),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StackAxis {
+    Horizontal,
+    Vertical,
+}
+
+impl From<Axis> for StackAxis {
+    fn from(axis: Axis) -> Self {
+        match axis {
+            Axis::Horizontal => StackAxis::Horizontal,
+            Axis::Vertical => StackAxis::Vertical,
+        }
+    }
+}
+
+impl From<StackAxis> for Axis {
+    fn from(axis: StackAxis) -> Self {
+        match axis {
+            StackAxis::Horizontal => Axis::Horizontal,
+            StackAxis::Vertical => Axis::Vertical,
+        }
+    }
+}
+
+impl PanelState {
+    /// Create a state with the panel name and a custom info value.
+    pub fn new<P: Panel + ?Sized>(panel: &P, info: serde_json::Value) -> Self {
+        Self {
+            panel_name: panel.panel_name().to_string(),
+            children: Vec::new(),
+            info: PanelInfo::Panel(info),
+        }
+    }
+
+    /// Rebuild a [`StackPanel`] from this state, children will be rebuilt recursively.
+    pub(crate) fn to_stack_panel(
+        &self,
+        dock_area: WeakView<DockArea>,
+        cx: &mut WindowContext,
+    ) -> View<StackPanel> {
+        let (sizes, axis, min_sizes, max_sizes) = match &self.info {
+            PanelInfo::Stack {
+                sizes,
+                axis,
+                min_sizes,
+                max_sizes,
+            } => (
+                sizes.as_slice(),
+                (*axis).into(),
+                min_sizes.as_slice(),
+                max_sizes.as_slice(),
+            ),
+            _ => (&[][..], Axis::Horizontal, &[][..], &[][..]),
+        };
+
+        let stack_panel = cx.new_view(|cx| StackPanel::new(axis, cx));
+        for (ix, child) in self.children.iter().enumerate() {
+            let mut size = PanelSize::new();
+            if let Some(value) = sizes.get(ix).filter(|size| **size > 0.) {
+                size = size.size(px(*value));
+            }
+            if let Some(min_size) = min_sizes.get(ix) {
+                size = size.min_size(px(*min_size));
+            }
+            if let Some(max_size) = max_sizes.get(ix).copied().flatten() {
+                size = size.max_size(px(max_size));
+            }
+
+            if child.panel_name == "StackPanel" {
+                let panel = child.to_stack_panel(dock_area.clone(), cx);
+                stack_panel.update(cx, |view, cx| {
+                    view.add_panel(panel, size, dock_area.clone(), cx)
+                });
+            } else if let Some(panel) = child.to_tab_panel(dock_area.clone(), cx) {
+                stack_panel.update(cx, |view, cx| {
+                    view.add_panel(panel, size, dock_area.clone(), cx)
+                });
+            }
+        }
+
+        stack_panel
+    }
+
+    /// Rebuild a [`TabPanel`] from this state, the children are restored by the [`PanelRegistry`].
+    ///
+    /// Returns `None` if none of the children can be restored.
+    pub(crate) fn to_tab_panel(
+        &self,
+        dock_area: WeakView<DockArea>,
+        cx: &mut WindowContext,
+    ) -> Option<View<TabPanel>> {
+        let active_index = match self.info {
+            PanelInfo::Tabs { active_index } => active_index,
+            _ => 0,
+        };
+
+        let panels = self
+            .children
+            .iter()
+            .filter_map(|child| PanelRegistry::build_panel(child, dock_area.clone(), cx))
+            .collect::<Vec<_>>();
+        if panels.is_empty() {
+            return None;
+        }
+        let active_index = active_index.min(panels.len() - 1);
+
+        Some(cx.new_view(|cx| {
+            let mut tab_panel = TabPanel::new(None, dock_area.clone(), cx);
+            for panel in panels {
+                tab_panel.add_panel(panel, cx);
+            }
+            tab_panel.set_active_ix(active_index, cx);
+            tab_panel
+        }))
+    }
+}
+
+type PanelBuilder =
+    Rc<dyn Fn(WeakView<DockArea>, &PanelState, &mut WindowContext) -> Option<Arc<dyn PanelView>>>;
+
+/// The registry of the panel builders, used to restore the panels from a [`PanelState`].
+#[derive(Default)]
+pub struct PanelRegistry {
+    items: HashMap<String, PanelBuilder>,
+}
+
+impl Global for PanelRegistry {}
+
+impl PanelRegistry {
+    pub(crate) fn build_panel(
+        state: &PanelState,
+        dock_area: WeakView<DockArea>,
+        cx: &mut WindowContext,
+    ) -> Option<Arc<dyn PanelView>> {
+        let build = cx
+            .try_global::<PanelRegistry>()?
+            .items
+            .get(&state.panel_name)?
+            .clone();
+        build(dock_area, state, cx)
+    }
+}
+
+/// Register a panel builder by the panel name, that the [`DockArea`] can restore the panel from the saved state.
+///
+/// The builder returns `None` if the panel can't be restored, then it will be skipped.
+pub fn register_panel<F>(cx: &mut AppContext, panel_name: &str, build: F)
+where
+    F: Fn(WeakView<DockArea>, &PanelState, &mut WindowContext) -> Option<Arc<dyn PanelView>>
+        + 'static,
+{
+    cx.default_global::<PanelRegistry>()
+        .items
+        .insert(panel_name.to_string(), Rc::new(build));
+}
@@ -1,29 +1,47 @@
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
 
 use gpui::{
-    div, prelude::FluentBuilder, rems, AnchorCorner, AppContext, DefiniteLength, DismissEvent,
-    DragMoveEvent, Empty, EventEmitter, FocusHandle, FocusableView, InteractiveElement as _,
-    IntoElement, ParentElement, Pixels, Render, ScrollHandle, StatefulInteractiveElement, Styled,
-    View, ViewContext, VisualContext as _, WeakView, WindowContext,
+    canvas, div, prelude::FluentBuilder, px, rems, AnchorCorner, AnyElement, AppContext, Bounds,
+    ClickEvent, DefiniteLength, DismissEvent, DragMoveEvent, Empty, EntityId, EventEmitter,
+    FocusHandle, FocusableView, InteractiveElement as _, IntoElement, KeyDownEvent, MouseButton,
+    MouseDownEvent, ParentElement, Pixels, Render, ScrollHandle, SharedString,
+    StatefulInteractiveElement, Styled, Subscription, View, ViewContext, VisualContext as _,
+    WeakView, WindowContext,
 };
 use rust_i18n::t;
 
 use crate::{
+    accessibility::{register_accessible, Accessible as _, AccessibleNode, AccessibleRole},
     button::Button,
+    context_menu::ContextMenuExt,
+    drag_drop::{DragDropExt as _, Draggable, DropIndicator, DropTarget},
+    focusable::inner_focus_ring,
     h_flex,
+    input::{InputEvent, TextInput},
+    perf::perf_scope,
     popup_menu::{PopupMenu, PopupMenuExt},
     tab::{Tab, TabBar},
-    theme::ActiveTheme,
+    theme::{ActiveTheme, Theme},
     tooltip::Tooltip,
-    v_flex, AxisExt, IconName, Placement, Selectable, Sizable,
+    v_flex, AxisExt, ContextModal as _, Icon, IconName, Placement, Selectable, Sizable,
+    TabStop as _, WithRemSize,
 };
 
-use super::{ClosePanel, DockArea, Panel, PanelView, StackPanel, ToggleZoom};
+use super::{
+    CloseAllPanels, CloseDecision, CloseOtherPanels, ClosePanel, DockArea, FloatPanel,
+    MoveToOtherPanel, NextTab, Panel, PanelBadge, PanelInfo, PanelState, PanelView, PinPanel,
+    PrevTab, RenamePanel, ResetPanelZoom, SplitDown, SplitRight, StackPanel, ToggleFloating,
+    ToggleZoom, ZoomInPanel, ZoomOutPanel,
+};
 
 #[derive(Debug)]
 pub enum PanelEvent {
     ZoomIn,
     ZoomOut,
+    /// The panels have been moved or resized, e.g.: a tab is dropped into another TabPanel.
+    LayoutChanged,
+    /// The title of a panel has been renamed inline in the tab, see [`Panel::renameable`].
+    Renamed(SharedString),
 }
 
 #[derive(Clone)]
@@ -38,8 +56,8 @@ impl DragPanel {
     }
 }
 
-impl Render for DragPanel {
-    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+impl Draggable for DragPanel {
+    fn render_preview(&self, cx: &mut WindowContext) -> AnyElement {
         div()
             .id("drag-panel")
             .cursor_grab()
@@ -49,15 +67,23 @@ impl Render for DragPanel {
             .overflow_hidden()
             .whitespace_nowrap()
             .border_1()
-            .border_color(cx.theme().border)
+            .border_color(cx.theme().tokens.outline)
             .rounded_md()
             .text_color(cx.theme().tab_foreground)
             .bg(cx.theme().tab_active)
             .opacity(0.75)
             .child(self.panel.title(cx))
+            .into_any_element()
     }
 }
 
+/// The tab that is being renamed inline.
+struct RenamingTab {
+    panel: Arc<dyn PanelView>,
+    input: View<TextInput>,
+    _subscription: Subscription,
+}
+
 pub struct TabPanel {
     focus_handle: FocusHandle,
     dock_area: WeakView<DockArea>,
@@ -67,9 +93,19 @@ pub struct TabPanel {
     tab_bar_scroll_handle: ScrollHandle,
 
     is_zoomed: bool,
+    /// Floating above the docked layout of the [`DockArea`], it can't be split or zoomed in.
+    floating: bool,
 
     /// When drag move, will get the placement of the panel to be split
     will_split_placement: Option<Placement>,
+    renaming: Option<RenamingTab>,
+    /// The bounds in the window of the last paint.
+    bounds: Bounds<Pixels>,
+    /// The scales of the panels' content zoomed by [`ZoomInPanel`] and [`ZoomOutPanel`],
+    /// independent of the [`Theme::scale`] of the whole UI.
+    panel_scales: HashMap<EntityId, f32>,
+
+    _subscriptions: Vec<Subscription>,
 }
 
 impl TabPanel {
@@ -78,15 +114,23 @@ impl TabPanel {
         dock_area: WeakView<DockArea>,
         cx: &mut ViewContext<Self>,
     ) -> Self {
+        let focus_handle = cx.focus_handle();
+        let _subscriptions = vec![cx.on_focus_in(&focus_handle, Self::report_active_panel)];
+
         Self {
-            focus_handle: cx.focus_handle(),
+            focus_handle,
             dock_area,
             stack_panel,
             panels: Vec::new(),
             active_ix: 0,
             tab_bar_scroll_handle: ScrollHandle::new(),
             will_split_placement: None,
+            renaming: None,
+            bounds: Bounds::default(),
+            panel_scales: HashMap::new(),
             is_zoomed: false,
+            floating: false,
+            _subscriptions,
         }
     }
 
@@ -94,17 +138,227 @@ impl TabPanel {
         self.stack_panel = Some(parent);
     }
 
+    pub(super) fn set_floating(&mut self, floating: bool) {
+        self.floating = floating;
+    }
+
+    /// Returns true if it's floating above the docked layout of the [`DockArea`].
+    pub fn is_floating(&self) -> bool {
+        self.floating
+    }
+
+    /// Returns the bounds in the window of the last paint, with the tab bar.
+    pub fn bounds(&self) -> Bounds<Pixels> {
+        self.bounds
+    }
+
+    /// Returns the scale of the panel's content, `1.0` if it's not zoomed.
+    pub fn panel_scale(&self, panel: &Arc<dyn PanelView>) -> f32 {
+        self.panel_scales
+            .get(&panel.view().entity_id())
+            .copied()
+            .unwrap_or(1.0)
+    }
+
+    /// Set the scale of the active panel's content, clamped like the [`Theme::scale`].
+    pub fn set_active_panel_scale(&mut self, scale: f32, cx: &mut ViewContext<Self>) {
+        let Some(panel) = self.active_panel() else {
+            return;
+        };
+
+        let scale = ((scale / Theme::SCALE_STEP).round() * Theme::SCALE_STEP)
+            .clamp(Theme::MIN_SCALE, Theme::MAX_SCALE);
+        let entity_id = panel.view().entity_id();
+        if scale == 1.0 {
+            self.panel_scales.remove(&entity_id);
+        } else {
+            self.panel_scales.insert(entity_id, scale);
+        }
+        cx.notify();
+    }
+
     /// Return current active_panel View
     pub fn active_panel(&self) -> Option<Arc<dyn PanelView>> {
         self.panels.get(self.active_ix).cloned()
     }
 
-    fn set_active_ix(&mut self, ix: usize, cx: &mut ViewContext<Self>) {
+    pub(super) fn set_active_ix(&mut self, ix: usize, cx: &mut ViewContext<Self>) {
         self.active_ix = ix;
         self.tab_bar_scroll_handle.scroll_to_item(ix);
+        if self.focus_handle.contains_focused(cx) {
+            self.report_active_panel(cx);
+        }
         cx.notify();
     }
 
+    /// Focus the TabPanel, then the active tab can be switched by `left` and `right`,
+    /// and `enter` to focus the content of the active panel.
+    pub fn focus(&self, cx: &mut WindowContext) {
+        self.focus_handle.focus(cx);
+    }
+
+    /// Activate the panel at the index and focus the content of it.
+    pub fn focus_panel_at(&mut self, ix: usize, cx: &mut ViewContext<Self>) {
+        if ix >= self.panels.len() {
+            return;
+        }
+
+        self.set_active_ix(ix, cx);
+        self.focus_active_panel(cx);
+    }
+
+    /// Focus the content of the active panel.
+    pub fn focus_active_panel(&self, cx: &mut WindowContext) {
+        if let Some(panel) = self.active_panel() {
+            panel.focus_handle(cx).focus(cx);
+        }
+    }
+
+    /// Zoom in to fill the dock area, or zoom out if it has been zoomed in.
+    pub fn toggle_zoom(&mut self, cx: &mut ViewContext<Self>) {
+        self.on_action_toggle_zoom(&ToggleZoom, cx);
+    }
+
+    /// Start to rename the title of the panel at the index inline, return `false` if it can't be renamed,
+    /// see [`Panel::can_rename`].
+    pub fn start_rename(&mut self, ix: usize, cx: &mut ViewContext<Self>) -> bool {
+        let Some(panel) = self.panels.get(ix).cloned() else {
+            return false;
+        };
+        if self.renaming.is_some() || !panel.can_rename(cx) {
+            return false;
+        }
+
+        let title = panel.title(cx);
+        let input = cx.new_view(|cx| {
+            let mut input = TextInput::new(cx).appearance(false).xsmall();
+            input.set_text(title, cx);
+            input
+        });
+        let _subscription = cx.subscribe(&input, |this, _, event: &InputEvent, cx| match event {
+            InputEvent::PressEnter | InputEvent::Blur => this.finish_rename(true, cx),
+            _ => {}
+        });
+        input.update(cx, |input, cx| input.focus(cx));
+
+        self.renaming = Some(RenamingTab {
+            panel,
+            input,
+            _subscription,
+        });
+        cx.notify();
+        true
+    }
+
+    /// Finish the inline rename, the title is applied if `confirm` and it has been changed.
+    fn finish_rename(&mut self, confirm: bool, cx: &mut ViewContext<Self>) {
+        let Some(renaming) = self.renaming.take() else {
+            return;
+        };
+
+        let title: SharedString = renaming.input.read(cx).text().trim().to_string().into();
+        if confirm && !title.is_empty() && title != renaming.panel.title(cx) {
+            renaming.panel.rename(title.clone(), cx);
+            cx.emit(PanelEvent::Renamed(title));
+        }
+
+        self.focus_handle.focus(cx);
+        cx.notify();
+    }
+
+    /// Rename the panel by double-click if it is renameable, otherwise toggle the zoom.
+    fn on_double_click_tab(&mut self, ix: usize, cx: &mut ViewContext<Self>) {
+        if self.renaming.is_some() {
+            return;
+        }
+
+        let renameable = self
+            .panels
+            .get(ix)
+            .map_or(false, |panel| panel.renameable(cx));
+        if !renameable || !self.start_rename(ix, cx) {
+            self.on_action_toggle_zoom(&ToggleZoom, cx);
+        }
+    }
+
+    /// The input to rename the title inline, if the panel is being renamed.
+    fn render_rename_input(
+        &self,
+        panel: &Arc<dyn PanelView>,
+        cx: &mut ViewContext<Self>,
+    ) -> Option<impl IntoElement> {
+        let renaming = self.renaming.as_ref()?;
+        if renaming.panel.view() != panel.view() {
+            return None;
+        }
+
+        Some(
+            div()
+                .w_32()
+                .child(renaming.input.clone())
+                // Select the text in the input, without dragging or double-clicking the tab.
+                .on_mouse_down(MouseButton::Left, |_, cx| cx.stop_propagation())
+                // Escape to cancel, before it is dispatched to the Root to close the modal.
+                .on_key_down(cx.listener(|this, event: &KeyDownEvent, cx| {
+                    if event.keystroke.key == "escape" {
+                        this.finish_rename(false, cx);
+                        cx.stop_propagation();
+                    }
+                })),
+        )
+    }
+
+    fn on_key_down(&mut self, event: &KeyDownEvent, cx: &mut ViewContext<Self>) {
+        // Only handle the keys when the TabPanel itself is focused, not the content.
+        if !self.focus_handle.is_focused(cx) {
+            return;
+        }
+
+        match event.keystroke.key.as_str() {
+            "left" => self.on_action_prev_tab(&PrevTab, cx),
+            "right" => self.on_action_next_tab(&NextTab, cx),
+            "enter" => self.focus_active_panel(cx),
+            _ => return,
+        }
+        cx.stop_propagation();
+    }
+
+    /// Tell the DockArea the active panel, called when focused or the active tab is changed.
+    fn report_active_panel(&mut self, cx: &mut ViewContext<Self>) {
+        // Deferred, because this may be called in the update of the DockArea.
+        cx.defer(|this, cx| {
+            let panel = this.active_panel();
+            let tab_panel = cx.view().downgrade();
+            let _ = this.dock_area.update(cx, |dock_area, cx| {
+                dock_area.set_active_panel(panel, tab_panel, cx)
+            });
+        });
+    }
+
+    /// Return all panels in the tabs.
+    pub fn panels(&self) -> &[Arc<dyn PanelView>] {
+        &self.panels
+    }
+
+    /// Activate the panel if it is in the tabs, return `false` if not found.
+    pub fn activate_panel(
+        &mut self,
+        panel: &Arc<dyn PanelView>,
+        cx: &mut ViewContext<Self>,
+    ) -> bool {
+        let entity_id = panel.view().entity_id();
+        if let Some(ix) = self
+            .panels
+            .iter()
+            .position(|p| p.view().entity_id() == entity_id)
+        {
+            self.set_active_ix(ix, cx);
+            true
+        } else {
+            false
+        }
+    }
+
     /// Add a panel to the end of the tabs
     pub fn add_panel(&mut self, panel: Arc<dyn PanelView>, cx: &mut ViewContext<Self>) {
         if self
@@ -143,7 +397,7 @@ impl TabPanel {
         cx.notify();
     }
 
-    fn insert_panel_at(
+    pub(super) fn insert_panel_at(
         &mut self,
         panel: Arc<dyn PanelView>,
         ix: usize,
@@ -168,16 +422,110 @@ impl TabPanel {
         self.remove_self_if_empty(cx)
     }
 
-    fn detach_panel(&mut self, panel: Arc<dyn PanelView>, cx: &mut ViewContext<Self>) {
+    /// Close the panel by the user, the panel can intercept it by [`Panel::can_close`].
+    pub fn close_panel(&mut self, panel: Arc<dyn PanelView>, cx: &mut ViewContext<Self>) {
+        match panel.can_close(cx) {
+            CloseDecision::Close => {
+                self.detach_closed_panel(panel, cx);
+                self.remove_self_if_empty(cx);
+            }
+            CloseDecision::Confirm(message) => self.confirm_close(panel, message, cx),
+            CloseDecision::Keep => {}
+        }
+    }
+
+    /// Open a Modal to ask for Save / Discard / Cancel before closing the panel.
+    fn confirm_close(
+        &mut self,
+        panel: Arc<dyn PanelView>,
+        message: SharedString,
+        cx: &mut ViewContext<Self>,
+    ) {
+        self.activate_panel(&panel, cx);
+        let tab_panel = cx.view().downgrade();
+        let title = panel.title(cx);
+
+        cx.open_modal(move |modal, cx| {
+            let close = {
+                let panel = panel.clone();
+                let tab_panel = tab_panel.clone();
+                move |save: bool, cx: &mut WindowContext| {
+                    cx.close_modal();
+                    if save {
+                        panel.save(cx);
+                    }
+                    let _ = tab_panel.update(cx, |view, cx| {
+                        view.detach_closed_panel(panel.clone(), cx);
+                        view.remove_self_if_empty(cx);
+                    });
+                }
+            };
+
+            modal
+                .title(title.clone())
+                .accessible_label(title.clone())
+                .width(px(400.))
+                .child(message.clone())
+                .footer(
+                    h_flex()
+                        .gap_2()
+                        .child(
+                            Button::new("save", cx)
+                                .primary()
+                                .label(t!("Dock.Save"))
+                                .on_click({
+                                    let close = close.clone();
+                                    move |_, cx| close(true, cx)
+                                }),
+                        )
+                        .child(
+                            Button::new("discard", cx)
+                                .danger()
+                                .label(t!("Dock.Discard"))
+                                .on_click(move |_, cx| close(false, cx)),
+                        )
+                        .child(
+                            Button::new("cancel", cx)
+                                .label(t!("Dock.Cancel"))
+                                .on_click(|_, cx| cx.close_modal()),
+                        ),
+                )
+        });
+    }
+
+    pub(super) fn detach_panel(&mut self, panel: Arc<dyn PanelView>, cx: &mut ViewContext<Self>) {
         let panel_view = panel.view();
+        self.panel_scales.remove(&panel_view.entity_id());
         self.panels.retain(|p| p.view() != panel_view);
+        // Deferred, because this may be called in the update of the DockArea.
+        cx.defer(move |this, cx| {
+            let _ = this.dock_area.update(cx, |dock_area, cx| {
+                dock_area.remove_active_panel(&panel_view, cx)
+            });
+        });
         if self.active_ix >= self.panels.len() {
             self.set_active_ix(self.panels.len().saturating_sub(1), cx)
+        } else if self.focus_handle.contains_focused(cx) {
+            self.report_active_panel(cx);
         }
     }
 
+    /// Detach the panel closed by the user, and remember its state and position in the [`DockArea`]
+    /// to be reopened by [`DockArea::reopen_closed_panel`].
+    fn detach_closed_panel(&mut self, panel: Arc<dyn PanelView>, cx: &mut ViewContext<Self>) {
+        if let Some(ix) = self.panels.iter().position(|p| p.view() == panel.view()) {
+            let state = panel.dump(cx);
+            let tab_panel = cx.view().downgrade();
+            let _ = self.dock_area.update(cx, |dock_area, _| {
+                dock_area.push_closed_panel(state, tab_panel, ix)
+            });
+        }
+
+        self.detach_panel(panel, cx);
+    }
+
     /// Check to remove self from the parent StackPanel, if there is no panel left
-    fn remove_self_if_empty(&self, cx: &mut ViewContext<Self>) {
+    pub(super) fn remove_self_if_empty(&self, cx: &mut ViewContext<Self>) {
         if !self.panels.is_empty() {
             return;
         }
@@ -190,9 +538,85 @@ impl TabPanel {
         }
     }
 
+    /// Return the next TabPanel in the DockArea, used to move the panel to.
+    fn other_tab_panel(&self, cx: &mut ViewContext<Self>) -> Option<View<TabPanel>> {
+        let dock_area = self.dock_area.upgrade()?;
+        let tab_panels = dock_area.read(cx).tab_panels(cx);
+        if tab_panels.len() < 2 {
+            return None;
+        }
+
+        let ix = tab_panels.iter().position(|p| p == cx.view())?;
+        tab_panels.get((ix + 1) % tab_panels.len()).cloned()
+    }
+
+    /// Build the context menu of the tab, the actions will be dispatched to this TabPanel.
+    fn tab_context_menu(
+        &self,
+        panel: &Arc<dyn PanelView>,
+        cx: &mut ViewContext<Self>,
+    ) -> impl Fn(PopupMenu, &mut ViewContext<PopupMenu>) -> PopupMenu + 'static {
+        let focus_handle = self.focus_handle.clone();
+        let closeable = panel.closeable(cx);
+        let closeable_others = self
+            .panels
+            .iter()
+            .any(|p| p.view() != panel.view() && p.closeable(cx));
+        let closeable_all = closeable || closeable_others;
+        let can_rename = panel.can_rename(cx);
+        let floating = self.floating;
+        let splittable = !floating && self.panels.len() > 1;
+        let movable = splittable || self.other_tab_panel(cx).is_some();
+        let floatable = self
+            .dock_area
+            .upgrade()
+            .map_or(false, |dock_area| dock_area.read(cx).is_floatable());
+
+        move |menu, _| {
+            menu.track_focus(&focus_handle)
+                .when(can_rename, |this| {
+                    this.menu(t!("Dock.Rename"), Box::new(RenamePanel))
+                        .separator()
+                })
+                .when(closeable, |this| {
+                    this.menu(t!("Dock.Close"), Box::new(ClosePanel))
+                })
+                .when(closeable_others, |this| {
+                    this.menu(t!("Dock.Close Others"), Box::new(CloseOtherPanels))
+                })
+                .when(closeable_all, |this| {
+                    this.menu(t!("Dock.Close All"), Box::new(CloseAllPanels))
+                })
+                .when(movable, |this| {
+                    this.when(closeable_all, |this| this.separator())
+                        .menu(t!("Dock.Move to Other Panel"), Box::new(MoveToOtherPanel))
+                })
+                .when(splittable, |this| {
+                    this.menu(t!("Dock.Split Right"), Box::new(SplitRight))
+                        .menu(t!("Dock.Split Down"), Box::new(SplitDown))
+                })
+                .when(closeable_all && !movable, |this| this.separator())
+                .map(|this| {
+                    if floating {
+                        this.menu(t!("Dock.Dock Panel"), Box::new(ToggleFloating))
+                    } else {
+                        this.menu(t!("Dock.Float Panel"), Box::new(ToggleFloating))
+                    }
+                })
+                .when(floatable, |this| {
+                    this.menu(t!("Dock.Float"), Box::new(FloatPanel))
+                        .menu(t!("Dock.Picture in Picture"), Box::new(PinPanel))
+                })
+        }
+    }
+
     fn render_menu_button(&self, cx: &mut ViewContext<Self>) -> impl IntoElement {
         let is_zoomed = self.is_zoomed;
         let closeable = self.closeable(cx);
+        let floating = self.floating;
+        let scale = self
+            .active_panel()
+            .map_or(1.0, |panel| self.panel_scale(&panel));
         let view = cx.view().clone();
         let build_popup_menu = move |this, cx: &WindowContext| view.read(cx).popup_menu(this, cx);
 
@@ -200,6 +624,18 @@ impl TabPanel {
             .gap_2()
             .occlude()
             .items_center()
+            .when(scale != 1.0, |this| {
+                this.child(
+                    Button::new("reset-panel-zoom", cx)
+                        .label(format!("{}%", (scale * 100.).round()))
+                        .xsmall()
+                        .ghost()
+                        .tooltip(t!("Dock.Reset Panel Zoom"))
+                        .on_click(cx.listener(|view, _, cx| {
+                            view.on_action_reset_panel_zoom(&ResetPanelZoom, cx)
+                        })),
+                )
+            })
             .when(self.is_zoomed, |this| {
                 this.child(
                     Button::new("zoom", cx)
@@ -219,14 +655,16 @@ impl TabPanel {
                     .ghost()
                     .popup_menu(move |this, cx| {
                         build_popup_menu(this, cx)
-                            .menu(
-                                if is_zoomed {
-                                    t!("Dock.Zoom Out")
-                                } else {
-                                    t!("Dock.Zoom In")
-                                },
-                                Box::new(ToggleZoom),
-                            )
+                            .when(!floating, |this| {
+                                this.menu(
+                                    if is_zoomed {
+                                        t!("Dock.Zoom Out")
+                                    } else {
+                                        t!("Dock.Zoom In")
+                                    },
+                                    Box::new(ToggleZoom),
+                                )
+                            })
                             .when(closeable, |this| {
                                 this.separator()
                                     .menu(t!("Dock.Close"), Box::new(ClosePanel))
@@ -236,12 +674,45 @@ impl TabPanel {
             )
     }
 
+    fn render_badge(badge: PanelBadge, cx: &WindowContext) -> impl IntoElement {
+        h_flex()
+            .flex_none()
+            .justify_center()
+            .rounded_full()
+            .bg(cx.theme().tokens.accent)
+            .text_color(cx.theme().tokens.on_accent)
+            .map(|this| match badge {
+                PanelBadge::Count(count) => this
+                    .h(px(16.))
+                    .min_w(px(16.))
+                    .px_1()
+                    .text_size(px(10.))
+                    .child(if count > 99 {
+                        "99+".to_string()
+                    } else {
+                        count.to_string()
+                    }),
+                PanelBadge::Dot => this.size(px(6.)),
+            })
+    }
+
     fn render_tabs(&self, cx: &mut ViewContext<Self>) -> impl IntoElement {
         let view = cx.view().clone();
+        // Show the focus ring on the active tab when the TabPanel itself is focused by keyboard.
+        let focused = self.focus_handle.is_focused(cx);
 
         if self.panels.len() == 1 {
             let panel = self.panels.get(0).unwrap();
             let title = panel.title(cx);
+            let icon = panel.icon(cx);
+            let badge = panel.badge(cx);
+            let renameable = panel.renameable(cx);
+            let rename_input = self.render_rename_input(panel, cx);
+            // The single tab is not a `Tab`, so report it as the selected tab here.
+            register_accessible(
+                AccessibleNode::new(AccessibleRole::Tab, Some(title.clone())).selected(true),
+                cx,
+            );
 
             return h_flex()
                 .justify_between()
@@ -255,19 +726,54 @@ impl TabPanel {
                         .px_3()
                         .min_w_16()
                         .overflow_hidden()
-                        .text_ellipsis()
-                        .child(title.clone())
-                        .tooltip(move |cx| Tooltip::new(title.clone(), cx))
-                        .on_drag(
-                            DragPanel {
-                                panel: panel.clone(),
-                                tab_panel: view,
-                            },
-                            |drag, cx| {
-                                cx.stop_propagation();
-                                cx.new_view(|_| drag.clone())
+                        .child(
+                            h_flex()
+                                .gap_1()
+                                .when_some(icon, |this, icon| this.child(Icon::new(icon).small()))
+                                .map(|this| match rename_input {
+                                    Some(input) => this.child(input),
+                                    None => this.child(
+                                        div()
+                                            .overflow_hidden()
+                                            .text_ellipsis()
+                                            .child(title.clone()),
+                                    ),
+                                })
+                                .when_some(badge, |this, badge| {
+                                    this.child(Self::render_badge(badge, cx))
+                                }),
+                        )
+                        .on_click(cx.listener(|view, event: &ClickEvent, cx| {
+                            if event.up.click_count == 2 {
+                                view.on_double_click_tab(0, cx);
+                            }
+                        }))
+                        .when(focused, |this| this.relative().child(inner_focus_ring(cx)))
+                        .tooltip(move |cx| {
+                            if renameable {
+                                Tooltip::with_meta(
+                                    title.clone(),
+                                    Some(&RenamePanel),
+                                    t!("Dock.Double-click to rename").to_string(),
+                                    cx,
+                                )
+                            } else {
+                                Tooltip::with_meta(
+                                    title.clone(),
+                                    Some(&ToggleZoom),
+                                    t!("Dock.Double-click to zoom").to_string(),
+                                    cx,
+                                )
+                            }
+                        })
+                        .context_menu(self.tab_context_menu(panel, cx))
+                        .drag_source(DragPanel::new(panel.clone(), view))
+                        .drop_target(DropTarget::new(cx.listener(
+                            |this, drag: &DragPanel, cx| {
+                                this.will_split_placement = None;
+                                this.on_drop(drag, None, cx)
                             },
-                        ),
+                        ))),
                 )
                 .child(self.render_menu_button(cx))
                 .into_any_element();
@@ -279,26 +785,45 @@ impl TabPanel {
             .track_scroll(self.tab_bar_scroll_handle.clone())
             .children(self.panels.iter().enumerate().map(|(ix, panel)| {
                 let active = ix == self.active_ix;
-                Tab::new(("tab", ix), panel.title(cx))
+                let label = match self.render_rename_input(panel, cx) {
+                    Some(input) => input.into_any_element(),
+                    None => panel.title(cx).into_any_element(),
+                };
+                Tab::new(("tab", ix), label)
+                    .accessible_label(panel.title(cx))
                     .py_2()
                     .selected(active)
-                    .on_click(cx.listener(move |view, _, cx| {
-                        view.set_active_ix(ix, cx);
-                    }))
-                    .on_drag(DragPanel::new(panel.clone(), view.clone()), |drag, cx| {
-                        cx.stop_propagation();
-                        cx.new_view(|_| drag.clone())
+                    .when_some(panel.icon(cx), |this, icon| {
+                        this.prefix(Icon::new(icon).small().into_any_element())
                     })
-                    .drag_over::<DragPanel>(|this, _, cx| {
-                        this.rounded_l_none()
-                            .border_l_2()
-                            .border_r_0()
-                            .border_color(cx.theme().drag_border)
+                    .when_some(panel.badge(cx), |this, badge| {
+                        this.suffix(Self::render_badge(badge, cx).into_any_element())
                     })
-                    .on_drop(cx.listener(move |this, drag: &DragPanel, cx| {
-                        this.will_split_placement = None;
-                        this.on_drop(drag, Some(ix), cx)
+                    .when(active && focused, |this| {
+                        this.relative().child(inner_focus_ring(cx))
+                    })
+                    .on_click(cx.listener(move |view, event: &ClickEvent, cx| {
+                        view.set_active_ix(ix, cx);
+                        // Double click to rename the panel, or zoom the TabPanel to fill the DockArea, or back.
+                        if event.up.click_count == 2 {
+                            view.on_double_click_tab(ix, cx);
+                        }
                     }))
+                    // Activate the tab before the context menu is opened, the menu actions apply to the active panel.
+                    .capture_any_mouse_down(cx.listener(move |view, event: &MouseDownEvent, cx| {
+                        if event.button == MouseButton::Right {
+                            view.set_active_ix(ix, cx);
+                        }
+                    }))
+                    .context_menu(self.tab_context_menu(panel, cx))
+                    .drag_source(DragPanel::new(panel.clone(), view.clone()))
+                    .drop_target(
+                        DropTarget::new(cx.listener(move |this, drag: &DragPanel, cx| {
+                            this.will_split_placement = None;
+                            this.on_drop(drag, Some(ix), cx)
+                        }))
+                        .indicator(|_, _| DropIndicator::Line(Placement::Left)),
+                    )
             }))
             .child(
                 // empty space to allow move to last tab right
@@ -307,18 +832,19 @@ impl TabPanel {
                     .h_full()
                     .flex_grow()
                     .min_w_16()
-                    .drag_over::<DragPanel>(|this, _, cx| this.bg(cx.theme().drop_target))
-                    .on_drop(cx.listener(move |this, drag: &DragPanel, cx| {
-                        this.will_split_placement = None;
+                    .drop_target(DropTarget::new(cx.listener(
+                        move |this, drag: &DragPanel, cx| {
+                            this.will_split_placement = None;
 
-                        let ix = if drag.tab_panel == view {
-                            Some(tabs_count - 1)
-                        } else {
-                            None
-                        };
+                            let ix = if drag.tab_panel == view {
+                                Some(tabs_count - 1)
+                            } else {
+                                None
+                            };
 
-                        this.on_drop(drag, ix, cx)
-                    })),
+                            this.on_drop(drag, ix, cx)
+                        },
+                    ))),
             )
             .suffix(
                 h_flex()
@@ -328,7 +854,7 @@ impl TabPanel {
                     .border_l_1()
                     .border_b_1()
                     .h_full()
-                    .border_color(cx.theme().border)
+                    .border_color(cx.theme().tokens.outline)
                     .bg(cx.theme().tab_bar)
                     .px_3()
                     .child(self.render_menu_button(cx)),
@@ -339,13 +865,27 @@ impl TabPanel {
     fn render_active_panel(&self, cx: &mut ViewContext<Self>) -> impl IntoElement {
         self.active_panel()
             .map(|panel| {
+                let scale = self.panel_scale(&panel);
+                let content = perf_scope(panel.title(cx), panel.view());
+
                 div()
                     .id("tab-content")
                     .group("")
                     .overflow_y_scroll()
                     .overflow_x_hidden()
                     .flex_1()
-                    .child(panel.view())
+                    .map(|this| {
+                        // Keep the content as it is without the zoom, the wrapper changes the layout.
+                        if scale == 1.0 {
+                            this.child(content)
+                        } else {
+                            this.child(
+                                WithRemSize::new(cx.rem_size() * scale)
+                                    .size_full()
+                                    .child(content),
+                            )
+                        }
+                    })
                     .on_drag_move(cx.listener(Self::on_panel_drag_move))
                     .child(
                         div()
@@ -369,9 +909,13 @@ impl TabPanel {
                                 None => this.top_0().left_0().size_full(),
                             })
                             .group_drag_over::<DragPanel>("", |this| this.visible())
-                            .on_drop(cx.listener(|this, drag: &DragPanel, cx| {
-                                this.on_drop(drag, None, cx)
-                            })),
+                            // Shown by the group, it's already filled with the drop target color.
+                            .drop_target(
+                                DropTarget::new(cx.listener(|this, drag: &DragPanel, cx| {
+                                    this.on_drop(drag, None, cx)
+                                }))
+                                .indicator(|_, _| DropIndicator::None),
+                            ),
                     )
                     .into_any_element()
             })
@@ -383,6 +927,16 @@ impl TabPanel {
         let bounds = drag.bounds;
         let position = drag.event.position;
 
+        // The drag move event is received by all the TabPanels, skip if the mouse is not over this one.
+        // The floating TabPanels can't be split, the panel is dropped into the tabs.
+        if self.floating || !bounds.contains(&position) {
+            if self.will_split_placement.is_some() {
+                self.will_split_placement = None;
+                cx.notify();
+            }
+            return;
+        }
+
         // Check the mouse position to determine the split direction
         if position.x < bounds.left() + bounds.size.width * 0.35 {
             self.will_split_placement = Some(Placement::Left);
@@ -438,6 +992,7 @@ impl TabPanel {
             }
         }
 
+        cx.emit(PanelEvent::LayoutChanged);
         self.remove_self_if_empty(cx);
     }
 
@@ -517,7 +1072,29 @@ impl TabPanel {
         }
     }
 
+    fn on_action_zoom_in_panel(&mut self, _: &ZoomInPanel, cx: &mut ViewContext<Self>) {
+        let Some(panel) = self.active_panel() else {
+            return;
+        };
+        self.set_active_panel_scale(self.panel_scale(&panel) + Theme::SCALE_STEP, cx);
+    }
+
+    fn on_action_zoom_out_panel(&mut self, _: &ZoomOutPanel, cx: &mut ViewContext<Self>) {
+        let Some(panel) = self.active_panel() else {
+            return;
+        };
+        self.set_active_panel_scale(self.panel_scale(&panel) - Theme::SCALE_STEP, cx);
+    }
+
+    fn on_action_reset_panel_zoom(&mut self, _: &ResetPanelZoom, cx: &mut ViewContext<Self>) {
+        self.set_active_panel_scale(1.0, cx);
+    }
+
     fn on_action_toggle_zoom(&mut self, _: &ToggleZoom, cx: &mut ViewContext<Self>) {
+        if self.floating {
+            return;
+        }
+
         self.is_zoomed = !self.is_zoomed;
         if self.is_zoomed {
             cx.emit(PanelEvent::ZoomIn)
@@ -526,14 +1103,168 @@ impl TabPanel {
         }
     }
 
+    fn on_action_rename_panel(&mut self, _: &RenamePanel, cx: &mut ViewContext<Self>) {
+        self.start_rename(self.active_ix, cx);
+    }
+
+    fn on_action_float_panel(&mut self, _: &FloatPanel, cx: &mut ViewContext<Self>) {
+        let Some(panel) = self.active_panel() else {
+            return;
+        };
+        let Some(dock_area) = self.dock_area.upgrade() else {
+            return;
+        };
+
+        self.detach_panel(panel.clone(), cx);
+        self.remove_self_if_empty(cx);
+        dock_area.update(cx, |view, cx| view.float_panel(panel, cx));
+    }
+
+    fn on_action_pin_panel(&mut self, _: &PinPanel, cx: &mut ViewContext<Self>) {
+        let Some(panel) = self.active_panel() else {
+            return;
+        };
+        let Some(dock_area) = self.dock_area.upgrade() else {
+            return;
+        };
+
+        self.detach_panel(panel.clone(), cx);
+        self.remove_self_if_empty(cx);
+        dock_area.update(cx, |view, cx| view.pin_panel(panel, cx));
+    }
+
+    /// Float the active panel above the docked layout, or dock it back if this TabPanel is floating.
+    fn on_action_toggle_floating(&mut self, _: &ToggleFloating, cx: &mut ViewContext<Self>) {
+        let Some(panel) = self.active_panel() else {
+            return;
+        };
+        let Some(dock_area) = self.dock_area.upgrade() else {
+            return;
+        };
+
+        self.detach_panel(panel.clone(), cx);
+        if self.floating {
+            dock_area.update(cx, |view, cx| view.dock_panel(panel, cx));
+        } else {
+            self.remove_self_if_empty(cx);
+            dock_area.update(cx, |view, cx| view.float_panel_in_area(panel, cx));
+        }
+    }
+
+    fn on_action_next_tab(&mut self, _: &NextTab, cx: &mut ViewContext<Self>) {
+        if self.panels.is_empty() {
+            return;
+        }
+
+        self.set_active_ix((self.active_ix + 1) % self.panels.len(), cx);
+    }
+
+    fn on_action_prev_tab(&mut self, _: &PrevTab, cx: &mut ViewContext<Self>) {
+        if self.panels.is_empty() {
+            return;
+        }
+
+        let len = self.panels.len();
+        self.set_active_ix((self.active_ix + len - 1) % len, cx);
+    }
+
     fn on_action_close_panel(&mut self, _: &ClosePanel, cx: &mut ViewContext<Self>) {
         if let Some(panel) = self.active_panel() {
-            self.remove_panel(panel, cx);
+            self.close_panel(panel, cx);
+        }
+    }
+
+    fn on_action_close_other_panels(&mut self, _: &CloseOtherPanels, cx: &mut ViewContext<Self>) {
+        let Some(active_panel) = self.active_panel() else {
+            return;
+        };
+
+        let panels = self
+            .panels
+            .iter()
+            .filter(|p| p.view() != active_panel.view() && p.closeable(cx))
+            .cloned()
+            .collect::<Vec<_>>();
+        self.close_panels(panels, cx);
+        self.activate_panel(&active_panel, cx);
+    }
+
+    fn on_action_close_all_panels(&mut self, _: &CloseAllPanels, cx: &mut ViewContext<Self>) {
+        let panels = self
+            .panels
+            .iter()
+            .filter(|p| p.closeable(cx))
+            .cloned()
+            .collect::<Vec<_>>();
+        self.close_panels(panels, cx);
+    }
+
+    /// Close the panels that can be closed directly, then ask for the first one that needs to confirm.
+    fn close_panels(&mut self, panels: Vec<Arc<dyn PanelView>>, cx: &mut ViewContext<Self>) {
+        let mut confirm = None;
+        for panel in panels {
+            match panel.can_close(cx) {
+                CloseDecision::Close => self.detach_closed_panel(panel, cx),
+                CloseDecision::Confirm(message) => {
+                    confirm.get_or_insert((panel, message));
+                }
+                CloseDecision::Keep => {}
+            }
+        }
+
+        if let Some((panel, message)) = confirm {
+            self.confirm_close(panel, message, cx);
+        } else {
+            self.remove_self_if_empty(cx);
+        }
+    }
+
+    /// Move the active panel to the next TabPanel, or split to the right if there is no other TabPanel.
+    fn on_action_move_to_other_panel(&mut self, _: &MoveToOtherPanel, cx: &mut ViewContext<Self>) {
+        let Some(panel) = self.active_panel() else {
+            return;
+        };
+
+        if let Some(tab_panel) = self.other_tab_panel(cx) {
+            self.detach_panel(panel.clone(), cx);
+            tab_panel.update(cx, |view, cx| view.add_panel(panel, cx));
+            self.remove_self_if_empty(cx);
+        } else if !self.floating && self.panels.len() > 1 {
+            self.detach_panel(panel.clone(), cx);
+            self.split_panel(panel, Placement::Right, None, cx);
         }
     }
+
+    /// Move the active panel into a new TabPanel at the placement of this one.
+    ///
+    /// Nothing happens if there is only one panel, because it would leave this TabPanel empty.
+    fn split_active_panel(&mut self, placement: Placement, cx: &mut ViewContext<Self>) {
+        if self.floating || self.panels.len() < 2 {
+            return;
+        }
+        let Some(panel) = self.active_panel() else {
+            return;
+        };
+
+        self.detach_panel(panel.clone(), cx);
+        self.split_panel(panel.clone(), placement, None, cx);
+        panel.focus_handle(cx).focus(cx);
+    }
+
+    fn on_action_split_right(&mut self, _: &SplitRight, cx: &mut ViewContext<Self>) {
+        self.split_active_panel(Placement::Right, cx);
+    }
+
+    fn on_action_split_down(&mut self, _: &SplitDown, cx: &mut ViewContext<Self>) {
+        self.split_active_panel(Placement::Bottom, cx);
+    }
 }
 
 impl Panel for TabPanel {
+    fn panel_name(&self) -> &'static str {
+        "TabPanel"
+    }
+
     fn title(&self, cx: &WindowContext) -> gpui::SharedString {
         self.active_panel()
             .map(|panel| panel.title(cx))
@@ -553,6 +1284,16 @@ impl Panel for TabPanel {
             menu
         }
     }
+
+    fn dump(&self, cx: &AppContext) -> PanelState {
+        PanelState {
+            panel_name: self.panel_name().to_string(),
+            children: self.panels.iter().map(|panel| panel.dump(cx)).collect(),
+            info: PanelInfo::Tabs {
+                active_index: self.active_ix,
+            },
+        }
+    }
 }
 impl FocusableView for TabPanel {
     fn focus_handle(&self, _: &AppContext) -> gpui::FocusHandle {
@@ -563,15 +1304,41 @@ impl EventEmitter<DismissEvent> for TabPanel {}
 impl EventEmitter<PanelEvent> for TabPanel {}
 impl Render for TabPanel {
     fn render(&mut self, cx: &mut ViewContext<Self>) -> impl gpui::IntoElement {
+        let view = cx.view().clone();
+
         v_flex()
             .id("tab-panel")
-            .track_focus(&self.focus_handle)
+            .key_context("TabPanel")
+            .tab_stop(&self.focus_handle, cx)
+            .on_key_down(cx.listener(Self::on_key_down))
             .on_action(cx.listener(Self::on_action_toggle_zoom))
+            .on_action(cx.listener(Self::on_action_zoom_in_panel))
+            .on_action(cx.listener(Self::on_action_zoom_out_panel))
+            .on_action(cx.listener(Self::on_action_reset_panel_zoom))
             .on_action(cx.listener(Self::on_action_close_panel))
+            .on_action(cx.listener(Self::on_action_close_other_panels))
+            .on_action(cx.listener(Self::on_action_close_all_panels))
+            .on_action(cx.listener(Self::on_action_move_to_other_panel))
+            .on_action(cx.listener(Self::on_action_float_panel))
+            .on_action(cx.listener(Self::on_action_pin_panel))
+            .on_action(cx.listener(Self::on_action_toggle_floating))
+            .on_action(cx.listener(Self::on_action_rename_panel))
+            .on_action(cx.listener(Self::on_action_next_tab))
+            .on_action(cx.listener(Self::on_action_prev_tab))
+            .on_action(cx.listener(Self::on_action_split_right))
+            .on_action(cx.listener(Self::on_action_split_down))
             .size_full()
             .overflow_hidden()
-            .bg(cx.theme().background)
+            .bg(cx.theme().tokens.surface)
             .child(self.render_tabs(cx))
             .child(self.render_active_panel(cx))
+            .child(
+                canvas(
+                    move |bounds, cx| view.update(cx, |this, _| this.bounds = bounds),
+                    |_, _, _| {},
+                )
+                .absolute()
+                .size_full(),
+            )
     }
 }
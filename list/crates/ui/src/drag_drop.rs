@@ -0,0 +1,144 @@
+//! The typed drag and drop on top of the gpui drags, used by the tabs, the list items,
+//! the table headers, the kanban cards and the dropped files.
+//!
+//! - A [`Draggable`] payload is dragged from a [`DragDropExt::drag_source`], with its preview.
+//! - A [`DropTarget`] accepts the payloads of a type by the [`DragDropExt::drop_target`],
+//!   and shows a [`DropIndicator`] while one is dragged over it.
+//!
+//! The payloads of the files dropped from the OS are the [`gpui::ExternalPaths`].
+
+use std::rc::Rc;
+
+use gpui::{
+    AnyElement, InteractiveElement, IntoElement, Render, StatefulInteractiveElement,
+    StyleRefinement, Styled as _, ViewContext, VisualContext as _, WindowContext,
+};
+
+use crate::{theme::ActiveTheme as _, Placement};
+
+/// The payload of a drag, the type of it decides which [`DropTarget`]s accept it.
+pub trait Draggable: Clone + 'static {
+    /// Render the preview that follows the mouse while dragging.
+    fn render_preview(&self, cx: &mut WindowContext) -> AnyElement;
+}
+
+/// The view of the preview of a [`Draggable`] while dragging.
+pub struct DragPreview<T: Draggable>(T);
+
+impl<T: Draggable> Render for DragPreview<T> {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        self.0.render_preview(cx)
+    }
+}
+
+/// How a [`DropTarget`] shows a payload is dragged over it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DropIndicator {
+    None,
+    /// Fill the target with the drop target color.
+    Highlight,
+    /// Fill the target and draw a border around it, e.g.: the edges of the dock.
+    Outline,
+    /// Draw a line on the side that the payload will be inserted to, e.g.: between the items.
+    ///
+    /// The line replaces the border of the opposite side, to keep the size of the bordered targets.
+    Line(Placement),
+}
+
+impl DropIndicator {
+    fn apply(self, style: StyleRefinement, cx: &WindowContext) -> StyleRefinement {
+        let theme = cx.theme();
+        match self {
+            Self::None => style,
+            Self::Highlight => style.bg(theme.drop_target),
+            Self::Outline => style
+                .bg(theme.drop_target)
+                .border_1()
+                .border_color(theme.drag_border),
+            Self::Line(placement) => match placement {
+                Placement::Top => style.rounded_t_none().border_t_2().border_b_0(),
+                Placement::Bottom => style.rounded_b_none().border_b_2().border_t_0(),
+                Placement::Left => style.rounded_l_none().border_l_2().border_r_0(),
+                Placement::Right => style.rounded_r_none().border_r_2().border_l_0(),
+            }
+            .border_color(theme.drag_border),
+        }
+    }
+}
+
+/// The target to drop the payloads of the type `T` to.
+pub struct DropTarget<T: 'static> {
+    indicator: Rc<dyn Fn(&T, &WindowContext) -> DropIndicator>,
+    can_drop: Option<Rc<dyn Fn(&T, &WindowContext) -> bool>>,
+    on_drop: Rc<dyn Fn(&T, &mut WindowContext)>,
+}
+
+impl<T: 'static> DropTarget<T> {
+    /// Create a drop target with the handler of the dropped payload, it's highlighted while dragged over.
+    pub fn new(on_drop: impl Fn(&T, &mut WindowContext) + 'static) -> Self {
+        Self {
+            indicator: Rc::new(|_, _| DropIndicator::Highlight),
+            can_drop: None,
+            on_drop: Rc::new(on_drop),
+        }
+    }
+
+    /// Set the indicator by the dragged payload, default is [`DropIndicator::Highlight`].
+    pub fn indicator(
+        mut self,
+        indicator: impl Fn(&T, &WindowContext) -> DropIndicator + 'static,
+    ) -> Self {
+        self.indicator = Rc::new(indicator);
+        self
+    }
+
+    /// Only accept the payloads that the predicate returns true for,
+    /// e.g.: the items of the same list, the others are not indicated and not dropped.
+    pub fn can_drop(mut self, predicate: impl Fn(&T, &WindowContext) -> bool + 'static) -> Self {
+        self.can_drop = Some(Rc::new(predicate));
+        self
+    }
+
+    fn accepts(&self, payload: &T, cx: &WindowContext) -> bool {
+        self.can_drop
+            .as_ref()
+            .map_or(true, |can_drop| can_drop(payload, cx))
+    }
+}
+
+/// Extends the elements to be the sources and the targets of the drag and drop.
+pub trait DragDropExt: InteractiveElement + Sized {
+    /// Drag the payload from this element, with the [`Draggable::render_preview`].
+    fn drag_source<T: Draggable>(self, payload: T) -> Self
+    where
+        Self: StatefulInteractiveElement,
+    {
+        self.on_drag(payload, |payload, cx| {
+            cx.stop_propagation();
+            cx.new_view(|_| DragPreview(payload.clone()))
+        })
+    }
+
+    /// Accept the payloads of the [`DropTarget`] dropped to this element.
+    fn drop_target<T: 'static>(self, target: DropTarget<T>) -> Self {
+        let target = Rc::new(target);
+
+        self.drag_over::<T>({
+            let target = target.clone();
+            move |style, payload, cx| {
+                if target.accepts(payload, cx) {
+                    (target.indicator)(payload, cx).apply(style, cx)
+                } else {
+                    style
+                }
+            }
+        })
+        .on_drop(move |payload: &T, cx| {
+            if target.accepts(payload, cx) {
+                (target.on_drop)(payload, cx)
+            }
+        })
+    }
+}
+
+impl<E: InteractiveElement> DragDropExt for E {}
@@ -2,21 +2,35 @@ use std::{rc::Rc, time::Duration};
 
 use gpui::{
     anchored, div, point, prelude::FluentBuilder as _, px, Animation, AnimationExt as _,
-    AnyElement, ClickEvent, DefiniteLength, DismissEvent, Div, EventEmitter, FocusHandle,
-    InteractiveElement as _, IntoElement, MouseButton, ParentElement, Pixels, RenderOnce, Styled,
-    WindowContext,
+    AnyElement, ClickEvent, DefiniteLength, DismissEvent, Div, DragMoveEvent, EventEmitter,
+    FocusHandle, InteractiveElement as _, IntoElement, MouseButton, ParentElement, Pixels, Render,
+    RenderOnce, StatefulInteractiveElement as _, Styled, VisualContext as _, WindowContext,
 };
 
 use crate::{
-    button::Button, h_flex, modal::overlay_color, root::ContextModal as _, scroll::ScrollbarAxis,
-    theme::ActiveTheme, v_flex, IconName, Placement, Sizable, StyledExt as _,
+    animation::{default_easing, reduce_motion, Easing},
+    button::Button,
+    h_flex,
+    modal::overlay_color,
+    root::{ContextModal as _, Root},
+    scroll::ScrollbarAxis,
+    theme::ActiveTheme,
+    v_flex, IconName, Placement, Sizable, StyledExt as _,
 };
 
+/// The width (or height) of the resize handle at the inner edge of the drawer.
+const RESIZE_HANDLE_SIZE: Pixels = px(6.);
+
+#[derive(Clone, Render)]
+pub struct ResizeDrawer;
+
 #[derive(IntoElement)]
 pub struct Drawer {
     focus_handle: FocusHandle,
     placement: Placement,
     size: DefiniteLength,
+    min_size: Pixels,
+    max_size: Option<Pixels>,
     resizable: bool,
     on_close: Rc<dyn Fn(&ClickEvent, &mut WindowContext) + 'static>,
     title: Option<AnyElement>,
@@ -24,6 +38,10 @@ pub struct Drawer {
     content: Div,
     margin_top: Pixels,
     overlay: bool,
+    pub(crate) duration: Duration,
+    easing: Easing,
+    /// True if the drawer is rendered for the closing animation, it doesn't handle the events.
+    pub(crate) closing: bool,
 }
 
 impl Drawer {
@@ -32,6 +50,8 @@ impl Drawer {
             focus_handle: cx.focus_handle(),
             placement: Placement::Right,
             size: DefiniteLength::Absolute(px(350.).into()),
+            min_size: px(100.),
+            max_size: None,
             resizable: true,
             title: None,
             footer: None,
@@ -39,6 +59,9 @@ impl Drawer {
             margin_top: px(0.),
             overlay: true,
             on_close: Rc::new(|_, _| {}),
+            duration: Duration::from_secs_f64(0.2),
+            easing: default_easing(),
+            closing: false,
         }
     }
 
@@ -60,6 +83,18 @@ impl Drawer {
         self
     }
 
+    /// Sets the minimum size when resizing the drawer, default is 100px.
+    pub fn min_size(mut self, size: Pixels) -> Self {
+        self.min_size = size;
+        self
+    }
+
+    /// Sets the maximum size when resizing the drawer, default is `None` to limit by the window size.
+    pub fn max_size(mut self, size: Pixels) -> Self {
+        self.max_size = Some(size);
+        self
+    }
+
     /// Sets the margin top of the drawer, default is 0px.
     ///
     /// This is used to let Drawer be placed below a Windows Title, you can give the height of the title bar.
@@ -79,7 +114,7 @@ impl Drawer {
         self.placement = placement;
     }
 
-    /// Sets whether the drawer is resizable, default is `true`.
+    /// Sets whether the drawer can be resized by dragging the inner edge, default is `true`.
     pub fn resizable(mut self, resizable: bool) -> Self {
         self.resizable = resizable;
         self
@@ -91,6 +126,20 @@ impl Drawer {
         self
     }
 
+    /// Set the duration of the slide animation when opening and closing, default is 200ms.
+    ///
+    /// The animation is disabled by [`crate::animation::set_reduce_motion`].
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// Set the easing of the slide animation, default is `cubic_bezier(0.32, 0.72, 0., 1.)`.
+    pub fn easing(mut self, easing: impl Fn(f32) -> f32 + 'static) -> Self {
+        self.easing = Rc::new(easing);
+        self
+    }
+
     /// Listen to the close event of the drawer.
     pub fn on_close(
         mut self,
@@ -120,17 +169,33 @@ impl RenderOnce for Drawer {
         let titlebar_height = self.margin_top;
         let size = cx.viewport_size();
         let on_close = self.on_close.clone();
+        let drawer_size = Root::read(cx)
+            .drawer_size
+            .map(DefiniteLength::from)
+            .unwrap_or(self.size);
+        let (min_size, max_size) = (self.min_size, self.max_size);
+        let closing = self.closing;
+        let animation = (!reduce_motion(cx)).then(|| {
+            let easing = self.easing.clone();
+            Animation::new(self.duration).with_easing(move |delta| easing(delta))
+        });
+        // Slide in from the outside of the window by the size of the drawer.
+        let offset = if placement.is_horizontal() {
+            drawer_size.to_pixels(size.width.into(), cx.rem_size())
+        } else {
+            drawer_size.to_pixels((size.height - titlebar_height).into(), cx.rem_size())
+        };
 
         anchored()
             .position(point(px(0.), titlebar_height))
             .snap_to_window()
             .child(
                 div()
-                    .occlude()
+                    .when(!closing, |this| this.occlude())
                     .w(size.width)
                     .h(size.height - titlebar_height)
                     .bg(overlay_color(self.overlay, cx))
-                    .when(self.overlay, |this| {
+                    .when(self.overlay && !closing, |this| {
                         this.on_mouse_down(MouseButton::Left, {
                             let on_close = self.on_close.clone();
                             move |_, cx| {
@@ -139,21 +204,44 @@ impl RenderOnce for Drawer {
                             }
                         })
                     })
+                    .on_drag_move(move |e: &DragMoveEvent<ResizeDrawer>, cx| {
+                        let position = e.event.position;
+                        let viewport = cx.viewport_size();
+                        let (new_size, max_limit) = match placement {
+                            Placement::Left => (position.x, viewport.width),
+                            Placement::Right => (viewport.width - position.x, viewport.width),
+                            Placement::Top => (
+                                position.y - titlebar_height,
+                                viewport.height - titlebar_height,
+                            ),
+                            Placement::Bottom => (
+                                viewport.height - position.y,
+                                viewport.height - titlebar_height,
+                            ),
+                        };
+                        let max_size = max_size.unwrap_or(max_limit).min(max_limit);
+                        let new_size = new_size.max(min_size).min(max_size);
+
+                        Root::update(cx, move |root, cx| {
+                            root.drawer_size = Some(new_size);
+                            cx.notify();
+                        });
+                    })
                     .child(
                         v_flex()
                             .id("")
                             .track_focus(&focus_handle)
                             .absolute()
                             .occlude()
-                            .bg(cx.theme().background)
-                            .border_color(cx.theme().border)
+                            .bg(cx.theme().tokens.surface)
+                            .border_color(cx.theme().tokens.outline)
                             .shadow_xl()
                             .map(|this| {
                                 // Set the size of the drawer.
                                 if placement.is_horizontal() {
-                                    this.h_full().w(self.size)
+                                    this.h_full().w(drawer_size)
                                 } else {
-                                    this.w_full().h(self.size)
+                                    this.w_full().h(drawer_size)
                                 }
                             })
                             .map(|this| match self.placement {
@@ -205,20 +293,50 @@ impl RenderOnce for Drawer {
                                         .child(footer),
                                 )
                             })
-                            .with_animation(
-                                "slide",
-                                Animation::new(Duration::from_secs_f64(0.15)),
-                                move |this, delta| {
-                                    let y = px(-100.) + delta * px(100.);
-                                    this.map(|this| match placement {
-                                        Placement::Top => this.top(y),
-                                        Placement::Right => this.right(y),
-                                        Placement::Bottom => this.bottom(y),
-                                        Placement::Left => this.left(y),
+                            .when(self.resizable, |this| {
+                                this.child(render_resize_handle(placement))
+                            })
+                            .map(|this| match animation {
+                                Some(animation) => this
+                                    .with_animation("slide", animation, move |this, delta| {
+                                        let progress = if closing { 1. - delta } else { delta };
+                                        let position = -offset * (1. - progress);
+                                        this.map(|this| match placement {
+                                            Placement::Top => this.top(position),
+                                            Placement::Right => this.right(position),
+                                            Placement::Bottom => this.bottom(position),
+                                            Placement::Left => this.left(position),
+                                        })
                                     })
-                                },
-                            ),
+                                    .into_any_element(),
+                                None => this.into_any_element(),
+                            }),
                     ),
             )
     }
 }
+
+/// Render the handle at the inner edge of the drawer, drag it to resize the drawer.
+fn render_resize_handle(placement: Placement) -> impl IntoElement {
+    div()
+        .id("drawer-resize-handle")
+        .occlude()
+        .absolute()
+        .map(|this| match placement {
+            Placement::Left => this.top_0().bottom_0().right(-RESIZE_HANDLE_SIZE / 2.),
+            Placement::Right => this.top_0().bottom_0().left(-RESIZE_HANDLE_SIZE / 2.),
+            Placement::Top => this.left_0().right_0().bottom(-RESIZE_HANDLE_SIZE / 2.),
+            Placement::Bottom => this.left_0().right_0().top(-RESIZE_HANDLE_SIZE / 2.),
+        })
+        .map(|this| {
+            if placement.is_horizontal() {
+                this.h_full().w(RESIZE_HANDLE_SIZE).cursor_col_resize()
+            } else {
+                this.w_full().h(RESIZE_HANDLE_SIZE).cursor_row_resize()
+            }
+        })
+        .on_drag(ResizeDrawer, |drag, cx| {
+            cx.stop_propagation();
+            cx.new_view(|_| drag.clone())
+        })
+}
@@ -1,3 +1,5 @@
+use std::rc::Rc;
+
 use gpui::{
     actions, anchored, canvas, deferred, div, prelude::FluentBuilder, px, rems, AnyElement,
     AppContext, Bounds, ClickEvent, DismissEvent, ElementId, EventEmitter, FocusHandle,
@@ -78,6 +80,13 @@ pub trait DropdownDelegate: Sized {
         (0..self.len()).find(|&i| self.get(i).map_or(false, |item| item.value() == value))
     }
 
+    /// Returns the group title of the item, the items of the same group must be adjacent.
+    ///
+    /// The group title is shown above the first item of the group in the menu.
+    fn group(&self, _ix: usize) -> Option<SharedString> {
+        None
+    }
+
     fn can_search(&self) -> bool {
         false
     }
@@ -107,10 +116,52 @@ impl<T: DropdownItem> DropdownDelegate for Vec<T> {
     }
 }
 
+/// A row of the dropdown menu.
+enum DropdownRow {
+    Group(SharedString),
+    /// The index of the item in the [`DropdownDelegate`].
+    Item(usize),
+}
+
 struct DropdownListDelegate<D: DropdownDelegate + 'static> {
     delegate: D,
     dropdown: WeakView<Dropdown<D>>,
+    /// The index of the selected row.
     selected_index: Option<usize>,
+    rows: Vec<DropdownRow>,
+}
+
+impl<D> DropdownListDelegate<D>
+where
+    D: DropdownDelegate + 'static,
+{
+    /// Rebuild the rows from the items of the delegate, insert the group titles.
+    fn update_rows(&mut self) {
+        self.rows.clear();
+
+        let mut last_group = None;
+        for ix in 0..self.delegate.len() {
+            let group = self.delegate.group(ix);
+            if group.is_some() && group != last_group {
+                self.rows.extend(group.clone().map(DropdownRow::Group));
+            }
+            last_group = group;
+            self.rows.push(DropdownRow::Item(ix));
+        }
+    }
+
+    fn item_index(&self, row_ix: usize) -> Option<usize> {
+        match self.rows.get(row_ix) {
+            Some(DropdownRow::Item(ix)) => Some(*ix),
+            _ => None,
+        }
+    }
+
+    fn row_index(&self, item_ix: usize) -> Option<usize> {
+        self.rows
+            .iter()
+            .position(|row| matches!(row, DropdownRow::Item(ix) if *ix == item_ix))
+    }
 }
 
 impl<D> ListDelegate for DropdownListDelegate<D>
@@ -120,13 +171,17 @@ where
     type Item = ListItem;
 
     fn items_count(&self) -> usize {
-        self.delegate.len()
+        self.rows.len()
     }
 
     fn confirmed_index(&self) -> Option<usize> {
         self.selected_index
     }
 
+    fn can_select(&self, ix: usize) -> bool {
+        self.item_index(ix).is_some()
+    }
+
     fn render_item(&self, ix: usize, cx: &mut gpui::ViewContext<List<Self>>) -> Option<Self::Item> {
         let selected = self
             .selected_index
@@ -136,7 +191,23 @@ where
             .upgrade()
             .map_or(Size::Medium, |dropdown| dropdown.read(cx).size);
 
-        if let Some(item) = self.delegate.get(ix) {
+        if let Some(DropdownRow::Group(title)) = self.rows.get(ix) {
+            return Some(
+                ListItem::new(("list-group", ix))
+                    .disabled(true)
+                    .input_text_size(size)
+                    .list_size(size)
+                    .child(
+                        div()
+                            .whitespace_nowrap()
+                            .font_semibold()
+                            .text_color(cx.theme().tokens.on_surface_muted)
+                            .child(title.clone()),
+                    ),
+            );
+        }
+
+        if let Some(item) = self.item_index(ix).and_then(|ix| self.delegate.get(ix)) {
             let list_item = ListItem::new(("list-item", ix))
                 .check_icon(IconName::Check)
                 .cursor_pointer()
@@ -166,6 +237,7 @@ where
             cx.update_view(&view, |view, cx| {
                 let selected_value = self
                     .selected_index
+                    .and_then(|ix| self.item_index(ix))
                     .and_then(|ix| self.delegate.get(ix))
                     .map(|item| item.value().clone());
                 cx.emit(DropdownEvent::Confirm(selected_value.clone()));
@@ -177,11 +249,18 @@ where
     }
 
     fn perform_search(&mut self, query: &str, cx: &mut ViewContext<List<Self>>) -> Task<()> {
-        self.dropdown
-            .upgrade()
-            .map_or(Task::Ready(None), |dropdown| {
-                dropdown.update(cx, |_, cx| self.delegate.perform_search(query, cx))
-            })
+        let Some(dropdown) = self.dropdown.upgrade() else {
+            return Task::Ready(None);
+        };
+
+        let search = dropdown.update(cx, |_, cx| self.delegate.perform_search(query, cx));
+        self.update_rows();
+
+        // The items may be changed after the async search is done.
+        cx.spawn(|list, mut cx| async move {
+            search.await;
+            let _ = list.update(&mut cx, |list, _| list.delegate_mut().update_rows());
+        })
     }
 
     fn set_selected_index(&mut self, ix: Option<usize>, _: &mut ViewContext<List<Self>>) {
@@ -199,7 +278,7 @@ where
             h_flex()
                 .justify_center()
                 .py_6()
-                .text_color(cx.theme().muted_foreground.opacity(0.6))
+                .text_color(cx.theme().tokens.on_surface_muted.opacity(0.6))
                 .child(Icon::new(IconName::Inbox).size(px(28.)))
                 .into_any_element()
         }
@@ -231,17 +310,34 @@ pub struct Dropdown<D: DropdownDelegate + 'static> {
 
 pub struct SearchableVec<T> {
     items: Vec<T>,
-    matched_items: Vec<T>,
+    /// The group title of each item in the `items`.
+    groups: Vec<Option<SharedString>>,
+    /// The indices of the items matched the query.
+    matched_items: Vec<usize>,
 }
 
 impl<T: DropdownItem + Clone> SearchableVec<T> {
     pub fn new(items: impl Into<Vec<T>>) -> Self {
         let items = items.into();
         Self {
-            items: items.clone(),
-            matched_items: items,
+            groups: vec![None; items.len()],
+            matched_items: (0..items.len()).collect(),
+            items,
         }
     }
+
+    /// Create with the items in groups, the group title is shown above the items of the group.
+    pub fn grouped(groups: impl IntoIterator<Item = (impl Into<SharedString>, Vec<T>)>) -> Self {
+        let mut this = Self::new(vec![]);
+        for (title, items) in groups {
+            let title = title.into();
+            this.groups
+                .extend(items.iter().map(|_| Some(title.clone())));
+            this.items.extend(items);
+        }
+        this.matched_items = (0..this.items.len()).collect();
+        this
+    }
 }
 
 impl<T: DropdownItem + Clone> DropdownDelegate for SearchableVec<T> {
@@ -252,7 +348,7 @@ impl<T: DropdownItem + Clone> DropdownDelegate for SearchableVec<T> {
     }
 
     fn get(&self, ix: usize) -> Option<&Self::Item> {
-        self.matched_items.get(ix)
+        self.matched_items.get(ix).map(|&ix| &self.items[ix])
     }
 
     fn position<V>(&self, value: &V) -> Option<usize>
@@ -260,13 +356,15 @@ impl<T: DropdownItem + Clone> DropdownDelegate for SearchableVec<T> {
         Self::Item: DropdownItem<Value = V>,
         V: PartialEq,
     {
-        for (ix, item) in self.matched_items.iter().enumerate() {
-            if item.value() == value {
-                return Some(ix);
-            }
-        }
+        self.matched_items
+            .iter()
+            .position(|&ix| self.items[ix].value() == value)
+    }
 
-        None
+    fn group(&self, ix: usize) -> Option<SharedString> {
+        self.matched_items
+            .get(ix)
+            .and_then(|&ix| self.groups[ix].clone())
     }
 
     fn can_search(&self) -> bool {
@@ -274,11 +372,13 @@ impl<T: DropdownItem + Clone> DropdownDelegate for SearchableVec<T> {
     }
 
     fn perform_search(&mut self, query: &str, _cx: &mut ViewContext<Dropdown<Self>>) -> Task<()> {
+        let query = query.to_lowercase();
         self.matched_items = self
             .items
             .iter()
-            .filter(|item| item.title().to_lowercase().contains(&query.to_lowercase()))
-            .cloned()
+            .enumerate()
+            .filter(|(_, item)| item.title().to_lowercase().contains(&query))
+            .map(|(ix, _)| ix)
             .collect();
 
         Task::Ready(Some(()))
@@ -287,13 +387,58 @@ impl<T: DropdownItem + Clone> DropdownDelegate for SearchableVec<T> {
 
 impl From<Vec<SharedString>> for SearchableVec<SharedString> {
     fn from(items: Vec<SharedString>) -> Self {
+        Self::new(items)
+    }
+}
+
+/// A delegate loads the items by the query asynchronously, e.g.: from a remote API.
+///
+/// The `loader` is called with the query when the search input changed,
+/// and the items are replaced by the loaded items. The items are empty before the first search.
+pub struct AsyncSearchableVec<T> {
+    items: Vec<T>,
+    loader: Rc<dyn Fn(&str, &mut AppContext) -> Task<Vec<T>>>,
+}
+
+impl<T: DropdownItem + 'static> AsyncSearchableVec<T> {
+    pub fn new(loader: impl Fn(&str, &mut AppContext) -> Task<Vec<T>> + 'static) -> Self {
         Self {
-            items: items.clone(),
-            matched_items: items,
+            items: Vec::new(),
+            loader: Rc::new(loader),
         }
     }
 }
 
+impl<T: DropdownItem + 'static> DropdownDelegate for AsyncSearchableVec<T> {
+    type Item = T;
+
+    fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    fn get(&self, ix: usize) -> Option<&Self::Item> {
+        self.items.get(ix)
+    }
+
+    fn can_search(&self) -> bool {
+        true
+    }
+
+    fn perform_search(&mut self, query: &str, cx: &mut ViewContext<Dropdown<Self>>) -> Task<()> {
+        let load = (self.loader)(query, cx);
+
+        cx.spawn(|dropdown, mut cx| async move {
+            let items = load.await;
+            let _ = dropdown.update(&mut cx, |dropdown, cx| {
+                dropdown.list.update(cx, |list, cx| {
+                    list.delegate_mut().delegate.items = items;
+                    cx.notify();
+                });
+            });
+        })
+    }
+}
+
 impl<D> Dropdown<D>
 where
     D: DropdownDelegate + 'static,
@@ -305,11 +450,13 @@ where
         cx: &mut ViewContext<Self>,
     ) -> Self {
         let focus_handle = cx.focus_handle();
-        let delegate = DropdownListDelegate {
+        let mut delegate = DropdownListDelegate {
             delegate,
             dropdown: cx.view().downgrade(),
-            selected_index,
+            selected_index: None,
+            rows: Vec::new(),
         };
+        delegate.update_rows();
 
         let searchable = delegate.delegate.can_search();
 
@@ -406,7 +553,8 @@ where
         cx: &mut ViewContext<Self>,
     ) {
         self.list.update(cx, |list, cx| {
-            list.set_selected_index(selected_index, cx);
+            let row_ix = selected_index.and_then(|ix| list.delegate().row_index(ix));
+            list.set_selected_index(row_ix, cx);
         });
         self.update_selected_value(cx);
     }
@@ -424,7 +572,9 @@ where
     }
 
     pub fn selected_index(&self, cx: &WindowContext) -> Option<usize> {
-        self.list.read(cx).selected_index()
+        let list = self.list.read(cx);
+        list.selected_index()
+            .and_then(|row_ix| list.delegate().item_index(row_ix))
     }
 
     fn update_selected_value(&mut self, cx: &WindowContext) {
@@ -513,12 +663,12 @@ where
             h_flex()
                 .children(self.title_prefix.clone().map(|prefix| {
                     div()
-                        .text_color(cx.theme().accent_foreground)
+                        .text_color(cx.theme().tokens.on_highlight)
                         .child(prefix.clone())
                 }))
                 .child(title.clone())
         } else {
-            div().text_color(cx.theme().accent_foreground).child(
+            div().text_color(cx.theme().tokens.on_highlight).child(
                 self.placeholder
                     .clone()
                     .unwrap_or_else(|| t!("Dropdown.placeholder").into()),
@@ -527,7 +677,7 @@ where
 
         title.when(self.disabled, |this| {
             this.cursor_not_allowed()
-                .text_color(cx.theme().muted_foreground)
+                .text_color(cx.theme().tokens.on_surface_muted)
         })
     }
 }
@@ -587,7 +737,7 @@ where
                     .flex()
                     .items_center()
                     .justify_between()
-                    .bg(cx.theme().background)
+                    .bg(cx.theme().tokens.surface)
                     .border_1()
                     .border_color(cx.theme().input)
                     .rounded(px(cx.theme().radius))
@@ -645,7 +795,7 @@ where
 
                                 this.child(
                                     Icon::new(icon)
-                                        .text_color(cx.theme().muted_foreground)
+                                        .text_color(cx.theme().tokens.on_surface_muted)
                                         .when(self.disabled, |this| this.cursor_not_allowed()),
                                 )
                             }),
@@ -674,9 +824,9 @@ where
                                         .track_focus(&self.list.focus_handle(cx))
                                         .occlude()
                                         .mt_1p5()
-                                        .bg(cx.theme().background)
+                                        .bg(cx.theme().tokens.surface)
                                         .border_1()
-                                        .border_color(cx.theme().border)
+                                        .border_color(cx.theme().tokens.outline)
                                         .rounded(px(cx.theme().radius))
                                         .shadow_md()
                                         .on_mouse_down_out(|_, cx| {
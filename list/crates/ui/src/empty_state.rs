@@ -0,0 +1,89 @@
+use gpui::{
+    prelude::FluentBuilder as _, AnyElement, IntoElement, ParentElement, RenderOnce, SharedString,
+    Styled, WindowContext,
+};
+
+use crate::{theme::ActiveTheme, v_flex, Icon, IconName};
+
+/// A placeholder to show in place of the content of a List or Table, when there is no data or the data failed to load.
+#[derive(IntoElement)]
+pub struct EmptyState {
+    icon: IconName,
+    message: SharedString,
+    description: Option<SharedString>,
+    action: Option<AnyElement>,
+    error: bool,
+}
+
+impl EmptyState {
+    /// Create an empty state with the `Inbox` icon.
+    pub fn new(message: impl Into<SharedString>) -> Self {
+        Self {
+            icon: IconName::Inbox,
+            message: message.into(),
+            description: None,
+            action: None,
+            error: false,
+        }
+    }
+
+    /// Create an error state with the `CircleX` icon in the destructive color.
+    pub fn error(message: impl Into<SharedString>) -> Self {
+        Self {
+            icon: IconName::CircleX,
+            error: true,
+            ..Self::new(message)
+        }
+    }
+
+    pub fn icon(mut self, icon: IconName) -> Self {
+        self.icon = icon;
+        self
+    }
+
+    /// Set the secondary text below the message.
+    pub fn description(mut self, description: impl Into<SharedString>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Set the element below the message, e.g.: a Button to reload the data.
+    pub fn action(mut self, action: impl IntoElement) -> Self {
+        self.action = Some(action.into_any_element());
+        self
+    }
+}
+
+impl RenderOnce for EmptyState {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let icon_color = if self.error {
+            cx.theme().tokens.danger
+        } else {
+            cx.theme().tokens.on_surface_muted.opacity(0.6)
+        };
+
+        v_flex()
+            .size_full()
+            .items_center()
+            .justify_center()
+            .gap_2()
+            .py_6()
+            .child(Icon::new(self.icon).size_12().text_color(icon_color))
+            .child(
+                v_flex()
+                    .items_center()
+                    .gap_1()
+                    .text_sm()
+                    .child(self.message)
+                    .when_some(self.description, |this, description| {
+                        this.child(
+                            v_flex()
+                                .text_xs()
+                                .text_color(cx.theme().tokens.on_surface_muted)
+                                .child(description),
+                        )
+                    }),
+            )
+            .when_some(self.action, |this, action| this.child(action))
+    }
+}
@@ -0,0 +1,192 @@
+//! The native dialogs to pick the files to open or save, with the filters of the extensions.
+//!
+//! The directory of the last picked file is remembered, to save the next file in it.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::anyhow;
+use gpui::{AppContext, Global, PathPromptOptions, SharedString, Task, WindowContext};
+
+/// The directory to save the files in, see [`set_default_directory`].
+struct DialogDirectory(PathBuf);
+
+impl Global for DialogDirectory {}
+
+/// Set the directory to save the files in, until a file is picked by the dialogs.
+pub fn set_default_directory(dir: impl Into<PathBuf>, cx: &mut AppContext) {
+    cx.set_global(DialogDirectory(dir.into()));
+}
+
+fn default_directory(cx: &AppContext) -> PathBuf {
+    cx.try_global::<DialogDirectory>()
+        .map(|dir| dir.0.clone())
+        .or_else(|| std::env::current_dir().ok())
+        .unwrap_or_default()
+}
+
+/// Remember the directory of the picked path.
+fn remember_directory(path: &Path, cx: &mut AppContext) {
+    if let Some(dir) = path.parent() {
+        set_default_directory(dir, cx);
+    }
+}
+
+/// Returns true if the extension of the path is one of the `extensions`, case insensitive.
+///
+/// All the paths are accepted if the `extensions` is empty.
+fn has_extension(path: &Path, extensions: &[SharedString]) -> bool {
+    extensions.is_empty()
+        || path.extension().map_or(false, |ext| {
+            extensions
+                .iter()
+                .any(|extension| ext.eq_ignore_ascii_case(extension.as_ref()))
+        })
+}
+
+/// The options of [`prompt_open`].
+#[derive(Debug, Clone, Default)]
+pub struct OpenOptions {
+    directories: bool,
+    multiple: bool,
+    extensions: Vec<SharedString>,
+}
+
+impl OpenOptions {
+    /// Pick a file.
+    pub fn file() -> Self {
+        Self::default()
+    }
+
+    /// Pick a directory instead of a file.
+    pub fn directory() -> Self {
+        Self {
+            directories: true,
+            ..Self::default()
+        }
+    }
+
+    /// Allow to pick multiple files or directories.
+    pub fn multiple(mut self) -> Self {
+        self.multiple = true;
+        self
+    }
+
+    /// Only accept the files with the extensions, e.g.: `&["csv", "json"]`.
+    pub fn extensions(mut self, extensions: &[&'static str]) -> Self {
+        self.extensions = extensions
+            .iter()
+            .map(|ext| SharedString::from(*ext))
+            .collect();
+        self
+    }
+}
+
+/// Open the dialog to pick the files to open, the result is empty if the dialog is cancelled.
+///
+/// Returns an error if a picked file is not in the [`OpenOptions::extensions`].
+pub fn prompt_open(
+    options: OpenOptions,
+    cx: &mut WindowContext,
+) -> Task<anyhow::Result<Vec<PathBuf>>> {
+    let paths = cx.prompt_for_paths(PathPromptOptions {
+        files: !options.directories,
+        directories: options.directories,
+        multiple: options.multiple,
+    });
+
+    cx.spawn(|mut cx| async move {
+        let paths = paths.await??.unwrap_or_default();
+
+        if let Some(path) = paths
+            .iter()
+            .find(|path| !has_extension(path, &options.extensions))
+        {
+            let expected = options
+                .extensions
+                .iter()
+                .map(|ext| format!(".{}", ext))
+                .collect::<Vec<_>>()
+                .join(", ");
+            return Err(anyhow!(
+                "Unsupported file {}, expected: {}",
+                path.display(),
+                expected
+            ));
+        }
+
+        if let Some(path) = paths.first() {
+            cx.update(|cx| remember_directory(path, cx))?;
+        }
+        Ok(paths)
+    })
+}
+
+/// The options of [`prompt_save`].
+#[derive(Debug, Clone, Default)]
+pub struct SaveOptions {
+    directory: Option<PathBuf>,
+    extension: Option<SharedString>,
+}
+
+impl SaveOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The directory to open the dialog in, default is the directory of the last picked file.
+    pub fn directory(mut self, directory: impl Into<PathBuf>) -> Self {
+        self.directory = Some(directory.into());
+        self
+    }
+
+    /// The extension to append to the file name if it has no extension, e.g.: `"json"`.
+    pub fn extension(mut self, extension: &'static str) -> Self {
+        self.extension = Some(extension.into());
+        self
+    }
+}
+
+/// Open the dialog to pick the path to save the file, `None` if the dialog is cancelled.
+pub fn prompt_save(
+    options: SaveOptions,
+    cx: &mut WindowContext,
+) -> Task<anyhow::Result<Option<PathBuf>>> {
+    let directory = options
+        .directory
+        .clone()
+        .unwrap_or_else(|| default_directory(cx));
+    let path = cx.prompt_for_new_path(&directory);
+
+    cx.spawn(|mut cx| async move {
+        let Some(mut path) = path.await?? else {
+            return Ok(None);
+        };
+
+        if let Some(extension) = &options.extension {
+            if path.extension().is_none() {
+                path.set_extension(extension.as_ref());
+            }
+        }
+
+        cx.update(|cx| remember_directory(&path, cx))?;
+        Ok(Some(path))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::has_extension;
+
+    #[test]
+    fn test_has_extension() {
+        let extensions = ["csv".into(), "json".into()];
+
+        assert!(has_extension(Path::new("data.csv"), &extensions));
+        assert!(has_extension(Path::new("/tmp/DATA.JSON"), &extensions));
+        assert!(!has_extension(Path::new("data.txt"), &extensions));
+        assert!(!has_extension(Path::new("data"), &extensions));
+        assert!(has_extension(Path::new("data"), &[]));
+    }
+}
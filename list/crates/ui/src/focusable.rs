@@ -1,4 +1,9 @@
-use gpui::{FocusHandle, ViewContext};
+use gpui::{
+    actions, div, AppContext, Div, FocusHandle, Focusable, InteractiveElement, KeyBinding,
+    Styled as _, ViewContext, WindowContext,
+};
+
+use crate::{theme::ActiveTheme as _, Root};
 
 /// A trait for views that can cycle focus between its children.
 ///
@@ -38,3 +43,43 @@ pub trait FocusableCycle {
         cx.stop_propagation();
     }
 }
+
+actions!(focusable, [FocusNext, FocusPrev]);
+
+pub(crate) fn init(cx: &mut AppContext) {
+    cx.bind_keys([
+        KeyBinding::new("tab", FocusNext, Some("Root")),
+        KeyBinding::new("shift-tab", FocusPrev, Some("Root")),
+    ]);
+}
+
+/// Extends [`InteractiveElement`] to join the Tab / Shift-Tab focus traversal of the window.
+pub trait TabStop: InteractiveElement + Sized {
+    /// Track the focus handle, and add it to the tab stops of the window.
+    ///
+    /// The tab stops are cycled in the order they are rendered.
+    fn tab_stop(self, focus_handle: &FocusHandle, cx: &mut WindowContext) -> Focusable<Self> {
+        register_tab_stop(focus_handle, cx);
+        self.track_focus(focus_handle)
+    }
+}
+
+impl<E: InteractiveElement> TabStop for E {}
+
+/// Returns a ring to be added as the last child of a `relative` element, to show it is focused.
+///
+/// Unlike [`crate::StyledExt::focus_ring`], the ring is drawn inside, so it still be visible
+/// when the parent is clipped.
+pub(crate) fn inner_focus_ring(cx: &WindowContext) -> Div {
+    div()
+        .absolute()
+        .inset_0()
+        .border_2()
+        .border_color(cx.theme().tokens.focus)
+}
+
+/// Add the focus handle to the tab stops of the window, this must be called on render.
+pub(crate) fn register_tab_stop(focus_handle: &FocusHandle, cx: &mut WindowContext) {
+    let focus_handle = focus_handle.clone();
+    Root::update(cx, move |root, _| root.tab_stops.push(focus_handle));
+}
@@ -0,0 +1,76 @@
+use std::ops::Range;
+
+/// The result of [`fuzzy_match`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FuzzyMatch {
+    /// The higher score means the better match.
+    pub score: usize,
+    /// The byte ranges of the matched chars in the text, the adjacent chars are merged into one range.
+    pub ranges: Vec<Range<usize>>,
+}
+
+/// Match the query in the text, returns `None` if not matched.
+///
+/// All chars of the query (ignoring the whitespaces) must appear in the text in order, case-insensitive.
+/// The consecutive matches and the matches at the start of words get a higher score.
+pub fn fuzzy_match(query: &str, text: &str) -> Option<FuzzyMatch> {
+    let mut result = FuzzyMatch::default();
+    let mut text_chars = text.char_indices();
+    let mut prev_char: Option<char> = None;
+    let mut consecutive = false;
+
+    for query_char in query.chars().filter(|c| !c.is_whitespace()) {
+        loop {
+            let (ix, char) = text_chars.next()?;
+            let is_word_start = prev_char.map_or(true, |c| !c.is_alphanumeric());
+            prev_char = Some(char);
+
+            if char.to_lowercase().eq(query_char.to_lowercase()) {
+                result.score += 1;
+                if consecutive {
+                    result.score += 2;
+                }
+                if is_word_start {
+                    result.score += 3;
+                }
+
+                let end = ix + char.len_utf8();
+                match result.ranges.last_mut() {
+                    Some(range) if consecutive => range.end = end,
+                    _ => result.ranges.push(ix..end),
+                }
+                consecutive = true;
+                break;
+            }
+            consecutive = false;
+        }
+    }
+
+    Some(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fuzzy_match() {
+        assert_eq!(fuzzy_match("", "Hello"), Some(FuzzyMatch::default()));
+        assert_eq!(fuzzy_match("hx", "Hello"), None);
+        assert_eq!(fuzzy_match("hello world", "Hello"), None);
+
+        let m = fuzzy_match("hlo", "Hello").unwrap();
+        assert_eq!(m.ranges, vec![0..1, 2..3, 4..5]);
+
+        let m = fuzzy_match("ell", "Hello").unwrap();
+        assert_eq!(m.ranges, vec![1..4]);
+
+        let m = fuzzy_match("gw", "Hello 世界 gpui world").unwrap();
+        assert_eq!(m.ranges, vec![13..14, 18..19]);
+
+        // The match at the start of words is better.
+        let a = fuzzy_match("st", "Story").unwrap();
+        let b = fuzzy_match("st", "Test").unwrap();
+        assert!(a.score > b.score);
+    }
+}
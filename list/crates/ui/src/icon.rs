@@ -1,112 +1,101 @@
+use std::borrow::Cow;
+
 use crate::{theme::ActiveTheme, Sizable, Size};
 use gpui::{
-    prelude::FluentBuilder as _, svg, AnyElement, Hsla, IntoElement, Render, RenderOnce,
-    SharedString, StyleRefinement, Styled, Svg, View, VisualContext, WindowContext,
+    prelude::FluentBuilder as _, svg, AnyElement, AssetSource, Hsla, IntoElement, Render,
+    RenderOnce, SharedString, StyleRefinement, Styled, Svg, View, VisualContext, WindowContext,
 };
 
-#[derive(IntoElement, Clone)]
-pub enum IconName {
-    ArrowDown,
-    ArrowLeft,
-    ArrowRight,
-    ArrowUp,
-    Asterisk,
-    Bell,
-    Calendar,
-    Check,
-    ChevronDown,
-    ChevronLeft,
-    ChevronRight,
-    ChevronUp,
-    ChevronsUpDown,
-    CircleCheck,
-    CircleX,
-    Close,
-    Copy,
-    Dash,
-    Delete,
-    Ellipsis,
-    EllipsisVertical,
-    Eye,
-    EyeOff,
-    GitHub,
-    Globe,
-    Heart,
-    HeartOff,
-    Inbox,
-    Info,
-    Loader,
-    LoaderCircle,
-    Maximize,
-    Menu,
-    Minimize,
-    Minus,
-    Moon,
-    Palette,
-    Plus,
-    Search,
-    SortAscending,
-    SortDescending,
-    Star,
-    StarOff,
-    Sun,
-    ThumbsDown,
-    ThumbsUp,
-    TriangleAlert,
+macro_rules! icon_names {
+    ($($name:ident => $file:literal),* $(,)?) => {
+        #[derive(IntoElement, Clone, Copy, Debug, PartialEq, Eq)]
+        pub enum IconName {
+            $($name,)*
+        }
+
+        impl IconName {
+            /// All the icons of the bundled set.
+            pub const ALL: &'static [IconName] = &[$(IconName::$name),*];
+
+            pub fn path(self) -> SharedString {
+                match self {
+                    $(IconName::$name => concat!("icons/", $file, ".svg"),)*
+                }
+                .into()
+            }
+
+            /// Returns the SVG of the icon, it's embedded into the binary,
+            /// so the icons work without the `icons` in the assets of the app, see [`IconAssets`].
+            pub fn svg(self) -> &'static [u8] {
+                match self {
+                    $(IconName::$name => include_bytes!(concat!("../../../assets/icons/", $file, ".svg")),)*
+                }
+            }
+        }
+    };
 }
 
+icon_names!(
+    ArrowDown => "arrow-down",
+    ArrowLeft => "arrow-left",
+    ArrowRight => "arrow-right",
+    ArrowUp => "arrow-up",
+    Asterisk => "asterisk",
+    Bell => "bell",
+    Calendar => "calendar",
+    Check => "check",
+    ChevronDown => "chevron-down",
+    ChevronLeft => "chevron-left",
+    ChevronRight => "chevron-right",
+    ChevronUp => "chevron-up",
+    ChevronsUpDown => "chevrons-up-down",
+    CircleCheck => "circle-check",
+    CircleX => "circle-x",
+    Close => "close",
+    Copy => "copy",
+    Dash => "dash",
+    Delete => "delete",
+    Ellipsis => "ellipsis",
+    EllipsisVertical => "ellipsis-vertical",
+    Eye => "eye",
+    EyeOff => "eye-off",
+    File => "file",
+    Folder => "folder",
+    FolderOpen => "folder-open",
+    GitHub => "github",
+    Globe => "globe",
+    Heart => "heart",
+    HeartOff => "heart-off",
+    Inbox => "inbox",
+    Info => "info",
+    Loader => "loader",
+    LoaderCircle => "loader-circle",
+    Maximize => "maximize",
+    Menu => "menu",
+    Minimize => "minimize",
+    Minus => "minus",
+    Moon => "moon",
+    Palette => "palette",
+    PanelLeft => "panel-left",
+    PanelRight => "panel-right",
+    Plus => "plus",
+    RefreshCw => "refresh-cw",
+    Search => "search",
+    Settings => "settings",
+    SortAscending => "sort-ascending",
+    SortDescending => "sort-descending",
+    Star => "star",
+    StarOff => "star-off",
+    Sun => "sun",
+    ThumbsDown => "thumbs-down",
+    ThumbsUp => "thumbs-up",
+    TriangleAlert => "triangle-alert",
+);
+
 impl IconName {
-    pub fn path(self) -> SharedString {
-        match self {
-            IconName::ArrowDown => "icons/arrow-down.svg",
-            IconName::ArrowLeft => "icons/arrow-left.svg",
-            IconName::ArrowRight => "icons/arrow-right.svg",
-            IconName::ArrowUp => "icons/arrow-up.svg",
-            IconName::Asterisk => "icons/asterisk.svg",
-            IconName::Bell => "icons/bell.svg",
-            IconName::Calendar => "icons/calendar.svg",
-            IconName::Check => "icons/check.svg",
-            IconName::ChevronDown => "icons/chevron-down.svg",
-            IconName::ChevronLeft => "icons/chevron-left.svg",
-            IconName::ChevronRight => "icons/chevron-right.svg",
-            IconName::ChevronUp => "icons/chevron-up.svg",
-            IconName::ChevronsUpDown => "icons/chevrons-up-down.svg",
-            IconName::CircleCheck => "icons/circle-check.svg",
-            IconName::CircleX => "icons/circle-x.svg",
-            IconName::Close => "icons/close.svg",
-            IconName::Copy => "icons/copy.svg",
-            IconName::Dash => "icons/dash.svg",
-            IconName::Delete => "icons/delete.svg",
-            IconName::Ellipsis => "icons/ellipsis.svg",
-            IconName::EllipsisVertical => "icons/ellipsis-vertical.svg",
-            IconName::Eye => "icons/eye.svg",
-            IconName::EyeOff => "icons/eye-off.svg",
-            IconName::GitHub => "icons/github.svg",
-            IconName::Globe => "icons/globe.svg",
-            IconName::Heart => "icons/heart.svg",
-            IconName::HeartOff => "icons/heart-off.svg",
-            IconName::Inbox => "icons/inbox.svg",
-            IconName::Info => "icons/info.svg",
-            IconName::Loader => "icons/loader.svg",
-            IconName::LoaderCircle => "icons/loader-circle.svg",
-            IconName::Maximize => "icons/maximize.svg",
-            IconName::Menu => "icons/menu.svg",
-            IconName::Minimize => "icons/minimize.svg",
-            IconName::Minus => "icons/minus.svg",
-            IconName::Moon => "icons/moon.svg",
-            IconName::Palette => "icons/palette.svg",
-            IconName::Plus => "icons/plus.svg",
-            IconName::Search => "icons/search.svg",
-            IconName::SortAscending => "icons/sort-ascending.svg",
-            IconName::SortDescending => "icons/sort-descending.svg",
-            IconName::Star => "icons/star.svg",
-            IconName::StarOff => "icons/star-off.svg",
-            IconName::Sun => "icons/sun.svg",
-            IconName::ThumbsDown => "icons/thumbs-down.svg",
-            IconName::ThumbsUp => "icons/thumbs-up.svg",
-            IconName::TriangleAlert => "icons/triangle-alert.svg",
-        }
-        .into()
+    /// Returns the icon of the path, e.g.: `icons/check.svg`.
+    pub fn from_path(path: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|icon| icon.path() == path)
     }
 
     /// Return the icon as a View<Icon>
@@ -241,7 +230,9 @@ impl From<Icon> for AnyElement {
 
 impl Render for Icon {
     fn render(&mut self, cx: &mut gpui::ViewContext<Self>) -> impl IntoElement {
-        let text_color = self.text_color.unwrap_or_else(|| cx.theme().foreground);
+        let text_color = self
+            .text_color
+            .unwrap_or_else(|| cx.theme().tokens.on_surface);
 
         svg()
             .flex_none()
@@ -256,3 +247,41 @@ impl Render for Icon {
             .path(self.path.clone())
     }
 }
+
+/// The [`AssetSource`] of the bundled icons, e.g.: `icons/check.svg`.
+///
+/// Use it as the assets of the app, or load from it when the path is not found in the assets of the app.
+pub struct IconAssets;
+
+impl AssetSource for IconAssets {
+    fn load(&self, path: &str) -> gpui::Result<Option<Cow<'static, [u8]>>> {
+        Ok(IconName::from_path(path).map(|icon| Cow::Borrowed(icon.svg())))
+    }
+
+    fn list(&self, path: &str) -> gpui::Result<Vec<SharedString>> {
+        Ok(IconName::ALL
+            .iter()
+            .map(|icon| icon.path())
+            .filter(|icon_path| icon_path.starts_with(path))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bundled_icons() {
+        for icon in IconName::ALL {
+            assert_eq!(IconName::from_path(&icon.path()), Some(*icon));
+            assert!(std::str::from_utf8(icon.svg()).unwrap().contains("<svg"));
+        }
+
+        assert_eq!(
+            IconAssets.list("icons/").unwrap().len(),
+            IconName::ALL.len()
+        );
+        assert!(IconAssets.load("icons/unknown.svg").unwrap().is_none());
+    }
+}
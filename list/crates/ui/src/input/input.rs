@@ -12,17 +12,17 @@ use crate::history::History;
 use crate::indicator::Indicator;
 use crate::theme::ActiveTheme;
 use crate::StyledExt as _;
-use crate::{event::InteractiveElementExt as _, Size};
+use crate::{event::InteractiveElementExt as _, focusable::register_tab_stop, Size};
 use crate::{Sizable, StyleSized};
 use gpui::prelude::FluentBuilder as _;
 use gpui::{
     actions, div, fill, point, px, relative, rems, size, AnyElement, AppContext, Bounds,
     ClickEvent, ClipboardItem, Context as _, Element, ElementId, ElementInputHandler, EventEmitter,
     FocusHandle, FocusableView, GlobalElementId, InteractiveElement as _, IntoElement, KeyBinding,
-    KeyDownEvent, LayoutId, Model, MouseButton, MouseDownEvent, MouseMoveEvent, MouseUpEvent,
-    PaintQuad, ParentElement as _, Pixels, Point, Render, ShapedLine, SharedString, Style,
-    Styled as _, TextRun, UTF16Selection, UnderlineStyle, View, ViewContext, ViewInputHandler,
-    WindowContext,
+    KeyContext, KeyDownEvent, LayoutId, Model, MouseButton, MouseDownEvent, MouseMoveEvent,
+    MouseUpEvent, PaintQuad, ParentElement as _, Pixels, Point, Render, ScrollWheelEvent,
+    ShapedLine, SharedString, Style, Styled as _, TextRun, UTF16Selection, UnderlineStyle, View,
+    ViewContext, ViewInputHandler, WindowContext, WrappedLine,
 };
 use unicode_segmentation::*;
 
@@ -50,6 +50,10 @@ actions!(
         MoveToStartOfLine,
         MoveToEndOfLine,
         TextChanged,
+        Up,
+        Down,
+        SelectUp,
+        SelectDown,
     ]
 );
 
@@ -62,6 +66,8 @@ pub enum InputEvent {
 }
 
 const CONTEXT: &str = "Input";
+/// The key context of the [`TextInput::multi_line`] input, in addition to the [`CONTEXT`].
+const MULTI_LINE_CONTEXT: &str = "Input && multi_line";
 
 pub fn init(cx: &mut AppContext) {
     cx.bind_keys([
@@ -76,6 +82,10 @@ pub fn init(cx: &mut AppContext) {
         KeyBinding::new("end", End, Some(CONTEXT)),
         KeyBinding::new("shift-home", SelectToHome, Some(CONTEXT)),
         KeyBinding::new("shift-end", SelectToEnd, Some(CONTEXT)),
+        KeyBinding::new("up", Up, Some(MULTI_LINE_CONTEXT)),
+        KeyBinding::new("down", Down, Some(MULTI_LINE_CONTEXT)),
+        KeyBinding::new("shift-up", SelectUp, Some(MULTI_LINE_CONTEXT)),
+        KeyBinding::new("shift-down", SelectDown, Some(MULTI_LINE_CONTEXT)),
         #[cfg(target_os = "macos")]
         KeyBinding::new("ctrl-cmd-space", ShowCharacterPalette, Some(CONTEXT)),
         #[cfg(target_os = "macos")]
@@ -122,11 +132,17 @@ pub struct TextInput {
     selection_reversed: bool,
     marked_range: Option<Range<usize>>,
     last_layout: Option<ShapedLine>,
+    /// The wrapped lines of the [`TextInput::multi_line`] input, split by `\n`.
+    last_lines: Vec<WrappedLine>,
+    last_line_height: Pixels,
+    /// The cursor of the last paint, to scroll the cursor into view only when it has been moved.
+    last_cursor_offset: Option<usize>,
     last_bounds: Option<Bounds<Pixels>>,
     scroll_offset: Point<Pixels>,
     is_selecting: bool,
     disabled: bool,
     masked: bool,
+    multi_line: bool,
     appearance: bool,
     cleanable: bool,
     size: Size,
@@ -151,11 +167,15 @@ impl TextInput {
             selection_reversed: false,
             marked_range: None,
             last_layout: None,
+            last_lines: Vec::new(),
+            last_line_height: px(20.),
+            last_cursor_offset: None,
             last_bounds: None,
             scroll_offset: point(px(0.), px(0.)),
             is_selecting: false,
             disabled: false,
             masked: false,
+            multi_line: false,
             appearance: true,
             cleanable: false,
             loading: false,
@@ -241,6 +261,15 @@ impl TextInput {
         self
     }
 
+    /// Allow to input multiple lines, the text is wrapped by the width, and scrolled vertically.
+    ///
+    /// The `enter` inserts a line break instead of emitting [`InputEvent::PressEnter`],
+    /// and the `up` and `down` move the cursor between the lines.
+    pub fn multi_line(mut self) -> Self {
+        self.multi_line = true;
+        self
+    }
+
     /// Set the prefix element of the input field, for example a search Icon.
     pub fn prefix<F, E>(mut self, builder: F) -> Self
     where
@@ -341,22 +370,62 @@ impl TextInput {
         self.select_to(self.text.len(), cx)
     }
 
+    fn up(&mut self, _: &Up, cx: &mut ViewContext<Self>) {
+        self.pause_blink_cursor(cx);
+        self.move_to(self.vertical_offset(self.cursor_offset(), -1), cx);
+    }
+
+    fn down(&mut self, _: &Down, cx: &mut ViewContext<Self>) {
+        self.pause_blink_cursor(cx);
+        self.move_to(self.vertical_offset(self.cursor_offset(), 1), cx);
+    }
+
+    fn select_up(&mut self, _: &SelectUp, cx: &mut ViewContext<Self>) {
+        self.select_to(self.vertical_offset(self.cursor_offset(), -1), cx);
+    }
+
+    fn select_down(&mut self, _: &SelectDown, cx: &mut ViewContext<Self>) {
+        self.select_to(self.vertical_offset(self.cursor_offset(), 1), cx);
+    }
+
     fn home(&mut self, _: &Home, cx: &mut ViewContext<Self>) {
         self.pause_blink_cursor(cx);
-        self.move_to(0, cx);
+        self.move_to(self.start_of_line(self.cursor_offset()), cx);
     }
 
     fn end(&mut self, _: &End, cx: &mut ViewContext<Self>) {
         self.pause_blink_cursor(cx);
-        self.move_to(self.text.len(), cx);
+        self.move_to(self.end_of_line(self.cursor_offset()), cx);
     }
 
     fn select_to_home(&mut self, _: &SelectToHome, cx: &mut ViewContext<Self>) {
-        self.select_to(0, cx);
+        self.select_to(self.start_of_line(self.cursor_offset()), cx);
     }
 
     fn select_to_end(&mut self, _: &SelectToEnd, cx: &mut ViewContext<Self>) {
-        self.select_to(self.text.len(), cx);
+        self.select_to(self.end_of_line(self.cursor_offset()), cx);
+    }
+
+    /// Returns the start of the line of the offset in the [`TextInput::multi_line`] input,
+    /// or the start of the text.
+    fn start_of_line(&self, offset: usize) -> usize {
+        if !self.multi_line {
+            return 0;
+        }
+
+        self.text[..offset].rfind('\n').map_or(0, |ix| ix + 1)
+    }
+
+    /// Returns the end of the line of the offset in the [`TextInput::multi_line`] input,
+    /// or the end of the text.
+    fn end_of_line(&self, offset: usize) -> usize {
+        if !self.multi_line {
+            return self.text.len();
+        }
+
+        self.text[offset..]
+            .find('\n')
+            .map_or(self.text.len(), |ix| offset + ix)
     }
 
     fn backspace(&mut self, _: &Backspace, cx: &mut ViewContext<Self>) {
@@ -376,7 +445,12 @@ impl TextInput {
     }
 
     fn enter(&mut self, _: &Enter, cx: &mut ViewContext<Self>) {
-        cx.emit(InputEvent::PressEnter);
+        if self.multi_line {
+            self.replace_text_in_range(None, "\n", cx);
+            self.pause_blink_cursor(cx);
+        } else {
+            cx.emit(InputEvent::PressEnter);
+        }
     }
 
     fn clean(&mut self, _: &ClickEvent, cx: &mut ViewContext<Self>) {
@@ -435,7 +509,10 @@ impl TextInput {
 
     fn paste(&mut self, _: &Paste, cx: &mut ViewContext<Self>) {
         if let Some(clipboard) = cx.read_from_clipboard() {
-            let new_text = clipboard.text().unwrap_or_default().replace('\n', "");
+            let mut new_text = clipboard.text().unwrap_or_default().replace("\r\n", "\n");
+            if !self.multi_line {
+                new_text = new_text.replace('\n', "");
+            }
             self.replace_text_in_range(None, &new_text, cx);
         }
     }
@@ -501,6 +578,12 @@ impl TextInput {
             return 0;
         }
 
+        if self.multi_line {
+            return self.last_bounds.as_ref().map_or(0, |bounds| {
+                self.offset_for_position(position - bounds.origin)
+            });
+        }
+
         let (Some(bounds), Some(line)) = (self.last_bounds.as_ref(), self.last_layout.as_ref())
         else {
             return 0;
@@ -514,6 +597,87 @@ impl TextInput {
         line.closest_index_for_x(position.x - bounds.left())
     }
 
+    /// Returns the position of the offset relative to the origin of the wrapped lines,
+    /// the `y` is the top of the visual line.
+    fn position_for_offset(&self, offset: usize) -> Option<Point<Pixels>> {
+        let line_height = self.last_line_height;
+        let mut line_top = px(0.);
+        let mut line_start = 0;
+        for line in &self.last_lines {
+            let line_end = line_start + line.len();
+            if offset <= line_end {
+                return line
+                    .position_for_index(offset - line_start, line_height)
+                    .map(|position| point(position.x, line_top + position.y));
+            }
+
+            line_top += line.size(line_height).height;
+            // Skip the `\n` between the lines.
+            line_start = line_end + 1;
+        }
+
+        None
+    }
+
+    /// Returns the offset of the character closest to the position, which is relative to
+    /// the origin of the wrapped lines.
+    fn offset_for_position(&self, position: Point<Pixels>) -> usize {
+        if position.y < px(0.) {
+            return 0;
+        }
+
+        let line_height = self.last_line_height;
+        let mut line_top = px(0.);
+        let mut line_start = 0;
+        for line in &self.last_lines {
+            let line_end = line_start + line.len();
+            let line_bottom = line_top + line.size(line_height).height;
+            if position.y < line_bottom {
+                // The visual line of the position, if the line is wrapped.
+                let row = ((position.y - line_top).0 / line_height.0).floor();
+                let line_text = self.text.get(line_start..line_end).unwrap_or_default();
+
+                return line_text
+                    .char_indices()
+                    .map(|(ix, _)| ix)
+                    .chain(Some(line_text.len()))
+                    .filter_map(|ix| {
+                        let pos = line.position_for_index(ix, line_height)?;
+                        ((pos.y.0 / line_height.0).round() == row)
+                            .then_some((ix, (pos.x - position.x).0.abs()))
+                    })
+                    .min_by(|(_, a), (_, b)| a.total_cmp(b))
+                    .map_or(line_end, |(ix, _)| line_start + ix);
+            }
+
+            line_top = line_bottom;
+            line_start = line_end + 1;
+        }
+
+        self.text.len()
+    }
+
+    /// Returns the offset of moving the cursor up (`rows < 0`) or down by the visual lines.
+    fn vertical_offset(&self, offset: usize, rows: isize) -> usize {
+        let Some(position) = self.position_for_offset(offset) else {
+            return offset;
+        };
+
+        let line_height = self.last_line_height;
+        let y = position.y + line_height * (rows as f32 + 0.5);
+        if y < px(0.) {
+            return 0;
+        }
+
+        self.offset_for_position(point(position.x, y))
+    }
+
+    fn on_scroll_wheel(&mut self, event: &ScrollWheelEvent, cx: &mut ViewContext<Self>) {
+        let delta = event.delta.pixel_delta(self.last_line_height);
+        self.scroll_offset.y = (self.scroll_offset.y + delta.y).min(px(0.));
+        cx.notify();
+    }
+
     fn select_to(&mut self, offset: usize, cx: &mut ViewContext<Self>) {
         if self.selection_reversed {
             self.selected_range.start = offset
@@ -777,8 +941,17 @@ impl ViewInputHandler for TextInput {
         bounds: Bounds<Pixels>,
         _cx: &mut ViewContext<Self>,
     ) -> Option<Bounds<Pixels>> {
-        let last_layout = self.last_layout.as_ref()?;
         let range = self.range_from_utf16(&range_utf16);
+        if self.multi_line {
+            let start = self.position_for_offset(range.start)?;
+            let end = self.position_for_offset(range.end)?;
+            return Some(Bounds::from_corners(
+                bounds.origin + start,
+                bounds.origin + point(end.x, end.y + self.last_line_height),
+            ));
+        }
+
+        let last_layout = self.last_layout.as_ref()?;
         Some(Bounds::from_corners(
             point(
                 bounds.left() + last_layout.x_for_index(range.start),
@@ -804,11 +977,124 @@ struct TextElement {
 struct PrepaintState {
     scroll_offset: Point<Pixels>,
     line: Option<ShapedLine>,
+    /// The wrapped lines of the [`TextInput::multi_line`] input.
+    lines: Vec<WrappedLine>,
+    line_height: Pixels,
+    cursor_offset: usize,
     cursor: Option<PaintQuad>,
-    selection: Option<PaintQuad>,
+    selections: Vec<PaintQuad>,
     bounds: Bounds<Pixels>,
 }
 
+impl TextElement {
+    /// Scroll the cursor into view vertically, and build the cursor and selection of the wrapped lines.
+    fn prepaint_multi_line(
+        input: &TextInput,
+        lines: Vec<WrappedLine>,
+        bounds: Bounds<Pixels>,
+        cx: &WindowContext,
+    ) -> PrepaintState {
+        let line_height = cx.line_height();
+        let selected_range = input.selected_range.clone();
+        let cursor_offset = input.cursor_offset();
+
+        // The positions of the offsets in the lines, relative to the top-left of the text.
+        let position_for_offset = |offset: usize| {
+            let mut line_top = px(0.);
+            let mut line_start = 0;
+            for line in &lines {
+                let line_end = line_start + line.len();
+                if offset <= line_end {
+                    if let Some(position) =
+                        line.position_for_index(offset - line_start, line_height)
+                    {
+                        return point(position.x, line_top + position.y);
+                    }
+                }
+                line_top += line.size(line_height).height;
+                line_start = line_end + 1;
+            }
+            point(px(0.), line_top)
+        };
+        let text_height = lines
+            .iter()
+            .map(|line| line.size(line_height).height)
+            .fold(px(0.), |a, b| a + b);
+
+        let mut scroll_offset = input.scroll_offset;
+        scroll_offset.x = px(0.);
+        let cursor_pos = position_for_offset(cursor_offset);
+        if input.last_cursor_offset != Some(cursor_offset) {
+            if scroll_offset.y + cursor_pos.y < px(0.) {
+                // cursor is out of top
+                scroll_offset.y = -cursor_pos.y;
+            } else if scroll_offset.y + cursor_pos.y + line_height > bounds.size.height {
+                // cursor is out of bottom
+                scroll_offset.y = bounds.size.height - line_height - cursor_pos.y;
+            }
+        }
+        scroll_offset.y = scroll_offset
+            .y
+            .max((bounds.size.height - text_height).min(px(0.)))
+            .min(px(0.));
+
+        let mut bounds = bounds;
+        bounds.origin = bounds.origin + scroll_offset;
+
+        let inset = px(0.5);
+        let mut selections = vec![];
+        let mut cursor = None;
+        if selected_range.is_empty() {
+            if input.show_cursor(cx) {
+                cursor = Some(fill(
+                    Bounds::new(
+                        point(
+                            bounds.left() + cursor_pos.x,
+                            bounds.top() + cursor_pos.y + inset,
+                        ),
+                        size(px(2.), line_height - inset * 2),
+                    ),
+                    crate::blue_500(),
+                ));
+            }
+        } else {
+            let start = position_for_offset(selected_range.start);
+            let end = position_for_offset(selected_range.end);
+            let right = bounds.size.width;
+            let mut selection = |left: Pixels, top: Pixels, right: Pixels, bottom: Pixels| {
+                selections.push(fill(
+                    Bounds::from_corners(
+                        point(bounds.left() + left, bounds.top() + top),
+                        point(bounds.left() + right, bounds.top() + bottom),
+                    ),
+                    cx.theme().tokens.selection,
+                ));
+            };
+
+            if start.y == end.y {
+                selection(start.x, start.y, end.x, end.y + line_height);
+            } else {
+                selection(start.x, start.y, right, start.y + line_height);
+                if end.y > start.y + line_height {
+                    selection(px(0.), start.y + line_height, right, end.y);
+                }
+                selection(px(0.), end.y, end.x, end.y + line_height);
+            }
+        }
+
+        PrepaintState {
+            scroll_offset,
+            bounds,
+            line: None,
+            lines,
+            line_height,
+            cursor_offset,
+            cursor,
+            selections,
+        }
+    }
+}
+
 impl IntoElement for TextElement {
     type Element = Self;
 
@@ -832,7 +1118,11 @@ impl Element for TextElement {
     ) -> (LayoutId, Self::RequestLayoutState) {
         let mut style = Style::default();
         style.size.width = relative(1.).into();
-        style.size.height = cx.line_height().into();
+        style.size.height = if self.input.read(cx).multi_line {
+            relative(1.).into()
+        } else {
+            cx.line_height().into()
+        };
         (cx.request_layout(style, []), ())
     }
 
@@ -851,14 +1141,14 @@ impl Element for TextElement {
         let style = cx.text_style();
 
         let (display_text, text_color) = if text.is_empty() {
-            (placeholder, cx.theme().muted_foreground)
+            (placeholder, cx.theme().tokens.on_surface_muted)
         } else if input.masked {
             (
                 "*".repeat(text.chars().count()).into(),
-                cx.theme().foreground,
+                cx.theme().tokens.on_surface,
             )
         } else {
-            (text, cx.theme().foreground)
+            (text, cx.theme().tokens.on_surface)
         };
 
         let run = TextRun {
@@ -898,6 +1188,16 @@ impl Element for TextElement {
         };
 
         let font_size = style.font_size.to_pixels(cx.rem_size());
+        if input.multi_line {
+            let lines = cx
+                .text_system()
+                .shape_text(display_text, font_size, &runs, Some(bounds.size.width))
+                .unwrap()
+                .into_iter()
+                .collect();
+            return Self::prepaint_multi_line(input, lines, bounds, cx);
+        }
+
         let line = cx
             .text_system()
             .shape_line(display_text, font_size, &runs)
@@ -939,7 +1239,7 @@ impl Element for TextElement {
         let (selection, cursor) = if selected_range.is_empty() && input.show_cursor(cx) {
             // cursor blink
             (
-                None,
+                vec![],
                 Some(fill(
                     Bounds::new(
                         point(bounds.left() + cursor_pos, bounds.top() + inset),
@@ -951,7 +1251,7 @@ impl Element for TextElement {
         } else {
             // selection background
             (
-                Some(fill(
+                vec![fill(
                     Bounds::from_corners(
                         point(
                             bounds.left() + line.x_for_index(selected_range.start),
@@ -962,8 +1262,8 @@ impl Element for TextElement {
                             bounds.bottom(),
                         ),
                     ),
-                    cx.theme().selection,
-                )),
+                    cx.theme().tokens.selection,
+                )],
                 None,
             )
         };
@@ -972,8 +1272,11 @@ impl Element for TextElement {
             scroll_offset,
             bounds,
             line: Some(line),
+            lines: vec![],
+            line_height: cx.line_height(),
+            cursor_offset: input.cursor_offset(),
             cursor,
-            selection,
+            selections: selection,
         }
     }
 
@@ -993,20 +1296,36 @@ impl Element for TextElement {
             &focus_handle,
             ElementInputHandler::new(bounds, self.input.clone()),
         );
-        if let Some(selection) = prepaint.selection.take() {
+        for selection in prepaint.selections.drain(..) {
             cx.paint_quad(selection)
         }
-        let line = prepaint.line.take().unwrap();
-        line.paint(bounds.origin, cx.line_height(), cx).unwrap();
+        let line = prepaint.line.take();
+        if let Some(line) = line.as_ref() {
+            line.paint(bounds.origin, cx.line_height(), cx).unwrap();
+        }
+        let mut line_top = px(0.);
+        for line in &prepaint.lines {
+            line.paint(
+                bounds.origin + point(px(0.), line_top),
+                prepaint.line_height,
+                cx,
+            )
+            .unwrap();
+            line_top += line.size(prepaint.line_height).height;
+        }
 
         if focused {
             if let Some(cursor) = prepaint.cursor.take() {
                 cx.paint_quad(cursor);
             }
         }
+        let lines = std::mem::take(&mut prepaint.lines);
         self.input.update(cx, |input, _cx| {
             input.scroll_offset = prepaint.scroll_offset;
-            input.last_layout = Some(line);
+            input.last_layout = line;
+            input.last_lines = lines;
+            input.last_line_height = prepaint.line_height;
+            input.last_cursor_offset = Some(prepaint.cursor_offset);
             input.last_bounds = Some(bounds);
         });
     }
@@ -1015,13 +1334,22 @@ impl Element for TextElement {
 impl Render for TextInput {
     fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
         let focused = self.focus_handle.is_focused(cx);
+        if !self.disabled {
+            register_tab_stop(&self.focus_handle, cx);
+        }
 
         let prefix = self.prefix.as_ref().map(|build| build(cx));
         let suffix = self.suffix.as_ref().map(|build| build(cx));
 
+        let mut key_context = KeyContext::default();
+        key_context.add(CONTEXT);
+        if self.multi_line {
+            key_context.add("multi_line");
+        }
+
         div()
             .flex()
-            .key_context(CONTEXT)
+            .key_context(key_context)
             .track_focus(&self.focus_handle)
             .when(!self.disabled, |this| {
                 this.on_action(cx.listener(Self::backspace))
@@ -1044,6 +1372,13 @@ impl Render for TextInput {
             .on_action(cx.listener(Self::undo))
             .on_action(cx.listener(Self::redo))
             .on_action(cx.listener(Self::redo))
+            .when(self.multi_line, |this| {
+                this.on_action(cx.listener(Self::up))
+                    .on_action(cx.listener(Self::down))
+                    .on_action(cx.listener(Self::select_up))
+                    .on_action(cx.listener(Self::select_down))
+                    .on_scroll_wheel(cx.listener(Self::on_scroll_wheel))
+            })
             // Double click to select all
             .on_double_click(cx.listener(|view, _, cx| {
                 view.select_all(&SelectAll, cx);
@@ -1057,12 +1392,12 @@ impl Render for TextInput {
             .line_height(rems(1.25))
             .text_size(rems(0.875))
             .input_py(self.size)
-            .input_h(self.size)
+            .when(!self.multi_line, |this| this.input_h(self.size))
             .when(self.appearance, |this| {
                 this.bg(if self.disabled {
-                    cx.theme().muted
+                    cx.theme().tokens.surface_muted
                 } else {
-                    cx.theme().background
+                    cx.theme().tokens.surface
                 })
                 .border_color(cx.theme().input)
                 .border_1()
@@ -1074,11 +1409,18 @@ impl Render for TextInput {
             })
             .children(prefix)
             .gap_1()
-            .items_center()
+            .map(|this| {
+                if self.multi_line {
+                    this.items_start()
+                } else {
+                    this.items_center()
+                }
+            })
             .child(
                 div()
                     .id("TextElement")
                     .flex_grow()
+                    .when(self.multi_line, |this| this.h_full().overflow_y_hidden())
                     .overflow_x_hidden()
                     .cursor_text()
                     .child(TextElement {
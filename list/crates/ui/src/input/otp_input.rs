@@ -204,8 +204,10 @@ impl Render for OtpInput {
                     .id(("input-otp", i))
                     .border_1()
                     .border_color(cx.theme().input)
-                    .bg(cx.theme().background)
-                    .when(is_input_focused, |this| this.border_color(cx.theme().ring))
+                    .bg(cx.theme().tokens.surface)
+                    .when(is_input_focused, |this| {
+                        this.border_color(cx.theme().tokens.focus)
+                    })
                     .shadow_sm()
                     .items_center()
                     .justify_center()
@@ -224,7 +226,7 @@ impl Render for OtpInput {
                             if self.masked {
                                 this.child(
                                     Icon::new(IconName::Asterisk)
-                                        .text_color(cx.theme().secondary_foreground)
+                                        .text_color(cx.theme().tokens.on_neutral)
                                         .with_size(text_size),
                                 )
                             } else {
@@ -0,0 +1,156 @@
+use gpui::{
+    AnyElement, AppContext, Bounds, DispatchPhase, Element, ElementId, Global, GlobalElementId,
+    Hitbox, IntoElement, LayoutId, MouseMoveEvent, Pixels, SharedString, StyleRefinement, Styled,
+    WindowContext,
+};
+
+/// The element under the mouse, reported by [`InspectorExt::inspect`] while the [`Inspector`] is enabled.
+#[derive(Clone)]
+pub struct InspectedElement {
+    /// The name of the component, e.g.: `Button`.
+    pub name: SharedString,
+    /// The bounds in the window.
+    pub bounds: Bounds<Pixels>,
+    /// The rem size to resolve the rem lengths of the style.
+    pub rem_size: Pixels,
+    /// The style set by the component, without the states like hover and active.
+    pub style: StyleRefinement,
+}
+
+/// The global state of the inspector, observe it to be notified when the hovered element changes.
+#[derive(Default)]
+pub struct Inspector {
+    /// The number of the enabled inspectors, the elements are only wrapped when it's not zero.
+    enabled: usize,
+    hovered: Option<InspectedElement>,
+}
+
+impl Global for Inspector {}
+
+impl Inspector {
+    /// Start to report the hovered elements, call [`Inspector::disable`] when the inspector is closed.
+    pub fn enable(cx: &mut AppContext) {
+        cx.default_global::<Inspector>().enabled += 1;
+        cx.refresh();
+    }
+
+    pub fn disable(cx: &mut AppContext) {
+        let inspector = cx.default_global::<Inspector>();
+        inspector.enabled = inspector.enabled.saturating_sub(1);
+        if inspector.enabled == 0 {
+            inspector.hovered = None;
+        }
+        cx.refresh();
+    }
+
+    pub fn is_enabled(cx: &AppContext) -> bool {
+        cx.try_global::<Inspector>()
+            .map_or(false, |inspector| inspector.enabled > 0)
+    }
+
+    /// Returns the last hovered element.
+    pub fn hovered(cx: &AppContext) -> Option<&InspectedElement> {
+        cx.try_global::<Inspector>()?.hovered.as_ref()
+    }
+
+    fn hover(element: InspectedElement, cx: &mut WindowContext) {
+        // Hold `alt` to keep the element, then the mouse can be moved to the inspector.
+        if cx.modifiers().alt {
+            return;
+        }
+        if let Some(hovered) = Self::hovered(cx) {
+            if hovered.name == element.name && hovered.bounds == element.bounds {
+                return;
+            }
+        }
+
+        cx.update_global::<Inspector, _>(|inspector, _| inspector.hovered = Some(element));
+    }
+}
+
+pub trait InspectorExt: Styled + IntoElement + Sized {
+    /// Report the style and the bounds to the [`Inspector`] when it's hovered,
+    /// the element is not wrapped if the inspector is not enabled.
+    fn inspect(mut self, name: impl Into<SharedString>, cx: &WindowContext) -> AnyElement {
+        if !Inspector::is_enabled(cx) {
+            return self.into_any_element();
+        }
+
+        let style = self.style().clone();
+        Inspectable {
+            name: name.into(),
+            style,
+            child: self.into_any_element(),
+        }
+        .into_any_element()
+    }
+}
+
+impl<T: Styled + IntoElement> InspectorExt for T {}
+
+struct Inspectable {
+    name: SharedString,
+    style: StyleRefinement,
+    child: AnyElement,
+}
+
+impl IntoElement for Inspectable {
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {
+        self
+    }
+}
+
+impl Element for Inspectable {
+    type RequestLayoutState = ();
+    type PrepaintState = Hitbox;
+
+    fn id(&self) -> Option<ElementId> {
+        None
+    }
+
+    fn request_layout(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        cx: &mut WindowContext,
+    ) -> (LayoutId, Self::RequestLayoutState) {
+        (self.child.request_layout(cx), ())
+    }
+
+    fn prepaint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        bounds: Bounds<Pixels>,
+        _request_layout: &mut Self::RequestLayoutState,
+        cx: &mut WindowContext,
+    ) -> Self::PrepaintState {
+        self.child.prepaint(cx);
+        cx.insert_hitbox(bounds, false)
+    }
+
+    fn paint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        bounds: Bounds<Pixels>,
+        _request_layout: &mut Self::RequestLayoutState,
+        hitbox: &mut Self::PrepaintState,
+        cx: &mut WindowContext,
+    ) {
+        self.child.paint(cx);
+
+        // Registered after the children, so the innermost hovered element is reported last in the bubble phase.
+        let hitbox = hitbox.clone();
+        let element = InspectedElement {
+            name: self.name.clone(),
+            bounds,
+            rem_size: cx.rem_size(),
+            style: self.style.clone(),
+        };
+        cx.on_mouse_event(move |_: &MouseMoveEvent, phase, cx| {
+            if phase == DispatchPhase::Bubble && hitbox.is_hovered(cx) {
+                Inspector::hover(element.clone(), cx);
+            }
+        });
+    }
+}
@@ -0,0 +1,319 @@
+use std::rc::Rc;
+
+use gpui::{
+    div, prelude::FluentBuilder as _, px, AnyElement, Div, ElementId, InteractiveElement as _,
+    IntoElement, ParentElement, RenderOnce, SharedString, Styled, WindowContext,
+};
+use smallvec::SmallVec;
+
+use crate::{
+    drag_drop::{DragDropExt as _, Draggable, DropIndicator, DropTarget},
+    h_flex,
+    tag::Badge,
+    theme::ActiveTheme as _,
+    v_flex, Placement, Sizable as _,
+};
+
+type OnMove = Rc<dyn Fn(&CardMove, &mut WindowContext)>;
+
+/// The move of a card of a kanban board, dropped by dragging it to a [`KanbanColumn`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CardMove {
+    pub from_column: usize,
+    pub from_ix: usize,
+    pub to_column: usize,
+    /// The index of the card to insert before in the target column, or the count of the cards to append.
+    pub to_ix: usize,
+}
+
+impl CardMove {
+    /// Move the card in the cards of the columns, returns false if the move is out of bounds.
+    pub fn apply<T>(&self, columns: &mut [Vec<T>]) -> bool {
+        let (Some(from), Some(to)) = (columns.get(self.from_column), columns.get(self.to_column))
+        else {
+            return false;
+        };
+        if self.from_ix >= from.len() || self.to_ix > to.len() {
+            return false;
+        }
+
+        let card = columns[self.from_column].remove(self.from_ix);
+        // The index is shifted by removing the card before it in the same column.
+        let to_ix = if self.from_column == self.to_column && self.from_ix < self.to_ix {
+            self.to_ix - 1
+        } else {
+            self.to_ix
+        };
+        columns[self.to_column].insert(to_ix, card);
+        true
+    }
+}
+
+/// The dragging card of a [`KanbanColumn`].
+#[derive(Clone)]
+pub struct DragCard {
+    column_ix: usize,
+    card_ix: usize,
+    title: SharedString,
+}
+
+impl DragCard {
+    fn card_move(&self, to_column: usize, to_ix: usize) -> CardMove {
+        CardMove {
+            from_column: self.column_ix,
+            from_ix: self.card_ix,
+            to_column,
+            to_ix,
+        }
+    }
+}
+
+impl Draggable for DragCard {
+    fn render_preview(&self, cx: &mut WindowContext) -> AnyElement {
+        div()
+            .id("drag-card")
+            .cursor_grab()
+            .w(px(220.))
+            .p_3()
+            .border_1()
+            .border_color(cx.theme().drag_border)
+            .rounded_md()
+            .shadow_md()
+            .bg(cx.theme().tokens.surface_raised)
+            .text_color(cx.theme().tokens.on_surface_raised)
+            .text_sm()
+            .opacity(0.9)
+            .child(self.title.clone())
+            .into_any_element()
+    }
+}
+
+/// A card of a [`KanbanColumn`], with a title, an optional description, and the children in the footer,
+/// e.g.: the tags and the avatar of the assignee.
+#[derive(IntoElement)]
+pub struct KanbanCard {
+    id: ElementId,
+    base: Div,
+    title: SharedString,
+    description: Option<SharedString>,
+    children: SmallVec<[AnyElement; 2]>,
+    /// The column and the index of the card, set by the column.
+    position: (usize, usize),
+    on_move: Option<OnMove>,
+}
+
+impl KanbanCard {
+    pub fn new(id: impl Into<ElementId>, title: impl Into<SharedString>) -> Self {
+        Self {
+            id: id.into(),
+            base: v_flex(),
+            title: title.into(),
+            description: None,
+            children: SmallVec::new(),
+            position: (0, 0),
+            on_move: None,
+        }
+    }
+
+    pub fn description(mut self, description: impl Into<SharedString>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+}
+
+impl ParentElement for KanbanCard {
+    fn extend(&mut self, elements: impl IntoIterator<Item = AnyElement>) {
+        self.children.extend(elements)
+    }
+}
+
+impl Styled for KanbanCard {
+    fn style(&mut self) -> &mut gpui::StyleRefinement {
+        self.base.style()
+    }
+}
+
+impl RenderOnce for KanbanCard {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let (column_ix, card_ix) = self.position;
+        let drag = DragCard {
+            column_ix,
+            card_ix,
+            title: self.title.clone(),
+        };
+
+        self.base
+            .id(self.id)
+            .gap_2()
+            .p_3()
+            .border_1()
+            .border_color(cx.theme().tokens.outline)
+            .rounded_md()
+            .bg(cx.theme().tokens.surface_raised)
+            .text_color(cx.theme().tokens.on_surface_raised)
+            .cursor_grab()
+            .child(div().text_sm().child(self.title))
+            .when_some(self.description, |this, description| {
+                this.child(
+                    div()
+                        .text_xs()
+                        .text_color(cx.theme().tokens.on_surface_muted)
+                        .child(description),
+                )
+            })
+            .when(!self.children.is_empty(), |this| {
+                this.child(h_flex().gap_2().flex_wrap().children(self.children))
+            })
+            .when_some(self.on_move, |this, on_move| {
+                this.drag_source(drag).drop_target(
+                    DropTarget::new(move |drag: &DragCard, cx| {
+                        on_move(&drag.card_move(column_ix, card_ix), cx)
+                    })
+                    .can_drop(move |drag, _| (drag.column_ix, drag.card_ix) != (column_ix, card_ix))
+                    // Show the drop line above, the dragged card is inserted before this one.
+                    .indicator(|_, _| DropIndicator::Line(Placement::Top)),
+                )
+            })
+    }
+}
+
+/// A column of a kanban board, the [`KanbanCard`]s can be dragged to reorder them,
+/// or to move them to the other columns of the board.
+///
+/// The columns only report the [`CardMove`] by the [`KanbanColumn::on_move`],
+/// apply it to the cards with the [`CardMove::apply`].
+#[derive(IntoElement)]
+pub struct KanbanColumn {
+    id: ElementId,
+    base: Div,
+    ix: usize,
+    title: SharedString,
+    cards: Vec<KanbanCard>,
+    on_move: Option<OnMove>,
+}
+
+impl KanbanColumn {
+    /// Create the column at the index `ix` of the board.
+    pub fn new(id: impl Into<ElementId>, ix: usize, title: impl Into<SharedString>) -> Self {
+        Self {
+            id: id.into(),
+            base: v_flex(),
+            ix,
+            title: title.into(),
+            cards: Vec::new(),
+            on_move: None,
+        }
+    }
+
+    pub fn card(mut self, card: KanbanCard) -> Self {
+        self.cards.push(card);
+        self
+    }
+
+    pub fn cards(mut self, cards: impl IntoIterator<Item = KanbanCard>) -> Self {
+        self.cards.extend(cards);
+        self
+    }
+
+    /// Set the handler of dropping a card to this column, the card can be from any column of the board.
+    pub fn on_move(mut self, handler: impl Fn(&CardMove, &mut WindowContext) + 'static) -> Self {
+        self.on_move = Some(Rc::new(handler));
+        self
+    }
+}
+
+impl Styled for KanbanColumn {
+    fn style(&mut self) -> &mut gpui::StyleRefinement {
+        self.base.style()
+    }
+}
+
+impl RenderOnce for KanbanColumn {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let column_ix = self.ix;
+        let cards_count = self.cards.len();
+        let on_move = self.on_move;
+
+        self.base
+            .id(self.id)
+            .w(px(260.))
+            .flex_shrink_0()
+            .gap_2()
+            .p_2()
+            .rounded_lg()
+            .bg(cx.theme().tokens.surface_muted)
+            .child(
+                h_flex()
+                    .gap_2()
+                    .px_1()
+                    .text_sm()
+                    .child(div().flex_1().child(self.title))
+                    .child(Badge::count(cards_count, 99).small()),
+            )
+            .children(
+                self.cards
+                    .into_iter()
+                    .enumerate()
+                    .map(|(card_ix, mut card)| {
+                        card.position = (column_ix, card_ix);
+                        card.on_move = on_move.clone();
+                        card
+                    }),
+            )
+            // The rest of the column to append the dropped card to the end.
+            .child(
+                div()
+                    .id("kanban-column-end")
+                    .flex_1()
+                    .min_h_12()
+                    .rounded_md()
+                    .when_some(on_move, |this, on_move| {
+                        this.drop_target(DropTarget::new(move |drag: &DragCard, cx| {
+                            on_move(&drag.card_move(column_ix, cards_count), cx)
+                        }))
+                    }),
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CardMove;
+
+    fn apply(columns: &mut [Vec<&str>], from: (usize, usize), to: (usize, usize)) -> bool {
+        CardMove {
+            from_column: from.0,
+            from_ix: from.1,
+            to_column: to.0,
+            to_ix: to.1,
+        }
+        .apply(columns)
+    }
+
+    #[test]
+    fn test_apply_card_move() {
+        let mut columns = vec![vec!["a", "b", "c"], vec!["d"]];
+
+        // Move to the other column.
+        assert!(apply(&mut columns, (0, 0), (1, 0)));
+        assert_eq!(columns, [vec!["b", "c"], vec!["a", "d"]]);
+
+        // Append to the end.
+        assert!(apply(&mut columns, (0, 1), (1, 2)));
+        assert_eq!(columns, [vec!["b"], vec!["a", "d", "c"]]);
+
+        // Move down in the same column, inserted before the card dropped on.
+        assert!(apply(&mut columns, (1, 0), (1, 2)));
+        assert_eq!(columns, [vec!["b"], vec!["d", "a", "c"]]);
+
+        // Move up in the same column.
+        assert!(apply(&mut columns, (1, 2), (1, 0)));
+        assert_eq!(columns, [vec!["b"], vec!["c", "d", "a"]]);
+
+        // Out of bounds.
+        assert!(!apply(&mut columns, (0, 1), (1, 0)));
+        assert!(!apply(&mut columns, (0, 0), (1, 4)));
+        assert!(!apply(&mut columns, (0, 0), (2, 0)));
+        assert_eq!(columns, [vec!["b"], vec!["c", "d", "a"]]);
+    }
+}
@@ -1,6 +1,8 @@
+use std::ops::Range;
+
 use gpui::{
-    div, prelude::FluentBuilder, rems, Div, IntoElement, ParentElement, RenderOnce, SharedString,
-    Styled, WindowContext,
+    div, prelude::FluentBuilder, rems, Div, FontWeight, HighlightStyle, IntoElement, ParentElement,
+    Refineable as _, RenderOnce, SharedString, Styled, StyledText, WindowContext,
 };
 
 use crate::{h_flex, theme::ActiveTheme};
@@ -19,6 +21,7 @@ pub struct Label {
     label: SharedString,
     align: TextAlign,
     marked: bool,
+    highlights: Vec<Range<usize>>,
 }
 
 impl Label {
@@ -28,6 +31,7 @@ impl Label {
             label: label.into(),
             align: TextAlign::default(),
             marked: false,
+            highlights: Vec::new(),
         }
     }
 
@@ -55,6 +59,12 @@ impl Label {
         self.marked = masked;
         self
     }
+
+    /// Highlight the byte ranges of the label, e.g.: the matched ranges of a search query.
+    pub fn highlights(mut self, ranges: impl IntoIterator<Item = Range<usize>>) -> Self {
+        self.highlights = ranges.into_iter().collect();
+        self
+    }
 }
 
 impl Styled for Label {
@@ -66,16 +76,33 @@ impl Styled for Label {
 const MASKED: &'static str = "•";
 
 impl RenderOnce for Label {
-    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+    fn render(mut self, cx: &mut WindowContext) -> impl IntoElement {
         let text = self.label;
 
         let text_display = if self.marked {
-            MASKED.repeat(text.chars().count())
+            StyledText::new(MASKED.repeat(text.chars().count()))
+        } else if self.highlights.is_empty() {
+            StyledText::new(text)
         } else {
-            text.to_string()
+            let mut text_style = cx.text_style();
+            text_style.color = cx.theme().tokens.on_surface;
+            text_style.font_family = cx.theme().font_family.clone();
+            if let Some(refinement) = self.base.style().text.as_ref() {
+                text_style.refine(refinement);
+            }
+
+            let highlight = HighlightStyle {
+                color: Some(cx.theme().tokens.accent),
+                font_weight: Some(FontWeight::BOLD),
+                ..Default::default()
+            };
+            StyledText::new(text).with_highlights(
+                &text_style,
+                self.highlights.into_iter().map(|range| (range, highlight)),
+            )
         };
 
-        div().text_color(cx.theme().foreground).child(
+        div().text_color(cx.theme().tokens.on_surface).child(
             self.base
                 .map(|this| match self.align {
                     TextAlign::Left => this.justify_start(),
@@ -6,25 +6,40 @@ mod root;
 mod styled;
 mod svg_img;
 mod time;
+mod with_rem_size;
 
+pub mod accessibility;
 pub mod animation;
+pub mod avatar;
+pub mod breadcrumb;
 pub mod button;
+pub mod chart;
 pub mod checkbox;
 pub mod clipboard;
 pub mod color_picker;
+pub mod column_table;
 pub mod context_menu;
 pub mod divider;
 pub mod dock;
+pub mod drag_drop;
 pub mod drawer;
 pub mod dropdown;
+pub mod empty_state;
+pub mod file_dialog;
+pub mod fuzzy;
 pub mod history;
 pub mod indicator;
 pub mod input;
+pub mod inspector;
+pub mod kanban;
 pub mod label;
 pub mod link;
 pub mod list;
+pub mod menu_bar;
 pub mod modal;
 pub mod notification;
+pub mod number_input;
+pub mod perf;
 pub mod popover;
 pub mod popup_menu;
 pub mod prelude;
@@ -34,19 +49,24 @@ pub mod resizable;
 pub mod scroll;
 pub mod skeleton;
 pub mod slider;
+pub mod stepper;
 pub mod switch;
 pub mod tab;
 pub mod table;
+pub mod tag;
+pub mod task_manager;
 pub mod theme;
 pub mod tooltip;
+pub mod tree;
 pub mod webview;
+pub mod wizard;
 
 // re-export
 pub use wry;
 
 pub use crate::Disableable;
 pub use event::InteractiveElementExt;
-pub use focusable::FocusableCycle;
+pub use focusable::{FocusableCycle, TabStop};
 pub use root::{ContextModal, Root};
 pub use styled::*;
 pub use time::*;
@@ -54,17 +74,23 @@ pub use time::*;
 pub use colors::*;
 pub use icon::*;
 pub use svg_img::*;
+pub use with_rem_size::*;
 
 /// Initialize the UI module.
 pub fn init(cx: &mut gpui::AppContext) {
+    focusable::init(cx);
     input::init(cx);
+    number_input::init(cx);
     list::init(cx);
+    modal::init(cx);
     dropdown::init(cx);
     date_picker::init(cx);
     popover::init(cx);
     popup_menu::init(cx);
     context_menu::init(cx);
     table::init(cx);
+    task_manager::init(cx);
+    tree::init(cx);
     webview::init(cx)
 }
 
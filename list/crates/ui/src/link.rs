@@ -53,18 +53,18 @@ impl RenderOnce for Link {
         let on_click = self.on_click;
 
         div()
-            .text_color(cx.theme().link)
+            .text_color(cx.theme().tokens.link)
             .text_decoration_1()
-            .text_decoration_color(cx.theme().link)
+            .text_decoration_color(cx.theme().tokens.link)
             .hover(|this| {
-                this.text_color(cx.theme().link.opacity(0.8))
+                this.text_color(cx.theme().tokens.link.opacity(0.8))
                     .text_decoration_1()
             })
             .cursor_pointer()
             .child(
                 self.base
                     .active(|this| {
-                        this.text_color(cx.theme().link.opacity(0.6))
+                        this.text_color(cx.theme().tokens.link.opacity(0.6))
                             .text_decoration_1()
                     })
                     .on_mouse_down(MouseButton::Left, |_, cx| {
@@ -0,0 +1,121 @@
+use gpui::{
+    div, AppContext, IntoElement, ParentElement as _, Styled as _, Task, ViewContext, WindowContext,
+};
+use rust_i18n::t;
+
+use crate::{h_flex, indicator::Indicator, theme::ActiveTheme as _, Sizable as _};
+
+use super::{List, ListDelegate, ListItem};
+
+/// A source of the items that are too many to load at once, the items are loaded page by page.
+pub trait DataSource: 'static {
+    type Item: 'static;
+
+    /// Load at most `limit` items from the `offset`.
+    ///
+    /// Returns fewer items than the `limit` means there are no more items.
+    fn load_page(&self, offset: usize, limit: usize, cx: &mut AppContext) -> Task<Vec<Self::Item>>;
+}
+
+type RenderItem<T> = Box<dyn Fn(&T, usize, &mut WindowContext) -> ListItem>;
+
+/// A [`ListDelegate`] for a [`DataSource`], the next page is loaded when the list is scrolled near the end.
+pub struct PagedListDelegate<S: DataSource> {
+    source: S,
+    page_size: usize,
+    items: Vec<S::Item>,
+    render_item: RenderItem<S::Item>,
+    selected_index: Option<usize>,
+    loading: bool,
+    has_more: bool,
+}
+
+impl<S: DataSource> PagedListDelegate<S> {
+    pub fn new(
+        source: S,
+        render_item: impl Fn(&S::Item, usize, &mut WindowContext) -> ListItem + 'static,
+    ) -> Self {
+        Self {
+            source,
+            page_size: 100,
+            items: vec![],
+            render_item: Box::new(render_item),
+            selected_index: None,
+            loading: false,
+            has_more: true,
+        }
+    }
+
+    /// Set the number of the items to load at a time, default: 100
+    pub fn page_size(mut self, page_size: usize) -> Self {
+        self.page_size = page_size.max(1);
+        self
+    }
+
+    /// Returns the loaded items.
+    pub fn items(&self) -> &[S::Item] {
+        &self.items
+    }
+
+    pub fn source(&self) -> &S {
+        &self.source
+    }
+
+    /// Returns true if a page is loading.
+    pub fn is_loading(&self) -> bool {
+        self.loading
+    }
+}
+
+impl<S: DataSource> ListDelegate for PagedListDelegate<S> {
+    type Item = ListItem;
+
+    /// Include a loading row at the end when a page is loading.
+    fn items_count(&self) -> usize {
+        self.items.len() + usize::from(self.loading)
+    }
+
+    fn render_item(&self, ix: usize, cx: &mut ViewContext<List<Self>>) -> Option<Self::Item> {
+        if let Some(item) = self.items.get(ix) {
+            let selected = Some(ix) == self.selected_index;
+            return Some((self.render_item)(item, ix, cx).selected(selected));
+        }
+
+        Some(
+            ListItem::new("loading-more").disabled(true).child(
+                h_flex()
+                    .gap_2()
+                    .text_color(cx.theme().tokens.on_surface_muted)
+                    .child(Indicator::new().small())
+                    .child(div().text_sm().child(t!("List.Loading").to_string())),
+            ),
+        )
+    }
+
+    fn set_selected_index(&mut self, ix: Option<usize>, _: &mut ViewContext<List<Self>>) {
+        self.selected_index = ix.filter(|ix| *ix < self.items.len());
+    }
+
+    fn can_load_more(&self, _: &AppContext) -> bool {
+        self.has_more && !self.loading
+    }
+
+    fn load_more(&mut self, cx: &mut ViewContext<List<Self>>) {
+        let limit = self.page_size;
+        let load = self.source.load_page(self.items.len(), limit, cx);
+        self.loading = true;
+        cx.notify();
+
+        cx.spawn(|list, mut cx| async move {
+            let items = load.await;
+            let _ = list.update(&mut cx, |list, cx| {
+                let delegate = list.delegate_mut();
+                delegate.has_more = items.len() >= limit;
+                delegate.items.extend(items);
+                delegate.loading = false;
+                cx.notify();
+            });
+        })
+        .detach();
+    }
+}
@@ -0,0 +1,251 @@
+use gpui::{
+    div, prelude::FluentBuilder as _, IntoElement, ParentElement as _, RenderOnce, SharedString,
+    Styled as _, WindowContext,
+};
+
+use crate::{h_flex, theme::ActiveTheme as _, Icon, IconName, Sizable as _};
+
+/// A section of the [`ListGroups`].
+pub struct ListGroup<T> {
+    pub title: SharedString,
+    pub items: Vec<T>,
+    collapsed: bool,
+}
+
+impl<T> ListGroup<T> {
+    pub fn is_collapsed(&self) -> bool {
+        self.collapsed
+    }
+
+    /// The number of the rows of the group, including the header.
+    fn rows_count(&self) -> usize {
+        1 + if self.collapsed { 0 } else { self.items.len() }
+    }
+}
+
+/// A row of the [`ListGroups`].
+pub enum ListGroupRow<'a, T> {
+    Header(&'a ListGroup<T>),
+    Item(&'a T),
+}
+
+/// The items grouped into the sections, flattened into the rows of a [`crate::list::List`],
+/// each group has a header row followed by its items, the items are hidden if the group is collapsed.
+///
+/// Use it in a [`crate::list::ListDelegate`], return the header rows from the
+/// [`crate::list::ListDelegate::is_group_header`], and toggle them in the
+/// [`crate::list::ListDelegate::toggle_group`].
+pub struct ListGroups<T> {
+    groups: Vec<ListGroup<T>>,
+}
+
+impl<T> Default for ListGroups<T> {
+    fn default() -> Self {
+        Self { groups: Vec::new() }
+    }
+}
+
+impl<T> ListGroups<T> {
+    pub fn new(groups: impl IntoIterator<Item = (impl Into<SharedString>, Vec<T>)>) -> Self {
+        Self {
+            groups: groups
+                .into_iter()
+                .map(|(title, items)| ListGroup {
+                    title: title.into(),
+                    items,
+                    collapsed: false,
+                })
+                .collect(),
+        }
+    }
+
+    /// Group the items by the key, the groups are in the order of the first item of them.
+    pub fn group_by<K>(items: impl IntoIterator<Item = T>, key: impl Fn(&T) -> K) -> Self
+    where
+        K: Into<SharedString>,
+    {
+        let mut groups: Vec<ListGroup<T>> = Vec::new();
+        for item in items {
+            let title: SharedString = key(&item).into();
+            match groups.iter_mut().find(|group| group.title == title) {
+                Some(group) => group.items.push(item),
+                None => groups.push(ListGroup {
+                    title,
+                    items: vec![item],
+                    collapsed: false,
+                }),
+            }
+        }
+
+        Self { groups }
+    }
+
+    pub fn groups(&self) -> &[ListGroup<T>] {
+        &self.groups
+    }
+
+    /// The number of the rows, including the headers and the items of the expanded groups.
+    pub fn rows_count(&self) -> usize {
+        self.groups.iter().map(ListGroup::rows_count).sum()
+    }
+
+    /// Returns the index of the group and the index of the item in the group of the row,
+    /// the item index is `None` for a header row.
+    fn locate(&self, ix: usize) -> Option<(usize, Option<usize>)> {
+        let mut start = 0;
+        for (group_ix, group) in self.groups.iter().enumerate() {
+            let count = group.rows_count();
+            if ix < start + count {
+                return Some((group_ix, (ix - start).checked_sub(1)));
+            }
+            start += count;
+        }
+
+        None
+    }
+
+    pub fn row(&self, ix: usize) -> Option<ListGroupRow<T>> {
+        let (group_ix, item_ix) = self.locate(ix)?;
+        let group = &self.groups[group_ix];
+        match item_ix {
+            None => Some(ListGroupRow::Header(group)),
+            Some(item_ix) => group.items.get(item_ix).map(ListGroupRow::Item),
+        }
+    }
+
+    pub fn is_header(&self, ix: usize) -> bool {
+        matches!(self.locate(ix), Some((_, None)))
+    }
+
+    /// Collapse or expand the group of the header row, returns false if the row is not a header.
+    pub fn toggle(&mut self, ix: usize) -> bool {
+        let Some((group_ix, None)) = self.locate(ix) else {
+            return false;
+        };
+
+        let group = &mut self.groups[group_ix];
+        group.collapsed = !group.collapsed;
+        true
+    }
+
+    /// Collapse or expand all the groups.
+    pub fn set_collapsed_all(&mut self, collapsed: bool) {
+        for group in &mut self.groups {
+            group.collapsed = collapsed;
+        }
+    }
+}
+
+/// The header row of a group, with a chevron to show it's collapsed or expanded, and the count of the items.
+#[derive(IntoElement)]
+pub struct ListGroupHeader {
+    title: SharedString,
+    count: Option<usize>,
+    collapsed: bool,
+}
+
+impl ListGroupHeader {
+    pub fn new(title: impl Into<SharedString>) -> Self {
+        Self {
+            title: title.into(),
+            count: None,
+            collapsed: false,
+        }
+    }
+
+    /// Show the count of the items after the title.
+    pub fn count(mut self, count: usize) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    pub fn collapsed(mut self, collapsed: bool) -> Self {
+        self.collapsed = collapsed;
+        self
+    }
+}
+
+impl<T> From<&ListGroup<T>> for ListGroupHeader {
+    fn from(group: &ListGroup<T>) -> Self {
+        Self::new(group.title.clone())
+            .count(group.items.len())
+            .collapsed(group.collapsed)
+    }
+}
+
+impl RenderOnce for ListGroupHeader {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        h_flex()
+            .w_full()
+            .gap_1()
+            .py_1()
+            .px_2()
+            .bg(cx.theme().list_head)
+            .text_sm()
+            .text_color(cx.theme().tokens.on_surface_muted)
+            .cursor_pointer()
+            .child(
+                Icon::new(if self.collapsed {
+                    IconName::ChevronRight
+                } else {
+                    IconName::ChevronDown
+                })
+                .small(),
+            )
+            .child(div().flex_1().child(self.title))
+            .when_some(self.count, |this, count| this.child(count.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ListGroupRow, ListGroups};
+
+    fn groups() -> ListGroups<&'static str> {
+        ListGroups::group_by(["apple", "banana", "avocado", "cherry"], |item| {
+            item[..1].to_uppercase()
+        })
+    }
+
+    #[test]
+    fn test_group_by() {
+        let groups = groups();
+        let titles = groups
+            .groups()
+            .iter()
+            .map(|group| group.title.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(titles, ["A", "B", "C"]);
+        assert_eq!(groups.groups()[0].items, ["apple", "avocado"]);
+        assert_eq!(groups.rows_count(), 7);
+    }
+
+    #[test]
+    fn test_rows() {
+        let groups = groups();
+        assert!(
+            matches!(groups.row(0), Some(ListGroupRow::Header(group)) if group.title.as_ref() == "A")
+        );
+        assert!(matches!(
+            groups.row(2),
+            Some(ListGroupRow::Item(&"avocado"))
+        ));
+        assert!(groups.is_header(3));
+        assert!(matches!(groups.row(4), Some(ListGroupRow::Item(&"banana"))));
+        assert!(groups.row(7).is_none());
+    }
+
+    #[test]
+    fn test_toggle() {
+        let mut groups = groups();
+        assert!(!groups.toggle(1));
+        assert!(groups.toggle(0));
+        assert!(groups.groups()[0].is_collapsed());
+        assert_eq!(groups.rows_count(), 5);
+        assert!(groups.is_header(1));
+        assert!(matches!(groups.row(2), Some(ListGroupRow::Item(&"banana"))));
+
+        groups.set_collapsed_all(false);
+        assert_eq!(groups.rows_count(), 7);
+    }
+}
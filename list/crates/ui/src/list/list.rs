@@ -1,20 +1,45 @@
+use std::collections::BTreeSet;
 use std::time::Duration;
 use std::{cell::Cell, rc::Rc};
 
+use crate::button::Button;
+use crate::drag_drop::{DragDropExt as _, Draggable, DropIndicator, DropTarget};
+use crate::empty_state::EmptyState;
+use crate::focusable::{inner_focus_ring, TabStop as _};
 use crate::input::{InputEvent, TextInput};
-use crate::scroll::ScrollbarState;
+use crate::scroll::{ScrollHandleOffsetable as _, ScrollbarState};
 use crate::theme::ActiveTheme;
-use crate::IconName;
+use crate::{h_flex, Icon, IconName, Placement, Sizable as _};
 use crate::{scroll::Scrollbar, v_flex};
 use gpui::{
-    actions, div, prelude::FluentBuilder, uniform_list, AppContext, FocusHandle, FocusableView,
-    InteractiveElement, IntoElement, KeyBinding, Length, ListSizingBehavior, MouseButton,
-    ParentElement, Render, Styled, Task, UniformListScrollHandle, View, ViewContext, VisualContext,
+    actions, canvas, div, point, prelude::FluentBuilder, px, uniform_list, AnyElement, AppContext,
+    Div, EntityId, EventEmitter, FocusHandle, FocusableView, InteractiveElement, IntoElement,
+    KeyBinding, Length, ListSizingBehavior, MouseButton, MouseDownEvent, ParentElement, Pixels,
+    Render, ScrollHandle, ScrollWheelEvent, Stateful, StatefulInteractiveElement as _, Styled,
+    Task, UniformListScrollHandle, View, ViewContext, VisualContext,
 };
 use gpui::{Entity, SharedString, WindowContext};
+use rust_i18n::t;
 use smol::Timer;
 
-actions!(list, [Cancel, Confirm, SelectPrev, SelectNext]);
+actions!(
+    list,
+    [
+        Cancel,
+        Confirm,
+        SelectPrev,
+        SelectNext,
+        ExtendSelectPrev,
+        ExtendSelectNext
+    ]
+);
+
+/// The number of rows to render above and below the visible area in virtualized mode.
+const OVERSCAN_ROWS: usize = 5;
+/// Load more items when the last visible row is within this number of rows from the end.
+const LOAD_MORE_THRESHOLD: usize = 20;
+/// Refresh a [`List::refreshable`] list when it's pulled down this distance at the top.
+const PULL_TO_REFRESH_DISTANCE: f32 = 64.;
 
 pub fn init(cx: &mut AppContext) {
     let context: Option<&str> = Some("List");
@@ -23,9 +48,47 @@ pub fn init(cx: &mut AppContext) {
         KeyBinding::new("enter", Confirm, context),
         KeyBinding::new("up", SelectPrev, context),
         KeyBinding::new("down", SelectNext, context),
+        KeyBinding::new("shift-up", ExtendSelectPrev, context),
+        KeyBinding::new("shift-down", ExtendSelectNext, context),
     ]);
 }
 
+pub enum ListEvent {
+    /// An item has been dragged from the `from` index to the `to` index,
+    /// the delegate's items should be moved the same way, e.g.: `items.insert(to, items.remove(from))`.
+    Reordered { from: usize, to: usize },
+    /// The selected items of a [`List::multi_select`] list have changed, with all the selected indices in order.
+    SelectionChanged(Vec<usize>),
+}
+
+/// The dragging item of a reorderable [`List`].
+#[derive(Clone)]
+pub struct DragListItem {
+    list_id: EntityId,
+    ix: usize,
+}
+
+impl Draggable for DragListItem {
+    fn render_preview(&self, cx: &mut WindowContext) -> AnyElement {
+        h_flex()
+            .id("drag-list-item")
+            .cursor_grab()
+            .gap_2()
+            .py_1()
+            .px_3()
+            .w_24()
+            .border_1()
+            .border_color(cx.theme().tokens.outline)
+            .rounded_md()
+            .text_color(cx.theme().tokens.on_surface)
+            .bg(cx.theme().list_active)
+            .opacity(0.75)
+            .child(Icon::new(IconName::Menu).size_4())
+            .child(format!("#{}", self.ix + 1))
+            .into_any_element()
+    }
+}
+
 /// A delegate for the List.
 #[allow(unused)]
 pub trait ListDelegate: Sized + 'static {
@@ -47,7 +110,21 @@ pub trait ListDelegate: Sized + 'static {
 
     /// Return a Element to show when list is empty.
     fn render_empty(&self, cx: &mut ViewContext<List<Self>>) -> impl IntoElement {
-        div()
+        EmptyState::new(t!("List.No Data").to_string())
+    }
+
+    /// Return the error message if failed to load the items, the error state is shown instead of the items.
+    fn error(&self) -> Option<SharedString> {
+        None
+    }
+
+    /// Return a Element to show when the [`ListDelegate::error`] is not None.
+    fn render_error(
+        &self,
+        error: SharedString,
+        cx: &mut ViewContext<List<Self>>,
+    ) -> impl IntoElement {
+        EmptyState::error(error)
     }
 
     /// Return the confirmed index of the selected item.
@@ -55,14 +132,55 @@ pub trait ListDelegate: Sized + 'static {
         None
     }
 
+    /// Return false if the item can't be selected, e.g.: a group header.
+    ///
+    /// The item is skipped by the keyboard navigation, and clicking it does nothing.
+    fn can_select(&self, ix: usize) -> bool {
+        true
+    }
+
+    /// Return true if the item is the header row of a group, e.g.: a row of the [`super::ListGroups`].
+    ///
+    /// The header of the first visible group sticks to the top if [`List::sticky_headers`] is set,
+    /// and clicking a header calls [`ListDelegate::toggle_group`].
+    fn is_group_header(&self, ix: usize) -> bool {
+        false
+    }
+
+    /// Collapse or expand the group of the header row, remove or insert the items after it.
+    fn toggle_group(&mut self, ix: usize, cx: &mut ViewContext<List<Self>>) {}
+
     /// Set the selected index, just store the ix, don't confirm.
     fn set_selected_index(&mut self, ix: Option<usize>, cx: &mut ViewContext<List<Self>>);
 
+    /// Set all the selected indices of a [`List::multi_select`] list, in order.
+    ///
+    /// The `selected_index` is the last clicked or keyboard navigated one of them.
+    fn set_selected_indices(&mut self, indices: &[usize], cx: &mut ViewContext<List<Self>>) {}
+
     /// Set the confirm and give the selected index, this is means user have clicked the item or pressed Enter.
     fn confirm(&mut self, ix: Option<usize>, cx: &mut ViewContext<List<Self>>) {}
 
     /// Cancel the selection, e.g.: Pressed ESC.
     fn cancel(&mut self, cx: &mut ViewContext<List<Self>>) {}
+
+    /// Return true if there are more items to load by [`ListDelegate::load_more`].
+    fn can_load_more(&self, cx: &AppContext) -> bool {
+        false
+    }
+
+    /// Load more items, called when the list is scrolled near the end.
+    ///
+    /// This is only called when [`ListDelegate::can_load_more`] returns true,
+    /// so return false from there while loading to avoid loading the same items twice.
+    fn load_more(&mut self, cx: &mut ViewContext<List<Self>>) {}
+
+    /// Reload the items, called by the refresh button or pulling down at the top of a [`List::refreshable`] list.
+    ///
+    /// The refreshing indicator is shown in the header until the task is finished.
+    fn refresh(&mut self, cx: &mut ViewContext<List<Self>>) -> Task<()> {
+        Task::Ready(Some(()))
+    }
 }
 
 pub struct List<D: ListDelegate> {
@@ -77,8 +195,28 @@ pub struct List<D: ListDelegate> {
     vertical_scroll_handle: UniformListScrollHandle,
     scrollbar_state: Rc<Cell<ScrollbarState>>,
 
+    /// The fixed row height, when set the list will render in virtualized mode.
+    row_height: Option<Pixels>,
+    virtual_scroll_handle: ScrollHandle,
+    viewport_height: Pixels,
+    sticky_headers: bool,
+    /// The index of the first visible row, to find the group header to stick to the top.
+    first_visible_index: usize,
+
     selected_index: Option<usize>,
+    multi_select: bool,
+    /// The selected items in the `multi_select` mode.
+    selected_indices: BTreeSet<usize>,
+    /// The start of the range to select by Shift-click or Shift-arrow.
+    anchor_index: Option<usize>,
+    reorderable: bool,
+    refreshable: bool,
+    refreshing: bool,
+    /// The distance that the list has been pulled down at the top, to refresh.
+    pull_distance: Pixels,
     _search_task: Task<()>,
+    _refresh_task: Task<()>,
+    _pull_task: Task<()>,
 }
 
 impl<D> List<D>
@@ -103,12 +241,26 @@ where
             query_input: Some(query_input),
             last_query: None,
             selected_index: None,
+            multi_select: false,
+            selected_indices: BTreeSet::new(),
+            anchor_index: None,
             vertical_scroll_handle: UniformListScrollHandle::new(),
             scrollbar_state: Rc::new(Cell::new(ScrollbarState::new())),
             max_height: None,
             enable_scrollbar: true,
+            row_height: None,
+            virtual_scroll_handle: ScrollHandle::new(),
+            viewport_height: px(0.),
+            sticky_headers: false,
+            first_visible_index: 0,
             loading: false,
+            reorderable: false,
+            refreshable: false,
+            refreshing: false,
+            pull_distance: px(0.),
             _search_task: Task::Ready(None),
+            _refresh_task: Task::Ready(None),
+            _pull_task: Task::Ready(None),
         }
     }
 
@@ -122,6 +274,51 @@ where
         self
     }
 
+    /// Render the list in virtualized mode, all rows must have the same `row_height`.
+    ///
+    /// Only the visible rows plus an overscan buffer will be rendered,
+    /// this is useful for a large number of items.
+    pub fn virtualized(mut self, row_height: impl Into<Pixels>) -> Self {
+        self.row_height = Some(row_height.into());
+        self
+    }
+
+    /// Stick the header of the group at the top while its items are scrolled, default: false
+    ///
+    /// The group headers are the items that [`ListDelegate::is_group_header`] returns true.
+    pub fn sticky_headers(mut self, sticky_headers: bool) -> Self {
+        self.sticky_headers = sticky_headers;
+        self
+    }
+
+    /// Allow the items to be reordered by drag and drop, default: false
+    ///
+    /// The [`ListEvent::Reordered`] will be emitted when an item is dropped,
+    /// the drag is disabled when the list is filtered by a query.
+    pub fn reorderable(mut self, reorderable: bool) -> Self {
+        self.reorderable = reorderable;
+        self
+    }
+
+    /// Allow to select multiple items, default: false
+    ///
+    /// - Shift-click or Shift-Up / Shift-Down to select a range from the last selected item.
+    /// - Cmd-click (Ctrl-click on Linux and Windows) to toggle an item.
+    ///
+    /// The [`ListEvent::SelectionChanged`] will be emitted when the selection changes.
+    pub fn multi_select(mut self, multi_select: bool) -> Self {
+        self.multi_select = multi_select;
+        self
+    }
+
+    /// Show a refresh button in the header and refresh by pulling down at the top, default: false
+    ///
+    /// The items are reloaded by [`ListDelegate::refresh`].
+    pub fn refreshable(mut self, refreshable: bool) -> Self {
+        self.refreshable = refreshable;
+        self
+    }
+
     pub fn no_query(mut self) -> Self {
         self.query_input = None;
         self
@@ -139,6 +336,18 @@ where
         self.focus_handle(cx).focus(cx);
     }
 
+    /// Select the item at the index, scroll to it and focus the list.
+    pub fn focus_item(&mut self, ix: usize, cx: &mut ViewContext<Self>) {
+        if ix >= self.delegate.items_count() {
+            return;
+        }
+
+        self.set_selected_index(Some(ix), cx);
+        self.scroll_to_selected_item(cx);
+        self.focus_handle.focus(cx);
+        cx.notify();
+    }
+
     pub fn set_selected_index(&mut self, ix: Option<usize>, cx: &mut ViewContext<Self>) {
         self.selected_index = ix;
         self.delegate.set_selected_index(ix, cx);
@@ -148,6 +357,70 @@ where
         self.selected_index
     }
 
+    /// Returns the selected indices in order, only for a [`List::multi_select`] list.
+    pub fn selected_indices(&self) -> Vec<usize> {
+        self.selected_indices.iter().copied().collect()
+    }
+
+    /// Replace the selection of a [`List::multi_select`] list, the last one will be the `selected_index`.
+    pub fn set_selected_indices(
+        &mut self,
+        indices: impl IntoIterator<Item = usize>,
+        cx: &mut ViewContext<Self>,
+    ) {
+        let count = self.delegate.items_count();
+        let indices = indices
+            .into_iter()
+            .filter(|&ix| ix < count && self.delegate.can_select(ix))
+            .collect::<BTreeSet<_>>();
+        let ix = indices.last().copied();
+        self.anchor_index = ix;
+        self.set_selected_index(ix, cx);
+        self.update_selection(indices, cx);
+    }
+
+    /// Update the selected indices and emit [`ListEvent::SelectionChanged`] if changed.
+    fn update_selection(&mut self, indices: BTreeSet<usize>, cx: &mut ViewContext<Self>) {
+        if !self.multi_select || indices == self.selected_indices {
+            return;
+        }
+
+        self.selected_indices = indices;
+        let indices = self.selected_indices();
+        self.delegate.set_selected_indices(&indices, cx);
+        cx.emit(ListEvent::SelectionChanged(indices));
+        cx.notify();
+    }
+
+    /// Select the range from the anchor to `ix`, or toggle `ix` if `toggle` is true.
+    fn select_with_modifiers(
+        &mut self,
+        ix: usize,
+        range: bool,
+        toggle: bool,
+        cx: &mut ViewContext<Self>,
+    ) {
+        let mut indices = self.selected_indices.clone();
+        if range {
+            let anchor = self.anchor_index.unwrap_or(ix);
+            let (start, end) = (anchor.min(ix), anchor.max(ix));
+            indices = (start..=end)
+                .filter(|&ix| self.delegate.can_select(ix))
+                .collect();
+        } else if toggle {
+            if !indices.remove(&ix) {
+                indices.insert(ix);
+            }
+            self.anchor_index = Some(ix);
+        } else {
+            indices = BTreeSet::from([ix]);
+            self.anchor_index = Some(ix);
+        }
+
+        self.set_selected_index(Some(ix), cx);
+        self.update_selection(indices, cx);
+    }
+
     /// Set the query_input text
     pub fn set_query(&mut self, query: &str, cx: &mut ViewContext<Self>) {
         if let Some(query_input) = &self.query_input {
@@ -166,6 +439,15 @@ where
             return None;
         }
 
+        if let Some(row_height) = self.row_height {
+            return Some(Scrollbar::vertical(
+                cx.view().entity_id(),
+                self.scrollbar_state.clone(),
+                self.virtual_scroll_handle.clone(),
+                gpui::size(px(0.), row_height * self.delegate.items_count() as f32),
+            ));
+        }
+
         Some(Scrollbar::uniform_scroll(
             cx.view().entity_id(),
             self.scrollbar_state.clone(),
@@ -174,12 +456,141 @@ where
         ))
     }
 
-    fn scroll_to_selected_item(&mut self, _cx: &mut ViewContext<Self>) {
+    /// Scroll to make the selected item visible.
+    pub fn scroll_to_selected_item(&mut self, _cx: &mut ViewContext<Self>) {
         if let Some(ix) = self.selected_index {
+            self.scroll_to_item(ix);
+        }
+    }
+
+    fn scroll_to_item(&mut self, ix: usize) {
+        if let Some(row_height) = self.row_height {
+            self.scroll_to_row(ix, row_height);
+        } else {
             self.vertical_scroll_handle.scroll_to_item(ix);
         }
     }
 
+    fn scroll_to_top(&mut self) {
+        if self.row_height.is_some() {
+            self.virtual_scroll_handle.set_offset(point(px(0.), px(0.)));
+        } else {
+            self.vertical_scroll_handle.scroll_to_item(0);
+        }
+    }
+
+    /// Scroll the virtualized list to make the row visible.
+    fn scroll_to_row(&self, ix: usize, row_height: Pixels) {
+        let mut offset = self.virtual_scroll_handle.offset();
+        let scroll_top = -offset.y;
+        let row_top = row_height * ix as f32;
+        let row_bottom = row_top + row_height;
+
+        if row_top < scroll_top {
+            offset.y = -row_top;
+        } else if row_bottom > scroll_top + self.viewport_height {
+            offset.y = -(row_bottom - self.viewport_height);
+        } else {
+            return;
+        }
+
+        self.virtual_scroll_handle.set_offset(offset);
+    }
+
+    /// Load more items by the delegate if the visible rows have reached near the end.
+    fn load_more_if_needed(&mut self, visible_end: usize, cx: &mut ViewContext<Self>) {
+        if visible_end + LOAD_MORE_THRESHOLD < self.delegate.items_count()
+            || !self.delegate.can_load_more(cx)
+        {
+            return;
+        }
+
+        // Deferred, because this is called while rendering.
+        cx.defer(|this, cx| {
+            if this.delegate.can_load_more(cx) {
+                this.delegate.load_more(cx);
+            }
+        });
+    }
+
+    pub fn is_refreshing(&self) -> bool {
+        self.refreshing
+    }
+
+    /// Reload the items by [`ListDelegate::refresh`], does nothing if it's already refreshing.
+    pub fn refresh(&mut self, cx: &mut ViewContext<Self>) {
+        if self.refreshing {
+            return;
+        }
+
+        self.refreshing = true;
+        self.pull_distance = px(0.);
+        let refresh = self.delegate.refresh(cx);
+        self._refresh_task = cx.spawn(|this, mut cx| async move {
+            refresh.await;
+
+            let _ = this.update(&mut cx, |this, cx| {
+                this.refreshing = false;
+                // Drop the selection that is out of the reloaded items.
+                let count = this.delegate.items_count();
+                if this.selected_index.map_or(false, |ix| ix >= count) {
+                    this.set_selected_index(None, cx);
+                    this.anchor_index = None;
+                }
+                let indices = this
+                    .selected_indices
+                    .iter()
+                    .copied()
+                    .filter(|&ix| ix < count)
+                    .collect();
+                this.update_selection(indices, cx);
+                cx.notify();
+            });
+        });
+        cx.notify();
+    }
+
+    fn is_scrolled_to_top(&self) -> bool {
+        let offset = if self.row_height.is_some() {
+            self.virtual_scroll_handle.offset()
+        } else {
+            self.vertical_scroll_handle.offset()
+        };
+        offset.y >= px(0.)
+    }
+
+    /// Pull down the list by scrolling up at the top, refresh when it's pulled far enough.
+    fn on_scroll_wheel(&mut self, event: &ScrollWheelEvent, cx: &mut ViewContext<Self>) {
+        if !self.refreshable || self.refreshing {
+            return;
+        }
+
+        let delta = event.delta.pixel_delta(cx.line_height());
+        if delta.y <= px(0.) || !self.is_scrolled_to_top() {
+            if self.pull_distance > px(0.) {
+                self.pull_distance = px(0.);
+                cx.notify();
+            }
+            return;
+        }
+
+        self.pull_distance += delta.y;
+        if self.pull_distance >= px(PULL_TO_REFRESH_DISTANCE) {
+            self.refresh(cx);
+            return;
+        }
+
+        // Spring back if the scrolling stops before it's pulled far enough.
+        self._pull_task = cx.spawn(|this, mut cx| async move {
+            Timer::after(Duration::from_millis(300)).await;
+            let _ = this.update(&mut cx, |this, cx| {
+                this.pull_distance = px(0.);
+                cx.notify();
+            });
+        });
+        cx.notify();
+    }
+
     fn on_query_input_event(
         &mut self,
         _: View<TextInput>,
@@ -199,8 +610,14 @@ where
                 self._search_task = cx.spawn(|this, mut cx| async move {
                     search.await;
 
-                    let _ = this.update(&mut cx, |this, _| {
-                        this.vertical_scroll_handle.scroll_to_item(0);
+                    let _ = this.update(&mut cx, |this, cx| {
+                        // Select the first matched item, so it can be confirmed by Enter directly.
+                        let ix = this.next_selectable_index(None);
+                        this.set_selected_index(ix, cx);
+                        // The indices are changed by the search.
+                        this.anchor_index = ix;
+                        this.update_selection(BTreeSet::new(), cx);
+                        this.scroll_to_top();
                         this.last_query = Some(text);
                     });
 
@@ -226,6 +643,8 @@ where
 
     fn on_action_cancel(&mut self, _: &Cancel, cx: &mut ViewContext<Self>) {
         self.set_selected_index(None, cx);
+        self.anchor_index = None;
+        self.update_selection(BTreeSet::new(), cx);
         self.delegate.cancel(cx);
         cx.notify();
     }
@@ -240,41 +659,364 @@ where
     }
 
     fn on_action_select_prev(&mut self, _: &SelectPrev, cx: &mut ViewContext<Self>) {
-        if self.delegate.items_count() == 0 {
+        let Some(ix) = self.prev_selectable_index(self.selected_index) else {
             return;
-        }
+        };
+
+        self.select_by_keyboard(ix, false, cx);
+    }
+
+    fn on_action_extend_select_prev(&mut self, _: &ExtendSelectPrev, cx: &mut ViewContext<Self>) {
+        // Don't wrap to the end when extending the selection.
+        let Some(ix) = self
+            .prev_selectable_index(self.selected_index)
+            .filter(|&ix| self.selected_index.map_or(true, |selected| ix < selected))
+        else {
+            return;
+        };
+
+        self.select_by_keyboard(ix, true, cx);
+    }
+
+    fn on_action_extend_select_next(&mut self, _: &ExtendSelectNext, cx: &mut ViewContext<Self>) {
+        let Some(ix) = self
+            .next_selectable_index(self.selected_index)
+            .filter(|&ix| self.selected_index.map_or(true, |selected| ix > selected))
+        else {
+            return;
+        };
+
+        self.select_by_keyboard(ix, true, cx);
+    }
 
-        let selected_index = self.selected_index.unwrap_or(0);
-        if selected_index > 0 {
-            self.selected_index = Some(selected_index - 1);
+    /// Move the selection to `ix` by the keyboard, extend the selection from the anchor if `extend` is true.
+    fn select_by_keyboard(&mut self, ix: usize, extend: bool, cx: &mut ViewContext<Self>) {
+        if self.multi_select {
+            self.select_with_modifiers(ix, extend, false, cx);
         } else {
-            self.selected_index = Some(self.delegate.items_count() - 1);
+            self.selected_index = Some(ix);
         }
-
         self.scroll_to_selected_item(cx);
         cx.notify();
     }
 
-    fn on_action_select_next(&mut self, _: &SelectNext, cx: &mut ViewContext<Self>) {
-        if self.delegate.items_count() == 0 {
+    /// Returns the selectable index before `ix`, wraps to the end.
+    fn prev_selectable_index(&self, ix: Option<usize>) -> Option<usize> {
+        let count = self.delegate.items_count();
+        let start = ix.unwrap_or(0);
+        (1..=count)
+            .map(|offset| (start + count - offset) % count)
+            .find(|&ix| self.delegate.can_select(ix))
+    }
+
+    /// Returns the selectable index after `ix`, or the first one if `ix` is None, wraps to the start.
+    fn next_selectable_index(&self, ix: Option<usize>) -> Option<usize> {
+        let count = self.delegate.items_count();
+        let start = ix.map_or(0, |ix| ix + 1);
+        (0..count)
+            .map(|offset| (start + offset) % count)
+            .find(|&ix| self.delegate.can_select(ix))
+    }
+
+    /// Collapse or expand the group by the delegate, and keep the selection on the same items.
+    fn toggle_group(&mut self, ix: usize, cx: &mut ViewContext<Self>) {
+        let count = self.delegate.items_count();
+        self.delegate.toggle_group(ix, cx);
+        let new_count = self.delegate.items_count();
+
+        // The items after the header are removed or inserted.
+        let move_index = |selected: usize| {
+            if selected <= ix {
+                Some(selected)
+            } else if new_count >= count {
+                Some(selected + (new_count - count))
+            } else if selected <= ix + (count - new_count) {
+                None
+            } else {
+                Some(selected - (count - new_count))
+            }
+        };
+        let selected_index = self.selected_index.and_then(move_index);
+        let selected_indices = self
+            .selected_indices
+            .iter()
+            .copied()
+            .filter_map(move_index)
+            .collect();
+        self.anchor_index = self.anchor_index.and_then(move_index);
+
+        self.set_selected_index(selected_index, cx);
+        self.update_selection(selected_indices, cx);
+        cx.notify();
+    }
+
+    /// Returns the index of the group header of the first visible row.
+    fn sticky_header_index(&self) -> Option<usize> {
+        if !self.sticky_headers {
+            return None;
+        }
+
+        let first = self
+            .first_visible_index
+            .min(self.delegate.items_count().checked_sub(1)?);
+        (0..=first)
+            .rev()
+            .find(|&ix| self.delegate.is_group_header(ix))
+    }
+
+    /// Render the header of the first visible group at the top, it's pushed up by the next header.
+    fn render_sticky_header(&mut self, cx: &mut ViewContext<Self>) -> Option<impl IntoElement> {
+        let ix = self.sticky_header_index()?;
+        let next = self.first_visible_index + 1;
+        // The offset is only known by the fixed row height.
+        let top = match self.row_height {
+            Some(row_height) if self.delegate.is_group_header(next) => {
+                let scroll_top = -self.virtual_scroll_handle.offset().y;
+                (row_height * next as f32 - scroll_top - row_height).min(px(0.))
+            }
+            _ => px(0.),
+        };
+
+        Some(
+            div()
+                .id("sticky-header")
+                .absolute()
+                .top(top)
+                .left_0()
+                .right_0()
+                .bg(cx.theme().tokens.surface)
+                .children(self.delegate.render_item(ix, cx))
+                .on_mouse_down(
+                    MouseButton::Left,
+                    cx.listener(move |this, _, cx| {
+                        cx.stop_propagation();
+                        this.toggle_group(ix, cx);
+                        this.scroll_to_item(ix);
+                    }),
+                ),
+        )
+    }
+
+    /// Render the query input and the refresh button.
+    fn render_header(&self, cx: &mut ViewContext<Self>) -> Option<impl IntoElement> {
+        if self.query_input.is_none() && !self.refreshable {
+            return None;
+        }
+
+        Some(
+            h_flex()
+                .py_1()
+                .px_2()
+                .gap_1()
+                .justify_end()
+                .border_b_1()
+                .border_color(cx.theme().tokens.outline)
+                .when_some(self.query_input.clone(), |this, input| {
+                    this.child(div().flex_1().child(input))
+                })
+                .when(self.refreshable, |this| {
+                    this.child(
+                        Button::new("refresh", cx)
+                            .ghost()
+                            .xsmall()
+                            .icon(IconName::RefreshCw)
+                            .loading(self.refreshing)
+                            .tooltip(t!("List.Refresh"))
+                            .on_click(cx.listener(|this, _, cx| this.refresh(cx))),
+                    )
+                }),
+        )
+    }
+
+    /// Render the hint above the items while the list is pulled down.
+    fn render_pull_indicator(&self, cx: &mut ViewContext<Self>) -> Option<impl IntoElement> {
+        if self.pull_distance <= px(0.) {
+            return None;
+        }
+
+        Some(
+            h_flex()
+                .flex_none()
+                .h(self.pull_distance.min(px(PULL_TO_REFRESH_DISTANCE)))
+                .gap_1()
+                .justify_center()
+                .overflow_hidden()
+                .text_xs()
+                .text_color(cx.theme().tokens.on_surface_muted)
+                .child(Icon::new(IconName::ArrowDown).xsmall())
+                .child(t!("List.Pull to Refresh").to_string()),
+        )
+    }
+
+    fn can_reorder(&self) -> bool {
+        self.reorderable && self.last_query.as_deref().map_or(true, str::is_empty)
+    }
+
+    /// Move the dragged item to the index of the dropped row.
+    fn on_drop_item(&mut self, drag: &DragListItem, ix: usize, cx: &mut ViewContext<Self>) {
+        if drag.list_id != cx.view().entity_id() || drag.ix == ix {
             return;
         }
 
-        if let Some(selected_index) = self.selected_index {
-            if selected_index < self.delegate.items_count() - 1 {
-                self.selected_index = Some(selected_index + 1);
+        let (from, to) = (drag.ix, ix);
+        // Keep the selection on the same items.
+        let move_index = |selected: usize| {
+            if selected == from {
+                to
+            } else if from < selected && selected <= to {
+                selected - 1
+            } else if to <= selected && selected < from {
+                selected + 1
             } else {
-                self.selected_index = Some(0);
+                selected
             }
-        } else {
-            self.selected_index = Some(0);
-        }
+        };
+        let selected_index = self.selected_index.map(move_index);
+        let selected_indices = self
+            .selected_indices
+            .iter()
+            .copied()
+            .map(move_index)
+            .collect();
+        self.anchor_index = self.anchor_index.map(move_index);
 
-        self.scroll_to_selected_item(cx);
+        cx.emit(ListEvent::Reordered { from, to });
+        self.set_selected_index(selected_index, cx);
+        self.update_selection(selected_indices, cx);
         cx.notify();
     }
+
+    fn on_action_select_next(&mut self, _: &SelectNext, cx: &mut ViewContext<Self>) {
+        let Some(ix) = self.next_selectable_index(self.selected_index) else {
+            return;
+        };
+
+        self.select_by_keyboard(ix, false, cx);
+    }
+}
+
+impl<D> List<D>
+where
+    D: ListDelegate,
+{
+    fn render_list_item(
+        &mut self,
+        ix: usize,
+        selected_bg: gpui::Hsla,
+        cx: &mut ViewContext<Self>,
+    ) -> Stateful<Div> {
+        let list_id = cx.view().entity_id();
+
+        div()
+            .id(("list-item", ix))
+            .w_full()
+            .children(self.delegate.render_item(ix, cx))
+            .when(
+                self.selected_index == Some(ix) || self.selected_indices.contains(&ix),
+                |this| this.bg(selected_bg),
+            )
+            .on_mouse_down(
+                MouseButton::Left,
+                cx.listener(move |this, event: &MouseDownEvent, cx| {
+                    cx.stop_propagation();
+                    if this.delegate.is_group_header(ix) {
+                        this.toggle_group(ix, cx);
+                        return;
+                    }
+                    if !this.delegate.can_select(ix) {
+                        return;
+                    }
+
+                    let (range, toggle) = (event.modifiers.shift, event.modifiers.secondary());
+                    if this.multi_select && (range || toggle) {
+                        this.select_with_modifiers(ix, range, toggle, cx);
+                        return;
+                    }
+
+                    if this.multi_select {
+                        this.select_with_modifiers(ix, false, false, cx);
+                    }
+                    this.selected_index = Some(ix);
+                    this.on_action_confirm(&Confirm, cx);
+                }),
+            )
+            .when(self.can_reorder(), |this| {
+                this.drag_source(DragListItem { list_id, ix }).drop_target(
+                    DropTarget::new(cx.listener(move |this, drag: &DragListItem, cx| {
+                        this.on_drop_item(drag, ix, cx)
+                    }))
+                    .can_drop(move |drag, _| drag.list_id == list_id && drag.ix != ix)
+                    // Show the drop line on the side that the item will be moved to.
+                    .indicator(move |drag, _| {
+                        DropIndicator::Line(if drag.ix < ix {
+                            Placement::Bottom
+                        } else {
+                            Placement::Top
+                        })
+                    }),
+                )
+            })
+    }
+
+    /// Render only the visible rows (with the overscan rows) by the fixed row height.
+    fn render_virtualized_items(
+        &mut self,
+        row_height: Pixels,
+        selected_bg: gpui::Hsla,
+        cx: &mut ViewContext<Self>,
+    ) -> impl IntoElement {
+        let view = cx.view().clone();
+        let items_count = self.delegate.items_count();
+        let scroll_top = -self.virtual_scroll_handle.offset().y;
+
+        let first_visible = (scroll_top / row_height).floor().max(0.) as usize;
+        let visible_count = (self.viewport_height / row_height).ceil() as usize + 1;
+        let start = first_visible.saturating_sub(OVERSCAN_ROWS);
+        let end = (first_visible + visible_count + OVERSCAN_ROWS).min(items_count);
+        self.first_visible_index = first_visible;
+        self.load_more_if_needed(end, cx);
+
+        div()
+            .id("virtual-list")
+            .flex_grow()
+            .size_full()
+            .overflow_y_scroll()
+            .track_scroll(&self.virtual_scroll_handle)
+            .child(
+                div()
+                    .relative()
+                    .w_full()
+                    .h(row_height * items_count as f32)
+                    .children((start..end).map(|ix| {
+                        self.render_list_item(ix, selected_bg, cx)
+                            .absolute()
+                            .left_0()
+                            .right_0()
+                            .top(row_height * ix as f32)
+                            .h(row_height)
+                            .overflow_hidden()
+                    })),
+            )
+            .child(
+                canvas(
+                    move |bounds, cx| {
+                        view.update(cx, |list, cx| {
+                            if list.viewport_height != bounds.size.height {
+                                list.viewport_height = bounds.size.height;
+                                cx.notify();
+                            }
+                        })
+                    },
+                    |_, _, _| {},
+                )
+                .absolute()
+                .size_full(),
+            )
+    }
 }
 
+impl<D> EventEmitter<ListEvent> for List<D> where D: ListDelegate {}
+
 impl<D> FocusableView for List<D>
 where
     D: ListDelegate,
@@ -295,7 +1037,12 @@ where
     fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
         let view = cx.view().clone();
         let vertical_scroll_handle = self.vertical_scroll_handle.clone();
-        let items_count = self.delegate.items_count();
+        let error = self.delegate.error();
+        let items_count = if error.is_some() {
+            0
+        } else {
+            self.delegate.items_count()
+        };
         let sizing_behavior = if self.max_height.is_some() {
             ListSizingBehavior::Infer
         } else {
@@ -303,11 +1050,15 @@ where
         };
 
         let selected_bg = cx.theme().list_active;
+        let focused = self.focus_handle.is_focused(cx);
+        if items_count == 0 && error.is_none() {
+            self.load_more_if_needed(0, cx);
+        }
 
         v_flex()
             .key_context("List")
             .id("list")
-            .track_focus(&self.focus_handle)
+            .tab_stop(&self.focus_handle, cx)
             .size_full()
             .relative()
             .overflow_hidden()
@@ -315,52 +1066,42 @@ where
             .on_action(cx.listener(Self::on_action_confirm))
             .on_action(cx.listener(Self::on_action_select_next))
             .on_action(cx.listener(Self::on_action_select_prev))
-            .when_some(self.query_input.clone(), |this, input| {
-                this.child(
-                    div()
-                        .py_1()
-                        .px_2()
-                        .border_b_1()
-                        .border_color(cx.theme().border)
-                        .child(input),
-                )
-            })
+            .on_action(cx.listener(Self::on_action_extend_select_next))
+            .on_action(cx.listener(Self::on_action_extend_select_prev))
+            .children(self.render_header(cx))
+            .children(self.render_pull_indicator(cx))
             .child(
                 v_flex()
                     .flex_grow()
                     .relative()
                     .when_some(self.max_height, |this, h| this.max_h(h))
                     .overflow_hidden()
-                    .when(items_count == 0, |this| {
-                        this.child(self.delegate().render_empty(cx))
+                    .on_scroll_wheel(cx.listener(Self::on_scroll_wheel))
+                    .map(|this| match error {
+                        Some(error) => this.child(self.delegate().render_error(error, cx)),
+                        None if items_count == 0 => this.child(self.delegate().render_empty(cx)),
+                        None => this,
                     })
-                    .when(items_count > 0, |this| {
+                    .when_some(
+                        self.row_height.filter(|_| items_count > 0),
+                        |this, row_height| {
+                            this.child(self.render_virtualized_items(row_height, selected_bg, cx))
+                        },
+                    )
+                    .when(items_count > 0 && self.row_height.is_none(), |this| {
                         this.child(
                             uniform_list(view, "uniform-list", items_count, {
                                 move |list, visible_range, cx| {
+                                    list.load_more_if_needed(visible_range.end, cx);
+                                    // Update the sticky header, it's rendered before the rows.
+                                    if list.sticky_headers
+                                        && list.first_visible_index != visible_range.start
+                                    {
+                                        list.first_visible_index = visible_range.start;
+                                        cx.notify();
+                                    }
                                     visible_range
-                                        .map(|ix| {
-                                            div()
-                                                .id("list-item")
-                                                .w_full()
-                                                .children(list.delegate.render_item(ix, cx))
-                                                .when_some(
-                                                    list.selected_index,
-                                                    |this, selected_index| {
-                                                        this.when(ix == selected_index, |this| {
-                                                            this.bg(selected_bg)
-                                                        })
-                                                    },
-                                                )
-                                                .on_mouse_down(
-                                                    MouseButton::Left,
-                                                    cx.listener(move |this, _, cx| {
-                                                        cx.stop_propagation();
-                                                        this.selected_index = Some(ix);
-                                                        this.on_action_confirm(&Confirm, cx);
-                                                    }),
-                                                )
-                                        })
+                                        .map(|ix| list.render_list_item(ix, selected_bg, cx))
                                         .collect::<Vec<_>>()
                                 }
                             })
@@ -370,7 +1111,11 @@ where
                             .into_any_element(),
                         )
                     })
+                    .when(items_count > 0, |this| {
+                        this.children(self.render_sticky_header(cx))
+                    })
                     .children(self.render_scrollbar(cx)),
             )
+            .when(focused, |this| this.child(inner_focus_ring(cx)))
     }
 }
@@ -5,7 +5,12 @@ use gpui::{
 };
 use smallvec::SmallVec;
 
-use crate::{h_flex, theme::ActiveTheme, Disableable, Icon, IconName, Selectable, Sizable as _};
+use crate::{
+    accessibility::{register_accessible, Accessible, AccessibleNode, AccessibleRole},
+    h_flex,
+    theme::{ActiveTheme, ComponentKind, StyleOverridesExt as _},
+    Disableable, Icon, IconName, Selectable, Sizable as _,
+};
 
 #[derive(IntoElement)]
 pub struct ListItem {
@@ -19,6 +24,7 @@ pub struct ListItem {
     on_mouse_enter: Option<Box<dyn Fn(&MouseMoveEvent, &mut WindowContext) + 'static>>,
     suffix: Option<Box<dyn Fn(&mut WindowContext) -> AnyElement + 'static>>,
     children: SmallVec<[AnyElement; 2]>,
+    accessible_label: Option<SharedString>,
 }
 
 impl ListItem {
@@ -34,6 +40,7 @@ impl ListItem {
             suffix: None,
             group_id: None,
             children: SmallVec::new(),
+            accessible_label: None,
         }
     }
 
@@ -90,6 +97,13 @@ impl ListItem {
     }
 }
 
+impl Accessible for ListItem {
+    fn accessible_label(mut self, label: impl Into<SharedString>) -> Self {
+        self.accessible_label = Some(label.into());
+        self
+    }
+}
+
 impl Disableable for ListItem {
     fn disabled(mut self, disabled: bool) -> Self {
         self.disabled = disabled;
@@ -119,13 +133,20 @@ impl ParentElement for ListItem {
 impl RenderOnce for ListItem {
     fn render(self, cx: &mut WindowContext) -> impl IntoElement {
         let is_active = self.selected || self.confirmed;
+        register_accessible(
+            AccessibleNode::new(AccessibleRole::ListItem, self.accessible_label.clone())
+                .selected(is_active)
+                .disabled(self.disabled),
+            cx,
+        );
 
         self.base
             .when_some(self.group_id, |this, group_id| this.group(group_id))
-            .text_color(cx.theme().foreground)
+            .text_color(cx.theme().tokens.on_surface)
             .relative()
             .items_center()
             .justify_between()
+            .style_overrides(ComponentKind::ListItem, cx)
             .when_some(self.on_click, |this, on_click| {
                 if !self.disabled {
                     this.cursor_pointer().on_click(on_click)
@@ -153,14 +174,14 @@ impl RenderOnce for ListItem {
                     .gap_x_1()
                     .child(div().w_full().children(self.children))
                     .when_some(self.check_icon, |this, icon| {
-                        this.child(
-                            div().w_5().items_center().justify_center().when(
-                                self.confirmed,
-                                |this| {
-                                    this.child(icon.small().text_color(cx.theme().muted_foreground))
-                                },
-                            ),
-                        )
+                        this.child(div().w_5().items_center().justify_center().when(
+                            self.confirmed,
+                            |this| {
+                                this.child(
+                                    icon.small().text_color(cx.theme().tokens.on_surface_muted),
+                                )
+                            },
+                        ))
                     }),
             )
             .when_some(self.suffix, |this, suffix| this.child(suffix(cx)))
@@ -1,5 +1,9 @@
+mod data_source;
+mod group;
 mod list;
 mod list_item;
 
+pub use data_source::*;
+pub use group::*;
 pub use list::*;
 pub use list_item::*;
@@ -0,0 +1,61 @@
+use std::rc::Rc;
+
+use gpui::{
+    div, ElementId, InteractiveElement as _, IntoElement, ParentElement, RenderOnce, SharedString,
+    Styled as _, ViewContext, WindowContext,
+};
+
+use crate::{
+    button::Button,
+    h_flex,
+    popup_menu::{PopupMenu, PopupMenuExt as _},
+    Sizable as _,
+};
+
+type MenuBuilder = Rc<dyn Fn(PopupMenu, &mut ViewContext<PopupMenu>) -> PopupMenu>;
+
+/// An in-window menu bar, for the platforms without the native application menus (Windows, Linux).
+///
+/// Each menu is a ghost button to open a [`PopupMenu`], the menu items dispatch the actions.
+#[derive(IntoElement)]
+pub struct MenuBar {
+    id: ElementId,
+    menus: Vec<(SharedString, MenuBuilder)>,
+}
+
+impl MenuBar {
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            menus: vec![],
+        }
+    }
+
+    /// Add a menu with the name, the items are built by `f` when the menu is opened.
+    pub fn menu(
+        mut self,
+        name: impl Into<SharedString>,
+        f: impl Fn(PopupMenu, &mut ViewContext<PopupMenu>) -> PopupMenu + 'static,
+    ) -> Self {
+        self.menus.push((name.into(), Rc::new(f)));
+        self
+    }
+}
+
+impl RenderOnce for MenuBar {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        h_flex()
+            .id(self.id)
+            .gap_0p5()
+            .children(self.menus.into_iter().enumerate().map(|(ix, (name, f))| {
+                // Wrap with an id, to keep the state of the popovers apart.
+                div().id(ix).child(
+                    Button::new(("menu", ix), cx)
+                        .label(name)
+                        .small()
+                        .ghost()
+                        .popup_menu(move |menu, cx| f(menu, cx)),
+                )
+            }))
+    }
+}
@@ -1,16 +1,30 @@
 use std::{rc::Rc, time::Duration};
 
 use gpui::{
-    anchored, div, hsla, prelude::FluentBuilder, px, Animation, AnimationExt as _, AnyElement,
-    Bounds, ClickEvent, Div, Hsla, InteractiveElement, IntoElement, MouseButton, ParentElement,
-    Pixels, Point, RenderOnce, Styled, WindowContext,
+    actions, anchored, div, hsla, prelude::FluentBuilder, px, Animation, AnimationExt as _,
+    AnyElement, AppContext, Bounds, ClickEvent, Div, FocusHandle, Hsla, InteractiveElement,
+    IntoElement, KeyBinding, MouseButton, ParentElement, Pixels, Point, RenderOnce, SharedString,
+    Styled, WindowContext,
 };
+use rust_i18n::t;
 
 use crate::{
-    animation::cubic_bezier, button::Button, theme::ActiveTheme as _, v_flex, ContextModal,
-    IconName, Sizable as _,
+    accessibility::{register_accessible, Accessible, AccessibleNode, AccessibleRole},
+    animation::{default_easing, reduce_motion, Easing},
+    button::Button,
+    theme::ActiveTheme as _,
+    v_flex, ContextModal, IconName, Sizable as _,
 };
 
+/// The scale of the modal at the start of the opening animation.
+const START_SCALE: f32 = 0.95;
+
+actions!(modal, [Escape]);
+
+pub fn init(cx: &mut AppContext) {
+    cx.bind_keys([KeyBinding::new("escape", Escape, Some("Root"))]);
+}
+
 #[derive(IntoElement)]
 pub struct Modal {
     base: Div,
@@ -20,9 +34,18 @@ pub struct Modal {
     width: Pixels,
     max_width: Option<Pixels>,
     margin_top: Option<Pixels>,
-    on_close: Rc<dyn Fn(&ClickEvent, &mut WindowContext) + 'static>,
+    pub(crate) on_close: Rc<dyn Fn(&ClickEvent, &mut WindowContext) + 'static>,
     show_close: bool,
     overlay: bool,
+    /// The index in the modal stack, used to identify the elements of the stacked modals.
+    pub(crate) layer_ix: usize,
+    /// Tracked by the modal, to keep the Tab focus traversal inside it.
+    pub(crate) focus_handle: FocusHandle,
+    pub(crate) duration: Duration,
+    easing: Easing,
+    /// True if the modal is rendered for the closing animation, it doesn't handle the events.
+    pub(crate) closing: bool,
+    accessible_label: Option<SharedString>,
 }
 
 pub(crate) fn overlay_color(overlay: bool, cx: &WindowContext) -> Hsla {
@@ -40,9 +63,9 @@ pub(crate) fn overlay_color(overlay: bool, cx: &WindowContext) -> Hsla {
 impl Modal {
     pub fn new(cx: &mut WindowContext) -> Self {
         let base = v_flex()
-            .bg(cx.theme().background)
+            .bg(cx.theme().tokens.surface)
             .border_1()
-            .border_color(cx.theme().border)
+            .border_color(cx.theme().tokens.outline)
             .rounded_lg()
             .shadow_xl()
             .min_h_48()
@@ -60,6 +83,12 @@ impl Modal {
             overlay: true,
             on_close: Rc::new(|_, _| {}),
             show_close: true,
+            layer_ix: 0,
+            focus_handle: cx.focus_handle(),
+            duration: Duration::from_secs_f64(0.2),
+            easing: default_easing(),
+            closing: false,
+            accessible_label: None,
         }
     }
 
@@ -113,6 +142,28 @@ impl Modal {
         self.overlay = overlay;
         self
     }
+
+    /// Set the duration of the fade and scale animation when opening and closing, defaults to 200ms.
+    ///
+    /// The animation is disabled by [`crate::animation::set_reduce_motion`].
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// Set the easing of the animation, defaults to `cubic_bezier(0.32, 0.72, 0., 1.)`.
+    pub fn easing(mut self, easing: impl Fn(f32) -> f32 + 'static) -> Self {
+        self.easing = Rc::new(easing);
+        self
+    }
+}
+
+impl Accessible for Modal {
+    /// The title can be any element, so set the text to announce when the modal is opened.
+    fn accessible_label(mut self, label: impl Into<SharedString>) -> Self {
+        self.accessible_label = Some(label.into());
+        self
+    }
 }
 
 impl ParentElement for Modal {
@@ -129,6 +180,12 @@ impl Styled for Modal {
 
 impl RenderOnce for Modal {
     fn render(self, cx: &mut WindowContext) -> impl gpui::IntoElement {
+        if !self.closing {
+            register_accessible(
+                AccessibleNode::new(AccessibleRole::Dialog, self.accessible_label.clone()),
+                cx,
+            );
+        }
         let on_close = self.on_close.clone();
         let view_size = cx.viewport_size();
         let bounds = Bounds {
@@ -136,15 +193,24 @@ impl RenderOnce for Modal {
             size: view_size,
         };
         let y = self.margin_top.unwrap_or(view_size.height / 10.);
-        let x = bounds.center().x - self.width / 2.;
+        let width = self.width;
+        let x = bounds.center().x - width / 2.;
+        let closing = self.closing;
+        let animation = (!reduce_motion(cx)).then(|| {
+            let easing = self.easing.clone();
+            Animation::new(self.duration).with_easing(move |delta| easing(delta))
+        });
+        // The progress of the animation, from 0 (hidden) to 1 (shown).
+        let progress = move |delta: f32| if closing { 1. - delta } else { delta };
 
         anchored().snap_to_window().child(
             div()
-                .occlude()
+                .id(("modal-overlay", self.layer_ix))
+                .when(!closing, |this| this.occlude())
                 .w(view_size.width)
                 .h(view_size.height)
                 .bg(overlay_color(self.overlay, cx))
-                .when(self.overlay, |this| {
+                .when(self.overlay && !closing, |this| {
                     this.on_mouse_down(MouseButton::Left, {
                         let on_close = self.on_close.clone();
                         move |_, cx| {
@@ -155,16 +221,17 @@ impl RenderOnce for Modal {
                 })
                 .child(
                     self.base
-                        .id("modal")
+                        .id(("modal", self.layer_ix))
+                        .track_focus(&self.focus_handle)
                         .absolute()
-                        .occlude()
+                        .when(!closing, |this| this.occlude())
                         .relative()
                         .left(x)
                         .top(y)
-                        .w(self.width)
+                        .w(width)
                         .when_some(self.max_width, |this, w| this.max_w(w))
                         .children(self.title)
-                        .when(self.show_close, |this| {
+                        .when(self.show_close && !closing, |this| {
                             this.child(
                                 Button::new("close", cx)
                                     .absolute()
@@ -173,6 +240,7 @@ impl RenderOnce for Modal {
                                     .small()
                                     .ghost()
                                     .icon(IconName::Close)
+                                    .accessible_label(t!("Modal.Close"))
                                     .on_click(move |_, cx| {
                                         on_close(&ClickEvent::default(), cx);
                                         cx.close_modal();
@@ -181,16 +249,28 @@ impl RenderOnce for Modal {
                         })
                         .child(self.content)
                         .children(self.footer)
-                        .with_animation(
-                            "slide-down",
-                            Animation::new(Duration::from_secs_f64(0.25))
-                                .with_easing(cubic_bezier(0.32, 0.72, 0., 1.)),
-                            move |this, delta| {
-                                let y_offset = px(0.) + delta * px(30.);
-                                this.top(y + y_offset).opacity(delta)
-                            },
-                        ),
-                ),
+                        .map(|this| match animation.clone() {
+                            // Scale from the center, there is no transform, so shrink the width.
+                            Some(animation) => this
+                                .with_animation("scale", animation, move |this, delta| {
+                                    let progress = progress(delta);
+                                    let scale = START_SCALE + (1. - START_SCALE) * progress;
+                                    this.w(width * scale)
+                                        .left(x + width * (1. - scale) / 2.)
+                                        .top(y + px(16.) * (1. - progress))
+                                })
+                                .into_any_element(),
+                            None => this.into_any_element(),
+                        }),
+                )
+                .map(|this| match animation {
+                    Some(animation) => this
+                        .with_animation("fade", animation, move |this, delta| {
+                            this.opacity(progress(delta))
+                        })
+                        .into_any_element(),
+                    None => this.into_any_element(),
+                }),
         )
     }
 }
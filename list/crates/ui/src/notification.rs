@@ -1,17 +1,29 @@
 use std::{any::TypeId, collections::VecDeque, sync::Arc, time::Duration};
 
+use chrono::{DateTime, Local};
 use gpui::{
     div, prelude::FluentBuilder, px, Animation, AnimationExt, ClickEvent, DismissEvent, ElementId,
-    EventEmitter, InteractiveElement as _, IntoElement, ParentElement as _, Render, SharedString,
-    StatefulInteractiveElement, Styled, View, ViewContext, VisualContext, WindowContext,
+    EventEmitter, InteractiveElement as _, IntoElement, ParentElement as _, Render, RenderOnce,
+    SharedString, StatefulInteractiveElement, Styled, View, ViewContext, VisualContext,
+    WindowContext,
 };
 use smol::Timer;
 
 use crate::{
-    animation::cubic_bezier, button::Button, h_flex, theme::ActiveTheme as _, v_flex, Icon,
-    IconName, Sizable as _, StyledExt,
+    animation::cubic_bezier, button::Button, h_flex, root::ContextModal as _,
+    theme::ActiveTheme as _, v_flex, Icon, IconName, Sizable as _, StyledExt,
 };
 
+/// The default duration to auto hide the notification.
+const DEFAULT_DURATION: Duration = Duration::from_secs(5);
+/// The max number of notifications to show when the list is not hovered.
+const MAX_COLLAPSED: usize = 3;
+/// The max number of notifications to show when the list is hovered.
+const MAX_EXPANDED: usize = 10;
+/// The max number of records to keep in the history.
+const MAX_HISTORY: usize = 100;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum NotificationType {
     Info,
     Success,
@@ -19,6 +31,21 @@ pub enum NotificationType {
     Error,
 }
 
+impl NotificationType {
+    fn icon(&self) -> Icon {
+        match self {
+            NotificationType::Info => Icon::new(IconName::Info).text_color(crate::blue_500()),
+            NotificationType::Success => {
+                Icon::new(IconName::CircleCheck).text_color(crate::green_500())
+            }
+            NotificationType::Warning => {
+                Icon::new(IconName::TriangleAlert).text_color(crate::yellow_500())
+            }
+            NotificationType::Error => Icon::new(IconName::CircleX).text_color(crate::red_500()),
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Clone)]
 pub(crate) enum NotificationId {
     Id(TypeId),
@@ -48,10 +75,19 @@ pub struct Notification {
     message: SharedString,
     icon: Option<Icon>,
     autohide: bool,
+    duration: Duration,
     on_click: Option<Arc<dyn Fn(&ClickEvent, &mut WindowContext)>>,
+    actions: Vec<NotificationAction>,
     closing: bool,
 }
 
+/// A button in the notification, e.g.: "Undo", "Open".
+#[derive(Clone)]
+struct NotificationAction {
+    label: SharedString,
+    on_click: Arc<dyn Fn(&ClickEvent, &mut WindowContext)>,
+}
+
 impl From<SharedString> for Notification {
     fn from(s: SharedString) -> Self {
         Self::new(s)
@@ -92,7 +128,9 @@ impl Notification {
             type_: NotificationType::Info,
             icon: None,
             autohide: true,
+            duration: DEFAULT_DURATION,
             on_click: None,
+            actions: Vec::new(),
             closing: false,
         }
     }
@@ -158,6 +196,14 @@ impl Notification {
         self
     }
 
+    /// Set the duration to auto hide the notification, default is 5 seconds.
+    ///
+    /// The timer is paused while the mouse is hovering on the notifications.
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+
     /// Set the click callback of the notification.
     pub fn on_click(
         mut self,
@@ -167,6 +213,26 @@ impl Notification {
         self
     }
 
+    /// Add an action button to the notification, the notification is dismissed after clicked.
+    ///
+    /// Use `cx.listener` to run the callback in the context of the view that pushed the notification.
+    ///
+    /// ```rs
+    /// Notification::new("The file has been deleted.")
+    ///     .action("Undo", cx.listener(|view, _, cx| view.undo(cx)))
+    /// ```
+    pub fn action(
+        mut self,
+        label: impl Into<SharedString>,
+        on_click: impl Fn(&ClickEvent, &mut WindowContext) + 'static,
+    ) -> Self {
+        self.actions.push(NotificationAction {
+            label: label.into(),
+            on_click: Arc::new(on_click),
+        });
+        self
+    }
+
     fn dismiss(&mut self, _: &ClickEvent, cx: &mut ViewContext<Self>) {
         self.closing = true;
         cx.notify();
@@ -191,21 +257,7 @@ impl FluentBuilder for Notification {}
 impl Render for Notification {
     fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
         let closing = self.closing;
-        let icon = match self.icon.clone() {
-            Some(icon) => icon,
-            None => match self.type_ {
-                NotificationType::Info => Icon::new(IconName::Info).text_color(crate::blue_500()),
-                NotificationType::Success => {
-                    Icon::new(IconName::CircleCheck).text_color(crate::green_500())
-                }
-                NotificationType::Warning => {
-                    Icon::new(IconName::TriangleAlert).text_color(crate::yellow_500())
-                }
-                NotificationType::Error => {
-                    Icon::new(IconName::CircleX).text_color(crate::red_500())
-                }
-            },
-        };
+        let icon = self.icon.clone().unwrap_or_else(|| self.type_.icon());
 
         div()
             .id("notification")
@@ -214,8 +266,8 @@ impl Render for Notification {
             .relative()
             .w_96()
             .border_1()
-            .border_color(cx.theme().border)
-            .bg(cx.theme().popover)
+            .border_color(cx.theme().tokens.outline)
+            .bg(cx.theme().tokens.overlay)
             .rounded_md()
             .shadow_md()
             .py_2()
@@ -230,7 +282,23 @@ impl Render for Notification {
                         this.child(div().text_sm().font_semibold().child(title))
                     })
                     .overflow_hidden()
-                    .child(div().text_sm().child(self.message.clone())),
+                    .child(div().text_sm().child(self.message.clone()))
+                    .when(!self.actions.is_empty(), |this| {
+                        this.child(h_flex().pt_1().gap_2().children(
+                            self.actions.iter().enumerate().map(|(ix, action)| {
+                                let on_click = action.on_click.clone();
+                                Button::new(("action", ix), cx)
+                                    .label(action.label.clone())
+                                    .small()
+                                    .when(ix > 0, |this| this.ghost())
+                                    .on_click(cx.listener(move |view, event, cx| {
+                                        cx.stop_propagation();
+                                        view.dismiss(event, cx);
+                                        on_click(event, cx);
+                                    }))
+                            }),
+                        ))
+                    }),
             )
             .when_some(self.on_click.clone(), |this, on_click| {
                 this.cursor_pointer()
@@ -239,23 +307,21 @@ impl Render for Notification {
                         on_click(event, cx);
                     }))
             })
-            .when(!self.autohide, |this| {
-                this.child(
-                    h_flex()
-                        .absolute()
-                        .top_1()
-                        .right_1()
-                        .invisible()
-                        .group_hover("", |this| this.visible())
-                        .child(
-                            Button::new("close", cx)
-                                .icon(IconName::Close)
-                                .ghost()
-                                .xsmall()
-                                .on_click(cx.listener(Self::dismiss)),
-                        ),
-                )
-            })
+            .child(
+                h_flex()
+                    .absolute()
+                    .top_1()
+                    .right_1()
+                    .invisible()
+                    .group_hover("", |this| this.visible())
+                    .child(
+                        Button::new("close", cx)
+                            .icon(IconName::Close)
+                            .ghost()
+                            .xsmall()
+                            .on_click(cx.listener(Self::dismiss)),
+                    ),
+            )
             .with_animation(
                 ElementId::NamedInteger("slide-down".into(), closing as usize),
                 Animation::new(Duration::from_secs_f64(0.15))
@@ -273,10 +339,22 @@ impl Render for Notification {
     }
 }
 
+/// A record of a pushed notification, kept in the history after the notification is dismissed.
+#[derive(Debug, Clone)]
+pub struct NotificationRecord {
+    pub type_: NotificationType,
+    pub title: Option<SharedString>,
+    pub message: SharedString,
+    pub created_at: DateTime<Local>,
+}
+
 /// A list of notifications.
 pub struct NotificationList {
     /// Notifications that will be auto hidden.
     pub(crate) notifications: VecDeque<View<Notification>>,
+    /// The history of the pushed notifications, the latest is at the end.
+    history: VecDeque<NotificationRecord>,
+    unread_count: usize,
     expanded: bool,
 }
 
@@ -284,6 +362,8 @@ impl NotificationList {
     pub fn new(_cx: &mut ViewContext<Self>) -> Self {
         Self {
             notifications: VecDeque::new(),
+            history: VecDeque::new(),
+            unread_count: 0,
             expanded: false,
         }
     }
@@ -292,6 +372,18 @@ impl NotificationList {
         let notification = notification.into();
         let id = notification.id.clone();
         let autohide = notification.autohide;
+        let duration = notification.duration;
+
+        self.history.push_back(NotificationRecord {
+            type_: notification.type_,
+            title: notification.title.clone(),
+            message: notification.message.clone(),
+            created_at: Local::now(),
+        });
+        if self.history.len() > MAX_HISTORY {
+            self.history.pop_front();
+        }
+        self.unread_count += 1;
 
         // Remove the notification by id, for keep unique.
         self.notifications.retain(|note| note.read(cx).id != id);
@@ -302,29 +394,38 @@ impl NotificationList {
         })
         .detach();
 
+        let note = notification.downgrade();
         self.notifications.push_back(notification);
         if autohide {
-            // Sleep for 5 seconds to autohide the notification
             cx.spawn(|view, mut cx| async move {
-                Timer::after(Duration::from_secs(5)).await;
-                let _ = view.update(&mut cx, |view, cx| {
-                    if let Some(ix) = view
-                        .notifications
-                        .iter()
-                        .position(|note| note.read(cx).autohide)
-                    {
-                        if let Some(note) = view.notifications.get(ix) {
-                            note.update(cx, |note, cx| note.dismiss(&ClickEvent::default(), cx));
-                        }
-                    }
-                    cx.notify()
-                });
+                Timer::after(duration).await;
+                // Keep the notifications while the mouse is hovering on them.
+                while view
+                    .update(&mut cx, |view, _| view.expanded)
+                    .unwrap_or(false)
+                {
+                    Timer::after(Duration::from_secs(1)).await;
+                }
+
+                let _ = note.update(&mut cx, |note, cx| note.dismiss(&ClickEvent::default(), cx));
             })
             .detach();
         }
         cx.notify();
     }
 
+    /// Dismiss the notification with the given id, if it is still showing.
+    pub(crate) fn remove(&mut self, id: NotificationId, cx: &mut ViewContext<Self>) {
+        if let Some(note) = self
+            .notifications
+            .iter()
+            .find(|note| note.read(cx).id == id)
+            .cloned()
+        {
+            note.update(cx, |note, cx| note.dismiss(&ClickEvent::default(), cx));
+        }
+    }
+
     pub fn clear(&mut self, cx: &mut ViewContext<Self>) {
         self.notifications.clear();
         cx.notify();
@@ -333,12 +434,44 @@ impl NotificationList {
     pub fn notifications(&self) -> Vec<View<Notification>> {
         self.notifications.iter().cloned().collect()
     }
+
+    /// Returns the history of the notifications, the latest is at the end.
+    pub fn history(&self) -> Vec<NotificationRecord> {
+        self.history.iter().cloned().collect()
+    }
+
+    /// Returns the number of the notifications pushed after the last [`NotificationList::mark_all_read`].
+    pub fn unread_count(&self) -> usize {
+        self.unread_count
+    }
+
+    pub fn mark_all_read(&mut self, cx: &mut ViewContext<Self>) {
+        self.unread_count = 0;
+        cx.notify();
+    }
+
+    pub fn clear_history(&mut self, cx: &mut ViewContext<Self>) {
+        self.history.clear();
+        self.unread_count = 0;
+        cx.notify();
+    }
 }
 
 impl Render for NotificationList {
     fn render(&mut self, cx: &mut gpui::ViewContext<Self>) -> impl IntoElement {
         let size = cx.viewport_size();
-        let items = self.notifications.iter().rev().take(10).rev().cloned();
+        let max_items = if self.expanded {
+            MAX_EXPANDED
+        } else {
+            MAX_COLLAPSED
+        };
+        let items = self
+            .notifications
+            .iter()
+            .rev()
+            .take(max_items)
+            .rev()
+            .cloned();
 
         div()
             .absolute()
@@ -363,3 +496,67 @@ impl Render for NotificationList {
             )
     }
 }
+
+/// The history of the notifications, e.g.: show in a Drawer.
+#[derive(IntoElement, Default)]
+pub struct NotificationHistory;
+
+impl NotificationHistory {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl RenderOnce for NotificationHistory {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let history = cx.notification_history();
+
+        v_flex()
+            .gap_2()
+            .when(history.is_empty(), |this| {
+                this.child(
+                    div()
+                        .py_4()
+                        .text_sm()
+                        .text_color(cx.theme().tokens.on_surface_muted)
+                        .child("No notifications."),
+                )
+            })
+            .children(history.iter().rev().map(|record| {
+                h_flex()
+                    .items_start()
+                    .gap_3()
+                    .py_2()
+                    .border_b_1()
+                    .border_color(cx.theme().tokens.outline)
+                    .child(div().pt_0p5().child(record.type_.icon()))
+                    .child(
+                        v_flex()
+                            .flex_1()
+                            .gap_1()
+                            .overflow_hidden()
+                            .when_some(record.title.clone(), |this, title| {
+                                this.child(div().text_sm().font_semibold().child(title))
+                            })
+                            .child(div().text_sm().child(record.message.clone()))
+                            .child(
+                                div()
+                                    .text_xs()
+                                    .text_color(cx.theme().tokens.on_surface_muted)
+                                    .child(record.created_at.format("%H:%M:%S").to_string()),
+                            ),
+                    )
+            }))
+            .when(!history.is_empty(), |this| {
+                this.child(
+                    h_flex().justify_end().child(
+                        Button::new("clear-history", cx)
+                            .label("Clear")
+                            .small()
+                            .ghost()
+                            .on_click(|_, cx| cx.clear_notification_history()),
+                    ),
+                )
+            })
+    }
+}
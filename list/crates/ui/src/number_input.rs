@@ -0,0 +1,175 @@
+use gpui::{
+    actions, AppContext, EventEmitter, FocusHandle, FocusableView, InteractiveElement as _,
+    IntoElement, KeyBinding, ParentElement as _, Render, Styled as _, View, ViewContext,
+    VisualContext as _,
+};
+
+use crate::{
+    button::Button,
+    h_flex,
+    input::{InputEvent, TextInput},
+    Disableable as _, IconName, Sizable as _,
+};
+
+actions!(number_input, [Increment, Decrement]);
+
+const CONTEXT: &str = "NumberInput";
+
+pub fn init(cx: &mut AppContext) {
+    cx.bind_keys([
+        KeyBinding::new("up", Increment, Some(CONTEXT)),
+        KeyBinding::new("down", Decrement, Some(CONTEXT)),
+    ])
+}
+
+pub enum NumberInputEvent {
+    /// The value is changed by typing, the spinner buttons or the `up` / `down` keys.
+    Change(f64),
+}
+
+/// A input for numbers, with the spinner buttons to increment or decrement the value.
+///
+/// Only numbers can be typed in, the value is clamped into `min..=max` when press enter or blur.
+pub struct NumberInput {
+    input: View<TextInput>,
+    value: f64,
+    min: f64,
+    max: f64,
+    step: f64,
+}
+
+impl NumberInput {
+    pub fn new(cx: &mut ViewContext<Self>) -> Self {
+        let input = cx.new_view(|cx| {
+            TextInput::new(cx)
+                .validate(|text| matches!(text, "" | "-" | ".") || text.parse::<f64>().is_ok())
+        });
+        cx.subscribe(&input, Self::on_input_event).detach();
+
+        let this = Self {
+            input,
+            value: 0.,
+            min: f64::MIN,
+            max: f64::MAX,
+            step: 1.,
+        };
+        this.update_text(this.value, cx);
+        this
+    }
+
+    /// Set the minimum value, default: `f64::MIN`
+    pub fn min(mut self, min: f64) -> Self {
+        self.min = min;
+        self
+    }
+
+    /// Set the maximum value, default: `f64::MAX`
+    pub fn max(mut self, max: f64) -> Self {
+        self.max = max;
+        self
+    }
+
+    /// Set the value to increment or decrement by the spinner buttons, default: 1.0
+    pub fn step(mut self, step: f64) -> Self {
+        self.step = step;
+        self
+    }
+
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    /// Set the value, the value is clamped into `min..=max`.
+    pub fn set_value(&mut self, value: f64, cx: &mut ViewContext<Self>) {
+        let value = value.clamp(self.min, self.max);
+        self.update_text(value, cx);
+        if value != self.value {
+            self.value = value;
+            cx.emit(NumberInputEvent::Change(value));
+        }
+        cx.notify();
+    }
+
+    /// The number of the decimal places of the `step`, to avoid the float errors, e.g.: 0.30000000000000004
+    fn precision(&self) -> usize {
+        let step = self.step.to_string();
+        step.split_once('.')
+            .map_or(0, |(_, decimals)| decimals.len())
+    }
+
+    fn update_text(&self, value: f64, cx: &mut ViewContext<Self>) {
+        let text = format!("{:.*}", self.precision(), value);
+        self.input.update(cx, |input, cx| input.set_text(text, cx));
+    }
+
+    fn on_input_event(
+        &mut self,
+        _: View<TextInput>,
+        event: &InputEvent,
+        cx: &mut ViewContext<Self>,
+    ) {
+        match event {
+            InputEvent::Change(text) => {
+                let Ok(value) = text.parse::<f64>() else {
+                    return;
+                };
+                // Allow to type the out of range value, it will be clamped on confirm.
+                if value != self.value && (self.min..=self.max).contains(&value) {
+                    self.value = value;
+                    cx.emit(NumberInputEvent::Change(value));
+                }
+            }
+            InputEvent::PressEnter | InputEvent::Blur => {
+                let value = self
+                    .input
+                    .read(cx)
+                    .text()
+                    .parse::<f64>()
+                    .unwrap_or(self.value);
+                self.set_value(value, cx);
+            }
+            _ => {}
+        }
+    }
+
+    fn increment(&mut self, _: &Increment, cx: &mut ViewContext<Self>) {
+        self.set_value(self.value + self.step, cx);
+    }
+
+    fn decrement(&mut self, _: &Decrement, cx: &mut ViewContext<Self>) {
+        self.set_value(self.value - self.step, cx);
+    }
+}
+
+impl EventEmitter<NumberInputEvent> for NumberInput {}
+
+impl FocusableView for NumberInput {
+    fn focus_handle(&self, cx: &AppContext) -> FocusHandle {
+        self.input.focus_handle(cx)
+    }
+}
+
+impl Render for NumberInput {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        h_flex()
+            .key_context(CONTEXT)
+            .on_action(cx.listener(Self::increment))
+            .on_action(cx.listener(Self::decrement))
+            .gap_1()
+            .child(self.input.clone())
+            .child(
+                Button::new("decrement", cx)
+                    .icon(IconName::Minus)
+                    .small()
+                    .disabled(self.value <= self.min)
+                    .on_click(cx.listener(|this, _, cx| this.decrement(&Decrement, cx))),
+            )
+            .child(
+                Button::new("increment", cx)
+                    .icon(IconName::Plus)
+                    .small()
+                    .disabled(self.value >= self.max)
+                    .on_click(cx.listener(|this, _, cx| this.increment(&Increment, cx))),
+            )
+    }
+}
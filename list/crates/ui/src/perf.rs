@@ -0,0 +1,422 @@
+//! The performance overlay of the window, to diagnose the slow rendering, toggled by [`TogglePerfOverlay`].
+//!
+//! The statistics are only collected while the overlay is shown, see [`PerfScope::on_measure`] to always measure:
+//!
+//! - The FPS and the frame time, from the render of the [`Root`] to the paint of the overlay.
+//! - The render time of the [`perf_scope`]s, e.g.: the panels of the dock.
+
+use std::{
+    cell::RefCell,
+    collections::{HashMap, VecDeque},
+    rc::Rc,
+    time::{Duration, Instant},
+};
+
+use gpui::{
+    actions, canvas, div, prelude::FluentBuilder as _, px, AnyElement, Bounds, Element, ElementId,
+    GlobalElementId, IntoElement, LayoutId, ParentElement as _, Pixels, RenderOnce, SharedString,
+    Styled as _, WindowContext,
+};
+
+use crate::{
+    h_flex,
+    theme::{ActiveTheme as _, Colorize as _},
+    v_flex, Root, StyledExt as _,
+};
+
+actions!(debug, [TogglePerfOverlay]);
+
+/// The number of the recent frames to calculate the frame time.
+const FRAME_SAMPLES: usize = 120;
+/// The scopes not rendered in this duration are hidden from the overlay.
+const SCOPE_TIMEOUT: Duration = Duration::from_secs(2);
+/// The max number of the scopes shown in the overlay.
+const MAX_SCOPES: usize = 8;
+
+/// The render time of a [`perf_scope`].
+#[derive(Debug, Clone, Copy)]
+pub struct ScopeStats {
+    pub last: Duration,
+    pub max: Duration,
+    rendered_at: Instant,
+}
+
+/// The render statistics of a window.
+#[derive(Debug, Default)]
+pub struct PerfStats {
+    frame_start: Option<Instant>,
+    /// The start and the duration of the recent frames, the latest is at the end.
+    frames: VecDeque<(Instant, Duration)>,
+    scopes: HashMap<SharedString, ScopeStats>,
+    /// The number of the scopes rendered in the current frame.
+    frame_scopes: usize,
+    last_frame_scopes: usize,
+}
+
+impl PerfStats {
+    pub(crate) fn begin_frame(&mut self, now: Instant) {
+        self.frame_start = Some(now);
+        self.frame_scopes = 0;
+    }
+
+    pub(crate) fn end_frame(&mut self, now: Instant) {
+        let Some(start) = self.frame_start.take() else {
+            return;
+        };
+
+        self.frames
+            .push_back((start, now.saturating_duration_since(start)));
+        if self.frames.len() > FRAME_SAMPLES {
+            self.frames.pop_front();
+        }
+        self.last_frame_scopes = self.frame_scopes;
+    }
+
+    pub(crate) fn record_scope(&mut self, name: SharedString, duration: Duration, now: Instant) {
+        self.frame_scopes += 1;
+        let stats = self.scopes.entry(name).or_insert(ScopeStats {
+            last: duration,
+            max: duration,
+            rendered_at: now,
+        });
+        stats.last = duration;
+        stats.max = stats.max.max(duration);
+        stats.rendered_at = now;
+    }
+
+    /// Returns the number of the frames drawn in the last second before the latest frame.
+    pub fn fps(&self) -> usize {
+        let Some((last, _)) = self.frames.back() else {
+            return 0;
+        };
+
+        self.frames
+            .iter()
+            .filter(|(start, _)| last.saturating_duration_since(*start) < Duration::from_secs(1))
+            .count()
+    }
+
+    /// Returns the average and the max duration of the recent frames.
+    pub fn frame_time(&self) -> (Duration, Duration) {
+        if self.frames.is_empty() {
+            return (Duration::ZERO, Duration::ZERO);
+        }
+
+        let total: Duration = self.frames.iter().map(|(_, duration)| *duration).sum();
+        let max = self
+            .frames
+            .iter()
+            .map(|(_, duration)| *duration)
+            .max()
+            .unwrap_or_default();
+        (total / self.frames.len() as u32, max)
+    }
+
+    /// Returns the number of the scopes rendered in the last frame.
+    pub fn rendered_scopes(&self) -> usize {
+        self.last_frame_scopes
+    }
+
+    /// Returns the scopes rendered recently, the slowest is the first.
+    pub fn recent_scopes(&self, now: Instant) -> Vec<(SharedString, ScopeStats)> {
+        let mut scopes = self
+            .scopes
+            .iter()
+            .filter(|(_, stats)| now.saturating_duration_since(stats.rendered_at) < SCOPE_TIMEOUT)
+            .map(|(name, stats)| (name.clone(), *stats))
+            .collect::<Vec<_>>();
+        scopes.sort_by(|a, b| b.1.last.cmp(&a.1.last).then_with(|| a.0.cmp(&b.0)));
+        scopes
+    }
+}
+
+/// Returns the statistics of the window, `None` if the overlay is hidden.
+fn window_stats(cx: &WindowContext) -> Option<Rc<RefCell<PerfStats>>> {
+    cx.window_handle()
+        .downcast::<Root>()
+        .and_then(|window| window.root_view(cx).ok())
+        .and_then(|root| root.read(cx).perf_stats.clone())
+}
+
+/// The time of the phases to draw the element of a [`perf_scope`].
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct RenderTimings {
+    /// The render of the views and the layout request, the views are rendered in it.
+    pub render: Duration,
+    pub prepaint: Duration,
+    pub paint: Duration,
+}
+
+impl RenderTimings {
+    pub fn total(&self) -> Duration {
+        self.render + self.prepaint + self.paint
+    }
+}
+
+/// Measure the time to render, layout and paint the `child`, it's shown in the overlay by the `name`.
+///
+/// The `child` is rendered as is if the overlay is hidden, and there is no [`PerfScope::on_measure`].
+pub fn perf_scope(name: impl Into<SharedString>, child: impl IntoElement) -> PerfScope {
+    PerfScope {
+        name: name.into(),
+        child: child.into_any_element(),
+        stats: None,
+        on_measure: None,
+        measuring: false,
+        timings: RenderTimings::default(),
+    }
+}
+
+/// The element of the [`perf_scope`].
+pub struct PerfScope {
+    name: SharedString,
+    child: AnyElement,
+    stats: Option<Rc<RefCell<PerfStats>>>,
+    on_measure: Option<Box<dyn Fn(&RenderTimings, &mut WindowContext) + 'static>>,
+    measuring: bool,
+    timings: RenderTimings,
+}
+
+impl PerfScope {
+    /// Always measure the `child`, and call the `handler` with the timings after it's painted.
+    pub fn on_measure(
+        mut self,
+        handler: impl Fn(&RenderTimings, &mut WindowContext) + 'static,
+    ) -> Self {
+        self.on_measure = Some(Box::new(handler));
+        self
+    }
+
+    /// Run the `f`, and returns its duration if the child is measured.
+    fn measure<R>(
+        &mut self,
+        cx: &mut WindowContext,
+        f: impl FnOnce(&mut AnyElement, &mut WindowContext) -> R,
+    ) -> (R, Duration) {
+        if !self.measuring {
+            return (f(&mut self.child, cx), Duration::ZERO);
+        }
+
+        let start = Instant::now();
+        let result = f(&mut self.child, cx);
+        (result, start.elapsed())
+    }
+}
+
+impl IntoElement for PerfScope {
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {
+        self
+    }
+}
+
+impl Element for PerfScope {
+    type RequestLayoutState = ();
+    type PrepaintState = ();
+
+    fn id(&self) -> Option<ElementId> {
+        None
+    }
+
+    fn request_layout(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        cx: &mut WindowContext,
+    ) -> (LayoutId, Self::RequestLayoutState) {
+        self.stats = window_stats(cx);
+        self.measuring = self.stats.is_some() || self.on_measure.is_some();
+        // The views are rendered in the layout, so the render time is included.
+        let (layout_id, render) = self.measure(cx, |child, cx| child.request_layout(cx));
+        self.timings = RenderTimings {
+            render,
+            ..Default::default()
+        };
+        (layout_id, ())
+    }
+
+    fn prepaint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _bounds: Bounds<Pixels>,
+        _request_layout: &mut Self::RequestLayoutState,
+        cx: &mut WindowContext,
+    ) -> Self::PrepaintState {
+        let (_, prepaint) = self.measure(cx, |child, cx| {
+            child.prepaint(cx);
+        });
+        self.timings.prepaint = prepaint;
+    }
+
+    fn paint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _bounds: Bounds<Pixels>,
+        _request_layout: &mut Self::RequestLayoutState,
+        _prepaint: &mut Self::PrepaintState,
+        cx: &mut WindowContext,
+    ) {
+        let (_, paint) = self.measure(cx, |child, cx| child.paint(cx));
+        self.timings.paint = paint;
+
+        if let Some(stats) = self.stats.take() {
+            stats.borrow_mut().record_scope(
+                self.name.clone(),
+                self.timings.total(),
+                Instant::now(),
+            );
+        }
+        if let Some(on_measure) = &self.on_measure {
+            on_measure(&self.timings, cx);
+        }
+    }
+}
+
+fn format_duration(duration: Duration) -> String {
+    format!("{:.1}ms", duration.as_secs_f64() * 1000.)
+}
+
+/// The overlay at the top-right corner of the [`Root`], it ends the frame when it's painted.
+#[derive(IntoElement)]
+pub(crate) struct PerfOverlay {
+    stats: Rc<RefCell<PerfStats>>,
+}
+
+impl PerfOverlay {
+    pub(crate) fn new(stats: Rc<RefCell<PerfStats>>) -> Self {
+        Self { stats }
+    }
+}
+
+impl RenderOnce for PerfOverlay {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let (fps, (avg, max), rendered_scopes, scopes) = {
+            let stats = self.stats.borrow();
+            (
+                stats.fps(),
+                stats.frame_time(),
+                stats.rendered_scopes(),
+                stats.recent_scopes(Instant::now()),
+            )
+        };
+
+        let row = |label: &'static str, value: String| {
+            h_flex()
+                .gap_4()
+                .justify_between()
+                .child(label)
+                .child(div().font_semibold().child(value))
+        };
+
+        let stats = self.stats.clone();
+        div()
+            .absolute()
+            .top(px(40.))
+            .right_2()
+            .w(px(240.))
+            .p_2()
+            .rounded_md()
+            .border_1()
+            .border_color(cx.theme().tokens.outline)
+            .bg(cx.theme().tokens.overlay.opacity(0.9))
+            .text_color(cx.theme().tokens.on_overlay)
+            .text_xs()
+            .font_family("monospace")
+            .child(
+                v_flex()
+                    .gap(px(2.))
+                    .child(row("FPS", fps.to_string()))
+                    .child(row("Frame time", format_duration(avg)))
+                    .child(row("Max frame time", format_duration(max)))
+                    .child(row("Windows", cx.windows().len().to_string()))
+                    .child(row("Panels rendered", rendered_scopes.to_string()))
+                    .when(!scopes.is_empty(), |this| {
+                        this.child(
+                            div()
+                                .mt_1()
+                                .pt_1()
+                                .border_t_1()
+                                .border_color(cx.theme().tokens.outline)
+                                .text_color(cx.theme().tokens.on_surface_muted)
+                                .child("Last render (max)"),
+                        )
+                    })
+                    .children(scopes.into_iter().take(MAX_SCOPES).map(|(name, scope)| {
+                        h_flex()
+                            .gap_2()
+                            .justify_between()
+                            .child(div().flex_1().overflow_hidden().child(name))
+                            .child(format!(
+                                "{} ({})",
+                                format_duration(scope.last),
+                                format_duration(scope.max)
+                            ))
+                    })),
+            )
+            .child(
+                canvas(
+                    |_, _| (),
+                    move |_, _, _| stats.borrow_mut().end_frame(Instant::now()),
+                )
+                .size_0(),
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use super::PerfStats;
+
+    #[test]
+    fn test_frame_stats() {
+        let mut stats = PerfStats::default();
+        assert_eq!(stats.fps(), 0);
+        assert_eq!(stats.frame_time(), (Duration::ZERO, Duration::ZERO));
+
+        let start = Instant::now();
+        for ix in 0..30 {
+            let frame_start = start + Duration::from_millis(ix * 50);
+            stats.begin_frame(frame_start);
+            stats.end_frame(frame_start + Duration::from_millis(if ix == 29 { 12 } else { 4 }));
+        }
+
+        // The frames in 0..=1450ms, and the last one is at 1450ms.
+        assert_eq!(stats.fps(), 20);
+        let (avg, max) = stats.frame_time();
+        assert_eq!(max, Duration::from_millis(12));
+        assert!(avg > Duration::from_millis(4) && avg < Duration::from_millis(5));
+
+        // The end without a begin is ignored.
+        stats.end_frame(start + Duration::from_secs(10));
+        assert_eq!(stats.frames.len(), 30);
+    }
+
+    #[test]
+    fn test_recent_scopes() {
+        let mut stats = PerfStats::default();
+        let now = Instant::now();
+
+        stats.begin_frame(now);
+        stats.record_scope("Old".into(), Duration::from_millis(50), now);
+        stats.end_frame(now);
+
+        let now = now + Duration::from_secs(3);
+        stats.begin_frame(now);
+        stats.record_scope("Table".into(), Duration::from_millis(8), now);
+        stats.record_scope("List".into(), Duration::from_millis(20), now);
+        stats.record_scope("Table".into(), Duration::from_millis(2), now);
+        stats.end_frame(now);
+
+        assert_eq!(stats.rendered_scopes(), 3);
+        let scopes = stats.recent_scopes(now);
+        let names = scopes
+            .iter()
+            .map(|(name, _)| name.as_ref())
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["List", "Table"]);
+        assert_eq!(scopes[1].1.last, Duration::from_millis(2));
+        assert_eq!(scopes[1].1.max, Duration::from_millis(8));
+    }
+}
@@ -2,7 +2,7 @@ use gpui::{
     actions, anchored, deferred, div, prelude::FluentBuilder as _, AnchorCorner, AnyElement,
     AppContext, Bounds, DismissEvent, DispatchPhase, Element, ElementId, EventEmitter, FocusHandle,
     FocusableView, GlobalElementId, Hitbox, InteractiveElement as _, IntoElement, KeyBinding,
-    LayoutId, ManagedView, MouseButton, MouseDownEvent, ParentElement, Pixels, Point, Render,
+    LayoutId, ManagedView, MouseButton, MouseDownEvent, ParentElement, Pixels, Point, Render, Size,
     Style, Styled, View, ViewContext, VisualContext, WindowContext,
 };
 use std::{cell::RefCell, rc::Rc};
@@ -142,8 +142,50 @@ where
         base.child((trigger)(is_open, cx)).into_element()
     }
 
-    fn resolved_corner(&self, bounds: Bounds<Pixels>) -> Point<Pixels> {
-        match self.anchor {
+    /// Returns the anchor to use, flipped to the other side of the trigger
+    /// if the popover would overflow the window on the side of the `anchor`.
+    fn flipped_anchor(
+        &self,
+        trigger_bounds: Bounds<Pixels>,
+        popover_size: Size<Pixels>,
+        cx: &WindowContext,
+    ) -> AnchorCorner {
+        let viewport_size = cx.viewport_size();
+        let (mut top, mut left) = match self.anchor {
+            AnchorCorner::TopLeft => (true, true),
+            AnchorCorner::TopRight => (true, false),
+            AnchorCorner::BottomLeft => (false, true),
+            AnchorCorner::BottomRight => (false, false),
+        };
+
+        // The popover is below the trigger with `Top*` anchors, and above with `Bottom*`.
+        let below_space = viewport_size.height - trigger_bounds.bottom();
+        let above_space = trigger_bounds.top();
+        if top && popover_size.height > below_space && above_space > below_space {
+            top = false;
+        } else if !top && popover_size.height > above_space && below_space > above_space {
+            top = true;
+        }
+
+        // The popover grows to the right with `*Left` anchors, and to the left with `*Right`.
+        let right_space = viewport_size.width - trigger_bounds.left();
+        let left_space = trigger_bounds.right();
+        if left && popover_size.width > right_space && left_space > right_space {
+            left = false;
+        } else if !left && popover_size.width > left_space && right_space > left_space {
+            left = true;
+        }
+
+        match (top, left) {
+            (true, true) => AnchorCorner::TopLeft,
+            (true, false) => AnchorCorner::TopRight,
+            (false, true) => AnchorCorner::BottomLeft,
+            (false, false) => AnchorCorner::BottomRight,
+        }
+    }
+
+    fn resolved_corner(anchor: AnchorCorner, bounds: Bounds<Pixels>) -> Point<Pixels> {
+        match anchor {
             AnchorCorner::TopLeft => AnchorCorner::BottomLeft,
             AnchorCorner::TopRight => AnchorCorner::BottomRight,
             AnchorCorner::BottomLeft => AnchorCorner::TopLeft,
@@ -188,6 +230,8 @@ pub struct PopoverElementState<M> {
     content_view: Rc<RefCell<Option<View<M>>>>,
     /// Trigger bounds for positioning the popover.
     trigger_bounds: Option<Bounds<Pixels>>,
+    /// The size of the popover in last paint, for flipping it when near the window edge.
+    popover_size: Option<Size<Pixels>>,
 }
 
 impl<M> Default for PopoverElementState<M> {
@@ -199,6 +243,7 @@ impl<M> Default for PopoverElementState<M> {
             trigger_element: None,
             content_view: Rc::new(RefCell::new(None)),
             trigger_bounds: None,
+            popover_size: None,
         }
     }
 }
@@ -207,6 +252,7 @@ pub struct PrepaintState {
     hitbox: Hitbox,
     /// Trigger bounds for limit a rect to handle mouse click.
     trigger_bounds: Option<Bounds<Pixels>>,
+    popover_size: Option<Size<Pixels>>,
 }
 
 impl<M: ManagedView> Element for Popover<M> {
@@ -230,14 +276,20 @@ impl<M: ManagedView> Element for Popover<M> {
             if let Some(content_view) = element_state.content_view.borrow_mut().as_mut() {
                 is_open = true;
 
-                let mut anchored = anchored().snap_to_window().anchor(view.anchor);
+                let mut anchor = view.anchor;
+                if let (Some(trigger_bounds), Some(popover_size)) =
+                    (element_state.trigger_bounds, element_state.popover_size)
+                {
+                    anchor = view.flipped_anchor(trigger_bounds, popover_size, cx);
+                }
+
+                let mut anchored = anchored().snap_to_window().anchor(anchor);
                 if let Some(trigger_bounds) = element_state.trigger_bounds {
-                    anchored = anchored.position(view.resolved_corner(trigger_bounds));
+                    anchored = anchored.position(Self::resolved_corner(anchor, trigger_bounds));
                 }
 
                 let mut element = {
                     let content_view_mut = element_state.content_view.clone();
-                    let anchor = view.anchor;
                     let no_style = view.no_style;
                     deferred(
                         anchored.child(
@@ -312,14 +364,15 @@ impl<M: ManagedView> Element for Popover<M> {
             .map(|id| cx.layout_bounds(id));
 
         // Prepare the popover, for get the bounds of it for open window size.
-        let _ = request_layout
+        let popover_size = request_layout
             .popover_layout_id
-            .map(|id| cx.layout_bounds(id));
+            .map(|id| cx.layout_bounds(id).size);
 
         let hitbox = cx.insert_hitbox(trigger_bounds.unwrap_or_default(), false);
 
         PrepaintState {
             trigger_bounds,
+            popover_size,
             hitbox,
         }
     }
@@ -334,6 +387,9 @@ impl<M: ManagedView> Element for Popover<M> {
     ) {
         self.with_element_state(id.unwrap(), cx, |this, element_state, cx| {
             element_state.trigger_bounds = prepaint.trigger_bounds;
+            if prepaint.popover_size.is_some() {
+                element_state.popover_size = prepaint.popover_size;
+            }
 
             if let Some(mut element) = request_layout.trigger_element.take() {
                 element.paint(cx);
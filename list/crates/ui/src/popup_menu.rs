@@ -356,12 +356,14 @@ impl PopupMenu {
     ) -> Option<impl IntoElement> {
         if let Some(action) = action {
             if let Some(keybinding) = cx.bindings_for_action(action.deref()).first() {
-                let el = div().text_color(cx.theme().muted_foreground).children(
-                    keybinding
-                        .keystrokes()
-                        .into_iter()
-                        .map(|keystroke| format!("{}", keystroke)),
-                );
+                let el = div()
+                    .text_color(cx.theme().tokens.on_surface_muted)
+                    .children(
+                        keybinding
+                            .keystrokes()
+                            .into_iter()
+                            .map(|keystroke| format!("{}", keystroke)),
+                    );
 
                 return Some(el);
             }
@@ -428,7 +430,7 @@ impl Render for PopupMenu {
             .gap_y_0p5()
             .min_w(rems(8.))
             .popover_style(cx)
-            .text_color(cx.theme().popover_foreground)
+            .text_color(cx.theme().tokens.on_overlay)
             .relative()
             .child({
                 canvas(
@@ -463,7 +465,7 @@ impl Render for PopupMenu {
                             .mx_neg_1()
                             .my_px()
                             .border_0()
-                            .bg(cx.theme().muted),
+                            .bg(cx.theme().tokens.surface_muted),
                     ),
                     PopupMenuItem::Item {
                         icon,
@@ -1,6 +1,8 @@
+use std::time::Duration;
+
 use gpui::{
-    div, prelude::FluentBuilder, px, relative, IntoElement, ParentElement, RenderOnce, Styled,
-    WindowContext,
+    div, ease_in_out, prelude::FluentBuilder, px, relative, Animation, AnimationExt as _,
+    IntoElement, ParentElement, RenderOnce, Styled, WindowContext,
 };
 
 use crate::theme::ActiveTheme;
@@ -9,6 +11,7 @@ use crate::theme::ActiveTheme;
 pub struct Progress {
     value: f32,
     height: f32,
+    indeterminate: bool,
 }
 
 impl Progress {
@@ -16,13 +19,21 @@ impl Progress {
         Progress {
             value: Default::default(),
             height: 8.,
+            indeterminate: false,
         }
     }
 
+    /// Set the value of the progress, range of 0.0..100.0
     pub fn value(mut self, value: f32) -> Self {
         self.value = value;
         self
     }
+
+    /// Show an animated bar instead of the value, for the task that the progress is unknown.
+    pub fn indeterminate(mut self, indeterminate: bool) -> Self {
+        self.indeterminate = indeterminate;
+        self
+    }
 }
 
 impl RenderOnce for Progress {
@@ -34,6 +45,35 @@ impl RenderOnce for Progress {
             v => v / 100.,
         });
 
+        if self.indeterminate {
+            // The width of the bar, relative to the track.
+            const BAR_WIDTH: f32 = 0.3;
+
+            return div()
+                .relative()
+                .h(px(self.height))
+                .rounded(rounded)
+                .overflow_hidden()
+                .bg(cx.theme().progress_bar.opacity(0.2))
+                .child(
+                    div()
+                        .absolute()
+                        .top_0()
+                        .h_full()
+                        .w(relative(BAR_WIDTH))
+                        .rounded(rounded)
+                        .bg(cx.theme().progress_bar)
+                        .with_animation(
+                            "progress-indeterminate",
+                            Animation::new(Duration::from_secs_f64(1.5))
+                                .repeat()
+                                .with_easing(ease_in_out),
+                            |this, delta| this.left(relative(delta * (1. + BAR_WIDTH) - BAR_WIDTH)),
+                        ),
+                )
+                .into_any_element();
+        }
+
         div()
             .relative()
             .h(px(self.height))
@@ -52,5 +92,6 @@ impl RenderOnce for Progress {
                         _ => this.rounded_l(rounded),
                     }),
             )
+            .into_any_element()
     }
 }
@@ -1,10 +1,12 @@
+use std::rc::Rc;
+
 use gpui::{
-    div, prelude::FluentBuilder, relative, svg, CursorStyle, ElementId, InteractiveElement,
+    div, prelude::FluentBuilder, relative, Axis, CursorStyle, ElementId, InteractiveElement,
     IntoElement, ParentElement, RenderOnce, SharedString, StatefulInteractiveElement, Styled,
     WindowContext,
 };
 
-use crate::{h_flex, theme::ActiveTheme, IconName};
+use crate::{h_flex, theme::ActiveTheme, v_flex, Icon, IconName};
 
 #[derive(IntoElement)]
 pub struct Radio {
@@ -50,16 +52,16 @@ impl Radio {
 impl RenderOnce for Radio {
     fn render(self, cx: &mut WindowContext) -> impl IntoElement {
         let color = if self.disabled {
-            cx.theme().primary.opacity(0.5)
+            cx.theme().tokens.accent.opacity(0.5)
         } else {
-            cx.theme().primary
+            cx.theme().tokens.accent
         };
 
         h_flex()
             .id(self.id)
             .gap_x_2()
             .cursor(CursorStyle::PointingHand)
-            .text_color(cx.theme().foreground)
+            .text_color(cx.theme().tokens.on_surface)
             .items_start()
             .child(
                 div()
@@ -71,14 +73,14 @@ impl RenderOnce for Radio {
                     .border_color(color)
                     .when(self.checked, |this| this.bg(color))
                     .child(
-                        svg()
+                        Icon::empty()
                             .absolute()
                             .top_px()
                             .left_px()
                             .size_3()
                             .text_color(color)
                             .when(self.checked, |this| {
-                                this.text_color(cx.theme().primary_foreground)
+                                this.text_color(cx.theme().tokens.on_accent)
                             })
                             .map(|this| match self.checked {
                                 true => this.path(IconName::Check.path()),
@@ -105,3 +107,107 @@ impl RenderOnce for Radio {
             )
     }
 }
+
+/// A group of [`Radio`], only one of them can be checked.
+#[derive(IntoElement)]
+pub struct RadioGroup {
+    id: ElementId,
+    radios: Vec<Radio>,
+    layout: Axis,
+    selected_index: Option<usize>,
+    disabled: bool,
+    on_change: Option<Rc<dyn Fn(&usize, &mut WindowContext) + 'static>>,
+}
+
+impl RadioGroup {
+    fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            radios: Vec::new(),
+            layout: Axis::Vertical,
+            selected_index: None,
+            disabled: false,
+            on_change: None,
+        }
+    }
+
+    /// Create a RadioGroup with the radios in a column.
+    pub fn vertical(id: impl Into<ElementId>) -> Self {
+        Self::new(id)
+    }
+
+    /// Create a RadioGroup with the radios in a row.
+    pub fn horizontal(id: impl Into<ElementId>) -> Self {
+        Self::new(id).layout(Axis::Horizontal)
+    }
+
+    pub fn layout(mut self, layout: Axis) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    /// Add a radio to the group, the `checked` and `on_click` of it are controlled by the group.
+    pub fn child(mut self, radio: impl Into<Radio>) -> Self {
+        self.radios.push(radio.into());
+        self
+    }
+
+    pub fn children(mut self, radios: impl IntoIterator<Item = impl Into<Radio>>) -> Self {
+        self.radios.extend(radios.into_iter().map(Into::into));
+        self
+    }
+
+    pub fn selected_index(mut self, ix: Option<usize>) -> Self {
+        self.selected_index = ix;
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Set the handler to call with the index of the radio when it is checked.
+    pub fn on_change(mut self, handler: impl Fn(&usize, &mut WindowContext) + 'static) -> Self {
+        self.on_change = Some(Rc::new(handler));
+        self
+    }
+}
+
+impl From<&'static str> for Radio {
+    fn from(label: &'static str) -> Self {
+        Self::new(label).label(label)
+    }
+}
+
+impl From<SharedString> for Radio {
+    fn from(label: SharedString) -> Self {
+        Self::new(label.clone()).label(label)
+    }
+}
+
+impl RenderOnce for RadioGroup {
+    fn render(self, _: &mut WindowContext) -> impl IntoElement {
+        let on_change = self.on_change;
+        let disabled = self.disabled;
+        let selected_index = self.selected_index;
+
+        let base = if self.layout == Axis::Vertical {
+            v_flex().gap_3()
+        } else {
+            h_flex().gap_x_6().flex_wrap()
+        };
+
+        base.id(self.id)
+            .children(self.radios.into_iter().enumerate().map(|(ix, radio)| {
+                let disabled = disabled || radio.disabled;
+                radio
+                    .checked(selected_index == Some(ix))
+                    .disabled(disabled)
+                    .map(|this| match on_change.clone() {
+                        Some(on_change) => this.on_click(move |_, cx| on_change(&ix, cx)),
+                        None => this,
+                    })
+            }))
+    }
+}
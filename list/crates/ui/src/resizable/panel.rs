@@ -1,20 +1,29 @@
-use std::rc::Rc;
+use std::{rc::Rc, time::Duration};
 
 use gpui::{
-    canvas, div, prelude::FluentBuilder, px, Along, AnyElement, AnyView, Axis, Bounds, Element,
-    Entity, EntityId, InteractiveElement as _, IntoElement, MouseMoveEvent, MouseUpEvent,
+    canvas, div, prelude::FluentBuilder, px, Along, Animation, AnimationExt as _, AnyElement,
+    AnyView, AppContext, Axis, Bounds, ClickEvent, Element, ElementId, Entity, EntityId,
+    EventEmitter, InteractiveElement as _, IntoElement, MouseMoveEvent, MouseUpEvent,
     ParentElement, Pixels, Render, StatefulInteractiveElement, Style, Styled, View, ViewContext,
     VisualContext as _, WindowContext,
 };
 
-use crate::{h_flex, theme::ActiveTheme, v_flex, AxisExt};
+use smol::Timer;
+
+use crate::{animation::cubic_bezier, h_flex, theme::ActiveTheme, v_flex, AxisExt};
 
 const PANEL_MIN_SIZE: Pixels = px(100.);
 const HANDLE_PADDING: Pixels = px(4.);
+const COLLAPSE_DURATION: Duration = Duration::from_millis(150);
 
 #[derive(Clone, Render)]
 pub struct DragPanel(pub (EntityId, usize, Axis));
 
+pub enum ResizablePanelEvent {
+    /// The panels have been resized by dragging the handle, emitted when the mouse is released.
+    Resized,
+}
+
 #[derive(Clone)]
 pub struct ResizablePanelGroup {
     panels: Vec<View<ResizablePanel>>,
@@ -24,6 +33,7 @@ pub struct ResizablePanelGroup {
     size: Option<Pixels>,
     bounds: Bounds<Pixels>,
     resizing_panel_ix: Option<usize>,
+    proportional: bool,
 }
 
 impl ResizablePanelGroup {
@@ -36,6 +46,7 @@ impl ResizablePanelGroup {
             size: None,
             bounds: Bounds::default(),
             resizing_panel_ix: None,
+            proportional: false,
         }
     }
 
@@ -55,6 +66,29 @@ impl ResizablePanelGroup {
         cx.notify();
     }
 
+    /// Keep the panels in the proportions of the group size when it is resized, default is false.
+    ///
+    /// By default the panels keep their sizes in pixels, and only the panels without size fill the rest space.
+    pub fn proportional(mut self, proportional: bool) -> Self {
+        self.proportional = proportional;
+        self
+    }
+
+    pub(crate) fn set_proportional(&mut self, proportional: bool, cx: &mut ViewContext<Self>) {
+        if self.proportional == proportional {
+            return;
+        }
+
+        self.proportional = proportional;
+        // The ratios will be calculated from the current sizes on the next layout.
+        self.clear_ratios(cx);
+        cx.notify();
+    }
+
+    pub fn is_proportional(&self) -> bool {
+        self.proportional
+    }
+
     /// Set the size of the resize handle, default is 3px.
     ///
     /// The handle size will inherit the parent group handle size, if you insert a group into another group.
@@ -121,6 +155,9 @@ impl ResizablePanelGroup {
         let old_panel_initial_size = old_panel.read(cx).initial_size;
 
         panel.initial_size = old_panel_initial_size;
+        panel.min_size = old_panel.read(cx).min_size;
+        panel.max_size = old_panel.read(cx).max_size;
+        panel.ratio = old_panel.read(cx).ratio;
         panel.axis = self.axis;
         panel.group = Some(cx.view().clone());
         self.sizes[ix] = panel.initial_size.unwrap_or_default();
@@ -128,6 +165,51 @@ impl ResizablePanelGroup {
         cx.notify()
     }
 
+    /// Returns the current sizes of the panels, the collapsed panels return the size before collapsed.
+    pub(crate) fn sizes(&self, cx: &AppContext) -> Vec<Pixels> {
+        self.panels
+            .iter()
+            .zip(self.sizes.iter())
+            .map(|(panel, size)| {
+                let panel = panel.read(cx);
+                if panel.collapsed {
+                    panel.expanded_size.unwrap_or(*size)
+                } else {
+                    *size
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the min and max sizes of the panels, see [`ResizablePanel::min_size`] and [`ResizablePanel::max_size`].
+    pub(crate) fn size_limits(&self, cx: &AppContext) -> Vec<(Pixels, Option<Pixels>)> {
+        self.panels
+            .iter()
+            .map(|panel| {
+                let panel = panel.read(cx);
+                (panel.min_size, panel.max_size)
+            })
+            .collect()
+    }
+
+    /// Collapse or expand the panel at the index, the size before collapsed will be restored when expanding.
+    pub fn set_collapsed(&mut self, ix: usize, collapsed: bool, cx: &mut ViewContext<Self>) {
+        let Some(panel) = self.panels.get(ix).cloned() else {
+            return;
+        };
+
+        panel.update(cx, |panel, cx| panel.set_collapsed(collapsed, cx));
+        self.sizes[ix] = panel.read(cx).size.unwrap_or_default();
+        cx.notify();
+    }
+
+    /// Returns true if the panel at the index is collapsed.
+    pub fn is_collapsed(&self, ix: usize, cx: &AppContext) -> bool {
+        self.panels
+            .get(ix)
+            .map_or(false, |panel| panel.read(cx).collapsed)
+    }
+
     pub fn remove_child(&mut self, ix: usize, cx: &mut ViewContext<Self>) {
         self.sizes.remove(ix);
         self.panels.remove(ix);
@@ -168,7 +250,7 @@ impl ResizablePanelGroup {
             })
             .child(
                 div()
-                    .bg(cx.theme().border)
+                    .bg(cx.theme().tokens.outline)
                     .when(self.axis.is_horizontal(), |this| {
                         this.h_full().w(self.handle_size)
                     })
@@ -176,6 +258,11 @@ impl ResizablePanelGroup {
                         this.w_full().h(self.handle_size)
                     }),
             )
+            .on_click(cx.listener(|view, event: &ClickEvent, cx| {
+                if event.up.click_count == 2 {
+                    view.reset_sizes(cx);
+                }
+            }))
             .on_drag(
                 DragPanel((cx.entity_id(), ix, axis)),
                 move |drag_panel, cx| {
@@ -189,74 +276,230 @@ impl ResizablePanelGroup {
             )
     }
 
+    fn clear_ratios(&mut self, cx: &mut ViewContext<Self>) {
+        for panel in &self.panels {
+            panel.update(cx, |panel, cx| {
+                panel.ratio = None;
+                cx.notify();
+            });
+        }
+    }
+
+    /// Calculate the ratios of the panels that have not got one from their real sizes,
+    /// e.g.: the panels just added, so they can be rescaled with the group.
+    fn sync_ratios(&mut self, cx: &mut ViewContext<Self>) {
+        if !self.proportional {
+            return;
+        }
+        let container_size = self.bounds.size.along(self.axis);
+        if container_size <= px(0.) {
+            return;
+        }
+
+        for (ix, panel) in self.panels.iter().enumerate() {
+            let size = self.sizes[ix];
+            let needs_ratio = {
+                let panel = panel.read(cx);
+                panel.ratio.is_none() && !panel.collapsed && panel.animation.is_none()
+            };
+            if needs_ratio && size > px(0.) {
+                panel.update(cx, |panel, cx| {
+                    panel.ratio = Some(size / container_size);
+                    cx.notify();
+                });
+            }
+        }
+    }
+
     fn sync_real_panel_sizes(&mut self, cx: &WindowContext) {
         for (i, panel) in self.panels.iter().enumerate() {
             self.sizes[i] = panel.read(cx).bounds.size.along(self.axis)
         }
     }
 
+    /// Returns the min and max size of the panel at the index.
+    fn panel_size_range(&self, ix: usize, cx: &AppContext) -> (Pixels, Pixels) {
+        let panel = self.panels[ix].read(cx);
+        if panel.collapsed {
+            return (px(0.), px(0.));
+        }
+        (panel.min_size, panel.max_size.unwrap_or(px(f32::MAX)))
+    }
+
+    fn panel_size_ranges(&self, cx: &AppContext) -> Vec<(Pixels, Pixels)> {
+        (0..self.panels.len())
+            .map(|ix| self.panel_size_range(ix, cx))
+            .collect()
+    }
+
+    /// Update the panels with the sizes, and the ratios in the proportional mode.
+    fn apply_sizes(&mut self, sizes: Vec<Pixels>, cx: &mut ViewContext<Self>) {
+        let container_size = self.bounds.size.along(self.axis);
+        let proportional = self.proportional && container_size > px(0.);
+        self.sizes = sizes;
+        for (i, panel) in self.panels.iter().enumerate() {
+            let size = self.sizes[i];
+            if size > px(0.) {
+                panel.update(cx, |this, cx| {
+                    this.size = Some(size);
+                    if proportional && !this.collapsed {
+                        this.ratio = Some(size / container_size);
+                    }
+                    cx.notify();
+                });
+            }
+        }
+        cx.notify();
+    }
+
     /// The `ix`` is the index of the panel to resize,
     /// and the `size` is the new size for the panel.
+    ///
+    /// The space is taken from (or given to) the panels after it, within their min and max sizes.
     fn resize_panels(&mut self, ix: usize, size: Pixels, cx: &mut ViewContext<Self>) {
-        let mut ix = ix;
         // Only resize the left panels.
         if ix >= self.panels.len() - 1 {
             return;
         }
-        let size = size.floor();
         let container_size = self.bounds.size.along(self.axis);
 
         self.sync_real_panel_sizes(cx);
 
-        let mut changed = size - self.sizes[ix];
-        let is_expand = changed > px(0.);
+        let limits = self.panel_size_ranges(cx);
+        let new_sizes = resize_sizes(&self.sizes, &limits, ix, size, container_size);
+        self.apply_sizes(new_sizes, cx);
+    }
 
-        let main_ix = ix;
-        let mut new_sizes = self.sizes.clone();
+    /// Reset the panels to the initial sizes, e.g.: double-click on the resize handle.
+    ///
+    /// The panels without an initial size share the rest space evenly, the collapsed panels are kept.
+    pub fn reset_sizes(&mut self, cx: &mut ViewContext<Self>) {
+        let container_size = self.bounds.size.along(self.axis);
+        if container_size <= px(0.) {
+            // Not laid out yet, let the flex layout size the panels.
+            for (ix, panel) in self.panels.iter().enumerate() {
+                panel.update(cx, |panel, cx| {
+                    panel.size = None;
+                    panel.ratio = None;
+                    cx.notify();
+                });
+                self.sizes[ix] = panel.read(cx).initial_size.unwrap_or_default();
+            }
+            cx.notify();
+            return;
+        }
 
-        if is_expand {
-            new_sizes[ix] = size;
+        let initial_sizes: Vec<_> = self
+            .panels
+            .iter()
+            .map(|panel| panel.read(cx).initial_size)
+            .collect();
+        let limits = self.panel_size_ranges(cx);
+        let sizes = reset_panel_sizes(&initial_sizes, &limits, container_size);
+        self.apply_sizes(sizes, cx);
+    }
+}
 
-            // Now to expand logic is correct.
-            while changed > px(0.) && ix < self.panels.len() - 1 {
-                ix += 1;
-                let available_size = (new_sizes[ix] - PANEL_MIN_SIZE).max(px(0.));
-                let to_reduce = changed.min(available_size);
-                new_sizes[ix] -= to_reduce;
-                changed -= to_reduce;
-            }
+/// Returns the sizes after resizing the panel at `ix` to `size`,
+/// the change is taken from (or given to) the panels after it, within their `(min, max)` limits.
+fn resize_sizes(
+    sizes: &[Pixels],
+    limits: &[(Pixels, Pixels)],
+    ix: usize,
+    size: Pixels,
+    container_size: Pixels,
+) -> Vec<Pixels> {
+    let (min_size, max_size) = limits[ix];
+    let size = size.floor().max(min_size).min(max_size);
+    let changed = size - sizes[ix];
+    let mut new_sizes = sizes.to_vec();
+
+    let mut remaining = changed.abs();
+    for next_ix in ix + 1..sizes.len() {
+        if remaining <= px(0.) {
+            break;
+        }
+
+        let (next_min, next_max) = limits[next_ix];
+        let available_size = if changed > px(0.) {
+            new_sizes[next_ix] - next_min
         } else {
-            let new_size = size.max(PANEL_MIN_SIZE);
-            new_sizes[ix] = new_size;
-            changed = size - PANEL_MIN_SIZE;
-            new_sizes[ix + 1] += self.sizes[ix] - new_size;
-
-            while changed < px(0.) && ix > 0 {
-                ix -= 1;
-                let available_size = self.sizes[ix] - PANEL_MIN_SIZE;
-                let to_increase = (changed).min(available_size);
-                new_sizes[ix] += to_increase;
-                changed += to_increase;
-            }
+            next_max - new_sizes[next_ix]
+        }
+        .max(px(0.));
+        let delta = remaining.min(available_size);
+        if changed > px(0.) {
+            new_sizes[next_ix] -= delta;
+        } else {
+            new_sizes[next_ix] += delta;
         }
+        remaining -= delta;
+    }
+
+    // Only apply the part of the change that the next panels can take.
+    let applied = changed.abs() - remaining;
+    if changed > px(0.) {
+        new_sizes[ix] += applied;
+    } else {
+        new_sizes[ix] -= applied;
+    }
+
+    // If total size exceeds container size, adjust the main panel
+    let total_size: Pixels = new_sizes.iter().map(|s| s.0).sum::<f32>().into();
+    if total_size > container_size {
+        let overflow = total_size - container_size;
+        new_sizes[ix] = (new_sizes[ix] - overflow).max(min_size);
+    }
+
+    new_sizes
+}
 
-        // If total size exceeds container size, adjust the main panel
-        let total_size: Pixels = new_sizes.iter().map(|s| s.0).sum::<f32>().into();
-        if total_size > container_size {
-            let overflow = total_size - container_size;
-            new_sizes[main_ix] = (new_sizes[main_ix] - overflow).max(PANEL_MIN_SIZE);
+/// Returns the sizes to reset the panels to, the panels with an initial size keep it,
+/// and the others share the rest of the `container_size` evenly, within their `(min, max)` limits.
+fn reset_panel_sizes(
+    initial_sizes: &[Option<Pixels>],
+    limits: &[(Pixels, Pixels)],
+    container_size: Pixels,
+) -> Vec<Pixels> {
+    let clamp = |ix: usize, size: Pixels| size.max(limits[ix].0).min(limits[ix].1);
+
+    let mut sizes: Vec<Option<Pixels>> = initial_sizes
+        .iter()
+        .enumerate()
+        .map(|(ix, size)| size.map(|size| clamp(ix, size)))
+        .collect();
+
+    // The panels hit their limits by the even share keep the limit size, and the rest is shared again.
+    loop {
+        let flex_ixs: Vec<usize> = (0..sizes.len()).filter(|ix| sizes[*ix].is_none()).collect();
+        if flex_ixs.is_empty() {
+            break;
         }
 
-        self.sizes = new_sizes;
-        for (i, panel) in self.panels.iter().enumerate() {
-            let size = self.sizes[i];
-            if size > px(0.) {
-                panel.update(cx, |this, _| this.size = Some(size));
+        let used: f32 = sizes.iter().flatten().map(|size| size.0).sum();
+        let share = px(((container_size.0 - used) / flex_ixs.len() as f32).max(0.));
+        let limited: Vec<usize> = flex_ixs
+            .iter()
+            .copied()
+            .filter(|ix| clamp(*ix, share) != share)
+            .collect();
+
+        if limited.is_empty() {
+            for ix in flex_ixs {
+                sizes[ix] = Some(share);
             }
+            break;
+        }
+        for ix in limited {
+            sizes[ix] = Some(clamp(ix, share));
         }
     }
+
+    sizes.into_iter().map(Option::unwrap_or_default).collect()
 }
 
+impl EventEmitter<ResizablePanelEvent> for ResizablePanelGroup {}
+
 impl Render for ResizablePanelGroup {
     fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
         let view = cx.view().clone();
@@ -269,7 +512,7 @@ impl Render for ResizablePanelGroup {
         container
             .size_full()
             .children(self.panels.iter().enumerate().map(|(ix, panel)| {
-                if ix > 0 {
+                if ix > 0 && !panel.read(cx).collapsed {
                     let handle = self.render_resize_handle(ix - 1, cx);
                     panel.update(cx, |view, _| {
                         view.resize_handle = Some(handle.into_any_element())
@@ -280,7 +523,12 @@ impl Render for ResizablePanelGroup {
             }))
             .child({
                 canvas(
-                    move |bounds, cx| view.update(cx, |r, _| r.bounds = bounds),
+                    move |bounds, cx| {
+                        view.update(cx, |r, cx| {
+                            r.bounds = bounds;
+                            r.sync_ratios(cx);
+                        })
+                    },
                     |_, _, _| {},
                 )
                 .absolute()
@@ -299,6 +547,16 @@ pub struct ResizablePanel {
     initial_size: Option<Pixels>,
     /// size is the size that the panel has when it is resized or ajusted by flex layout.
     size: Option<Pixels>,
+    min_size: Pixels,
+    max_size: Option<Pixels>,
+    collapsed: bool,
+    /// The size before collapsed, used to restore the size when expanding.
+    expanded_size: Option<Pixels>,
+    /// The ratio of the group size in the proportional mode, see [`ResizablePanelGroup::proportional`].
+    ratio: Option<f32>,
+    /// The (from, to) size of the collapse animation.
+    animation: Option<(Pixels, Pixels)>,
+    animation_ix: usize,
     axis: Axis,
     content_builder: Option<Rc<dyn Fn(&mut WindowContext) -> AnyElement>>,
     content_view: Option<AnyView>,
@@ -313,6 +571,13 @@ impl ResizablePanel {
             group: None,
             initial_size: None,
             size: None,
+            min_size: PANEL_MIN_SIZE,
+            max_size: None,
+            collapsed: false,
+            expanded_size: None,
+            ratio: None,
+            animation: None,
+            animation_ix: 0,
             axis: Axis::Horizontal,
             content_builder: None,
             content_view: None,
@@ -340,6 +605,56 @@ impl ResizablePanel {
         self
     }
 
+    /// Set the min size of the panel when resizing, default is 100px.
+    pub fn min_size(mut self, size: Pixels) -> Self {
+        self.min_size = size;
+        self
+    }
+
+    /// Set the max size of the panel when resizing, default is no limit.
+    pub fn max_size(mut self, size: Pixels) -> Self {
+        self.max_size = Some(size);
+        self
+    }
+
+    fn set_collapsed(&mut self, collapsed: bool, cx: &mut ViewContext<Self>) {
+        if self.collapsed == collapsed {
+            return;
+        }
+
+        let current_size = self.size.or(self.initial_size).unwrap_or_default();
+        let (from, to) = if collapsed {
+            self.expanded_size = Some(current_size);
+            (current_size, px(0.))
+        } else {
+            let size = self
+                .expanded_size
+                .take()
+                .or(self.initial_size)
+                .unwrap_or(PANEL_MIN_SIZE);
+            (px(0.), size)
+        };
+
+        self.collapsed = collapsed;
+        self.size = Some(to);
+        self.animation = Some((from, to));
+        self.animation_ix += 1;
+        cx.notify();
+
+        // Clear the animation when it's done, so the size can be changed by resizing.
+        let animation_ix = self.animation_ix;
+        cx.spawn(|view, mut cx| async move {
+            Timer::after(COLLAPSE_DURATION).await;
+            let _ = view.update(&mut cx, |view, cx| {
+                if view.animation_ix == animation_ix {
+                    view.animation = None;
+                    cx.notify();
+                }
+            });
+        })
+        .detach();
+    }
+
     /// Save the real panel size, and update group sizes
     fn update_size(&mut self, bounds: Bounds<Pixels>, cx: &mut ViewContext<Self>) {
         let new_size = bounds.size.along(self.axis);
@@ -367,15 +682,27 @@ impl FluentBuilder for ResizablePanel {}
 impl Render for ResizablePanel {
     fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
         let view = cx.view().clone();
+        let min_size = if self.collapsed {
+            px(0.)
+        } else {
+            self.min_size
+        };
 
-        div()
+        let panel = div()
             .flex()
             .flex_grow()
             .size_full()
             .relative()
+            .when(self.collapsed, |this| this.flex_none().overflow_hidden())
             .when(self.initial_size.is_none(), |this| this.flex_shrink())
-            .when(self.axis.is_vertical(), |this| this.min_h(PANEL_MIN_SIZE))
-            .when(self.axis.is_horizontal(), |this| this.min_w(PANEL_MIN_SIZE))
+            .when(self.axis.is_vertical(), |this| {
+                this.min_h(min_size)
+                    .when_some(self.max_size, |this, size| this.max_h(size))
+            })
+            .when(self.axis.is_horizontal(), |this| {
+                this.min_w(min_size)
+                    .when_some(self.max_size, |this, size| this.max_w(size))
+            })
             .when_some(self.initial_size, |this, size| {
                 // The `self.size` is None, that mean the initial size for the panel, so we need set flex_shrink_0
                 // To let it keep the initial size.
@@ -383,6 +710,12 @@ impl Render for ResizablePanel {
                     .flex_basis(size)
             })
             .when_some(self.size, |this, size| this.flex_basis(size))
+            .when_some(self.ratio.filter(|_| !self.collapsed), |this, ratio| {
+                // Share the group size by the ratio, so the panel is rescaled with the group.
+                let mut this = this.flex_basis(px(0.)).flex_shrink();
+                this.style().flex_grow = Some(ratio);
+                this
+            })
             .child({
                 canvas(
                     move |bounds, cx| view.update(cx, |r, cx| r.update_size(bounds, cx)),
@@ -393,7 +726,18 @@ impl Render for ResizablePanel {
             })
             .when_some(self.content_builder.clone(), |this, c| this.child(c(cx)))
             .when_some(self.content_view.clone(), |this, c| this.child(c))
-            .when_some(self.resize_handle.take(), |this, c| this.child(c))
+            .when_some(self.resize_handle.take(), |this, c| this.child(c));
+
+        match self.animation {
+            Some((from, to)) => panel
+                .with_animation(
+                    ElementId::NamedInteger("collapse".into(), self.animation_ix),
+                    Animation::new(COLLAPSE_DURATION).with_easing(cubic_bezier(0.4, 0., 0.2, 1.)),
+                    move |this, delta| this.flex_none().flex_basis(from + (to - from) * delta),
+                )
+                .into_any_element(),
+            None => panel.into_any_element(),
+        }
     }
 }
 
@@ -477,9 +821,79 @@ impl Element for ResizePanelGroupElement {
             let view = self.view.clone();
             move |_: &MouseUpEvent, phase, cx| {
                 if phase.bubble() {
-                    view.update(cx, |view, _| view.resizing_panel_ix = None);
+                    view.update(cx, |view, cx| {
+                        if view.resizing_panel_ix.take().is_some() {
+                            cx.emit(ResizablePanelEvent::Resized);
+                        }
+                    });
                 }
             }
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use gpui::{px, Pixels};
+
+    use super::{reset_panel_sizes, resize_sizes};
+
+    const NO_LIMIT: (Pixels, Pixels) = (px(0.), px(f32::MAX));
+
+    #[test]
+    fn test_reset_panel_sizes() {
+        // The panels without an initial size share the rest space evenly.
+        let sizes = reset_panel_sizes(
+            &[Some(px(200.)), None, None],
+            &[NO_LIMIT, NO_LIMIT, NO_LIMIT],
+            px(1000.),
+        );
+        assert_eq!(sizes, vec![px(200.), px(400.), px(400.)]);
+
+        let sizes = reset_panel_sizes(&[None, None, None, None], &[NO_LIMIT; 4], px(800.));
+        assert_eq!(sizes, vec![px(200.); 4]);
+
+        // The share is limited by the max size, the rest goes to the other panels.
+        let sizes = reset_panel_sizes(
+            &[None, None, None],
+            &[(px(0.), px(100.)), NO_LIMIT, NO_LIMIT],
+            px(900.),
+        );
+        assert_eq!(sizes, vec![px(100.), px(400.), px(400.)]);
+
+        // The collapsed panels keep 0.
+        let sizes = reset_panel_sizes(
+            &[None, Some(px(300.)), None],
+            &[NO_LIMIT, (px(0.), px(0.)), NO_LIMIT],
+            px(600.),
+        );
+        assert_eq!(sizes, vec![px(300.), px(0.), px(300.)]);
+
+        // No space left, the min sizes are kept.
+        let sizes = reset_panel_sizes(
+            &[Some(px(500.)), None],
+            &[NO_LIMIT, (px(100.), px(f32::MAX))],
+            px(400.),
+        );
+        assert_eq!(sizes, vec![px(500.), px(100.)]);
+    }
+
+    #[test]
+    fn test_resize_sizes() {
+        let limits = [(px(100.), px(f32::MAX)); 3];
+        let sizes = [px(300.), px(300.), px(300.)];
+
+        // Expand takes the space from the next panels, within their min sizes.
+        let new_sizes = resize_sizes(&sizes, &limits, 0, px(650.), px(900.));
+        assert_eq!(new_sizes, vec![px(650.), px(100.), px(150.)]);
+
+        // Shrink gives the space to the next panel, and stops at the min size.
+        let new_sizes = resize_sizes(&sizes, &limits, 0, px(50.), px(900.));
+        assert_eq!(new_sizes, vec![px(100.), px(500.), px(300.)]);
+
+        // The max size of the next panel limits the shrink.
+        let limits = [limits[0], (px(100.), px(350.)), (px(100.), px(350.))];
+        let new_sizes = resize_sizes(&sizes, &limits, 0, px(100.), px(900.));
+        assert_eq!(new_sizes, vec![px(200.), px(350.), px(350.)]);
+    }
+}
@@ -1,17 +1,25 @@
 use gpui::{
-    div, AnyView, FocusHandle, InteractiveElement, ParentElement as _, Render, Styled, View,
+    div, prelude::FluentBuilder as _, AnyView, ClickEvent, ElementId, FocusHandle,
+    InteractiveElement, IntoElement, ParentElement as _, Pixels, Render, Styled, Task, View,
     ViewContext, VisualContext as _, WindowContext,
 };
 use std::{
+    any::TypeId,
+    cell::RefCell,
     ops::{Deref, DerefMut},
     rc::Rc,
+    time::{Duration, Instant},
 };
 
 use crate::{
+    accessibility::AccessibleNode,
+    animation::reduce_motion,
     drawer::Drawer,
-    modal::Modal,
-    notification::{Notification, NotificationList},
-    theme::ActiveTheme,
+    focusable::{FocusNext, FocusPrev},
+    modal::{Escape, Modal},
+    notification::{Notification, NotificationList, NotificationRecord},
+    perf::{PerfOverlay, PerfStats, TogglePerfOverlay},
+    theme::{ActiveTheme, Theme},
 };
 
 /// Extension trait for [`WindowContext`] and [`ViewContext`] to add drawer functionality.
@@ -27,7 +35,10 @@ pub trait ContextModal: Sized {
     /// Closes the active Drawer.
     fn close_drawer(&mut self);
 
-    /// Opens a Modal.
+    /// Opens a Modal, it will be stacked on top of the current active Modal.
+    ///
+    /// The first tab stop in the Modal is focused after it's rendered, unless the focus is already
+    /// inside it, and the Tab / Shift-Tab focus traversal is kept inside the topmost Modal.
     fn open_modal<F>(&mut self, build: F)
     where
         F: Fn(Modal, &mut WindowContext) -> Modal + 'static;
@@ -35,14 +46,26 @@ pub trait ContextModal: Sized {
     /// Return true, if there is an active Modal.
     fn has_active_modal(&self) -> bool;
 
-    /// Closes the active Modal.
+    /// Closes the topmost Modal, and focus back to the element focused before it opened.
     fn close_modal(&mut self);
 
     /// Pushes a notification to the notification list.
     fn push_notification(&mut self, note: impl Into<Notification>);
+    /// Dismisses the notification with the id set by [`Notification::id`].
+    fn remove_notification<T: Sized + 'static>(&mut self);
+    /// Dismisses the notification with the id set by [`Notification::id1`].
+    fn remove_notification1<T: Sized + 'static>(&mut self, key: impl Into<ElementId>);
     fn clear_notifications(&mut self);
     /// Returns number of notifications.
     fn notifications(&self) -> Rc<Vec<View<Notification>>>;
+
+    /// Returns the history of the pushed notifications, the latest is at the end.
+    fn notification_history(&self) -> Rc<Vec<NotificationRecord>>;
+    /// Returns the number of the unread notifications in the history.
+    fn unread_notifications_count(&self) -> usize;
+    /// Mark all the notifications in the history as read.
+    fn mark_notifications_read(&mut self);
+    fn clear_notification_history(&mut self);
 }
 
 impl<'a> ContextModal for WindowContext<'a> {
@@ -53,6 +76,7 @@ impl<'a> ContextModal for WindowContext<'a> {
         Root::update(self, move |root, cx| {
             root.previous_focus_handle = cx.focused();
             root.active_drawer = Some(Rc::new(build));
+            root.drawer_size = None;
             cx.notify();
         })
     }
@@ -62,11 +86,7 @@ impl<'a> ContextModal for WindowContext<'a> {
     }
 
     fn close_drawer(&mut self) {
-        Root::update(self, |root, cx| {
-            root.active_drawer = None;
-            root.focus_back(cx);
-            cx.notify();
-        })
+        Root::update(self, |root, cx| root.close_drawer(cx))
     }
 
     fn open_modal<F>(&mut self, build: F)
@@ -74,22 +94,25 @@ impl<'a> ContextModal for WindowContext<'a> {
         F: Fn(Modal, &mut WindowContext) -> Modal + 'static,
     {
         Root::update(self, move |root, cx| {
-            root.previous_focus_handle = cx.focused();
-            root.active_modal = Some(Rc::new(build));
+            root.next_modal_id += 1;
+            root.active_modals.push(ActiveModal {
+                id: root.next_modal_id,
+                focus_handle: cx.focus_handle(),
+                previous_focus_handle: cx.focused(),
+                builder: Rc::new(build),
+                rendered: Rc::default(),
+            });
+            root.pending_modal_focus = true;
             cx.notify();
         })
     }
 
     fn has_active_modal(&self) -> bool {
-        Root::read(&self).active_modal.is_some()
+        !Root::read(&self).active_modals.is_empty()
     }
 
     fn close_modal(&mut self) {
-        Root::update(self, |root, cx| {
-            root.active_modal = None;
-            root.focus_back(cx);
-            cx.notify();
-        })
+        Root::update(self, |root, cx| root.pop_modal(cx))
     }
 
     fn push_notification(&mut self, note: impl Into<Notification>) {
@@ -100,6 +123,20 @@ impl<'a> ContextModal for WindowContext<'a> {
         })
     }
 
+    fn remove_notification<T: Sized + 'static>(&mut self) {
+        let id = TypeId::of::<T>().into();
+        Root::update(self, move |root, cx| {
+            root.notification.update(cx, |view, cx| view.remove(id, cx));
+        })
+    }
+
+    fn remove_notification1<T: Sized + 'static>(&mut self, key: impl Into<ElementId>) {
+        let id = (TypeId::of::<T>(), key.into()).into();
+        Root::update(self, move |root, cx| {
+            root.notification.update(cx, |view, cx| view.remove(id, cx));
+        })
+    }
+
     fn clear_notifications(&mut self) {
         Root::update(self, move |root, cx| {
             root.notification.update(cx, |view, cx| view.clear(cx));
@@ -110,6 +147,30 @@ impl<'a> ContextModal for WindowContext<'a> {
     fn notifications(&self) -> Rc<Vec<View<Notification>>> {
         Rc::new(Root::read(&self).notification.read(&self).notifications())
     }
+
+    fn notification_history(&self) -> Rc<Vec<NotificationRecord>> {
+        Rc::new(Root::read(&self).notification.read(&self).history())
+    }
+
+    fn unread_notifications_count(&self) -> usize {
+        Root::read(&self).notification.read(&self).unread_count()
+    }
+
+    fn mark_notifications_read(&mut self) {
+        Root::update(self, move |root, cx| {
+            root.notification
+                .update(cx, |view, cx| view.mark_all_read(cx));
+            cx.notify();
+        })
+    }
+
+    fn clear_notification_history(&mut self) {
+        Root::update(self, move |root, cx| {
+            root.notification
+                .update(cx, |view, cx| view.clear_history(cx));
+            cx.notify();
+        })
+    }
 }
 impl<'a, V> ContextModal for ViewContext<'a, V> {
     fn open_drawer<F>(&mut self, build: F)
@@ -146,6 +207,14 @@ impl<'a, V> ContextModal for ViewContext<'a, V> {
         self.deref_mut().push_notification(note)
     }
 
+    fn remove_notification<T: Sized + 'static>(&mut self) {
+        self.deref_mut().remove_notification::<T>()
+    }
+
+    fn remove_notification1<T: Sized + 'static>(&mut self, key: impl Into<ElementId>) {
+        self.deref_mut().remove_notification1::<T>(key)
+    }
+
     fn clear_notifications(&mut self) {
         self.deref_mut().clear_notifications()
     }
@@ -153,6 +222,43 @@ impl<'a, V> ContextModal for ViewContext<'a, V> {
     fn notifications(&self) -> Rc<Vec<View<Notification>>> {
         self.deref().notifications()
     }
+
+    fn notification_history(&self) -> Rc<Vec<NotificationRecord>> {
+        self.deref().notification_history()
+    }
+
+    fn unread_notifications_count(&self) -> usize {
+        self.deref().unread_notifications_count()
+    }
+
+    fn mark_notifications_read(&mut self) {
+        self.deref_mut().mark_notifications_read()
+    }
+
+    fn clear_notification_history(&mut self) {
+        self.deref_mut().clear_notification_history()
+    }
+}
+
+type DrawerBuilder = Rc<dyn Fn(Drawer, &mut WindowContext) -> Drawer + 'static>;
+
+#[derive(Clone)]
+struct ActiveModal {
+    /// The unique id of the modal, to find it when the closing animation is finished.
+    id: usize,
+    /// Tracked by the modal element, to find the tab stops inside it.
+    focus_handle: FocusHandle,
+    /// The focus handle before the modal opened, to focus back when it is closed.
+    previous_focus_handle: Option<FocusHandle>,
+    builder: Rc<dyn Fn(Modal, &mut WindowContext) -> Modal + 'static>,
+    /// Kept from the last render, to close the modal without building it again, `None` if not rendered yet.
+    rendered: Rc<RefCell<Option<RenderedModal>>>,
+}
+
+/// The close callback and the animation duration of a rendered modal.
+struct RenderedModal {
+    on_close: Rc<dyn Fn(&ClickEvent, &mut WindowContext) + 'static>,
+    duration: Duration,
 }
 
 /// Root is a view for the App window for as the top level view (Must be the first view in the window).
@@ -162,19 +268,50 @@ pub struct Root {
     /// Used to store the focus handle of the previus revious view.
     /// When the Modal, Drawer closes, we will focus back to the previous view.
     previous_focus_handle: Option<FocusHandle>,
-    pub active_drawer: Option<Rc<dyn Fn(Drawer, &mut WindowContext) -> Drawer + 'static>>,
-    pub active_modal: Option<Rc<dyn Fn(Modal, &mut WindowContext) -> Modal + 'static>>,
+    pub active_drawer: Option<DrawerBuilder>,
+    /// The closed drawer that is still rendered for the closing animation.
+    closing_drawer: Option<DrawerBuilder>,
+    _closing_drawer_task: Option<Task<()>>,
+    /// The size of the active drawer after resized by the user.
+    pub(crate) drawer_size: Option<Pixels>,
+    /// The stack of the opened modals, the last one is the topmost.
+    active_modals: Vec<ActiveModal>,
+    /// The closed modals that are still rendered for the closing animation.
+    closing_modals: Vec<ActiveModal>,
+    next_modal_id: usize,
+    /// Focus the topmost modal after the next render, it's set when a modal is opened.
+    pending_modal_focus: bool,
+    /// The focus handles for Tab / Shift-Tab traversal in the render order, collected on every render.
+    pub(crate) tab_stops: Vec<FocusHandle>,
+    /// The accessibility metadata of the components in the render order, collected on every render.
+    pub(crate) accessible_nodes: Vec<AccessibleNode>,
     pub notification: View<NotificationList>,
+    /// The render statistics of the window, `Some` if the performance overlay is shown.
+    pub(crate) perf_stats: Option<Rc<RefCell<PerfStats>>>,
+    _perf_task: Option<Task<()>>,
     child: AnyView,
 }
 
 impl Root {
     pub fn new(child: AnyView, cx: &mut ViewContext<Self>) -> Self {
+        cx.on_release(|_, window, cx| Theme::remove_window_theme(window, cx))
+            .detach();
+
         Self {
             previous_focus_handle: None,
             active_drawer: None,
-            active_modal: None,
+            closing_drawer: None,
+            _closing_drawer_task: None,
+            drawer_size: None,
+            active_modals: Vec::new(),
+            closing_modals: Vec::new(),
+            next_modal_id: 0,
+            pending_modal_focus: false,
+            tab_stops: Vec::new(),
+            accessible_nodes: Vec::new(),
             notification: cx.new_view(NotificationList::new),
+            perf_stats: None,
+            _perf_task: None,
             child,
         }
     }
@@ -202,6 +339,250 @@ impl Root {
         root.read(cx)
     }
 
+    /// Returns the accessibility metadata of the components rendered in the last frame.
+    pub fn accessible_nodes(&self) -> &[AccessibleNode] {
+        &self.accessible_nodes
+    }
+
+    /// Returns the child view of the Root.
+    pub fn view(&self) -> &AnyView {
+        &self.child
+    }
+
+    /// Render the active drawer, and the closed one during its closing animation.
+    ///
+    /// This should be added to the window view before the modal layer, it's hidden when there is an active modal.
+    pub fn render_drawer_layer(cx: &mut WindowContext) -> Option<impl IntoElement> {
+        let root = Root::read(cx);
+        if !root.active_modals.is_empty() {
+            return None;
+        }
+        let active_drawer = root.active_drawer.clone();
+        let closing_drawer = root.closing_drawer.clone();
+        if active_drawer.is_none() && closing_drawer.is_none() {
+            return None;
+        }
+
+        Some(
+            div()
+                .when_some(closing_drawer, |this, builder| {
+                    let mut drawer = Drawer::new(cx);
+                    drawer.closing = true;
+                    this.child(div().id("closing-drawer").child(builder(drawer, cx)))
+                })
+                .when_some(active_drawer, |this, builder| {
+                    this.child(builder(Drawer::new(cx), cx))
+                }),
+        )
+    }
+
+    /// Render the opened modals, the topmost is rendered at the last.
+    ///
+    /// The closed modals are rendered on the top during the closing animation.
+    ///
+    /// This should be added to the window view as the last child, to be on top of the other elements.
+    pub fn render_modal_layer(cx: &mut WindowContext) -> Option<impl IntoElement> {
+        let root = Root::read(cx);
+        let active_modals = root.active_modals.clone();
+        let closing_modals = root.closing_modals.clone();
+        if active_modals.is_empty() && closing_modals.is_empty() {
+            return None;
+        }
+
+        Some(
+            div()
+                .children(
+                    active_modals
+                        .into_iter()
+                        .enumerate()
+                        .map(|(ix, active_modal)| {
+                            let mut modal = Modal::new(cx);
+                            modal.layer_ix = ix;
+                            modal.focus_handle = active_modal.focus_handle.clone();
+                            let modal = (active_modal.builder)(modal, cx);
+                            *active_modal.rendered.borrow_mut() = Some(RenderedModal {
+                                on_close: modal.on_close.clone(),
+                                duration: modal.duration,
+                            });
+                            modal
+                        }),
+                )
+                .children(closing_modals.into_iter().map(|closing_modal| {
+                    let mut modal = Modal::new(cx);
+                    modal.closing = true;
+                    modal.focus_handle = closing_modal.focus_handle.clone();
+                    div()
+                        .id(("closing-modal", closing_modal.id))
+                        .child((closing_modal.builder)(modal, cx))
+                })),
+        )
+    }
+
+    /// Close the topmost modal by Escape, the `on_close` callback of the modal will be called.
+    fn on_action_escape(&mut self, _: &Escape, cx: &mut ViewContext<Self>) {
+        let Some(active_modal) = self.active_modals.last().cloned() else {
+            cx.propagate();
+            return;
+        };
+
+        let on_close = active_modal
+            .rendered
+            .borrow()
+            .as_ref()
+            .map(|rendered| rendered.on_close.clone());
+        self.pop_modal(cx);
+        // Deferred, the callback may update the Root, e.g.: open another modal.
+        if let Some(on_close) = on_close {
+            WindowContext::defer(cx, move |cx| on_close(&ClickEvent::default(), cx));
+        }
+    }
+
+    fn on_action_focus_next(&mut self, _: &FocusNext, cx: &mut ViewContext<Self>) {
+        self.cycle_tab_stop(true, cx);
+    }
+
+    fn on_action_focus_prev(&mut self, _: &FocusPrev, cx: &mut ViewContext<Self>) {
+        self.cycle_tab_stop(false, cx);
+    }
+
+    /// Returns the tab stops inside the topmost modal, or all the tab stops if there is no modal.
+    fn active_tab_stops(&self, cx: &WindowContext) -> Vec<FocusHandle> {
+        match self.active_modals.last() {
+            Some(modal) => self
+                .tab_stops
+                .iter()
+                .filter(|handle| modal.focus_handle.contains(handle, cx))
+                .cloned()
+                .collect(),
+            None => self.tab_stops.clone(),
+        }
+    }
+
+    /// Focus the first tab stop in the topmost modal, or the modal itself if there is no tab stop.
+    fn focus_modal(&mut self, cx: &mut ViewContext<Self>) {
+        let Some(modal) = self.active_modals.last() else {
+            return;
+        };
+        if modal.focus_handle.contains_focused(cx) {
+            return;
+        }
+
+        let handle = self
+            .active_tab_stops(cx)
+            .into_iter()
+            .next()
+            .unwrap_or_else(|| modal.focus_handle.clone());
+        cx.focus(&handle);
+    }
+
+    /// Focus the next (or previous) tab stop of the focused one.
+    ///
+    /// When a modal is opened, only the tab stops inside the topmost modal are cycled.
+    fn cycle_tab_stop(&mut self, is_next: bool, cx: &mut ViewContext<Self>) {
+        let tab_stops = self.active_tab_stops(cx);
+        let len = tab_stops.len();
+        if len == 0 {
+            // Keep the focus in the modal, instead of moving to the elements behind the overlay.
+            match self.active_modals.last() {
+                Some(modal) => cx.focus(&modal.focus_handle),
+                None => cx.propagate(),
+            }
+            return;
+        }
+
+        // The inner tab stops are rendered after the outer, so find the last one contains the focus.
+        let current = tab_stops
+            .iter()
+            .rposition(|handle| handle.contains_focused(cx));
+        let ix = match (current, is_next) {
+            (Some(ix), true) => (ix + 1) % len,
+            (Some(ix), false) => (ix + len - 1) % len,
+            (None, true) => 0,
+            (None, false) => len - 1,
+        };
+
+        cx.focus(&tab_stops[ix]);
+    }
+
+    /// Show or hide the performance overlay, the statistics are reset when it's shown.
+    pub fn toggle_perf_overlay(&mut self, cx: &mut ViewContext<Self>) {
+        if self.perf_stats.take().is_some() {
+            self._perf_task = None;
+        } else {
+            self.perf_stats = Some(Rc::default());
+            // Refresh the overlay even if nothing else is changed, it's also counted in the FPS.
+            self._perf_task = Some(cx.spawn(|this, mut cx| async move {
+                loop {
+                    cx.background_executor()
+                        .timer(Duration::from_millis(500))
+                        .await;
+                    if this.update(&mut cx, |_, cx| cx.notify()).is_err() {
+                        return;
+                    }
+                }
+            }));
+        }
+        cx.notify();
+    }
+
+    fn on_action_toggle_perf_overlay(&mut self, _: &TogglePerfOverlay, cx: &mut ViewContext<Self>) {
+        self.toggle_perf_overlay(cx);
+    }
+
+    fn pop_modal(&mut self, cx: &mut ViewContext<Self>) {
+        if let Some(modal) = self.active_modals.pop() {
+            if let Some(handle) = &modal.previous_focus_handle {
+                cx.focus(handle);
+            }
+
+            // The modal has not been shown if it's not rendered, there is nothing to animate.
+            let duration = modal
+                .rendered
+                .borrow()
+                .as_ref()
+                .map(|rendered| rendered.duration);
+            if let Some(duration) = duration.filter(|_| !reduce_motion(cx)) {
+                let id = modal.id;
+                self.closing_modals.push(modal);
+                cx.spawn(|this, mut cx| async move {
+                    cx.background_executor().timer(duration).await;
+                    let _ = this.update(&mut cx, |this, cx| {
+                        this.closing_modals.retain(|modal| modal.id != id);
+                        cx.notify();
+                    });
+                })
+                .detach();
+            }
+        }
+        cx.notify();
+    }
+
+    fn close_drawer(&mut self, cx: &mut ViewContext<Self>) {
+        let Some(builder) = self.active_drawer.take() else {
+            return;
+        };
+        self.focus_back(cx);
+
+        if reduce_motion(cx) {
+            self.drawer_size = None;
+        } else {
+            // Keep the size of the drawer until the closing animation is finished.
+            let duration = builder(Drawer::new(cx), cx).duration;
+            self.closing_drawer = Some(builder);
+            self._closing_drawer_task = Some(cx.spawn(|this, mut cx| async move {
+                cx.background_executor().timer(duration).await;
+                let _ = this.update(&mut cx, |this, cx| {
+                    this.closing_drawer = None;
+                    if this.active_drawer.is_none() {
+                        this.drawer_size = None;
+                    }
+                    cx.notify();
+                });
+            }));
+        }
+        cx.notify();
+    }
+
     fn focus_back(&mut self, cx: &mut WindowContext) {
         if let Some(handle) = self.previous_focus_handle.take() {
             cx.focus(&handle);
@@ -211,10 +592,30 @@ impl Root {
 
 impl Render for Root {
     fn render(&mut self, cx: &mut gpui::ViewContext<Self>) -> impl gpui::IntoElement {
+        // The tab stops will be registered again by the children.
+        self.tab_stops.clear();
+        self.accessible_nodes.clear();
+        if std::mem::take(&mut self.pending_modal_focus) {
+            // The tab stops of the modal are registered after this render.
+            cx.defer(|this, cx| this.focus_modal(cx));
+        }
+        if let Some(stats) = &self.perf_stats {
+            stats.borrow_mut().begin_frame(Instant::now());
+        }
+
         div()
             .id("root")
+            .key_context("Root")
+            .on_action(cx.listener(Self::on_action_escape))
+            .on_action(cx.listener(Self::on_action_focus_next))
+            .on_action(cx.listener(Self::on_action_focus_prev))
+            .on_action(cx.listener(Self::on_action_toggle_perf_overlay))
+            .relative()
             .size_full()
-            .text_color(cx.theme().foreground)
+            .text_color(cx.theme().tokens.on_surface)
             .child(self.child.clone())
+            .when_some(self.perf_stats.clone(), |this, stats| {
+                this.child(PerfOverlay::new(stats))
+            })
     }
 }
@@ -1,15 +1,36 @@
-use std::{cell::Cell, rc::Rc};
+use std::{
+    cell::Cell,
+    rc::Rc,
+    time::{Duration, Instant},
+};
 
 use crate::theme::ActiveTheme;
 use gpui::{
     fill, point, px, relative, size, Bounds, ContentMask, Edges, Element, EntityId, Hitbox,
     IntoElement, MouseDownEvent, MouseMoveEvent, MouseUpEvent, PaintQuad, Pixels, Point, Position,
-    ScrollHandle, Style, UniformListScrollHandle,
+    ScrollHandle, ScrollWheelEvent, Style, UniformListScrollHandle,
 };
+use serde::{Deserialize, Serialize};
 
 const MIN_THUMB_SIZE: f32 = 80.;
 const THUMB_RADIUS: Pixels = Pixels(5.0);
 const THUMB_INSET: Pixels = Pixels(2.);
+/// The time to keep the overlay scrollbar visible after the last scroll.
+const FADE_OUT_DELAY: Duration = Duration::from_millis(1200);
+const FADE_OUT_DURATION: Duration = Duration::from_millis(300);
+
+/// When to show the scrollbars, see [`crate::theme::Theme::scrollbar_show`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ScrollbarShow {
+    /// Show when the mouse is over the scroll area.
+    #[default]
+    Hover,
+    /// Overlay mode, show while scrolling and fade out after a while.
+    Scrolling,
+    /// Always show the scrollbars.
+    Always,
+}
 
 pub trait ScrollHandleOffsetable {
     fn offset(&self) -> Point<Pixels>;
@@ -49,6 +70,7 @@ pub struct ScrollbarState {
     dragged_axis: Option<ScrollbarAxis>,
     drag_pos: Point<Pixels>,
     visible: bool,
+    last_scroll_time: Option<Instant>,
 }
 
 impl Default for ScrollbarState {
@@ -58,6 +80,7 @@ impl Default for ScrollbarState {
             dragged_axis: None,
             drag_pos: point(px(0.), px(0.)),
             visible: false,
+            last_scroll_time: None,
         }
     }
 }
@@ -96,6 +119,30 @@ impl ScrollbarState {
         state.visible = visiable;
         state
     }
+
+    fn with_last_scroll_time(&self, time: Option<Instant>) -> Self {
+        let mut state = *self;
+        state.last_scroll_time = time;
+        state
+    }
+
+    /// The opacity of the scrollbar in the [`ScrollbarShow::Scrolling`] mode,
+    /// `1.0` while scrolling and fade out to `0.0` after the [`FADE_OUT_DELAY`].
+    fn scrolling_opacity(&self) -> f32 {
+        if self.hovered_axis.is_some() || self.dragged_axis.is_some() {
+            return 1.;
+        }
+
+        let Some(elapsed) = self.last_scroll_time.map(|time| time.elapsed()) else {
+            return 0.;
+        };
+        if elapsed < FADE_OUT_DELAY {
+            1.
+        } else {
+            let fade = (elapsed - FADE_OUT_DELAY).as_secs_f32() / FADE_OUT_DURATION.as_secs_f32();
+            (1. - fade).max(0.)
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -297,6 +344,31 @@ impl Element for Scrollbar {
         let hitbox_bounds = hitbox.bounds;
         let mut has_both = self.axis.is_both();
 
+        let (visible, opacity) = match cx.theme().scrollbar_show {
+            ScrollbarShow::Hover => (self.state.get().visible, 1.),
+            ScrollbarShow::Always => (true, 1.),
+            ScrollbarShow::Scrolling => {
+                let opacity = self.state.get().scrolling_opacity();
+                // Keep repainting until the scrollbar is faded out.
+                if opacity > 0. && self.state.get().last_scroll_time.is_some() {
+                    cx.request_animation_frame();
+                }
+                (opacity > 0., opacity)
+            }
+        };
+
+        cx.on_mouse_event({
+            let state = self.state.clone();
+            let view_id = self.view_id;
+
+            move |event: &ScrollWheelEvent, phase, cx| {
+                if phase.bubble() && hitbox_bounds.contains(&event.position) {
+                    state.set(state.get().with_last_scroll_time(Some(Instant::now())));
+                    cx.notify(view_id);
+                }
+            }
+        });
+
         cx.with_content_mask(
             Some(ContentMask {
                 bounds: hitbox_bounds,
@@ -363,13 +435,23 @@ impl Element for Scrollbar {
                         },
                     };
 
-                    let thumb_bg = cx.theme().scrollbar_thumb;
+                    let thumb_bg = cx.theme().scrollbar_thumb.opacity(opacity);
                     let state = self.state.clone();
                     let (thumb_bg, bar_bg, bar_border, inset) =
                         if state.get().dragged_axis == Some(axis) {
-                            (thumb_bg, cx.theme().scrollbar, cx.theme().border, px(1.))
+                            (
+                                thumb_bg,
+                                cx.theme().scrollbar,
+                                cx.theme().tokens.outline,
+                                px(1.),
+                            )
                         } else if state.get().hovered_axis == Some(axis) {
-                            (thumb_bg, cx.theme().scrollbar, cx.theme().border, px(1.))
+                            (
+                                thumb_bg,
+                                cx.theme().scrollbar,
+                                cx.theme().tokens.outline,
+                                px(1.),
+                            )
                         } else {
                             (
                                 thumb_bg.opacity(0.3),
@@ -404,7 +486,7 @@ impl Element for Scrollbar {
                         )
                     };
 
-                    if state.get().visible {
+                    if visible {
                         cx.paint_quad(fill(bounds, bar_bg));
 
                         cx.paint_quad(PaintQuad {
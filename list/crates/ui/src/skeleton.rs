@@ -1,21 +1,40 @@
 use std::time::Duration;
 
 use gpui::{
-    bounce, div, ease_in_out, Animation, AnimationExt, Div, IntoElement, ParentElement as _,
-    RenderOnce, Styled,
+    bounce, div, ease_in_out, prelude::FluentBuilder as _, px, relative, Animation, AnimationExt,
+    Div, IntoElement, ParentElement as _, RenderOnce, Styled, WindowContext,
 };
 
-use crate::theme::ActiveTheme;
+use crate::{animation::reduce_motion, h_flex, theme::ActiveTheme, v_flex};
 
+/// The width of the shimmer band, relative to the block.
+const SHIMMER_WIDTH: f32 = 0.4;
+
+/// A placeholder block with a shimmer animation, to show the shape of the content that is loading.
+///
+/// The animation is disabled by [`crate::animation::set_reduce_motion`].
 #[derive(IntoElement)]
 pub struct Skeleton {
     base: Div,
+    circle: bool,
 }
 
 impl Skeleton {
+    /// Create a block in the size of a line of text, use the [`Styled`] methods to change the size.
     pub fn new() -> Self {
         Self {
             base: div().w_full().h_4().rounded_md(),
+            circle: false,
+        }
+    }
+
+    /// Create a circle in the size of an avatar.
+    ///
+    /// The circle pulses instead of the shimmer, the shimmer band can't be clipped to the circle.
+    pub fn avatar() -> Self {
+        Self {
+            base: div().flex_none().size_10().rounded_full(),
+            circle: true,
         }
     }
 }
@@ -27,18 +46,116 @@ impl Styled for Skeleton {
 }
 
 impl RenderOnce for Skeleton {
-    fn render(self, cx: &mut gpui::WindowContext) -> impl IntoElement {
-        div().child(
-            self.base.bg(cx.theme().skeleton).with_animation(
-                "skeleton",
-                Animation::new(Duration::from_secs(2))
-                    .repeat()
-                    .with_easing(bounce(ease_in_out)),
-                move |this, delta| {
-                    let v = 1.0 - delta * 0.5;
-                    this.opacity(v)
-                },
-            ),
-        )
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let animate = !reduce_motion(cx);
+        let base = self.base.bg(cx.theme().skeleton);
+
+        if self.circle {
+            return div()
+                .flex_none()
+                .map(|this| {
+                    if animate {
+                        this.child(
+                            base.with_animation(
+                                "skeleton",
+                                Animation::new(Duration::from_secs(2))
+                                    .repeat()
+                                    .with_easing(bounce(ease_in_out)),
+                                move |this, delta| {
+                                    let v = 1.0 - delta * 0.5;
+                                    this.opacity(v)
+                                },
+                            ),
+                        )
+                    } else {
+                        this.child(base)
+                    }
+                })
+                .into_any_element();
+        }
+
+        base.relative()
+            .overflow_hidden()
+            .when(animate, |this| {
+                this.child(
+                    div()
+                        .absolute()
+                        .top_0()
+                        .bottom_0()
+                        .w(relative(SHIMMER_WIDTH))
+                        .bg(cx.theme().tokens.surface.opacity(0.35))
+                        .with_animation(
+                            "skeleton-shimmer",
+                            Animation::new(Duration::from_secs_f64(1.5))
+                                .repeat()
+                                .with_easing(ease_in_out),
+                            |this, delta| {
+                                this.left(relative(delta * (1. + SHIMMER_WIDTH) - SHIMMER_WIDTH))
+                            },
+                        ),
+                )
+            })
+            .into_any_element()
+    }
+}
+
+/// The placeholder of a paragraph, the last line is shorter.
+#[derive(IntoElement)]
+pub struct SkeletonText {
+    lines: usize,
+}
+
+impl SkeletonText {
+    pub fn new(lines: usize) -> Self {
+        Self { lines }
+    }
+}
+
+impl RenderOnce for SkeletonText {
+    fn render(self, _: &mut WindowContext) -> impl IntoElement {
+        let lines = self.lines.max(1);
+
+        v_flex().w_full().gap_2().children((0..lines).map(|ix| {
+            Skeleton::new().when(lines > 1 && ix == lines - 1, |this| this.w(relative(0.6)))
+        }))
+    }
+}
+
+/// The placeholder of a card, with a media block, an avatar and the text lines.
+#[derive(IntoElement)]
+pub struct SkeletonCard {
+    base: Div,
+}
+
+impl SkeletonCard {
+    pub fn new() -> Self {
+        Self {
+            base: v_flex().w(px(280.)),
+        }
+    }
+}
+
+impl Styled for SkeletonCard {
+    fn style(&mut self) -> &mut gpui::StyleRefinement {
+        self.base.style()
+    }
+}
+
+impl RenderOnce for SkeletonCard {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        self.base
+            .gap_3()
+            .p_3()
+            .border_1()
+            .border_color(cx.theme().tokens.outline)
+            .rounded_lg()
+            .child(Skeleton::new().h(px(120.)))
+            .child(
+                h_flex()
+                    .gap_3()
+                    .items_center()
+                    .child(Skeleton::avatar())
+                    .child(SkeletonText::new(2)),
+            )
     }
 }
@@ -62,12 +62,17 @@ impl Slider {
         self
     }
 
-    /// Set the value of the slider.
+    /// Set the value of the slider, the value is clamped into the range of `min..=max`.
     pub fn set_value(&mut self, value: f32, cx: &mut gpui::ViewContext<Self>) {
-        self.value = value;
+        self.value = value.clamp(self.min, self.max);
         cx.notify();
     }
 
+    /// Return the value of the slider.
+    pub fn value(&self) -> f32 {
+        self.value
+    }
+
     /// Return percentage value of the slider, range of 0.0..1.0
     fn relative_value(&self) -> f32 {
         let step = self.step;
@@ -105,7 +110,8 @@ impl Slider {
             }
         };
 
-        let value = (value / step).round() * step;
+        // Round to the steps from the `min`, e.g.: 1, 3, 5 for min: 1, step: 2
+        let value = min + ((value - min) / step).round() * step;
 
         self.value = value.clamp(self.min, self.max);
         cx.emit(SliderEvent::Change(self.value));
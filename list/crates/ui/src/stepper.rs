@@ -0,0 +1,129 @@
+use std::rc::Rc;
+
+use gpui::{
+    div, prelude::FluentBuilder as _, px, relative, ElementId, InteractiveElement as _,
+    IntoElement, ParentElement as _, RenderOnce, SharedString, StatefulInteractiveElement as _,
+    Styled as _, WindowContext,
+};
+
+use crate::{h_flex, theme::ActiveTheme as _, Icon, IconName, Sizable as _};
+
+/// The steps of a flow in a row, the steps before the selected one are completed.
+#[derive(IntoElement)]
+pub struct Stepper {
+    id: ElementId,
+    items: Vec<SharedString>,
+    selected_index: usize,
+    on_click: Option<Rc<dyn Fn(&usize, &mut WindowContext)>>,
+}
+
+impl Stepper {
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            items: Vec::new(),
+            selected_index: 0,
+            on_click: None,
+        }
+    }
+
+    /// Set the titles of the steps.
+    pub fn items(mut self, items: impl IntoIterator<Item = impl Into<SharedString>>) -> Self {
+        self.items = items.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Set the index of the current step, default: 0
+    pub fn selected_index(mut self, ix: usize) -> Self {
+        self.selected_index = ix;
+        self
+    }
+
+    /// Set the handler to call with the index of a completed step when it is clicked,
+    /// to go back to it.
+    pub fn on_click(mut self, handler: impl Fn(&usize, &mut WindowContext) + 'static) -> Self {
+        self.on_click = Some(Rc::new(handler));
+        self
+    }
+}
+
+impl RenderOnce for Stepper {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let theme = cx.theme().clone();
+        let selected_index = self.selected_index;
+        let on_click = self.on_click;
+
+        h_flex()
+            .id(self.id)
+            .w_full()
+            .gap_2()
+            .children(self.items.into_iter().enumerate().map(|(ix, title)| {
+                let completed = ix < selected_index;
+                let current = ix == selected_index;
+
+                h_flex()
+                    .when(ix > 0, |this| this.flex_1())
+                    .gap_2()
+                    .when(ix > 0, |this| {
+                        this.child(
+                            div()
+                                .flex_1()
+                                .min_w_4()
+                                .h(px(1.))
+                                .bg(if ix <= selected_index {
+                                    theme.tokens.accent
+                                } else {
+                                    theme.tokens.outline
+                                }),
+                        )
+                    })
+                    .child(
+                        h_flex()
+                            .id(("step", ix))
+                            .flex_none()
+                            .gap_2()
+                            .child(
+                                h_flex()
+                                    .flex_none()
+                                    .size_6()
+                                    .justify_center()
+                                    .rounded_full()
+                                    .border_1()
+                                    .text_xs()
+                                    .line_height(relative(1.))
+                                    .map(|this| {
+                                        if completed {
+                                            this.border_color(theme.tokens.accent)
+                                                .bg(theme.tokens.accent)
+                                                .child(
+                                                    Icon::new(IconName::Check)
+                                                        .xsmall()
+                                                        .text_color(theme.tokens.on_accent),
+                                                )
+                                        } else if current {
+                                            this.border_color(theme.tokens.accent)
+                                                .text_color(theme.tokens.accent)
+                                                .child((ix + 1).to_string())
+                                        } else {
+                                            this.border_color(theme.tokens.outline)
+                                                .text_color(theme.tokens.on_surface_muted)
+                                                .child((ix + 1).to_string())
+                                        }
+                                    }),
+                            )
+                            .child(
+                                div()
+                                    .text_sm()
+                                    .when(!current, |this| {
+                                        this.text_color(theme.tokens.on_surface_muted)
+                                    })
+                                    .child(title),
+                            )
+                            .when_some(on_click.clone().filter(|_| completed), |this, on_click| {
+                                this.cursor_pointer()
+                                    .on_click(move |_, cx| on_click(&ix, cx))
+                            }),
+                    )
+            }))
+    }
+}
@@ -4,7 +4,11 @@ use crate::{
     scroll::{Scrollable, ScrollbarAxis},
     theme::ActiveTheme,
 };
-use gpui::{div, px, Axis, Div, Element, EntityId, FocusHandle, Pixels, Styled, WindowContext};
+use gpui::{
+    div, point, px, Axis, BoxShadow, Div, Element, EntityId, FocusHandle, Pixels, Styled,
+    WindowContext,
+};
+use smallvec::smallvec;
 
 /// Returns a `Div` as horizontal flex layout.
 pub fn h_flex() -> Div {
@@ -97,7 +101,23 @@ pub trait StyledExt: Styled + Sized {
 
     /// Render a border with a width of 1px, color ring color
     fn outline(self, cx: &WindowContext) -> Self {
-        self.border_color(cx.theme().ring)
+        self.border_color(cx.theme().tokens.focus)
+    }
+
+    /// Render a 2px ring of the theme ring color around the element when it is focused.
+    ///
+    /// This is drawn as a shadow, so the layout will not be changed.
+    fn focus_ring(self, focused: bool, cx: &WindowContext) -> Self {
+        if focused {
+            self.shadow(smallvec![BoxShadow {
+                color: cx.theme().tokens.focus,
+                offset: point(px(0.), px(0.)),
+                blur_radius: px(0.),
+                spread_radius: px(2.),
+            }])
+        } else {
+            self
+        }
     }
 
     /// Wraps the element in a ScrollView.
@@ -122,9 +142,9 @@ pub trait StyledExt: Styled + Sized {
 
     /// Set as Popover style
     fn popover_style(self, cx: &mut WindowContext) -> Self {
-        self.bg(cx.theme().popover)
+        self.bg(cx.theme().tokens.overlay)
             .border_1()
-            .border_color(cx.theme().border)
+            .border_color(cx.theme().tokens.outline)
             .shadow_lg()
             .rounded_lg()
     }
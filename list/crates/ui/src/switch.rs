@@ -1,6 +1,6 @@
 use std::time::Duration;
 
-use crate::{h_flex, theme::ActiveTheme, Disableable, Sizable, Size};
+use crate::{h_flex, inspector::InspectorExt as _, theme::ActiveTheme, Disableable, Sizable, Size};
 use gpui::{
     div, prelude::FluentBuilder as _, px, Animation, AnimationExt as _, Div, ElementId,
     InteractiveElement, IntoElement, ParentElement as _, RenderOnce, SharedString, Stateful,
@@ -88,8 +88,8 @@ impl RenderOnce for Switch {
         let checked = self.checked;
 
         let (bg, toggle_bg) = match self.checked {
-            true => (theme.primary, theme.background),
-            false => (theme.input, theme.background),
+            true => (theme.tokens.accent, theme.tokens.surface),
+            false => (theme.input, theme.tokens.surface),
         };
 
         let (bg, toggle_bg) = match self.disabled {
@@ -160,5 +160,6 @@ impl RenderOnce for Switch {
                     })
                 },
             )
+            .inspect("Switch", cx)
     }
 }
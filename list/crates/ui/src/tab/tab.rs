@@ -1,9 +1,12 @@
-use crate::theme::ActiveTheme;
+use crate::accessibility::{register_accessible, Accessible, AccessibleNode, AccessibleRole};
+use crate::context_menu::ContextMenuExt;
+use crate::inspector::InspectorExt as _;
+use crate::theme::{ActiveTheme, ComponentKind, StyleOverridesExt as _};
 use crate::Selectable;
 use gpui::prelude::FluentBuilder as _;
 use gpui::{
-    div, px, AnyElement, Div, ElementId, InteractiveElement, IntoElement, ParentElement as _,
-    RenderOnce, Stateful, StatefulInteractiveElement, Styled, WindowContext,
+    div, px, AnyElement, Div, ElementId, InteractiveElement, IntoElement, ParentElement,
+    RenderOnce, SharedString, Stateful, StatefulInteractiveElement, Styled, WindowContext,
 };
 
 #[derive(IntoElement)]
@@ -14,6 +17,7 @@ pub struct Tab {
     suffix: Option<AnyElement>,
     disabled: bool,
     selected: bool,
+    accessible_label: Option<SharedString>,
 }
 
 impl Tab {
@@ -25,6 +29,7 @@ impl Tab {
             selected: false,
             prefix: None,
             suffix: None,
+            accessible_label: None,
         }
     }
 
@@ -41,6 +46,14 @@ impl Tab {
     }
 }
 
+impl Accessible for Tab {
+    /// The label can be any element, so set the text to announce for the tab.
+    fn accessible_label(mut self, label: impl Into<SharedString>) -> Self {
+        self.accessible_label = Some(label.into());
+        self
+    }
+}
+
 impl Selectable for Tab {
     fn selected(mut self, selected: bool) -> Self {
         self.selected = selected;
@@ -48,6 +61,14 @@ impl Selectable for Tab {
     }
 }
 
+impl ParentElement for Tab {
+    fn extend(&mut self, elements: impl IntoIterator<Item = AnyElement>) {
+        self.base.extend(elements)
+    }
+}
+
+impl ContextMenuExt for Tab {}
+
 impl InteractiveElement for Tab {
     fn interactivity(&mut self) -> &mut gpui::Interactivity {
         self.base.interactivity()
@@ -64,10 +85,16 @@ impl Styled for Tab {
 
 impl RenderOnce for Tab {
     fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        register_accessible(
+            AccessibleNode::new(AccessibleRole::Tab, self.accessible_label.clone())
+                .selected(self.selected)
+                .disabled(self.disabled),
+            cx,
+        );
         let (text_color, bg_color) = match (self.selected, self.disabled) {
             (true, _) => (cx.theme().tab_active_foreground, cx.theme().tab_active),
             (false, true) => (cx.theme().tab_foreground.opacity(0.5), cx.theme().tab),
-            (false, false) => (cx.theme().muted_foreground, cx.theme().tab),
+            (false, false) => (cx.theme().tokens.on_surface_muted, cx.theme().tab),
         };
 
         self.base
@@ -76,17 +103,21 @@ impl RenderOnce for Tab {
             .flex_shrink_0()
             .cursor_pointer()
             .overflow_hidden()
-            .text_color(text_color)
-            .bg(bg_color)
+            .text_color(cx.theme().tokens.on_surface_muted)
+            .bg(cx.theme().tab)
             .border_x_1()
             .border_color(cx.theme().transparent)
-            .when(self.selected, |this| this.border_color(cx.theme().border))
             .text_sm()
-            .when(self.disabled, |this| this)
-            .when_some(self.prefix, |this, prefix| {
-                this.child(prefix).text_color(text_color)
+            .style_overrides(ComponentKind::Tab, cx)
+            .when(self.selected || self.disabled, |this| {
+                this.text_color(text_color).bg(bg_color)
+            })
+            .when(self.selected, |this| {
+                this.border_color(cx.theme().tokens.outline)
             })
+            .when_some(self.prefix, |this, prefix| this.child(prefix))
             .child(div().text_ellipsis().child(self.label))
             .when_some(self.suffix, |this, suffix| this.child(suffix))
+            .inspect("Tab", cx)
     }
 }
@@ -79,7 +79,7 @@ impl RenderOnce for TabBar {
                     .bottom_0()
                     .size_full()
                     .border_b_1()
-                    .border_color(cx.theme().border),
+                    .border_color(cx.theme().tokens.outline),
             )
             .when_some(self.prefix, |this, prefix| this.child(prefix))
             .child(
@@ -1,18 +1,33 @@
-use std::{cell::Cell, ops::Range, rc::Rc};
+use std::{
+    cell::Cell,
+    collections::{BTreeSet, HashMap},
+    ops::Range,
+    rc::Rc,
+    time::{Duration, Instant},
+};
 
 use crate::{
+    button::Button,
+    checkbox::Checkbox,
+    context_menu::ContextMenuExt as _,
+    drag_drop::{DragDropExt as _, Draggable, DropIndicator, DropTarget},
+    empty_state::EmptyState,
     h_flex,
+    popup_menu::PopupMenu,
     scroll::{ScrollableAxis, ScrollableMask, Scrollbar, ScrollbarState},
     theme::ActiveTheme,
-    v_flex, Icon, IconName,
+    v_flex, Disableable as _, Icon, IconName, Placement, Sizable as _,
 };
 use gpui::{
-    actions, canvas, div, prelude::FluentBuilder, px, uniform_list, AppContext, Bounds, Div,
-    DragMoveEvent, Entity, EntityId, EventEmitter, FocusHandle, FocusableView, InteractiveElement,
-    IntoElement, KeyBinding, MouseButton, ParentElement, Pixels, Point, Render, ScrollHandle,
-    SharedString, StatefulInteractiveElement as _, Styled, UniformListScrollHandle, ViewContext,
+    actions, canvas, div, impl_actions, prelude::FluentBuilder, px, uniform_list, AnyElement,
+    AppContext, Bounds, Div, DragMoveEvent, Entity, EntityId, EventEmitter, FocusHandle,
+    FocusableView, Global, InteractiveElement, IntoElement, KeyBinding, KeyDownEvent, MouseButton,
+    ParentElement, Pixels, Point, Render, ScrollHandle, SharedString,
+    StatefulInteractiveElement as _, Styled, UniformListScrollHandle, ViewContext,
     VisualContext as _, WindowContext,
 };
+use rust_i18n::t;
+use serde::{Deserialize, Serialize};
 
 actions!(
     table,
@@ -21,10 +36,78 @@ actions!(
         SelectPrev,
         SelectNext,
         SelectPrevColumn,
-        SelectNextColumn
+        SelectNextColumn,
+        SelectPageUp,
+        SelectPageDown,
+        SelectFirst,
+        SelectLast,
+        Confirm,
+        ResetColumns
     ]
 );
 
+/// Hide or show the column at the index, dispatched by the header context menu.
+#[derive(Clone, PartialEq, Eq, Deserialize)]
+struct ToggleColumn(usize);
+
+impl_actions!(table, [ToggleColumn]);
+
+/// The width of the checkbox column of a [`Table::checkable`] table.
+const CHECK_COL_WIDTH: Pixels = px(40.);
+/// The height of the header row.
+const HEADER_HEIGHT: Pixels = px(40.);
+/// The typed characters are reset after the pause, to start a new type-ahead.
+const TYPE_AHEAD_TIMEOUT: Duration = Duration::from_millis(1000);
+
+/// The order and the visibility of the columns of a table, by the [`TableDelegate::col_key`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TableLayout {
+    /// The keys of the columns in order.
+    pub columns: Vec<String>,
+    /// The keys of the hidden columns.
+    pub hidden: Vec<String>,
+}
+
+/// The column layouts of the tables by the [`Table::layout_id`].
+///
+/// Set this global on startup to restore the persisted layouts, and observe it to save them.
+#[derive(Debug, Clone, Default)]
+pub struct TableLayouts(pub HashMap<String, TableLayout>);
+
+impl Global for TableLayouts {}
+
+fn last_empty_col(_: &mut WindowContext) -> Div {
+    h_flex().w(px(100.)).h_full().flex_shrink_0()
+}
+
+/// Find the row whose text starts with the typed characters, case insensitive,
+/// searching from the selected row and wrapping around.
+///
+/// Typing the same character repeatedly cycles through the rows starting with it.
+fn find_type_ahead_row(
+    query: &str,
+    selected_row: Option<usize>,
+    rows_count: usize,
+    text: impl Fn(usize) -> Option<SharedString>,
+) -> Option<usize> {
+    let query = query.to_lowercase();
+    let mut chars = query.chars();
+    let first = chars.next()?;
+    let (query, start) = if chars.all(|c| c == first) {
+        (
+            first.to_string(),
+            selected_row.map_or(0, |row_ix| row_ix + 1),
+        )
+    } else {
+        (query, selected_row.unwrap_or(0))
+    };
+
+    (0..rows_count)
+        .map(|ix| (start + ix) % rows_count)
+        .find(|&row_ix| text(row_ix).map_or(false, |text| text.to_lowercase().starts_with(&query)))
+}
+
 pub fn init(cx: &mut AppContext) {
     let context = Some("Table");
     cx.bind_keys([
@@ -33,6 +116,11 @@ pub fn init(cx: &mut AppContext) {
         KeyBinding::new("down", SelectNext, context),
         KeyBinding::new("left", SelectPrevColumn, context),
         KeyBinding::new("right", SelectNextColumn, context),
+        KeyBinding::new("pageup", SelectPageUp, context),
+        KeyBinding::new("pagedown", SelectPageDown, context),
+        KeyBinding::new("home", SelectFirst, context),
+        KeyBinding::new("end", SelectLast, context),
+        KeyBinding::new("enter", Confirm, context),
     ]);
 }
 
@@ -41,6 +129,7 @@ struct ColGroup {
     width: Option<Pixels>,
     bounds: Bounds<Pixels>,
     sort: Option<ColSort>,
+    visible: bool,
 }
 
 #[derive(Clone)]
@@ -61,19 +150,20 @@ pub enum ColSort {
     Descending,
 }
 
-impl Render for DragCol {
-    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+impl Draggable for DragCol {
+    fn render_preview(&self, cx: &mut WindowContext) -> AnyElement {
         div()
             .px_4()
             .py_1()
             .bg(cx.theme().table_head)
             .border_1()
-            .border_color(cx.theme().border)
+            .border_color(cx.theme().tokens.outline)
             .shadow_md()
             .when_some(self.width, |this, width| this.w(width))
             .min_w(px(100.))
             .max_w(px(450.))
             .child(self.name.clone())
+            .into_any_element()
     }
 }
 
@@ -84,13 +174,21 @@ pub struct ResizeCol(pub (EntityId, usize));
 enum SelectionState {
     Column,
     Row,
+    /// A cell of the selected row is focused, moved by the arrow keys.
+    Cell,
 }
 
 #[derive(Clone)]
 pub enum TableEvent {
     SelectRow(usize),
     SelectCol(usize),
+    /// A cell is focused by the keyboard, with the row and the column index.
+    SelectCell(usize, usize),
+    /// The selected row is activated by pressing `enter`.
+    ConfirmRow(usize),
     ColWidthsChanged(Vec<Option<Pixels>>),
+    /// The checked rows of a [`Table::checkable`] table have changed, with all the checked indices in order.
+    CheckedRowsChanged(Vec<usize>),
 }
 
 pub struct Table<D: TableDelegate> {
@@ -107,6 +205,9 @@ pub struct Table<D: TableDelegate> {
     selection_state: SelectionState,
     selected_row: Option<usize>,
     selected_col: Option<usize>,
+    /// The characters typed to jump to the row, see [`TableDelegate::type_ahead_text`].
+    type_ahead: String,
+    type_ahead_at: Option<Instant>,
 
     /// The column index that is being resized.
     resizing_col: Option<usize>,
@@ -115,6 +216,16 @@ pub struct Table<D: TableDelegate> {
     stripe: bool,
     /// Set to use border style of the table.
     border: bool,
+    /// Show the checkbox column to check the rows.
+    checkable: bool,
+    checked_rows: BTreeSet<usize>,
+    /// The number of the columns at the start that are not scrolled horizontally.
+    frozen_cols: usize,
+
+    /// The id to save the column layout to the [`TableLayouts`].
+    layout_id: Option<SharedString>,
+    /// The column layout of the delegate, to reset to.
+    default_layout: TableLayout,
 }
 
 #[allow(unused)]
@@ -127,6 +238,13 @@ pub trait TableDelegate: Sized + 'static {
     /// Returns the name of the column at the given index.
     fn col_name(&self, col_ix: usize) -> SharedString;
 
+    /// Returns the unique key of the column at the given index, to save the column layout.
+    ///
+    /// Default: the column name
+    fn col_key(&self, col_ix: usize) -> SharedString {
+        self.col_name(col_ix)
+    }
+
     /// Returns whether the column at the given index can be resized. Default: true
     fn can_resize_col(&self, col_ix: usize) -> bool {
         true
@@ -180,6 +298,13 @@ pub trait TableDelegate: Sized + 'static {
         true
     }
 
+    /// Return the text of the row to match the typed characters, to jump to the row by typing.
+    ///
+    /// Default: None, the type-ahead is disabled.
+    fn type_ahead_text(&self, row_ix: usize) -> Option<SharedString> {
+        None
+    }
+
     /// Return true to enable column order change.
     fn can_move_col(&self, col_ix: usize) -> bool {
         false
@@ -190,13 +315,21 @@ pub trait TableDelegate: Sized + 'static {
 
     /// Return a Element to show when table is empty.
     fn render_empty(&self, cx: &mut ViewContext<Table<Self>>) -> impl IntoElement {
-        h_flex()
-            .size_full()
-            .justify_center()
-            .py_6()
-            .text_color(cx.theme().muted_foreground.opacity(0.6))
-            .child(Icon::new(IconName::Inbox).size_12())
-            .into_any_element()
+        EmptyState::new(t!("Table.No Data").to_string())
+    }
+
+    /// Return the error message if failed to load the rows, the error state is shown instead of the rows.
+    fn error(&self) -> Option<SharedString> {
+        None
+    }
+
+    /// Return a Element to show when the [`TableDelegate::error`] is not None.
+    fn render_error(
+        &self,
+        error: SharedString,
+        cx: &mut ViewContext<Table<Self>>,
+    ) -> impl IntoElement {
+        EmptyState::error(error)
     }
 
     /// Return true to enable load more data when scrolling to the bottom.
@@ -221,6 +354,16 @@ pub trait TableDelegate: Sized + 'static {
     /// This is always called when the table is near the bottom,
     /// so you must check if there is more data to load or lock the loading state.
     fn load_more(&mut self, cx: &mut ViewContext<Table<Self>>) {}
+
+    /// Render the actions for the checked rows of a [`Table::checkable`] table,
+    /// they are shown in a toolbar above the table when any rows are checked.
+    fn render_bulk_actions(
+        &self,
+        rows: &[usize],
+        cx: &mut ViewContext<Table<Self>>,
+    ) -> impl IntoElement {
+        div()
+    }
 }
 
 impl<D> Table<D>
@@ -238,13 +381,21 @@ where
             selection_state: SelectionState::Row,
             selected_row: None,
             selected_col: None,
+            type_ahead: String::new(),
+            type_ahead_at: None,
             resizing_col: None,
             bounds: Bounds::default(),
             stripe: false,
             border: true,
+            checkable: false,
+            checked_rows: BTreeSet::new(),
+            frozen_cols: 0,
+            layout_id: None,
+            default_layout: TableLayout::default(),
         };
 
         this.prepare_col_groups(cx);
+        this.default_layout = this.layout();
         this
     }
 
@@ -268,17 +419,197 @@ where
         self
     }
 
+    /// Set the number of the columns at the start to freeze, default to 0.
+    ///
+    /// The frozen columns stay visible while the other columns are scrolled horizontally,
+    /// like the header row that stays visible while the rows are scrolled vertically.
+    pub fn frozen_cols(mut self, frozen_cols: usize) -> Self {
+        self.frozen_cols = frozen_cols;
+        self
+    }
+
+    fn frozen_cols_count(&self) -> usize {
+        self.frozen_cols.min(self.col_groups.len())
+    }
+
+    /// Set to show a checkbox column at the start to check the rows, default to false.
+    ///
+    /// The checkbox in the header checks or unchecks all the rows,
+    /// and the [`TableDelegate::render_bulk_actions`] are shown above the table when any rows are checked.
+    pub fn checkable(mut self, checkable: bool) -> Self {
+        self.checkable = checkable;
+        self
+    }
+
+    /// Set the id to save the column order and visibility to the [`TableLayouts`],
+    /// the saved layout of the id is restored here.
+    pub fn layout_id(mut self, id: impl Into<SharedString>, cx: &mut ViewContext<Self>) -> Self {
+        let id = id.into();
+        let layout = cx
+            .try_global::<TableLayouts>()
+            .and_then(|layouts| layouts.0.get(id.as_ref()).cloned());
+        if let Some(layout) = layout {
+            self.apply_layout(&layout, cx);
+        }
+        self.layout_id = Some(id);
+        self
+    }
+
     fn prepare_col_groups(&mut self, cx: &mut ViewContext<Self>) {
         self.col_groups = (0..self.delegate.cols_count())
             .map(|col_ix| ColGroup {
                 width: self.delegate.col_width(col_ix),
                 bounds: Bounds::default(),
                 sort: self.delegate.col_sort(col_ix),
+                visible: true,
             })
             .collect();
         cx.notify();
     }
 
+    /// Returns the current order and visibility of the columns.
+    pub fn layout(&self) -> TableLayout {
+        let key = |col_ix| self.delegate.col_key(col_ix).to_string();
+        TableLayout {
+            columns: (0..self.col_groups.len()).map(key).collect(),
+            hidden: (0..self.col_groups.len())
+                .filter(|&col_ix| !self.col_groups[col_ix].visible)
+                .map(key)
+                .collect(),
+        }
+    }
+
+    /// Move the columns to the order of the layout and hide the hidden ones,
+    /// the columns that are not in the layout are kept after them.
+    fn apply_layout(&mut self, layout: &TableLayout, cx: &mut ViewContext<Self>) {
+        let mut to_ix = 0;
+        for key in &layout.columns {
+            let Some(col_ix) = (to_ix..self.col_groups.len())
+                .find(|&ix| self.delegate.col_key(ix).as_ref() == key)
+            else {
+                continue;
+            };
+            self.move_col(col_ix, to_ix, cx);
+            to_ix += 1;
+        }
+
+        for col_ix in 0..self.col_groups.len() {
+            let key = self.delegate.col_key(col_ix);
+            self.col_groups[col_ix].visible = !layout.hidden.iter().any(|k| k == key.as_ref());
+        }
+        cx.notify();
+    }
+
+    fn save_layout(&self, cx: &mut ViewContext<Self>) {
+        if let Some(id) = &self.layout_id {
+            cx.default_global::<TableLayouts>()
+                .0
+                .insert(id.to_string(), self.layout());
+        }
+    }
+
+    /// Returns whether the column at the given index is shown.
+    pub fn is_col_visible(&self, col_ix: usize) -> bool {
+        self.col_groups
+            .get(col_ix)
+            .map_or(false, |group| group.visible)
+    }
+
+    /// Hide or show the column at the given index, the last visible column can't be hidden.
+    pub fn set_col_visible(&mut self, col_ix: usize, visible: bool, cx: &mut ViewContext<Self>) {
+        let visible_count = self.col_groups.iter().filter(|group| group.visible).count();
+        if !visible && visible_count <= 1 {
+            return;
+        }
+        let Some(col_group) = self.col_groups.get_mut(col_ix) else {
+            return;
+        };
+
+        col_group.visible = visible;
+        if !visible && self.selected_col == Some(col_ix) {
+            self.selected_col = None;
+            if self.selection_state == SelectionState::Cell {
+                self.selection_state = SelectionState::Row;
+            }
+        }
+        self.save_layout(cx);
+        cx.notify();
+    }
+
+    fn action_toggle_col(&mut self, action: &ToggleColumn, cx: &mut ViewContext<Self>) {
+        let col_ix = action.0;
+        self.set_col_visible(col_ix, !self.is_col_visible(col_ix), cx);
+    }
+
+    fn action_reset_cols(&mut self, _: &ResetColumns, cx: &mut ViewContext<Self>) {
+        let layout = self.default_layout.clone();
+        self.apply_layout(&layout, cx);
+        self.save_layout(cx);
+    }
+
+    /// Returns the selected row index.
+    pub fn selected_row(&self) -> Option<usize> {
+        self.selected_row
+    }
+
+    /// Returns the selected column index.
+    pub fn selected_col(&self) -> Option<usize> {
+        self.selected_col
+    }
+
+    /// Returns the checked row indices in order.
+    pub fn checked_rows(&self) -> Vec<usize> {
+        // The rows may have been removed by the delegate.
+        let rows_count = self.delegate.rows_count();
+        self.checked_rows
+            .iter()
+            .copied()
+            .filter(|&row_ix| row_ix < rows_count)
+            .collect()
+    }
+
+    /// Replace the checked rows, and emit [`TableEvent::CheckedRowsChanged`] if changed.
+    pub fn set_checked_rows(
+        &mut self,
+        rows: impl IntoIterator<Item = usize>,
+        cx: &mut ViewContext<Self>,
+    ) {
+        let rows_count = self.delegate.rows_count();
+        let rows = rows
+            .into_iter()
+            .filter(|&row_ix| row_ix < rows_count)
+            .collect::<BTreeSet<_>>();
+        if rows == self.checked_rows {
+            return;
+        }
+
+        self.checked_rows = rows;
+        cx.emit(TableEvent::CheckedRowsChanged(self.checked_rows()));
+        cx.notify();
+    }
+
+    pub fn clear_checked_rows(&mut self, cx: &mut ViewContext<Self>) {
+        self.set_checked_rows([], cx);
+    }
+
+    fn check_row(&mut self, row_ix: usize, checked: bool, cx: &mut ViewContext<Self>) {
+        let mut rows = self.checked_rows.clone();
+        if checked {
+            rows.insert(row_ix);
+        } else {
+            rows.remove(&row_ix);
+        }
+        self.set_checked_rows(rows, cx);
+    }
+
+    fn check_all(&mut self, checked: bool, cx: &mut ViewContext<Self>) {
+        if checked {
+            self.set_checked_rows(0..self.delegate.rows_count(), cx);
+        } else {
+            self.clear_checked_rows(cx);
+        }
+    }
+
     fn set_selected_row(&mut self, row_ix: usize, cx: &mut ViewContext<Self>) {
         self.selection_state = SelectionState::Row;
         self.selected_row = Some(row_ix);
@@ -292,8 +623,9 @@ where
     fn set_selected_col(&mut self, col_ix: usize, cx: &mut ViewContext<Self>) {
         self.selection_state = SelectionState::Column;
         self.selected_col = Some(col_ix);
-        if let Some(col_ix) = self.selected_col {
-            self.horizontal_scroll_handle.scroll_to_item(col_ix);
+        // The frozen columns are always visible, and not the items of the scroll handle.
+        if let Some(item_ix) = col_ix.checked_sub(self.frozen_cols_count()) {
+            self.horizontal_scroll_handle.scroll_to_item(item_ix);
         }
         cx.emit(TableEvent::SelectCol(col_ix));
         cx.notify();
@@ -311,6 +643,86 @@ where
         self.set_selected_col(col_ix, cx)
     }
 
+    /// Focus the cell by the keyboard, the row is selected as well.
+    fn set_selected_cell(&mut self, row_ix: usize, col_ix: usize, cx: &mut ViewContext<Self>) {
+        self.selection_state = SelectionState::Cell;
+        self.selected_row = Some(row_ix);
+        self.selected_col = Some(col_ix);
+        self.vertical_scroll_handle.scroll_to_item(row_ix);
+        if let Some(item_ix) = col_ix.checked_sub(self.frozen_cols_count()) {
+            self.horizontal_scroll_handle.scroll_to_item(item_ix);
+        }
+        cx.emit(TableEvent::SelectCell(row_ix, col_ix));
+        cx.notify();
+    }
+
+    /// Returns the row and the column index of the cell focused by the keyboard.
+    pub fn selected_cell(&self) -> Option<(usize, usize)> {
+        if self.selection_state != SelectionState::Cell {
+            return None;
+        }
+
+        self.selected_row.zip(self.selected_col)
+    }
+
+    /// Select the row by the keyboard, keep the focused column if a cell is focused.
+    fn move_selected_row(&mut self, row_ix: usize, cx: &mut ViewContext<Self>) {
+        match self.selected_cell() {
+            Some((_, col_ix)) => self.set_selected_cell(row_ix, col_ix, cx),
+            None => self.set_selected_row(row_ix, cx),
+        }
+    }
+
+    /// Returns the next visible column in the direction, from the edge if `col_ix` is `None`.
+    fn next_visible_col(&self, col_ix: Option<usize>, forward: bool) -> Option<usize> {
+        let cols_count = self.col_groups.len();
+        let visible = |col_ix: &usize| self.is_col_visible(*col_ix);
+        match (col_ix, forward) {
+            (None, true) => (0..cols_count).find(visible),
+            (None, false) => (0..cols_count).rev().find(visible),
+            (Some(col_ix), true) => (col_ix + 1..cols_count).find(visible),
+            (Some(col_ix), false) => (0..col_ix).rev().find(visible),
+        }
+    }
+
+    /// Move the focused cell in the selected row, returns false if no row is selected.
+    fn move_selected_cell(&mut self, forward: bool, cx: &mut ViewContext<Self>) -> bool {
+        if self.selection_state == SelectionState::Column {
+            return false;
+        }
+        let Some(row_ix) = self.selected_row else {
+            return false;
+        };
+
+        let next_col = match self.selected_cell() {
+            // Start from the first column.
+            None => self.next_visible_col(None, true),
+            Some((_, col_ix)) => self.next_visible_col(Some(col_ix), forward).or_else(|| {
+                if self.delegate.can_loop_select() {
+                    self.next_visible_col(None, forward)
+                } else {
+                    Some(col_ix)
+                }
+            }),
+        };
+        if let Some(col_ix) = next_col {
+            self.set_selected_cell(row_ix, col_ix, cx);
+        }
+        true
+    }
+
+    /// The number of the rows in a page of the visible area.
+    fn page_rows_count(&self) -> usize {
+        let Some(row_height) = self.vertical_scroll_handle.0.borrow().last_item_height else {
+            return 1;
+        };
+        if row_height <= px(0.) {
+            return 1;
+        }
+
+        (((self.bounds.size.height - HEADER_HEIGHT) / row_height).floor() as usize).max(1)
+    }
+
     fn action_cancel(&mut self, _: &Cancel, cx: &mut ViewContext<Self>) {
         self.selection_state = SelectionState::Row;
         self.selected_row = None;
@@ -319,8 +731,12 @@ where
     }
 
     fn action_select_prev(&mut self, _: &SelectPrev, cx: &mut ViewContext<Self>) {
-        let mut selected_row = self.selected_row.unwrap_or(0);
         let rows_count = self.delegate.rows_count();
+        if rows_count == 0 {
+            return;
+        }
+
+        let mut selected_row = self.selected_row.unwrap_or(0);
         if selected_row > 0 {
             selected_row = selected_row - 1;
         } else {
@@ -329,12 +745,17 @@ where
             }
         }
 
-        self.set_selected_row(selected_row, cx);
+        self.move_selected_row(selected_row, cx);
     }
 
     fn action_select_next(&mut self, _: &SelectNext, cx: &mut ViewContext<Self>) {
+        let rows_count = self.delegate.rows_count();
+        if rows_count == 0 {
+            return;
+        }
+
         let mut selected_row = self.selected_row.unwrap_or(0);
-        if selected_row < self.delegate.rows_count() - 1 {
+        if selected_row < rows_count - 1 {
             selected_row += 1;
         } else {
             if self.delegate.can_loop_select() {
@@ -342,10 +763,54 @@ where
             }
         }
 
-        self.set_selected_row(selected_row, cx);
+        self.move_selected_row(selected_row, cx);
+    }
+
+    fn action_select_page_up(&mut self, _: &SelectPageUp, cx: &mut ViewContext<Self>) {
+        if self.delegate.rows_count() == 0 {
+            return;
+        }
+
+        let selected_row = self.selected_row.unwrap_or(0);
+        self.move_selected_row(selected_row.saturating_sub(self.page_rows_count()), cx);
+    }
+
+    fn action_select_page_down(&mut self, _: &SelectPageDown, cx: &mut ViewContext<Self>) {
+        let rows_count = self.delegate.rows_count();
+        if rows_count == 0 {
+            return;
+        }
+
+        let selected_row = self.selected_row.unwrap_or(0);
+        self.move_selected_row(
+            (selected_row + self.page_rows_count()).min(rows_count - 1),
+            cx,
+        );
+    }
+
+    fn action_select_first(&mut self, _: &SelectFirst, cx: &mut ViewContext<Self>) {
+        if self.delegate.rows_count() > 0 {
+            self.move_selected_row(0, cx);
+        }
+    }
+
+    fn action_select_last(&mut self, _: &SelectLast, cx: &mut ViewContext<Self>) {
+        if let Some(last_row) = self.delegate.rows_count().checked_sub(1) {
+            self.move_selected_row(last_row, cx);
+        }
+    }
+
+    fn action_confirm(&mut self, _: &Confirm, cx: &mut ViewContext<Self>) {
+        if let Some(row_ix) = self.selected_row {
+            cx.emit(TableEvent::ConfirmRow(row_ix));
+        }
     }
 
     fn action_select_prev_col(&mut self, _: &SelectPrevColumn, cx: &mut ViewContext<Self>) {
+        if self.move_selected_cell(false, cx) {
+            return;
+        }
+
         let mut selected_col = self.selected_col.unwrap_or(0);
         let cols_count = self.delegate.cols_count();
         if selected_col > 0 {
@@ -359,6 +824,10 @@ where
     }
 
     fn action_select_next_col(&mut self, _: &SelectNextColumn, cx: &mut ViewContext<Self>) {
+        if self.move_selected_cell(true, cx) {
+            return;
+        }
+
         let mut selected_col = self.selected_col.unwrap_or(0);
         if selected_col < self.delegate.cols_count() - 1 {
             selected_col += 1;
@@ -371,6 +840,40 @@ where
         self.set_selected_col(selected_col, cx);
     }
 
+    /// Jump to the row that matches the typed characters, see [`TableDelegate::type_ahead_text`].
+    fn on_key_down(&mut self, event: &KeyDownEvent, cx: &mut ViewContext<Self>) {
+        let keystroke = &event.keystroke;
+        let modifiers = keystroke.modifiers;
+        if modifiers.control || modifiers.alt || modifiers.platform || modifiers.function {
+            return;
+        }
+        let mut chars = keystroke.key.chars();
+        let (Some(c), None) = (chars.next(), chars.next()) else {
+            return;
+        };
+
+        let now = Instant::now();
+        if self
+            .type_ahead_at
+            .map_or(true, |at| now.duration_since(at) > TYPE_AHEAD_TIMEOUT)
+        {
+            self.type_ahead.clear();
+        }
+        self.type_ahead_at = Some(now);
+        self.type_ahead.push(c);
+
+        let row_ix = find_type_ahead_row(
+            &self.type_ahead,
+            self.selected_row,
+            self.delegate.rows_count(),
+            |row_ix| self.delegate.type_ahead_text(row_ix),
+        );
+        if let Some(row_ix) = row_ix {
+            self.move_selected_row(row_ix, cx);
+            cx.stop_propagation();
+        }
+    }
+
     fn render_cell(&self, col_ix: usize, _cx: &mut ViewContext<Self>) -> Div {
         let col_width = self.col_groups[col_ix].width;
 
@@ -434,7 +937,7 @@ where
                     .h_full()
                     .h_5()
                     .justify_center()
-                    .bg(cx.theme().border)
+                    .bg(cx.theme().tokens.outline)
                     .w(px(1.)),
             )
             .on_drag_move(cx.listener(move |view, e: &DragMoveEvent<ResizeCol>, cx| {
@@ -553,12 +1056,15 @@ where
         for (ix, col_group) in self.col_groups.iter_mut().enumerate() {
             if ix == col_ix {
                 col_group.sort = Some(sort);
-            } else {
+            } else if col_group.sort.is_some() {
+                // Keep the non-sortable columns as is.
                 col_group.sort = Some(ColSort::Default);
             }
         }
 
         self.delegate_mut().perform_sort(col_ix, sort, cx);
+        // The rows are moved by the sort.
+        self.clear_checked_rows(cx);
 
         cx.notify();
     }
@@ -588,14 +1094,14 @@ where
                 .ml_2()
                 .p(px(2.))
                 .rounded_sm()
-                .hover(|this| this.bg(cx.theme().secondary))
-                .active(|this| this.bg(cx.theme().secondary_active))
+                .hover(|this| this.bg(cx.theme().tokens.neutral))
+                .active(|this| this.bg(cx.theme().neutral_active))
                 .on_mouse_down(MouseButton::Left, |_, cx| cx.stop_propagation())
                 .on_click(cx.listener(move |table, _, cx| table.perform_sort(col_ix, cx)))
                 .child(
                     Icon::new(icon)
                         .size_3()
-                        .text_color(cx.theme().secondary_foreground),
+                        .text_color(cx.theme().tokens.on_neutral),
                 ),
         )
     }
@@ -607,6 +1113,10 @@ where
     fn render_th(&self, col_ix: usize, cx: &mut ViewContext<Self>) -> impl IntoElement {
         let entity_id = cx.entity_id();
         let col_group = self.col_groups.get(col_ix).expect("BUG: invalid col index");
+        // Keep an empty item for the hidden column, to keep the indices of the items.
+        if !col_group.visible {
+            return h_flex().into_any_element();
+        }
 
         let name = self.delegate.col_name(col_ix);
         h_flex()
@@ -629,34 +1139,21 @@ where
                             .children(self.render_sort_icon(col_ix, cx)),
                     )
                     .when(self.delegate.can_move_col(col_ix), |this| {
-                        this.on_drag(
-                            DragCol {
-                                entity_id,
-                                col_ix,
-                                name,
-                                width: col_group.width,
-                            },
-                            |drag, cx| {
-                                cx.stop_propagation();
-                                cx.new_view(|_| drag.clone())
-                            },
-                        )
-                        .drag_over::<DragCol>(|this, _, cx| {
-                            this.rounded_l_none()
-                                .border_l_2()
-                                .border_r_0()
-                                .border_color(cx.theme().drag_border)
+                        this.drag_source(DragCol {
+                            entity_id,
+                            col_ix,
+                            name,
+                            width: col_group.width,
                         })
-                        .on_drop(cx.listener(
-                            move |table, drag: &DragCol, cx| {
-                                // If the drag col is not the same as the drop col, then swap the cols.
-                                if drag.entity_id != cx.entity_id() {
-                                    return;
-                                }
-
+                        .drop_target(
+                            DropTarget::new(cx.listener(move |table, drag: &DragCol, cx| {
                                 table.move_col(drag.col_ix, col_ix, cx);
-                            },
-                        ))
+                                table.save_layout(cx);
+                            }))
+                            // Only move the cols of the same table.
+                            .can_drop(move |drag, _| drag.entity_id == entity_id)
+                            .indicator(|_, _| DropIndicator::Line(Placement::Left)),
+                        )
                     }),
             )
             // resize handle
@@ -671,6 +1168,182 @@ where
                 .absolute()
                 .size_full()
             })
+            .into_any_element()
+    }
+
+    /// Build the menu to hide or show the columns, opened by right clicking the header.
+    fn cols_menu(&self) -> impl Fn(PopupMenu, &mut ViewContext<PopupMenu>) -> PopupMenu + 'static {
+        let focus_handle = self.focus_handle.clone();
+        let cols = (0..self.col_groups.len())
+            .map(|col_ix| {
+                (
+                    col_ix,
+                    self.delegate.col_name(col_ix),
+                    self.col_groups[col_ix].visible,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        move |menu, _| {
+            let mut menu = menu.track_focus(&focus_handle);
+            for (col_ix, name, visible) in &cols {
+                menu =
+                    menu.menu_with_check(name.clone(), *visible, Box::new(ToggleColumn(*col_ix)));
+            }
+            menu.separator()
+                .menu(t!("Table.Reset Columns"), Box::new(ResetColumns))
+        }
+    }
+
+    /// Render the cell at the given row and column, the hidden column is kept as an empty item.
+    fn render_td(&self, row_ix: usize, col_ix: usize, cx: &mut ViewContext<Self>) -> Div {
+        let focused = self.selected_cell() == Some((row_ix, col_ix));
+
+        self.col_wrap(col_ix, cx)
+            .when(self.is_col_visible(col_ix), |this| {
+                this.child(
+                    self.render_cell(col_ix, cx)
+                        .flex_shrink_0()
+                        .child(self.delegate.render_td(row_ix, col_ix, cx))
+                        // Outline the focused cell without changing its size.
+                        .when(focused, |this| {
+                            this.relative().child(
+                                div()
+                                    .absolute()
+                                    .inset_0()
+                                    .border_1()
+                                    .border_color(cx.theme().tokens.focus),
+                            )
+                        }),
+                )
+            })
+    }
+
+    /// Render the cells of the scrollable columns of the row.
+    fn render_tds(&self, row_ix: usize, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        // Make the row scroll sync with the horizontal_scroll_handle to support horizontal scrolling.
+        let offset_x = self.horizontal_scroll_handle.offset().x;
+
+        // Clip the scrolled cells, to keep the checkbox and the frozen columns fixed.
+        h_flex()
+            .flex_1()
+            .overflow_hidden()
+            .children(
+                (self.frozen_cols_count()..self.col_groups.len())
+                    .map(|col_ix| self.render_td(row_ix, col_ix, cx).left(offset_x)),
+            )
+            .child(last_empty_col(cx))
+    }
+
+    /// Render the cells of the frozen columns of the row, they are not scrolled.
+    fn render_frozen_tds(
+        &self,
+        row_ix: usize,
+        cx: &mut ViewContext<Self>,
+    ) -> Option<impl IntoElement> {
+        let frozen_cols = self.frozen_cols_count();
+        if frozen_cols == 0 {
+            return None;
+        }
+
+        Some(
+            h_flex()
+                .flex_shrink_0()
+                .border_r_1()
+                .border_color(cx.theme().tokens.outline)
+                .children((0..frozen_cols).map(|col_ix| self.render_td(row_ix, col_ix, cx))),
+        )
+    }
+
+    fn render_frozen_ths(&self, cx: &mut ViewContext<Self>) -> Option<impl IntoElement> {
+        let frozen_cols = self.frozen_cols_count();
+        if frozen_cols == 0 {
+            return None;
+        }
+
+        Some(
+            h_flex()
+                .flex_shrink_0()
+                .h_10()
+                .border_r_1()
+                .border_color(cx.theme().tokens.outline)
+                .children((0..frozen_cols).map(|col_ix| self.render_th(col_ix, cx))),
+        )
+    }
+
+    /// Render the checkbox in the header to check or uncheck all the rows.
+    fn render_check_th(&self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let rows_count = self.delegate.rows_count();
+        let checked_count = self.checked_rows().len();
+
+        h_flex()
+            .id("check-all")
+            .flex_shrink_0()
+            .w(CHECK_COL_WIDTH)
+            .h_full()
+            .justify_center()
+            .on_mouse_down(MouseButton::Left, |_, cx| cx.stop_propagation())
+            .child(
+                Checkbox::new("check-all-checkbox")
+                    .checked(rows_count > 0 && checked_count == rows_count)
+                    .indeterminate(checked_count > 0 && checked_count < rows_count)
+                    .disabled(rows_count == 0)
+                    .on_click(cx.listener(|this, checked: &bool, cx| this.check_all(*checked, cx))),
+            )
+    }
+
+    fn render_check_td(&self, row_ix: usize, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        h_flex()
+            .id(("check-row", row_ix))
+            .flex_shrink_0()
+            .w(CHECK_COL_WIDTH)
+            .h_full()
+            .justify_center()
+            .on_mouse_down(MouseButton::Left, |_, cx| cx.stop_propagation())
+            .child(
+                Checkbox::new(("check-row-checkbox", row_ix))
+                    .checked(self.checked_rows.contains(&row_ix))
+                    .on_click(cx.listener(move |this, checked: &bool, cx| {
+                        this.check_row(row_ix, *checked, cx)
+                    })),
+            )
+    }
+
+    /// Render the count and the delegate's actions of the checked rows.
+    fn render_bulk_toolbar(&self, cx: &mut ViewContext<Self>) -> Option<impl IntoElement> {
+        let rows = self.checked_rows();
+        if !self.checkable || rows.is_empty() {
+            return None;
+        }
+
+        Some(
+            h_flex()
+                .h_10()
+                .flex_shrink_0()
+                .px_3()
+                .gap_3()
+                .border_b_1()
+                .border_color(cx.theme().tokens.outline)
+                .bg(cx.theme().table_active)
+                .child(
+                    div()
+                        .text_sm()
+                        .child(t!("Table.Selected", count = rows.len()).to_string()),
+                )
+                .child(
+                    h_flex()
+                        .flex_1()
+                        .gap_2()
+                        .child(self.delegate.render_bulk_actions(&rows, cx)),
+                )
+                .child(
+                    Button::new("clear-checked-rows", cx)
+                        .ghost()
+                        .xsmall()
+                        .label(t!("Table.Clear Selection"))
+                        .on_click(cx.listener(|this, _, cx| this.clear_checked_rows(cx))),
+                ),
+        )
     }
 
     /// Dispatch delegate's `load_more` method when the visible range is near the end.
@@ -729,13 +1402,8 @@ where
         let view = cx.view().clone();
         let vertical_scroll_handle = self.vertical_scroll_handle.clone();
         let horizontal_scroll_handle = self.horizontal_scroll_handle.clone();
-        let cols_count: usize = self.delegate.cols_count();
         let rows_count = self.delegate.rows_count();
 
-        fn last_empty_col(_: &mut WindowContext) -> Div {
-            h_flex().w(px(100.)).h_full().flex_shrink_0()
-        }
-
         fn tr(_: &mut WindowContext) -> Div {
             h_flex()
         }
@@ -749,35 +1417,51 @@ where
             .on_action(cx.listener(Self::action_select_prev))
             .on_action(cx.listener(Self::action_select_next_col))
             .on_action(cx.listener(Self::action_select_prev_col))
+            .on_action(cx.listener(Self::action_select_page_up))
+            .on_action(cx.listener(Self::action_select_page_down))
+            .on_action(cx.listener(Self::action_select_first))
+            .on_action(cx.listener(Self::action_select_last))
+            .on_action(cx.listener(Self::action_confirm))
+            .on_action(cx.listener(Self::action_toggle_col))
+            .on_action(cx.listener(Self::action_reset_cols))
+            .on_key_down(cx.listener(Self::on_key_down))
             .size_full()
             .overflow_hidden()
+            .children(self.render_bulk_toolbar(cx))
             .child(
                 v_flex()
                     .flex_grow()
                     .h_10()
                     .w_full()
                     .border_b_1()
-                    .border_color(cx.theme().border)
+                    .border_color(cx.theme().tokens.outline)
                     .child(
                         uniform_list(view.clone(), "table-uniform-list-head", 1, {
                             let horizontal_scroll_handle = horizontal_scroll_handle.clone();
                             move |table, _, cx| {
-                                // Columns
-                                tr(cx)
-                                    .id("table-head")
-                                    .w_full()
-                                    .h_10()
-                                    .overflow_scroll()
-                                    .track_scroll(&horizontal_scroll_handle)
+                                h_flex()
+                                    .id("table-header")
+                                    .size_full()
                                     .bg(cx.theme().table_head)
-                                    .children(
-                                        table
-                                            .col_groups
-                                            .iter()
-                                            .enumerate()
-                                            .map(|(col_ix, _)| table.render_th(col_ix, cx)),
+                                    .when(table.checkable, |this| {
+                                        this.child(table.render_check_th(cx))
+                                    })
+                                    .children(table.render_frozen_ths(cx))
+                                    .child(
+                                        // Columns
+                                        tr(cx)
+                                            .id("table-head")
+                                            .flex_1()
+                                            .h_10()
+                                            .overflow_scroll()
+                                            .track_scroll(&horizontal_scroll_handle)
+                                            .children(
+                                                (table.frozen_cols_count()..table.col_groups.len())
+                                                    .map(|col_ix| table.render_th(col_ix, cx)),
+                                            )
+                                            .child(last_empty_col(cx)),
                                     )
-                                    .child(last_empty_col(cx))
+                                    .context_menu(table.cols_menu())
                                     .map(|this| vec![this])
                             }
                         })
@@ -785,13 +1469,18 @@ where
                     ),
             )
             .map(|this| {
-                if rows_count == 0 {
+                if let Some(error) = self.delegate.error() {
+                    this.child(
+                        div()
+                            .size_full()
+                            .child(self.delegate.render_error(error, cx)),
+                    )
+                } else if rows_count == 0 {
                     this.child(div().size_full().child(self.delegate.render_empty(cx)))
                 } else {
                     this.child(
                         h_flex().id("table-body").flex_grow().size_full().child(
                             uniform_list(view, "table-uniform-list", rows_count, {
-                                let horizontal_scroll_handle = horizontal_scroll_handle.clone();
                                 move |table, visible_range, cx| {
                                     table.load_more(visible_range.clone(), cx);
 
@@ -804,7 +1493,7 @@ where
                                                 .w_full()
                                                 .when(row_ix > 0, |this| {
                                                     this.border_t_1()
-                                                        .border_color(cx.theme().border)
+                                                        .border_color(cx.theme().tokens.outline)
                                                 })
                                                 .when(table.stripe && row_ix % 2 != 0, |this| {
                                                     this.bg(cx.theme().table_even)
@@ -816,22 +1505,20 @@ where
                                                         this.bg(cx.theme().table_hover)
                                                     }
                                                 })
-                                                .children((0..cols_count).map(|col_ix| {
-                                                    table
-                                                        .col_wrap(col_ix, cx) // Make the row scroll sync with the horizontal_scroll_handle to support horizontal scrolling.
-                                                        .left(horizontal_scroll_handle.offset().x)
-                                                        .child(
-                                                            table
-                                                                .render_cell(col_ix, cx)
-                                                                .flex_shrink_0()
-                                                                .child(
-                                                                    table.delegate.render_td(
-                                                                        row_ix, col_ix, cx,
-                                                                    ),
-                                                                ),
+                                                .when(table.checkable, |this| {
+                                                    this.child(table.render_check_td(row_ix, cx))
+                                                        .when(
+                                                            table.checked_rows.contains(&row_ix),
+                                                            |this| {
+                                                                this.bg(cx
+                                                                    .theme()
+                                                                    .table_active
+                                                                    .opacity(0.5))
+                                                            },
                                                         )
-                                                }))
-                                                .child(last_empty_col(cx))
+                                                })
+                                                .children(table.render_frozen_tds(row_ix, cx))
+                                                .child(table.render_tds(row_ix, cx))
                                                 // Row selected style
                                                 .when_some(
                                                     table.selected_row,
@@ -839,7 +1526,7 @@ where
                                                         this.when(
                                                             row_ix == selected_row
                                                                 && table.selection_state
-                                                                    == SelectionState::Row,
+                                                                    != SelectionState::Column,
                                                             |this| this.bg(cx.theme().table_active),
                                                         )
                                                     },
@@ -868,7 +1555,9 @@ where
         div()
             .size_full()
             .when(self.border, |this| {
-                this.rounded_md().border_1().border_color(cx.theme().border)
+                this.rounded_md()
+                    .border_1()
+                    .border_color(cx.theme().tokens.outline)
             })
             .bg(cx.theme().table)
             .child(inner_table)
@@ -886,3 +1575,32 @@ where
             })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use gpui::SharedString;
+
+    use super::find_type_ahead_row;
+
+    const NAMES: [&str; 5] = ["Alice", "Bob", "anna", "Brian", "Carol"];
+
+    fn find(query: &str, selected_row: Option<usize>) -> Option<usize> {
+        find_type_ahead_row(query, selected_row, NAMES.len(), |row_ix| {
+            Some(SharedString::from(NAMES[row_ix]))
+        })
+    }
+
+    #[test]
+    fn test_find_type_ahead_row() {
+        assert_eq!(find("b", None), Some(1));
+        assert_eq!(find("br", Some(1)), Some(3));
+        assert_eq!(find("C", Some(4)), Some(4));
+        assert_eq!(find("d", None), None);
+        assert_eq!(find("", None), None);
+
+        // Repeat the same character to cycle the rows, wrapping around.
+        assert_eq!(find("a", Some(0)), Some(2));
+        assert_eq!(find("aa", Some(2)), Some(0));
+        assert_eq!(find("ann", Some(0)), Some(2));
+    }
+}
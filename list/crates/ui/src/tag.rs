@@ -0,0 +1,478 @@
+use std::rc::Rc;
+
+use gpui::{
+    div, prelude::FluentBuilder as _, px, relative, AppContext, ClickEvent, Div, ElementId,
+    EventEmitter, FocusHandle, FocusableView, Hsla, InteractiveElement as _, IntoElement,
+    ParentElement as _, Render, RenderOnce, SharedString, StatefulInteractiveElement as _, Styled,
+    View, ViewContext, VisualContext as _, WindowContext,
+};
+
+use crate::{
+    avatar::Avatar,
+    h_flex,
+    input::{Backspace, InputEvent, TextInput},
+    inspector::InspectorExt as _,
+    theme::ActiveTheme as _,
+    Icon, IconName, Sizable, Size,
+};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TagVariant {
+    /// Filled with the color.
+    #[default]
+    Solid,
+    /// The border and the text in the color, without the background.
+    Outline,
+}
+
+/// Returns the background, the foreground and the border colors of the variant.
+///
+/// The `color` is used for the color-coded tags, the theme colors are used if it's `None`.
+fn variant_colors(
+    variant: TagVariant,
+    color: Option<Hsla>,
+    cx: &WindowContext,
+) -> (Hsla, Hsla, Hsla) {
+    let theme = cx.theme();
+    match (variant, color) {
+        (TagVariant::Solid, Some(color)) => (color, crate::white(), color),
+        (TagVariant::Solid, None) => (
+            theme.tokens.neutral,
+            theme.tokens.on_neutral,
+            theme.tokens.outline,
+        ),
+        (TagVariant::Outline, Some(color)) => (theme.transparent, color, color),
+        (TagVariant::Outline, None) => (
+            theme.transparent,
+            theme.tokens.on_surface,
+            theme.tokens.outline,
+        ),
+    }
+}
+
+/// Returns the label of a count, the count larger than the `max` is shown as `max+`.
+fn format_count(count: usize, max: usize) -> String {
+    if count > max {
+        format!("{}+", max)
+    } else {
+        count.to_string()
+    }
+}
+
+/// A small pill to show a status or a count, e.g.: `New`, `99+`.
+#[derive(IntoElement)]
+pub struct Badge {
+    base: Div,
+    label: SharedString,
+    variant: TagVariant,
+    color: Option<Hsla>,
+    size: Size,
+}
+
+impl Badge {
+    pub fn new(label: impl Into<SharedString>) -> Self {
+        Self {
+            base: div(),
+            label: label.into(),
+            variant: TagVariant::default(),
+            color: None,
+            size: Size::Medium,
+        }
+    }
+
+    /// Create a badge of a count, the count larger than the `max` is shown as `max+`.
+    pub fn count(count: usize, max: usize) -> Self {
+        Self::new(format_count(count, max))
+    }
+
+    pub fn variant(mut self, variant: TagVariant) -> Self {
+        self.variant = variant;
+        self
+    }
+
+    pub fn outline(self) -> Self {
+        self.variant(TagVariant::Outline)
+    }
+
+    /// Set the color of the badge, default is the secondary color of the theme.
+    pub fn color(mut self, color: impl Into<Hsla>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+}
+
+impl Sizable for Badge {
+    fn with_size(mut self, size: impl Into<Size>) -> Self {
+        self.size = size.into();
+        self
+    }
+}
+
+impl Styled for Badge {
+    fn style(&mut self) -> &mut gpui::StyleRefinement {
+        self.base.style()
+    }
+}
+
+impl RenderOnce for Badge {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let (bg, fg, border) = variant_colors(self.variant, self.color, cx);
+
+        self.base
+            .flex()
+            .flex_none()
+            .items_center()
+            .justify_center()
+            .rounded_full()
+            .border_1()
+            .border_color(border)
+            .bg(bg)
+            .text_color(fg)
+            .line_height(relative(1.))
+            .map(|this| match self.size {
+                Size::XSmall | Size::Small => this.h_4().min_w_4().px_1().text_size(px(10.)),
+                Size::Large => this.h_6().min_w_6().px_2().text_sm(),
+                _ => this.h_5().min_w_5().px_1p5().text_xs(),
+            })
+            .child(self.label)
+            .inspect("Badge", cx)
+    }
+}
+
+/// A label of a category or a keyword, with an optional close button to remove it.
+///
+/// Use [`Tag::chip`] for the rounded one, e.g.: a filter or an user with the [`Avatar`].
+#[derive(IntoElement)]
+pub struct Tag {
+    base: Div,
+    id: ElementId,
+    label: SharedString,
+    icon: Option<Icon>,
+    avatar: Option<Avatar>,
+    variant: TagVariant,
+    color: Option<Hsla>,
+    size: Size,
+    chip: bool,
+    on_remove: Option<Rc<dyn Fn(&ClickEvent, &mut WindowContext)>>,
+}
+
+impl Tag {
+    pub fn new(id: impl Into<ElementId>, label: impl Into<SharedString>) -> Self {
+        Self {
+            base: div(),
+            id: id.into(),
+            label: label.into(),
+            icon: None,
+            avatar: None,
+            variant: TagVariant::default(),
+            color: None,
+            size: Size::Medium,
+            chip: false,
+            on_remove: None,
+        }
+    }
+
+    /// Create a fully rounded tag.
+    pub fn chip(id: impl Into<ElementId>, label: impl Into<SharedString>) -> Self {
+        Self {
+            chip: true,
+            ..Self::new(id, label)
+        }
+    }
+
+    pub fn variant(mut self, variant: TagVariant) -> Self {
+        self.variant = variant;
+        self
+    }
+
+    pub fn outline(self) -> Self {
+        self.variant(TagVariant::Outline)
+    }
+
+    /// Set the color of the tag, default is the secondary color of the theme.
+    pub fn color(mut self, color: impl Into<Hsla>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Set the icon before the label.
+    pub fn icon(mut self, icon: impl Into<Icon>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Set the avatar before the label, it's resized to fit the tag.
+    pub fn avatar(mut self, avatar: Avatar) -> Self {
+        self.avatar = Some(avatar);
+        self
+    }
+
+    /// Show a close button, the `handler` is called when it's clicked.
+    pub fn on_remove(
+        mut self,
+        handler: impl Fn(&ClickEvent, &mut WindowContext) + 'static,
+    ) -> Self {
+        self.on_remove = Some(Rc::new(handler));
+        self
+    }
+}
+
+impl Sizable for Tag {
+    fn with_size(mut self, size: impl Into<Size>) -> Self {
+        self.size = size.into();
+        self
+    }
+}
+
+impl Styled for Tag {
+    fn style(&mut self) -> &mut gpui::StyleRefinement {
+        self.base.style()
+    }
+}
+
+impl RenderOnce for Tag {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let (bg, fg, border) = variant_colors(self.variant, self.color, cx);
+        let (height, icon_size) = match self.size {
+            Size::XSmall | Size::Small => (px(20.), Size::XSmall),
+            Size::Large => (px(28.), Size::Medium),
+            _ => (px(24.), Size::Small),
+        };
+
+        self.base.child(
+            h_flex()
+                .id(self.id)
+                .flex_none()
+                .h(height)
+                .gap_1()
+                .px(height * 0.3)
+                .border_1()
+                .border_color(border)
+                .bg(bg)
+                .text_color(fg)
+                .map(|this| match self.size {
+                    Size::Large => this.text_sm(),
+                    _ => this.text_xs(),
+                })
+                .map(|this| {
+                    if self.chip {
+                        this.rounded_full()
+                    } else {
+                        this.rounded_md()
+                    }
+                })
+                .when_some(self.avatar, |this, avatar| {
+                    // Align the avatar to the rounded edge of the chip.
+                    this.pl(px(2.)).child(avatar.with_size(height - px(6.)))
+                })
+                .when_some(self.icon, |this, icon| {
+                    this.child(icon.with_size(icon_size).text_color(fg))
+                })
+                .child(self.label)
+                .when_some(self.on_remove, |this, on_remove| {
+                    this.child(
+                        div()
+                            .id("remove")
+                            .flex_none()
+                            .rounded_full()
+                            .cursor_pointer()
+                            .opacity(0.6)
+                            .hover(|this| this.opacity(1.))
+                            .child(
+                                Icon::new(IconName::Close)
+                                    .with_size(icon_size)
+                                    .text_color(fg),
+                            )
+                            .on_click(move |event, cx| {
+                                cx.stop_propagation();
+                                on_remove(event, cx)
+                            }),
+                    )
+                })
+                .inspect("Tag", cx),
+        )
+    }
+}
+
+/// Returns the trimmed tag to add, `None` if it's empty or already in the `tags`.
+fn normalize_tag(text: &str, tags: &[SharedString]) -> Option<SharedString> {
+    let text = text.trim();
+    if text.is_empty() || tags.iter().any(|tag| tag.as_ref() == text) {
+        return None;
+    }
+    Some(text.to_string().into())
+}
+
+pub enum TagInputEvent {
+    /// A tag is added by pressing `enter`.
+    Add(SharedString),
+    /// A tag is removed by the close button of the chip, or `backspace` in the empty input.
+    Remove(SharedString),
+}
+
+/// A text input that manages a list of chips, press `enter` to add the text as a chip.
+pub struct TagInput {
+    input: View<TextInput>,
+    tags: Vec<SharedString>,
+    size: Size,
+}
+
+impl TagInput {
+    pub fn new(cx: &mut ViewContext<Self>) -> Self {
+        let input = cx.new_view(|cx| TextInput::new(cx).appearance(false));
+        cx.subscribe(&input, Self::on_input_event).detach();
+
+        Self {
+            input,
+            tags: Vec::new(),
+            size: Size::Medium,
+        }
+    }
+
+    /// Set the placeholder of the input.
+    pub fn placeholder(
+        self,
+        placeholder: impl Into<SharedString>,
+        cx: &mut ViewContext<Self>,
+    ) -> Self {
+        self.input
+            .update(cx, |input, _| input.set_placeholder(placeholder));
+        self
+    }
+
+    pub fn tags(&self) -> &[SharedString] {
+        &self.tags
+    }
+
+    /// Replace the tags, the empty and the duplicate ones are skipped.
+    pub fn set_tags(
+        &mut self,
+        tags: impl IntoIterator<Item = impl Into<SharedString>>,
+        cx: &mut ViewContext<Self>,
+    ) {
+        self.tags.clear();
+        for tag in tags {
+            let tag = tag.into();
+            if let Some(tag) = normalize_tag(&tag, &self.tags) {
+                self.tags.push(tag);
+            }
+        }
+        cx.notify();
+    }
+
+    /// Add a tag, returns false if it's empty or already added.
+    pub fn add_tag(&mut self, tag: impl Into<SharedString>, cx: &mut ViewContext<Self>) -> bool {
+        let tag: SharedString = tag.into();
+        let Some(tag) = normalize_tag(&tag, &self.tags) else {
+            return false;
+        };
+
+        self.tags.push(tag.clone());
+        cx.emit(TagInputEvent::Add(tag));
+        cx.notify();
+        true
+    }
+
+    pub fn remove_tag(&mut self, ix: usize, cx: &mut ViewContext<Self>) {
+        if ix >= self.tags.len() {
+            return;
+        }
+
+        let tag = self.tags.remove(ix);
+        cx.emit(TagInputEvent::Remove(tag));
+        cx.notify();
+    }
+
+    fn on_input_event(
+        &mut self,
+        input: View<TextInput>,
+        event: &InputEvent,
+        cx: &mut ViewContext<Self>,
+    ) {
+        if let InputEvent::PressEnter = event {
+            let text = input.read(cx).text();
+            if self.add_tag(text, cx) {
+                input.update(cx, |input, cx| input.set_text("", cx));
+            }
+        }
+    }
+
+    /// Remove the last tag by `backspace` if the input is empty, before the input handles it.
+    fn backspace(&mut self, _: &Backspace, cx: &mut ViewContext<Self>) {
+        if self.tags.is_empty() || !self.input.read(cx).text().is_empty() {
+            return;
+        }
+
+        cx.stop_propagation();
+        self.remove_tag(self.tags.len() - 1, cx);
+    }
+}
+
+impl EventEmitter<TagInputEvent> for TagInput {}
+
+impl Sizable for TagInput {
+    fn with_size(mut self, size: impl Into<Size>) -> Self {
+        self.size = size.into();
+        self
+    }
+}
+
+impl FocusableView for TagInput {
+    fn focus_handle(&self, cx: &AppContext) -> FocusHandle {
+        self.input.focus_handle(cx)
+    }
+}
+
+impl Render for TagInput {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let focused = self.input.focus_handle(cx).is_focused(cx);
+
+        h_flex()
+            .id("tag-input")
+            .capture_action(cx.listener(Self::backspace))
+            .w_full()
+            .flex_wrap()
+            .gap_1()
+            .px_1()
+            .py(px(3.))
+            .border_1()
+            .border_color(if focused {
+                cx.theme().tokens.focus
+            } else {
+                cx.theme().input
+            })
+            .rounded_md()
+            .bg(cx.theme().tokens.surface)
+            .cursor_text()
+            .on_click(cx.listener(|this, _, cx| this.input.focus_handle(cx).focus(cx)))
+            .children(self.tags.iter().enumerate().map(|(ix, tag)| {
+                Tag::chip(("tag", ix), tag.clone())
+                    .with_size(self.size)
+                    .on_remove(cx.listener(move |this, _, cx| this.remove_tag(ix, cx)))
+            }))
+            .child(div().flex_1().min_w(px(80.)).child(self.input.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::SharedString;
+
+    use super::{format_count, normalize_tag};
+
+    #[test]
+    fn test_format_count() {
+        assert_eq!(format_count(0, 99), "0");
+        assert_eq!(format_count(99, 99), "99");
+        assert_eq!(format_count(100, 99), "99+");
+    }
+
+    #[test]
+    fn test_normalize_tag() {
+        let tags: Vec<SharedString> = vec!["rust".into(), "gpui".into()];
+        assert_eq!(normalize_tag("  ui ", &tags), Some("ui".into()));
+        assert_eq!(normalize_tag("rust", &tags), None);
+        assert_eq!(normalize_tag(" gpui", &tags), None);
+        assert_eq!(normalize_tag("   ", &tags), None);
+    }
+}
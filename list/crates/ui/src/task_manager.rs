@@ -0,0 +1,195 @@
+use std::{
+    future::Future,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+use gpui::{
+    AppContext, Context as _, EventEmitter, Global, Model, ModelContext, SharedString, Task,
+};
+
+/// The id of a task in the [`TaskManager`].
+pub type TaskId = usize;
+
+/// The cancellation token and the progress of a task, it's passed to the task to report the progress.
+///
+/// The task is dropped when it's cancelled, so the `async` code stops at the next `await`,
+/// the long running loop in the background thread should check [`TaskHandle::is_cancelled`].
+#[derive(Debug, Clone, Default)]
+pub struct TaskHandle {
+    cancelled: Arc<AtomicBool>,
+    /// The progress in range of 0.0..100.0, `None` if it is unknown.
+    progress: Arc<Mutex<Option<f32>>>,
+}
+
+impl TaskHandle {
+    /// Returns true if the task has been cancelled by the [`TaskManager::cancel`].
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+    }
+
+    /// Set the progress of the task, range of 0.0..100.0
+    pub fn set_progress(&self, value: f32) {
+        if let Ok(mut progress) = self.progress.lock() {
+            *progress = Some(value.clamp(0., 100.));
+        }
+    }
+
+    /// Returns the progress of the task, `None` if it is not reported.
+    pub fn progress(&self) -> Option<f32> {
+        self.progress.lock().ok().and_then(|progress| *progress)
+    }
+
+    fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+    }
+}
+
+/// A running task tracked by the [`TaskManager`].
+pub struct BackgroundTask {
+    pub id: TaskId,
+    pub name: SharedString,
+    started_at: Instant,
+    handle: TaskHandle,
+    task: Task<()>,
+}
+
+impl BackgroundTask {
+    /// Returns the progress of the task, `None` if it is unknown.
+    pub fn progress(&self) -> Option<f32> {
+        self.handle.progress()
+    }
+
+    /// Returns the time since the task was spawned.
+    pub fn elapsed(&self) -> Duration {
+        self.started_at.elapsed()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TaskEvent {
+    Finished(TaskId),
+    Cancelled(TaskId),
+}
+
+/// Tracks the background tasks with the names and the progress, to show them and cancel them.
+///
+/// It's a global model, use [`TaskManager::global`] to spawn the tasks and observe the changes.
+pub struct TaskManager {
+    tasks: Vec<BackgroundTask>,
+    next_id: TaskId,
+}
+
+struct GlobalTaskManager(Model<TaskManager>);
+
+impl Global for GlobalTaskManager {}
+
+impl EventEmitter<TaskEvent> for TaskManager {}
+
+pub fn init(cx: &mut AppContext) {
+    let task_manager = cx.new_model(|_| TaskManager::new());
+    cx.set_global(GlobalTaskManager(task_manager));
+}
+
+impl TaskManager {
+    fn new() -> Self {
+        Self {
+            tasks: Vec::new(),
+            next_id: 0,
+        }
+    }
+
+    /// Returns the global TaskManager.
+    pub fn global(cx: &AppContext) -> Model<Self> {
+        cx.global::<GlobalTaskManager>().0.clone()
+    }
+
+    /// Returns the running tasks, the oldest is the first.
+    pub fn tasks(&self) -> &[BackgroundTask] {
+        &self.tasks
+    }
+
+    /// Spawn the future returned by `f`, and track it until it's finished or cancelled.
+    ///
+    /// The `f` is called with the [`TaskHandle`] of the task, to report the progress.
+    pub fn spawn<F, Fut>(
+        &mut self,
+        name: impl Into<SharedString>,
+        f: F,
+        cx: &mut ModelContext<Self>,
+    ) -> TaskId
+    where
+        F: FnOnce(TaskHandle) -> Fut,
+        Fut: Future<Output = ()> + 'static,
+    {
+        let id = self.next_id;
+        self.next_id += 1;
+
+        let handle = TaskHandle::default();
+        let future = f(handle.clone());
+        let task = cx.spawn(|this, mut cx| async move {
+            future.await;
+            let _ = this.update(&mut cx, |this, cx| this.finish(id, cx));
+        });
+
+        self.tasks.push(BackgroundTask {
+            id,
+            name: name.into(),
+            started_at: Instant::now(),
+            handle,
+            task,
+        });
+        cx.notify();
+        id
+    }
+
+    /// Cancel the task by dropping it, returns false if the task is already finished.
+    pub fn cancel(&mut self, id: TaskId, cx: &mut ModelContext<Self>) -> bool {
+        let Some(ix) = self.tasks.iter().position(|task| task.id == id) else {
+            return false;
+        };
+
+        let task = self.tasks.remove(ix);
+        task.handle.cancel();
+        drop(task);
+        cx.emit(TaskEvent::Cancelled(id));
+        cx.notify();
+        true
+    }
+
+    fn finish(&mut self, id: TaskId, cx: &mut ModelContext<Self>) {
+        let Some(ix) = self.tasks.iter().position(|task| task.id == id) else {
+            return;
+        };
+
+        // The task is still running this callback, so detach it instead of dropping.
+        self.tasks.remove(ix).task.detach();
+        cx.emit(TaskEvent::Finished(id));
+        cx.notify();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TaskHandle;
+
+    #[test]
+    fn test_task_handle() {
+        let handle = TaskHandle::default();
+        assert!(!handle.is_cancelled());
+        assert_eq!(handle.progress(), None);
+
+        handle.set_progress(42.);
+        assert_eq!(handle.clone().progress(), Some(42.));
+        handle.set_progress(120.);
+        assert_eq!(handle.progress(), Some(100.));
+        handle.set_progress(-1.);
+        assert_eq!(handle.progress(), Some(0.));
+
+        handle.clone().cancel();
+        assert!(handle.is_cancelled());
+    }
+}
@@ -0,0 +1,912 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    ops::Deref,
+};
+
+use anyhow::{anyhow, Result};
+use gpui::{
+    actions, hsla, point, px, AnyWindowHandle, AppContext, BoxShadow, Global, Hsla, ModelContext,
+    Pixels, SharedString, ViewContext, WindowAppearance, WindowContext,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{scroll::ScrollbarShow, ColorExt as _};
+
+mod overrides;
+mod palette;
+mod tokens;
+
+use overrides::ComponentStyleJson;
+pub use overrides::{ComponentKind, ComponentStyle, StyleOverrides, StyleOverridesExt};
+pub use palette::{SeedPalette, CHART_COLORS};
+pub use tokens::ThemeTokens;
+use tokens::{shift_lightness, state_colors};
+
+actions!(theme, [ZoomIn, ZoomOut, ResetZoom]);
+
+pub trait ActiveTheme {
+    fn theme(&self) -> &Theme;
+}
+
+impl ActiveTheme for AppContext {
+    fn theme(&self) -> &Theme {
+        Theme::get_global(self)
+    }
+}
+
+impl<'a, V> ActiveTheme for ViewContext<'a, V> {
+    fn theme(&self) -> &Theme {
+        self.deref().theme()
+    }
+}
+
+impl<'a, V> ActiveTheme for ModelContext<'a, V> {
+    fn theme(&self) -> &Theme {
+        self.deref().theme()
+    }
+}
+
+impl<'a> ActiveTheme for WindowContext<'a> {
+    /// Returns the theme of the window if it's overridden by [`Theme::set_window_theme`].
+    fn theme(&self) -> &Theme {
+        Theme::window_theme(self).unwrap_or_else(|| self.deref().theme())
+    }
+}
+
+/// Make a [gpui::Hsla] color.
+///
+/// - h: 0..360.0
+/// - s: 0.0..100.0
+/// - l: 0.0..100.0
+pub fn hsl(h: f32, s: f32, l: f32) -> Hsla {
+    hsla(h / 360., s / 100.0, l / 100.0, 1.0)
+}
+
+/// Make a BoxShadow like CSS
+///
+/// e.g:
+///
+/// If CSS is `box-shadow: 0 0 10px 0 rgba(0, 0, 0, 0.1);`
+///
+/// Then the equivalent in Rust is `box_shadow(0., 0., 10., 0., hsla(0., 0., 0., 0.1))`
+pub fn box_shadow(
+    x: impl Into<Pixels>,
+    y: impl Into<Pixels>,
+    blur: impl Into<Pixels>,
+    spread: impl Into<Pixels>,
+    color: Hsla,
+) -> BoxShadow {
+    BoxShadow {
+        offset: point(x.into(), y.into()),
+        blur_radius: blur.into(),
+        spread_radius: spread.into(),
+        color,
+    }
+}
+pub trait Colorize {
+    fn opacity(&self, opacity: f32) -> Hsla;
+    fn divide(&self, divisor: f32) -> Hsla;
+    fn invert(&self) -> Hsla;
+    fn invert_l(&self) -> Hsla;
+    fn lighten(&self, amount: f32) -> Hsla;
+    fn darken(&self, amount: f32) -> Hsla;
+}
+
+impl Colorize for Hsla {
+    /// Returns a new color with the given opacity.
+    ///
+    /// The opacity is a value between 0.0 and 1.0, where 0.0 is fully transparent and 1.0 is fully opaque.
+    fn opacity(&self, factor: f32) -> Hsla {
+        Hsla {
+            a: self.a * factor.clamp(0.0, 1.0),
+            ..*self
+        }
+    }
+
+    /// Returns a new color with each channel divided by the given divisor.
+    ///
+    /// The divisor in range of 0.0 .. 1.0
+    fn divide(&self, divisor: f32) -> Hsla {
+        Hsla {
+            a: divisor,
+            ..*self
+        }
+    }
+
+    /// Return inverted color
+    fn invert(&self) -> Hsla {
+        Hsla {
+            h: (self.h + 1.8) % 3.6,
+            s: 1.0 - self.s,
+            l: 1.0 - self.l,
+            a: self.a,
+        }
+    }
+
+    /// Return inverted lightness
+    fn invert_l(&self) -> Hsla {
+        Hsla {
+            l: 1.0 - self.l,
+            ..*self
+        }
+    }
+
+    /// Return a new color with the lightness increased by the given factor.
+    fn lighten(&self, factor: f32) -> Hsla {
+        let l = (self.l * 1.0 - factor.clamp(0.0, 1.0)).min(1.0);
+
+        Hsla { l, ..*self }
+    }
+
+    /// Return a new color with the darkness increased by the given factor.
+    fn darken(&self, factor: f32) -> Hsla {
+        let l = (self.l * 1.0 - factor.clamp(0.0, 1.0)).max(0.0);
+
+        Hsla { l, ..*self }
+    }
+}
+
+/// The theme of the application.
+///
+/// The components use the semantic [`ThemeTokens`], the other colors are resolved from the tokens
+/// for the mode by [`Theme::resolve`], so a custom theme only needs to define the tokens.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Theme {
+    pub mode: ThemeMode,
+    pub tokens: ThemeTokens,
+    pub transparent: Hsla,
+    pub title_bar_background: Hsla,
+    /// Basic font size
+    pub font_size: f32,
+    /// The scale of the UI, multiplies the rem size of the windows, see [`Theme::rem_size`].
+    ///
+    /// It's a preference of the user rather than a part of the theme, so it's kept when the theme is changed.
+    pub scale: f32,
+    pub font_family: SharedString,
+    pub accent_hover: Hsla,
+    pub accent_active: Hsla,
+    pub neutral_hover: Hsla,
+    pub neutral_active: Hsla,
+    pub danger_hover: Hsla,
+    pub danger_active: Hsla,
+    pub input: Hsla,
+    pub scrollbar: Hsla,
+    pub scrollbar_thumb: Hsla,
+    pub panel: Hsla,
+    pub drag_border: Hsla,
+    pub drop_target: Hsla,
+    pub radius: f32,
+    /// The mode to show the scrollbars.
+    pub scrollbar_show: ScrollbarShow,
+    /// The styles of the components that override their default styles.
+    pub style_overrides: StyleOverrides,
+    pub tab_bar: Hsla,
+    pub tab: Hsla,
+    pub tab_active: Hsla,
+    pub tab_foreground: Hsla,
+    pub tab_active_foreground: Hsla,
+    pub progress_bar: Hsla,
+    pub slider_bar: Hsla,
+    pub slider_thumb: Hsla,
+    pub list: Hsla,
+    pub list_even: Hsla,
+    pub list_head: Hsla,
+    pub list_active: Hsla,
+    pub list_hover: Hsla,
+    pub table: Hsla,
+    pub table_even: Hsla,
+    pub table_head: Hsla,
+    pub table_active: Hsla,
+    pub table_hover: Hsla,
+    pub link_hover: Hsla,
+    pub link_active: Hsla,
+    pub skeleton: Hsla,
+    /// The default colors of the chart series, see [`crate::chart::chart_color`].
+    pub chart_1: Hsla,
+    pub chart_2: Hsla,
+    pub chart_3: Hsla,
+    pub chart_4: Hsla,
+    pub chart_5: Hsla,
+    pub chart_6: Hsla,
+    pub chart_7: Hsla,
+    pub chart_8: Hsla,
+}
+
+impl Global for Theme {}
+
+/// The themes of the windows that override the global [`Theme`].
+#[derive(Default)]
+struct WindowThemes(HashMap<AnyWindowHandle, Theme>);
+
+impl Global for WindowThemes {}
+
+impl Theme {
+    pub fn get_global(cx: &AppContext) -> &Self {
+        cx.global::<Self>()
+    }
+
+    /// Create a theme from the tokens, the other colors are resolved for the mode.
+    pub fn from_tokens(tokens: ThemeTokens, mode: ThemeMode) -> Self {
+        let transparent = Hsla::transparent_black();
+        let mut theme = Theme {
+            mode,
+            tokens,
+            transparent,
+            font_size: 14.0,
+            scale: 1.0,
+            font_family: if cfg!(target_os = "macos") {
+                ".SystemUIFont".into()
+            } else if cfg!(target_os = "windows") {
+                "Segoe UI".into()
+            } else {
+                "FreeMono".into()
+            },
+            radius: 4.0,
+            scrollbar_show: ScrollbarShow::default(),
+            style_overrides: StyleOverrides::default(),
+            title_bar_background: transparent,
+            accent_hover: transparent,
+            accent_active: transparent,
+            neutral_hover: transparent,
+            neutral_active: transparent,
+            danger_hover: transparent,
+            danger_active: transparent,
+            input: transparent,
+            scrollbar: transparent,
+            scrollbar_thumb: transparent,
+            panel: transparent,
+            drag_border: transparent,
+            drop_target: transparent,
+            tab_bar: transparent,
+            tab: transparent,
+            tab_active: transparent,
+            tab_foreground: transparent,
+            tab_active_foreground: transparent,
+            progress_bar: transparent,
+            slider_bar: transparent,
+            slider_thumb: transparent,
+            list: transparent,
+            list_even: transparent,
+            list_head: transparent,
+            list_active: transparent,
+            list_hover: transparent,
+            table: transparent,
+            table_even: transparent,
+            table_head: transparent,
+            table_active: transparent,
+            table_hover: transparent,
+            link_hover: transparent,
+            link_active: transparent,
+            skeleton: transparent,
+            chart_1: transparent,
+            chart_2: transparent,
+            chart_3: transparent,
+            chart_4: transparent,
+            chart_5: transparent,
+            chart_6: transparent,
+            chart_7: transparent,
+            chart_8: transparent,
+        };
+        theme.resolve();
+        theme
+    }
+
+    /// Resolve the colors of the components from the [`Theme::tokens`] for the [`Theme::mode`].
+    ///
+    /// The changed colors are overwritten, call it after the tokens are changed.
+    pub fn resolve(&mut self) {
+        let tokens = self.tokens;
+        let is_dark = self.mode.is_dark();
+
+        (self.accent_hover, self.accent_active) =
+            state_colors(tokens.accent, tokens.on_accent, 0.1, 0.2);
+        (self.neutral_hover, self.neutral_active) =
+            state_colors(tokens.neutral, tokens.on_neutral, 0.07, 0.14);
+        (self.danger_hover, self.danger_active) =
+            state_colors(tokens.danger, tokens.on_danger, 0.05, 0.1);
+
+        self.title_bar_background = if is_dark {
+            tokens.surface_raised
+        } else {
+            tokens.surface
+        };
+        self.input = tokens.outline;
+        self.scrollbar = tokens.surface_muted.opacity(0.7);
+        self.scrollbar_thumb = tokens.on_surface_muted.opacity(0.6);
+        self.panel = tokens.surface_raised;
+        self.drag_border = crate::blue_500();
+        self.drop_target = tokens.link.opacity(if is_dark { 0.15 } else { 0.25 });
+
+        self.tab_bar = if is_dark {
+            tokens.surface_raised
+        } else {
+            tokens.surface_muted
+        };
+        self.tab = gpui::transparent_black();
+        self.tab_active = tokens.surface;
+        self.tab_foreground = tokens.on_surface;
+        self.tab_active_foreground = tokens.on_surface;
+
+        self.progress_bar = tokens.accent;
+        self.slider_bar = tokens.accent;
+        self.slider_thumb = tokens.surface;
+
+        self.list = tokens.surface;
+        self.list_even = shift_lightness(tokens.surface, if is_dark { 0.02 } else { -0.04 });
+        self.list_head = tokens.surface;
+        self.list_active = shift_lightness(tokens.surface_muted, if is_dark { 0. } else { -0.08 });
+        self.list_hover = self.list_active.opacity(0.6);
+        self.table = self.list;
+        self.table_even = self.list_even;
+        self.table_head = self.list_head;
+        self.table_active = self.list_active;
+        self.table_hover = self.list_active.opacity(0.8);
+
+        self.link_hover = shift_lightness(tokens.link, 0.1);
+        self.link_active = shift_lightness(tokens.link, -0.1);
+        self.skeleton = tokens.accent.opacity(0.1);
+
+        // Lighter in the dark theme.
+        (
+            self.chart_1,
+            self.chart_2,
+            self.chart_3,
+            self.chart_4,
+            self.chart_5,
+            self.chart_6,
+            self.chart_7,
+            self.chart_8,
+        ) = if is_dark {
+            (
+                crate::blue_400(),
+                crate::emerald_400(),
+                crate::amber_400(),
+                crate::rose_400(),
+                crate::violet_400(),
+                crate::cyan_400(),
+                crate::orange_400(),
+                crate::pink_400(),
+            )
+        } else {
+            (
+                crate::blue_500(),
+                crate::emerald_500(),
+                crate::amber_500(),
+                crate::rose_500(),
+                crate::violet_500(),
+                crate::cyan_500(),
+                crate::orange_500(),
+                crate::pink_500(),
+            )
+        };
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ThemeMode {
+    Light,
+    Dark,
+}
+
+/// The theme mode chosen by the user.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ThemeModeSetting {
+    Light,
+    Dark,
+    /// Follow the system appearance.
+    #[default]
+    System,
+    /// The light theme with the high contrast colors, see [`Theme::high_contrast`].
+    HighContrastLight,
+    /// The dark theme with the high contrast colors, see [`Theme::high_contrast`].
+    HighContrastDark,
+}
+
+impl Global for ThemeModeSetting {}
+
+impl ThemeModeSetting {
+    pub fn is_high_contrast(&self) -> bool {
+        matches!(self, Self::HighContrastLight | Self::HighContrastDark)
+    }
+}
+
+impl ThemeMode {
+    pub fn is_dark(&self) -> bool {
+        matches!(self, Self::Dark)
+    }
+}
+
+macro_rules! theme_colors {
+    ($($name:ident),* $(,)?) => {
+        impl Theme {
+            /// Returns all the colors of the theme with the field name, the tokens first,
+            /// e.g.: `("accent", theme.tokens.accent)`, `("accent_hover", theme.accent_hover)`.
+            pub fn colors(&self) -> Vec<(&'static str, Hsla)> {
+                let mut colors = self.tokens.colors();
+                colors.extend([$((stringify!($name), self.$name)),*]);
+                colors
+            }
+
+            /// Set the color by the field name, returns `false` if the name is not a theme color.
+            ///
+            /// Setting a token resolves the other colors again, see [`Theme::resolve`].
+            pub fn set_color(&mut self, name: &str, color: Hsla) -> bool {
+                if self.tokens.set_color(name, color) {
+                    self.resolve();
+                    return true;
+                }
+
+                match name {
+                    $(stringify!($name) => self.$name = color,)*
+                    _ => return false,
+                }
+                true
+            }
+        }
+    };
+}
+
+theme_colors!(
+    title_bar_background,
+    accent_hover,
+    accent_active,
+    neutral_hover,
+    neutral_active,
+    danger_hover,
+    danger_active,
+    input,
+    scrollbar,
+    scrollbar_thumb,
+    panel,
+    drag_border,
+    drop_target,
+    tab_bar,
+    tab,
+    tab_active,
+    tab_foreground,
+    tab_active_foreground,
+    progress_bar,
+    slider_bar,
+    slider_thumb,
+    list,
+    list_even,
+    list_head,
+    list_active,
+    list_hover,
+    table,
+    table_even,
+    table_head,
+    table_active,
+    table_hover,
+    link_hover,
+    link_active,
+    skeleton,
+    chart_1,
+    chart_2,
+    chart_3,
+    chart_4,
+    chart_5,
+    chart_6,
+    chart_7,
+    chart_8,
+);
+
+/// Returns the current name of a color renamed by the semantic tokens,
+/// to load the themes exported before them.
+fn legacy_color_name(name: &str) -> Option<&'static str> {
+    Some(match name {
+        "background" => "surface",
+        "foreground" => "on_surface",
+        "card" => "surface_raised",
+        "card_foreground" => "on_surface_raised",
+        "popover" => "overlay",
+        "popover_foreground" => "on_overlay",
+        "muted" => "surface_muted",
+        "muted_foreground" => "on_surface_muted",
+        "primary" => "accent",
+        "primary_hover" => "accent_hover",
+        "primary_active" => "accent_active",
+        "primary_foreground" => "on_accent",
+        "secondary" => "neutral",
+        "secondary_hover" => "neutral_hover",
+        "secondary_active" => "neutral_active",
+        "secondary_foreground" => "on_neutral",
+        "accent" => "highlight",
+        "accent_foreground" => "on_highlight",
+        "destructive" => "danger",
+        "destructive_hover" => "danger_hover",
+        "destructive_active" => "danger_active",
+        "destructive_foreground" => "on_danger",
+        "border" => "outline",
+        "ring" => "focus",
+        _ => return None,
+    })
+}
+
+/// The JSON format of a [`Theme`], the colors are saved as hex strings, e.g.: `#3B82F6`.
+#[derive(Debug, Serialize, Deserialize)]
+struct ThemeJson {
+    mode: ThemeMode,
+    #[serde(default)]
+    font_size: Option<f32>,
+    #[serde(default)]
+    font_family: Option<String>,
+    #[serde(default)]
+    radius: Option<f32>,
+    #[serde(default)]
+    scrollbar_show: Option<ScrollbarShow>,
+    /// The [`ThemeTokens`], the missing ones use the default tokens of the mode.
+    #[serde(default)]
+    tokens: BTreeMap<String, String>,
+    /// The colors that override the resolved colors.
+    ///
+    /// The themes exported before the tokens saved all the colors here, with the legacy names.
+    #[serde(default)]
+    colors: BTreeMap<String, String>,
+    /// The [`StyleOverrides`] of the components.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    components: BTreeMap<ComponentKind, ComponentStyleJson>,
+}
+
+impl Theme {
+    pub const MIN_SCALE: f32 = 0.5;
+    pub const MAX_SCALE: f32 = 2.0;
+    /// The step of the [`ZoomIn`] and [`ZoomOut`] actions.
+    pub const SCALE_STEP: f32 = 0.1;
+
+    /// Export the theme as a JSON string.
+    ///
+    /// Only the colors that differ from the resolved ones are saved besides the tokens.
+    pub fn to_json(&self) -> String {
+        let resolved = Theme::from_tokens(self.tokens, self.mode);
+        let tokens_len = self.tokens.colors().len();
+        let json = ThemeJson {
+            mode: self.mode,
+            font_size: Some(self.font_size),
+            font_family: Some(self.font_family.to_string()),
+            radius: Some(self.radius),
+            scrollbar_show: Some(self.scrollbar_show),
+            tokens: self
+                .tokens
+                .colors()
+                .into_iter()
+                .map(|(name, color)| (name.to_string(), color.to_hex_string()))
+                .collect(),
+            colors: self
+                .colors()
+                .into_iter()
+                .zip(resolved.colors())
+                .skip(tokens_len)
+                .filter(|((_, color), (_, resolved))| color != resolved)
+                .map(|((name, color), _)| (name.to_string(), color.to_hex_string()))
+                .collect(),
+            components: self
+                .style_overrides
+                .iter()
+                .map(|(kind, style)| (*kind, style.into()))
+                .collect(),
+        };
+
+        serde_json::to_string_pretty(&json).unwrap_or_default()
+    }
+
+    /// Load a theme from the JSON string that exported by [`Theme::to_json`].
+    ///
+    /// The missing tokens will use the default tokens of the theme mode,
+    /// then the other colors are resolved from the tokens, unless they are in the `colors`.
+    pub fn from_json(json: &str) -> Result<Self> {
+        let json: ThemeJson = serde_json::from_str(json)?;
+        let parse = |name: &str, hex: &str| {
+            Hsla::parse_hex_string(hex)
+                .map_err(|_| anyhow!("invalid color `{}` for `{}`", hex, name))
+        };
+
+        let mut tokens = ThemeTokens::for_mode(json.mode);
+        for (name, hex) in &json.tokens {
+            if !tokens.set_color(name, parse(name, hex)?) {
+                return Err(anyhow!("unknown theme token `{}`", name));
+            }
+        }
+        let mut overrides = vec![];
+        for (name, hex) in &json.colors {
+            let color = parse(name, hex)?;
+            let name = legacy_color_name(name).unwrap_or(name.as_str());
+            if !tokens.set_color(name, color) {
+                overrides.push((name, color));
+            }
+        }
+
+        let mut theme = Theme::from_tokens(tokens, json.mode);
+        if let Some(font_size) = json.font_size {
+            theme.font_size = font_size;
+        }
+        if let Some(font_family) = json.font_family {
+            theme.font_family = font_family.into();
+        }
+        if let Some(radius) = json.radius {
+            theme.radius = radius;
+        }
+        if let Some(scrollbar_show) = json.scrollbar_show {
+            theme.scrollbar_show = scrollbar_show;
+        }
+
+        for (name, color) in overrides {
+            if !theme.set_color(name, color) {
+                return Err(anyhow!("unknown theme color `{}`", name));
+            }
+        }
+        for (kind, style) in &json.components {
+            theme.style_overrides.set(*kind, style.to_style(*kind)?);
+        }
+
+        Ok(theme)
+    }
+
+    fn new() -> Self {
+        Self::for_mode(ThemeMode::Dark)
+    }
+
+    pub fn init(cx: &mut AppContext) {
+        cx.set_global(Theme::new());
+        cx.set_global(ThemeModeSetting::default());
+        Self::sync_system_appearance(cx);
+
+        cx.on_action(|_: &ZoomIn, cx: &mut AppContext| {
+            Self::set_scale(cx.theme().scale + Self::SCALE_STEP, cx)
+        });
+        cx.on_action(|_: &ZoomOut, cx: &mut AppContext| {
+            Self::set_scale(cx.theme().scale - Self::SCALE_STEP, cx)
+        });
+        cx.on_action(|_: &ResetZoom, cx: &mut AppContext| Self::set_scale(1.0, cx));
+    }
+
+    /// Sync the theme with the system appearance.
+    ///
+    /// This does nothing if a manual mode is chosen by [`Theme::set_mode_setting`].
+    pub fn sync_system_appearance(cx: &mut AppContext) {
+        if Self::mode_setting(cx) != ThemeModeSetting::System {
+            return;
+        }
+
+        match cx.window_appearance() {
+            WindowAppearance::Dark | WindowAppearance::VibrantDark => {
+                Self::change(ThemeMode::Dark, cx)
+            }
+            WindowAppearance::Light | WindowAppearance::VibrantLight => {
+                Self::change(ThemeMode::Light, cx)
+            }
+        }
+    }
+
+    /// Returns the chosen theme mode, default is [`ThemeModeSetting::System`].
+    pub fn mode_setting(cx: &AppContext) -> ThemeModeSetting {
+        cx.try_global::<ThemeModeSetting>()
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Choose the theme mode, the [`ThemeModeSetting::System`] will follow the system appearance.
+    pub fn set_mode_setting(setting: ThemeModeSetting, cx: &mut AppContext) {
+        cx.set_global(setting);
+        match setting {
+            ThemeModeSetting::Light => Self::change(ThemeMode::Light, cx),
+            ThemeModeSetting::Dark => Self::change(ThemeMode::Dark, cx),
+            ThemeModeSetting::System => Self::sync_system_appearance(cx),
+            ThemeModeSetting::HighContrastLight => {
+                Self::replace_mode(Self::high_contrast(ThemeMode::Light), cx)
+            }
+            ThemeModeSetting::HighContrastDark => {
+                Self::replace_mode(Self::high_contrast(ThemeMode::Dark), cx)
+            }
+        }
+    }
+
+    /// Create the default theme of the mode.
+    pub fn for_mode(mode: ThemeMode) -> Self {
+        Self::from_tokens(ThemeTokens::for_mode(mode), mode)
+    }
+
+    /// Create the high contrast theme of the mode, the text colors meet the WCAG AAA contrast ratio (7:1).
+    pub fn high_contrast(mode: ThemeMode) -> Self {
+        let mut theme = Self::from_tokens(ThemeTokens::high_contrast(mode), mode);
+        theme.drag_border = theme.tokens.focus;
+        theme
+    }
+
+    pub fn change(mode: ThemeMode, cx: &mut AppContext) {
+        Self::replace_mode(Self::for_mode(mode), cx);
+    }
+
+    /// Replace the global theme by the default theme of another mode,
+    /// the [`Theme::style_overrides`] of the current one are kept, they are not the colors of a mode.
+    fn replace_mode(mut theme: Theme, cx: &mut AppContext) {
+        if let Some(current) = cx.try_global::<Theme>() {
+            theme.style_overrides = current.style_overrides.clone();
+        }
+        Self::replace_global(theme, cx);
+    }
+
+    /// Replace the global theme, the [`Theme::scale`] of the current one is kept.
+    ///
+    /// The [`Theme::style_overrides`] are replaced by the ones of the theme, e.g.: to undo or load a theme.
+    pub fn replace_global(mut theme: Theme, cx: &mut AppContext) {
+        if let Some(current) = cx.try_global::<Theme>() {
+            theme.scale = current.scale;
+        }
+        cx.set_global(theme);
+        cx.refresh();
+    }
+
+    /// Set the [`Theme::scale`] of the global theme, clamped to [`Theme::MIN_SCALE`] and [`Theme::MAX_SCALE`].
+    pub fn set_scale(scale: f32, cx: &mut AppContext) {
+        // Round to the step to avoid the float errors after zooming several times, e.g.: 1.2000001
+        let scale = ((scale / Self::SCALE_STEP).round() * Self::SCALE_STEP)
+            .clamp(Self::MIN_SCALE, Self::MAX_SCALE);
+        if cx.theme().scale == scale {
+            return;
+        }
+
+        cx.global_mut::<Theme>().scale = scale;
+        cx.refresh();
+    }
+
+    /// Returns the rem size of the windows for the base font size, scaled by the [`Theme::scale`].
+    ///
+    /// All the components are sized in rems, so they are scaled together, use it with `cx.set_rem_size`.
+    pub fn rem_size(&self, base_font_size: f32) -> Pixels {
+        px((base_font_size * self.scale).round())
+    }
+
+    /// Returns the theme that overrides the global theme in the window.
+    pub fn window_theme<'a>(cx: &'a WindowContext) -> Option<&'a Self> {
+        cx.try_global::<WindowThemes>()?.0.get(&cx.window_handle())
+    }
+
+    /// Override the global theme in the current window, `None` to use the global theme again.
+    ///
+    /// The changes of the global theme, e.g.: [`Theme::change`], will not apply to the overridden window.
+    pub fn set_window_theme(theme: Option<Theme>, cx: &mut WindowContext) {
+        let window = cx.window_handle();
+        let themes = &mut cx.default_global::<WindowThemes>().0;
+        match theme {
+            Some(theme) => themes.insert(window, theme),
+            None => themes.remove(&window),
+        };
+        cx.refresh();
+    }
+
+    /// Remove the theme of the closed window.
+    pub(crate) fn remove_window_theme(window: AnyWindowHandle, cx: &mut AppContext) {
+        if let Some(themes) = cx.try_global::<WindowThemes>() {
+            if themes.0.contains_key(&window) {
+                cx.global_mut::<WindowThemes>().0.remove(&window);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ColorExt as _;
+
+    #[test]
+    fn test_high_contrast_ratios() {
+        for mode in [ThemeMode::Light, ThemeMode::Dark] {
+            let theme = Theme::high_contrast(mode);
+            let tokens = theme.tokens;
+            let texts = [
+                (tokens.on_surface, tokens.surface),
+                (tokens.on_surface_raised, tokens.surface_raised),
+                (tokens.on_overlay, tokens.overlay),
+                (tokens.on_accent, tokens.accent),
+                (tokens.on_accent, theme.accent_hover),
+                (tokens.on_accent, theme.accent_active),
+                (tokens.on_neutral, tokens.neutral),
+                (tokens.on_neutral, theme.neutral_hover),
+                (tokens.on_neutral, theme.neutral_active),
+                (tokens.on_danger, tokens.danger),
+                (tokens.on_danger, theme.danger_hover),
+                (tokens.on_danger, theme.danger_active),
+                (tokens.on_surface_muted, tokens.surface_muted),
+                (tokens.on_surface_muted, tokens.surface),
+                (tokens.on_highlight, tokens.highlight),
+                (theme.tab_foreground, theme.tab_bar),
+                (tokens.on_surface, theme.list_active),
+                (tokens.on_surface, tokens.selection),
+                (tokens.link, tokens.surface),
+            ];
+            for (fg, bg) in texts {
+                assert!(
+                    fg.contrast_ratio(bg) >= 7.,
+                    "{:?}: {:?} on {:?}",
+                    mode,
+                    fg,
+                    bg
+                );
+            }
+
+            for color in [tokens.outline, tokens.focus, tokens.accent] {
+                assert!(color.contrast_ratio(tokens.surface) >= 3.);
+            }
+        }
+    }
+
+    #[test]
+    fn test_json_tokens() {
+        let mut theme = Theme::for_mode(ThemeMode::Light);
+        theme.set_color("accent", hsl(220., 90., 50.));
+        theme.set_color("list_hover", hsl(0., 0., 50.));
+        assert_eq!(
+            theme.accent_hover,
+            Theme::from_tokens(theme.tokens, theme.mode).accent_hover
+        );
+
+        let loaded = Theme::from_json(&theme.to_json()).unwrap();
+        let hex = |colors: Vec<(&'static str, Hsla)>| {
+            colors
+                .into_iter()
+                .map(|(name, color)| (name, color.to_hex_string()))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(hex(loaded.tokens.colors()), hex(theme.tokens.colors()));
+        assert_eq!(
+            loaded.list_hover.to_hex_string(),
+            theme.list_hover.to_hex_string()
+        );
+
+        // The themes exported before the tokens.
+        let legacy = Theme::from_json(
+            r##"{ "mode": "dark", "colors": { "primary": "#FF0000", "accent": "#00FF00" } }"##,
+        )
+        .unwrap();
+        assert_eq!(legacy.tokens.accent.to_hex_string(), "#FF0000");
+        assert_eq!(legacy.tokens.highlight.to_hex_string(), "#00FF00");
+        assert!(Theme::from_json(r#"{ "mode": "dark", "tokens": { "primary": "#FF0000" } }"#).is_err());
+    }
+
+    #[test]
+    fn test_seed_palette() {
+        for mode in [ThemeMode::Light, ThemeMode::Dark] {
+            for seed in [hsl(220., 90., 50.), hsl(50., 100., 60.), hsl(0., 0., 10.)] {
+                let palette = SeedPalette::generate(seed, mode);
+                assert!(palette.on_accent.contrast_ratio(palette.accent) >= 4.5);
+                assert_ne!(palette.accent_hover, palette.accent);
+
+                let theme = Theme::for_mode(mode).with_palette(&palette);
+                assert_eq!(theme.tokens.accent, seed);
+                assert_eq!(theme.chart_3, palette.charts[2]);
+                assert_eq!(theme.chart_8, palette.charts[7]);
+                assert_eq!(theme.progress_bar, seed);
+            }
+        }
+    }
+
+    #[test]
+    fn test_json_style_overrides() {
+        let mut theme = Theme::for_mode(ThemeMode::Dark);
+        let style = ComponentStyle {
+            radius: Some(8.),
+            padding_x: Some(10.),
+            background: Some(hsl(0., 100., 50.)),
+            ..Default::default()
+        };
+        theme.style_overrides.set(ComponentKind::Button, style);
+        theme
+            .style_overrides
+            .set(ComponentKind::Tab, ComponentStyle::default());
+
+        let loaded = Theme::from_json(&theme.to_json()).unwrap();
+        assert_eq!(
+            loaded.style_overrides.get(ComponentKind::Button),
+            Some(&style)
+        );
+        assert_eq!(loaded.style_overrides.get(ComponentKind::Tab), None);
+        assert!(Theme::for_mode(ThemeMode::Dark)
+            .to_json()
+            .find("components")
+            .is_none());
+    }
+}
@@ -0,0 +1,174 @@
+use std::collections::BTreeMap;
+
+use anyhow::{anyhow, Result};
+use gpui::{px, Hsla, Styled, WindowContext};
+use serde::{Deserialize, Serialize};
+
+use super::ActiveTheme as _;
+use crate::ColorExt as _;
+
+/// The components whose style can be overridden by the [`StyleOverrides`] of the theme.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum ComponentKind {
+    Button,
+    Tab,
+    ListItem,
+}
+
+impl ComponentKind {
+    pub const ALL: [Self; 3] = [Self::Button, Self::Tab, Self::ListItem];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Button => "Button",
+            Self::Tab => "Tab",
+            Self::ListItem => "ListItem",
+        }
+    }
+}
+
+/// The style of a component that overrides its default style, `None` to keep the default.
+///
+/// The lengths are in pixels, the colors replace the normal colors,
+/// the selected, disabled, hovered and active ones are kept.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ComponentStyle {
+    pub radius: Option<f32>,
+    pub padding_x: Option<f32>,
+    pub padding_y: Option<f32>,
+    pub background: Option<Hsla>,
+    pub foreground: Option<Hsla>,
+    pub border: Option<Hsla>,
+}
+
+impl ComponentStyle {
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// The style overrides of the components in the [`super::Theme`],
+/// to change the components without forking them.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct StyleOverrides(BTreeMap<ComponentKind, ComponentStyle>);
+
+impl StyleOverrides {
+    pub fn get(&self, kind: ComponentKind) -> Option<&ComponentStyle> {
+        self.0.get(&kind)
+    }
+
+    /// Set the style of the component, the empty style removes the override.
+    pub fn set(&mut self, kind: ComponentKind, style: ComponentStyle) {
+        if style.is_empty() {
+            self.0.remove(&kind);
+        } else {
+            self.0.insert(kind, style);
+        }
+    }
+
+    /// Update the style of the component, it's created if not exists.
+    pub fn update(&mut self, kind: ComponentKind, f: impl FnOnce(&mut ComponentStyle)) {
+        let mut style = self.get(kind).copied().unwrap_or_default();
+        f(&mut style);
+        self.set(kind, style);
+    }
+
+    pub fn remove(&mut self, kind: ComponentKind) {
+        self.0.remove(&kind);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&ComponentKind, &ComponentStyle)> {
+        self.0.iter()
+    }
+}
+
+/// The JSON format of a [`ComponentStyle`], the colors are hex strings.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub(super) struct ComponentStyleJson {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    radius: Option<f32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    padding_x: Option<f32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    padding_y: Option<f32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    background: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    foreground: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    border: Option<String>,
+}
+
+impl From<&ComponentStyle> for ComponentStyleJson {
+    fn from(style: &ComponentStyle) -> Self {
+        let hex = |color: Option<Hsla>| color.map(|color| color.to_hex_string());
+        Self {
+            radius: style.radius,
+            padding_x: style.padding_x,
+            padding_y: style.padding_y,
+            background: hex(style.background),
+            foreground: hex(style.foreground),
+            border: hex(style.border),
+        }
+    }
+}
+
+impl ComponentStyleJson {
+    pub(super) fn to_style(&self, kind: ComponentKind) -> Result<ComponentStyle> {
+        let parse = |name: &str, hex: &Option<String>| {
+            hex.as_ref()
+                .map(|hex| {
+                    Hsla::parse_hex_string(hex).map_err(|_| {
+                        anyhow!("invalid color `{}` for `{}` of {}", hex, name, kind.name())
+                    })
+                })
+                .transpose()
+        };
+
+        Ok(ComponentStyle {
+            radius: self.radius,
+            padding_x: self.padding_x,
+            padding_y: self.padding_y,
+            background: parse("background", &self.background)?,
+            foreground: parse("foreground", &self.foreground)?,
+            border: parse("border", &self.border)?,
+        })
+    }
+}
+
+pub trait StyleOverridesExt: Styled + Sized {
+    /// Apply the style overrides of the component in the theme to its normal state,
+    /// call it after the default style of the component to replace it,
+    /// and before the styles of the other states, e.g.: selected, disabled, so they are kept.
+    fn style_overrides(mut self, kind: ComponentKind, cx: &WindowContext) -> Self {
+        let Some(style) = cx.theme().style_overrides.get(kind).copied() else {
+            return self;
+        };
+
+        if let Some(radius) = style.radius {
+            self = self.rounded(px(radius));
+        }
+        if let Some(padding_x) = style.padding_x {
+            self = self.px(px(padding_x));
+        }
+        if let Some(padding_y) = style.padding_y {
+            self = self.py(px(padding_y));
+        }
+        if let Some(background) = style.background {
+            self = self.bg(background);
+        }
+        if let Some(foreground) = style.foreground {
+            self = self.text_color(foreground);
+        }
+        if let Some(border) = style.border {
+            self = self.border_color(border);
+        }
+        self
+    }
+}
+
+impl<T: Styled> StyleOverridesExt for T {}
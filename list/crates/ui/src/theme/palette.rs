@@ -0,0 +1,122 @@
+use gpui::Hsla;
+
+use super::{hsl, tokens::state_colors, Theme, ThemeMode};
+use crate::ColorExt as _;
+
+/// The number of the chart colors of the theme, `chart_1` to `chart_8`.
+pub const CHART_COLORS: usize = 8;
+
+/// The palette derived from a seed color by the HSL adjustments,
+/// to preview before it's applied by [`Theme::with_palette`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SeedPalette {
+    pub seed: Hsla,
+    pub accent: Hsla,
+    pub on_accent: Hsla,
+    pub accent_hover: Hsla,
+    pub accent_active: Hsla,
+    pub surface_muted: Hsla,
+    pub on_surface_muted: Hsla,
+    pub highlight: Hsla,
+    pub outline: Hsla,
+    pub focus: Hsla,
+    /// The seed hue rotated evenly around the color wheel.
+    pub charts: [Hsla; CHART_COLORS],
+}
+
+impl SeedPalette {
+    /// Generate the palette of the seed color for the mode.
+    ///
+    /// The neutrals keep the hue of the seed with a low saturation, so they are tinted by it.
+    pub fn generate(seed: Hsla, mode: ThemeMode) -> Self {
+        let seed = Hsla { a: 1., ..seed };
+        let (h, s) = (seed.h * 360., seed.s * 100.);
+        let is_dark = mode.is_dark();
+
+        // The text on the accent is the lighter or the darker one with the better contrast.
+        let (light_text, dark_text) = (hsl(h, 10., 98.), hsl(h, 10., 10.));
+        let on_accent = if seed.contrast_ratio(light_text) >= seed.contrast_ratio(dark_text) {
+            light_text
+        } else {
+            dark_text
+        };
+        let (accent_hover, accent_active) = state_colors(seed, on_accent, 0.1, 0.2);
+
+        let tint = |saturation: f32, light: f32, dark: f32| {
+            hsl(h, s * saturation, if is_dark { dark } else { light })
+        };
+        let chart_saturation = s.clamp(55., 85.);
+        let chart_lightness = if is_dark { 60. } else { 50. };
+        let charts = std::array::from_fn(|ix| {
+            let hue = (h + ix as f32 * 360. / CHART_COLORS as f32) % 360.;
+            hsl(hue, chart_saturation, chart_lightness)
+        });
+
+        Self {
+            seed,
+            accent: seed,
+            on_accent,
+            accent_hover,
+            accent_active,
+            surface_muted: tint(0.2, 96., 16.),
+            on_surface_muted: tint(0.1, 46., 65.),
+            highlight: tint(0.4, 95., 18.),
+            outline: tint(0.25, 90., 20.),
+            focus: hsl(h, s, if is_dark { 70. } else { 60. }),
+            charts,
+        }
+    }
+
+    /// Returns the colors with the names of the theme, to show the palette.
+    pub fn colors(&self) -> Vec<(&'static str, Hsla)> {
+        let mut colors = vec![
+            ("accent", self.accent),
+            ("on_accent", self.on_accent),
+            ("accent_hover", self.accent_hover),
+            ("accent_active", self.accent_active),
+            ("surface_muted", self.surface_muted),
+            ("on_surface_muted", self.on_surface_muted),
+            ("highlight", self.highlight),
+            ("outline", self.outline),
+            ("focus", self.focus),
+        ];
+        colors.extend(CHART_NAMES.into_iter().zip(self.charts));
+        colors
+    }
+}
+
+const CHART_NAMES: [&str; CHART_COLORS] = [
+    "chart_1", "chart_2", "chart_3", "chart_4", "chart_5", "chart_6", "chart_7", "chart_8",
+];
+
+impl Theme {
+    /// Returns a copy of the theme with the palette applied, the other colors are resolved from it.
+    ///
+    /// Replace the global theme with it to apply all the colors at once, e.g.: [`Theme::replace_global`].
+    pub fn with_palette(&self, palette: &SeedPalette) -> Self {
+        let mut theme = self.clone();
+        let tokens = &mut theme.tokens;
+        tokens.accent = palette.accent;
+        tokens.on_accent = palette.on_accent;
+        tokens.surface_muted = palette.surface_muted;
+        tokens.on_surface_muted = palette.on_surface_muted;
+        tokens.highlight = palette.highlight;
+        tokens.outline = palette.outline;
+        tokens.focus = palette.focus;
+        theme.resolve();
+
+        theme.accent_hover = palette.accent_hover;
+        theme.accent_active = palette.accent_active;
+        [
+            theme.chart_1,
+            theme.chart_2,
+            theme.chart_3,
+            theme.chart_4,
+            theme.chart_5,
+            theme.chart_6,
+            theme.chart_7,
+            theme.chart_8,
+        ] = palette.charts;
+        theme
+    }
+}
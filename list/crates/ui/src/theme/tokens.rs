@@ -0,0 +1,211 @@
+use gpui::Hsla;
+
+use super::{hsl, ThemeMode};
+use crate::ColorExt as _;
+
+macro_rules! theme_tokens {
+    ($($(#[$meta:meta])* $name:ident),* $(,)?) => {
+        /// The semantic colors of a theme, the other colors of the [`super::Theme`] are resolved from them.
+        ///
+        /// The `on_*` tokens are the text and icon colors on the paired token.
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        pub struct ThemeTokens {
+            $($(#[$meta])* pub $name: Hsla,)*
+        }
+
+        impl ThemeTokens {
+            /// Returns all the tokens with the field name, e.g.: `("accent", tokens.accent)`.
+            pub fn colors(&self) -> Vec<(&'static str, Hsla)> {
+                vec![$((stringify!($name), self.$name)),*]
+            }
+
+            /// Set the token by the field name, returns `false` if the name is not a token.
+            pub fn set_color(&mut self, name: &str, color: Hsla) -> bool {
+                match name {
+                    $(stringify!($name) => self.$name = color,)*
+                    _ => return false,
+                }
+                true
+            }
+        }
+    };
+}
+
+theme_tokens!(
+    /// The background of the window.
+    surface,
+    on_surface,
+    /// The background of the panels and the cards, raised above the [`ThemeTokens::surface`].
+    surface_raised,
+    on_surface_raised,
+    /// The background of the popovers, the menus and the modals.
+    overlay,
+    on_overlay,
+    /// The subdued background, e.g.: the tab bar, also used for the secondary texts.
+    surface_muted,
+    on_surface_muted,
+    /// The color of the primary actions, e.g.: the primary button, the checked checkbox.
+    accent,
+    on_accent,
+    /// The color of the secondary actions, e.g.: the default button.
+    neutral,
+    on_neutral,
+    /// The background of the hovered or selected items, e.g.: the menu items.
+    highlight,
+    on_highlight,
+    /// The color of the destructive actions and the errors.
+    danger,
+    on_danger,
+    /// The borders and the dividers.
+    outline,
+    /// The focus ring.
+    focus,
+    /// The background of the selected text.
+    selection,
+    link,
+);
+
+impl ThemeTokens {
+    /// Returns the default tokens of the mode.
+    pub fn for_mode(mode: ThemeMode) -> Self {
+        match mode {
+            ThemeMode::Light => Self::light(),
+            ThemeMode::Dark => Self::dark(),
+        }
+    }
+
+    /// Returns the high contrast tokens of the mode,
+    /// the `on_*` tokens have at least 7:1 (WCAG AAA) to their backgrounds.
+    pub fn high_contrast(mode: ThemeMode) -> Self {
+        match mode {
+            ThemeMode::Light => Self::high_contrast_light(),
+            ThemeMode::Dark => Self::high_contrast_dark(),
+        }
+    }
+
+    fn light() -> Self {
+        Self {
+            surface: hsl(0.0, 0.0, 100.),
+            on_surface: hsl(240.0, 10., 3.9),
+            surface_raised: hsl(0.0, 0.0, 100.0),
+            on_surface_raised: hsl(240.0, 10.0, 3.9),
+            overlay: hsl(0.0, 0.0, 100.0),
+            on_overlay: hsl(240.0, 10.0, 3.9),
+            surface_muted: hsl(240.0, 4.8, 95.9),
+            on_surface_muted: hsl(240.0, 3.8, 46.1),
+            accent: hsl(223.0, 5.9, 10.0),
+            on_accent: hsl(223.0, 0.0, 98.0),
+            neutral: hsl(240.0, 4.8, 95.9),
+            on_neutral: hsl(240.0, 59.0, 10.),
+            highlight: hsl(240.0, 5.0, 96.0),
+            on_highlight: hsl(240.0, 5.9, 10.0),
+            danger: hsl(0.0, 84.2, 60.2),
+            on_danger: hsl(0.0, 0.0, 98.0),
+            outline: hsl(240.0, 5.9, 90.0),
+            focus: hsl(240.0, 5.9, 65.0),
+            selection: hsl(211.0, 97.0, 85.0),
+            link: hsl(221.0, 83.0, 53.0),
+        }
+    }
+
+    fn dark() -> Self {
+        Self {
+            surface: hsl(0.0, 0.0, 6.0),
+            on_surface: hsl(0., 0., 98.),
+            surface_raised: hsl(299.0, 2., 9.),
+            on_surface_raised: hsl(0.0, 0.0, 98.0),
+            overlay: hsl(240.0, 10.0, 3.9),
+            on_overlay: hsl(0.0, 0.0, 98.0),
+            surface_muted: hsl(240.0, 3.7, 15.9),
+            on_surface_muted: hsl(240.0, 5.0, 64.9),
+            accent: hsl(223.0, 0.0, 98.0),
+            on_accent: hsl(223.0, 5.9, 10.0),
+            neutral: hsl(240.0, 3.7, 15.9),
+            on_neutral: hsl(0.0, 0.0, 98.0),
+            highlight: hsl(240.0, 3.7, 15.9),
+            on_highlight: hsl(0.0, 0.0, 98.0),
+            danger: hsl(0.0, 62.8, 30.6),
+            on_danger: hsl(0.0, 0.0, 98.0),
+            outline: hsl(240.0, 3.7, 15.9),
+            focus: hsl(240.0, 4.9, 83.9),
+            selection: hsl(211.0, 97.0, 22.0),
+            link: hsl(221.0, 83.0, 53.0),
+        }
+    }
+
+    fn high_contrast_light() -> Self {
+        Self {
+            surface: hsl(0., 0., 100.),
+            on_surface: hsl(0., 0., 0.),
+            surface_raised: hsl(0., 0., 100.),
+            on_surface_raised: hsl(0., 0., 0.),
+            overlay: hsl(0., 0., 100.),
+            on_overlay: hsl(0., 0., 0.),
+            surface_muted: hsl(0., 0., 93.),
+            on_surface_muted: hsl(0., 0., 25.),
+            accent: hsl(220., 100., 25.),
+            on_accent: hsl(0., 0., 100.),
+            neutral: hsl(0., 0., 93.),
+            on_neutral: hsl(0., 0., 0.),
+            highlight: hsl(220., 100., 92.),
+            on_highlight: hsl(0., 0., 0.),
+            danger: hsl(0., 100., 30.),
+            on_danger: hsl(0., 0., 100.),
+            outline: hsl(0., 0., 20.),
+            focus: hsl(220., 100., 40.),
+            selection: hsl(211., 100., 80.),
+            link: hsl(220., 100., 30.),
+        }
+    }
+
+    fn high_contrast_dark() -> Self {
+        Self {
+            surface: hsl(0., 0., 0.),
+            on_surface: hsl(0., 0., 100.),
+            surface_raised: hsl(0., 0., 5.),
+            on_surface_raised: hsl(0., 0., 100.),
+            overlay: hsl(0., 0., 5.),
+            on_overlay: hsl(0., 0., 100.),
+            surface_muted: hsl(0., 0., 15.),
+            on_surface_muted: hsl(0., 0., 80.),
+            accent: hsl(50., 100., 60.),
+            on_accent: hsl(0., 0., 0.),
+            neutral: hsl(0., 0., 15.),
+            on_neutral: hsl(0., 0., 100.),
+            highlight: hsl(50., 100., 20.),
+            on_highlight: hsl(0., 0., 100.),
+            danger: hsl(0., 100., 70.),
+            on_danger: hsl(0., 0., 0.),
+            outline: hsl(0., 0., 85.),
+            focus: hsl(50., 100., 60.),
+            selection: hsl(211., 100., 30.),
+            link: hsl(200., 100., 70.),
+        }
+    }
+}
+
+/// Returns the color with the lightness shifted by the delta, clamped to 0.0..1.0.
+pub(crate) fn shift_lightness(color: Hsla, delta: f32) -> Hsla {
+    Hsla {
+        l: (color.l + delta).clamp(0.0, 1.0),
+        ..color
+    }
+}
+
+/// Returns the hover and the active colors of a filled color with the `on` text color.
+///
+/// The states move toward the lightness of the text, unless it breaks the AAA contrast (7:1) the pair has,
+/// then they move away to keep the text readable.
+pub(crate) fn state_colors(color: Hsla, on: Hsla, hover: f32, active: f32) -> (Hsla, Hsla) {
+    let mut sign = if on.l > color.l { 1. } else { -1. };
+    if color.contrast_ratio(on) >= 7.
+        && shift_lightness(color, sign * active).contrast_ratio(on) < 7.
+    {
+        sign = -sign;
+    }
+
+    (
+        shift_lightness(color, sign * hover),
+        shift_lightness(color, sign * active),
+    )
+}
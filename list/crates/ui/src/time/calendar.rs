@@ -337,7 +337,7 @@ impl Calendar {
             .h_9()
             .rounded_md()
             .justify_center()
-            .text_color(cx.theme().muted_foreground)
+            .text_color(cx.theme().tokens.on_surface_muted)
             .text_sm()
             .child(week.into())
     }
@@ -359,25 +359,25 @@ impl Calendar {
             .justify_center()
             .cursor_pointer()
             .when(muted, |this| {
-                this.text_color(cx.theme().muted_foreground.opacity(0.3))
+                this.text_color(cx.theme().tokens.on_surface_muted.opacity(0.3))
             })
             .when(secondary_active, |this| {
                 this.bg(if muted {
-                    cx.theme().accent.opacity(0.5)
+                    cx.theme().tokens.highlight.opacity(0.5)
                 } else {
-                    cx.theme().accent
+                    cx.theme().tokens.highlight
                 })
-                .text_color(cx.theme().accent_foreground)
+                .text_color(cx.theme().tokens.on_highlight)
             })
             .when(!active, |this| {
                 this.hover(|this| {
-                    this.bg(cx.theme().accent)
-                        .text_color(cx.theme().accent_foreground)
+                    this.bg(cx.theme().tokens.highlight)
+                        .text_color(cx.theme().tokens.on_highlight)
                 })
             })
             .when(active, |this| {
-                this.bg(cx.theme().primary)
-                    .text_color(cx.theme().primary_foreground)
+                this.bg(cx.theme().tokens.accent)
+                    .text_color(cx.theme().tokens.on_accent)
             })
             .child(label.into())
     }
@@ -210,7 +210,7 @@ impl Render for DatePicker {
                     .flex()
                     .items_center()
                     .justify_between()
-                    .bg(cx.theme().background)
+                    .bg(cx.theme().tokens.surface)
                     .border_1()
                     .border_color(cx.theme().input)
                     .rounded(px(cx.theme().radius))
@@ -236,7 +236,7 @@ impl Render for DatePicker {
                             .when(!show_clean, |this| {
                                 this.child(
                                     Icon::new(IconName::Calendar)
-                                        .text_color(cx.theme().muted_foreground),
+                                        .text_color(cx.theme().tokens.on_surface_muted),
                                 )
                             }),
                     ),
@@ -255,10 +255,10 @@ impl Render for DatePicker {
                                 .p_3()
                                 .w(px(popover_width))
                                 .border_1()
-                                .border_color(cx.theme().border)
+                                .border_color(cx.theme().tokens.outline)
                                 .shadow_lg()
                                 .rounded_lg()
-                                .bg(cx.theme().background)
+                                .bg(cx.theme().tokens.surface)
                                 .on_mouse_up_out(
                                     MouseButton::Left,
                                     cx.listener(|view, _, cx| view.escape(&Escape, cx)),
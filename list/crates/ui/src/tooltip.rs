@@ -1,37 +1,202 @@
+use std::time::Duration;
+
 use gpui::{
-    div, px, AnyView, IntoElement, ParentElement, Render, SharedString, Styled, ViewContext,
-    VisualContext, WindowContext,
+    anchored, div, point, prelude::FluentBuilder as _, px, Action, AnchorCorner, AnyView,
+    AppContext, Global, IntoElement, ParentElement, Pixels, Point, Render, SharedString, Styled,
+    Task, ViewContext, VisualContext, WindowContext,
 };
 
-use crate::theme::ActiveTheme;
+use crate::{h_flex, theme::ActiveTheme, v_flex, Placement};
+
+/// The gap between the tooltip and the mouse cursor.
+const CURSOR_GAP: Pixels = px(12.);
+
+/// The app-wide options of the tooltips.
+///
+/// Use [`TooltipManager::set_delay`] and [`TooltipManager::set_placement`] to change them.
+#[derive(Debug, Clone, Copy)]
+pub struct TooltipManager {
+    /// The extra delay to show the tooltip, after the hover delay of gpui.
+    pub delay: Duration,
+    /// The side of the mouse cursor to show the tooltip.
+    pub placement: Placement,
+}
+
+impl Default for TooltipManager {
+    fn default() -> Self {
+        Self {
+            delay: Duration::ZERO,
+            placement: Placement::Bottom,
+        }
+    }
+}
+
+impl Global for TooltipManager {}
+
+impl TooltipManager {
+    pub fn global(cx: &AppContext) -> Self {
+        cx.try_global::<Self>().copied().unwrap_or_default()
+    }
+
+    pub fn set_delay(delay: Duration, cx: &mut AppContext) {
+        cx.default_global::<Self>().delay = delay;
+    }
+
+    pub fn set_placement(placement: Placement, cx: &mut AppContext) {
+        cx.default_global::<Self>().placement = placement;
+    }
+}
 
 pub struct Tooltip {
     text: SharedString,
+    /// The keystrokes of the action, e.g.: `cmd-b`.
+    key_binding: Option<SharedString>,
+    /// The secondary text below the `text`.
+    meta: Option<SharedString>,
+    placement: Placement,
+    /// The mouse position when the tooltip was shown.
+    position: Point<Pixels>,
+    visible: bool,
+    _delay_task: Task<()>,
 }
 
 impl Tooltip {
     pub fn new(text: impl Into<SharedString>, cx: &mut WindowContext) -> AnyView {
-        cx.new_view(|_| Self { text: text.into() }).into()
+        Self::build(text.into(), None, None, cx)
+    }
+
+    /// Show the text with the keybinding of the action.
+    pub fn for_action(
+        text: impl Into<SharedString>,
+        action: &dyn Action,
+        cx: &mut WindowContext,
+    ) -> AnyView {
+        Self::build(text.into(), Some(action), None, cx)
+    }
+
+    /// Show the text with the keybinding of the action (if any), and the `meta` text below.
+    pub fn with_meta(
+        text: impl Into<SharedString>,
+        action: Option<&dyn Action>,
+        meta: impl Into<SharedString>,
+        cx: &mut WindowContext,
+    ) -> AnyView {
+        Self::build(text.into(), action, Some(meta.into()), cx)
+    }
+
+    fn build(
+        text: SharedString,
+        action: Option<&dyn Action>,
+        meta: Option<SharedString>,
+        cx: &mut WindowContext,
+    ) -> AnyView {
+        let key_binding = action.and_then(|action| {
+            cx.bindings_for_action(action).first().map(|binding| {
+                binding
+                    .keystrokes()
+                    .iter()
+                    .map(|keystroke| format!("{}", keystroke))
+                    .collect::<Vec<_>>()
+                    .join(" ")
+                    .into()
+            })
+        });
+        let manager = TooltipManager::global(cx);
+        let position = cx.mouse_position();
+
+        cx.new_view(|cx| {
+            let visible = manager.delay.is_zero();
+            let _delay_task = if visible {
+                Task::ready(())
+            } else {
+                cx.spawn(|this, mut cx| async move {
+                    cx.background_executor().timer(manager.delay).await;
+                    let _ = this.update(&mut cx, |this, cx| {
+                        this.visible = true;
+                        cx.notify();
+                    });
+                })
+            };
+
+            Self {
+                text,
+                key_binding,
+                meta,
+                placement: manager.placement,
+                position,
+                visible,
+                _delay_task,
+            }
+        })
+        .into()
     }
 }
 
 impl Render for Tooltip {
     fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        if !self.visible {
+            return div();
+        }
+
+        let (position, anchor) = match self.placement {
+            Placement::Top => (
+                self.position - point(px(0.), CURSOR_GAP),
+                AnchorCorner::BottomLeft,
+            ),
+            Placement::Bottom => (
+                self.position + point(px(0.), CURSOR_GAP),
+                AnchorCorner::TopLeft,
+            ),
+            Placement::Left => (
+                self.position - point(CURSOR_GAP, px(0.)),
+                AnchorCorner::TopRight,
+            ),
+            Placement::Right => (
+                self.position + point(CURSOR_GAP, px(0.)),
+                AnchorCorner::TopLeft,
+            ),
+        };
+
         div().child(
-            // Wrap in a child, to ensure the left margin is applied to the tooltip
-            div()
-                .m_3()
-                .bg(cx.theme().popover)
-                .text_color(cx.theme().popover_foreground)
-                .bg(cx.theme().popover)
-                .border_1()
-                .border_color(cx.theme().border)
-                .shadow_md()
-                .rounded(px(6.))
-                .py_0p5()
-                .px_2()
-                .text_sm()
-                .child(self.text.clone()),
+            anchored()
+                .position(position)
+                .anchor(anchor)
+                .snap_to_window()
+                .child(
+                    v_flex()
+                        .gap_0p5()
+                        .bg(cx.theme().tokens.overlay)
+                        .text_color(cx.theme().tokens.on_overlay)
+                        .border_1()
+                        .border_color(cx.theme().tokens.outline)
+                        .shadow_md()
+                        .rounded(px(6.))
+                        .py_0p5()
+                        .px_2()
+                        .text_sm()
+                        .child(
+                            h_flex()
+                                .gap_3()
+                                .justify_between()
+                                .child(self.text.clone())
+                                .when_some(self.key_binding.clone(), |this, key_binding| {
+                                    this.child(
+                                        div()
+                                            .text_xs()
+                                            .text_color(cx.theme().tokens.on_surface_muted)
+                                            .child(key_binding),
+                                    )
+                                }),
+                        )
+                        .when_some(self.meta.clone(), |this, meta| {
+                            this.child(
+                                div()
+                                    .text_xs()
+                                    .text_color(cx.theme().tokens.on_surface_muted)
+                                    .child(meta),
+                            )
+                        }),
+                ),
         )
     }
 }
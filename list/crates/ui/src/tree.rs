@@ -0,0 +1,686 @@
+use std::{cell::Cell, rc::Rc};
+
+use gpui::{
+    actions, div, prelude::FluentBuilder as _, px, uniform_list, AppContext, ClickEvent, Entity,
+    EventEmitter, FocusHandle, FocusableView, InteractiveElement as _, IntoElement, KeyBinding,
+    MouseButton, MouseDownEvent, ParentElement as _, Pixels, Render, SharedString,
+    StatefulInteractiveElement as _, Styled as _, Task, UniformListScrollHandle, ViewContext,
+    WindowContext,
+};
+
+use crate::{
+    h_flex,
+    indicator::Indicator,
+    scroll::{Scrollbar, ScrollbarState},
+    theme::ActiveTheme as _,
+    v_flex, Icon, IconName, Sizable as _,
+};
+
+actions!(
+    tree,
+    [
+        SelectPrev,
+        SelectNext,
+        ExtendSelectPrev,
+        ExtendSelectNext,
+        Expand,
+        Collapse,
+        Confirm,
+        ToggleSelect
+    ]
+);
+
+/// The indent width of each level of the tree.
+const INDENT_WIDTH: Pixels = px(16.);
+
+pub fn init(cx: &mut AppContext) {
+    let context: Option<&str> = Some("Tree");
+    cx.bind_keys([
+        KeyBinding::new("up", SelectPrev, context),
+        KeyBinding::new("down", SelectNext, context),
+        KeyBinding::new("shift-up", ExtendSelectPrev, context),
+        KeyBinding::new("shift-down", ExtendSelectNext, context),
+        KeyBinding::new("right", Expand, context),
+        KeyBinding::new("left", Collapse, context),
+        KeyBinding::new("enter", Confirm, context),
+        KeyBinding::new("space", ToggleSelect, context),
+    ]);
+}
+
+/// A node of the [`TreeView`].
+#[derive(Clone)]
+pub struct TreeItem {
+    id: SharedString,
+    label: SharedString,
+    icon: Option<IconName>,
+    /// The loaded children, `None` means the children have not been loaded yet.
+    children: Option<Vec<TreeItem>>,
+    has_children: bool,
+    expanded: bool,
+    loading: bool,
+}
+
+impl TreeItem {
+    /// Create a leaf item, the `id` must be unique in the tree.
+    pub fn new(id: impl Into<SharedString>, label: impl Into<SharedString>) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            icon: None,
+            children: Some(vec![]),
+            has_children: false,
+            expanded: false,
+            loading: false,
+        }
+    }
+
+    pub fn icon(mut self, icon: IconName) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    /// Set the children of the item.
+    pub fn children(mut self, children: impl IntoIterator<Item = TreeItem>) -> Self {
+        self.children = Some(children.into_iter().collect());
+        self.has_children = true;
+        self
+    }
+
+    /// Mark the item has children, they will be loaded by [`TreeView::on_load_children`] on first expand.
+    pub fn lazy(mut self) -> Self {
+        self.children = None;
+        self.has_children = true;
+        self
+    }
+
+    pub fn expanded(mut self, expanded: bool) -> Self {
+        self.expanded = expanded;
+        self
+    }
+
+    pub fn id(&self) -> &SharedString {
+        &self.id
+    }
+
+    pub fn label(&self) -> &SharedString {
+        &self.label
+    }
+
+    pub fn is_leaf(&self) -> bool {
+        !self.has_children
+    }
+
+    pub fn is_expanded(&self) -> bool {
+        self.expanded
+    }
+
+    pub fn is_loading(&self) -> bool {
+        self.loading
+    }
+}
+
+#[derive(Clone)]
+pub enum TreeEvent {
+    /// The selected items have changed.
+    Select(Vec<SharedString>),
+    /// The item has been double clicked or pressed Enter.
+    Confirm(SharedString),
+}
+
+/// The visible row of the tree, the `path` is the indexes from the root to the item.
+struct TreeEntry {
+    path: Vec<usize>,
+}
+
+type LoadChildren =
+    Rc<dyn Fn(&TreeItem, &mut WindowContext) -> Task<anyhow::Result<Vec<TreeItem>>>>;
+
+/// A tree view with expand/collapse, keyboard navigation, multi-select and lazy loaded children.
+pub struct TreeView {
+    focus_handle: FocusHandle,
+    items: Vec<TreeItem>,
+    entries: Vec<TreeEntry>,
+    selected: Vec<SharedString>,
+    /// The item of the keyboard cursor.
+    active_id: Option<SharedString>,
+    /// The item to start the range selection from.
+    anchor_id: Option<SharedString>,
+    multiple: bool,
+    load_children: Option<LoadChildren>,
+    vertical_scroll_handle: UniformListScrollHandle,
+    scrollbar_state: Rc<Cell<ScrollbarState>>,
+}
+
+impl TreeView {
+    pub fn new(items: Vec<TreeItem>, cx: &mut ViewContext<Self>) -> Self {
+        let mut this = Self {
+            focus_handle: cx.focus_handle(),
+            items,
+            entries: vec![],
+            selected: vec![],
+            active_id: None,
+            anchor_id: None,
+            multiple: false,
+            load_children: None,
+            vertical_scroll_handle: UniformListScrollHandle::new(),
+            scrollbar_state: Rc::new(Cell::new(ScrollbarState::new())),
+        };
+        this.rebuild_entries();
+        this
+    }
+
+    /// Allow to select multiple items by `shift` or `cmd` / `ctrl` click, default: false
+    pub fn multiple(mut self, multiple: bool) -> Self {
+        self.multiple = multiple;
+        self
+    }
+
+    /// Set the callback to load the children of a [`TreeItem::lazy`] item.
+    ///
+    /// If the task returns an error, the item will be collapsed and loaded again on next expand.
+    pub fn on_load_children(
+        mut self,
+        f: impl Fn(&TreeItem, &mut WindowContext) -> Task<anyhow::Result<Vec<TreeItem>>> + 'static,
+    ) -> Self {
+        self.load_children = Some(Rc::new(f));
+        self
+    }
+
+    pub fn items(&self) -> &[TreeItem] {
+        &self.items
+    }
+
+    pub fn set_items(&mut self, items: Vec<TreeItem>, cx: &mut ViewContext<Self>) {
+        self.items = items;
+        self.rebuild_entries();
+        self.selected.retain(|id| {
+            self.entries
+                .iter()
+                .any(|e| item_at(&self.items, &e.path).map(|item| &item.id) == Some(id))
+        });
+        cx.notify();
+    }
+
+    /// Returns the ids of the selected items.
+    pub fn selected_ids(&self) -> &[SharedString] {
+        &self.selected
+    }
+
+    /// Expand or collapse the item by id.
+    pub fn set_expanded(&mut self, id: &SharedString, expanded: bool, cx: &mut ViewContext<Self>) {
+        let Some(path) = find_path(&self.items, id) else {
+            return;
+        };
+        let Some(item) = item_at_mut(&mut self.items, &path) else {
+            return;
+        };
+        if item.is_leaf() || item.expanded == expanded {
+            return;
+        }
+
+        item.expanded = expanded;
+        let need_load = expanded && item.children.is_none() && !item.loading;
+        if need_load {
+            self.load_children(&path, cx);
+        }
+
+        self.rebuild_entries();
+        if !expanded {
+            self.unselect_hidden(cx);
+        }
+        cx.notify();
+    }
+
+    pub fn toggle_expanded(&mut self, id: &SharedString, cx: &mut ViewContext<Self>) {
+        let Some(expanded) = find_path(&self.items, id)
+            .and_then(|path| item_at(&self.items, &path))
+            .map(|item| item.expanded)
+        else {
+            return;
+        };
+
+        self.set_expanded(id, !expanded, cx);
+    }
+
+    fn load_children(&mut self, path: &[usize], cx: &mut ViewContext<Self>) {
+        let Some(load_children) = self.load_children.clone() else {
+            return;
+        };
+        let Some(item) = item_at_mut(&mut self.items, path) else {
+            return;
+        };
+
+        item.loading = true;
+        let item = item.clone();
+        let task = load_children(&item, cx);
+
+        cx.spawn(|view, mut cx| async move {
+            let result = task.await;
+            view.update(&mut cx, |view, cx| {
+                let Some(path) = find_path(&view.items, &item.id) else {
+                    return;
+                };
+                if let Some(item) = item_at_mut(&mut view.items, &path) {
+                    item.loading = false;
+                    match result {
+                        Ok(children) => item.children = Some(children),
+                        Err(_) => item.expanded = false,
+                    }
+                }
+                view.rebuild_entries();
+                cx.notify();
+            })
+        })
+        .detach();
+    }
+
+    /// Flatten the expanded items into the visible entries.
+    fn rebuild_entries(&mut self) {
+        fn walk(items: &[TreeItem], parent: &[usize], entries: &mut Vec<TreeEntry>) {
+            for (ix, item) in items.iter().enumerate() {
+                let mut path = parent.to_vec();
+                path.push(ix);
+
+                let children = item.children.as_ref().filter(|_| item.expanded);
+                entries.push(TreeEntry { path: path.clone() });
+                if let Some(children) = children {
+                    walk(children, &path, entries);
+                }
+            }
+        }
+
+        self.entries.clear();
+        walk(&self.items, &[], &mut self.entries);
+    }
+
+    fn entry_item(&self, ix: usize) -> Option<&TreeItem> {
+        self.entries
+            .get(ix)
+            .and_then(|entry| item_at(&self.items, &entry.path))
+    }
+
+    fn entry_ix(&self, id: &SharedString) -> Option<usize> {
+        (0..self.entries.len()).find(|ix| self.entry_item(*ix).map(|item| &item.id) == Some(id))
+    }
+
+    fn active_ix(&self) -> Option<usize> {
+        self.active_id.as_ref().and_then(|id| self.entry_ix(id))
+    }
+
+    fn set_active(&mut self, ix: usize) {
+        self.active_id = self.entry_item(ix).map(|item| item.id.clone());
+        self.vertical_scroll_handle.scroll_to_item(ix);
+    }
+
+    fn select(&mut self, ix: usize, cx: &mut ViewContext<Self>) {
+        let Some(id) = self.entry_item(ix).map(|item| item.id.clone()) else {
+            return;
+        };
+
+        self.selected = vec![id.clone()];
+        self.anchor_id = Some(id);
+        self.set_active(ix);
+        cx.emit(TreeEvent::Select(self.selected.clone()));
+        cx.notify();
+    }
+
+    fn toggle_select(&mut self, ix: usize, cx: &mut ViewContext<Self>) {
+        if !self.multiple {
+            self.select(ix, cx);
+            return;
+        }
+        let Some(id) = self.entry_item(ix).map(|item| item.id.clone()) else {
+            return;
+        };
+
+        if let Some(pos) = self.selected.iter().position(|selected| selected == &id) {
+            self.selected.remove(pos);
+        } else {
+            self.selected.push(id.clone());
+        }
+        self.anchor_id = Some(id);
+        self.set_active(ix);
+        cx.emit(TreeEvent::Select(self.selected.clone()));
+        cx.notify();
+    }
+
+    fn extend_select(&mut self, ix: usize, cx: &mut ViewContext<Self>) {
+        let Some(anchor_ix) = self
+            .anchor_id
+            .as_ref()
+            .and_then(|id| self.entry_ix(id))
+            .filter(|_| self.multiple)
+        else {
+            self.select(ix, cx);
+            return;
+        };
+
+        let range = anchor_ix.min(ix)..=anchor_ix.max(ix);
+        self.selected = range
+            .filter_map(|ix| self.entry_item(ix).map(|item| item.id.clone()))
+            .collect();
+        self.set_active(ix);
+        cx.emit(TreeEvent::Select(self.selected.clone()));
+        cx.notify();
+    }
+
+    /// Remove the items from selection that have been hidden by collapsing their parent.
+    fn unselect_hidden(&mut self, cx: &mut ViewContext<Self>) {
+        let len = self.selected.len();
+        let visible = |id: &SharedString| self.entry_ix(id).is_some();
+        let selected = self
+            .selected
+            .iter()
+            .filter(|id| visible(id))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        if self.active_id.as_ref().map_or(false, |id| !visible(id)) {
+            self.active_id = None;
+        }
+        if selected.len() != len {
+            self.selected = selected;
+            cx.emit(TreeEvent::Select(self.selected.clone()));
+        }
+    }
+
+    fn confirm(&mut self, ix: usize, cx: &mut ViewContext<Self>) {
+        let Some(id) = self.entry_item(ix).map(|item| item.id.clone()) else {
+            return;
+        };
+
+        self.toggle_expanded(&id, cx);
+        cx.emit(TreeEvent::Confirm(id));
+    }
+
+    fn on_action_select_prev(&mut self, _: &SelectPrev, cx: &mut ViewContext<Self>) {
+        let ix = self.active_ix().map_or(0, |ix| ix.saturating_sub(1));
+        self.select(ix, cx);
+    }
+
+    fn on_action_select_next(&mut self, _: &SelectNext, cx: &mut ViewContext<Self>) {
+        let ix = self
+            .active_ix()
+            .map_or(0, |ix| (ix + 1).min(self.entries.len().saturating_sub(1)));
+        self.select(ix, cx);
+    }
+
+    fn on_action_extend_select_prev(&mut self, _: &ExtendSelectPrev, cx: &mut ViewContext<Self>) {
+        let ix = self.active_ix().map_or(0, |ix| ix.saturating_sub(1));
+        self.extend_select(ix, cx);
+    }
+
+    fn on_action_extend_select_next(&mut self, _: &ExtendSelectNext, cx: &mut ViewContext<Self>) {
+        let ix = self
+            .active_ix()
+            .map_or(0, |ix| (ix + 1).min(self.entries.len().saturating_sub(1)));
+        self.extend_select(ix, cx);
+    }
+
+    /// Expand the active item, or move to the first child if it is already expanded.
+    fn on_action_expand(&mut self, _: &Expand, cx: &mut ViewContext<Self>) {
+        let Some(ix) = self.active_ix() else {
+            return;
+        };
+        let Some(item) = self.entry_item(ix) else {
+            return;
+        };
+        if item.is_leaf() {
+            return;
+        }
+
+        if item.expanded {
+            let has_children = item.children.as_ref().map_or(false, |c| !c.is_empty());
+            if has_children {
+                self.select(ix + 1, cx);
+            }
+        } else {
+            let id = item.id.clone();
+            self.set_expanded(&id, true, cx);
+        }
+    }
+
+    /// Collapse the active item, or move to the parent if it is already collapsed.
+    fn on_action_collapse(&mut self, _: &Collapse, cx: &mut ViewContext<Self>) {
+        let Some(ix) = self.active_ix() else {
+            return;
+        };
+        let Some(item) = self.entry_item(ix) else {
+            return;
+        };
+
+        if item.expanded {
+            let id = item.id.clone();
+            self.set_expanded(&id, false, cx);
+        } else {
+            let path = &self.entries[ix].path;
+            if path.len() > 1 {
+                let parent_id = item_at(&self.items, &path[..path.len() - 1]).map(|p| p.id.clone());
+                if let Some(parent_ix) = parent_id.and_then(|id| self.entry_ix(&id)) {
+                    self.select(parent_ix, cx);
+                }
+            }
+        }
+    }
+
+    fn on_action_confirm(&mut self, _: &Confirm, cx: &mut ViewContext<Self>) {
+        if let Some(ix) = self.active_ix() {
+            self.confirm(ix, cx);
+        }
+    }
+
+    fn on_action_toggle_select(&mut self, _: &ToggleSelect, cx: &mut ViewContext<Self>) {
+        if let Some(ix) = self.active_ix() {
+            self.toggle_select(ix, cx);
+        }
+    }
+
+    fn on_entry_mouse_down(
+        &mut self,
+        ix: usize,
+        event: &MouseDownEvent,
+        cx: &mut ViewContext<Self>,
+    ) {
+        self.focus_handle.focus(cx);
+
+        if event.click_count == 2 {
+            self.confirm(ix, cx);
+        } else if event.modifiers.shift {
+            self.extend_select(ix, cx);
+        } else if event.modifiers.platform || event.modifiers.control {
+            self.toggle_select(ix, cx);
+        } else {
+            self.select(ix, cx);
+        }
+    }
+
+    fn render_entry(&self, ix: usize, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let entry = &self.entries[ix];
+        let depth = entry.path.len() - 1;
+        let item = item_at(&self.items, &entry.path).expect("tree entry must have an item");
+        let id = item.id.clone();
+        let selected = self.selected.contains(&item.id);
+        let active = self.active_id.as_ref() == Some(&item.id) && self.focus_handle.is_focused(cx);
+
+        h_flex()
+            .id(ix)
+            .h_7()
+            .gap_1()
+            .pr_2()
+            .pl(px(8.) + INDENT_WIDTH * depth as f32)
+            .border_1()
+            .border_color(gpui::transparent_black())
+            .when(selected, |this| this.bg(cx.theme().list_active))
+            .when(!selected, |this| {
+                this.hover(|this| this.bg(cx.theme().tokens.highlight))
+            })
+            .when(active, |this| this.border_color(cx.theme().tokens.focus))
+            .child(
+                div()
+                    .id("disclosure")
+                    .flex()
+                    .flex_none()
+                    .items_center()
+                    .justify_center()
+                    .size_4()
+                    .text_color(cx.theme().tokens.on_surface_muted)
+                    .map(|this| {
+                        if item.loading {
+                            this.child(Indicator::new().xsmall())
+                        } else if item.is_leaf() {
+                            this
+                        } else {
+                            this.child(
+                                Icon::new(if item.expanded {
+                                    IconName::ChevronDown
+                                } else {
+                                    IconName::ChevronRight
+                                })
+                                .xsmall(),
+                            )
+                            .on_mouse_down(MouseButton::Left, |_, cx| cx.stop_propagation())
+                            .on_click(cx.listener(
+                                move |this, _: &ClickEvent, cx| this.toggle_expanded(&id, cx),
+                            ))
+                        }
+                    }),
+            )
+            .when_some(item.icon.clone(), |this, icon| {
+                this.child(
+                    Icon::new(icon)
+                        .small()
+                        .text_color(cx.theme().tokens.on_surface_muted),
+                )
+            })
+            .child(
+                div()
+                    .flex_1()
+                    .overflow_hidden()
+                    .whitespace_nowrap()
+                    .child(item.label.clone()),
+            )
+            .on_mouse_down(
+                MouseButton::Left,
+                cx.listener(move |this, event, cx| this.on_entry_mouse_down(ix, event, cx)),
+            )
+    }
+}
+
+fn item_at<'a>(items: &'a [TreeItem], path: &[usize]) -> Option<&'a TreeItem> {
+    let (first, rest) = path.split_first()?;
+    let item = items.get(*first)?;
+    if rest.is_empty() {
+        return Some(item);
+    }
+
+    item_at(item.children.as_deref()?, rest)
+}
+
+fn item_at_mut<'a>(items: &'a mut [TreeItem], path: &[usize]) -> Option<&'a mut TreeItem> {
+    let (first, rest) = path.split_first()?;
+    let item = items.get_mut(*first)?;
+    if rest.is_empty() {
+        return Some(item);
+    }
+
+    item_at_mut(item.children.as_deref_mut()?, rest)
+}
+
+fn find_path(items: &[TreeItem], id: &SharedString) -> Option<Vec<usize>> {
+    for (ix, item) in items.iter().enumerate() {
+        if &item.id == id {
+            return Some(vec![ix]);
+        }
+
+        if let Some(mut path) = item
+            .children
+            .as_deref()
+            .and_then(|children| find_path(children, id))
+        {
+            path.insert(0, ix);
+            return Some(path);
+        }
+    }
+
+    None
+}
+
+impl EventEmitter<TreeEvent> for TreeView {}
+
+impl FocusableView for TreeView {
+    fn focus_handle(&self, _: &AppContext) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for TreeView {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let view = cx.view().clone();
+        let entries_count = self.entries.len();
+
+        v_flex()
+            .key_context("Tree")
+            .id("tree")
+            .track_focus(&self.focus_handle)
+            .size_full()
+            .relative()
+            .overflow_hidden()
+            .on_action(cx.listener(Self::on_action_select_prev))
+            .on_action(cx.listener(Self::on_action_select_next))
+            .on_action(cx.listener(Self::on_action_extend_select_prev))
+            .on_action(cx.listener(Self::on_action_extend_select_next))
+            .on_action(cx.listener(Self::on_action_expand))
+            .on_action(cx.listener(Self::on_action_collapse))
+            .on_action(cx.listener(Self::on_action_confirm))
+            .on_action(cx.listener(Self::on_action_toggle_select))
+            .child(
+                uniform_list(
+                    view,
+                    "tree-entries",
+                    entries_count,
+                    |tree, visible_range, cx| {
+                        visible_range
+                            .map(|ix| tree.render_entry(ix, cx))
+                            .collect::<Vec<_>>()
+                    },
+                )
+                .flex_grow()
+                .track_scroll(self.vertical_scroll_handle.clone()),
+            )
+            .child(Scrollbar::uniform_scroll(
+                cx.view().entity_id(),
+                self.scrollbar_state.clone(),
+                self.vertical_scroll_handle.clone(),
+                entries_count,
+            ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{find_path, item_at, TreeItem};
+
+    #[test]
+    fn test_find_path() {
+        let items = vec![
+            TreeItem::new("src", "src").children(vec![
+                TreeItem::new("src/main.rs", "main.rs"),
+                TreeItem::new("src/ui", "ui")
+                    .children(vec![TreeItem::new("src/ui/tree.rs", "tree.rs")]),
+            ]),
+            TreeItem::new("assets", "assets").lazy(),
+        ];
+
+        assert_eq!(
+            find_path(&items, &"src/ui/tree.rs".into()),
+            Some(vec![0, 1, 0])
+        );
+        assert_eq!(find_path(&items, &"assets".into()), Some(vec![1]));
+        assert_eq!(find_path(&items, &"unknown".into()), None);
+        assert_eq!(
+            item_at(&items, &[0, 1, 0]).map(|item| item.label().to_string()),
+            Some("tree.rs".to_string())
+        );
+        assert!(item_at(&items, &[1, 0]).is_none());
+    }
+}
@@ -0,0 +1,237 @@
+use std::rc::Rc;
+
+use gpui::{
+    div, prelude::FluentBuilder as _, px, AnyElement, IntoElement, ParentElement as _, Pixels,
+    Render, SharedString, Styled as _, ViewContext, VisualContext as _, WindowContext,
+};
+
+use rust_i18n::t;
+
+use crate::{
+    button::Button, h_flex, stepper::Stepper, theme::ActiveTheme as _, v_flex, ContextModal as _,
+    Disableable as _,
+};
+
+type ValidateFn = Rc<dyn Fn(&mut WindowContext) -> Result<(), SharedString>>;
+
+/// A step of the [`Wizard`].
+pub struct WizardStep {
+    title: SharedString,
+    content: Rc<dyn Fn(&mut WindowContext) -> AnyElement>,
+    validate: Option<ValidateFn>,
+}
+
+impl WizardStep {
+    /// Create a step, the `content` is rendered when the step is the current one.
+    ///
+    /// Return a `View` from the `content` to keep the state of the step.
+    pub fn new<F, E>(title: impl Into<SharedString>, content: F) -> Self
+    where
+        F: Fn(&mut WindowContext) -> E + 'static,
+        E: IntoElement,
+    {
+        Self {
+            title: title.into(),
+            content: Rc::new(move |cx| content(cx).into_any_element()),
+            validate: None,
+        }
+    }
+
+    /// Set the validation of the step, it's called before going to the next step or finishing,
+    /// the error message is shown below the content if it returns `Err`.
+    pub fn validate(
+        mut self,
+        validate: impl Fn(&mut WindowContext) -> Result<(), SharedString> + 'static,
+    ) -> Self {
+        self.validate = Some(Rc::new(validate));
+        self
+    }
+}
+
+/// A multi-step flow in a modal, with a [`Stepper`] and the Back / Next / Finish buttons.
+pub struct Wizard {
+    title: SharedString,
+    steps: Vec<WizardStep>,
+    width: Pixels,
+    on_finish: Option<Rc<dyn Fn(&mut WindowContext)>>,
+    on_cancel: Option<Rc<dyn Fn(&mut WindowContext)>>,
+}
+
+impl Wizard {
+    pub fn new(title: impl Into<SharedString>) -> Self {
+        Self {
+            title: title.into(),
+            steps: Vec::new(),
+            width: px(560.),
+            on_finish: None,
+            on_cancel: None,
+        }
+    }
+
+    pub fn step(mut self, step: WizardStep) -> Self {
+        self.steps.push(step);
+        self
+    }
+
+    /// Sets the width of the modal, defaults to 560px.
+    pub fn width(mut self, width: Pixels) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Set the handler to call when the last step is validated and the modal is closed.
+    pub fn on_finish(mut self, handler: impl Fn(&mut WindowContext) + 'static) -> Self {
+        self.on_finish = Some(Rc::new(handler));
+        self
+    }
+
+    /// Set the handler to call when the modal is closed before finishing.
+    pub fn on_cancel(mut self, handler: impl Fn(&mut WindowContext) + 'static) -> Self {
+        self.on_cancel = Some(Rc::new(handler));
+        self
+    }
+
+    /// Open the wizard in a modal, starting from the first step.
+    pub fn open(self, cx: &mut WindowContext) {
+        let title = self.title.clone();
+        let width = self.width;
+        let on_cancel = self.on_cancel.clone();
+        let view = cx.new_view(|_| WizardView::new(self));
+
+        cx.open_modal(move |modal, _| {
+            let on_cancel = on_cancel.clone();
+            modal
+                .title(title.clone())
+                .width(width)
+                .on_close(move |_, cx| {
+                    if let Some(on_cancel) = &on_cancel {
+                        on_cancel(cx);
+                    }
+                })
+                .child(view.clone())
+        });
+    }
+}
+
+/// The content of the modal opened by [`Wizard::open`], it keeps the current step.
+struct WizardView {
+    steps: Vec<WizardStep>,
+    current: usize,
+    error: Option<SharedString>,
+    on_finish: Option<Rc<dyn Fn(&mut WindowContext)>>,
+    on_cancel: Option<Rc<dyn Fn(&mut WindowContext)>>,
+}
+
+impl WizardView {
+    fn new(wizard: Wizard) -> Self {
+        Self {
+            steps: wizard.steps,
+            current: 0,
+            error: None,
+            on_finish: wizard.on_finish,
+            on_cancel: wizard.on_cancel,
+        }
+    }
+
+    fn is_last(&self) -> bool {
+        self.current + 1 >= self.steps.len()
+    }
+
+    fn go_to(&mut self, ix: usize, cx: &mut ViewContext<Self>) {
+        self.current = ix.min(self.steps.len().saturating_sub(1));
+        self.error = None;
+        cx.notify();
+    }
+
+    fn back(&mut self, cx: &mut ViewContext<Self>) {
+        self.go_to(self.current.saturating_sub(1), cx);
+    }
+
+    /// Validate the current step, then go to the next step or finish.
+    fn next(&mut self, cx: &mut ViewContext<Self>) {
+        let validate = self
+            .steps
+            .get(self.current)
+            .and_then(|step| step.validate.clone());
+        if let Some(Err(err)) = validate.map(|validate| validate(cx)) {
+            self.error = Some(err);
+            cx.notify();
+            return;
+        }
+
+        if !self.is_last() {
+            self.go_to(self.current + 1, cx);
+            return;
+        }
+
+        cx.close_modal();
+        if let Some(on_finish) = self.on_finish.clone() {
+            on_finish(cx);
+        }
+    }
+
+    fn cancel(&mut self, cx: &mut ViewContext<Self>) {
+        cx.close_modal();
+        if let Some(on_cancel) = self.on_cancel.clone() {
+            on_cancel(cx);
+        }
+    }
+}
+
+impl Render for WizardView {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let content = self
+            .steps
+            .get(self.current)
+            .map(|step| step.content.clone());
+
+        v_flex()
+            .gap_4()
+            .child(
+                Stepper::new("wizard-stepper")
+                    .items(self.steps.iter().map(|step| step.title.clone()))
+                    .selected_index(self.current)
+                    .on_click(cx.listener(|this, ix: &usize, cx| this.go_to(*ix, cx))),
+            )
+            .child(
+                v_flex()
+                    .min_h(px(120.))
+                    .when_some(content, |this, content| this.child(content(cx))),
+            )
+            .when_some(self.error.clone(), |this, error| {
+                this.child(
+                    div()
+                        .text_sm()
+                        .text_color(cx.theme().tokens.danger)
+                        .child(error),
+                )
+            })
+            .child(
+                h_flex()
+                    .gap_2()
+                    .justify_end()
+                    .child(
+                        Button::new("wizard-cancel", cx)
+                            .ghost()
+                            .label(t!("Wizard.Cancel"))
+                            .on_click(cx.listener(|this, _, cx| this.cancel(cx))),
+                    )
+                    .child(
+                        Button::new("wizard-back", cx)
+                            .label(t!("Wizard.Back"))
+                            .disabled(self.current == 0)
+                            .on_click(cx.listener(|this, _, cx| this.back(cx))),
+                    )
+                    .child(
+                        Button::new("wizard-next", cx)
+                            .primary()
+                            .label(if self.is_last() {
+                                t!("Wizard.Finish")
+                            } else {
+                                t!("Wizard.Next")
+                            })
+                            .on_click(cx.listener(|this, _, cx| this.next(cx))),
+                    ),
+            )
+    }
+}
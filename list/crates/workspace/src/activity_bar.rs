@@ -0,0 +1,161 @@
+use std::rc::Rc;
+
+use gpui::{
+    div, prelude::FluentBuilder as _, px, ClickEvent, ElementId, InteractiveElement as _,
+    IntoElement, ParentElement as _, RenderOnce, SharedString, Styled as _, WindowContext,
+};
+use ui::{
+    button::Button, dock::PanelBadge, h_flex, theme::ActiveTheme as _, v_flex, IconName,
+    Selectable as _,
+};
+
+/// An icon button in the [`ActivityBar`], to show or hide a panel in the side dock.
+#[derive(IntoElement)]
+pub struct ActivityItem {
+    id: ElementId,
+    icon: IconName,
+    tooltip: SharedString,
+    badge: Option<PanelBadge>,
+    active: bool,
+    on_click: Option<Rc<dyn Fn(&ClickEvent, &mut WindowContext)>>,
+}
+
+impl ActivityItem {
+    pub fn new(id: impl Into<ElementId>, icon: IconName) -> Self {
+        Self {
+            id: id.into(),
+            icon,
+            tooltip: SharedString::default(),
+            badge: None,
+            active: false,
+            on_click: None,
+        }
+    }
+
+    /// Set the tooltip, usually the title of the panel.
+    pub fn tooltip(mut self, tooltip: impl Into<SharedString>) -> Self {
+        self.tooltip = tooltip.into();
+        self
+    }
+
+    /// Set the badge shown at the top-right corner of the icon, e.g.: the [`ui::dock::Panel::badge`].
+    pub fn badge(mut self, badge: Option<PanelBadge>) -> Self {
+        self.badge = badge;
+        self
+    }
+
+    /// Set true if the panel of the item is shown in the side dock.
+    pub fn active(mut self, active: bool) -> Self {
+        self.active = active;
+        self
+    }
+
+    pub fn on_click(mut self, handler: impl Fn(&ClickEvent, &mut WindowContext) + 'static) -> Self {
+        self.on_click = Some(Rc::new(handler));
+        self
+    }
+}
+
+impl RenderOnce for ActivityItem {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let on_click = self.on_click.clone();
+
+        div()
+            .relative()
+            .w_full()
+            .flex()
+            .justify_center()
+            // The indicator of the active item on the left edge.
+            .when(self.active, |this| {
+                this.child(
+                    div()
+                        .absolute()
+                        .left_0()
+                        .top_1()
+                        .bottom_1()
+                        .w(px(2.))
+                        .bg(cx.theme().tokens.accent),
+                )
+            })
+            .child(
+                Button::new(self.id, cx)
+                    .icon(self.icon)
+                    .ghost()
+                    .selected(self.active)
+                    .when(!self.tooltip.is_empty(), |this| this.tooltip(self.tooltip))
+                    .when_some(on_click, |this, on_click| {
+                        this.on_click(move |event, cx| on_click(event, cx))
+                    }),
+            )
+            .when_some(self.badge, |this, badge| {
+                this.child(
+                    h_flex()
+                        .absolute()
+                        .top_0()
+                        .right_1()
+                        .justify_center()
+                        .rounded_full()
+                        .bg(cx.theme().tokens.accent)
+                        .text_color(cx.theme().tokens.on_accent)
+                        .map(|this| match badge {
+                            PanelBadge::Count(count) => this
+                                .h(px(14.))
+                                .min_w(px(14.))
+                                .px(px(3.))
+                                .text_size(px(9.))
+                                .child(if count > 99 {
+                                    "99+".to_string()
+                                } else {
+                                    count.to_string()
+                                }),
+                            PanelBadge::Dot => this.size(px(6.)),
+                        }),
+                )
+            })
+    }
+}
+
+/// A vertical rail of icons at the edge of the workspace, like the activity bar of VS Code.
+#[derive(IntoElement)]
+pub struct ActivityBar {
+    id: ElementId,
+    items: Vec<ActivityItem>,
+}
+
+impl ActivityBar {
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            items: Vec::new(),
+        }
+    }
+
+    /// Add an item to the end of the bar.
+    pub fn item(mut self, item: ActivityItem) -> Self {
+        self.items.push(item);
+        self
+    }
+
+    /// Add the items to the end of the bar.
+    pub fn items(mut self, items: impl IntoIterator<Item = ActivityItem>) -> Self {
+        self.items.extend(items);
+        self
+    }
+}
+
+impl RenderOnce for ActivityBar {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        v_flex()
+            .id(self.id)
+            .flex_none()
+            .w(px(44.))
+            .h_full()
+            .py_2()
+            .gap_1()
+            .items_center()
+            .border_r_1()
+            .border_color(cx.theme().tokens.outline)
+            .bg(cx.theme().tab_bar)
+            .children(self.items)
+    }
+}
@@ -461,7 +461,7 @@ impl Render for Dock {
             .track_focus(&self.focus_handle)
             .flex()
             .bg(cx.theme().panel)
-            .border_color(cx.theme().border)
+            .border_color(cx.theme().tokens.outline)
             .overflow_hidden()
             .map(|this| match self.position().axis() {
                 Axis::Horizontal => this.w(size).h_full().flex_row(),
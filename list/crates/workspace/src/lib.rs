@@ -1,10 +1,14 @@
+mod activity_bar;
 pub mod dock;
 pub mod item;
 pub mod pane;
 pub mod pane_group;
+mod status_bar;
 mod title_bar;
 mod util;
 mod workspace;
 
+pub use activity_bar::{ActivityBar, ActivityItem};
+pub use status_bar::{StatusBar, StatusItemView};
 pub use title_bar::TitleBar;
 pub use workspace::*;
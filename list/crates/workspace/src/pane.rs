@@ -20,6 +20,7 @@ use serde::Deserialize;
 
 use crate::util::ResultExt;
 use ui::{
+    accessibility::Accessible as _,
     label::Label,
     tab::{Tab, TabBar},
     theme::{ActiveTheme, Colorize as _},
@@ -662,6 +663,9 @@ impl Pane {
         let _position_relative_to_active_item = ix.cmp(&self.active_item_index);
 
         Tab::new(ix, label)
+            .when_some(item.tab_tooltip(cx), |this, tooltip| {
+                this.accessible_label(tooltip)
+            })
             .group("tab")
             .px(px(5.))
             .prefix(div().size(px(13.)).into_any_element())
@@ -673,8 +677,8 @@ impl Pane {
                     .rounded_sm()
                     .invisible()
                     .child(Icon::new(IconName::Close).size(px(12.)))
-                    .hover(|this| this.bg(cx.theme().accent.darken(0.1)))
-                    .active(|this| this.bg(cx.theme().accent.darken(0.2)))
+                    .hover(|this| this.bg(cx.theme().tokens.highlight.darken(0.1)))
+                    .active(|this| this.bg(cx.theme().tokens.highlight.darken(0.2)))
                     .on_click(cx.listener(move |pane, _, cx| {
                         pane.close_item_by_id(item_id, cx).detach_and_log_err(cx);
                     }))
@@ -775,7 +775,7 @@ mod element {
                     };
                     cx.set_cursor_style(cursor_style, &handle.hitbox);
                     // Pane Group border
-                    cx.paint_quad(gpui::fill(handle.divider_bounds, cx.theme().border));
+                    cx.paint_quad(gpui::fill(handle.divider_bounds, cx.theme().tokens.outline));
 
                     cx.on_mouse_event({
                         let dragged_handle = layout.dragged_handle.clone();
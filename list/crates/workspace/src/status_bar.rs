@@ -0,0 +1,125 @@
+use std::{any::TypeId, sync::Arc};
+
+use gpui::{
+    AnyView, IntoElement, ParentElement as _, Render, Styled as _, View, ViewContext, WindowContext,
+};
+use ui::{dock::PanelView, h_flex, theme::ActiveTheme as _};
+
+/// An item that can be added to the [`StatusBar`].
+pub trait StatusItemView: Render {
+    /// Called when the active panel of the workspace has changed, to update the item by the panel.
+    fn set_active_panel(
+        &mut self,
+        _panel: Option<Arc<dyn PanelView>>,
+        _cx: &mut ViewContext<Self>,
+    ) {
+    }
+}
+
+trait StatusItemViewHandle: 'static {
+    fn to_any(&self) -> AnyView;
+    fn set_active_panel(&self, panel: Option<Arc<dyn PanelView>>, cx: &mut WindowContext);
+    fn item_type(&self) -> TypeId;
+}
+
+impl<T: StatusItemView> StatusItemViewHandle for View<T> {
+    fn to_any(&self) -> AnyView {
+        self.clone().into()
+    }
+
+    fn set_active_panel(&self, panel: Option<Arc<dyn PanelView>>, cx: &mut WindowContext) {
+        self.update(cx, |this, cx| this.set_active_panel(panel, cx));
+    }
+
+    fn item_type(&self) -> TypeId {
+        TypeId::of::<T>()
+    }
+}
+
+/// The bar at the bottom of the workspace, with the items on the left and right side.
+pub struct StatusBar {
+    left_items: Vec<Box<dyn StatusItemViewHandle>>,
+    right_items: Vec<Box<dyn StatusItemViewHandle>>,
+    active_panel: Option<Arc<dyn PanelView>>,
+}
+
+impl StatusBar {
+    pub fn new(_cx: &mut ViewContext<Self>) -> Self {
+        Self {
+            left_items: Vec::new(),
+            right_items: Vec::new(),
+            active_panel: None,
+        }
+    }
+
+    /// Add an item to the end of the left side.
+    pub fn add_left_item<T: StatusItemView>(&mut self, item: View<T>, cx: &mut ViewContext<Self>) {
+        item.set_active_panel(self.active_panel.clone(), cx);
+        self.left_items.push(Box::new(item));
+        cx.notify();
+    }
+
+    /// Add an item to the start of the right side.
+    pub fn add_right_item<T: StatusItemView>(&mut self, item: View<T>, cx: &mut ViewContext<Self>) {
+        item.set_active_panel(self.active_panel.clone(), cx);
+        self.right_items.insert(0, Box::new(item));
+        cx.notify();
+    }
+
+    /// Returns the first item of the type.
+    pub fn item_of_type<T: StatusItemView>(&self) -> Option<View<T>> {
+        self.left_items
+            .iter()
+            .chain(self.right_items.iter())
+            .find_map(|item| item.to_any().downcast().ok())
+    }
+
+    /// Remove all the items of the type.
+    pub fn remove_item<T: StatusItemView>(&mut self, cx: &mut ViewContext<Self>) {
+        let item_type = TypeId::of::<T>();
+        self.left_items.retain(|item| item.item_type() != item_type);
+        self.right_items
+            .retain(|item| item.item_type() != item_type);
+        cx.notify();
+    }
+
+    /// Set the active panel and tell all the items.
+    pub fn set_active_panel(
+        &mut self,
+        panel: Option<Arc<dyn PanelView>>,
+        cx: &mut ViewContext<Self>,
+    ) {
+        self.active_panel = panel;
+        for item in self.left_items.iter().chain(self.right_items.iter()) {
+            item.set_active_panel(self.active_panel.clone(), cx);
+        }
+        cx.notify();
+    }
+}
+
+impl Render for StatusBar {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        h_flex()
+            .w_full()
+            .h_7()
+            .px_2()
+            .gap_4()
+            .justify_between()
+            .border_t_1()
+            .border_color(cx.theme().tokens.outline)
+            .bg(cx.theme().title_bar_background)
+            .text_xs()
+            .text_color(cx.theme().tokens.on_surface_muted)
+            .child(
+                h_flex()
+                    .gap_3()
+                    .overflow_hidden()
+                    .children(self.left_items.iter().map(|item| item.to_any())),
+            )
+            .child(
+                h_flex()
+                    .gap_3()
+                    .children(self.right_items.iter().map(|item| item.to_any())),
+            )
+    }
+}
@@ -106,7 +106,7 @@ impl RenderOnce for TitleBar {
             })
             .shadow_sm()
             .border_b_1()
-            .border_color(theme.border)
+            .border_color(theme.tokens.outline)
             .bg(theme.title_bar_background)
             .content_stretch()
             .child(
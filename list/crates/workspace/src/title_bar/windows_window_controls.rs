@@ -117,7 +117,7 @@ impl RenderOnce for WindowsCaptionButton {
             .h_full()
             .text_size(px(10.0))
             .font_family(Self::get_font())
-            .text_color(cx.theme().foreground)
+            .text_color(cx.theme().tokens.on_surface)
             .hover(|style| style.bg(self.hover_background_color))
             .active(|style| {
                 let mut active_color = self.hover_background_color;
@@ -116,8 +116,8 @@ impl Render for Workspace {
         //         div()
         //             .h_full()
         //             .w(relative(size))
-        //             .bg(cx.theme().background)
-        //             .border_color(cx.theme().border)
+        //             .bg(cx.theme().tokens.surface)
+        //             .border_color(cx.theme().tokens.outline)
         //     })
         // };
 
@@ -131,8 +131,8 @@ impl Render for Workspace {
             .gap_0()
             .justify_start()
             .items_start()
-            .text_color(cx.theme().foreground)
-            .bg(cx.theme().background)
+            .text_color(cx.theme().tokens.on_surface)
+            .bg(cx.theme().tokens.surface)
             // .children(self.titlebar_item.clone())
             .child(
                 div()
@@ -226,8 +226,8 @@ impl Render for Workspace {
                             .occlude()
                             .absolute()
                             .overflow_hidden()
-                            .border_color(cx.theme().border)
-                            .bg(cx.theme().background)
+                            .border_color(cx.theme().tokens.outline)
+                            .bg(cx.theme().tokens.surface)
                             .child(zoomed_view)
                             .inset_0()
                             .shadow_lg();